@@ -5,7 +5,7 @@ use crate::const_helpers::const_slice_mut;
 /// Represents a fixed-size array of bytes. This array is optimized
 /// for size. The size of the array cannot exceed `u8::MAX`.
 #[repr(C)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 #[must_use]
 pub struct FixedBytes<const CAPACITY: usize> {
     data: [u8; CAPACITY],
@@ -141,6 +141,16 @@ impl<const CAPACITY: usize> AsMut<[u8]> for FixedBytes<CAPACITY> {
     }
 }
 
+impl<const CAPACITY: usize> PartialEq for FixedBytes<CAPACITY> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Only the pushed bytes are meaningful, the rest of the buffer is garbage.
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const CAPACITY: usize> Eq for FixedBytes<CAPACITY> {}
+
 impl<const CAPACITY: usize> Hash for FixedBytes<CAPACITY> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.as_slice().hash(state);
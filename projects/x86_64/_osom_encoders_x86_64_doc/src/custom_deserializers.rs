@@ -35,3 +35,15 @@ where
 
     Ok(decoded)
 }
+
+pub fn de_mandatory_prefix<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let decoded = de_primary_opcode(deserializer)?;
+    if decoded.len() != 1 {
+        return Err(serde::de::Error::custom("Mandatory prefix must be a single byte."));
+    }
+
+    Ok(Some(decoded[0]))
+}
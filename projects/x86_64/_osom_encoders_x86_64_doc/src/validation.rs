@@ -2,9 +2,9 @@ use std::collections::HashSet;
 
 use strum::IntoEnumIterator as _;
 
-use crate::FlagId;
+use crate::{FlagId, PrefixId};
 
-use super::{OperandEncodingId, OperandId, X86Doc};
+use super::{InstructionVariant, OperandEncodingId, OperandId, X86Doc};
 
 pub fn validate(doc: &X86Doc) {
     // Validate operands.
@@ -44,6 +44,8 @@ pub fn validate(doc: &X86Doc) {
         "Expected {expected_flags_count}, but got {flags_count}."
     );
 
+    validate_prefixes(doc);
+
     // Validate operand encodings.
     let mut doc_operand_encodings = HashSet::new();
     let mut doc_operand_encodings_count = 0;
@@ -74,6 +76,10 @@ pub fn validate(doc: &X86Doc) {
             valid_name(name),
             "Instruction group name [{name}] contains invalid characters."
         );
+        assert!(
+            name != "safe",
+            "Instruction group name [{name}] is reserved for safe encoders module."
+        );
         let id = doc_instruction_group.id;
         assert!(
             seen_names.insert(name.clone()),
@@ -104,14 +110,529 @@ pub fn validate(doc: &X86Doc) {
                 "Instruction variant id [{variant_id}] inside group [{name}] has unique name [{unique_name}] which is not unique."
             );
 
-            let opcode = &doc_instruction_variant.primary_opcode;
-            if doc_instruction_variant.operand_encoding == OperandEncodingId::OI {
-                assert!(opcode.len() == 1, "Opcode for OI encoding must be 1 byte long.");
+            if let Some(mnemonic) = &doc_instruction_variant.mnemonic {
+                assert!(
+                    valid_name(mnemonic),
+                    "Instruction variant id [{variant_id}] inside group [{name}] has mnemonic [{mnemonic}] which contains invalid characters."
+                );
             }
+
+            validate_variant(name, doc_instruction_variant);
         }
     }
 }
 
+fn validate_variant(name: &str, variant: &InstructionVariant) {
+    validate_operand_encoding(name, variant);
+    validate_lock_flag(name, variant);
+    validate_prefix(name, variant);
+    validate_mandatory_prefix(name, variant);
+    validate_cc_flag(name, variant);
+    validate_vex_flag(name, variant);
+    validate_xmm_operands(name, variant);
+}
+
+fn validate_mandatory_prefix(name: &str, variant: &InstructionVariant) {
+    let Some(mandatory_prefix) = variant.mandatory_prefix else {
+        return;
+    };
+    let variant_id = variant.id;
+    assert!(
+        matches!(mandatory_prefix, 0x66 | 0xF2 | 0xF3),
+        "Instruction variant id [{variant_id}] inside group [{name}] has mandatory prefix [{mandatory_prefix:02X}], but only 66, F2 and F3 are supported."
+    );
+    assert!(
+        mandatory_prefix != 0x66 || !variant.flags.contains(&FlagId::OSO),
+        "Instruction variant id [{variant_id}] inside group [{name}] cannot have both OSO flag and 66 mandatory prefix."
+    );
+    assert!(
+        variant.prefix.is_none(),
+        "Instruction variant id [{variant_id}] inside group [{name}] cannot have both prefix and mandatory prefix."
+    );
+}
+
+fn validate_cc_flag(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    if variant.flags.contains(&FlagId::CcAliases) {
+        assert!(
+            variant.flags.contains(&FlagId::Cc),
+            "Instruction variant id [{variant_id}] inside group [{name}] has CcAliases flag, but no Cc flag."
+        );
+    }
+    if !variant.flags.contains(&FlagId::Cc) {
+        return;
+    }
+    assert!(
+        variant.name.is_none(),
+        "Instruction variant id [{variant_id}] inside group [{name}] has Cc flag, its name is derived from the conditions."
+    );
+    assert!(
+        variant.mnemonic.is_some(),
+        "Instruction variant id [{variant_id}] inside group [{name}] has Cc flag, but no mnemonic to add the conditions to."
+    );
+    assert!(
+        variant.primary_opcode.last().unwrap().trailing_zeros() >= 4,
+        "Instruction variant id [{variant_id}] inside group [{name}] has Cc flag, but the lower 4 bits of its opcode are not zero."
+    );
+    for flag in [FlagId::Lock, FlagId::Vex, FlagId::Evex] {
+        assert!(
+            !variant.flags.contains(&flag),
+            "Instruction variant id [{variant_id}] inside group [{name}] cannot have both Cc and {flag:?} flags."
+        );
+    }
+}
+
+fn validate_prefixes(doc: &X86Doc) {
+    let mut prefixes = HashSet::new();
+    let mut prefixes_count = 0;
+    for prefix in &doc.prefixes {
+        prefixes.insert(prefix.id.clone());
+        prefixes_count += 1;
+    }
+
+    let mut expected_prefixes_count = 0;
+    for prefix_id in PrefixId::iter() {
+        assert!(prefixes.contains(&prefix_id), "Prefix [{prefix_id:?}] is not valid.");
+        expected_prefixes_count += 1;
+    }
+
+    assert_eq!(
+        prefixes_count, expected_prefixes_count,
+        "Expected {expected_prefixes_count} prefixes, but got {prefixes_count}."
+    );
+}
+
+fn validate_operand_encoding(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    match variant.operand_encoding {
+        OperandEncodingId::OI => {
+            assert!(
+                variant.primary_opcode.len() == 1,
+                "Opcode for OI encoding must be 1 byte long."
+            );
+        }
+        OperandEncodingId::RMI | OperandEncodingId::MRI | OperandEncodingId::RVM | OperandEncodingId::RMV => {
+            assert!(
+                count_non_decorator_operands(variant) == 3,
+                "Instruction variant id [{variant_id}] inside group [{name}] with {:?} encoding must have exactly 3 operands.",
+                variant.operand_encoding
+            );
+        }
+        OperandEncodingId::RVMI => {
+            assert!(
+                count_non_decorator_operands(variant) == 4,
+                "Instruction variant id [{variant_id}] inside group [{name}] with RVMI encoding must have exactly 4 operands."
+            );
+        }
+        OperandEncodingId::VM => {
+            assert!(
+                variant.extended_opcode.is_some(),
+                "Instruction variant id [{variant_id}] inside group [{name}] with VM encoding must have extended opcode."
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Counts the operands of `variant` ignoring EVEX decorators, i.e. masking,
+/// static rounding and SAE.
+fn count_non_decorator_operands(variant: &InstructionVariant) -> usize {
+    variant
+        .operands
+        .iter()
+        .filter(|operand| !is_evex_decorator(operand))
+        .count()
+}
+
+fn is_evex_decorator(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::Mask | OperandId::MergeMask | OperandId::Er | OperandId::Sae
+    )
+}
+
+fn validate_lock_flag(name: &str, variant: &InstructionVariant) {
+    if !variant.flags.contains(&FlagId::Lock) {
+        return;
+    }
+    let variant_id = variant.id;
+    assert!(
+        matches!(
+            variant.operand_encoding,
+            OperandEncodingId::M | OperandEncodingId::MI | OperandEncodingId::MR
+        ),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Lock flag must have M, MI or MR encoding."
+    );
+    assert!(
+        matches!(
+            variant.operands.first(),
+            Some(
+                OperandId::RM8
+                    | OperandId::RM16
+                    | OperandId::RM32
+                    | OperandId::RM64
+                    | OperandId::Mem64
+                    | OperandId::Mem128
+            )
+        ),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Lock flag must have register or memory destination."
+    );
+}
+
+fn validate_vex_flag(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    let is_vex = variant.flags.contains(&FlagId::Vex);
+    assert!(
+        is_vex || !variant.flags.contains(&FlagId::VexL),
+        "Instruction variant id [{variant_id}] inside group [{name}] with VexL flag must have Vex flag."
+    );
+    if variant.flags.contains(&FlagId::Evex) {
+        validate_evex_flag(name, variant);
+        return;
+    }
+    let requires_vex = matches!(
+        variant.operand_encoding,
+        OperandEncodingId::RVM | OperandEncodingId::RMV | OperandEncodingId::VM
+    );
+    assert!(
+        is_vex || !requires_vex,
+        "Instruction variant id [{variant_id}] inside group [{name}] with {:?} encoding must have Vex flag.",
+        variant.operand_encoding
+    );
+    assert!(
+        variant.operand_encoding != OperandEncodingId::RVMI,
+        "Instruction variant id [{variant_id}] inside group [{name}] with RVMI encoding must have Evex flag."
+    );
+    if !is_vex {
+        return;
+    }
+
+    assert!(
+        requires_vex
+            || matches!(
+                variant.operand_encoding,
+                OperandEncodingId::RMI | OperandEncodingId::MRI | OperandEncodingId::MR | OperandEncodingId::ZO
+            ),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag must have RVM, RMV, VM, RMI, MRI, MR or ZO encoding."
+    );
+    assert!(
+        matches!(variant.primary_opcode.as_slice(), [0x0F, _] | [0x0F, 0x38 | 0x3A, _]),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag must have 0F, 0F38 or 0F3A opcode map."
+    );
+    assert!(
+        !variant.flags.contains(&FlagId::OSO) && !variant.flags.contains(&FlagId::Lock),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag cannot have OSO or Lock flags."
+    );
+}
+
+fn validate_evex_flag(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    assert!(
+        !variant.flags.contains(&FlagId::Vex),
+        "Instruction variant id [{variant_id}] inside group [{name}] cannot have both Vex and Evex flags."
+    );
+    assert!(
+        matches!(
+            variant.operand_encoding,
+            OperandEncodingId::RVM | OperandEncodingId::RVMI | OperandEncodingId::MR
+        ),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag must have RVM, RVMI or MR encoding."
+    );
+    assert!(
+        matches!(variant.primary_opcode.as_slice(), [0x0F, _] | [0x0F, 0x38 | 0x3A, _]),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag must have 0F, 0F38 or 0F3A opcode map."
+    );
+    assert!(
+        !variant.flags.contains(&FlagId::OSO) && !variant.flags.contains(&FlagId::Lock),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag cannot have OSO or Lock flags."
+    );
+}
+
+fn validate_xmm_operands(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    if variant.flags.contains(&FlagId::Evex) {
+        validate_evex_operands(name, variant);
+        return;
+    }
+    assert!(
+        !variant.operands.iter().any(is_evex_only_operand),
+        "Instruction variant id [{variant_id}] inside group [{name}] uses ZMM, XmmR, VSIB memory with ZMM index or EVEX decorator operand without Evex flag."
+    );
+    if variant.flags.contains(&FlagId::Vex) {
+        validate_vex_vector_operands(name, variant);
+        return;
+    }
+    assert!(
+        !variant.operands.iter().any(is_vex_only_vector_operand),
+        "Instruction variant id [{variant_id}] inside group [{name}] uses YMM, VEX.vvvv XMM, XmmM8, XmmM16, VSIB memory or opmask operand without Vex flag."
+    );
+    let has_xmm = variant.operands.iter().any(|operand| {
+        matches!(
+            operand,
+            OperandId::Xmm | OperandId::XmmM32 | OperandId::XmmM64 | OperandId::XmmM128 | OperandId::XMM0
+        )
+    });
+    if !has_xmm {
+        assert!(
+            !variant
+                .operands
+                .iter()
+                .any(|operand| matches!(operand, OperandId::R32M8 | OperandId::R32M16)),
+            "Instruction variant id [{variant_id}] inside group [{name}] uses R32M8 or R32M16 operand without XMM operands."
+        );
+        assert!(
+            variant.operand_encoding != OperandEncodingId::MRI,
+            "Instruction variant id [{variant_id}] inside group [{name}] with MRI encoding must have XMM operands."
+        );
+        return;
+    }
+    let explicit_operands = variant.explicit_operands();
+    match variant.operand_encoding {
+        OperandEncodingId::MR => assert!(
+            explicit_operands.len() == 2,
+            "Instruction variant id [{variant_id}] inside group [{name}] with XMM operands and MR encoding must have exactly 2 explicit operands."
+        ),
+        OperandEncodingId::RMI | OperandEncodingId::MRI => assert!(
+            explicit_operands.len() == 3 && explicit_operands[2] == OperandId::Imm8,
+            "Instruction variant id [{variant_id}] inside group [{name}] with XMM operands and {:?} encoding must have exactly 2 explicit operands followed by Imm8.",
+            variant.operand_encoding
+        ),
+        _ => panic!(
+            "Instruction variant id [{variant_id}] inside group [{name}] with XMM operands must have MR, RMI or MRI encoding."
+        ),
+    }
+    assert!(
+        variant.operands.contains(&OperandId::Xmm)
+            || matches!(variant.operands.first(), Some(OperandId::Reg32 | OperandId::Reg64)),
+        "Instruction variant id [{variant_id}] inside group [{name}] with XMM operands must have XMM or general purpose register in the reg field."
+    );
+    assert!(
+        !variant.flags.contains(&FlagId::OSO),
+        "Instruction variant id [{variant_id}] inside group [{name}] with XMM operands cannot have OSO flag."
+    );
+}
+
+fn validate_vex_vector_operands(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    let operands = &variant.operands;
+    if !operands.iter().any(is_vector_operand) {
+        assert!(
+            variant.operand_encoding != OperandEncodingId::MR,
+            "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag and MR encoding must have XMM or YMM operands."
+        );
+        assert!(
+            variant.operand_encoding != OperandEncodingId::ZO || operands.is_empty(),
+            "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag and ZO encoding cannot have operands."
+        );
+        return;
+    }
+
+    if operands.iter().any(is_opmask_operand) {
+        validate_vex_opmask_operands(name, variant);
+        return;
+    }
+
+    let is_register = |operand: &OperandId| matches!(operand, OperandId::Xmm | OperandId::Ymm);
+    let is_vvvv = |operand: &OperandId| matches!(operand, OperandId::VXmm | OperandId::VYmm);
+    let valid = match variant.operand_encoding {
+        OperandEncodingId::RVM => {
+            operands.len() == 3
+                && is_register(&operands[0])
+                && is_vvvv(&operands[1])
+                && is_vector_operand(&operands[2])
+                && !is_vvvv(&operands[2])
+        }
+        OperandEncodingId::MR => {
+            operands.len() == 2
+                && operands
+                    .iter()
+                    .all(|operand| is_vector_operand(operand) && !is_vvvv(operand))
+                && operands.iter().any(is_register)
+        }
+        OperandEncodingId::MRI => {
+            operands.len() == 3
+                && is_vector_operand(&operands[0])
+                && !is_register(&operands[0])
+                && !is_vvvv(&operands[0])
+                && is_register(&operands[1])
+                && operands[2] == OperandId::Imm8
+        }
+        OperandEncodingId::RMV => {
+            operands.len() == 3 && is_register(&operands[0]) && is_vsib_operand(&operands[1]) && is_vvvv(&operands[2])
+        }
+        _ => false,
+    };
+    assert!(
+        valid,
+        "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag and XMM or YMM operands must have RVM encoding with reg, VEX.vvvv and r/m operands, MR encoding with reg and r/m operands, MRI encoding with r/m, reg and Imm8 operands, or RMV encoding with reg, VSIB memory and VEX.vvvv operands."
+    );
+}
+
+fn validate_vex_opmask_operands(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    let valid = matches!(
+        (&variant.operand_encoding, variant.operands.as_slice()),
+        (OperandEncodingId::RVM, [OperandId::K, OperandId::VK, OperandId::KR])
+            | (
+                OperandEncodingId::MR,
+                [
+                    OperandId::K,
+                    OperandId::KR | OperandId::KM16 | OperandId::Reg32 | OperandId::Reg64
+                ] | [OperandId::Mem16 | OperandId::Reg32 | OperandId::Reg64, OperandId::KR]
+                    | [OperandId::Mem16, OperandId::K],
+            )
+    );
+    assert!(
+        valid,
+        "Instruction variant id [{variant_id}] inside group [{name}] with Vex flag and opmask operands must have RVM encoding with K, VK and KR operands, or MR encoding with opmask reg operand and r/m operand."
+    );
+}
+
+fn validate_evex_operands(name: &str, variant: &InstructionVariant) {
+    let variant_id = variant.id;
+    let operands = variant
+        .operands
+        .iter()
+        .filter(|operand| !is_evex_decorator(operand))
+        .collect::<Vec<_>>();
+    let mask = variant
+        .operands
+        .iter()
+        .position(|operand| matches!(operand, OperandId::Mask | OperandId::MergeMask));
+    assert!(
+        mask == Some(1),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag must have Mask or MergeMask right after the destination."
+    );
+    let is_register_only_rm = |operand: &OperandId| matches!(operand, OperandId::ZmmR | OperandId::XmmR);
+    let has_rounding = variant
+        .operands
+        .iter()
+        .any(|operand| matches!(operand, OperandId::Er | OperandId::Sae));
+    assert!(
+        has_rounding == operands.iter().any(|operand| is_register_only_rm(operand)),
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag must have ZmmR or XmmR r/m operand if and only if it has Er or Sae operand."
+    );
+    if operands[0] == &OperandId::K
+        || variant.operand_encoding == OperandEncodingId::MR && is_memory_destination(operands[0])
+        || operands.iter().any(|operand| is_vsib_operand(operand))
+    {
+        assert!(
+            variant.operands[1] == OperandId::MergeMask,
+            "Instruction variant id [{variant_id}] inside group [{name}] with opmask destination, memory destination or VSIB memory must have MergeMask operand."
+        );
+    }
+
+    let is_register = |operand: &OperandId| matches!(operand, OperandId::Zmm | OperandId::Xmm | OperandId::K);
+    let is_vsib_register = |operand: &OperandId| matches!(operand, OperandId::Zmm | OperandId::Ymm);
+    let is_vvvv = |operand: &OperandId| matches!(operand, OperandId::VZmm | OperandId::VXmm);
+    let is_rm = |operand: &OperandId| {
+        matches!(
+            operand,
+            OperandId::ZmmR
+                | OperandId::ZmmM512
+                | OperandId::ZmmM512B32
+                | OperandId::ZmmM512B64
+                | OperandId::XmmR
+                | OperandId::XmmM32
+                | OperandId::XmmM64
+        )
+    };
+    let valid = match (&variant.operand_encoding, operands.as_slice()) {
+        (OperandEncodingId::RVM, [reg, vvvv, rm]) | (OperandEncodingId::RVMI, [reg, vvvv, rm, OperandId::Imm8]) => {
+            is_register(reg) && is_vvvv(vvvv) && is_rm(rm)
+        }
+        (OperandEncodingId::MR, [reg, rm]) => {
+            is_register(reg) && is_rm(rm)
+                || is_rm(reg) && is_register(rm)
+                || is_vsib_register(reg) && is_vsib_operand(rm)
+                || is_vsib_operand(reg) && is_vsib_register(rm)
+        }
+        _ => false,
+    };
+    assert!(
+        valid,
+        "Instruction variant id [{variant_id}] inside group [{name}] with Evex flag must have RVM or RVMI encoding with reg, EVEX.vvvv and r/m operands, or MR encoding with reg and r/m (or VSIB memory) operands."
+    );
+}
+
+fn is_memory_destination(operand: &OperandId) -> bool {
+    matches!(operand, OperandId::ZmmM512) || is_vsib_operand(operand)
+}
+
+fn is_vsib_operand(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::Vm32X | OperandId::Vm32Y | OperandId::Vm32Z | OperandId::Vm64X | OperandId::Vm64Y | OperandId::Vm64Z
+    )
+}
+
+fn is_opmask_operand(operand: &OperandId) -> bool {
+    matches!(operand, OperandId::K | OperandId::VK | OperandId::KR | OperandId::KM16)
+}
+
+/// Returns true if `operand` can be used only together with EVEX prefix.
+fn is_evex_only_operand(operand: &OperandId) -> bool {
+    is_evex_decorator(operand)
+        || matches!(
+            operand,
+            OperandId::Zmm
+                | OperandId::VZmm
+                | OperandId::ZmmR
+                | OperandId::ZmmM512
+                | OperandId::ZmmM512B32
+                | OperandId::ZmmM512B64
+                | OperandId::XmmR
+                | OperandId::Vm32Z
+                | OperandId::Vm64Z
+        )
+}
+
+fn is_vector_operand(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::Xmm
+            | OperandId::XmmM8
+            | OperandId::XmmM16
+            | OperandId::XmmM32
+            | OperandId::XmmM64
+            | OperandId::XmmM128
+            | OperandId::XMM0
+            | OperandId::VXmm
+            | OperandId::Ymm
+            | OperandId::VYmm
+            | OperandId::YmmM256
+    ) || is_opmask_operand(operand)
+}
+
+/// Returns true if `operand` can be used only together with VEX (or EVEX) prefix.
+fn is_vex_only_vector_operand(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::VXmm
+            | OperandId::XmmM8
+            | OperandId::XmmM16
+            | OperandId::Ymm
+            | OperandId::VYmm
+            | OperandId::YmmM256
+            | OperandId::Mem16
+            | OperandId::Vm32X
+            | OperandId::Vm32Y
+            | OperandId::Vm64X
+            | OperandId::Vm64Y
+    ) || is_opmask_operand(operand)
+}
+
+fn validate_prefix(name: &str, variant: &InstructionVariant) {
+    if variant.prefix.is_none() {
+        return;
+    }
+    let variant_id = variant.id;
+    assert!(
+        variant.operand_encoding == OperandEncodingId::ZO,
+        "Instruction variant id [{variant_id}] inside group [{name}] with prefix must have ZO encoding."
+    );
+}
+
 fn valid_name(name: &str) -> bool {
     let mut first_char = true;
     !name.is_empty()
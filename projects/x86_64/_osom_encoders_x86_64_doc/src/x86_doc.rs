@@ -30,6 +30,24 @@ pub enum OperandEncodingId {
 
     /// Register encoded in opcode.
     O,
+
+    /// Register operand, memory/register operand and immediate operand.
+    RMI,
+
+    /// Memory/register operand, register operand and immediate operand.
+    MRI,
+
+    /// Register operand, VEX.vvvv register operand and memory/register operand.
+    RVM,
+
+    /// Register operand, memory/register operand and VEX.vvvv register operand.
+    RMV,
+
+    /// VEX.vvvv register operand and memory/register operand with an extended opcode.
+    VM,
+
+    /// Register operand, EVEX.vvvv register operand, memory/register operand and immediate operand.
+    RVMI,
 }
 
 /// Represents various operands used by the `X86_64` instruction set.
@@ -60,6 +78,12 @@ pub enum OperandId {
     /// 64-bit general purpose register or memory operand.
     RM64,
 
+    /// 32-bit general purpose register or 8-bit memory operand.
+    R32M8,
+
+    /// 32-bit general purpose register or 16-bit memory operand.
+    R32M16,
+
     /// 8-bit general purpose register.
     Reg8,
 
@@ -72,8 +96,147 @@ pub enum OperandId {
     /// 64-bit general purpose register.
     Reg64,
 
+    /// 32-bit general purpose register encoded in VEX.vvvv.
+    VReg32,
+
+    /// 64-bit general purpose register encoded in VEX.vvvv.
+    VReg64,
+
+    /// 128-bit XMM register.
+    Xmm,
+
+    /// 128-bit XMM register encoded in VEX.vvvv.
+    VXmm,
+
+    /// XMM register or 8-bit memory operand.
+    XmmM8,
+
+    /// XMM register or 16-bit memory operand.
+    XmmM16,
+
+    /// XMM register or 32-bit memory operand.
+    XmmM32,
+
+    /// XMM register or 64-bit memory operand.
+    XmmM64,
+
+    /// XMM register or 128-bit memory operand.
+    XmmM128,
+
+    /// 256-bit YMM register.
+    Ymm,
+
+    /// 256-bit YMM register encoded in VEX.vvvv.
+    VYmm,
+
+    /// YMM register or 256-bit memory operand.
+    YmmM256,
+
+    /// 512-bit ZMM register.
+    Zmm,
+
+    /// 512-bit ZMM register encoded in EVEX.vvvv.
+    VZmm,
+
+    /// 512-bit ZMM register in the r/m field, used by static rounding and SAE forms.
+    ZmmR,
+
+    /// ZMM register or 512-bit memory operand.
+    ZmmM512,
+
+    /// ZMM register, 512-bit memory operand or 32-bit memory operand broadcast to all elements.
+    ZmmM512B32,
+
+    /// ZMM register, 512-bit memory operand or 64-bit memory operand broadcast to all elements.
+    ZmmM512B64,
+
+    /// XMM register in the r/m field, used by static rounding forms of EVEX scalar instructions.
+    XmmR,
+
+    /// VSIB memory operand with 32-bit indexes in XMM register.
+    Vm32X,
+
+    /// VSIB memory operand with 32-bit indexes in YMM register.
+    Vm32Y,
+
+    /// VSIB memory operand with 32-bit indexes in ZMM register.
+    Vm32Z,
+
+    /// VSIB memory operand with 64-bit indexes in XMM register.
+    Vm64X,
+
+    /// VSIB memory operand with 64-bit indexes in YMM register.
+    Vm64Y,
+
+    /// VSIB memory operand with 64-bit indexes in ZMM register.
+    Vm64Z,
+
+    /// Opmask register.
+    K,
+
+    /// Opmask register encoded in VEX.vvvv.
+    VK,
+
+    /// Opmask register in the r/m field.
+    KR,
+
+    /// Opmask register or 16-bit memory operand.
+    KM16,
+
+    /// EVEX write masking of the destination, i.e. `{k}` with optional `{z}`.
+    Mask,
+
+    /// EVEX merge masking of the destination, i.e. `{k}` without `{z}`.
+    MergeMask,
+
+    /// EVEX static rounding, i.e. `{rn-sae}`, `{rd-sae}`, `{ru-sae}` or `{rz-sae}`.
+    Er,
+
+    /// 16-bit memory.
+    Mem16,
+
     /// 64-bit memory.
     Mem64,
+
+    /// 128-bit memory.
+    Mem128,
+
+    /// Implicit AL register, not passed to encoders.
+    AL,
+
+    /// Implicit AX register, not passed to encoders.
+    AX,
+
+    /// Implicit EAX register, not passed to encoders.
+    EAX,
+
+    /// Implicit RAX register, not passed to encoders.
+    RAX,
+
+    /// Implicit CL register, not passed to encoders.
+    CL,
+
+    /// Implicit XMM0 register, not passed to encoders.
+    XMM0,
+
+    /// Implicit constant 1, not passed to encoders.
+    One,
+
+    /// Implicit EVEX suppression of all floating-point exceptions, i.e. `{sae}`,
+    /// not passed to encoders.
+    Sae,
+}
+
+impl OperandId {
+    /// Returns true if the operand is implied by the opcode itself,
+    /// and thus is not passed to the encoders.
+    #[must_use]
+    pub fn is_implicit(&self) -> bool {
+        matches!(
+            self,
+            Self::AL | Self::AX | Self::EAX | Self::RAX | Self::CL | Self::XMM0 | Self::One | Self::Sae
+        )
+    }
 }
 
 /// Represents various flags used by the `X86_64` instruction set.
@@ -85,8 +248,189 @@ pub enum FlagId {
 
     /// Requires REX.W prefix.
     RexW,
+
+    /// Immediate operand is unsigned, i.e. it is neither sign-extended nor relative.
+    UnsignedImm,
+
+    /// Accepts LOCK prefix, when the destination (first) operand is memory.
+    Lock,
+
+    /// Requires VEX prefix. The opcode map, the mandatory prefix and
+    /// REX.W are encoded inside of it.
+    Vex,
+
+    /// Sets VEX.L for VEX encoded instructions without YMM operands, e.g. `kandw`.
+    VexL,
+
+    /// Requires EVEX prefix. The opcode map, the mandatory prefix, REX.W,
+    /// the vector length and the masking are encoded inside of it.
+    Evex,
+
+    /// The condition code is added to the last opcode byte, e.g. `0F 90+cc`
+    /// of `setcc`. The variant stands for one instruction per condition,
+    /// see [`InstructionVariant::expand_conditions`].
+    Cc,
+
+    /// Together with [`FlagId::Cc`], requests a dedicated encoder per condition,
+    /// e.g. `jcc::encode_e_imm8`.
+    CcAliases,
+}
+
+/// Represents legacy prefixes, which are written before the mnemonic,
+/// e.g. `rep movsb`.
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone, EnumIter, IntoStaticStr)]
+#[must_use]
+pub enum PrefixId {
+    /// REP prefix, repeats the string instruction RCX times.
+    Rep,
+
+    /// REPE prefix, repeats the string comparison while equal.
+    RepE,
+
+    /// REPNE prefix, repeats the string comparison while not equal.
+    RepNE,
+}
+
+impl PrefixId {
+    /// Returns the encoded byte of the prefix.
+    #[must_use]
+    pub fn byte(&self) -> u8 {
+        match self {
+            Self::Rep | Self::RepE => 0xF3,
+            Self::RepNE => 0xF2,
+        }
+    }
+
+    /// Returns the prefix as written in assembly, e.g. `repne`.
+    #[must_use]
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Self::Rep => "rep",
+            Self::RepE => "repe",
+            Self::RepNE => "repne",
+        }
+    }
+}
+
+/// Represents a condition of instructions with [`FlagId::Cc`] flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct ConditionCode {
+    /// The name of the condition, e.g. `NE`. It matches the name
+    /// of the corresponding `Condition` constant.
+    pub name: &'static str,
+
+    /// The value added to the last opcode byte.
+    pub code: u8,
+
+    pub description: &'static str,
 }
 
+/// All the conditions of instructions with [`FlagId::Cc`] flag, including
+/// the aliases (e.g. `P` and `PE`).
+pub const CONDITION_CODES: [ConditionCode; 20] = [
+    ConditionCode {
+        name: "A",
+        code: 0x7,
+        description: "Above (in unsigned sense)",
+    },
+    ConditionCode {
+        name: "AE",
+        code: 0x3,
+        description: "Above Or Equal (in unsigned sense)",
+    },
+    ConditionCode {
+        name: "B",
+        code: 0x2,
+        description: "Below (in unsigned sense)",
+    },
+    ConditionCode {
+        name: "BE",
+        code: 0x6,
+        description: "Below Or Equal (in unsigned sense)",
+    },
+    ConditionCode {
+        name: "E",
+        code: 0x4,
+        description: "Equal",
+    },
+    ConditionCode {
+        name: "G",
+        code: 0xF,
+        description: "Greater (in signed sense)",
+    },
+    ConditionCode {
+        name: "GE",
+        code: 0xD,
+        description: "Greater Or Equal (in signed sense)",
+    },
+    ConditionCode {
+        name: "L",
+        code: 0xC,
+        description: "Less (in signed sense)",
+    },
+    ConditionCode {
+        name: "LE",
+        code: 0xE,
+        description: "Less Or Equal (in signed sense)",
+    },
+    ConditionCode {
+        name: "NE",
+        code: 0x5,
+        description: "Not Equal",
+    },
+    ConditionCode {
+        name: "NO",
+        code: 0x1,
+        description: "No Overflow",
+    },
+    ConditionCode {
+        name: "NP",
+        code: 0xB,
+        description: "No Parity",
+    },
+    ConditionCode {
+        name: "NS",
+        code: 0x9,
+        description: "No Sign",
+    },
+    ConditionCode {
+        name: "O",
+        code: 0x0,
+        description: "Overflow",
+    },
+    ConditionCode {
+        name: "P",
+        code: 0xA,
+        description: "Parity",
+    },
+    ConditionCode {
+        name: "PE",
+        code: 0xA,
+        description: "Parity Even",
+    },
+    ConditionCode {
+        name: "PO",
+        code: 0xB,
+        description: "Parity Odd",
+    },
+    ConditionCode {
+        name: "S",
+        code: 0x8,
+        description: "Sign",
+    },
+    ConditionCode {
+        name: "C",
+        code: 0x2,
+        description: "Carry",
+    },
+    ConditionCode {
+        name: "NC",
+        code: 0x3,
+        description: "Not Carry",
+    },
+];
+
 /// Full description of a flag.
 #[derive(Debug, Deserialize)]
 #[must_use]
@@ -95,6 +439,14 @@ pub struct FlagWithDescription {
     pub description: String,
 }
 
+/// Full description of a prefix.
+#[derive(Debug, Deserialize)]
+#[must_use]
+pub struct PrefixWithDescription {
+    pub id: PrefixId,
+    pub description: String,
+}
+
 /// Full description of an operand encoding.
 #[derive(Debug, Deserialize)]
 #[must_use]
@@ -113,30 +465,49 @@ pub struct Operand {
 
 /// Represents a single instruction variant, e.g.
 /// `mov eax, 1`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[must_use]
 pub struct InstructionVariant {
     pub id: u32,
     pub name: Option<String>,
+    pub mnemonic: Option<String>,
     #[serde(deserialize_with = "crate::custom_deserializers::de_primary_opcode")]
     pub primary_opcode: Vec<u8>,
     pub extended_opcode: Option<u8>,
+    /// The prefix which is a part of the opcode, e.g. `F3` of `popcnt`.
+    /// It is placed after other legacy prefixes, right before REX, unless
+    /// the variant has [`FlagId::Vex`] or [`FlagId::Evex`] flag, in which case
+    /// it is encoded inside VEX or EVEX.
+    #[serde(default, deserialize_with = "crate::custom_deserializers::de_mandatory_prefix")]
+    pub mandatory_prefix: Option<u8>,
     pub operand_encoding: OperandEncodingId,
     #[serde(default)]
     pub operands: Vec<OperandId>,
     pub description: String,
     #[serde(default)]
     pub flags: HashSet<FlagId>,
+    pub prefix: Option<PrefixId>,
+    /// The condition of the variant expanded from [`FlagId::Cc`] variant,
+    /// see [`InstructionVariant::expand_conditions`].
+    #[serde(skip)]
+    pub condition: Option<ConditionCode>,
 }
 
 impl InstructionVariant {
-    #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn get_unique_name(&self) -> String {
         if let Some(name) = self.name.as_ref() {
             return name.clone();
         }
 
+        self.get_operands_name()
+    }
+
+    /// Returns the name built from the operands of the variant,
+    /// e.g. `RM64_Imm8`, regardless of its explicit name.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn get_operands_name(&self) -> String {
         if self.operands.is_empty() {
             return String::new();
         }
@@ -158,11 +529,54 @@ impl InstructionVariant {
         }
         name
     }
+
+    /// Returns the mnemonic of the variant, which defaults to the name
+    /// of the `group` it belongs to.
+    #[must_use]
+    pub fn get_mnemonic<'a>(&'a self, group: &'a InstructionGroup) -> &'a str {
+        self.mnemonic.as_deref().unwrap_or(&group.name)
+    }
+
+    /// Returns the variants the variant stands for. For variant with [`FlagId::Cc`]
+    /// flag these are the variants with the condition code added to the opcode, one
+    /// per [`CONDITION_CODES`] entry, e.g. `E_RM8` with `sete` mnemonic for `set`
+    /// mnemonic. Otherwise it is the variant itself.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn expand_conditions(&self) -> Vec<InstructionVariant> {
+        if !self.flags.contains(&FlagId::Cc) {
+            return vec![self.clone()];
+        }
+
+        let mnemonic = self.mnemonic.as_deref().unwrap_or_default();
+        let operands_name = self.get_operands_name();
+        CONDITION_CODES
+            .iter()
+            .map(|condition| {
+                let mut variant = self.clone();
+                variant.name = Some(format!("{}_{operands_name}", condition.name));
+                variant.mnemonic = Some(format!("{mnemonic}{}", condition.name.to_lowercase()));
+                *variant.primary_opcode.last_mut().unwrap() |= condition.code;
+                variant.description = format!("{} Condition: {}.", self.description, condition.description);
+                variant.flags.remove(&FlagId::Cc);
+                variant.flags.remove(&FlagId::CcAliases);
+                variant.condition = Some(*condition);
+                variant
+            })
+            .collect()
+    }
+
+    /// Returns the operands which are passed to the encoders, i.e. all
+    /// operands except for the implicit ones.
+    #[must_use]
+    pub fn explicit_operands(&self) -> Vec<OperandId> {
+        self.operands.iter().filter(|op| !op.is_implicit()).cloned().collect()
+    }
 }
 
 /// Represents a group of instruction variants. Typically
 /// a group covers a single mnemonic, e.g. `mov`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[must_use]
 pub struct InstructionGroup {
     pub id: u32,
@@ -170,6 +584,29 @@ pub struct InstructionGroup {
     pub variants: Vec<InstructionVariant>,
 }
 
+impl InstructionGroup {
+    /// Returns the group with all its variants expanded, see
+    /// [`InstructionVariant::expand_conditions`]. The variants are
+    /// renumbered in order, so that their ids stay unique.
+    pub fn expand_conditions(&self) -> Self {
+        let variants = self
+            .variants
+            .iter()
+            .flat_map(InstructionVariant::expand_conditions)
+            .zip(0..)
+            .map(|(mut variant, id)| {
+                variant.id = id;
+                variant
+            })
+            .collect();
+        Self {
+            id: self.id,
+            name: self.name.clone(),
+            variants,
+        }
+    }
+}
+
 /// Represents the `X86_64` instruction set documentation.
 #[derive(Debug, Deserialize)]
 #[must_use]
@@ -177,6 +614,7 @@ pub struct X86Doc {
     pub operand_encodings: Vec<OperandEncoding>,
     pub operands: Vec<Operand>,
     pub flags: Vec<FlagWithDescription>,
+    pub prefixes: Vec<PrefixWithDescription>,
     pub instruction_groups: Vec<InstructionGroup>,
 }
 
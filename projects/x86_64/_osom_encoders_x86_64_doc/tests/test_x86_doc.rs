@@ -1,11 +1,12 @@
-use _osom_encoders_x86_64_doc::{OperandEncodingId, OperandId, X86Doc};
+use _osom_encoders_x86_64_doc::{CONDITION_CODES, FlagId, OperandEncodingId, OperandId, PrefixId, X86Doc};
 
 #[test]
 fn test_x86_doc() {
     let x86_doc = X86Doc::create();
-    assert_eq!(x86_doc.operand_encodings.len(), 7);
-    assert_eq!(x86_doc.operands.len(), 13);
-    assert_eq!(x86_doc.instruction_groups.len(), 18);
+    assert_eq!(x86_doc.operand_encodings.len(), 13);
+    assert_eq!(x86_doc.operands.len(), 57);
+    assert_eq!(x86_doc.prefixes.len(), 3);
+    assert_eq!(x86_doc.instruction_groups.len(), 282);
 
     let lock_group = x86_doc
         .instruction_groups
@@ -30,4 +31,174 @@ fn test_x86_doc() {
     assert_eq!(mov_variant_2.extended_opcode, Some(0));
     assert_eq!(mov_variant_2.operand_encoding, OperandEncodingId::MI);
     assert_eq!(mov_variant_2.operands, vec![OperandId::RM16, OperandId::Imm16]);
+
+    let shl_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "shl")
+        .unwrap();
+    let shl_variant_cl = shl_group.variants.iter().find(|variant| variant.id == 10).unwrap();
+    assert_eq!(shl_variant_cl.get_unique_name(), "RM32_CL");
+    assert_eq!(shl_variant_cl.primary_opcode, &[0xD3]);
+    assert_eq!(shl_variant_cl.extended_opcode, Some(4));
+    assert_eq!(shl_variant_cl.operand_encoding, OperandEncodingId::M);
+    assert_eq!(shl_variant_cl.explicit_operands(), vec![OperandId::RM32]);
+
+    let cmpxchg16b_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "cmpxchg16b")
+        .unwrap();
+    let cmpxchg16b_variant = cmpxchg16b_group.variants.first().unwrap();
+    assert_eq!(cmpxchg16b_variant.primary_opcode, &[0x0F, 0xC7]);
+    assert_eq!(cmpxchg16b_variant.extended_opcode, Some(1));
+    assert_eq!(cmpxchg16b_variant.operand_encoding, OperandEncodingId::M);
+    assert_eq!(cmpxchg16b_variant.operands, vec![OperandId::Mem128]);
+    assert!(cmpxchg16b_variant.flags.contains(&FlagId::RexW));
+    assert!(cmpxchg16b_variant.flags.contains(&FlagId::Lock));
+    assert_eq!(cmpxchg16b_variant.prefix, None);
+
+    let scas_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "scas")
+        .unwrap();
+    let scas_variant = scas_group
+        .variants
+        .iter()
+        .find(|variant| variant.get_unique_name() == "Repne_Q")
+        .unwrap();
+    assert_eq!(scas_variant.primary_opcode, &[0xAF]);
+    assert_eq!(scas_variant.operand_encoding, OperandEncodingId::ZO);
+    assert_eq!(scas_variant.prefix, Some(PrefixId::RepNE));
+    assert_eq!(scas_variant.prefix.as_ref().map(PrefixId::byte), Some(0xF2));
+    assert!(scas_variant.flags.contains(&FlagId::RexW));
+    assert_eq!(scas_variant.mandatory_prefix, None);
+
+    let popcnt_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "popcnt")
+        .unwrap();
+    let popcnt_variant = popcnt_group.variants.first().unwrap();
+    assert_eq!(popcnt_variant.primary_opcode, &[0x0F, 0xB8]);
+    assert_eq!(popcnt_variant.mandatory_prefix, Some(0xF3));
+    assert_eq!(popcnt_variant.operands, vec![OperandId::Reg16, OperandId::RM16]);
+
+    let shlx_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "shlx")
+        .unwrap();
+    let shlx_variant = shlx_group.variants.last().unwrap();
+    assert_eq!(shlx_variant.primary_opcode, &[0x0F, 0x38, 0xF7]);
+    assert_eq!(shlx_variant.mandatory_prefix, Some(0x66));
+    assert_eq!(shlx_variant.operand_encoding, OperandEncodingId::RMV);
+    assert_eq!(
+        shlx_variant.operands,
+        vec![OperandId::Reg64, OperandId::RM64, OperandId::VReg64]
+    );
+    assert!(shlx_variant.flags.contains(&FlagId::Vex));
+    assert!(shlx_variant.flags.contains(&FlagId::RexW));
+
+    let vcmppd_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "vcmppd")
+        .unwrap();
+    let vcmppd_variant = vcmppd_group.variants.last().unwrap();
+    assert_eq!(vcmppd_variant.get_unique_name(), "K_MergeMask_VZmm_ZmmR_Sae_Imm8");
+    assert_eq!(vcmppd_variant.primary_opcode, &[0x0F, 0xC2]);
+    assert_eq!(vcmppd_variant.mandatory_prefix, Some(0x66));
+    assert_eq!(vcmppd_variant.operand_encoding, OperandEncodingId::RVMI);
+    assert_eq!(
+        vcmppd_variant.explicit_operands(),
+        vec![
+            OperandId::K,
+            OperandId::MergeMask,
+            OperandId::VZmm,
+            OperandId::ZmmR,
+            OperandId::Imm8
+        ]
+    );
+    assert!(vcmppd_variant.flags.contains(&FlagId::Evex));
+    assert!(vcmppd_variant.flags.contains(&FlagId::RexW));
+
+    let vfmadd231sd_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "vfmadd231sd")
+        .unwrap();
+    let vfmadd231sd_variant = vfmadd231sd_group.variants.first().unwrap();
+    assert_eq!(vfmadd231sd_variant.primary_opcode, &[0x0F, 0x38, 0xB9]);
+    assert_eq!(vfmadd231sd_variant.mandatory_prefix, Some(0x66));
+    assert_eq!(vfmadd231sd_variant.operand_encoding, OperandEncodingId::RVM);
+    assert_eq!(
+        vfmadd231sd_variant.operands,
+        vec![OperandId::Xmm, OperandId::VXmm, OperandId::XmmM64]
+    );
+    assert!(vfmadd231sd_variant.flags.contains(&FlagId::Vex));
+    assert!(vfmadd231sd_variant.flags.contains(&FlagId::RexW));
+
+    let vcvtps2ph_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "vcvtps2ph")
+        .unwrap();
+    let vcvtps2ph_variant = vcvtps2ph_group.variants.last().unwrap();
+    assert_eq!(vcvtps2ph_variant.get_unique_name(), "XmmM128_Ymm_Imm8");
+    assert_eq!(vcvtps2ph_variant.primary_opcode, &[0x0F, 0x3A, 0x1D]);
+    assert_eq!(vcvtps2ph_variant.operand_encoding, OperandEncodingId::MRI);
+    assert!(!vcvtps2ph_variant.flags.contains(&FlagId::RexW));
+
+    let vpgatherdq_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "vpgatherdq")
+        .unwrap();
+    let vpgatherdq_variant = vpgatherdq_group
+        .variants
+        .iter()
+        .find(|variant| variant.id == 1)
+        .unwrap();
+    assert_eq!(vpgatherdq_variant.get_unique_name(), "Ymm_Vm32X_VYmm");
+    assert_eq!(vpgatherdq_variant.primary_opcode, &[0x0F, 0x38, 0x90]);
+    assert_eq!(vpgatherdq_variant.operand_encoding, OperandEncodingId::RMV);
+    assert!(vpgatherdq_variant.flags.contains(&FlagId::Vex));
+    assert!(vpgatherdq_variant.flags.contains(&FlagId::RexW));
+
+    let vscatterqps_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "vscatterqps")
+        .unwrap();
+    let vscatterqps_variant = vscatterqps_group.variants.first().unwrap();
+    assert_eq!(vscatterqps_variant.primary_opcode, &[0x0F, 0x38, 0xA3]);
+    assert_eq!(vscatterqps_variant.operand_encoding, OperandEncodingId::MR);
+    assert_eq!(
+        vscatterqps_variant.operands,
+        vec![OperandId::Vm64Z, OperandId::MergeMask, OperandId::Ymm]
+    );
+    assert!(vscatterqps_variant.flags.contains(&FlagId::Evex));
+
+    let setcc_group = x86_doc
+        .instruction_groups
+        .iter()
+        .find(|group| group.name == "setcc")
+        .unwrap();
+    assert_eq!(setcc_group.variants.len(), 1);
+    let setcc_variant = setcc_group.variants.first().unwrap();
+    assert_eq!(setcc_variant.primary_opcode, &[0x0F, 0x90]);
+    assert!(setcc_variant.flags.contains(&FlagId::Cc));
+    let setcc_expanded = setcc_group.expand_conditions();
+    assert_eq!(setcc_expanded.variants.len(), CONDITION_CODES.len());
+    let sete_variant = setcc_expanded
+        .variants
+        .iter()
+        .find(|variant| variant.get_mnemonic(&setcc_expanded) == "sete")
+        .unwrap();
+    assert_eq!(sete_variant.get_unique_name(), "E_RM8");
+    assert_eq!(sete_variant.primary_opcode, &[0x0F, 0x94]);
+    assert_eq!(sete_variant.condition.unwrap().name, "E");
+    assert!(!sete_variant.flags.contains(&FlagId::Cc));
 }
@@ -1,18 +1,30 @@
 #![allow(clippy::write_with_newline, clippy::unused_self, clippy::unnecessary_wraps)]
-use std::{error::Error, fmt::Write, fs::OpenOptions, path::PathBuf};
+use std::{collections::BTreeMap, error::Error, fmt::Write, fs::OpenOptions, path::PathBuf};
 
 use std::io::Write as _;
 
-use _osom_encoders_x86_64_doc::{FlagId, InstructionGroup, InstructionVariant, OperandEncodingId, OperandId, X86Doc};
+use _osom_encoders_x86_64_doc::{
+    FlagId, InstructionGroup, InstructionVariant, OperandEncodingId, OperandId, PrefixId, X86Doc,
+};
 
 pub struct Generator {
     target_dir: PathBuf,
     doc: X86Doc,
+
+    /// The instruction groups of `doc` with the variants expanded
+    /// per condition, see [`InstructionGroup::expand_conditions`].
+    expanded_groups: Vec<InstructionGroup>,
 }
 
 const ENCODERS_DIR: &str = "encoders";
+const SAFE_ENCODERS_DIR: &str = "safe";
+const DECODERS_DIR: &str = "decoders";
 const GEN_INFO: &str = "// ** This file is automatically generated from x86.yaml schema. Do not modify! **\n\n";
 
+/// Instruction variant together with the group it belongs to, and whether
+/// it is the LOCK-prefixed form of the variant.
+type GroupVariant<'a> = (&'a InstructionGroup, &'a InstructionVariant, bool);
+
 impl Generator {
     pub fn new(target_dir: PathBuf) -> Self {
         let mut doc = X86Doc::create();
@@ -20,11 +32,21 @@ impl Generator {
         for group in &mut doc.instruction_groups {
             group.variants.sort_by_key(|variant| variant.id);
         }
-        Self { target_dir, doc }
+        let expanded_groups = doc
+            .instruction_groups
+            .iter()
+            .map(InstructionGroup::expand_conditions)
+            .collect();
+        Self {
+            target_dir,
+            doc,
+            expanded_groups,
+        }
     }
 
     pub fn generate(self) -> Result<(), Box<dyn Error>> {
         self.generate_encoders()?;
+        self.generate_decoders()?;
         Ok(())
     }
 
@@ -44,6 +66,7 @@ impl Generator {
         }
 
         self.update_nop_file()?;
+        self.generate_safe_encoders()?;
         Ok(())
     }
 
@@ -61,6 +84,7 @@ impl Generator {
         for group in &self.doc.instruction_groups {
             let _ = write!(content, "pub mod {};\n", group.name);
         }
+        let _ = write!(content, "\npub mod {SAFE_ENCODERS_DIR};\n");
 
         std::fs::write(encoders_lib_file, content)?;
 
@@ -83,42 +107,66 @@ impl Generator {
         let _ = write!(content, "use crate::models::{{\n");
         let _ = write!(content, "    EncodedX86_64Instruction, GPR, GPROrMemory, Memory,\n");
         let _ = write!(content, "    Immediate8, Immediate16, Immediate32, Immediate64,\n");
-        let _ = write!(content, "    Offset, Scale, Size,\n");
+        let _ = write!(
+            content,
+            "    Offset, Scale, Size, Condition, XMM, XMMOrMemory, YMM, YMMOrMemory,\n"
+        );
+        let _ = write!(
+            content,
+            "    ZMM, ZMMOrMemory, Opmask, OpmaskOrMemory, Masking, Rounding, VSIBMemory,\n"
+        );
         let _ = write!(content, "}};\n\n");
         for variant in &group.variants {
-            let _ = write!(content, "{}", self.generate_variant_file(variant)?);
+            let _ = write!(content, "{}", self.generate_variant_file(variant, false)?);
+            if variant.flags.contains(&FlagId::Lock) {
+                let _ = write!(content, "{}", self.generate_variant_file(variant, true)?);
+            }
+            if variant.flags.contains(&FlagId::CcAliases) {
+                for expanded in variant.expand_conditions() {
+                    let _ = write!(content, "{}", self.generate_variant_file(&expanded, false)?);
+                }
+            }
         }
         content.push('\n');
         std::fs::write(encoder_file, content)?;
         Ok(())
     }
 
-    fn generate_variant_file(&self, variant: &InstructionVariant) -> Result<String, Box<dyn Error>> {
+    /// Generates the encoder of `variant`. If `lock` is true, then the LOCK-prefixed
+    /// variant is generated instead.
+    fn generate_variant_file(&self, variant: &InstructionVariant, lock: bool) -> Result<String, Box<dyn Error>> {
         let mut content = String::new();
-        let unique_name = variant.get_unique_name().to_lowercase();
-        let fn_name = if unique_name.is_empty() {
-            "encode"
-        } else {
-            &format!("encode_{unique_name}")
-        };
+        let fn_name = generate_lock_aware_encoder_name(variant, lock);
 
         let partial_encoder_name = generate_partial_encoder_name(variant);
 
         let passed_args = generate_passed_args(variant);
 
         let mut args = Vec::new();
-        for operand in &variant.operands {
+        if variant.flags.contains(&FlagId::Cc) {
+            args.push("condition: Condition".to_string());
+        }
+        for operand in &variant.explicit_operands() {
             let op_name = <&'static str>::from(operand).to_lowercase();
             let op_type = map_operand_to_type(operand);
             args.push(format!("{op_name}: {op_type}"));
         }
         let args = args.join(", ");
 
-        let _ = write!(content, "/// {}\n", variant.description);
+        let _ = write!(content, "/// {}\n", generate_lock_aware_description(variant, lock));
         let _ = write!(content, "///\n");
         let _ = write!(content, "/// # Safety\n");
         let _ = write!(content, "///\n");
-        if variant.operands.is_empty() {
+        if lock {
+            let _ = write!(
+                content,
+                "/// It is the caller's responsibility to ensure that the operands are valid\n"
+            );
+            let _ = write!(
+                content,
+                "/// and that the destination is memory. Otherwise the behavior is undefined.\n"
+            );
+        } else if variant.explicit_operands().is_empty() {
             let _ = write!(
                 content,
                 "/// This function is safe to call. It is marked as unsafe for consistency with other encoders.\n"
@@ -135,228 +183,1580 @@ impl Generator {
             content,
             "pub const unsafe fn {fn_name}({args}) -> EncodedX86_64Instruction {{\n"
         );
-        let op_enc = <&'static str>::from(variant.operand_encoding.clone()).to_lowercase();
+        let op_enc = generate_partial_module_name(variant);
+        let mut call = format!("crate::partial_encoders::{op_enc}::{partial_encoder_name}({passed_args})");
+        if lock {
+            call = format!("crate::partial_encoders::core::with_lock_prefix(&{call})");
+        }
         let _ = write!(content, "    unsafe {{\n");
-        let _ = write!(
-            content,
-            "        crate::partial_encoders::{op_enc}::{partial_encoder_name}({passed_args})\n"
-        );
+        let _ = write!(content, "        {call}\n");
         let _ = write!(content, "    }}\n");
         let _ = write!(content, "}}\n\n");
         Ok(content)
     }
 
-    fn update_nop_file(&self) -> Result<(), Box<dyn Error>> {
-        let content = "
-/// Represents length for NOP instruction. Internally this is `u8`
-/// but with values in `0..=9` range.
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-#[repr(transparent)]
-#[must_use]
-pub struct NopLength {
-    len: u8,
-}
+    fn generate_safe_encoders(&self) -> Result<(), Box<dyn Error>> {
+        let safe_dir = self.target_dir.join(ENCODERS_DIR).join(SAFE_ENCODERS_DIR);
+        std::fs::create_dir_all(&safe_dir)?;
 
-/// Represents the length out of range error. This happens whenever
-/// the NOP instruction is outside of `0..=9` range.
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-#[repr(transparent)]
-#[must_use]
-pub struct NopLengthOutOfRange {
-    phantom: core::marker::PhantomData<()>,
-}
+        let safe_mod_file = safe_dir.join("mod.rs");
+        println!(">>> Generating {}", safe_mod_file.display());
 
-impl NopLength {
-    /// Creates a new [`NopLength`].
-    ///
-    /// # Errors
-    ///
-    /// Returns [`NopLengthOutOfRange`] if `len > 9`.
-    #[inline]
-    pub const fn new(len: u8) -> Result<Self, NopLengthOutOfRange> {
-        if len > 9 {
-            return Err(NopLengthOutOfRange {
-                phantom: core::marker::PhantomData,
-            });
+        let mut content = String::new();
+        let _ = write!(
+            content,
+            "//! This module contains the safe encoders for the `X86_64` instruction set.\n"
+        );
+        let _ = write!(content, "//!\n");
+        let _ = write!(
+            content,
+            "//! Unlike the encoders in the parent module, these validate their operands\n"
+        );
+        let _ = write!(
+            content,
+            "//! (in release builds as well) and return [`EncodeError`][crate::models::EncodeError]\n"
+        );
+        let _ = write!(content, "//! instead of producing invalid encodings.\n\n");
+        let _ = write!(content, "{GEN_INFO}");
+        for group in &self.doc.instruction_groups {
+            let _ = write!(content, "pub mod {};\n", group.name);
         }
+        std::fs::write(safe_mod_file, content)?;
 
-        Ok(unsafe { Self::new_unchecked(len) })
-    }
-
-    /// Creates a new [`NopLength`].
-    ///
-    /// # Safety
-    ///
-    /// It is up to the caller to ensure that `len <= 9`.
-    #[inline(always)]
-    pub const unsafe fn new_unchecked(len: u8) -> Self {
-        Self { len }
-    }
+        for group in &self.doc.instruction_groups {
+            self.generate_safe_encoder_file(group)?;
+        }
 
-    /// Returns the internal length.
-    #[must_use]
-    #[inline(always)]
-    pub const fn as_u8(self) -> u8 {
-        self.len
+        Ok(())
     }
-}
 
-impl TryFrom<u8> for NopLength {
-    type Error = NopLengthOutOfRange;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Self::new(value)
-    }
-}
+    fn generate_safe_encoder_file(&self, group: &InstructionGroup) -> Result<(), Box<dyn Error>> {
+        let encoder_file = self
+            .target_dir
+            .join(ENCODERS_DIR)
+            .join(SAFE_ENCODERS_DIR)
+            .join(format!("{}.rs", group.name));
+        println!(">>> Generating {}", encoder_file.display());
 
-impl From<NopLength> for u8 {
-    fn from(value: NopLength) -> Self {
-        value.len
-    }
-}
+        let mut content = String::new();
 
-/// No operation with a given length.
-///
-/// # Notes
-/// 
-/// * The [`encode_with_len(0)`][encode_with_len] call returns an empty instruction (of length 0).
-/// * The [`encode_with_len(1)`][encode_with_len] call is equivalent to [`encode()`] call. Except
-///   it is slightly less efficient.
-/// * The [`encode_with_len(n)`][encode_with_len] call returns an instruction of length `n`.
-/// 
-/// # Safety
-///
-/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
-#[allow(clippy::missing_panics_doc)]
-pub const unsafe fn encode_with_len(len: NopLength) -> EncodedX86_64Instruction {
-    use crate::partial_encoders::zo::encode;
-    unsafe {
-        match len.as_u8() {
-            0 => encode([]),
-            1 => encode([0x90]),
-            2 => encode([0x66, 0x90]),
-            3 => encode([0x0F, 0x1F, 0x00]),
-            4 => encode([0x0F, 0x1F, 0x40, 0x00]),
-            5 => encode([0x0F, 0x1F, 0x44, 0x00, 0x00]),
-            6 => encode([0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00]),
-            7 => encode([0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00]),
-            8 => encode([0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]),
-            9 => encode([0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]),
-            _ => panic!(\"Invalid length for encode_with_len call. Expected u8 in 1..=9 range.\"),
+        let _ = write!(
+            content,
+            "//! This module contains the safe encoders for the `{}` instruction group.\n",
+            group.name
+        );
+        let _ = write!(content, "#![allow(unused_imports)]\n\n");
+        let _ = write!(content, "{GEN_INFO}");
+        let _ = write!(content, "use crate::models::{{\n");
+        let _ = write!(
+            content,
+            "    EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Memory,\n"
+        );
+        let _ = write!(content, "    Immediate8, Immediate16, Immediate32, Immediate64,\n");
+        let _ = write!(
+            content,
+            "    Offset, Scale, Size, Condition, XMM, XMMOrMemory, YMM, YMMOrMemory,\n"
+        );
+        let _ = write!(
+            content,
+            "    ZMM, ZMMOrMemory, Opmask, OpmaskOrMemory, Masking, Rounding, VSIBMemory,\n"
+        );
+        let _ = write!(content, "}};\n");
+        let _ = write!(content, "use crate::validation;\n\n");
+        for variant in &group.variants {
+            let _ = write!(content, "{}", self.generate_safe_variant(group, variant, false)?);
+            if variant.flags.contains(&FlagId::Lock) {
+                let _ = write!(content, "{}", self.generate_safe_variant(group, variant, true)?);
+            }
+            if variant.flags.contains(&FlagId::CcAliases) {
+                for expanded in variant.expand_conditions() {
+                    let _ = write!(content, "{}", self.generate_safe_variant(group, &expanded, false)?);
+                }
+            }
         }
-    }
-}
-"
-        .trim();
-
-        let encoder_file = self.target_dir.join(ENCODERS_DIR).join("nop.rs");
-
-        let mut file = OpenOptions::new()
-            .append(true)
-            .create(false)
-            .open(encoder_file)
-            .unwrap();
-
-        file.write_all(content.as_bytes())?;
+        content.push('\n');
+        std::fs::write(encoder_file, content)?;
         Ok(())
     }
-}
 
-fn map_operand_to_type(operand: &OperandId) -> &'static str {
-    match operand {
-        OperandId::Imm8 => "Immediate8",
-        OperandId::Imm16 => "Immediate16",
-        OperandId::Imm32 => "Immediate32",
-        OperandId::Imm64 => "Immediate64",
-        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64 => "GPROrMemory",
-        OperandId::Reg8 | OperandId::Reg16 | OperandId::Reg32 | OperandId::Reg64 => "GPR",
-        OperandId::Mem64 => "Memory",
-    }
-}
+    fn generate_safe_variant(
+        &self,
+        group: &InstructionGroup,
+        variant: &InstructionVariant,
+        lock: bool,
+    ) -> Result<String, Box<dyn Error>> {
+        let mut content = String::new();
+        let fn_name = generate_lock_aware_encoder_name(variant, lock);
 
-#[allow(clippy::single_match_else)]
-fn generate_passed_args(variant: &InstructionVariant) -> String {
-    let opcode = match variant.operand_encoding {
-        OperandEncodingId::OI => {
-            assert!(
-                variant.primary_opcode.len() == 1,
-                "Opcode for OI encoding must be 1 byte long."
-            );
-            format!("0x{:02X}", variant.primary_opcode[0])
+        let mut args = Vec::new();
+        let mut arg_names = Vec::new();
+        if variant.flags.contains(&FlagId::Cc) {
+            args.push("condition: Condition".to_string());
+            arg_names.push("condition".to_string());
         }
-        _ => {
-            let mut opcode = Vec::with_capacity(variant.primary_opcode.len());
-            for x in &variant.primary_opcode {
-                opcode.push(format!("0x{x:02X}"));
-            }
-            format!("[{}]", opcode.join(", "))
+        for operand in &variant.explicit_operands() {
+            let op_name = <&'static str>::from(operand).to_lowercase();
+            let op_type = map_operand_to_type(operand);
+            args.push(format!("{op_name}: {op_type}"));
+            arg_names.push(op_name);
         }
-    };
-
-    let mut passed_args = Vec::with_capacity(variant.operands.len() + 1);
-    passed_args.push(opcode);
-
-    if let Some(extended_opcode) = variant.extended_opcode {
-        passed_args.push(format!("0x{extended_opcode:02X}"));
-    }
-
-    let has_oso = bool_to_string(variant.flags.contains(&FlagId::OSO));
-    let has_rex_w = bool_to_string(variant.flags.contains(&FlagId::RexW));
+        let args = args.join(", ");
+        let arg_names = arg_names.join(", ");
 
-    match variant.operand_encoding {
-        OperandEncodingId::MR => {
-            assert!(variant.operands.len() == 2, "MR encoding must have exactly 2 operands.");
-            let mut operand0 = variant.operands[0].clone();
-            let mut operand1 = variant.operands[1].clone();
+        let _ = write!(content, "/// {}\n", generate_lock_aware_description(variant, lock));
+        let _ = write!(content, "///\n");
+        let _ = write!(
+            content,
+            "/// Safe variant of [`{0}::{fn_name}`][crate::encoders::{0}::{fn_name}].\n",
+            group.name
+        );
+        let _ = write!(content, "///\n");
+        let _ = write!(content, "/// # Errors\n");
+        let _ = write!(content, "///\n");
 
-            assert!(
-                is_gpr(&operand0) || is_gpr(&operand1),
-                "One of the operands in MR encoding has to be GPR."
+        let mut validations = generate_safe_validations(variant);
+        if lock && is_gpr_or_memory(&variant.operands[0]) {
+            let op_name = <&'static str>::from(&variant.operands[0]).to_lowercase();
+            validations.insert(0, format!("validation::validate_lock_destination({op_name})"));
+        }
+        if validations.is_empty() {
+            let _ = write!(
+                content,
+                "/// This function never fails. It returns [`Result`] for consistency with other safe encoders.\n"
             );
-            if is_gpr(&operand0) {
-                std::mem::swap(&mut operand0, &mut operand1);
-            }
-            assert!(
-                is_gpr_or_memory(&operand0) || operand0 == OperandId::Mem64,
-                "The other MR encoding operand has to be Reg/Mem."
+        } else {
+            let _ = write!(
+                content,
+                "/// Returns [`EncodeError`] if the operands are not valid for this instruction.\n"
             );
-
-            let op0_name = <&'static str>::from(operand0.clone()).to_lowercase();
-            let op1_name = <&'static str>::from(operand1.clone()).to_lowercase();
-
-            passed_args.push(op0_name);
-            passed_args.push(op1_name);
         }
-        OperandEncodingId::M => {
-            assert!(variant.operands.len() == 1, "M encoding must have exactly 1 operand.");
-            assert!(
-                variant.extended_opcode.is_some(),
-                "M encoding must have an extended opcode."
-            );
-            let operand0 = variant.operands[0].clone();
-            let op_name = <&'static str>::from(operand0).to_lowercase();
-            passed_args.push(op_name);
-            passed_args.push(has_rex_w.to_string());
-            passed_args.push(has_oso.to_string());
+        let _ = write!(content, "#[inline]\n");
+        let _ = write!(
+            content,
+            "pub const fn {fn_name}({args}) -> Result<EncodedX86_64Instruction, EncodeError> {{\n"
+        );
+        for validation in validations {
+            let _ = write!(content, "    if let Err(err) = {validation} {{\n");
+            let _ = write!(content, "        return Err(err);\n");
+            let _ = write!(content, "    }}\n");
         }
-        _ => {
-            for operand in &variant.operands {
-                let op_name = <&'static str>::from(operand).to_lowercase();
-                passed_args.push(op_name);
-            }
+        let _ = write!(
+            content,
+            "    Ok(unsafe {{ crate::encoders::{}::{fn_name}({arg_names}) }})\n",
+            group.name
+        );
+        let _ = write!(content, "}}\n\n");
+        Ok(content)
+    }
+
+    fn generate_decoders(&self) -> Result<(), Box<dyn Error>> {
+        let decoders_dir = self.target_dir.join(DECODERS_DIR);
+        println!("Generating decoders to {}", decoders_dir.display());
+
+        if decoders_dir.exists() {
+            std::fs::remove_dir_all(&decoders_dir)?;
         }
+        std::fs::create_dir_all(&decoders_dir)?;
+
+        self.generate_decoders_mod()?;
+        self.generate_instruction_file()?;
+        self.generate_instruction_form_file()?;
+        Ok(())
     }
 
-    passed_args.join(", ")
-}
+    fn generate_decoders_mod(&self) -> Result<(), Box<dyn Error>> {
+        let decoders_mod_file = self.target_dir.join(DECODERS_DIR).join("mod.rs");
+        println!(">>> Generating {}", decoders_mod_file.display());
+
+        let mut content = String::new();
+        let _ = write!(
+            content,
+            "//! This module contains the decoders for the `X86_64` instruction set,\n"
+        );
+        let _ = write!(
+            content,
+            "//! i.e. the inverse of the [`encoders`][crate::encoders] module.\n\n"
+        );
+        let _ = write!(content, "{GEN_INFO}");
+        let _ = write!(content, "mod instruction;\n");
+        let _ = write!(content, "pub use instruction::*;\n\n");
+        let _ = write!(content, "mod instruction_form;\n");
+        let _ = write!(content, "pub use instruction_form::*;\n\n");
+        let _ = write!(content, "use crate::models::{{DecodeError, Size}};\n");
+        let _ = write!(
+            content,
+            "use crate::partial_decoders::{{Decoder, const_try, evex, i, m, mi, mr, o, oi, rmi, vex, xmm, zo}};\n\n"
+        );
+
+        let _ = write!(
+            content,
+            "/// Decodes a single instruction from the beginning of `bytes`. Returns the decoded\n"
+        );
+        let _ = write!(
+            content,
+            "/// [`Instruction`] together with the number of bytes it occupies.\n"
+        );
+        let _ = write!(content, "///\n");
+        let _ = write!(
+            content,
+            "/// Whenever multiple instructions share the same encoding (e.g. `jcc::encode_p_imm8`\n"
+        );
+        let _ = write!(
+            content,
+            "/// and `jcc::encode_pe_imm8`), the one described first in `x86.yaml` is returned.\n"
+        );
+        let _ = write!(content, "///\n");
+        let _ = write!(content, "/// # Errors\n");
+        let _ = write!(content, "///\n");
+        let _ = write!(
+            content,
+            "/// Returns [`DecodeError`] if `bytes` do not start with a supported instruction.\n"
+        );
+        let _ = write!(content, "#[allow(clippy::too_many_lines)]\n");
+        let _ = write!(
+            content,
+            "pub const fn decode(bytes: &[u8]) -> Result<(Instruction, usize), DecodeError> {{\n"
+        );
+        let _ = write!(content, "    let mut decoder = Decoder::new(bytes);\n");
+        let _ = write!(content, "    match const_try!(decoder.opcode_byte()) {{\n");
+
+        for (pattern, variants) in self.group_variants_by_opcode_byte() {
+            let _ = write!(content, "        {pattern} => {{\n");
+            for (group, variant, lock) in variants {
+                let _ = write!(content, "{}", generate_variant_decoder(group, variant, lock));
+            }
+            let _ = write!(content, "        }}\n");
+        }
+
+        let _ = write!(content, "        _ => {{}}\n");
+        let _ = write!(content, "    }}\n");
+        let _ = write!(content, "    Err(DecodeError::UnknownInstruction)\n");
+        let _ = write!(content, "}}\n");
+
+        std::fs::write(decoders_mod_file, content)?;
+        Ok(())
+    }
+
+    /// Returns all the instruction variants in the order of `x86.yaml`. Variants
+    /// with [`FlagId::Lock`] are directly followed by their LOCK-prefixed form.
+    fn group_variants(&self) -> Vec<GroupVariant<'_>> {
+        let mut result = Vec::new();
+        for group in &self.expanded_groups {
+            for variant in &group.variants {
+                result.push((group, variant, false));
+                if variant.flags.contains(&FlagId::Lock) {
+                    result.push((group, variant, true));
+                }
+            }
+        }
+        result
+    }
+
+    /// Groups all variants by the first byte of their opcode (following the prefixes).
+    /// Returns a list of match patterns together with variants to try, in the
+    /// order of `x86.yaml`. Bytes with the same variants share a single pattern.
+    fn group_variants_by_opcode_byte(&self) -> Vec<(String, Vec<GroupVariant<'_>>)> {
+        let mut by_byte = BTreeMap::<u8, Vec<GroupVariant>>::new();
+        for (group, variant, lock) in self.group_variants() {
+            // The LOCK prefix is decoded together with the instruction it prefixes.
+            if group.name == "lock" {
+                continue;
+            }
+
+            // VEX and EVEX prefixes replace the leading 0F, 0F 38 and 0F 3A bytes.
+            let first_byte = if is_vex(variant) || is_evex(variant) {
+                *variant.primary_opcode.last().unwrap()
+            } else {
+                variant.primary_opcode[0]
+            };
+            let first_bytes = match variant.operand_encoding {
+                OperandEncodingId::O | OperandEncodingId::OI if variant.primary_opcode.len() == 1 => {
+                    first_byte..=first_byte + 0b111
+                }
+                _ => first_byte..=first_byte,
+            };
+            for byte in first_bytes {
+                by_byte.entry(byte).or_default().push((group, variant, lock));
+            }
+        }
+
+        // Merge consecutive bytes with the same variants into ranges.
+        let mut result: Vec<(u8, u8, Vec<GroupVariant>)> = Vec::new();
+        for (byte, variants) in by_byte {
+            if let Some((_, last, last_variants)) = result.last_mut()
+                && *last + 1 == byte
+                && same_variants(last_variants, &variants)
+            {
+                *last = byte;
+                continue;
+            }
+            result.push((byte, byte, variants));
+        }
+
+        // Merge non-consecutive ranges with the same variants into a single arm.
+        let mut arms: Vec<(String, Vec<GroupVariant>)> = Vec::new();
+        for (first, last, variants) in result {
+            let pattern = if first == last {
+                format!("0x{first:02X}")
+            } else {
+                format!("0x{first:02X}..=0x{last:02X}")
+            };
+            match arms
+                .iter_mut()
+                .find(|(_, arm_variants)| same_variants(arm_variants, &variants))
+            {
+                Some((arm_pattern, _)) => {
+                    arm_pattern.push_str(" | ");
+                    arm_pattern.push_str(&pattern);
+                }
+                None => arms.push((pattern, variants)),
+            }
+        }
+        arms
+    }
+
+    fn generate_instruction_file(&self) -> Result<(), Box<dyn Error>> {
+        let instruction_file = self.target_dir.join(DECODERS_DIR).join("instruction.rs");
+        println!(">>> Generating {}", instruction_file.display());
+
+        let mut content = String::new();
+        let _ = write!(content, "{GEN_INFO}");
+        let _ = write!(content, "use crate::models::{{\n");
+        let _ = write!(
+            content,
+            "    EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Memory,\n"
+        );
+        let _ = write!(content, "    Immediate8, Immediate16, Immediate32, Immediate64,\n");
+        let _ = write!(
+            content,
+            "    Operand, Operands, Size, Condition, XMM, XMMOrMemory, YMM, YMMOrMemory,\n"
+        );
+        let _ = write!(
+            content,
+            "    ZMM, ZMMOrMemory, Opmask, OpmaskOrMemory, Masking, Rounding, VSIBMemory,\n"
+        );
+        let _ = write!(content, "}};\n\n");
+
+        let _ = write!(
+            content,
+            "/// Represents a single `X86_64` instruction, as returned by [`decode`][super::decode].\n"
+        );
+        let _ = write!(
+            content,
+            "/// Each variant corresponds to exactly one encoder and holds its operands.\n"
+        );
+        let _ = write!(content, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        let _ = write!(content, "#[must_use]\n");
+        let _ = write!(content, "pub enum Instruction {{\n");
+        for (group, variant, lock) in self.group_variants() {
+            let name = generate_instruction_name(group, variant, lock);
+            let _ = write!(content, "    /// {}\n", generate_lock_aware_description(variant, lock));
+            if variant.explicit_operands().is_empty() {
+                let _ = write!(content, "    {name},\n\n");
+            } else {
+                let fields = variant
+                    .explicit_operands()
+                    .iter()
+                    .map(|operand| {
+                        let op_name = <&'static str>::from(operand).to_lowercase();
+                        format!("{op_name}: {}", map_operand_to_type(operand))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = write!(content, "    {name} {{ {fields} }},\n\n");
+            }
+        }
+        let _ = write!(content, "}}\n\n");
+
+        let _ = write!(content, "impl Instruction {{\n");
+        let _ = write!(content, "{}", self.generate_encode_fn());
+        let _ = write!(content, "{}", self.generate_mnemonic_fn());
+        let _ = write!(content, "{}", self.generate_operands_fn());
+        let _ = write!(content, "}}\n");
+
+        std::fs::write(instruction_file, content)?;
+        Ok(())
+    }
+
+    fn generate_operand_kind_enum(&self) -> String {
+        let mut content = String::new();
+        let _ = write!(content, "pub enum OperandKind {{\n");
+        for operand in &self.doc.operands {
+            let _ = write!(content, "    /// {}\n", operand.description);
+            let _ = write!(content, "    {},\n\n", <&'static str>::from(&operand.id));
+        }
+        let _ = write!(content, "}}\n\n");
+
+        let implicit = self
+            .doc
+            .operands
+            .iter()
+            .filter(|operand| operand.id.is_implicit())
+            .map(|operand| format!("Self::{}", <&'static str>::from(&operand.id)))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let _ = write!(content, "impl OperandKind {{\n");
+        let _ = write!(
+            content,
+            "    /// Returns true if the operand is implicit, i.e. it is not passed to the encoders.\n"
+        );
+        let _ = write!(content, "    #[inline]\n");
+        let _ = write!(content, "    #[must_use]\n");
+        let _ = write!(content, "    pub const fn is_implicit(self) -> bool {{\n");
+        let _ = write!(content, "        matches!(self, {implicit})\n");
+        let _ = write!(content, "    }}\n");
+        let _ = write!(content, "}}\n\n");
+        content
+    }
+
+    fn generate_form_fn(&self) -> String {
+        let mut content = String::new();
+        let _ = write!(content, "\nimpl Instruction {{\n");
+        let _ = write!(
+            content,
+            "    /// Returns the [`InstructionForm`] the instruction was built from.\n"
+        );
+        let _ = write!(content, "    #[allow(clippy::too_many_lines)]\n");
+        let _ = write!(content, "    pub const fn form(self) -> &'static InstructionForm {{\n");
+        let _ = write!(content, "        let idx = match self {{\n");
+        for (idx, (group, variant, lock)) in self.group_variants().into_iter().enumerate() {
+            let pattern = generate_instruction_pattern(group, variant, lock, false);
+            let _ = write!(content, "            {pattern} => {idx},\n");
+        }
+        let _ = write!(content, "        }};\n");
+        let _ = write!(content, "        &INSTRUCTION_FORMS[idx]\n");
+        let _ = write!(content, "    }}\n");
+        let _ = write!(content, "}}\n");
+        content
+    }
+
+    fn generate_instruction_form_file(&self) -> Result<(), Box<dyn Error>> {
+        let instruction_form_file = self.target_dir.join(DECODERS_DIR).join("instruction_form.rs");
+        println!(">>> Generating {}", instruction_form_file.display());
+
+        let mut content = String::new();
+        let _ = write!(content, "{GEN_INFO}");
+        let _ = write!(content, "use super::Instruction;\n");
+        let _ = write!(content, "use crate::models::{{Operand, Size}};\n\n");
+
+        let _ = write!(content, "/// Represents the kind of an instruction operand.\n");
+        let _ = write!(content, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        let _ = write!(content, "#[must_use]\n");
+        let _ = write!(content, "{}", self.generate_operand_kind_enum());
+
+        let _ = write!(
+            content,
+            "/// Describes a single instruction variant: its mnemonic, the kinds of its operands\n"
+        );
+        let _ = write!(
+            content,
+            "/// (in Intel order, including the implicit ones) and the way to build [`Instruction`].\n"
+        );
+        let _ = write!(content, "#[derive(Debug, Clone, Copy)]\n");
+        let _ = write!(content, "#[must_use]\n");
+        let _ = write!(content, "pub struct InstructionForm {{\n");
+        let _ = write!(
+            content,
+            "    /// The name of the corresponding [`Instruction`] variant.\n"
+        );
+        let _ = write!(content, "    pub name: &'static str,\n");
+        let _ = write!(content, "    pub mnemonic: &'static str,\n");
+        let _ = write!(
+            content,
+            "    /// The legacy prefix written before the mnemonic, e.g. `rep` in `rep movsb`.\n"
+        );
+        let _ = write!(content, "    pub prefix: Option<&'static str>,\n");
+        let _ = write!(content, "    pub operands: &'static [OperandKind],\n");
+        let _ = write!(
+            content,
+            "    /// The size of the operation, taken from the first register or memory operand.\n"
+        );
+        let _ = write!(
+            content,
+            "    /// If there are no such operands, then it is implied by the prefixes (if any).\n"
+        );
+        let _ = write!(content, "    pub operation_size: Option<Size>,\n");
+        let _ = write!(
+            content,
+            "    /// Whether the immediate operand is unsigned, i.e. neither sign-extended nor relative.\n"
+        );
+        let _ = write!(content, "    pub unsigned_immediate: bool,\n");
+        let _ = write!(content, "    pub(crate) build: fn(&[Operand]) -> Instruction,\n");
+        let _ = write!(content, "}}\n\n");
+
+        let forms_count = self.group_variants().len();
+        let _ = write!(content, "/// All the instruction forms, in the order of `x86.yaml`.\n");
+        let _ = write!(
+            content,
+            "pub static INSTRUCTION_FORMS: [InstructionForm; {forms_count}] = [\n"
+        );
+        for (group, variant, lock) in self.group_variants() {
+            let _ = write!(content, "{}", generate_instruction_form_entry(group, variant, lock));
+        }
+        let _ = write!(content, "];\n\n");
+
+        let _ = write!(content, "impl InstructionForm {{\n");
+        let _ = write!(
+            content,
+            "    /// Builds the [`Instruction`] out of `operands`, which have to match\n"
+        );
+        let _ = write!(content, "    /// [`InstructionForm::operands`] kinds.\n");
+        let _ = write!(content, "    ///\n");
+        let _ = write!(content, "    /// # Panics\n");
+        let _ = write!(content, "    ///\n");
+        let _ = write!(
+            content,
+            "    /// If the number or the kinds of `operands` do not match the form.\n"
+        );
+        let _ = write!(content, "    #[inline]\n");
+        let _ = write!(
+            content,
+            "    pub fn build(&self, operands: &[Operand]) -> Instruction {{\n"
+        );
+        let _ = write!(
+            content,
+            "        assert!(operands.len() == self.operands.len(), \"Invalid number of operands.\");\n"
+        );
+        let _ = write!(content, "        (self.build)(operands)\n");
+        let _ = write!(content, "    }}\n");
+        let _ = write!(content, "}}\n");
+        let _ = write!(content, "{}", self.generate_form_fn());
+
+        std::fs::write(instruction_form_file, content)?;
+        Ok(())
+    }
+
+    fn generate_encode_fn(&self) -> String {
+        let mut content = String::new();
+        let _ = write!(
+            content,
+            "    /// Encodes the instruction with the corresponding safe encoder.\n"
+        );
+        let _ = write!(content, "    ///\n");
+        let _ = write!(content, "    /// # Errors\n");
+        let _ = write!(content, "    ///\n");
+        let _ = write!(
+            content,
+            "    /// Returns [`EncodeError`] if the operands are not valid for the instruction.\n"
+        );
+        let _ = write!(content, "    #[allow(clippy::too_many_lines)]\n");
+        let _ = write!(
+            content,
+            "    pub const fn encode(self) -> Result<EncodedX86_64Instruction, EncodeError> {{\n"
+        );
+        let _ = write!(content, "        match self {{\n");
+        for (group, variant, lock) in self.group_variants() {
+            let name = generate_instruction_name(group, variant, lock);
+            let fn_name = generate_lock_aware_encoder_name(variant, lock);
+            let arg_names = variant
+                .explicit_operands()
+                .iter()
+                .map(|operand| <&'static str>::from(operand).to_lowercase())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let pattern = if variant.explicit_operands().is_empty() {
+                format!("Self::{name}")
+            } else {
+                format!("Self::{name} {{ {arg_names} }}")
+            };
+            let (fn_name, passed_args) = match variant.condition {
+                // Expanded variants are encoded with the encoder taking the condition.
+                Some(condition) => (
+                    format!("encode_{}", variant.get_operands_name().to_lowercase()),
+                    format!("Condition::{}, {arg_names}", condition.name),
+                ),
+                None => (fn_name, arg_names),
+            };
+            let _ = write!(
+                content,
+                "            {pattern} => crate::encoders::safe::{}::{fn_name}({passed_args}),\n",
+                group.name
+            );
+        }
+        let _ = write!(content, "        }}\n");
+        let _ = write!(content, "    }}\n\n");
+        content
+    }
+
+    fn generate_mnemonic_fn(&self) -> String {
+        // Group the variants by mnemonic, keeping the order of `x86.yaml`.
+        let mut mnemonics: Vec<(&str, Vec<String>)> = Vec::new();
+        for (group, variant, lock) in self.group_variants() {
+            let mnemonic = variant.get_mnemonic(group);
+            let pattern = generate_instruction_pattern(group, variant, lock, false);
+            match mnemonics.iter_mut().find(|(m, _)| *m == mnemonic) {
+                Some((_, patterns)) => patterns.push(pattern),
+                None => mnemonics.push((mnemonic, vec![pattern])),
+            }
+        }
+
+        let mut content = String::new();
+        let _ = write!(
+            content,
+            "    /// Returns the mnemonic of the instruction, as used by assemblers, e.g. `\"mov\"`.\n"
+        );
+        let _ = write!(content, "    #[allow(clippy::too_many_lines)]\n");
+        let _ = write!(content, "    #[must_use]\n");
+        let _ = write!(content, "    pub const fn mnemonic(self) -> &'static str {{\n");
+        let _ = write!(content, "        match self {{\n");
+        for (mnemonic, patterns) in mnemonics {
+            let _ = write!(content, "            {} => \"{mnemonic}\",\n", patterns.join(" | "));
+        }
+        let _ = write!(content, "        }}\n");
+        let _ = write!(content, "    }}\n\n");
+        content
+    }
+
+    fn generate_operands_fn(&self) -> String {
+        let mut content = String::new();
+        let _ = write!(
+            content,
+            "    /// Returns the operands of the instruction (including the implicit ones)\n"
+        );
+        let _ = write!(content, "    /// in Intel order, i.e. destination first.\n");
+        let _ = write!(
+            content,
+            "    #[allow(clippy::too_many_lines, clippy::match_same_arms)]\n"
+        );
+        let _ = write!(content, "    pub const fn operands(self) -> Operands {{\n");
+        let _ = write!(content, "        match self {{\n");
+        for (group, variant, lock) in self.group_variants() {
+            let pattern = generate_instruction_pattern(group, variant, lock, true);
+            let operands = variant
+                .operands
+                .iter()
+                .map(|operand| generate_operand_expression(variant, operand))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let _ = write!(
+                content,
+                "            {pattern} => Operands::from_array([{operands}]),\n"
+            );
+        }
+        let _ = write!(content, "        }}\n");
+        let _ = write!(content, "    }}\n");
+        content
+    }
+
+    fn update_nop_file(&self) -> Result<(), Box<dyn Error>> {
+        let content = "
+/// Represents length for NOP instruction. Internally this is `u8`
+/// but with values in `0..=9` range.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(transparent)]
+#[must_use]
+pub struct NopLength {
+    len: u8,
+}
+
+/// Represents the length out of range error. This happens whenever
+/// the NOP instruction is outside of `0..=9` range.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(transparent)]
+#[must_use]
+pub struct NopLengthOutOfRange {
+    phantom: core::marker::PhantomData<()>,
+}
+
+impl NopLength {
+    /// Creates a new [`NopLength`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NopLengthOutOfRange`] if `len > 9`.
+    #[inline]
+    pub const fn new(len: u8) -> Result<Self, NopLengthOutOfRange> {
+        if len > 9 {
+            return Err(NopLengthOutOfRange {
+                phantom: core::marker::PhantomData,
+            });
+        }
+
+        Ok(unsafe { Self::new_unchecked(len) })
+    }
+
+    /// Creates a new [`NopLength`].
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that `len <= 9`.
+    #[inline(always)]
+    pub const unsafe fn new_unchecked(len: u8) -> Self {
+        Self { len }
+    }
+
+    /// Returns the internal length.
+    #[must_use]
+    #[inline(always)]
+    pub const fn as_u8(self) -> u8 {
+        self.len
+    }
+}
+
+impl TryFrom<u8> for NopLength {
+    type Error = NopLengthOutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<NopLength> for u8 {
+    fn from(value: NopLength) -> Self {
+        value.len
+    }
+}
+
+/// No operation with a given length.
+///
+/// # Notes
+/// 
+/// * The [`encode_with_len(0)`][encode_with_len] call returns an empty instruction (of length 0).
+/// * The [`encode_with_len(1)`][encode_with_len] call is equivalent to [`encode()`] call. Except
+///   it is slightly less efficient.
+/// * The [`encode_with_len(n)`][encode_with_len] call returns an instruction of length `n`.
+/// 
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[allow(clippy::missing_panics_doc)]
+pub const unsafe fn encode_with_len(len: NopLength) -> EncodedX86_64Instruction {
+    use crate::partial_encoders::zo::encode;
+    unsafe {
+        match len.as_u8() {
+            0 => encode([]),
+            1 => encode([0x90]),
+            2 => encode([0x66, 0x90]),
+            3 => encode([0x0F, 0x1F, 0x00]),
+            4 => encode([0x0F, 0x1F, 0x40, 0x00]),
+            5 => encode([0x0F, 0x1F, 0x44, 0x00, 0x00]),
+            6 => encode([0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00]),
+            7 => encode([0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00]),
+            8 => encode([0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            9 => encode([0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            _ => panic!(\"Invalid length for encode_with_len call. Expected u8 in 1..=9 range.\"),
+        }
+    }
+}
+"
+        .trim();
+
+        let encoder_file = self.target_dir.join(ENCODERS_DIR).join("nop.rs");
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .create(false)
+            .open(encoder_file)
+            .unwrap();
+
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+}
+
+fn map_operand_to_type(operand: &OperandId) -> &'static str {
+    match operand {
+        OperandId::Imm8 => "Immediate8",
+        OperandId::Imm16 => "Immediate16",
+        OperandId::Imm32 => "Immediate32",
+        OperandId::Imm64 => "Immediate64",
+        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64 | OperandId::R32M8 | OperandId::R32M16 => {
+            "GPROrMemory"
+        }
+        OperandId::Reg8
+        | OperandId::Reg16
+        | OperandId::Reg32
+        | OperandId::Reg64
+        | OperandId::VReg32
+        | OperandId::VReg64 => "GPR",
+        OperandId::Mem16 | OperandId::Mem64 | OperandId::Mem128 => "Memory",
+        OperandId::Xmm | OperandId::VXmm | OperandId::XmmR => "XMM",
+        OperandId::XmmM8 | OperandId::XmmM16 | OperandId::XmmM32 | OperandId::XmmM64 | OperandId::XmmM128 => {
+            "XMMOrMemory"
+        }
+        OperandId::Ymm | OperandId::VYmm => "YMM",
+        OperandId::YmmM256 => "YMMOrMemory",
+        OperandId::Zmm | OperandId::VZmm | OperandId::ZmmR => "ZMM",
+        OperandId::ZmmM512 | OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => "ZMMOrMemory",
+        OperandId::Vm32X
+        | OperandId::Vm32Y
+        | OperandId::Vm32Z
+        | OperandId::Vm64X
+        | OperandId::Vm64Y
+        | OperandId::Vm64Z => "VSIBMemory",
+        OperandId::K | OperandId::VK | OperandId::KR => "Opmask",
+        OperandId::KM16 => "OpmaskOrMemory",
+        OperandId::Mask | OperandId::MergeMask => "Masking",
+        OperandId::Er => "Rounding",
+        OperandId::AL
+        | OperandId::AX
+        | OperandId::EAX
+        | OperandId::RAX
+        | OperandId::CL
+        | OperandId::XMM0
+        | OperandId::One
+        | OperandId::Sae => {
+            panic!("Implicit operands are not passed to encoders.")
+        }
+    }
+}
+
+/// Returns the name of [`Operand`] method converting it to the type of `operand`.
+fn map_operand_to_conversion(operand: &OperandId) -> &'static str {
+    match operand {
+        OperandId::Imm8 => "to_imm8",
+        OperandId::Imm16 => "to_imm16",
+        OperandId::Imm32 => "to_imm32",
+        OperandId::Imm64 => "to_imm64",
+        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64 | OperandId::R32M8 | OperandId::R32M16 => {
+            "to_gpr_or_memory"
+        }
+        OperandId::Reg8
+        | OperandId::Reg16
+        | OperandId::Reg32
+        | OperandId::Reg64
+        | OperandId::VReg32
+        | OperandId::VReg64 => "to_gpr",
+        OperandId::Mem16 | OperandId::Mem64 | OperandId::Mem128 => "to_memory",
+        OperandId::Xmm | OperandId::VXmm | OperandId::XmmR => "to_xmm",
+        OperandId::XmmM8 | OperandId::XmmM16 | OperandId::XmmM32 | OperandId::XmmM64 | OperandId::XmmM128 => {
+            "to_xmm_or_memory"
+        }
+        OperandId::Ymm | OperandId::VYmm => "to_ymm",
+        OperandId::YmmM256 => "to_ymm_or_memory",
+        OperandId::Zmm | OperandId::VZmm | OperandId::ZmmR => "to_zmm",
+        OperandId::ZmmM512 | OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => "to_zmm_or_memory",
+        OperandId::Vm32X
+        | OperandId::Vm32Y
+        | OperandId::Vm32Z
+        | OperandId::Vm64X
+        | OperandId::Vm64Y
+        | OperandId::Vm64Z => "to_vsib_memory",
+        OperandId::K | OperandId::VK | OperandId::KR => "to_opmask",
+        OperandId::KM16 => "to_opmask_or_memory",
+        OperandId::Mask | OperandId::MergeMask => "to_masking",
+        OperandId::Er => "to_rounding",
+        OperandId::AL
+        | OperandId::AX
+        | OperandId::EAX
+        | OperandId::RAX
+        | OperandId::CL
+        | OperandId::XMM0
+        | OperandId::One
+        | OperandId::Sae => {
+            panic!("Implicit operands are not passed to encoders.")
+        }
+    }
+}
+
+fn generate_instruction_form_entry(group: &InstructionGroup, variant: &InstructionVariant, lock: bool) -> String {
+    let mut content = String::new();
+    let name = generate_instruction_name(group, variant, lock);
+    let kinds = variant
+        .operands
+        .iter()
+        .map(|operand| format!("OperandKind::{}", <&'static str>::from(operand)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fields = variant
+        .operands
+        .iter()
+        .enumerate()
+        .filter(|(_, operand)| !operand.is_implicit())
+        .map(|(idx, operand)| {
+            let op_name = <&'static str>::from(operand).to_lowercase();
+            format!("{op_name}: ops[{idx}].{}()", map_operand_to_conversion(operand))
+        })
+        .collect::<Vec<_>>();
+    let build = if fields.is_empty() {
+        format!("|_| Instruction::{name}")
+    } else {
+        format!("|ops| Instruction::{name} {{ {} }}", fields.join(", "))
+    };
+    let _ = write!(content, "    InstructionForm {{\n");
+    let _ = write!(content, "        name: \"{name}\",\n");
+    let _ = write!(content, "        mnemonic: \"{}\",\n", variant.get_mnemonic(group));
+    let prefix = if lock {
+        "Some(\"lock\")".to_string()
+    } else {
+        variant.prefix.as_ref().map_or_else(
+            || "None".to_string(),
+            |prefix| format!("Some(\"{}\")", prefix.mnemonic()),
+        )
+    };
+    let _ = write!(content, "        prefix: {prefix},\n");
+    let operation_size = variant
+        .operands
+        .iter()
+        .find_map(operand_size)
+        .or_else(|| {
+            if variant.flags.contains(&FlagId::OSO) {
+                Some("Size::Bit16")
+            } else if variant.flags.contains(&FlagId::RexW) && !is_vex(variant) && !is_evex(variant) {
+                // VEX.W and EVEX.W select the element size instead.
+                Some("Size::Bit64")
+            } else {
+                None
+            }
+        })
+        .map_or_else(|| "None".to_string(), |size| format!("Some({size})"));
+    let unsigned_immediate = bool_to_string(variant.flags.contains(&FlagId::UnsignedImm));
+    let _ = write!(content, "        operands: &[{kinds}],\n");
+    let _ = write!(content, "        operation_size: {operation_size},\n");
+    let _ = write!(content, "        unsigned_immediate: {unsigned_immediate},\n");
+    let _ = write!(content, "        build: {build},\n");
+    let _ = write!(content, "    }},\n");
+    content
+}
+
+/// Returns the names of the Reg/Mem and the GPR operands of MR encoded `variant`, in that order.
+fn generate_mr_operand_names(variant: &InstructionVariant) -> [String; 2] {
+    assert!(variant.operands.len() == 2, "MR encoding must have exactly 2 operands.");
+    let mut operand0 = variant.operands[0].clone();
+    let mut operand1 = variant.operands[1].clone();
+
+    assert!(
+        is_gpr(&operand0) || is_gpr(&operand1),
+        "One of the operands in MR encoding has to be GPR."
+    );
+    if is_gpr(&operand0) {
+        std::mem::swap(&mut operand0, &mut operand1);
+    }
+    assert!(
+        is_gpr_or_memory(&operand0) || operand0 == OperandId::Mem64,
+        "The other MR encoding operand has to be Reg/Mem."
+    );
+
+    [
+        <&'static str>::from(operand0).to_lowercase(),
+        <&'static str>::from(operand1).to_lowercase(),
+    ]
+}
+
+#[allow(clippy::single_match_else)]
+fn generate_passed_args(variant: &InstructionVariant) -> String {
+    let opcode = match variant.operand_encoding {
+        OperandEncodingId::OI => {
+            assert!(
+                variant.primary_opcode.len() == 1,
+                "Opcode for OI encoding must be 1 byte long."
+            );
+            format!("0x{:02X}", variant.primary_opcode[0])
+        }
+        _ => generate_opcode_array(variant),
+    };
+
+    let mut passed_args = Vec::with_capacity(variant.operands.len() + 1);
+    passed_args.push(opcode);
+
+    if let Some(extended_opcode) = variant.extended_opcode {
+        passed_args.push(format!("0x{extended_opcode:02X}"));
+    }
+
+    let has_oso = bool_to_string(variant.flags.contains(&FlagId::OSO));
+    let has_rex_w = bool_to_string(variant.flags.contains(&FlagId::RexW));
+
+    assert!(
+        variant.mandatory_prefix.is_none()
+            || is_vex(variant)
+            || is_evex(variant)
+            || has_xmm_operand(variant)
+            || matches!(variant.operand_encoding, OperandEncodingId::MR),
+        "Mandatory prefix of legacy encoded variant is supported only with MR encoding or XMM operands."
+    );
+
+    match variant.operand_encoding {
+        _ if is_evex(variant) => {
+            passed_args.push(generate_mandatory_prefix_byte(variant));
+            passed_args.push(has_rex_w.to_string());
+            passed_args.extend(generate_evex_operand_names(variant));
+        }
+        _ if is_vex_vector(variant) => {
+            passed_args.extend(generate_vex_vector_prefix_args(variant));
+            passed_args.extend(generate_vex_vector_operand_names(variant));
+        }
+        _ if is_vex(variant) => {
+            passed_args.push(generate_mandatory_prefix_byte(variant));
+            passed_args.push(has_rex_w.to_string());
+            passed_args.extend(generate_vex_operand_names(variant));
+        }
+        OperandEncodingId::MR | OperandEncodingId::RMI | OperandEncodingId::MRI if has_xmm_operand(variant) => {
+            passed_args.push(generate_mandatory_prefix_byte(variant));
+            passed_args.push(has_rex_w.to_string());
+            passed_args.extend(generate_xmm_operand_names(variant));
+        }
+        OperandEncodingId::MR => {
+            if variant.mandatory_prefix.is_some() {
+                passed_args.push(generate_mandatory_prefix_byte(variant));
+            }
+            passed_args.extend(generate_mr_operand_names(variant));
+        }
+        OperandEncodingId::M => {
+            assert!(
+                variant.explicit_operands().len() == 1,
+                "M encoding must have exactly 1 explicit operand."
+            );
+            assert!(
+                variant.extended_opcode.is_some(),
+                "M encoding must have an extended opcode."
+            );
+            let operand0 = variant.operands[0].clone();
+            if is_memory(&operand0) {
+                passed_args.push(<&'static str>::from(operand0).to_lowercase());
+                passed_args.push(has_rex_w.to_string());
+                return passed_args.join(", ");
+            }
+            let size = operand_size(&operand0).expect("M encoding operand must be Reg/Mem.");
+            let op_name = <&'static str>::from(operand0).to_lowercase();
+            passed_args.push(op_name);
+            passed_args.push(size.to_string());
+            passed_args.push(has_rex_w.to_string());
+            passed_args.push(has_oso.to_string());
+        }
+        OperandEncodingId::RMI => {
+            assert!(
+                variant.operands.len() == 3,
+                "RMI encoding must have exactly 3 operands."
+            );
+            assert!(
+                is_gpr(&variant.operands[0]) && is_gpr_or_memory(&variant.operands[1]),
+                "RMI encoding must have GPR and Reg/Mem operands, followed by an immediate."
+            );
+            for operand in &variant.operands {
+                passed_args.push(<&'static str>::from(operand).to_lowercase());
+            }
+        }
+        OperandEncodingId::ZO if is_zo_with_prefixes(variant) => {
+            passed_args.push(generate_prefix_byte(variant));
+            passed_args.push(has_oso.to_string());
+            passed_args.push(has_rex_w.to_string());
+        }
+        _ => {
+            for operand in &variant.explicit_operands() {
+                let op_name = <&'static str>::from(operand).to_lowercase();
+                passed_args.push(op_name);
+            }
+        }
+    }
+
+    passed_args.join(", ")
+}
+
+/// Generates the array expression of the primary opcode of `variant`, e.g. `[0x0F, 0xAF]`.
+/// Generates the opcode array of `variant`. The condition code is added to the last
+/// byte of variants with [`FlagId::Cc`] flag, e.g. `[0x0F, 0x90 | condition.code()]`.
+fn generate_opcode_array(variant: &InstructionVariant) -> String {
+    let mut bytes = variant
+        .primary_opcode
+        .iter()
+        .map(|x| format!("0x{x:02X}"))
+        .collect::<Vec<_>>();
+    if variant.flags.contains(&FlagId::Cc) {
+        bytes.last_mut().unwrap().push_str(" | condition.code()");
+    }
+    format!("[{}]", bytes.join(", "))
+}
+
+fn same_variants(left: &[GroupVariant<'_>], right: &[GroupVariant<'_>]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(l, r)| l.0.id == r.0.id && l.1.id == r.1.id && l.2 == r.2)
+}
+
+/// Generates the name of [`Instruction`] enum variant, e.g. `AddRm64Imm8`, or
+/// `AddLockRm64Imm8` for the LOCK-prefixed form if `lock` is true.
+fn generate_instruction_name(group: &InstructionGroup, variant: &InstructionVariant, lock: bool) -> String {
+    let mut name = to_camel_case(&group.name);
+    if lock {
+        name.push_str("Lock");
+    }
+    name.push_str(&to_camel_case(&variant.get_unique_name()));
+    name
+}
+
+/// Generates the match pattern for [`Instruction`] enum variant. If `with_bindings`
+/// is false, then the fields are ignored.
+fn generate_instruction_pattern(
+    group: &InstructionGroup,
+    variant: &InstructionVariant,
+    lock: bool,
+    with_bindings: bool,
+) -> String {
+    let name = generate_instruction_name(group, variant, lock);
+    let operands = variant.explicit_operands();
+    if operands.is_empty() {
+        format!("Self::{name}")
+    } else if with_bindings {
+        let bindings = operands
+            .iter()
+            .map(|operand| <&'static str>::from(operand).to_lowercase())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Self::{name} {{ {bindings} }}")
+    } else {
+        format!("Self::{name} {{ .. }}")
+    }
+}
+
+/// Generates the expression converting given operand of `variant` to [`Operand`].
+fn generate_operand_expression(variant: &InstructionVariant, operand: &OperandId) -> String {
+    let op_name = <&'static str>::from(operand).to_lowercase();
+    match operand {
+        OperandId::Imm8 | OperandId::Imm16 | OperandId::Imm32 | OperandId::Imm64 => {
+            if variant.flags.contains(&FlagId::UnsignedImm) {
+                format!("Operand::from_unsigned_{op_name}({op_name})")
+            } else {
+                format!("Operand::from_{op_name}({op_name})")
+            }
+        }
+        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64 => {
+            let size = operand_size(operand).unwrap();
+            format!("Operand::from_gpr_or_memory({op_name}, {size})")
+        }
+        OperandId::R32M8 => format!("Operand::from_gpr_or_memory({op_name}, Size::Bit8)"),
+        OperandId::R32M16 => format!("Operand::from_gpr_or_memory({op_name}, Size::Bit16)"),
+        OperandId::Reg8
+        | OperandId::Reg16
+        | OperandId::Reg32
+        | OperandId::Reg64
+        | OperandId::VReg32
+        | OperandId::VReg64 => {
+            format!("Operand::GPR {{ gpr: {op_name} }}")
+        }
+        // `lea` only computes the address of its memory operand, so the memory has no size.
+        OperandId::Mem64 if variant.operand_encoding == OperandEncodingId::MR => {
+            format!("Operand::Memory {{ memory: {op_name}, size: None }}")
+        }
+        OperandId::Mem64 => format!("Operand::Memory {{ memory: {op_name}, size: Some(Size::Bit64) }}"),
+        OperandId::Xmm => "Operand::XMM { xmm }".to_string(),
+        OperandId::VXmm => "Operand::XMM { xmm: vxmm }".to_string(),
+        OperandId::XmmM8 => format!("Operand::from_xmm_or_memory({op_name}, Size::Bit8)"),
+        OperandId::XmmM16 => format!("Operand::from_xmm_or_memory({op_name}, Size::Bit16)"),
+        OperandId::XmmM32 => format!("Operand::from_xmm_or_memory({op_name}, Size::Bit32)"),
+        OperandId::XmmM64 => format!("Operand::from_xmm_or_memory({op_name}, Size::Bit64)"),
+        OperandId::XmmM128 => format!("Operand::from_xmm_or_memory({op_name}, Size::Bit128)"),
+        OperandId::Ymm => "Operand::YMM { ymm }".to_string(),
+        OperandId::VYmm => "Operand::YMM { ymm: vymm }".to_string(),
+        OperandId::YmmM256 => format!("Operand::from_ymm_or_memory({op_name}, Size::Bit256)"),
+        OperandId::Zmm => "Operand::ZMM { zmm }".to_string(),
+        OperandId::VZmm | OperandId::ZmmR => format!("Operand::ZMM {{ zmm: {op_name} }}"),
+        OperandId::ZmmM512 | OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => {
+            let element_size = if variant.flags.contains(&FlagId::RexW) {
+                "Size::Bit64"
+            } else {
+                "Size::Bit32"
+            };
+            format!("Operand::from_zmm_or_memory({op_name}, {element_size})")
+        }
+        OperandId::XmmR => "Operand::XMM { xmm: xmmr }".to_string(),
+        OperandId::Vm32X
+        | OperandId::Vm32Y
+        | OperandId::Vm32Z
+        | OperandId::Vm64X
+        | OperandId::Vm64Y
+        | OperandId::Vm64Z => {
+            let size = vsib_memory_size(variant);
+            format!("Operand::VSIBMemory {{ memory: {op_name}, size: {size} }}")
+        }
+        OperandId::K | OperandId::VK | OperandId::KR => format!("Operand::Opmask {{ opmask: {op_name} }}"),
+        OperandId::KM16 => format!("Operand::from_opmask_or_memory({op_name}, Size::Bit16)"),
+        OperandId::Mem16 => format!("Operand::Memory {{ memory: {op_name}, size: Some(Size::Bit16) }}"),
+        OperandId::Mem128 => format!("Operand::Memory {{ memory: {op_name}, size: Some(Size::Bit128) }}"),
+        OperandId::Mask | OperandId::MergeMask => format!("Operand::Masking {{ masking: {op_name} }}"),
+        OperandId::Er => "Operand::Rounding { rounding: er }".to_string(),
+        OperandId::Sae => "Operand::Sae".to_string(),
+        OperandId::AL | OperandId::AX | OperandId::EAX | OperandId::RAX | OperandId::CL => {
+            format!("Operand::GPR {{ gpr: GPR::{} }}", <&'static str>::from(operand))
+        }
+        OperandId::XMM0 => "Operand::XMM { xmm: XMM::XMM0 }".to_string(),
+        OperandId::One => "Operand::from_imm8(Immediate8::from_i8(1))".to_string(),
+    }
+}
+
+fn to_camel_case(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for part in value.split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+    result
+}
+
+/// Generates the code which tries to decode given variant and returns on success.
+fn generate_variant_decoder(group: &InstructionGroup, variant: &InstructionVariant, lock: bool) -> String {
+    let name = generate_instruction_name(group, variant, lock);
+    let mut partial_decoder_name = generate_partial_decoder_name(variant);
+    let op_dec = generate_partial_module_name(variant);
+
+    let opcode = generate_opcode_array(variant);
+    let operand_names = variant
+        .explicit_operands()
+        .iter()
+        .map(|operand| <&'static str>::from(operand).to_lowercase())
+        .collect::<Vec<_>>();
+
+    let mut passed_args = vec!["&mut decoder".to_string()];
+    let mut bindings = operand_names.clone();
+    match variant.operand_encoding {
+        _ if is_evex(variant) => {
+            passed_args.extend(generate_evex_decoder_args(variant, opcode));
+            bindings = generate_evex_operand_names(variant);
+        }
+        _ if is_vex_vector(variant) => {
+            passed_args.push(opcode);
+            passed_args.extend(generate_vex_vector_prefix_args(variant));
+            passed_args.extend(variant.operands.iter().find_map(vsib_index_size).map(str::to_string));
+            bindings = generate_vex_vector_operand_names(variant);
+        }
+        _ if is_vex(variant) => {
+            passed_args.push(opcode);
+            if let Some(extended_opcode) = variant.extended_opcode {
+                passed_args.push(format!("0x{extended_opcode:02X}"));
+            }
+            passed_args.push(generate_mandatory_prefix_byte(variant));
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string());
+            passed_args.push(operand_size(&variant.operands[0]).unwrap().to_string());
+            bindings = generate_vex_operand_names(variant);
+        }
+        OperandEncodingId::MR | OperandEncodingId::RMI | OperandEncodingId::MRI if has_xmm_operand(variant) => {
+            passed_args.push(opcode);
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string());
+            if let Some(size) = variant.operands.iter().find_map(operand_size) {
+                passed_args.push(size.to_string());
+            }
+            bindings = generate_xmm_operand_names(variant);
+        }
+        OperandEncodingId::ZO if is_zo_with_prefixes(variant) => {
+            passed_args.push(opcode);
+            passed_args.push(generate_prefix_byte(variant));
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::OSO)).to_string());
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string());
+        }
+        OperandEncodingId::ZO | OperandEncodingId::I => {
+            passed_args.push(opcode);
+        }
+        OperandEncodingId::OI => {
+            passed_args.push(format!("0x{:02X}", variant.primary_opcode[0]));
+        }
+        OperandEncodingId::O | OperandEncodingId::RMI => {
+            passed_args.push(opcode);
+            passed_args.push(operand_size(&variant.operands[0]).unwrap().to_string());
+        }
+        OperandEncodingId::RVM | OperandEncodingId::RMV | OperandEncodingId::VM => {
+            panic!("{:?} encoding requires Vex flag.", variant.operand_encoding)
+        }
+        OperandEncodingId::RVMI => panic!("RVMI encoding requires Evex flag."),
+        OperandEncodingId::MI => {
+            passed_args.push(opcode);
+            passed_args.push(format!("0x{:02X}", variant.extended_opcode.unwrap()));
+        }
+        OperandEncodingId::M if is_memory(&variant.operands[0]) => {
+            passed_args.push(opcode);
+            passed_args.push(format!("0x{:02X}", variant.extended_opcode.unwrap()));
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string());
+        }
+        OperandEncodingId::M => {
+            passed_args.push(opcode);
+            passed_args.push(format!("0x{:02X}", variant.extended_opcode.unwrap()));
+            passed_args.push(operand_size(&variant.operands[0]).unwrap().to_string());
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string());
+            passed_args.push(bool_to_string(variant.flags.contains(&FlagId::OSO)).to_string());
+        }
+        OperandEncodingId::MRI => panic!("MRI encoding is supported for SSE instructions only."),
+        OperandEncodingId::MR => {
+            let reg = variant.operands.iter().find(|op| is_gpr(op)).unwrap();
+            let other = variant.operands.iter().find(|op| !is_gpr(op)).unwrap();
+            passed_args.push(opcode);
+            passed_args.push(operand_size(reg).unwrap().to_string());
+            if is_gpr_or_memory(other) && operand_size(other) != operand_size(reg) {
+                partial_decoder_name = "decode_mixed".to_string();
+                passed_args.push(operand_size(other).unwrap().to_string());
+            }
+            bindings = vec![
+                <&'static str>::from(other).to_lowercase(),
+                <&'static str>::from(reg).to_lowercase(),
+            ];
+        }
+    }
+
+    let call = format!("{op_dec}::{partial_decoder_name}({})", passed_args.join(", "));
+    let instruction = if operand_names.is_empty() {
+        format!("Instruction::{name}")
+    } else {
+        format!("Instruction::{name} {{ {} }}", operand_names.join(", "))
+    };
+
+    let arm = generate_decoder_arm(&call, &bindings, &instruction);
+    wrap_mandatory_prefix(variant, wrap_lock_prefix(lock, arm))
+}
+
+fn generate_partial_decoder_name(variant: &InstructionVariant) -> String {
+    if is_zmm_store(variant) {
+        "decode_zmm_or_memory_zmm".to_string()
+    } else if is_scatter(variant) {
+        let kind = if variant.operands.contains(&OperandId::Ymm) {
+            "ymm"
+        } else {
+            "zmm"
+        };
+        format!("decode_vsib_{kind}")
+    } else if variant.mandatory_prefix.is_some()
+        && matches!(variant.operand_encoding, OperandEncodingId::MR)
+        && !is_vex(variant)
+        && !is_evex(variant)
+        && !has_xmm_operand(variant)
+    {
+        // The mandatory prefix is matched by the decoder, see `wrap_mandatory_prefix`.
+        "decode".to_string()
+    } else {
+        generate_partial_encoder_name(variant).replacen("encode", "decode", 1)
+    }
+}
+
+/// Returns the arguments passed to the EVEX partial decoder of `variant`, following the decoder.
+fn generate_evex_decoder_args(variant: &InstructionVariant, opcode: String) -> Vec<String> {
+    let mut args = vec![
+        opcode,
+        generate_mandatory_prefix_byte(variant),
+        bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string(),
+    ];
+    if let Some(broadcast) = variant.operands.iter().find_map(zmm_or_memory_broadcast)
+        && !is_zmm_store(variant)
+    {
+        args.push(bool_to_string(broadcast).to_string());
+    }
+    args.extend(variant.operands.iter().find_map(vsib_index_size).map(str::to_string));
+    args
+}
+
+/// Whether `variant` is EVEX encoded store, i.e. with ZMM or memory destination. Its
+/// decoder has to reject zeroing-masking, which is rejected by the validation.
+fn is_zmm_store(variant: &InstructionVariant) -> bool {
+    variant.operands.first() == Some(&OperandId::ZmmM512)
+}
+
+/// Wraps the decoder `arm` of legacy encoded `variant` in setting and clearing its mandatory prefix.
+fn wrap_mandatory_prefix(variant: &InstructionVariant, arm: String) -> String {
+    match variant.mandatory_prefix {
+        Some(mandatory_prefix) if !is_vex(variant) && !is_evex(variant) => format!(
+            "            decoder.set_mandatory_prefix(0x{mandatory_prefix:02X});\n{arm}            decoder.set_mandatory_prefix(0);\n"
+        ),
+        _ => arm,
+    }
+}
+
+/// Wraps the decoder `arm` of the LOCK-prefixed form in setting and clearing the expected LOCK prefix.
+fn wrap_lock_prefix(lock: bool, arm: String) -> String {
+    if lock {
+        format!("            decoder.set_lock_prefix(true);\n{arm}            decoder.set_lock_prefix(false);\n")
+    } else {
+        arm
+    }
+}
+
+/// Generates the code which returns `instruction` if the partial decoder `call`
+/// succeeds, binding its result to `bindings`.
+fn generate_decoder_arm(call: &str, bindings: &[String], instruction: &str) -> String {
+    let mut content = String::new();
+    match bindings.len() {
+        0 => {
+            let _ = write!(content, "            if const_try!({call}) {{\n");
+        }
+        1 => {
+            let _ = write!(
+                content,
+                "            if let Some({}) = const_try!({call}) {{\n",
+                bindings[0]
+            );
+        }
+        _ => {
+            let _ = write!(
+                content,
+                "            if let Some(({})) = const_try!({call}) {{\n",
+                bindings.join(", ")
+            );
+        }
+    }
+    let _ = write!(
+        content,
+        "                return Ok(({instruction}, decoder.length()));\n"
+    );
+    let _ = write!(content, "            }}\n");
+    content
+}
+
+fn generate_encoder_name(variant: &InstructionVariant) -> String {
+    let unique_name = variant.get_unique_name().to_lowercase();
+    if unique_name.is_empty() {
+        "encode".to_string()
+    } else {
+        format!("encode_{unique_name}")
+    }
+}
+
+fn operand_size(operand: &OperandId) -> Option<&'static str> {
+    match operand {
+        OperandId::RM8 | OperandId::Reg8 | OperandId::AL => Some("Size::Bit8"),
+        OperandId::RM16 | OperandId::Reg16 | OperandId::AX => Some("Size::Bit16"),
+        OperandId::RM32
+        | OperandId::Reg32
+        | OperandId::VReg32
+        | OperandId::EAX
+        | OperandId::R32M8
+        | OperandId::R32M16 => Some("Size::Bit32"),
+        OperandId::RM64 | OperandId::Reg64 | OperandId::VReg64 | OperandId::RAX => Some("Size::Bit64"),
+        _ => None,
+    }
+}
+
+/// Generates a list of expressions of `Result<(), EncodeError>` type, that validate
+/// the operands of given variant.
+fn generate_safe_validations(variant: &InstructionVariant) -> Vec<String> {
+    let mut validations = Vec::new();
+    for operand in &variant.explicit_operands() {
+        let op_name = <&'static str>::from(operand).to_lowercase();
+        if is_gpr_or_memory(operand) {
+            let size = operand_size(operand).unwrap();
+            validations.push(format!("validation::validate_gpr_or_memory({op_name}, {size})"));
+        } else if is_gpr(operand) || is_vex_gpr(operand) {
+            let size = operand_size(operand).unwrap();
+            validations.push(format!("validation::validate_gpr({op_name}, {size})"));
+        } else if is_memory(operand) {
+            validations.push(format!("validation::validate_memory({op_name})"));
+        } else if let Some(validation) = generate_vector_validation(variant, operand) {
+            validations.push(validation);
+        }
+    }
+
+    if variant.operand_encoding == OperandEncodingId::MR {
+        let rm = variant.operands.iter().find(|op| is_gpr_or_memory(op));
+        let reg = variant.operands.iter().find(|op| is_gpr(op));
+        if let (Some(rm), Some(reg)) = (rm, reg)
+            && (*rm == OperandId::RM8 || *reg == OperandId::Reg8)
+        {
+            let rm_name = <&'static str>::from(rm).to_lowercase();
+            let reg_name = <&'static str>::from(reg).to_lowercase();
+            let rex_w = bool_to_string(*reg == OperandId::Reg64);
+            validations.push(format!(
+                "validation::validate_bit8_high_with_rex({rm_name}, {reg_name}, {rex_w})"
+            ));
+        }
+    }
+
+    if let Some(validation) = generate_gather_validation(variant) {
+        validations.push(validation);
+    }
+
+    validations
+}
+
+/// Generates the expression validating that the destination, the vector index
+/// and the vector mask (if any) of gather `variant` are distinct registers.
+fn generate_gather_validation(variant: &InstructionVariant) -> Option<String> {
+    let operands = variant.explicit_operands();
+    let vsib = operands.iter().find(|op| vsib_index_size(op).is_some())?;
+    if is_scatter(variant) {
+        return None;
+    }
+    let destination = <&'static str>::from(&operands[0]).to_lowercase();
+    let vsib = <&'static str>::from(vsib).to_lowercase();
+    let mask = operands
+        .iter()
+        .find(|op| matches!(op, OperandId::VXmm | OperandId::VYmm))
+        .map_or("None".to_string(), |op| {
+            format!("Some({}.index())", <&'static str>::from(op).to_lowercase())
+        });
+    Some(format!(
+        "validation::validate_gather_registers({destination}.index(), {vsib}, {mask})"
+    ))
+}
+
+/// Generates the expression validating vector, opmask or EVEX decorator `operand`
+/// of `variant`, if it needs one.
+fn generate_vector_validation(variant: &InstructionVariant, operand: &OperandId) -> Option<String> {
+    let op_name = <&'static str>::from(operand).to_lowercase();
+    let validation = match operand {
+        OperandId::Xmm | OperandId::VXmm if !is_evex(variant) => format!("validation::validate_xmm({op_name})"),
+        OperandId::Ymm | OperandId::VYmm if !is_evex(variant) => format!("validation::validate_ymm({op_name})"),
+        _ if is_xmm_or_memory(operand) && is_evex(variant) => {
+            format!("validation::validate_evex_xmm_or_memory({op_name})")
+        }
+        _ if is_xmm_or_memory(operand) => format!("validation::validate_xmm_or_memory({op_name})"),
+        OperandId::YmmM256 => format!("validation::validate_ymm_or_memory({op_name})"),
+        OperandId::ZmmM512 | OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => {
+            let broadcast = bool_to_string(zmm_or_memory_broadcast(operand).unwrap());
+            format!("validation::validate_zmm_or_memory({op_name}, {broadcast})")
+        }
+        OperandId::KM16 => format!("validation::validate_opmask_or_memory({op_name})"),
+        OperandId::Mem16 => format!("validation::validate_memory({op_name})"),
+        OperandId::Mask => format!("validation::validate_masking({op_name}, true)"),
+        OperandId::MergeMask if variant.operands.iter().any(|op| vsib_index_size(op).is_some()) => {
+            format!("validation::validate_vsib_masking({op_name})")
+        }
+        OperandId::MergeMask => format!("validation::validate_masking({op_name}, false)"),
+        _ if let Some(index_size) = vsib_index_size(operand) => {
+            let evex = bool_to_string(is_evex(variant));
+            format!("validation::validate_vsib_memory({op_name}, {index_size}, {evex})")
+        }
+        _ => return None,
+    };
+    Some(validation)
+}
+
+/// Returns whether ZMM or memory `operand` allows embedded broadcast, or `None`
+/// if `operand` is not ZMM or memory.
+fn zmm_or_memory_broadcast(operand: &OperandId) -> Option<bool> {
+    match operand {
+        OperandId::ZmmM512 => Some(false),
+        OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => Some(true),
+        _ => None,
+    }
+}
+
+/// Returns the size of the vector index of VSIB memory `operand`, or `None`
+/// if `operand` is not VSIB memory.
+fn vsib_index_size(operand: &OperandId) -> Option<&'static str> {
+    match operand {
+        OperandId::Vm32X | OperandId::Vm64X => Some("Size::Bit128"),
+        OperandId::Vm32Y | OperandId::Vm64Y => Some("Size::Bit256"),
+        OperandId::Vm32Z | OperandId::Vm64Z => Some("Size::Bit512"),
+        _ => None,
+    }
+}
+
+/// Returns the size of a single element gathered (or scattered) by `variant`,
+/// which is used as the size of its VSIB memory operand (same as objdump does),
+/// e.g. `vgatherqps xmm1, dword ptr [rax + xmm2*4], xmm3`.
+fn vsib_memory_size(variant: &InstructionVariant) -> &'static str {
+    if variant.flags.contains(&FlagId::RexW) {
+        "Size::Bit64"
+    } else {
+        "Size::Bit32"
+    }
+}
+
+/// Whether `variant` is a scatter, i.e. with VSIB memory destination.
+fn is_scatter(variant: &InstructionVariant) -> bool {
+    variant.operands.first().and_then(vsib_index_size).is_some()
+}
 
 fn is_gpr_or_memory(operand: &OperandId) -> bool {
     matches!(
         operand,
-        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64
+        OperandId::RM8 | OperandId::RM16 | OperandId::RM32 | OperandId::RM64 | OperandId::R32M8 | OperandId::R32M16
     )
 }
 
+/// Whether `operand` is a memory only operand, i.e. `m64` or `m128`.
+fn is_memory(operand: &OperandId) -> bool {
+    matches!(operand, OperandId::Mem64 | OperandId::Mem128)
+}
+
 fn is_gpr(operand: &OperandId) -> bool {
     matches!(
         operand,
@@ -364,18 +1764,265 @@ fn is_gpr(operand: &OperandId) -> bool {
     )
 }
 
-fn generate_partial_encoder_name(variant: &InstructionVariant) -> String {
-    let unique_name = variant.get_unique_name().to_lowercase();
-    let fn_name = if unique_name.is_empty() {
-        "encode"
+fn is_vex_gpr(operand: &OperandId) -> bool {
+    matches!(operand, OperandId::VReg32 | OperandId::VReg64)
+}
+
+fn is_vex(variant: &InstructionVariant) -> bool {
+    variant.flags.contains(&FlagId::Vex)
+}
+
+fn is_evex(variant: &InstructionVariant) -> bool {
+    variant.flags.contains(&FlagId::Evex)
+}
+
+fn is_xmm_or_memory(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::XmmM8 | OperandId::XmmM16 | OperandId::XmmM32 | OperandId::XmmM64 | OperandId::XmmM128
+    )
+}
+
+/// Returns true if `operand` is XMM, YMM or opmask register, including VEX.vvvv ones, or memory.
+fn is_vector(operand: &OperandId) -> bool {
+    matches!(
+        operand,
+        OperandId::Xmm | OperandId::VXmm | OperandId::Ymm | OperandId::VYmm | OperandId::YmmM256
+    ) || is_xmm_or_memory(operand)
+        || is_opmask(operand)
+}
+
+fn is_opmask(operand: &OperandId) -> bool {
+    matches!(operand, OperandId::K | OperandId::VK | OperandId::KR | OperandId::KM16)
+}
+
+/// Returns true if `variant` is VEX-encoded AVX instruction, i.e. it has XMM, YMM
+/// or opmask operands or no operands at all (e.g. `vzeroupper`).
+fn is_vex_vector(variant: &InstructionVariant) -> bool {
+    is_vex(variant) && (variant.operand_encoding == OperandEncodingId::ZO || variant.operands.iter().any(is_vector))
+}
+
+fn has_xmm_operand(variant: &InstructionVariant) -> bool {
+    variant
+        .operands
+        .iter()
+        .any(|operand| *operand == OperandId::Xmm || is_xmm_or_memory(operand))
+}
+
+/// Returns the operands of SSE `variant` in the order expected by the partial
+/// encoders, i.e. reg operand (XMM or GPR) followed by r/m operand. The trailing
+/// immediate (if any) and implicit operands are skipped.
+fn generate_xmm_operands(variant: &InstructionVariant) -> (OperandId, OperandId) {
+    let operands = variant
+        .explicit_operands()
+        .into_iter()
+        .filter(|operand| *operand != OperandId::Imm8)
+        .collect::<Vec<_>>();
+    assert!(
+        operands.len() == 2,
+        "SSE encoding must have exactly 2 non-immediate operands."
+    );
+    let is_reg = |operand: &OperandId| *operand == OperandId::Xmm || is_gpr(operand);
+    if is_reg(&operands[0]) {
+        (operands[0].clone(), operands[1].clone())
+    } else {
+        assert!(
+            is_reg(&operands[1]),
+            "One of the operands in SSE encoding has to be XMM or GPR."
+        );
+        (operands[1].clone(), operands[0].clone())
+    }
+}
+
+fn generate_xmm_operand_names(variant: &InstructionVariant) -> Vec<String> {
+    let (reg, rm) = generate_xmm_operands(variant);
+    let mut names = vec![
+        <&'static str>::from(reg).to_lowercase(),
+        <&'static str>::from(rm).to_lowercase(),
+    ];
+    if variant.operands.contains(&OperandId::Imm8) {
+        names.push("imm8".to_string());
+    }
+    names
+}
+
+/// Returns the name of the partial encoders (and decoders) module of `variant`.
+fn generate_partial_module_name(variant: &InstructionVariant) -> String {
+    if is_evex(variant) {
+        "evex".to_string()
+    } else if is_vex(variant) {
+        "vex".to_string()
+    } else if has_xmm_operand(variant) {
+        "xmm".to_string()
     } else {
-        &format!("encode_{unique_name}")
+        <&'static str>::from(variant.operand_encoding.clone()).to_lowercase()
+    }
+}
+
+/// Generates the operand names of VEX-encoded `variant` in the order expected
+/// by the partial encoders, i.e. reg, VEX.vvvv and r/m operands, followed by immediate.
+fn generate_vex_operand_names(variant: &InstructionVariant) -> Vec<String> {
+    let find = |predicate: fn(&OperandId) -> bool| variant.operands.iter().find(|op| predicate(op));
+    let operands = match variant.operand_encoding {
+        OperandEncodingId::RVM | OperandEncodingId::RMV => vec![find(is_gpr), find(is_vex_gpr), find(is_gpr_or_memory)],
+        OperandEncodingId::VM => vec![find(is_vex_gpr), find(is_gpr_or_memory)],
+        _ => variant.operands.iter().map(Some).collect(),
     };
+    operands
+        .into_iter()
+        .map(|operand| {
+            let operand = operand.expect("VEX encoded variant is missing an operand.");
+            <&'static str>::from(operand).to_lowercase()
+        })
+        .collect()
+}
+
+/// Returns the operands of VEX-encoded AVX `variant` in the order expected by
+/// the partial encoders, i.e. reg, VEX.vvvv (if any) and r/m operands, followed by
+/// the immediate (if any).
+fn generate_vex_vector_operands(variant: &InstructionVariant) -> Vec<OperandId> {
+    let operands = variant.explicit_operands();
+    match variant.operand_encoding {
+        OperandEncodingId::RVM => operands,
+        OperandEncodingId::MR => {
+            assert!(operands.len() == 2, "AVX MR encoding must have exactly 2 operands.");
+            if matches!(
+                operands[0],
+                OperandId::Xmm | OperandId::Ymm | OperandId::K | OperandId::Reg32
+            ) {
+                operands
+            } else {
+                vec![operands[1].clone(), operands[0].clone()]
+            }
+        }
+        OperandEncodingId::MRI => {
+            assert!(operands.len() == 3, "AVX MRI encoding must have exactly 3 operands.");
+            vec![operands[1].clone(), operands[0].clone(), operands[2].clone()]
+        }
+        OperandEncodingId::RMV => {
+            assert!(operands.len() == 3, "AVX RMV encoding must have exactly 3 operands.");
+            vec![operands[0].clone(), operands[2].clone(), operands[1].clone()]
+        }
+        OperandEncodingId::ZO => Vec::new(),
+        _ => panic!(
+            "{:?} encoding is not supported for AVX instructions.",
+            variant.operand_encoding
+        ),
+    }
+}
+
+/// Generates the VEX.pp, VEX.W and (for zero operand and opmask instructions) VEX.L
+/// arguments of AVX partial encoders and decoders.
+fn generate_vex_vector_prefix_args(variant: &InstructionVariant) -> Vec<String> {
+    let mut args = vec![
+        generate_mandatory_prefix_byte(variant),
+        bool_to_string(variant.flags.contains(&FlagId::RexW)).to_string(),
+    ];
+    if variant.operand_encoding == OperandEncodingId::ZO || variant.operands.iter().any(is_opmask) {
+        args.push(bool_to_string(variant.flags.contains(&FlagId::VexL)).to_string());
+    }
+    args
+}
+
+fn generate_vex_vector_operand_names(variant: &InstructionVariant) -> Vec<String> {
+    generate_vex_vector_operands(variant)
+        .into_iter()
+        .map(|operand| <&'static str>::from(operand).to_lowercase())
+        .collect()
+}
+
+/// Returns the operands of EVEX-encoded `variant` in the order expected by the
+/// partial encoders, i.e. reg, EVEX.vvvv (if any) and r/m operands, followed by
+/// rounding, SAE and immediate (if any). The masking is skipped.
+fn generate_evex_operands(variant: &InstructionVariant) -> Vec<OperandId> {
+    let mut operands = variant
+        .operands
+        .iter()
+        .filter(|operand| !matches!(operand, OperandId::Mask | OperandId::MergeMask))
+        .cloned()
+        .collect::<Vec<_>>();
+    if variant.operand_encoding == OperandEncodingId::MR
+        && !matches!(
+            operands[0],
+            OperandId::Zmm | OperandId::Ymm | OperandId::Xmm | OperandId::K
+        )
+    {
+        operands.swap(0, 1);
+    }
+    operands
+}
+
+/// Generates the operand names of EVEX-encoded `variant` in the order expected
+/// by the partial encoders, i.e. reg, masking, EVEX.vvvv (if any) and r/m operands,
+/// followed by rounding and immediate (if any).
+fn generate_evex_operand_names(variant: &InstructionVariant) -> Vec<String> {
+    let mut names = generate_evex_operands(variant)
+        .into_iter()
+        .filter(|operand| !operand.is_implicit())
+        .map(|operand| <&'static str>::from(operand).to_lowercase())
+        .collect::<Vec<_>>();
+    let masking = variant
+        .operands
+        .iter()
+        .find(|operand| matches!(operand, OperandId::Mask | OperandId::MergeMask))
+        .expect("EVEX encoded variant is missing masking operand.");
+    names.insert(1, <&'static str>::from(masking).to_lowercase());
+    names
+}
+
+/// Generates the mandatory prefix byte of `variant`, or `0x00` if there is none.
+fn generate_mandatory_prefix_byte(variant: &InstructionVariant) -> String {
+    format!("0x{:02X}", variant.mandatory_prefix.unwrap_or(0))
+}
+
+fn generate_partial_encoder_name(variant: &InstructionVariant) -> String {
+    let fn_name = generate_encoder_name(variant);
 
     match variant.operand_encoding {
+        _ if is_evex(variant) => {
+            let kinds = generate_evex_operands(variant)
+                .iter()
+                .map(|operand| match operand {
+                    OperandId::Zmm | OperandId::VZmm | OperandId::ZmmR => "zmm",
+                    OperandId::ZmmM512 | OperandId::ZmmM512B32 | OperandId::ZmmM512B64 => "zmm_or_memory",
+                    OperandId::Xmm | OperandId::VXmm | OperandId::XmmR => "xmm",
+                    OperandId::Ymm => "ymm",
+                    OperandId::K => "k",
+                    _ if vsib_index_size(operand).is_some() => "vsib",
+                    OperandId::Er => "er",
+                    OperandId::Sae => "sae",
+                    OperandId::Imm8 => "imm8",
+                    _ => "xmm_or_memory",
+                })
+                .collect::<Vec<_>>();
+            format!("encode_{}", kinds.join("_"))
+        }
+        OperandEncodingId::ZO if is_vex_vector(variant) => "encode_zo".to_string(),
+        _ if is_vex_vector(variant) => {
+            let kinds = generate_vex_vector_operands(variant)
+                .iter()
+                .map(|operand| match operand {
+                    OperandId::Xmm | OperandId::VXmm => "xmm",
+                    OperandId::Ymm | OperandId::VYmm => "ymm",
+                    OperandId::YmmM256 => "ymm_or_memory",
+                    OperandId::K | OperandId::VK | OperandId::KR => "k",
+                    OperandId::KM16 => "k_or_memory",
+                    OperandId::Mem16 => "memory",
+                    OperandId::Reg32 => "gpr",
+                    OperandId::Imm8 => "imm8",
+                    _ if vsib_index_size(operand).is_some() => "vsib",
+                    _ => "xmm_or_memory",
+                })
+                .collect::<Vec<_>>();
+            format!("encode_{}", kinds.join("_"))
+        }
+        OperandEncodingId::RVM | OperandEncodingId::RMV => "encode_rvm".to_string(),
+        OperandEncodingId::VM => "encode_vm".to_string(),
+        OperandEncodingId::RMI if is_vex(variant) => "encode_rmi".to_string(),
         OperandEncodingId::I => {
-            assert!(variant.operands.len() == 1, "I encoding must have exactly 1 operand.");
-            let operand = &variant.operands[0];
+            let operands = variant.explicit_operands();
+            assert!(operands.len() == 1, "I encoding must have exactly 1 explicit operand.");
+            let operand = &operands[0];
             let op_name = <&'static str>::from(operand).to_lowercase();
 
             let suffix = if variant.flags.contains(&FlagId::RexW) {
@@ -388,6 +2035,23 @@ fn generate_partial_encoder_name(variant: &InstructionVariant) -> String {
 
             format!("encode_{op_name}{suffix}")
         }
+        OperandEncodingId::MR | OperandEncodingId::RMI | OperandEncodingId::MRI if has_xmm_operand(variant) => {
+            let (reg, rm) = generate_xmm_operands(variant);
+            let reg_kind = if reg == OperandId::Xmm { "xmm" } else { "gpr" };
+            let rm_kind = if rm == OperandId::Xmm {
+                "xmm"
+            } else if is_xmm_or_memory(&rm) {
+                "xmm_or_memory"
+            } else {
+                "gpr_or_memory"
+            };
+            let suffix = if variant.operands.contains(&OperandId::Imm8) {
+                "_imm8"
+            } else {
+                ""
+            };
+            format!("encode_{reg_kind}_{rm_kind}{suffix}")
+        }
         OperandEncodingId::MR => {
             assert!(variant.operands.len() == 2, "MR encoding must have exactly 2 operands.");
             let operand0 = variant.operands[0].clone();
@@ -401,11 +2065,56 @@ fn generate_partial_encoder_name(variant: &InstructionVariant) -> String {
                 return "encode_mem".to_string();
             }
 
+            if variant.mandatory_prefix.is_some() {
+                return "encode_with_mandatory_prefix".to_string();
+            }
+
             "encode".to_string()
         }
+        OperandEncodingId::M if is_memory(&variant.operands[0]) => "encode_memory".to_string(),
         OperandEncodingId::M => "encode_gpr_or_memory".to_string(),
+        OperandEncodingId::RMI => {
+            let immediate = <&'static str>::from(&variant.operands[2]).to_lowercase();
+            format!("encode_{immediate}")
+        }
+        OperandEncodingId::ZO if is_zo_with_prefixes(variant) => "encode_with_prefixes".to_string(),
+        OperandEncodingId::O if variant.flags.contains(&FlagId::RexW) => "encode_rexw".to_string(),
         OperandEncodingId::O | OperandEncodingId::ZO => "encode".to_string(),
-        _ => fn_name.to_string(),
+        _ => fn_name,
+    }
+}
+
+/// Returns true if `variant` is a zero operand instruction, which requires
+/// a legacy prefix (e.g. REP) or its size is implied by OSO/REX.W prefixes.
+fn is_zo_with_prefixes(variant: &InstructionVariant) -> bool {
+    variant.operand_encoding == OperandEncodingId::ZO
+        && (variant.prefix.is_some() || variant.flags.contains(&FlagId::OSO) || variant.flags.contains(&FlagId::RexW))
+}
+
+/// Generates the legacy prefix byte of `variant`, or `0x00` if there is none.
+fn generate_prefix_byte(variant: &InstructionVariant) -> String {
+    format!("0x{:02X}", variant.prefix.as_ref().map_or(0, PrefixId::byte))
+}
+
+/// Generates the encoder name of `variant`, prefixed with `lock_` if `lock` is true,
+/// e.g. `encode_lock_rm32_imm8`.
+fn generate_lock_aware_encoder_name(variant: &InstructionVariant, lock: bool) -> String {
+    let fn_name = generate_encoder_name(variant);
+    if lock {
+        fn_name.replacen("encode", "encode_lock", 1)
+    } else {
+        fn_name
+    }
+}
+
+fn generate_lock_aware_description(variant: &InstructionVariant, lock: bool) -> String {
+    if lock {
+        format!(
+            "{} LOCK-prefixed, i.e. atomic. The destination has to be memory.",
+            variant.description
+        )
+    } else {
+        variant.description.clone()
     }
 }
 
@@ -21,7 +21,7 @@ struct Args {
     destination: PathBuf,
 }
 
-pub fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
     let path = args.destination.absolutize()?.to_path_buf();
     let generator = generator::Generator::new(path);
@@ -7,6 +7,14 @@
 #![cfg_attr(docsrs, allow(unused_attributes))]
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::inline_always)]
+#![cfg_attr(
+    test,
+    allow(
+        clippy::unreadable_literal,
+        clippy::needless_borrows_for_generic_args,
+        clippy::cast_possible_truncation
+    )
+)]
 
 use std::marker::PhantomData;
 
@@ -33,8 +41,10 @@ impl ExecutablePtr<'_> {
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn as_ptr(&self) -> *const u8 {
+        // The code produced by the assembler may contain inline data (e.g. constants
+        // loaded with RIP-relative addressing), hence the memory has to stay readable.
         unsafe {
-            region::protect(self.ptr, self.len, region::Protection::EXECUTE)
+            region::protect(self.ptr, self.len, region::Protection::READ_EXECUTE)
                 .expect("Memory protection should be modifiable");
         };
         self.ptr
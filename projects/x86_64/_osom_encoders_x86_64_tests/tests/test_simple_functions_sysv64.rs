@@ -4,8 +4,9 @@ use std::mem::transmute;
 use _osom_encoders_x86_64_tests::ExecutableMemory;
 
 use osom_encoders_x86_64::{
+    assembler::Assembler,
     encoders,
-    models::{GPR, GPROrMemory, Memory, Scale},
+    models::{Condition, GPR, GPROrMemory, Memory, Scale},
 };
 
 macro_rules! to_fn {
@@ -95,3 +96,60 @@ fn test_sysv64_lea() {
         assert_eq!(func(123, -16), 107);
     }
 }
+
+#[test]
+fn test_sysv64_store_qword() {
+    unsafe {
+        let mut memory = ExecutableMemory::new();
+        let dst = GPROrMemory::from(Memory::Based {
+            base: GPR::RDI,
+            offset: 0.into(),
+        });
+        memory.push(encoders::mov::encode_rm64_imm32(dst, (-1).into()));
+        memory.push(encoders::ret::encode());
+
+        let exe = memory.as_exe();
+        let func = to_fn!(fn(*mut u64); exe);
+        let mut value: u64 = 0;
+        func(&raw mut value);
+        assert_eq!(value, u64::MAX);
+    }
+}
+
+#[test]
+fn test_sysv64_assembler_sum() {
+    unsafe {
+        let mut assembler = Assembler::new();
+        let loop_start = assembler.create_label();
+        let loop_end = assembler.create_label();
+        let constant = assembler.create_label();
+        let rax = GPROrMemory::from(GPR::RAX);
+        let rdi = GPROrMemory::from(GPR::RDI);
+        assembler.emit(encoders::mov::encode_rm64_imm32(rax, 0.into()));
+        assembler.bind_label(loop_start).unwrap();
+        assembler.emit(encoders::cmp::encode_rm64_imm8(rdi, 0i8.into()));
+        assembler.jcc(Condition::E, loop_end);
+        assembler.emit(encoders::add::encode_rm64_reg64(rax, GPR::RDI));
+        assembler.emit(encoders::sub::encode_rm64_imm8(rdi, 1i8.into()));
+        assembler.jmp(loop_start);
+        assembler.bind_label(loop_end).unwrap();
+        assembler
+            .emit_rip_relative(constant, |memory| {
+                encoders::safe::add::encode_reg64_rm64(GPR::RAX, GPROrMemory::from(memory))
+            })
+            .unwrap();
+        assembler.emit(encoders::ret::encode());
+        assembler.bind_label(constant).unwrap();
+        assembler.emit(1000u64.to_le_bytes());
+
+        let mut memory = ExecutableMemory::new();
+        memory.push(assembler.finalize().unwrap());
+
+        let exe = memory.as_exe();
+        let func = to_fn!(fn(u64) -> u64; exe);
+        assert_eq!(func(0), 1000);
+        assert_eq!(func(1), 1001);
+        assert_eq!(func(4), 1010);
+        assert_eq!(func(100), 6050);
+    }
+}
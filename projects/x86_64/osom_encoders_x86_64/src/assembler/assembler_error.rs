@@ -0,0 +1,59 @@
+use core::fmt;
+
+use crate::models::{EncodeError, GPR};
+
+use super::Label;
+
+/// Represents an error raised by [`Assembler`][super::Assembler].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum AssemblerError {
+    /// The label was already bound to a position.
+    LabelAlreadyBound { label: Label },
+
+    /// The label is referenced, but it was never bound to a position.
+    UnboundLabel { label: Label },
+
+    /// The label was not created by this assembler.
+    InvalidLabel { label: Label },
+
+    /// The distance to the label does not fit into the displacement.
+    DisplacementOutOfRange { label: Label },
+
+    /// The encoder passed to the assembler did not encode
+    /// the displacement to the label.
+    MissingDisplacement,
+
+    /// The register size is not supported by the instruction,
+    /// e.g. 8-bit destination of `lea`.
+    UnsupportedRegisterSize { gpr: GPR },
+
+    /// The instruction could not be encoded.
+    Encode(EncodeError),
+}
+
+impl From<EncodeError> for AssemblerError {
+    fn from(value: EncodeError) -> Self {
+        Self::Encode(value)
+    }
+}
+
+impl fmt::Display for AssemblerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LabelAlreadyBound { label } => write!(f, "label {} is already bound", label.index()),
+            Self::UnboundLabel { label } => write!(f, "label {} is never bound", label.index()),
+            Self::InvalidLabel { label } => write!(f, "label {} does not belong to the assembler", label.index()),
+            Self::DisplacementOutOfRange { label } => {
+                write!(f, "displacement to label {} is out of range", label.index())
+            }
+            Self::MissingDisplacement => write!(f, "instruction does not encode displacement to the label"),
+            Self::UnsupportedRegisterSize { gpr } => {
+                write!(f, "register {gpr:?} has size not supported by the instruction")
+            }
+            Self::Encode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl core::error::Error for AssemblerError {}
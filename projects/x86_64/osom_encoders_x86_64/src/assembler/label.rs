@@ -0,0 +1,24 @@
+/// Represents a position in the code emitted by [`Assembler`][super::Assembler].
+///
+/// Labels are created by [`Assembler::create_label`][super::Assembler::create_label]
+/// and can be referenced before they are bound to a position. Labels are only
+/// meaningful for the assembler that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[must_use]
+pub struct Label {
+    index: u32,
+}
+
+impl Label {
+    #[inline(always)]
+    pub(crate) const fn from_index(index: u32) -> Self {
+        Self { index }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> usize {
+        self.index as usize
+    }
+}
@@ -0,0 +1,18 @@
+//! This module contains the [`Assembler`], a growable code buffer
+//! which allows referencing code positions through [`Label`]s.
+//!
+//! All the displacements to labels are resolved at [`Assembler::finalize`].
+//! Alternatively, [`Assembler::finalize_relocatable`] leaves references to
+//! unbound labels for the linker, see [`elf`][crate::elf].
+
+mod label;
+pub use label::*;
+
+mod assembler_error;
+pub use assembler_error::*;
+
+mod relocatable_code;
+pub use relocatable_code::*;
+
+mod x86_64_assembler;
+pub use x86_64_assembler::*;
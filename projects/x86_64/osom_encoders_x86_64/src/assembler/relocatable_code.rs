@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use super::Label;
+
+/// Represents a rel32 displacement to a label, which was never bound
+/// to a position, i.e. to an external symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct LabelReference {
+    /// The position of the displacement in the code.
+    pub offset: usize,
+
+    /// The position the displacement is relative to, i.e. the end
+    /// of the instruction.
+    pub relative_to: usize,
+
+    pub label: Label,
+}
+
+impl LabelReference {
+    /// Returns the addend of the PC-relative relocation, i.e. the distance
+    /// from the end of the instruction to the displacement (always negative).
+    ///
+    /// # Panics
+    ///
+    /// If the offsets do not fit into `i64`.
+    #[inline]
+    #[must_use]
+    pub fn addend(&self) -> i64 {
+        let offset = i64::try_from(self.offset).expect("Offset out of range.");
+        let relative_to = i64::try_from(self.relative_to).expect("Offset out of range.");
+        offset - relative_to
+    }
+}
+
+/// Represents the code returned by [`Assembler::finalize_relocatable`][super::Assembler::finalize_relocatable],
+/// together with the final positions of labels and references to unbound labels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct RelocatableCode {
+    pub(super) code: Vec<u8>,
+    pub(super) labels: Vec<Option<usize>>,
+    pub(super) references: Vec<LabelReference>,
+}
+
+impl RelocatableCode {
+    /// Returns the final code.
+    #[inline(always)]
+    #[must_use]
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    /// Returns the final code, consuming `self`.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_code(self) -> Vec<u8> {
+        self.code
+    }
+
+    /// Returns the final position of `label` in the code, or `None` if
+    /// the label is not bound or was not created by the assembler.
+    #[inline]
+    #[must_use]
+    pub fn label_position(&self, label: Label) -> Option<usize> {
+        self.labels.get(label.index()).copied().flatten()
+    }
+
+    /// Returns all references to unbound labels, ordered by their offset.
+    #[inline(always)]
+    pub fn references(&self) -> &[LabelReference] {
+        &self.references
+    }
+}
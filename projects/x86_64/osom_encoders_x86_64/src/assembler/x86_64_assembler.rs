@@ -0,0 +1,513 @@
+use alloc::vec::Vec;
+
+use crate::constants::{JCC_LONG_LENGTH, JCC_SHORT_LENGTH, JMP_LONG_LENGTH, JMP_SHORT_LENGTH};
+use crate::encoders::{call, jcc, jmp, safe};
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate32, Memory, Offset, Size,
+};
+
+use super::{AssemblerError, Label, LabelReference, RelocatableCode};
+
+/// Represents a position in the code, which does not account for
+/// relaxable branches. Those are stored separately, since their
+/// size is known only at [`Assembler::finalize`].
+#[derive(Debug, Clone, Copy)]
+struct CodePosition {
+    /// The position in the code buffer.
+    position: usize,
+
+    /// The number of branches emitted before this position.
+    branches: usize,
+}
+
+/// Represents a rel32 displacement to a label, which is filled
+/// at [`Assembler::finalize`].
+#[derive(Debug, Clone, Copy)]
+struct Fixup {
+    /// The position of the displacement in the code.
+    position: CodePosition,
+
+    /// The position the displacement is relative to, i.e. the end
+    /// of the instruction.
+    relative_to: CodePosition,
+
+    target: Label,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BranchKind {
+    Jmp,
+
+    /// Conditional jump with the given condition.
+    Jcc {
+        condition: Condition,
+    },
+}
+
+/// Represents a `jmp` or `jcc` to a label, which starts as the short form
+/// and is promoted to the long form when the displacement does not fit into
+/// 8 bits.
+#[derive(Debug, Clone, Copy)]
+struct Branch {
+    /// The position in the code buffer, at which the branch is inserted.
+    position: usize,
+
+    kind: BranchKind,
+
+    target: Label,
+}
+
+impl BranchKind {
+    #[inline]
+    const fn length(self, is_long: bool) -> usize {
+        match (self, is_long) {
+            (Self::Jmp, false) => JMP_SHORT_LENGTH,
+            (Self::Jmp, true) => JMP_LONG_LENGTH,
+            (Self::Jcc { .. }, false) => JCC_SHORT_LENGTH,
+            (Self::Jcc { .. }, true) => JCC_LONG_LENGTH,
+        }
+    }
+
+    #[inline]
+    const fn encode_short(self, displacement: Immediate8) -> EncodedX86_64Instruction {
+        match self {
+            Self::Jmp => unsafe { jmp::encode_imm8(displacement) },
+            Self::Jcc { condition } => unsafe { jcc::encode_imm8(condition, displacement) },
+        }
+    }
+
+    #[inline]
+    const fn encode_long(self, displacement: Immediate32) -> EncodedX86_64Instruction {
+        match self {
+            Self::Jmp => unsafe { jmp::encode_imm32(displacement) },
+            Self::Jcc { condition } => unsafe { jcc::encode_imm32(condition, displacement) },
+        }
+    }
+}
+
+/// Represents a growable buffer of `X86_64` code with support for labels.
+///
+/// Instructions referencing labels (jumps, calls and RIP-relative memory
+/// operands) are emitted with a zero displacement, which is resolved
+/// once all labels are bound, at [`Assembler::finalize`].
+///
+/// Jumps to labels (`jmp` and `jcc`) are subject to branch relaxation:
+/// each starts as the short, rel8 form and is promoted to the long,
+/// rel32 form only if its displacement does not fit into 8 bits.
+///
+/// # Examples
+///
+/// ```
+/// use osom_encoders_x86_64::assembler::Assembler;
+/// use osom_encoders_x86_64::encoders::{ret, safe};
+/// use osom_encoders_x86_64::models::GPR;
+///
+/// let mut assembler = Assembler::new();
+/// let value = assembler.create_label();
+/// assembler.mov(GPR::RAX, value).unwrap();
+/// assembler.emit(unsafe { ret::encode() });
+/// assembler.bind_label(value).unwrap();
+/// assembler.emit(42u64.to_le_bytes());
+///
+/// let code = assembler.finalize().unwrap();
+/// assert_eq!(&code[..8], &[0x48, 0x8B, 0x05, 0x01, 0x00, 0x00, 0x00, 0xC3]);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct Assembler {
+    code: Vec<u8>,
+    labels: Vec<Option<CodePosition>>,
+    fixups: Vec<Fixup>,
+    branches: Vec<Branch>,
+}
+
+impl Assembler {
+    /// Creates a new, empty [`Assembler`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            code: Vec::new(),
+            labels: Vec::new(),
+            fixups: Vec::new(),
+            branches: Vec::new(),
+        }
+    }
+
+    /// Creates a new, empty [`Assembler`] with preallocated code buffer
+    /// of at least `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            code: Vec::with_capacity(capacity),
+            labels: Vec::new(),
+            fixups: Vec::new(),
+            branches: Vec::new(),
+        }
+    }
+
+    /// Returns the current position in the code, i.e. the number of bytes
+    /// emitted so far.
+    ///
+    /// This is the pre-relaxation offset: all jumps to labels are counted
+    /// in their short form. Jumps promoted to the long form at
+    /// [`Assembler::finalize`] move the final position further.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        let branches_length = self
+            .branches
+            .iter()
+            .map(|branch| branch.kind.length(false))
+            .sum::<usize>();
+        self.code.len() + branches_length
+    }
+
+    /// Creates a new, unbound [`Label`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than `u32::MAX` labels are created.
+    #[inline]
+    pub fn create_label(&mut self) -> Label {
+        let index = u32::try_from(self.labels.len()).expect("Too many labels.");
+        self.labels.push(None);
+        Label::from_index(index)
+    }
+
+    /// Binds `label` to the current position in the code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::LabelAlreadyBound`] if `label` is already bound
+    /// and [`AssemblerError::InvalidLabel`] if `label` was not created by this assembler.
+    pub fn bind_label(&mut self, label: Label) -> Result<(), AssemblerError> {
+        let position = self.current_position();
+        match self.labels.get_mut(label.index()) {
+            None => Err(AssemblerError::InvalidLabel { label }),
+            Some(Some(_)) => Err(AssemblerError::LabelAlreadyBound { label }),
+            Some(slot) => {
+                *slot = Some(position);
+                Ok(())
+            }
+        }
+    }
+
+    /// Appends `data` to the code. This is typically an [`EncodedX86_64Instruction`],
+    /// but it can be raw bytes as well, e.g. data referenced by labels.
+    #[inline]
+    pub fn emit<T: AsRef<[u8]>>(&mut self, data: T) {
+        self.code.extend_from_slice(data.as_ref());
+    }
+
+    /// Emits unconditional `jmp` to `target`. The final form (rel8 or rel32)
+    /// is chosen at [`Assembler::finalize`].
+    pub fn jmp(&mut self, target: Label) {
+        self.emit_branch(BranchKind::Jmp, target);
+    }
+
+    /// Emits `call rel32` to `target`.
+    pub fn call(&mut self, target: Label) {
+        let instruction = unsafe { call::encode_imm32(Immediate32::from_i32(0)) };
+        self.emit_rel32_at_end(instruction, target);
+    }
+
+    /// Emits conditional jump to `target`, taken if `condition` is met.
+    /// The final form (rel8 or rel32) is chosen at [`Assembler::finalize`].
+    pub fn jcc(&mut self, condition: Condition, target: Label) {
+        self.emit_branch(BranchKind::Jcc { condition }, target);
+    }
+
+    /// Emits `lea dst, [rip + target]`, i.e. loads the address of `target` into `dst`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::UnsupportedRegisterSize`] if `dst` is not 16, 32 or 64-bit wide.
+    pub fn lea(&mut self, dst: GPR, target: Label) -> Result<(), AssemblerError> {
+        match dst.size() {
+            Size::Bit16 => self.emit_rip_relative(target, |memory| safe::lea::encode_reg16_mem64(dst, memory)),
+            Size::Bit32 => self.emit_rip_relative(target, |memory| safe::lea::encode_reg32_mem64(dst, memory)),
+            Size::Bit64 => self.emit_rip_relative(target, |memory| safe::lea::encode_reg64_mem64(dst, memory)),
+            Size::Bit8 | Size::Bit128 | Size::Bit256 | Size::Bit512 => {
+                Err(AssemblerError::UnsupportedRegisterSize { gpr: dst })
+            }
+        }
+    }
+
+    /// Emits `mov dst, [rip + target]`, i.e. loads the value stored at `target` into `dst`.
+    /// The size of the load is the size of `dst`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::Encode`] if `dst` cannot be encoded and
+    /// [`AssemblerError::UnsupportedRegisterSize`] if `dst` is wider than 64 bits.
+    pub fn mov(&mut self, dst: GPR, target: Label) -> Result<(), AssemblerError> {
+        let encoder = match dst.size() {
+            Size::Bit8 => safe::mov::encode_reg8_rm8,
+            Size::Bit16 => safe::mov::encode_reg16_rm16,
+            Size::Bit32 => safe::mov::encode_reg32_rm32,
+            Size::Bit64 => safe::mov::encode_reg64_rm64,
+            Size::Bit128 | Size::Bit256 | Size::Bit512 => {
+                return Err(AssemblerError::UnsupportedRegisterSize { gpr: dst });
+            }
+        };
+        self.emit_rip_relative(target, |memory| encoder(dst, GPROrMemory::Memory { memory }))
+    }
+
+    /// Emits an arbitrary instruction with RIP-relative memory operand pointing
+    /// to `target`. The `encoder` receives the memory operand and has to encode
+    /// it verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::Encode`] if `encoder` fails and
+    /// [`AssemblerError::MissingDisplacement`] if `encoder` does not use
+    /// the passed memory operand.
+    pub fn emit_rip_relative<F>(&mut self, target: Label, encoder: F) -> Result<(), AssemblerError>
+    where
+        F: Fn(Memory) -> Result<EncodedX86_64Instruction, EncodeError>,
+    {
+        let zero = encoder(Memory::RelativeToRIP {
+            offset: Offset::from_i32(0),
+        })?;
+        let marker = encoder(Memory::RelativeToRIP {
+            offset: Offset::from_i32(-1),
+        })?;
+        self.emit_with_displacement(&zero, &marker, target)
+    }
+
+    /// Resolves all label references and returns the final code.
+    ///
+    /// Before that, branches to labels are relaxed: all of them start in the short
+    /// form, and those whose displacement does not fit into 8 bits are promoted
+    /// to the long form. Since promoting a branch moves the code after it, this
+    /// is repeated until no branch is promoted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::UnboundLabel`] if any referenced label is not
+    /// bound, [`AssemblerError::InvalidLabel`] if any referenced label was not
+    /// created by this assembler and [`AssemblerError::DisplacementOutOfRange`]
+    /// if the distance to a label does not fit into 32 bits.
+    pub fn finalize(self) -> Result<Vec<u8>, AssemblerError> {
+        Ok(self.finalize_with(false)?.code)
+    }
+
+    /// Same as [`Assembler::finalize`], except that labels which are referenced
+    /// but never bound are allowed. Those are treated as external symbols: the
+    /// displacements to them are left zeroed and reported as [`LabelReference`]s,
+    /// to be resolved by the linker, e.g. through [`ElfWriter`][crate::elf::ElfWriter].
+    /// Branches to such labels always use the long form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssemblerError::InvalidLabel`] if any referenced label was not
+    /// created by this assembler and [`AssemblerError::DisplacementOutOfRange`]
+    /// if the distance to a label does not fit into 32 bits.
+    pub fn finalize_relocatable(self) -> Result<RelocatableCode, AssemblerError> {
+        self.finalize_with(true)
+    }
+
+    fn finalize_with(self, allow_unbound: bool) -> Result<RelocatableCode, AssemblerError> {
+        let mut branch_targets = Vec::with_capacity(self.branches.len());
+        for branch in &self.branches {
+            branch_targets.push(self.referenced_position(branch.target, allow_unbound)?);
+        }
+
+        let mut is_long: Vec<bool> = branch_targets.iter().map(Option::is_none).collect();
+        let offsets = loop {
+            let offsets = self.branch_offsets(&is_long);
+            let mut changed = false;
+            for (index, branch) in self.branches.iter().enumerate() {
+                let Some(target) = branch_targets[index] else {
+                    continue;
+                };
+                if is_long[index] {
+                    continue;
+                }
+
+                let start = branch.position + offsets[index];
+                let end = start + branch.kind.length(false);
+                let target = resolve(target, &offsets);
+                if relative_displacement(end, target).is_none_or(|value| i8::try_from(value).is_err()) {
+                    is_long[index] = true;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break offsets;
+            }
+        };
+
+        let total_length = self.code.len() + offsets[self.branches.len()];
+        let mut result = Vec::with_capacity(total_length);
+        let mut references = Vec::new();
+        let mut code_position = 0;
+        for (index, branch) in self.branches.iter().enumerate() {
+            result.extend_from_slice(&self.code[code_position..branch.position]);
+            code_position = branch.position;
+
+            let end = result.len() + branch.kind.length(is_long[index]);
+            let displacement = if let Some(target) = branch_targets[index] {
+                relative_displacement(end, resolve(target, &offsets))
+                    .ok_or(AssemblerError::DisplacementOutOfRange { label: branch.target })?
+            } else {
+                references.push(LabelReference {
+                    offset: end - 4,
+                    relative_to: end,
+                    label: branch.target,
+                });
+                0
+            };
+            let instruction = if is_long[index] {
+                branch.kind.encode_long(Immediate32::from_i32(displacement))
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let displacement = displacement as i8;
+                branch.kind.encode_short(Immediate8::from_i8(displacement))
+            };
+            result.extend_from_slice(instruction.as_slice());
+        }
+        result.extend_from_slice(&self.code[code_position..]);
+
+        for fixup in &self.fixups {
+            let position = resolve(fixup.position, &offsets);
+            let relative_to = resolve(fixup.relative_to, &offsets);
+            let Some(target) = self.referenced_position(fixup.target, allow_unbound)? else {
+                references.push(LabelReference {
+                    offset: position,
+                    relative_to,
+                    label: fixup.target,
+                });
+                continue;
+            };
+            let displacement = relative_displacement(relative_to, resolve(target, &offsets))
+                .ok_or(AssemblerError::DisplacementOutOfRange { label: fixup.target })?;
+            result[position..position + 4].copy_from_slice(&displacement.to_le_bytes());
+        }
+        references.sort_by_key(|reference| reference.offset);
+
+        let labels = self
+            .labels
+            .iter()
+            .map(|position| position.map(|position| resolve(position, &offsets)))
+            .collect();
+        Ok(RelocatableCode {
+            code: result,
+            labels,
+            references,
+        })
+    }
+
+    /// Calculates by how many bytes the code is moved by the branches,
+    /// i.e. the `n`-th element is the total length of the first `n` branches.
+    fn branch_offsets(&self, is_long: &[bool]) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(self.branches.len() + 1);
+        let mut total = 0;
+        offsets.push(total);
+        for (branch, is_long) in self.branches.iter().zip(is_long) {
+            total += branch.kind.length(*is_long);
+            offsets.push(total);
+        }
+        offsets
+    }
+
+    #[inline]
+    fn current_position(&self) -> CodePosition {
+        CodePosition {
+            position: self.code.len(),
+            branches: self.branches.len(),
+        }
+    }
+
+    /// Returns the position of referenced `label`, or `None` if it is unbound
+    /// and `allow_unbound` is set.
+    fn referenced_position(&self, label: Label, allow_unbound: bool) -> Result<Option<CodePosition>, AssemblerError> {
+        match self.labels.get(label.index()) {
+            None => Err(AssemblerError::InvalidLabel { label }),
+            Some(None) if allow_unbound => Ok(None),
+            Some(None) => Err(AssemblerError::UnboundLabel { label }),
+            Some(Some(position)) => Ok(Some(*position)),
+        }
+    }
+
+    #[inline]
+    fn emit_branch(&mut self, kind: BranchKind, target: Label) {
+        self.branches.push(Branch {
+            position: self.code.len(),
+            kind,
+            target,
+        });
+    }
+
+    /// Emits `instruction` which ends with a rel32 displacement to `target`.
+    fn emit_rel32_at_end(&mut self, instruction: EncodedX86_64Instruction, target: Label) {
+        self.emit(instruction);
+        let relative_to = self.current_position();
+        self.fixups.push(Fixup {
+            position: CodePosition {
+                position: relative_to.position - 4,
+                branches: relative_to.branches,
+            },
+            relative_to,
+            target,
+        });
+    }
+
+    /// Emits `zero` instruction, with a fixup at the position of its displacement.
+    /// The position is found by comparing `zero` against `marker`, which is the same
+    /// instruction encoded with displacement `-1`.
+    fn emit_with_displacement(
+        &mut self,
+        zero: &EncodedX86_64Instruction,
+        marker: &EncodedX86_64Instruction,
+        target: Label,
+    ) -> Result<(), AssemblerError> {
+        let offset = find_displacement(zero.as_slice(), marker.as_slice()).ok_or(AssemblerError::MissingDisplacement)?;
+        let start = self.current_position();
+        self.code.extend_from_slice(zero.as_slice());
+        self.fixups.push(Fixup {
+            position: CodePosition {
+                position: start.position + offset,
+                branches: start.branches,
+            },
+            relative_to: self.current_position(),
+            target,
+        });
+        Ok(())
+    }
+}
+
+/// Finds the offset of the 32-bit displacement, which is all zeros in `zero`
+/// and all ones in `marker`. All other bytes have to match.
+fn find_displacement(zero: &[u8], marker: &[u8]) -> Option<usize> {
+    if zero.len() != marker.len() {
+        return None;
+    }
+
+    let offset = zero.iter().zip(marker).position(|(left, right)| left != right)?;
+    let end = offset + 4;
+    if end > zero.len()
+        || zero[offset..end] != [0x00; 4]
+        || marker[offset..end] != [0xFF; 4]
+        || zero[end..] != marker[end..]
+    {
+        return None;
+    }
+
+    Some(offset)
+}
+
+/// Calculates the final position in the code, given the offsets
+/// calculated by [`Assembler::branch_offsets`].
+#[inline(always)]
+fn resolve(position: CodePosition, offsets: &[usize]) -> usize {
+    position.position + offsets[position.branches]
+}
+
+#[inline]
+fn relative_displacement(from: usize, to: usize) -> Option<i32> {
+    let from = i64::try_from(from).ok()?;
+    let to = i64::try_from(to).ok()?;
+    i32::try_from(to - from).ok()
+}
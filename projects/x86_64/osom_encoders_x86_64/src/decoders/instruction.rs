@@ -0,0 +1,10630 @@
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Operand, Operands, Opmask, OpmaskOrMemory, Rounding, Size, VSIBMemory, XMM,
+    XMMOrMemory, YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+
+/// Represents a single `X86_64` instruction, as returned by [`decode`][super::decode].
+/// Each variant corresponds to exactly one encoder and holds its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum Instruction {
+    /// Lock prefix used for atomic operations.
+    Lock,
+
+    /// No operation.
+    Nop,
+
+    /// CPU identification.
+    Cpuid,
+
+    /// Fast call to privilege level 0 system procedures.
+    Sysenter,
+
+    /// Fast call to privilege level 0 system procedures. New variant of sysenter for 64-bit mode.
+    Syscall,
+
+    /// Return to calling procedure.
+    Ret,
+
+    /// Pop [16-bit immediate] bytes from stack and return to calling procedure.
+    RetImm16 { imm16: Immediate16 },
+
+    /// Move 8-bit immediate to 8-bit register or memory.
+    MovRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Move 16-bit immediate to 16-bit register or memory.
+    MovRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Move 32-bit immediate to 32-bit register or memory.
+    MovRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Move 32-bit immediate to 64-bit register or memory, sign extended.
+    MovRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Move 8-bit immediate to 8-bit register.
+    MovReg8Imm8 { reg8: GPR, imm8: Immediate8 },
+
+    /// Move 16-bit immediate to 16-bit register.
+    MovReg16Imm16 { reg16: GPR, imm16: Immediate16 },
+
+    /// Move 32-bit immediate to 32-bit register.
+    MovReg32Imm32 { reg32: GPR, imm32: Immediate32 },
+
+    /// Move 64-bit immediate to 64-bit register.
+    MovReg64Imm64 { reg64: GPR, imm64: Immediate64 },
+
+    /// Move 8-bit register to 8-bit register or memory.
+    MovRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Move 16-bit register to 16-bit register or memory.
+    MovRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Move 32-bit register to 32-bit register or memory.
+    MovRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Move 64-bit register to 64-bit register or memory.
+    MovRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Move 8-bit register or memory to 8-bit register.
+    MovReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register.
+    MovReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register.
+    MovReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register.
+    MovReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Add 8-bit immediate to AL register.
+    AddAlImm8 { imm8: Immediate8 },
+
+    /// Add 16-bit immediate to AX register.
+    AddAxImm16 { imm16: Immediate16 },
+
+    /// Add 32-bit immediate to EAX register.
+    AddEaxImm32 { imm32: Immediate32 },
+
+    /// Add 32-bit immediate to RAX register (sign-extended to 64 bits).
+    AddRaxImm32 { imm32: Immediate32 },
+
+    /// Add 8-bit immediate to 8-bit register or memory.
+    AddRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 16-bit immediate to 16-bit register or memory.
+    AddRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Add 16-bit immediate to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Add 32-bit immediate to 32-bit register or memory.
+    AddRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Add 32-bit immediate to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Add 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits).
+    AddRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Add 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Add 8-bit immediate (sign-extended) to 16-bit register or memory.
+    AddRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate (sign-extended) to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate (sign-extended) to 32-bit register or memory.
+    AddRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate (sign-extended) to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate (sign-extended) to 64-bit register or memory.
+    AddRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit immediate (sign-extended) to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Add 8-bit register to 8-bit register or memory.
+    AddRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Add 8-bit register to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Add 16-bit register to 16-bit register or memory.
+    AddRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Add 16-bit register to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Add 32-bit register to 32-bit register or memory.
+    AddRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Add 32-bit register to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Add 64-bit register to 64-bit register or memory.
+    AddRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Add 64-bit register to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AddLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Add 8-bit register or memory to 8-bit register.
+    AddReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Add 16-bit register or memory to 16-bit register.
+    AddReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Add 32-bit register or memory to 32-bit register.
+    AddReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Add 64-bit register or memory to 64-bit register.
+    AddReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Short jump to RIP-relative address. Takes 2 bytes only.
+    JmpImm8 { imm8: Immediate8 },
+
+    /// Long jump to RIP-relative address. Takes 5 bytes.
+    JmpImm32 { imm32: Immediate32 },
+
+    /// Jump to 64-bit register or memory.
+    JmpRm64 { rm64: GPROrMemory },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above (in unsigned sense).
+    JccAImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above Or Equal (in unsigned sense).
+    JccAeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below (in unsigned sense).
+    JccBImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below Or Equal (in unsigned sense).
+    JccBeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Equal.
+    JccEImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater (in signed sense).
+    JccGImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater Or Equal (in signed sense).
+    JccGeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less (in signed sense).
+    JccLImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less Or Equal (in signed sense).
+    JccLeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Equal.
+    JccNeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Overflow.
+    JccNoImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Parity.
+    JccNpImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Sign.
+    JccNsImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Overflow.
+    JccOImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity.
+    JccPImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Even.
+    JccPeImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Odd.
+    JccPoImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Sign.
+    JccSImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Carry.
+    JccCImm8 { imm8: Immediate8 },
+
+    /// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Carry.
+    JccNcImm8 { imm8: Immediate8 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above (in unsigned sense).
+    JccAImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above Or Equal (in unsigned sense).
+    JccAeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below (in unsigned sense).
+    JccBImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below Or Equal (in unsigned sense).
+    JccBeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Equal.
+    JccEImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater (in signed sense).
+    JccGImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater Or Equal (in signed sense).
+    JccGeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less (in signed sense).
+    JccLImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less Or Equal (in signed sense).
+    JccLeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Equal.
+    JccNeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Overflow.
+    JccNoImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Parity.
+    JccNpImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Sign.
+    JccNsImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Overflow.
+    JccOImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity.
+    JccPImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Even.
+    JccPeImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Odd.
+    JccPoImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Sign.
+    JccSImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Carry.
+    JccCImm32 { imm32: Immediate32 },
+
+    /// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Carry.
+    JccNcImm32 { imm32: Immediate32 },
+
+    /// Call to RIP-relative address.
+    CallImm32 { imm32: Immediate32 },
+
+    /// Call to address in 64-bit register or memory.
+    CallRm64 { rm64: GPROrMemory },
+
+    /// Compare 8-bit immediate with AL register.
+    CmpAlImm8 { imm8: Immediate8 },
+
+    /// Compare 16-bit immediate with AX register.
+    CmpAxImm16 { imm16: Immediate16 },
+
+    /// Compare 32-bit immediate with EAX register.
+    CmpEaxImm32 { imm32: Immediate32 },
+
+    /// Compare 32-bit immediate sign-extended with RAX register.
+    CmpRaxImm32 { imm32: Immediate32 },
+
+    /// Compare 8-bit immediate with 8-bit register or memory.
+    CmpRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Compare 16-bit immediate with 16-bit register or memory.
+    CmpRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Compare 32-bit immediate with 32-bit register or memory.
+    CmpRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Compare 32-bit immediate with 64-bit register or memory.
+    CmpRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Compare 8-bit immediate (sign-extended) with 16-bit register or memory.
+    CmpRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Compare 8-bit immediate (sign-extended) with 32-bit register or memory.
+    CmpRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Compare 8-bit immediate (sign-extended) with 64-bit register or memory.
+    CmpRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Compare 8-bit register with 8-bit register or memory.
+    CmpRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Compare 16-bit register with 16-bit register or memory.
+    CmpRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Compare 32-bit register with 32-bit register or memory.
+    CmpRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Compare 64-bit register with 64-bit register or memory.
+    CmpRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Compare 8-bit register or memory with 8-bit register.
+    CmpReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Compare 16-bit register or memory with 16-bit register.
+    CmpReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Compare 32-bit register or memory with 32-bit register.
+    CmpReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Compare 64-bit register or memory with 64-bit register.
+    CmpReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Load effective address from memory into 16-bit register.
+    LeaReg16Mem64 { reg16: GPR, mem64: Memory },
+
+    /// Load effective address from memory into 32-bit register.
+    LeaReg32Mem64 { reg32: GPR, mem64: Memory },
+
+    /// Load effective address from memory into 64-bit register.
+    LeaReg64Mem64 { reg64: GPR, mem64: Memory },
+
+    /// Push 64-bit register or memory onto the stack.
+    PushRm64 { rm64: GPROrMemory },
+
+    /// Push 64-bit register onto the stack.
+    PushReg64 { reg64: GPR },
+
+    /// Push 8-bit immediate onto the stack (sign-extended).
+    PushImm8 { imm8: Immediate8 },
+
+    /// Push 16-bit immediate onto the stack (sign-extended).
+    PushImm16 { imm16: Immediate16 },
+
+    /// Push 32-bit immediate onto the stack (sign-extended).
+    PushImm32 { imm32: Immediate32 },
+
+    /// Pop 64-bit value from the stack into register or memory.
+    PopRm64 { rm64: GPROrMemory },
+
+    /// Pop 64-bit value from the stack into register.
+    PopReg64 { reg64: GPR },
+
+    /// Subtract 8-bit immediate from AL register.
+    SubAlImm8 { imm8: Immediate8 },
+
+    /// Subtract 16-bit immediate from AX register.
+    SubAxImm16 { imm16: Immediate16 },
+
+    /// Subtract 32-bit immediate from EAX register.
+    SubEaxImm32 { imm32: Immediate32 },
+
+    /// Subtract 32-bit immediate from RAX register (sign-extended to 64 bits).
+    SubRaxImm32 { imm32: Immediate32 },
+
+    /// Subtract 8-bit immediate from 8-bit register or memory.
+    SubRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 16-bit immediate from 16-bit register or memory.
+    SubRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Subtract 16-bit immediate from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Subtract 32-bit immediate from 32-bit register or memory.
+    SubRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract 32-bit immediate from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits).
+    SubRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 16-bit register or memory.
+    SubRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 32-bit register or memory.
+    SubRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 64-bit register or memory.
+    SubRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit immediate (sign-extended) from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract 8-bit register from 8-bit register or memory.
+    SubRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Subtract 8-bit register from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Subtract 16-bit register from 16-bit register or memory.
+    SubRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Subtract 16-bit register from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Subtract 32-bit register from 32-bit register or memory.
+    SubRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Subtract 32-bit register from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Subtract 64-bit register from 64-bit register or memory.
+    SubRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Subtract 64-bit register from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SubLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Subtract 8-bit register or memory from 8-bit register.
+    SubReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Subtract 16-bit register or memory from 16-bit register.
+    SubReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Subtract 32-bit register or memory from 32-bit register.
+    SubReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Subtract 64-bit register or memory from 64-bit register.
+    SubReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Bitwise XOR 8-bit immediate with AL register.
+    XorAlImm8 { imm8: Immediate8 },
+
+    /// Bitwise XOR 16-bit immediate with AX register.
+    XorAxImm16 { imm16: Immediate16 },
+
+    /// Bitwise XOR 32-bit immediate with EAX register.
+    XorEaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise XOR 32-bit immediate with RAX register (sign-extended to 64 bits).
+    XorRaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise XOR 8-bit immediate with 8-bit register or memory.
+    XorRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 16-bit immediate with 16-bit register or memory.
+    XorRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise XOR 16-bit immediate with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise XOR 32-bit immediate with 32-bit register or memory.
+    XorRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise XOR 32-bit immediate with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise XOR 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits).
+    XorRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise XOR 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 16-bit register or memory.
+    XorRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 32-bit register or memory.
+    XorRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 64-bit register or memory.
+    XorRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit immediate (sign-extended) with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise XOR 8-bit register with 8-bit register or memory.
+    XorRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise XOR 8-bit register with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise XOR 16-bit register with 16-bit register or memory.
+    XorRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise XOR 16-bit register with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise XOR 32-bit register with 32-bit register or memory.
+    XorRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise XOR 32-bit register with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise XOR 64-bit register with 64-bit register or memory.
+    XorRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise XOR 64-bit register with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XorLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise XOR 8-bit register or memory with 8-bit register.
+    XorReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Bitwise XOR 16-bit register or memory with 16-bit register.
+    XorReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Bitwise XOR 32-bit register or memory with 32-bit register.
+    XorReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Bitwise XOR 64-bit register or memory with 64-bit register.
+    XorReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Generate software interrupt with vector specified by immediate byte.
+    IntImm8 { imm8: Immediate8 },
+
+    /// Generate debug trap. More or less equivalent to `int 1`.
+    Int1,
+
+    /// Generate breakpoint trap. More or less equivalent to `int 3`.
+    Int3,
+
+    /// Rotate 8-bit register or memory left once.
+    RolRm8One { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory left once.
+    RolRm16One { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory left once.
+    RolRm32One { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory left once.
+    RolRm64One { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory left by 8-bit immediate.
+    RolRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 16-bit register or memory left by 8-bit immediate.
+    RolRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 32-bit register or memory left by 8-bit immediate.
+    RolRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 64-bit register or memory left by 8-bit immediate.
+    RolRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 8-bit register or memory left by CL register.
+    RolRm8Cl { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory left by CL register.
+    RolRm16Cl { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory left by CL register.
+    RolRm32Cl { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory left by CL register.
+    RolRm64Cl { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory right once.
+    RorRm8One { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory right once.
+    RorRm16One { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory right once.
+    RorRm32One { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory right once.
+    RorRm64One { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory right by 8-bit immediate.
+    RorRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 16-bit register or memory right by 8-bit immediate.
+    RorRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 32-bit register or memory right by 8-bit immediate.
+    RorRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 64-bit register or memory right by 8-bit immediate.
+    RorRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 8-bit register or memory right by CL register.
+    RorRm8Cl { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory right by CL register.
+    RorRm16Cl { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory right by CL register.
+    RorRm32Cl { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory right by CL register.
+    RorRm64Cl { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory and carry flag left once.
+    RclRm8One { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory and carry flag left once.
+    RclRm16One { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory and carry flag left once.
+    RclRm32One { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory and carry flag left once.
+    RclRm64One { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory and carry flag left by 8-bit immediate.
+    RclRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 16-bit register or memory and carry flag left by 8-bit immediate.
+    RclRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 32-bit register or memory and carry flag left by 8-bit immediate.
+    RclRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 64-bit register or memory and carry flag left by 8-bit immediate.
+    RclRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 8-bit register or memory and carry flag left by CL register.
+    RclRm8Cl { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory and carry flag left by CL register.
+    RclRm16Cl { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory and carry flag left by CL register.
+    RclRm32Cl { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory and carry flag left by CL register.
+    RclRm64Cl { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory and carry flag right once.
+    RcrRm8One { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory and carry flag right once.
+    RcrRm16One { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory and carry flag right once.
+    RcrRm32One { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory and carry flag right once.
+    RcrRm64One { rm64: GPROrMemory },
+
+    /// Rotate 8-bit register or memory and carry flag right by 8-bit immediate.
+    RcrRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 16-bit register or memory and carry flag right by 8-bit immediate.
+    RcrRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 32-bit register or memory and carry flag right by 8-bit immediate.
+    RcrRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 64-bit register or memory and carry flag right by 8-bit immediate.
+    RcrRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Rotate 8-bit register or memory and carry flag right by CL register.
+    RcrRm8Cl { rm8: GPROrMemory },
+
+    /// Rotate 16-bit register or memory and carry flag right by CL register.
+    RcrRm16Cl { rm16: GPROrMemory },
+
+    /// Rotate 32-bit register or memory and carry flag right by CL register.
+    RcrRm32Cl { rm32: GPROrMemory },
+
+    /// Rotate 64-bit register or memory and carry flag right by CL register.
+    RcrRm64Cl { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory left once.
+    ShlRm8One { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory left once.
+    ShlRm16One { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory left once.
+    ShlRm32One { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory left once.
+    ShlRm64One { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory left by 8-bit immediate.
+    ShlRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 16-bit register or memory left by 8-bit immediate.
+    ShlRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 32-bit register or memory left by 8-bit immediate.
+    ShlRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 64-bit register or memory left by 8-bit immediate.
+    ShlRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 8-bit register or memory left by CL register.
+    ShlRm8Cl { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory left by CL register.
+    ShlRm16Cl { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory left by CL register.
+    ShlRm32Cl { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory left by CL register.
+    ShlRm64Cl { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory right (unsigned) once.
+    ShrRm8One { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory right (unsigned) once.
+    ShrRm16One { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory right (unsigned) once.
+    ShrRm32One { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory right (unsigned) once.
+    ShrRm64One { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory right (unsigned) by 8-bit immediate.
+    ShrRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 16-bit register or memory right (unsigned) by 8-bit immediate.
+    ShrRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 32-bit register or memory right (unsigned) by 8-bit immediate.
+    ShrRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 64-bit register or memory right (unsigned) by 8-bit immediate.
+    ShrRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 8-bit register or memory right (unsigned) by CL register.
+    ShrRm8Cl { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory right (unsigned) by CL register.
+    ShrRm16Cl { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory right (unsigned) by CL register.
+    ShrRm32Cl { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory right (unsigned) by CL register.
+    ShrRm64Cl { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory right (signed) once.
+    SarRm8One { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory right (signed) once.
+    SarRm16One { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory right (signed) once.
+    SarRm32One { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory right (signed) once.
+    SarRm64One { rm64: GPROrMemory },
+
+    /// Shift 8-bit register or memory right (signed) by 8-bit immediate.
+    SarRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 16-bit register or memory right (signed) by 8-bit immediate.
+    SarRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 32-bit register or memory right (signed) by 8-bit immediate.
+    SarRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 64-bit register or memory right (signed) by 8-bit immediate.
+    SarRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Shift 8-bit register or memory right (signed) by CL register.
+    SarRm8Cl { rm8: GPROrMemory },
+
+    /// Shift 16-bit register or memory right (signed) by CL register.
+    SarRm16Cl { rm16: GPROrMemory },
+
+    /// Shift 32-bit register or memory right (signed) by CL register.
+    SarRm32Cl { rm32: GPROrMemory },
+
+    /// Shift 64-bit register or memory right (signed) by CL register.
+    SarRm64Cl { rm64: GPROrMemory },
+
+    /// Unsigned multiply AL by 8-bit register or memory (AX = AL * r/m8).
+    MulRm8 { rm8: GPROrMemory },
+
+    /// Unsigned multiply AX by 16-bit register or memory (DX:AX = AX * r/m16).
+    MulRm16 { rm16: GPROrMemory },
+
+    /// Unsigned multiply EAX by 32-bit register or memory (EDX:EAX = EAX * r/m32).
+    MulRm32 { rm32: GPROrMemory },
+
+    /// Unsigned multiply RAX by 64-bit register or memory (RDX:RAX = RAX * r/m64).
+    MulRm64 { rm64: GPROrMemory },
+
+    /// Signed multiply AL by 8-bit register or memory (AX = AL * r/m8).
+    ImulRm8 { rm8: GPROrMemory },
+
+    /// Signed multiply AX by 16-bit register or memory (DX:AX = AX * r/m16).
+    ImulRm16 { rm16: GPROrMemory },
+
+    /// Signed multiply EAX by 32-bit register or memory (EDX:EAX = EAX * r/m32).
+    ImulRm32 { rm32: GPROrMemory },
+
+    /// Signed multiply RAX by 64-bit register or memory (RDX:RAX = RAX * r/m64).
+    ImulRm64 { rm64: GPROrMemory },
+
+    /// Signed multiply 16-bit register by 16-bit register or memory.
+    ImulReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Signed multiply 32-bit register by 32-bit register or memory.
+    ImulReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Signed multiply 64-bit register by 64-bit register or memory.
+    ImulReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Signed multiply 16-bit register or memory by 8-bit immediate (sign-extended), storing the result in 16-bit register.
+    ImulReg16Rm16Imm8 {
+        reg16: GPR,
+        rm16: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Signed multiply 32-bit register or memory by 8-bit immediate (sign-extended), storing the result in 32-bit register.
+    ImulReg32Rm32Imm8 {
+        reg32: GPR,
+        rm32: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Signed multiply 64-bit register or memory by 8-bit immediate (sign-extended), storing the result in 64-bit register.
+    ImulReg64Rm64Imm8 {
+        reg64: GPR,
+        rm64: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Signed multiply 16-bit register or memory by 16-bit immediate, storing the result in 16-bit register.
+    ImulReg16Rm16Imm16 {
+        reg16: GPR,
+        rm16: GPROrMemory,
+        imm16: Immediate16,
+    },
+
+    /// Signed multiply 32-bit register or memory by 32-bit immediate, storing the result in 32-bit register.
+    ImulReg32Rm32Imm32 {
+        reg32: GPR,
+        rm32: GPROrMemory,
+        imm32: Immediate32,
+    },
+
+    /// Signed multiply 64-bit register or memory by 32-bit immediate (sign-extended), storing the result in 64-bit register.
+    ImulReg64Rm64Imm32 {
+        reg64: GPR,
+        rm64: GPROrMemory,
+        imm32: Immediate32,
+    },
+
+    /// Unsigned divide AX by 8-bit register or memory (AL = quotient, AH = remainder).
+    DivRm8 { rm8: GPROrMemory },
+
+    /// Unsigned divide DX:AX by 16-bit register or memory (AX = quotient, DX = remainder).
+    DivRm16 { rm16: GPROrMemory },
+
+    /// Unsigned divide EDX:EAX by 32-bit register or memory (EAX = quotient, EDX = remainder).
+    DivRm32 { rm32: GPROrMemory },
+
+    /// Unsigned divide RDX:RAX by 64-bit register or memory (RAX = quotient, RDX = remainder).
+    DivRm64 { rm64: GPROrMemory },
+
+    /// Signed divide AX by 8-bit register or memory (AL = quotient, AH = remainder).
+    IdivRm8 { rm8: GPROrMemory },
+
+    /// Signed divide DX:AX by 16-bit register or memory (AX = quotient, DX = remainder).
+    IdivRm16 { rm16: GPROrMemory },
+
+    /// Signed divide EDX:EAX by 32-bit register or memory (EAX = quotient, EDX = remainder).
+    IdivRm32 { rm32: GPROrMemory },
+
+    /// Signed divide RDX:RAX by 64-bit register or memory (RAX = quotient, RDX = remainder).
+    IdivRm64 { rm64: GPROrMemory },
+
+    /// Bitwise AND 8-bit immediate with AL register.
+    AndAlImm8 { imm8: Immediate8 },
+
+    /// Bitwise AND 16-bit immediate with AX register.
+    AndAxImm16 { imm16: Immediate16 },
+
+    /// Bitwise AND 32-bit immediate with EAX register.
+    AndEaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with RAX register (sign-extended to 64 bits).
+    AndRaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise AND 8-bit immediate with 8-bit register or memory.
+    AndRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 16-bit immediate with 16-bit register or memory.
+    AndRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise AND 16-bit immediate with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise AND 32-bit immediate with 32-bit register or memory.
+    AndRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits).
+    AndRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 16-bit register or memory.
+    AndRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 32-bit register or memory.
+    AndRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 64-bit register or memory.
+    AndRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit immediate (sign-extended) with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 8-bit register with 8-bit register or memory.
+    AndRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise AND 8-bit register with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise AND 16-bit register with 16-bit register or memory.
+    AndRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise AND 16-bit register with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise AND 32-bit register with 32-bit register or memory.
+    AndRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise AND 32-bit register with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise AND 64-bit register with 64-bit register or memory.
+    AndRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise AND 64-bit register with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AndLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise AND 8-bit register or memory with 8-bit register.
+    AndReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Bitwise AND 16-bit register or memory with 16-bit register.
+    AndReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Bitwise AND 32-bit register or memory with 32-bit register.
+    AndReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Bitwise AND 64-bit register or memory with 64-bit register.
+    AndReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Bitwise OR 8-bit immediate with AL register.
+    OrAlImm8 { imm8: Immediate8 },
+
+    /// Bitwise OR 16-bit immediate with AX register.
+    OrAxImm16 { imm16: Immediate16 },
+
+    /// Bitwise OR 32-bit immediate with EAX register.
+    OrEaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise OR 32-bit immediate with RAX register (sign-extended to 64 bits).
+    OrRaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise OR 8-bit immediate with 8-bit register or memory.
+    OrRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 16-bit immediate with 16-bit register or memory.
+    OrRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise OR 16-bit immediate with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise OR 32-bit immediate with 32-bit register or memory.
+    OrRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise OR 32-bit immediate with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise OR 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits).
+    OrRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise OR 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 16-bit register or memory.
+    OrRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 32-bit register or memory.
+    OrRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 64-bit register or memory.
+    OrRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit immediate (sign-extended) with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise OR 8-bit register with 8-bit register or memory.
+    OrRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise OR 8-bit register with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise OR 16-bit register with 16-bit register or memory.
+    OrRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise OR 16-bit register with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise OR 32-bit register with 32-bit register or memory.
+    OrRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise OR 32-bit register with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise OR 64-bit register with 64-bit register or memory.
+    OrRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise OR 64-bit register with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    OrLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise OR 8-bit register or memory with 8-bit register.
+    OrReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Bitwise OR 16-bit register or memory with 16-bit register.
+    OrReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Bitwise OR 32-bit register or memory with 32-bit register.
+    OrReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Bitwise OR 64-bit register or memory with 64-bit register.
+    OrReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Add with carry 8-bit immediate to AL register.
+    AdcAlImm8 { imm8: Immediate8 },
+
+    /// Add with carry 16-bit immediate to AX register.
+    AdcAxImm16 { imm16: Immediate16 },
+
+    /// Add with carry 32-bit immediate to EAX register.
+    AdcEaxImm32 { imm32: Immediate32 },
+
+    /// Add with carry 32-bit immediate to RAX register (sign-extended to 64 bits).
+    AdcRaxImm32 { imm32: Immediate32 },
+
+    /// Add with carry 8-bit immediate to 8-bit register or memory.
+    AdcRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 16-bit immediate to 16-bit register or memory.
+    AdcRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Add with carry 16-bit immediate to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Add with carry 32-bit immediate to 32-bit register or memory.
+    AdcRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Add with carry 32-bit immediate to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits).
+    AdcRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory.
+    AdcRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory.
+    AdcRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory.
+    AdcRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Add with carry 8-bit register to 8-bit register or memory.
+    AdcRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Add with carry 8-bit register to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Add with carry 16-bit register to 16-bit register or memory.
+    AdcRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Add with carry 16-bit register to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Add with carry 32-bit register to 32-bit register or memory.
+    AdcRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Add with carry 32-bit register to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Add with carry 64-bit register to 64-bit register or memory.
+    AdcRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Add with carry 64-bit register to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    AdcLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Add with carry 8-bit register or memory to 8-bit register.
+    AdcReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Add with carry 16-bit register or memory to 16-bit register.
+    AdcReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Add with carry 32-bit register or memory to 32-bit register.
+    AdcReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Add with carry 64-bit register or memory to 64-bit register.
+    AdcReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Subtract with borrow 8-bit immediate from AL register.
+    SbbAlImm8 { imm8: Immediate8 },
+
+    /// Subtract with borrow 16-bit immediate from AX register.
+    SbbAxImm16 { imm16: Immediate16 },
+
+    /// Subtract with borrow 32-bit immediate from EAX register.
+    SbbEaxImm32 { imm32: Immediate32 },
+
+    /// Subtract with borrow 32-bit immediate from RAX register (sign-extended to 64 bits).
+    SbbRaxImm32 { imm32: Immediate32 },
+
+    /// Subtract with borrow 8-bit immediate from 8-bit register or memory.
+    SbbRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 16-bit immediate from 16-bit register or memory.
+    SbbRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Subtract with borrow 16-bit immediate from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Subtract with borrow 32-bit immediate from 32-bit register or memory.
+    SbbRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract with borrow 32-bit immediate from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract with borrow 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits).
+    SbbRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract with borrow 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 16-bit register or memory.
+    SbbRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 32-bit register or memory.
+    SbbRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 64-bit register or memory.
+    SbbRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit immediate (sign-extended) from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Subtract with borrow 8-bit register from 8-bit register or memory.
+    SbbRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Subtract with borrow 8-bit register from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Subtract with borrow 16-bit register from 16-bit register or memory.
+    SbbRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Subtract with borrow 16-bit register from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Subtract with borrow 32-bit register from 32-bit register or memory.
+    SbbRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Subtract with borrow 32-bit register from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Subtract with borrow 64-bit register from 64-bit register or memory.
+    SbbRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Subtract with borrow 64-bit register from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    SbbLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Subtract with borrow 8-bit register or memory from 8-bit register.
+    SbbReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Subtract with borrow 16-bit register or memory from 16-bit register.
+    SbbReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Subtract with borrow 32-bit register or memory from 32-bit register.
+    SbbReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Subtract with borrow 64-bit register or memory from 64-bit register.
+    SbbReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Bitwise AND 8-bit immediate with AL register, setting flags only.
+    TestAlImm8 { imm8: Immediate8 },
+
+    /// Bitwise AND 16-bit immediate with AX register, setting flags only.
+    TestAxImm16 { imm16: Immediate16 },
+
+    /// Bitwise AND 32-bit immediate with EAX register, setting flags only.
+    TestEaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with RAX register (sign-extended to 64 bits), setting flags only.
+    TestRaxImm32 { imm32: Immediate32 },
+
+    /// Bitwise AND 8-bit immediate with 8-bit register or memory, setting flags only.
+    TestRm8Imm8 { rm8: GPROrMemory, imm8: Immediate8 },
+
+    /// Bitwise AND 16-bit immediate with 16-bit register or memory, setting flags only.
+    TestRm16Imm16 { rm16: GPROrMemory, imm16: Immediate16 },
+
+    /// Bitwise AND 32-bit immediate with 32-bit register or memory, setting flags only.
+    TestRm32Imm32 { rm32: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 32-bit immediate with 64-bit register or memory (sign-extended to 64 bits), setting flags only.
+    TestRm64Imm32 { rm64: GPROrMemory, imm32: Immediate32 },
+
+    /// Bitwise AND 8-bit register with 8-bit register or memory, setting flags only.
+    TestRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Bitwise AND 16-bit register with 16-bit register or memory, setting flags only.
+    TestRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Bitwise AND 32-bit register with 32-bit register or memory, setting flags only.
+    TestRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Bitwise AND 64-bit register with 64-bit register or memory, setting flags only.
+    TestRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Bitwise NOT (one's complement) of 8-bit register or memory.
+    NotRm8 { rm8: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NotLockRm8 { rm8: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 16-bit register or memory.
+    NotRm16 { rm16: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NotLockRm16 { rm16: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 32-bit register or memory.
+    NotRm32 { rm32: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NotLockRm32 { rm32: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 64-bit register or memory.
+    NotRm64 { rm64: GPROrMemory },
+
+    /// Bitwise NOT (one's complement) of 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NotLockRm64 { rm64: GPROrMemory },
+
+    /// Negate (two's complement) 8-bit register or memory.
+    NegRm8 { rm8: GPROrMemory },
+
+    /// Negate (two's complement) 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NegLockRm8 { rm8: GPROrMemory },
+
+    /// Negate (two's complement) 16-bit register or memory.
+    NegRm16 { rm16: GPROrMemory },
+
+    /// Negate (two's complement) 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NegLockRm16 { rm16: GPROrMemory },
+
+    /// Negate (two's complement) 32-bit register or memory.
+    NegRm32 { rm32: GPROrMemory },
+
+    /// Negate (two's complement) 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NegLockRm32 { rm32: GPROrMemory },
+
+    /// Negate (two's complement) 64-bit register or memory.
+    NegRm64 { rm64: GPROrMemory },
+
+    /// Negate (two's complement) 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    NegLockRm64 { rm64: GPROrMemory },
+
+    /// Increment 8-bit register or memory by 1.
+    IncRm8 { rm8: GPROrMemory },
+
+    /// Increment 8-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    IncLockRm8 { rm8: GPROrMemory },
+
+    /// Increment 16-bit register or memory by 1.
+    IncRm16 { rm16: GPROrMemory },
+
+    /// Increment 16-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    IncLockRm16 { rm16: GPROrMemory },
+
+    /// Increment 32-bit register or memory by 1.
+    IncRm32 { rm32: GPROrMemory },
+
+    /// Increment 32-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    IncLockRm32 { rm32: GPROrMemory },
+
+    /// Increment 64-bit register or memory by 1.
+    IncRm64 { rm64: GPROrMemory },
+
+    /// Increment 64-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    IncLockRm64 { rm64: GPROrMemory },
+
+    /// Decrement 8-bit register or memory by 1.
+    DecRm8 { rm8: GPROrMemory },
+
+    /// Decrement 8-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    DecLockRm8 { rm8: GPROrMemory },
+
+    /// Decrement 16-bit register or memory by 1.
+    DecRm16 { rm16: GPROrMemory },
+
+    /// Decrement 16-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    DecLockRm16 { rm16: GPROrMemory },
+
+    /// Decrement 32-bit register or memory by 1.
+    DecRm32 { rm32: GPROrMemory },
+
+    /// Decrement 32-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    DecLockRm32 { rm32: GPROrMemory },
+
+    /// Decrement 64-bit register or memory by 1.
+    DecRm64 { rm64: GPROrMemory },
+
+    /// Decrement 64-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    DecLockRm64 { rm64: GPROrMemory },
+
+    /// Move 8-bit register or memory to 16-bit register with zero extension.
+    MovzxReg16Rm8 { reg16: GPR, rm8: GPROrMemory },
+
+    /// Move 8-bit register or memory to 32-bit register with zero extension.
+    MovzxReg32Rm8 { reg32: GPR, rm8: GPROrMemory },
+
+    /// Move 8-bit register or memory to 64-bit register with zero extension.
+    MovzxReg64Rm8 { reg64: GPR, rm8: GPROrMemory },
+
+    /// Move 16-bit register or memory to 32-bit register with zero extension.
+    MovzxReg32Rm16 { reg32: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 64-bit register with zero extension.
+    MovzxReg64Rm16 { reg64: GPR, rm16: GPROrMemory },
+
+    /// Move 8-bit register or memory to 16-bit register with sign extension.
+    MovsxReg16Rm8 { reg16: GPR, rm8: GPROrMemory },
+
+    /// Move 8-bit register or memory to 32-bit register with sign extension.
+    MovsxReg32Rm8 { reg32: GPR, rm8: GPROrMemory },
+
+    /// Move 8-bit register or memory to 64-bit register with sign extension.
+    MovsxReg64Rm8 { reg64: GPR, rm8: GPROrMemory },
+
+    /// Move 16-bit register or memory to 32-bit register with sign extension.
+    MovsxReg32Rm16 { reg32: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 64-bit register with sign extension.
+    MovsxReg64Rm16 { reg64: GPR, rm16: GPROrMemory },
+
+    /// Move 32-bit register or memory to 64-bit register with sign extension.
+    MovsxdReg64Rm32 { reg64: GPR, rm32: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Above (in unsigned sense).
+    SetccARm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Above Or Equal (in unsigned sense).
+    SetccAeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Below (in unsigned sense).
+    SetccBRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Below Or Equal (in unsigned sense).
+    SetccBeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Equal.
+    SetccERm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Greater (in signed sense).
+    SetccGRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Greater Or Equal (in signed sense).
+    SetccGeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Less (in signed sense).
+    SetccLRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Less Or Equal (in signed sense).
+    SetccLeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Not Equal.
+    SetccNeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: No Overflow.
+    SetccNoRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: No Parity.
+    SetccNpRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: No Sign.
+    SetccNsRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Overflow.
+    SetccORm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Parity.
+    SetccPRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Parity Even.
+    SetccPeRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Parity Odd.
+    SetccPoRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Sign.
+    SetccSRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Carry.
+    SetccCRm8 { rm8: GPROrMemory },
+
+    /// Set 8-bit register or memory to 1 if condition is met, 0 otherwise. Condition: Not Carry.
+    SetccNcRm8 { rm8: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Above (in unsigned sense).
+    CmovccAReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Above Or Equal (in unsigned sense).
+    CmovccAeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Below (in unsigned sense).
+    CmovccBReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Below Or Equal (in unsigned sense).
+    CmovccBeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Equal.
+    CmovccEReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Greater (in signed sense).
+    CmovccGReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Greater Or Equal (in signed sense).
+    CmovccGeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Less (in signed sense).
+    CmovccLReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Less Or Equal (in signed sense).
+    CmovccLeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Not Equal.
+    CmovccNeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: No Overflow.
+    CmovccNoReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: No Parity.
+    CmovccNpReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: No Sign.
+    CmovccNsReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Overflow.
+    CmovccOReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Parity.
+    CmovccPReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Parity Even.
+    CmovccPeReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Parity Odd.
+    CmovccPoReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Sign.
+    CmovccSReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Carry.
+    CmovccCReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 16-bit register or memory to 16-bit register if condition is met. Condition: Not Carry.
+    CmovccNcReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Above (in unsigned sense).
+    CmovccAReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Above Or Equal (in unsigned sense).
+    CmovccAeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Below (in unsigned sense).
+    CmovccBReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Below Or Equal (in unsigned sense).
+    CmovccBeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Equal.
+    CmovccEReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Greater (in signed sense).
+    CmovccGReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Greater Or Equal (in signed sense).
+    CmovccGeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Less (in signed sense).
+    CmovccLReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Less Or Equal (in signed sense).
+    CmovccLeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Not Equal.
+    CmovccNeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: No Overflow.
+    CmovccNoReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: No Parity.
+    CmovccNpReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: No Sign.
+    CmovccNsReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Overflow.
+    CmovccOReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Parity.
+    CmovccPReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Parity Even.
+    CmovccPeReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Parity Odd.
+    CmovccPoReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Sign.
+    CmovccSReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Carry.
+    CmovccCReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 32-bit register or memory to 32-bit register if condition is met. Condition: Not Carry.
+    CmovccNcReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Above (in unsigned sense).
+    CmovccAReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Above Or Equal (in unsigned sense).
+    CmovccAeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Below (in unsigned sense).
+    CmovccBReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Below Or Equal (in unsigned sense).
+    CmovccBeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Equal.
+    CmovccEReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Greater (in signed sense).
+    CmovccGReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Greater Or Equal (in signed sense).
+    CmovccGeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Less (in signed sense).
+    CmovccLReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Less Or Equal (in signed sense).
+    CmovccLeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Not Equal.
+    CmovccNeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: No Overflow.
+    CmovccNoReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: No Parity.
+    CmovccNpReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: No Sign.
+    CmovccNsReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Overflow.
+    CmovccOReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Parity.
+    CmovccPReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Parity Even.
+    CmovccPeReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Parity Odd.
+    CmovccPoReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Sign.
+    CmovccSReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Carry.
+    CmovccCReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move 64-bit register or memory to 64-bit register if condition is met. Condition: Not Carry.
+    CmovccNcReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Exchange 8-bit register with 8-bit register or memory.
+    XchgRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Exchange 8-bit register with 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XchgLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Exchange 16-bit register with 16-bit register or memory.
+    XchgRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Exchange 16-bit register with 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XchgLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Exchange 32-bit register with 32-bit register or memory.
+    XchgRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Exchange 32-bit register with 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XchgLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Exchange 64-bit register with 64-bit register or memory.
+    XchgRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Exchange 64-bit register with 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XchgLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Exchange 8-bit register or memory with 8-bit register (memory form is implicitly locked).
+    XchgReg8Rm8 { reg8: GPR, rm8: GPROrMemory },
+
+    /// Exchange 16-bit register or memory with 16-bit register (memory form is implicitly locked).
+    XchgReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Exchange 32-bit register or memory with 32-bit register (memory form is implicitly locked).
+    XchgReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Exchange 64-bit register or memory with 64-bit register (memory form is implicitly locked).
+    XchgReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Compare AL with 8-bit register or memory. If equal, load 8-bit register into register or memory, otherwise load register or memory into AL.
+    CmpxchgRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Compare AL with 8-bit register or memory. If equal, load 8-bit register into register or memory, otherwise load register or memory into AL. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    CmpxchgLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Compare AX with 16-bit register or memory. If equal, load 16-bit register into register or memory, otherwise load register or memory into AX.
+    CmpxchgRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Compare AX with 16-bit register or memory. If equal, load 16-bit register into register or memory, otherwise load register or memory into AX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    CmpxchgLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Compare EAX with 32-bit register or memory. If equal, load 32-bit register into register or memory, otherwise load register or memory into EAX.
+    CmpxchgRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Compare EAX with 32-bit register or memory. If equal, load 32-bit register into register or memory, otherwise load register or memory into EAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    CmpxchgLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Compare RAX with 64-bit register or memory. If equal, load 64-bit register into register or memory, otherwise load register or memory into RAX.
+    CmpxchgRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Compare RAX with 64-bit register or memory. If equal, load 64-bit register into register or memory, otherwise load register or memory into RAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    CmpxchgLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX.
+    Cmpxchg8bMem64 { mem64: Memory },
+
+    /// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    Cmpxchg8bLockMem64 { mem64: Memory },
+
+    /// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX.
+    Cmpxchg16bMem128 { mem128: Memory },
+
+    /// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    Cmpxchg16bLockMem128 { mem128: Memory },
+
+    /// Exchange 8-bit register and 8-bit register or memory, then load their sum into register or memory.
+    XaddRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Exchange 8-bit register and 8-bit register or memory, then load their sum into register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XaddLockRm8Reg8 { rm8: GPROrMemory, reg8: GPR },
+
+    /// Exchange 16-bit register and 16-bit register or memory, then load their sum into register or memory.
+    XaddRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Exchange 16-bit register and 16-bit register or memory, then load their sum into register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XaddLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Exchange 32-bit register and 32-bit register or memory, then load their sum into register or memory.
+    XaddRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Exchange 32-bit register and 32-bit register or memory, then load their sum into register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XaddLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Exchange 64-bit register and 64-bit register or memory, then load their sum into register or memory.
+    XaddRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Exchange 64-bit register and 64-bit register or memory, then load their sum into register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    XaddLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Move byte from address RSI to address RDI.
+    MovsB,
+
+    /// Move word from address RSI to address RDI.
+    MovsW,
+
+    /// Move doubleword from address RSI to address RDI.
+    MovsD,
+
+    /// Move quadword from address RSI to address RDI.
+    MovsQ,
+
+    /// Move RCX bytes from address RSI to address RDI.
+    MovsRepB,
+
+    /// Move RCX words from address RSI to address RDI.
+    MovsRepW,
+
+    /// Move RCX doublewords from address RSI to address RDI.
+    MovsRepD,
+
+    /// Move RCX quadwords from address RSI to address RDI.
+    MovsRepQ,
+
+    /// Store AL at address RDI.
+    StosB,
+
+    /// Store AX at address RDI.
+    StosW,
+
+    /// Store EAX at address RDI.
+    StosD,
+
+    /// Store RAX at address RDI.
+    StosQ,
+
+    /// Fill RCX bytes at address RDI with AL.
+    StosRepB,
+
+    /// Fill RCX words at address RDI with AX.
+    StosRepW,
+
+    /// Fill RCX doublewords at address RDI with EAX.
+    StosRepD,
+
+    /// Fill RCX quadwords at address RDI with RAX.
+    StosRepQ,
+
+    /// Load byte at address RSI into AL.
+    LodsB,
+
+    /// Load word at address RSI into AX.
+    LodsW,
+
+    /// Load doubleword at address RSI into EAX.
+    LodsD,
+
+    /// Load quadword at address RSI into RAX.
+    LodsQ,
+
+    /// Load RCX bytes at address RSI into AL.
+    LodsRepB,
+
+    /// Load RCX words at address RSI into AX.
+    LodsRepW,
+
+    /// Load RCX doublewords at address RSI into EAX.
+    LodsRepD,
+
+    /// Load RCX quadwords at address RSI into RAX.
+    LodsRepQ,
+
+    /// Compare byte at address RSI with byte at address RDI and set the status flags.
+    CmpsB,
+
+    /// Compare word at address RSI with word at address RDI and set the status flags.
+    CmpsW,
+
+    /// Compare doubleword at address RSI with doubleword at address RDI and set the status flags.
+    CmpsD,
+
+    /// Compare quadword at address RSI with quadword at address RDI and set the status flags.
+    CmpsQ,
+
+    /// Find non-matching bytes in RCX bytes at addresses RSI and RDI.
+    CmpsRepeB,
+
+    /// Find non-matching words in RCX words at addresses RSI and RDI.
+    CmpsRepeW,
+
+    /// Find non-matching doublewords in RCX doublewords at addresses RSI and RDI.
+    CmpsRepeD,
+
+    /// Find non-matching quadwords in RCX quadwords at addresses RSI and RDI.
+    CmpsRepeQ,
+
+    /// Find matching bytes in RCX bytes at addresses RSI and RDI.
+    CmpsRepneB,
+
+    /// Find matching words in RCX words at addresses RSI and RDI.
+    CmpsRepneW,
+
+    /// Find matching doublewords in RCX doublewords at addresses RSI and RDI.
+    CmpsRepneD,
+
+    /// Find matching quadwords in RCX quadwords at addresses RSI and RDI.
+    CmpsRepneQ,
+
+    /// Compare AL with byte at address RDI and set the status flags.
+    ScasB,
+
+    /// Compare AX with word at address RDI and set the status flags.
+    ScasW,
+
+    /// Compare EAX with doubleword at address RDI and set the status flags.
+    ScasD,
+
+    /// Compare RAX with quadword at address RDI and set the status flags.
+    ScasQ,
+
+    /// Find non-AL byte in RCX bytes at address RDI.
+    ScasRepeB,
+
+    /// Find non-AX word in RCX words at address RDI.
+    ScasRepeW,
+
+    /// Find non-EAX doubleword in RCX doublewords at address RDI.
+    ScasRepeD,
+
+    /// Find non-RAX quadword in RCX quadwords at address RDI.
+    ScasRepeQ,
+
+    /// Find AL in RCX bytes at address RDI.
+    ScasRepneB,
+
+    /// Find AX in RCX words at address RDI.
+    ScasRepneW,
+
+    /// Find EAX in RCX doublewords at address RDI.
+    ScasRepneD,
+
+    /// Find RAX in RCX quadwords at address RDI.
+    ScasRepneQ,
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag.
+    BtRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag.
+    BtRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag.
+    BtRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag.
+    BtRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag.
+    BtRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag.
+    BtRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and set it.
+    BtsRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and set it.
+    BtsRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and set it.
+    BtsRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+    BtsRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+    BtsRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+    BtsRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtsLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and clear it.
+    BtrRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and clear it.
+    BtrRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and clear it.
+    BtrRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+    BtrRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+    BtrRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+    BtrRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtrLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and complement it.
+    BtcRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm16Reg16 { rm16: GPROrMemory, reg16: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and complement it.
+    BtcRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm32Reg32 { rm32: GPROrMemory, reg32: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and complement it.
+    BtcRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm64Reg64 { rm64: GPROrMemory, reg64: GPR },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and complement it.
+    BtcRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm16Imm8 { rm16: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and complement it.
+    BtcRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm32Imm8 { rm32: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and complement it.
+    BtcRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and complement it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+    BtcLockRm64Imm8 { rm64: GPROrMemory, imm8: Immediate8 },
+
+    /// Bit scan forward on 16-bit register or memory, i.e. load the index of the least significant set bit into 16-bit register.
+    BsfReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Bit scan forward on 32-bit register or memory, i.e. load the index of the least significant set bit into 32-bit register.
+    BsfReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Bit scan forward on 64-bit register or memory, i.e. load the index of the least significant set bit into 64-bit register.
+    BsfReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Bit scan reverse on 16-bit register or memory, i.e. load the index of the most significant set bit into 16-bit register.
+    BsrReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Bit scan reverse on 32-bit register or memory, i.e. load the index of the most significant set bit into 32-bit register.
+    BsrReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Bit scan reverse on 64-bit register or memory, i.e. load the index of the most significant set bit into 64-bit register.
+    BsrReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Reverse the byte order of 32-bit register.
+    BswapReg32 { reg32: GPR },
+
+    /// Reverse the byte order of 64-bit register.
+    BswapReg64 { reg64: GPR },
+
+    /// Load the number of set bits in 16-bit register or memory into 16-bit register.
+    PopcntReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Load the number of set bits in 32-bit register or memory into 32-bit register.
+    PopcntReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Load the number of set bits in 64-bit register or memory into 64-bit register.
+    PopcntReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Load the number of leading zero bits in 16-bit register or memory into 16-bit register.
+    LzcntReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Load the number of leading zero bits in 32-bit register or memory into 32-bit register.
+    LzcntReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Load the number of leading zero bits in 64-bit register or memory into 64-bit register.
+    LzcntReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Load the number of trailing zero bits in 16-bit register or memory into 16-bit register.
+    TzcntReg16Rm16 { reg16: GPR, rm16: GPROrMemory },
+
+    /// Load the number of trailing zero bits in 32-bit register or memory into 32-bit register.
+    TzcntReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Load the number of trailing zero bits in 64-bit register or memory into 64-bit register.
+    TzcntReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Bitwise AND of inverted 32-bit VEX register with 32-bit register or memory into 32-bit register.
+    AndnReg32Vreg32Rm32 { reg32: GPR, vreg32: GPR, rm32: GPROrMemory },
+
+    /// Bitwise AND of inverted 64-bit VEX register with 64-bit register or memory into 64-bit register.
+    AndnReg64Vreg64Rm64 { reg64: GPR, vreg64: GPR, rm64: GPROrMemory },
+
+    /// Extract bit field of 32-bit register or memory, specified by 32-bit VEX register, into 32-bit register.
+    BextrReg32Rm32Vreg32 { reg32: GPR, rm32: GPROrMemory, vreg32: GPR },
+
+    /// Extract bit field of 64-bit register or memory, specified by 64-bit VEX register, into 64-bit register.
+    BextrReg64Rm64Vreg64 { reg64: GPR, rm64: GPROrMemory, vreg64: GPR },
+
+    /// Extract lowest set bit of 32-bit register or memory into 32-bit VEX register.
+    BlsiVreg32Rm32 { vreg32: GPR, rm32: GPROrMemory },
+
+    /// Extract lowest set bit of 64-bit register or memory into 64-bit VEX register.
+    BlsiVreg64Rm64 { vreg64: GPR, rm64: GPROrMemory },
+
+    /// Set all lower bits up to lowest set bit of 32-bit register or memory into 32-bit VEX register.
+    BlsmskVreg32Rm32 { vreg32: GPR, rm32: GPROrMemory },
+
+    /// Set all lower bits up to lowest set bit of 64-bit register or memory into 64-bit VEX register.
+    BlsmskVreg64Rm64 { vreg64: GPR, rm64: GPROrMemory },
+
+    /// Reset lowest set bit of 32-bit register or memory into 32-bit VEX register.
+    BlsrVreg32Rm32 { vreg32: GPR, rm32: GPROrMemory },
+
+    /// Reset lowest set bit of 64-bit register or memory into 64-bit VEX register.
+    BlsrVreg64Rm64 { vreg64: GPR, rm64: GPROrMemory },
+
+    /// Zero high bits of 32-bit register or memory, starting from index in 32-bit VEX register, into 32-bit register.
+    BzhiReg32Rm32Vreg32 { reg32: GPR, rm32: GPROrMemory, vreg32: GPR },
+
+    /// Zero high bits of 64-bit register or memory, starting from index in 64-bit VEX register, into 64-bit register.
+    BzhiReg64Rm64Vreg64 { reg64: GPR, rm64: GPROrMemory, vreg64: GPR },
+
+    /// Parallel deposit of bits from 32-bit VEX register using mask in 32-bit register or memory into 32-bit register.
+    PdepReg32Vreg32Rm32 { reg32: GPR, vreg32: GPR, rm32: GPROrMemory },
+
+    /// Parallel deposit of bits from 64-bit VEX register using mask in 64-bit register or memory into 64-bit register.
+    PdepReg64Vreg64Rm64 { reg64: GPR, vreg64: GPR, rm64: GPROrMemory },
+
+    /// Parallel extract of bits from 32-bit VEX register using mask in 32-bit register or memory into 32-bit register.
+    PextReg32Vreg32Rm32 { reg32: GPR, vreg32: GPR, rm32: GPROrMemory },
+
+    /// Parallel extract of bits from 64-bit VEX register using mask in 64-bit register or memory into 64-bit register.
+    PextReg64Vreg64Rm64 { reg64: GPR, vreg64: GPR, rm64: GPROrMemory },
+
+    /// Unsigned multiply of implicit EDX by 32-bit register or memory, high half into 32-bit register and low half into 32-bit VEX register, without affecting flags.
+    MulxReg32Vreg32Rm32 { reg32: GPR, vreg32: GPR, rm32: GPROrMemory },
+
+    /// Unsigned multiply of implicit RDX by 64-bit register or memory, high half into 64-bit register and low half into 64-bit VEX register, without affecting flags.
+    MulxReg64Vreg64Rm64 { reg64: GPR, vreg64: GPR, rm64: GPROrMemory },
+
+    /// Rotate 32-bit register or memory right by 8-bit immediate into 32-bit register, without affecting flags.
+    RorxReg32Rm32Imm8 {
+        reg32: GPR,
+        rm32: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Rotate 64-bit register or memory right by 8-bit immediate into 64-bit register, without affecting flags.
+    RorxReg64Rm64Imm8 {
+        reg64: GPR,
+        rm64: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Shift 32-bit register or memory arithmetically right by 32-bit VEX register into 32-bit register, without affecting flags.
+    SarxReg32Rm32Vreg32 { reg32: GPR, rm32: GPROrMemory, vreg32: GPR },
+
+    /// Shift 64-bit register or memory arithmetically right by 64-bit VEX register into 64-bit register, without affecting flags.
+    SarxReg64Rm64Vreg64 { reg64: GPR, rm64: GPROrMemory, vreg64: GPR },
+
+    /// Shift 32-bit register or memory left by 32-bit VEX register into 32-bit register, without affecting flags.
+    ShlxReg32Rm32Vreg32 { reg32: GPR, rm32: GPROrMemory, vreg32: GPR },
+
+    /// Shift 64-bit register or memory left by 64-bit VEX register into 64-bit register, without affecting flags.
+    ShlxReg64Rm64Vreg64 { reg64: GPR, rm64: GPROrMemory, vreg64: GPR },
+
+    /// Shift 32-bit register or memory logically right by 32-bit VEX register into 32-bit register, without affecting flags.
+    ShrxReg32Rm32Vreg32 { reg32: GPR, rm32: GPROrMemory, vreg32: GPR },
+
+    /// Shift 64-bit register or memory logically right by 64-bit VEX register into 64-bit register, without affecting flags.
+    ShrxReg64Rm64Vreg64 { reg64: GPR, rm64: GPROrMemory, vreg64: GPR },
+
+    /// Unsigned add 32-bit register or memory with carry flag into 32-bit register, affecting only carry flag.
+    AdcxReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Unsigned add 64-bit register or memory with carry flag into 64-bit register, affecting only carry flag.
+    AdcxReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Unsigned add 32-bit register or memory with overflow flag into 32-bit register, affecting only overflow flag.
+    AdoxReg32Rm32 { reg32: GPR, rm32: GPROrMemory },
+
+    /// Unsigned add 64-bit register or memory with overflow flag into 64-bit register, affecting only overflow flag.
+    AdoxReg64Rm64 { reg64: GPR, rm64: GPROrMemory },
+
+    /// Move scalar single-precision value from XMM register or 32-bit memory into XMM register.
+    MovssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Move scalar single-precision value from XMM register into XMM register or 32-bit memory.
+    MovssXmmm32Xmm { xmmm32: XMMOrMemory, xmm: XMM },
+
+    /// Move scalar double-precision value from XMM register or 64-bit memory into XMM register.
+    MovsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Move scalar double-precision value from XMM register into XMM register or 64-bit memory.
+    MovsdXmmm64Xmm { xmmm64: XMMOrMemory, xmm: XMM },
+
+    /// Add scalar single-precision value in XMM register or 32-bit memory to XMM register.
+    AddssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Add scalar double-precision value in XMM register or 64-bit memory to XMM register.
+    AddsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Subtract scalar single-precision value in XMM register or 32-bit memory from XMM register.
+    SubssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Subtract scalar double-precision value in XMM register or 64-bit memory from XMM register.
+    SubsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply XMM register by scalar single-precision value in XMM register or 32-bit memory.
+    MulssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply XMM register by scalar double-precision value in XMM register or 64-bit memory.
+    MulsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Divide scalar single-precision value in XMM register by XMM register or 32-bit memory.
+    DivssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Divide scalar double-precision value in XMM register by XMM register or 64-bit memory.
+    DivsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Compute square root of scalar single-precision value in XMM register or 32-bit memory into XMM register.
+    SqrtssXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Compute square root of scalar double-precision value in XMM register or 64-bit memory into XMM register.
+    SqrtsdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Compare scalar single-precision values in XMM register and XMM register or 32-bit memory (unordered), setting flags.
+    UcomissXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Compare scalar double-precision values in XMM register and XMM register or 64-bit memory (unordered), setting flags.
+    UcomisdXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Convert signed 32-bit register or memory into scalar double-precision value in XMM register.
+    Cvtsi2sdXmmRm32 { xmm: XMM, rm32: GPROrMemory },
+
+    /// Convert signed 64-bit register or memory into scalar double-precision value in XMM register.
+    Cvtsi2sdXmmRm64 { xmm: XMM, rm64: GPROrMemory },
+
+    /// Convert scalar double-precision value in XMM register or 64-bit memory into signed 32-bit register, with truncation.
+    Cvttsd2siReg32Xmmm64 { reg32: GPR, xmmm64: XMMOrMemory },
+
+    /// Convert scalar double-precision value in XMM register or 64-bit memory into signed 64-bit register, with truncation.
+    Cvttsd2siReg64Xmmm64 { reg64: GPR, xmmm64: XMMOrMemory },
+
+    /// Move 32-bit register or memory into XMM register, zeroing upper bits.
+    MovdXmmRm32 { xmm: XMM, rm32: GPROrMemory },
+
+    /// Move low 32 bits of XMM register into 32-bit register or memory.
+    MovdRm32Xmm { rm32: GPROrMemory, xmm: XMM },
+
+    /// Move 64-bit register or memory into XMM register, zeroing upper bits.
+    MovqXmmRm64 { xmm: XMM, rm64: GPROrMemory },
+
+    /// Move low 64 bits of XMM register into 64-bit register or memory.
+    MovqRm64Xmm { rm64: GPROrMemory, xmm: XMM },
+
+    /// Move aligned packed single-precision values from XMM register or 128-bit memory into XMM register.
+    MovapsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move aligned packed single-precision values from XMM register into XMM register or 128-bit memory.
+    MovapsXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move unaligned packed single-precision values from XMM register or 128-bit memory into XMM register.
+    MovupsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move unaligned packed single-precision values from XMM register into XMM register or 128-bit memory.
+    MovupsXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move aligned packed integer values from XMM register or 128-bit memory into XMM register.
+    MovdqaXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move aligned packed integer values from XMM register into XMM register or 128-bit memory.
+    MovdqaXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move unaligned packed integer values from XMM register or 128-bit memory into XMM register.
+    MovdquXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move unaligned packed integer values from XMM register into XMM register or 128-bit memory.
+    MovdquXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Add packed single-precision values in XMM register or 128-bit memory to XMM register.
+    AddpsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed double-precision values in XMM register or 128-bit memory to XMM register.
+    AddpdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values in XMM register by XMM register or 128-bit memory.
+    MulpsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values in XMM register by XMM register or 128-bit memory.
+    MulpdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed byte integers in XMM register or 128-bit memory to XMM register.
+    PaddbXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed word integers in XMM register or 128-bit memory to XMM register.
+    PaddwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed doubleword integers in XMM register or 128-bit memory to XMM register.
+    PadddXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed quadword integers in XMM register or 128-bit memory to XMM register.
+    PaddqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Subtract packed byte integers in XMM register or 128-bit memory from XMM register.
+    PsubbXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Subtract packed word integers in XMM register or 128-bit memory from XMM register.
+    PsubwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Subtract packed doubleword integers in XMM register or 128-bit memory from XMM register.
+    PsubdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Subtract packed quadword integers in XMM register or 128-bit memory from XMM register.
+    PsubqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise AND of XMM register and XMM register or 128-bit memory into XMM register.
+    PandXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise OR of XMM register and XMM register or 128-bit memory into XMM register.
+    PorXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise XOR of XMM register and XMM register or 128-bit memory into XMM register.
+    PxorXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed byte integers in XMM register and XMM register or 128-bit memory for equality, setting matching elements of XMM register to all ones.
+    PcmpeqbXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed word integers in XMM register and XMM register or 128-bit memory for equality, setting matching elements of XMM register to all ones.
+    PcmpeqwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed doubleword integers in XMM register and XMM register or 128-bit memory for equality, setting matching elements of XMM register to all ones.
+    PcmpeqdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed quadword integers in XMM register and XMM register or 128-bit memory for equality, setting matching elements of XMM register to all ones.
+    PcmpeqqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed signed byte integers in XMM register and XMM register or 128-bit memory for greater than, setting matching elements of XMM register to all ones.
+    PcmpgtbXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed signed word integers in XMM register and XMM register or 128-bit memory for greater than, setting matching elements of XMM register to all ones.
+    PcmpgtwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed signed doubleword integers in XMM register and XMM register or 128-bit memory for greater than, setting matching elements of XMM register to all ones.
+    PcmpgtdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Compare packed signed quadword integers in XMM register and XMM register or 128-bit memory for greater than, setting matching elements of XMM register to all ones.
+    PcmpgtqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Shuffle doublewords in XMM register or 128-bit memory into XMM register, as selected by 8-bit immediate.
+    PshufdXmmXmmm128Imm8 {
+        xmm: XMM,
+        xmmm128: XMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Shuffle bytes in XMM register, as selected by XMM register or 128-bit memory.
+    PshufbXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave low-order bytes of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpcklbwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave low-order words of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpcklwdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave low-order doublewords of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpckldqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave low-order quadwords of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpcklqdqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave high-order bytes of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpckhbwXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave high-order words of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpckhwdXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave high-order doublewords of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpckhdqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Interleave high-order quadwords of XMM register and XMM register or 128-bit memory into XMM register.
+    PunpckhqdqXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move mask made of the most significant bits of bytes in XMM register into 32-bit register.
+    PmovmskbReg32Xmm { reg32: GPR, xmm: XMM },
+
+    /// Insert byte from 32-bit register or 8-bit memory into XMM register, at position selected by 8-bit immediate.
+    PinsrbXmmR32m8Imm8 {
+        xmm: XMM,
+        r32m8: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Insert word from 32-bit register or 16-bit memory into XMM register, at position selected by 8-bit immediate.
+    PinsrwXmmR32m16Imm8 {
+        xmm: XMM,
+        r32m16: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Insert doubleword from 32-bit register or memory into XMM register, at position selected by 8-bit immediate.
+    PinsrdXmmRm32Imm8 {
+        xmm: XMM,
+        rm32: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Insert quadword from 64-bit register or memory into XMM register, at position selected by 8-bit immediate.
+    PinsrqXmmRm64Imm8 {
+        xmm: XMM,
+        rm64: GPROrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Extract byte selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 8-bit memory.
+    PextrbR32m8XmmImm8 {
+        r32m8: GPROrMemory,
+        xmm: XMM,
+        imm8: Immediate8,
+    },
+
+    /// Extract word selected by 8-bit immediate from XMM register into 32-bit register, zero extended.
+    PextrwReg32XmmImm8 { reg32: GPR, xmm: XMM, imm8: Immediate8 },
+
+    /// Extract word selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 16-bit memory.
+    PextrwR32m16XmmImm8 {
+        r32m16: GPROrMemory,
+        xmm: XMM,
+        imm8: Immediate8,
+    },
+
+    /// Extract doubleword selected by 8-bit immediate from XMM register into 32-bit register or memory.
+    PextrdRm32XmmImm8 {
+        rm32: GPROrMemory,
+        xmm: XMM,
+        imm8: Immediate8,
+    },
+
+    /// Extract quadword selected by 8-bit immediate from XMM register into 64-bit register or memory.
+    PextrqRm64XmmImm8 {
+        rm64: GPROrMemory,
+        xmm: XMM,
+        imm8: Immediate8,
+    },
+
+    /// Select packed single-precision values from XMM register and XMM register or 128-bit memory, as specified by the sign bits in implicit XMM0 register.
+    BlendvpsXmmXmmm128Xmm0 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Select packed double-precision values from XMM register and XMM register or 128-bit memory, as specified by the sign bits in implicit XMM0 register.
+    BlendvpdXmmXmmm128Xmm0 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Select bytes from XMM register and XMM register or 128-bit memory, as specified by the sign bits in implicit XMM0 register.
+    PblendvbXmmXmmm128Xmm0 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Set zero flag if bitwise AND of XMM register and XMM register or 128-bit memory is all zeros, and carry flag if bitwise AND NOT is all zeros.
+    PtestXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed single-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VaddpsXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed single-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VaddpsYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VaddpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Add packed single-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+    VaddpsZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Add packed double-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VaddpdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed double-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VaddpdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VaddpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Add packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+    VaddpdZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VmulpsXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VmulpsYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VmulpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Multiply packed single-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+    VmulpsZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VmulpdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VmulpdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VmulpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Multiply packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+    VmulpdZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Add packed bytes of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpaddbXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed bytes of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpaddbYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed words of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpaddwXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed words of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpaddwYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed doublewords of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpadddXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed doublewords of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpadddYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VpadddZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Add packed quadwords of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpaddqXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Add packed quadwords of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpaddqYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Add packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VpaddqZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Bitwise AND of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpandXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise AND of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpandYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Bitwise OR of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VporXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise OR of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VporYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Bitwise XOR of XMM VEX register and XMM register or 128-bit memory into XMM register.
+    VpxorXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Bitwise XOR of YMM VEX register and YMM register or 256-bit memory into YMM register.
+    VpxorYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Shuffle bytes of XMM VEX register and XMM register or 128-bit memory, within 128-bit lanes, into XMM register.
+    VpshufbXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Shuffle bytes of YMM VEX register and YMM register or 256-bit memory, within 128-bit lanes, into YMM register.
+    VpshufbYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Permute doublewords of YMM register or 256-bit memory using indexes from YMM VEX register into YMM register.
+    VpermdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Permute single-precision values of YMM register or 256-bit memory using indexes from YMM VEX register into YMM register.
+    VpermpsYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Broadcast byte of XMM register or 8-bit memory to all elements of XMM register.
+    VpbroadcastbXmmXmmm8 { xmm: XMM, xmmm8: XMMOrMemory },
+
+    /// Broadcast byte of XMM register or 8-bit memory to all elements of YMM register.
+    VpbroadcastbYmmXmmm8 { ymm: YMM, xmmm8: XMMOrMemory },
+
+    /// Broadcast word of XMM register or 16-bit memory to all elements of XMM register.
+    VpbroadcastwXmmXmmm16 { xmm: XMM, xmmm16: XMMOrMemory },
+
+    /// Broadcast word of XMM register or 16-bit memory to all elements of YMM register.
+    VpbroadcastwYmmXmmm16 { ymm: YMM, xmmm16: XMMOrMemory },
+
+    /// Broadcast doubleword of XMM register or 32-bit memory to all elements of XMM register.
+    VpbroadcastdXmmXmmm32 { xmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Broadcast doubleword of XMM register or 32-bit memory to all elements of YMM register.
+    VpbroadcastdYmmXmmm32 { ymm: YMM, xmmm32: XMMOrMemory },
+
+    /// Broadcast quadword of XMM register or 64-bit memory to all elements of XMM register.
+    VpbroadcastqXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Broadcast quadword of XMM register or 64-bit memory to all elements of YMM register.
+    VpbroadcastqYmmXmmm64 { ymm: YMM, xmmm64: XMMOrMemory },
+
+    /// Move aligned packed single-precision values from XMM register or 128-bit memory to XMM register.
+    VmovapsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move aligned packed single-precision values from XMM register to XMM register or 128-bit memory.
+    VmovapsXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move aligned packed single-precision values from YMM register or 256-bit memory to YMM register.
+    VmovapsYmmYmmm256 { ymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Move aligned packed single-precision values from YMM register to YMM register or 256-bit memory.
+    VmovapsYmmm256Ymm { ymmm256: YMMOrMemory, ymm: YMM },
+
+    /// Move unaligned packed single-precision values from XMM register or 128-bit memory to XMM register.
+    VmovupsXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move unaligned packed single-precision values from XMM register to XMM register or 128-bit memory.
+    VmovupsXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move unaligned packed single-precision values from YMM register or 256-bit memory to YMM register.
+    VmovupsYmmYmmm256 { ymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Move unaligned packed single-precision values from YMM register to YMM register or 256-bit memory.
+    VmovupsYmmm256Ymm { ymmm256: YMMOrMemory, ymm: YMM },
+
+    /// Move unaligned packed single-precision values from ZMM register or 512-bit memory to ZMM register with write masking.
+    VmovupsZmmMaskZmmm512 {
+        zmm: ZMM,
+        mask: Masking,
+        zmmm512: ZMMOrMemory,
+    },
+
+    /// Move unaligned packed single-precision values from ZMM register to ZMM register or 512-bit memory with merge masking.
+    VmovupsZmmm512MergemaskZmm {
+        zmmm512: ZMMOrMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Move aligned packed integer values from XMM register or 128-bit memory to XMM register.
+    VmovdqaXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move aligned packed integer values from XMM register to XMM register or 128-bit memory.
+    VmovdqaXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move aligned packed integer values from YMM register or 256-bit memory to YMM register.
+    VmovdqaYmmYmmm256 { ymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Move aligned packed integer values from YMM register to YMM register or 256-bit memory.
+    VmovdqaYmmm256Ymm { ymmm256: YMMOrMemory, ymm: YMM },
+
+    /// Move unaligned packed integer values from XMM register or 128-bit memory to XMM register.
+    VmovdquXmmXmmm128 { xmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Move unaligned packed integer values from XMM register to XMM register or 128-bit memory.
+    VmovdquXmmm128Xmm { xmmm128: XMMOrMemory, xmm: XMM },
+
+    /// Move unaligned packed integer values from YMM register or 256-bit memory to YMM register.
+    VmovdquYmmYmmm256 { ymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Move unaligned packed integer values from YMM register to YMM register or 256-bit memory.
+    VmovdquYmmm256Ymm { ymmm256: YMMOrMemory, ymm: YMM },
+
+    /// Zero the upper 128 bits of all YMM registers.
+    Vzeroupper,
+
+    /// Subtract packed single-precision values of ZMM register, 512-bit memory or broadcast 32-bit memory from ZMM EVEX register into ZMM register with write masking.
+    VsubpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Subtract packed single-precision values of ZMM register from ZMM EVEX register into ZMM register with write masking and static rounding.
+    VsubpsZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Subtract packed double-precision values of ZMM register, 512-bit memory or broadcast 64-bit memory from ZMM EVEX register into ZMM register with write masking.
+    VsubpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Subtract packed double-precision values of ZMM register from ZMM EVEX register into ZMM register with write masking and static rounding.
+    VsubpdZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Divide packed single-precision values of ZMM EVEX register by ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VdivpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Divide packed single-precision values of ZMM EVEX register by ZMM register into ZMM register with write masking and static rounding.
+    VdivpsZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Divide packed double-precision values of ZMM EVEX register by ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VdivpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Divide packed double-precision values of ZMM EVEX register by ZMM register into ZMM register with write masking and static rounding.
+    VdivpdZmmMaskVzmmZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Select minimum packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VminpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Select minimum packed single-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and suppressed floating-point exceptions.
+    VminpsZmmMaskVzmmZmmrSae {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+    },
+
+    /// Select minimum packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VminpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Select minimum packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and suppressed floating-point exceptions.
+    VminpdZmmMaskVzmmZmmrSae {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+    },
+
+    /// Select maximum packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VmaxpsZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Select maximum packed single-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and suppressed floating-point exceptions.
+    VmaxpsZmmMaskVzmmZmmrSae {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+    },
+
+    /// Select maximum packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VmaxpdZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Select maximum packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and suppressed floating-point exceptions.
+    VmaxpdZmmMaskVzmmZmmrSae {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+    },
+
+    /// Compute square roots of packed single-precision values of ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VsqrtpsZmmMaskZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Compute square roots of packed single-precision values of ZMM register into ZMM register with write masking and static rounding.
+    VsqrtpsZmmMaskZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Compute square roots of packed double-precision values of ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VsqrtpdZmmMaskZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Compute square roots of packed double-precision values of ZMM register into ZMM register with write masking and static rounding.
+    VsqrtpdZmmMaskZmmrEr {
+        zmm: ZMM,
+        mask: Masking,
+        zmmr: ZMM,
+        er: Rounding,
+    },
+
+    /// Add low single-precision values of XMM EVEX register and XMM register or 32-bit memory into XMM register with write masking.
+    VaddssXmmMaskVxmmXmmm32 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm32: XMMOrMemory,
+    },
+
+    /// Add low single-precision values of XMM EVEX register and XMM register into XMM register with write masking and static rounding.
+    VaddssXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Add low double-precision values of XMM EVEX register and XMM register or 64-bit memory into XMM register with write masking.
+    VaddsdXmmMaskVxmmXmmm64 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm64: XMMOrMemory,
+    },
+
+    /// Add low double-precision values of XMM EVEX register and XMM register into XMM register with write masking and static rounding.
+    VaddsdXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Subtract low single-precision value of XMM register or 32-bit memory from XMM EVEX register into XMM register with write masking.
+    VsubssXmmMaskVxmmXmmm32 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm32: XMMOrMemory,
+    },
+
+    /// Subtract low single-precision value of XMM register from XMM EVEX register into XMM register with write masking and static rounding.
+    VsubssXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Subtract low double-precision value of XMM register or 64-bit memory from XMM EVEX register into XMM register with write masking.
+    VsubsdXmmMaskVxmmXmmm64 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm64: XMMOrMemory,
+    },
+
+    /// Subtract low double-precision value of XMM register from XMM EVEX register into XMM register with write masking and static rounding.
+    VsubsdXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Multiply low single-precision values of XMM EVEX register and XMM register or 32-bit memory into XMM register with write masking.
+    VmulssXmmMaskVxmmXmmm32 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm32: XMMOrMemory,
+    },
+
+    /// Multiply low single-precision values of XMM EVEX register and XMM register into XMM register with write masking and static rounding.
+    VmulssXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Multiply low double-precision values of XMM EVEX register and XMM register or 64-bit memory into XMM register with write masking.
+    VmulsdXmmMaskVxmmXmmm64 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm64: XMMOrMemory,
+    },
+
+    /// Multiply low double-precision values of XMM EVEX register and XMM register into XMM register with write masking and static rounding.
+    VmulsdXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Divide low single-precision value of XMM EVEX register by XMM register or 32-bit memory into XMM register with write masking.
+    VdivssXmmMaskVxmmXmmm32 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm32: XMMOrMemory,
+    },
+
+    /// Divide low single-precision value of XMM EVEX register by XMM register into XMM register with write masking and static rounding.
+    VdivssXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Divide low double-precision value of XMM EVEX register by XMM register or 64-bit memory into XMM register with write masking.
+    VdivsdXmmMaskVxmmXmmm64 {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmm64: XMMOrMemory,
+    },
+
+    /// Divide low double-precision value of XMM EVEX register by XMM register into XMM register with write masking and static rounding.
+    VdivsdXmmMaskVxmmXmmrEr {
+        xmm: XMM,
+        mask: Masking,
+        vxmm: XMM,
+        xmmr: XMM,
+        er: Rounding,
+    },
+
+    /// Subtract packed doublewords of ZMM register, 512-bit memory or broadcast 32-bit memory from ZMM EVEX register into ZMM register with write masking.
+    VpsubdZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Subtract packed quadwords of ZMM register, 512-bit memory or broadcast 64-bit memory from ZMM EVEX register into ZMM register with write masking.
+    VpsubqZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Multiply packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory, storing the low 32 bits of each product into ZMM register with write masking.
+    VpmulldZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Bitwise AND of packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VpanddZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Bitwise AND of packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VpandqZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Bitwise OR of packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VpordZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Bitwise OR of packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VporqZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Bitwise XOR of packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+    VpxordZmmMaskVzmmZmmm512b32 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Bitwise XOR of packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+    VpxorqZmmMaskVzmmZmmm512b64 {
+        zmm: ZMM,
+        mask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Move unaligned packed doublewords from ZMM register or 512-bit memory to ZMM register with write masking.
+    Vmovdqu32ZmmMaskZmmm512 {
+        zmm: ZMM,
+        mask: Masking,
+        zmmm512: ZMMOrMemory,
+    },
+
+    /// Move unaligned packed doublewords from ZMM register to ZMM register or 512-bit memory with merge masking.
+    Vmovdqu32Zmmm512MergemaskZmm {
+        zmmm512: ZMMOrMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Move unaligned packed quadwords from ZMM register or 512-bit memory to ZMM register with write masking.
+    Vmovdqu64ZmmMaskZmmm512 {
+        zmm: ZMM,
+        mask: Masking,
+        zmmm512: ZMMOrMemory,
+    },
+
+    /// Move unaligned packed quadwords from ZMM register to ZMM register or 512-bit memory with merge masking.
+    Vmovdqu64Zmmm512MergemaskZmm {
+        zmmm512: ZMMOrMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Compare packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VcmppsKMergemaskVzmmZmmm512b32Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed single-precision values of ZMM EVEX register and ZMM register, using predicate selected by 8-bit immediate, into opmask register with merge masking and suppressed floating-point exceptions.
+    VcmppsKMergemaskVzmmZmmrSaeImm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VcmppdKMergemaskVzmmZmmm512b64Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed double-precision values of ZMM EVEX register and ZMM register, using predicate selected by 8-bit immediate, into opmask register with merge masking and suppressed floating-point exceptions.
+    VcmppdKMergemaskVzmmZmmrSaeImm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmr: ZMM,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory for equality into opmask register with merge masking.
+    VpcmpeqdKMergemaskVzmmZmmm512b32 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Compare packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory for signed greater than into opmask register with merge masking.
+    VpcmpgtdKMergemaskVzmmZmmm512b32 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+    },
+
+    /// Compare packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory for equality into opmask register with merge masking.
+    VpcmpeqqKMergemaskVzmmZmmm512b64 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Compare packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory for signed greater than into opmask register with merge masking.
+    VpcmpgtqKMergemaskVzmmZmmm512b64 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+    },
+
+    /// Compare packed signed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VpcmpdKMergemaskVzmmZmmm512b32Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed unsigned doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VpcmpudKMergemaskVzmmZmmm512b32Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b32: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed signed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VpcmpqKMergemaskVzmmZmmm512b64Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Compare packed unsigned quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+    VpcmpuqKMergemaskVzmmZmmm512b64Imm8 {
+        k: Opmask,
+        mergemask: Masking,
+        vzmm: ZMM,
+        zmmm512b64: ZMMOrMemory,
+        imm8: Immediate8,
+    },
+
+    /// Move 16-bit opmask from opmask register or 16-bit memory to opmask register.
+    KmovwKKm16 { k: Opmask, km16: OpmaskOrMemory },
+
+    /// Move 16-bit opmask from opmask register to 16-bit memory.
+    KmovwMem16K { mem16: Memory, k: Opmask },
+
+    /// Move 16-bit opmask from 32-bit register to opmask register.
+    KmovwKReg32 { k: Opmask, reg32: GPR },
+
+    /// Move 16-bit opmask from opmask register to 32-bit register, zero-extending it.
+    KmovwReg32Kr { reg32: GPR, kr: Opmask },
+
+    /// Bitwise AND of 16-bit opmasks of opmask VEX register and opmask register into opmask register.
+    KandwKVkKr { k: Opmask, vk: Opmask, kr: Opmask },
+
+    /// Bitwise AND of 16-bit opmask of opmask register and inverted 16-bit opmask of opmask VEX register into opmask register.
+    KandnwKVkKr { k: Opmask, vk: Opmask, kr: Opmask },
+
+    /// Bitwise OR of 16-bit opmasks of opmask VEX register and opmask register into opmask register.
+    KorwKVkKr { k: Opmask, vk: Opmask, kr: Opmask },
+
+    /// Bitwise XNOR of 16-bit opmasks of opmask VEX register and opmask register into opmask register.
+    KxnorwKVkKr { k: Opmask, vk: Opmask, kr: Opmask },
+
+    /// Bitwise XOR of 16-bit opmasks of opmask VEX register and opmask register into opmask register.
+    KxorwKVkKr { k: Opmask, vk: Opmask, kr: Opmask },
+
+    /// Bitwise NOT of 16-bit opmask of opmask register into opmask register.
+    KnotwKKr { k: Opmask, kr: Opmask },
+
+    /// Set zero flag if bitwise OR of 16-bit opmasks of both opmask registers is all zeros, and carry flag if it is all ones.
+    KortestwKKr { k: Opmask, kr: Opmask },
+
+    /// Multiply packed single-precision values of XMM register and XMM register or 128-bit memory, add XMM VEX register and store into XMM register.
+    Vfmadd132psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM register and YMM register or 256-bit memory, add YMM VEX register and store into YMM register.
+    Vfmadd132psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM register and XMM register or 128-bit memory, add XMM VEX register and store into XMM register.
+    Vfmadd132pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM register and YMM register or 256-bit memory, add YMM VEX register and store into YMM register.
+    Vfmadd132pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM register and XMM register or 32-bit memory, add XMM VEX register and store into XMM register.
+    Vfmadd132ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM register and XMM register or 64-bit memory, add XMM VEX register and store into XMM register.
+    Vfmadd132sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register, add XMM register or 128-bit memory and store into XMM register.
+    Vfmadd213psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register, add YMM register or 256-bit memory and store into YMM register.
+    Vfmadd213psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register, add XMM register or 128-bit memory and store into XMM register.
+    Vfmadd213pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register, add YMM register or 256-bit memory and store into YMM register.
+    Vfmadd213pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register, add XMM register or 32-bit memory and store into XMM register.
+    Vfmadd213ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register, add XMM register or 64-bit memory and store into XMM register.
+    Vfmadd213sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register or 128-bit memory, add XMM register and store into XMM register.
+    Vfmadd231psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register or 256-bit memory, add YMM register and store into YMM register.
+    Vfmadd231psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register or 128-bit memory, add XMM register and store into XMM register.
+    Vfmadd231pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register or 256-bit memory, add YMM register and store into YMM register.
+    Vfmadd231pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register or 32-bit memory, add XMM register and store into XMM register.
+    Vfmadd231ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register or 64-bit memory, add XMM register and store into XMM register.
+    Vfmadd231sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM register and XMM register or 128-bit memory, subtract XMM VEX register and store into XMM register.
+    Vfmsub132psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM register and YMM register or 256-bit memory, subtract YMM VEX register and store into YMM register.
+    Vfmsub132psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM register and XMM register or 128-bit memory, subtract XMM VEX register and store into XMM register.
+    Vfmsub132pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM register and YMM register or 256-bit memory, subtract YMM VEX register and store into YMM register.
+    Vfmsub132pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM register and XMM register or 32-bit memory, subtract XMM VEX register and store into XMM register.
+    Vfmsub132ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM register and XMM register or 64-bit memory, subtract XMM VEX register and store into XMM register.
+    Vfmsub132sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register, subtract XMM register or 128-bit memory and store into XMM register.
+    Vfmsub213psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register, subtract YMM register or 256-bit memory and store into YMM register.
+    Vfmsub213psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register, subtract XMM register or 128-bit memory and store into XMM register.
+    Vfmsub213pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register, subtract YMM register or 256-bit memory and store into YMM register.
+    Vfmsub213pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register, subtract XMM register or 32-bit memory and store into XMM register.
+    Vfmsub213ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register, subtract XMM register or 64-bit memory and store into XMM register.
+    Vfmsub213sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register or 128-bit memory, subtract XMM register and store into XMM register.
+    Vfmsub231psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register or 256-bit memory, subtract YMM register and store into YMM register.
+    Vfmsub231psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register or 128-bit memory, subtract XMM register and store into XMM register.
+    Vfmsub231pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register or 256-bit memory, subtract YMM register and store into YMM register.
+    Vfmsub231pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register or 32-bit memory, subtract XMM register and store into XMM register.
+    Vfmsub231ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register or 64-bit memory, subtract XMM register and store into XMM register.
+    Vfmsub231sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM register and XMM register or 128-bit memory, negate the product, add XMM VEX register and store into XMM register.
+    Vfnmadd132psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM register and YMM register or 256-bit memory, negate the product, add YMM VEX register and store into YMM register.
+    Vfnmadd132psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM register and XMM register or 128-bit memory, negate the product, add XMM VEX register and store into XMM register.
+    Vfnmadd132pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM register and YMM register or 256-bit memory, negate the product, add YMM VEX register and store into YMM register.
+    Vfnmadd132pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM register and XMM register or 32-bit memory, negate the product, add XMM VEX register and store into XMM register.
+    Vfnmadd132ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM register and XMM register or 64-bit memory, negate the product, add XMM VEX register and store into XMM register.
+    Vfnmadd132sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register, negate the product, add XMM register or 128-bit memory and store into XMM register.
+    Vfnmadd213psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register, negate the product, add YMM register or 256-bit memory and store into YMM register.
+    Vfnmadd213psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register, negate the product, add XMM register or 128-bit memory and store into XMM register.
+    Vfnmadd213pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register, negate the product, add YMM register or 256-bit memory and store into YMM register.
+    Vfnmadd213pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register, negate the product, add XMM register or 32-bit memory and store into XMM register.
+    Vfnmadd213ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register, negate the product, add XMM register or 64-bit memory and store into XMM register.
+    Vfnmadd213sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register or 128-bit memory, negate the product, add XMM register and store into XMM register.
+    Vfnmadd231psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register or 256-bit memory, negate the product, add YMM register and store into YMM register.
+    Vfnmadd231psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register or 128-bit memory, negate the product, add XMM register and store into XMM register.
+    Vfnmadd231pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register or 256-bit memory, negate the product, add YMM register and store into YMM register.
+    Vfnmadd231pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register or 32-bit memory, negate the product, add XMM register and store into XMM register.
+    Vfnmadd231ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register or 64-bit memory, negate the product, add XMM register and store into XMM register.
+    Vfnmadd231sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM register and XMM register or 128-bit memory, negate the product, subtract XMM VEX register and store into XMM register.
+    Vfnmsub132psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM register and YMM register or 256-bit memory, negate the product, subtract YMM VEX register and store into YMM register.
+    Vfnmsub132psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM register and XMM register or 128-bit memory, negate the product, subtract XMM VEX register and store into XMM register.
+    Vfnmsub132pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM register and YMM register or 256-bit memory, negate the product, subtract YMM VEX register and store into YMM register.
+    Vfnmsub132pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM register and XMM register or 32-bit memory, negate the product, subtract XMM VEX register and store into XMM register.
+    Vfnmsub132ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM register and XMM register or 64-bit memory, negate the product, subtract XMM VEX register and store into XMM register.
+    Vfnmsub132sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register, negate the product, subtract XMM register or 128-bit memory and store into XMM register.
+    Vfnmsub213psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register, negate the product, subtract YMM register or 256-bit memory and store into YMM register.
+    Vfnmsub213psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register, negate the product, subtract XMM register or 128-bit memory and store into XMM register.
+    Vfnmsub213pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register, negate the product, subtract YMM register or 256-bit memory and store into YMM register.
+    Vfnmsub213pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register, negate the product, subtract XMM register or 32-bit memory and store into XMM register.
+    Vfnmsub213ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register, negate the product, subtract XMM register or 64-bit memory and store into XMM register.
+    Vfnmsub213sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Multiply packed single-precision values of XMM VEX register and XMM register or 128-bit memory, negate the product, subtract XMM register and store into XMM register.
+    Vfnmsub231psXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed single-precision values of YMM VEX register and YMM register or 256-bit memory, negate the product, subtract YMM register and store into YMM register.
+    Vfnmsub231psYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply packed double-precision values of XMM VEX register and XMM register or 128-bit memory, negate the product, subtract XMM register and store into XMM register.
+    Vfnmsub231pdXmmVxmmXmmm128 { xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory },
+
+    /// Multiply packed double-precision values of YMM VEX register and YMM register or 256-bit memory, negate the product, subtract YMM register and store into YMM register.
+    Vfnmsub231pdYmmVymmYmmm256 { ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory },
+
+    /// Multiply low single-precision values of XMM VEX register and XMM register or 32-bit memory, negate the product, subtract XMM register and store into XMM register.
+    Vfnmsub231ssXmmVxmmXmmm32 { xmm: XMM, vxmm: XMM, xmmm32: XMMOrMemory },
+
+    /// Multiply low double-precision values of XMM VEX register and XMM register or 64-bit memory, negate the product, subtract XMM register and store into XMM register.
+    Vfnmsub231sdXmmVxmmXmmm64 { xmm: XMM, vxmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Convert four packed half-precision values of XMM register or 64-bit memory to packed single-precision values in XMM register.
+    Vcvtph2psXmmXmmm64 { xmm: XMM, xmmm64: XMMOrMemory },
+
+    /// Convert eight packed half-precision values of XMM register or 128-bit memory to packed single-precision values in YMM register.
+    Vcvtph2psYmmXmmm128 { ymm: YMM, xmmm128: XMMOrMemory },
+
+    /// Convert four packed single-precision values of XMM register to packed half-precision values in XMM register or 64-bit memory, with rounding control in the immediate.
+    Vcvtps2phXmmm64XmmImm8 {
+        xmmm64: XMMOrMemory,
+        xmm: XMM,
+        imm8: Immediate8,
+    },
+
+    /// Convert eight packed single-precision values of YMM register to packed half-precision values in XMM register or 128-bit memory, with rounding control in the immediate.
+    Vcvtps2phXmmm128YmmImm8 {
+        xmmm128: XMMOrMemory,
+        ymm: YMM,
+        imm8: Immediate8,
+    },
+
+    /// Gather packed doublewords from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VpgatherddXmmVm32xVxmm { xmm: XMM, vm32x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed doublewords from memory addressed by doubleword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+    VpgatherddYmmVm32yVymm { ymm: YMM, vm32y: VSIBMemory, vymm: YMM },
+
+    /// Gather packed doublewords from memory addressed by doubleword indexes of ZMM register into ZMM register with merge masking.
+    VpgatherddZmmMergemaskVm32z {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm32z: VSIBMemory,
+    },
+
+    /// Gather packed quadwords from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VpgatherdqXmmVm32xVxmm { xmm: XMM, vm32x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed quadwords from memory addressed by doubleword indexes of XMM register into YMM register, under the mask of YMM VEX register.
+    VpgatherdqYmmVm32xVymm { ymm: YMM, vm32x: VSIBMemory, vymm: YMM },
+
+    /// Gather packed quadwords from memory addressed by doubleword indexes of YMM register into ZMM register with merge masking.
+    VpgatherdqZmmMergemaskVm32y {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm32y: VSIBMemory,
+    },
+
+    /// Gather packed doublewords from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VpgatherqdXmmVm64xVxmm { xmm: XMM, vm64x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed doublewords from memory addressed by quadword indexes of YMM register into XMM register, under the mask of XMM VEX register.
+    VpgatherqdXmmVm64yVxmm { xmm: XMM, vm64y: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed doublewords from memory addressed by quadword indexes of ZMM register into YMM register with merge masking.
+    VpgatherqdYmmMergemaskVm64z {
+        ymm: YMM,
+        mergemask: Masking,
+        vm64z: VSIBMemory,
+    },
+
+    /// Gather packed quadwords from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VpgatherqqXmmVm64xVxmm { xmm: XMM, vm64x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed quadwords from memory addressed by quadword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+    VpgatherqqYmmVm64yVymm { ymm: YMM, vm64y: VSIBMemory, vymm: YMM },
+
+    /// Gather packed quadwords from memory addressed by quadword indexes of ZMM register into ZMM register with merge masking.
+    VpgatherqqZmmMergemaskVm64z {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm64z: VSIBMemory,
+    },
+
+    /// Gather packed single-precision values from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VgatherdpsXmmVm32xVxmm { xmm: XMM, vm32x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed single-precision values from memory addressed by doubleword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+    VgatherdpsYmmVm32yVymm { ymm: YMM, vm32y: VSIBMemory, vymm: YMM },
+
+    /// Gather packed single-precision values from memory addressed by doubleword indexes of ZMM register into ZMM register with merge masking.
+    VgatherdpsZmmMergemaskVm32z {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm32z: VSIBMemory,
+    },
+
+    /// Gather packed double-precision values from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VgatherdpdXmmVm32xVxmm { xmm: XMM, vm32x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed double-precision values from memory addressed by doubleword indexes of XMM register into YMM register, under the mask of YMM VEX register.
+    VgatherdpdYmmVm32xVymm { ymm: YMM, vm32x: VSIBMemory, vymm: YMM },
+
+    /// Gather packed double-precision values from memory addressed by doubleword indexes of YMM register into ZMM register with merge masking.
+    VgatherdpdZmmMergemaskVm32y {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm32y: VSIBMemory,
+    },
+
+    /// Gather packed single-precision values from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VgatherqpsXmmVm64xVxmm { xmm: XMM, vm64x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed single-precision values from memory addressed by quadword indexes of YMM register into XMM register, under the mask of XMM VEX register.
+    VgatherqpsXmmVm64yVxmm { xmm: XMM, vm64y: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed single-precision values from memory addressed by quadword indexes of ZMM register into YMM register with merge masking.
+    VgatherqpsYmmMergemaskVm64z {
+        ymm: YMM,
+        mergemask: Masking,
+        vm64z: VSIBMemory,
+    },
+
+    /// Gather packed double-precision values from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+    VgatherqpdXmmVm64xVxmm { xmm: XMM, vm64x: VSIBMemory, vxmm: XMM },
+
+    /// Gather packed double-precision values from memory addressed by quadword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+    VgatherqpdYmmVm64yVymm { ymm: YMM, vm64y: VSIBMemory, vymm: YMM },
+
+    /// Gather packed double-precision values from memory addressed by quadword indexes of ZMM register into ZMM register with merge masking.
+    VgatherqpdZmmMergemaskVm64z {
+        zmm: ZMM,
+        mergemask: Masking,
+        vm64z: VSIBMemory,
+    },
+
+    /// Scatter packed doublewords of ZMM register to memory addressed by doubleword indexes of ZMM register with merge masking.
+    VpscatterddVm32zMergemaskZmm {
+        vm32z: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Scatter packed quadwords of ZMM register to memory addressed by doubleword indexes of YMM register with merge masking.
+    VpscatterdqVm32yMergemaskZmm {
+        vm32y: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Scatter packed doublewords of YMM register to memory addressed by quadword indexes of ZMM register with merge masking.
+    VpscatterqdVm64zMergemaskYmm {
+        vm64z: VSIBMemory,
+        mergemask: Masking,
+        ymm: YMM,
+    },
+
+    /// Scatter packed quadwords of ZMM register to memory addressed by quadword indexes of ZMM register with merge masking.
+    VpscatterqqVm64zMergemaskZmm {
+        vm64z: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Scatter packed single-precision values of ZMM register to memory addressed by doubleword indexes of ZMM register with merge masking.
+    VscatterdpsVm32zMergemaskZmm {
+        vm32z: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Scatter packed double-precision values of ZMM register to memory addressed by doubleword indexes of YMM register with merge masking.
+    VscatterdpdVm32yMergemaskZmm {
+        vm32y: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+
+    /// Scatter packed single-precision values of YMM register to memory addressed by quadword indexes of ZMM register with merge masking.
+    VscatterqpsVm64zMergemaskYmm {
+        vm64z: VSIBMemory,
+        mergemask: Masking,
+        ymm: YMM,
+    },
+
+    /// Scatter packed double-precision values of ZMM register to memory addressed by quadword indexes of ZMM register with merge masking.
+    VscatterqpdVm64zMergemaskZmm {
+        vm64z: VSIBMemory,
+        mergemask: Masking,
+        zmm: ZMM,
+    },
+}
+
+impl Instruction {
+    /// Encodes the instruction with the corresponding safe encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError`] if the operands are not valid for the instruction.
+    #[allow(clippy::too_many_lines)]
+    pub const fn encode(self) -> Result<EncodedX86_64Instruction, EncodeError> {
+        match self {
+            Self::Lock => crate::encoders::safe::lock::encode(),
+            Self::Nop => crate::encoders::safe::nop::encode(),
+            Self::Cpuid => crate::encoders::safe::cpuid::encode(),
+            Self::Sysenter => crate::encoders::safe::sysenter::encode(),
+            Self::Syscall => crate::encoders::safe::syscall::encode(),
+            Self::Ret => crate::encoders::safe::ret::encode(),
+            Self::RetImm16 { imm16 } => crate::encoders::safe::ret::encode_imm16(imm16),
+            Self::MovRm8Imm8 { rm8, imm8 } => crate::encoders::safe::mov::encode_rm8_imm8(rm8, imm8),
+            Self::MovRm16Imm16 { rm16, imm16 } => crate::encoders::safe::mov::encode_rm16_imm16(rm16, imm16),
+            Self::MovRm32Imm32 { rm32, imm32 } => crate::encoders::safe::mov::encode_rm32_imm32(rm32, imm32),
+            Self::MovRm64Imm32 { rm64, imm32 } => crate::encoders::safe::mov::encode_rm64_imm32(rm64, imm32),
+            Self::MovReg8Imm8 { reg8, imm8 } => crate::encoders::safe::mov::encode_reg8_imm8(reg8, imm8),
+            Self::MovReg16Imm16 { reg16, imm16 } => crate::encoders::safe::mov::encode_reg16_imm16(reg16, imm16),
+            Self::MovReg32Imm32 { reg32, imm32 } => crate::encoders::safe::mov::encode_reg32_imm32(reg32, imm32),
+            Self::MovReg64Imm64 { reg64, imm64 } => crate::encoders::safe::mov::encode_reg64_imm64(reg64, imm64),
+            Self::MovRm8Reg8 { rm8, reg8 } => crate::encoders::safe::mov::encode_rm8_reg8(rm8, reg8),
+            Self::MovRm16Reg16 { rm16, reg16 } => crate::encoders::safe::mov::encode_rm16_reg16(rm16, reg16),
+            Self::MovRm32Reg32 { rm32, reg32 } => crate::encoders::safe::mov::encode_rm32_reg32(rm32, reg32),
+            Self::MovRm64Reg64 { rm64, reg64 } => crate::encoders::safe::mov::encode_rm64_reg64(rm64, reg64),
+            Self::MovReg8Rm8 { reg8, rm8 } => crate::encoders::safe::mov::encode_reg8_rm8(reg8, rm8),
+            Self::MovReg16Rm16 { reg16, rm16 } => crate::encoders::safe::mov::encode_reg16_rm16(reg16, rm16),
+            Self::MovReg32Rm32 { reg32, rm32 } => crate::encoders::safe::mov::encode_reg32_rm32(reg32, rm32),
+            Self::MovReg64Rm64 { reg64, rm64 } => crate::encoders::safe::mov::encode_reg64_rm64(reg64, rm64),
+            Self::AddAlImm8 { imm8 } => crate::encoders::safe::add::encode_al_imm8(imm8),
+            Self::AddAxImm16 { imm16 } => crate::encoders::safe::add::encode_ax_imm16(imm16),
+            Self::AddEaxImm32 { imm32 } => crate::encoders::safe::add::encode_eax_imm32(imm32),
+            Self::AddRaxImm32 { imm32 } => crate::encoders::safe::add::encode_rax_imm32(imm32),
+            Self::AddRm8Imm8 { rm8, imm8 } => crate::encoders::safe::add::encode_rm8_imm8(rm8, imm8),
+            Self::AddLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::add::encode_lock_rm8_imm8(rm8, imm8),
+            Self::AddRm16Imm16 { rm16, imm16 } => crate::encoders::safe::add::encode_rm16_imm16(rm16, imm16),
+            Self::AddLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::add::encode_lock_rm16_imm16(rm16, imm16),
+            Self::AddRm32Imm32 { rm32, imm32 } => crate::encoders::safe::add::encode_rm32_imm32(rm32, imm32),
+            Self::AddLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::add::encode_lock_rm32_imm32(rm32, imm32),
+            Self::AddRm64Imm32 { rm64, imm32 } => crate::encoders::safe::add::encode_rm64_imm32(rm64, imm32),
+            Self::AddLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::add::encode_lock_rm64_imm32(rm64, imm32),
+            Self::AddRm16Imm8 { rm16, imm8 } => crate::encoders::safe::add::encode_rm16_imm8(rm16, imm8),
+            Self::AddLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::add::encode_lock_rm16_imm8(rm16, imm8),
+            Self::AddRm32Imm8 { rm32, imm8 } => crate::encoders::safe::add::encode_rm32_imm8(rm32, imm8),
+            Self::AddLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::add::encode_lock_rm32_imm8(rm32, imm8),
+            Self::AddRm64Imm8 { rm64, imm8 } => crate::encoders::safe::add::encode_rm64_imm8(rm64, imm8),
+            Self::AddLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::add::encode_lock_rm64_imm8(rm64, imm8),
+            Self::AddRm8Reg8 { rm8, reg8 } => crate::encoders::safe::add::encode_rm8_reg8(rm8, reg8),
+            Self::AddLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::add::encode_lock_rm8_reg8(rm8, reg8),
+            Self::AddRm16Reg16 { rm16, reg16 } => crate::encoders::safe::add::encode_rm16_reg16(rm16, reg16),
+            Self::AddLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::add::encode_lock_rm16_reg16(rm16, reg16),
+            Self::AddRm32Reg32 { rm32, reg32 } => crate::encoders::safe::add::encode_rm32_reg32(rm32, reg32),
+            Self::AddLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::add::encode_lock_rm32_reg32(rm32, reg32),
+            Self::AddRm64Reg64 { rm64, reg64 } => crate::encoders::safe::add::encode_rm64_reg64(rm64, reg64),
+            Self::AddLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::add::encode_lock_rm64_reg64(rm64, reg64),
+            Self::AddReg8Rm8 { reg8, rm8 } => crate::encoders::safe::add::encode_reg8_rm8(reg8, rm8),
+            Self::AddReg16Rm16 { reg16, rm16 } => crate::encoders::safe::add::encode_reg16_rm16(reg16, rm16),
+            Self::AddReg32Rm32 { reg32, rm32 } => crate::encoders::safe::add::encode_reg32_rm32(reg32, rm32),
+            Self::AddReg64Rm64 { reg64, rm64 } => crate::encoders::safe::add::encode_reg64_rm64(reg64, rm64),
+            Self::JmpImm8 { imm8 } => crate::encoders::safe::jmp::encode_imm8(imm8),
+            Self::JmpImm32 { imm32 } => crate::encoders::safe::jmp::encode_imm32(imm32),
+            Self::JmpRm64 { rm64 } => crate::encoders::safe::jmp::encode_rm64(rm64),
+            Self::JccAImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::A, imm8),
+            Self::JccAeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::AE, imm8),
+            Self::JccBImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::B, imm8),
+            Self::JccBeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::BE, imm8),
+            Self::JccEImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::E, imm8),
+            Self::JccGImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::G, imm8),
+            Self::JccGeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::GE, imm8),
+            Self::JccLImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::L, imm8),
+            Self::JccLeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::LE, imm8),
+            Self::JccNeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::NE, imm8),
+            Self::JccNoImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::NO, imm8),
+            Self::JccNpImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::NP, imm8),
+            Self::JccNsImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::NS, imm8),
+            Self::JccOImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::O, imm8),
+            Self::JccPImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::P, imm8),
+            Self::JccPeImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::PE, imm8),
+            Self::JccPoImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::PO, imm8),
+            Self::JccSImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::S, imm8),
+            Self::JccCImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::C, imm8),
+            Self::JccNcImm8 { imm8 } => crate::encoders::safe::jcc::encode_imm8(Condition::NC, imm8),
+            Self::JccAImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::A, imm32),
+            Self::JccAeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::AE, imm32),
+            Self::JccBImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::B, imm32),
+            Self::JccBeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::BE, imm32),
+            Self::JccEImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::E, imm32),
+            Self::JccGImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::G, imm32),
+            Self::JccGeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::GE, imm32),
+            Self::JccLImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::L, imm32),
+            Self::JccLeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::LE, imm32),
+            Self::JccNeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::NE, imm32),
+            Self::JccNoImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::NO, imm32),
+            Self::JccNpImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::NP, imm32),
+            Self::JccNsImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::NS, imm32),
+            Self::JccOImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::O, imm32),
+            Self::JccPImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::P, imm32),
+            Self::JccPeImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::PE, imm32),
+            Self::JccPoImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::PO, imm32),
+            Self::JccSImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::S, imm32),
+            Self::JccCImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::C, imm32),
+            Self::JccNcImm32 { imm32 } => crate::encoders::safe::jcc::encode_imm32(Condition::NC, imm32),
+            Self::CallImm32 { imm32 } => crate::encoders::safe::call::encode_imm32(imm32),
+            Self::CallRm64 { rm64 } => crate::encoders::safe::call::encode_rm64(rm64),
+            Self::CmpAlImm8 { imm8 } => crate::encoders::safe::cmp::encode_al_imm8(imm8),
+            Self::CmpAxImm16 { imm16 } => crate::encoders::safe::cmp::encode_ax_imm16(imm16),
+            Self::CmpEaxImm32 { imm32 } => crate::encoders::safe::cmp::encode_eax_imm32(imm32),
+            Self::CmpRaxImm32 { imm32 } => crate::encoders::safe::cmp::encode_rax_imm32(imm32),
+            Self::CmpRm8Imm8 { rm8, imm8 } => crate::encoders::safe::cmp::encode_rm8_imm8(rm8, imm8),
+            Self::CmpRm16Imm16 { rm16, imm16 } => crate::encoders::safe::cmp::encode_rm16_imm16(rm16, imm16),
+            Self::CmpRm32Imm32 { rm32, imm32 } => crate::encoders::safe::cmp::encode_rm32_imm32(rm32, imm32),
+            Self::CmpRm64Imm32 { rm64, imm32 } => crate::encoders::safe::cmp::encode_rm64_imm32(rm64, imm32),
+            Self::CmpRm16Imm8 { rm16, imm8 } => crate::encoders::safe::cmp::encode_rm16_imm8(rm16, imm8),
+            Self::CmpRm32Imm8 { rm32, imm8 } => crate::encoders::safe::cmp::encode_rm32_imm8(rm32, imm8),
+            Self::CmpRm64Imm8 { rm64, imm8 } => crate::encoders::safe::cmp::encode_rm64_imm8(rm64, imm8),
+            Self::CmpRm8Reg8 { rm8, reg8 } => crate::encoders::safe::cmp::encode_rm8_reg8(rm8, reg8),
+            Self::CmpRm16Reg16 { rm16, reg16 } => crate::encoders::safe::cmp::encode_rm16_reg16(rm16, reg16),
+            Self::CmpRm32Reg32 { rm32, reg32 } => crate::encoders::safe::cmp::encode_rm32_reg32(rm32, reg32),
+            Self::CmpRm64Reg64 { rm64, reg64 } => crate::encoders::safe::cmp::encode_rm64_reg64(rm64, reg64),
+            Self::CmpReg8Rm8 { reg8, rm8 } => crate::encoders::safe::cmp::encode_reg8_rm8(reg8, rm8),
+            Self::CmpReg16Rm16 { reg16, rm16 } => crate::encoders::safe::cmp::encode_reg16_rm16(reg16, rm16),
+            Self::CmpReg32Rm32 { reg32, rm32 } => crate::encoders::safe::cmp::encode_reg32_rm32(reg32, rm32),
+            Self::CmpReg64Rm64 { reg64, rm64 } => crate::encoders::safe::cmp::encode_reg64_rm64(reg64, rm64),
+            Self::LeaReg16Mem64 { reg16, mem64 } => crate::encoders::safe::lea::encode_reg16_mem64(reg16, mem64),
+            Self::LeaReg32Mem64 { reg32, mem64 } => crate::encoders::safe::lea::encode_reg32_mem64(reg32, mem64),
+            Self::LeaReg64Mem64 { reg64, mem64 } => crate::encoders::safe::lea::encode_reg64_mem64(reg64, mem64),
+            Self::PushRm64 { rm64 } => crate::encoders::safe::push::encode_rm64(rm64),
+            Self::PushReg64 { reg64 } => crate::encoders::safe::push::encode_reg64(reg64),
+            Self::PushImm8 { imm8 } => crate::encoders::safe::push::encode_imm8(imm8),
+            Self::PushImm16 { imm16 } => crate::encoders::safe::push::encode_imm16(imm16),
+            Self::PushImm32 { imm32 } => crate::encoders::safe::push::encode_imm32(imm32),
+            Self::PopRm64 { rm64 } => crate::encoders::safe::pop::encode_rm64(rm64),
+            Self::PopReg64 { reg64 } => crate::encoders::safe::pop::encode_reg64(reg64),
+            Self::SubAlImm8 { imm8 } => crate::encoders::safe::sub::encode_al_imm8(imm8),
+            Self::SubAxImm16 { imm16 } => crate::encoders::safe::sub::encode_ax_imm16(imm16),
+            Self::SubEaxImm32 { imm32 } => crate::encoders::safe::sub::encode_eax_imm32(imm32),
+            Self::SubRaxImm32 { imm32 } => crate::encoders::safe::sub::encode_rax_imm32(imm32),
+            Self::SubRm8Imm8 { rm8, imm8 } => crate::encoders::safe::sub::encode_rm8_imm8(rm8, imm8),
+            Self::SubLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::sub::encode_lock_rm8_imm8(rm8, imm8),
+            Self::SubRm16Imm16 { rm16, imm16 } => crate::encoders::safe::sub::encode_rm16_imm16(rm16, imm16),
+            Self::SubLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::sub::encode_lock_rm16_imm16(rm16, imm16),
+            Self::SubRm32Imm32 { rm32, imm32 } => crate::encoders::safe::sub::encode_rm32_imm32(rm32, imm32),
+            Self::SubLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::sub::encode_lock_rm32_imm32(rm32, imm32),
+            Self::SubRm64Imm32 { rm64, imm32 } => crate::encoders::safe::sub::encode_rm64_imm32(rm64, imm32),
+            Self::SubLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::sub::encode_lock_rm64_imm32(rm64, imm32),
+            Self::SubRm16Imm8 { rm16, imm8 } => crate::encoders::safe::sub::encode_rm16_imm8(rm16, imm8),
+            Self::SubLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::sub::encode_lock_rm16_imm8(rm16, imm8),
+            Self::SubRm32Imm8 { rm32, imm8 } => crate::encoders::safe::sub::encode_rm32_imm8(rm32, imm8),
+            Self::SubLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::sub::encode_lock_rm32_imm8(rm32, imm8),
+            Self::SubRm64Imm8 { rm64, imm8 } => crate::encoders::safe::sub::encode_rm64_imm8(rm64, imm8),
+            Self::SubLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::sub::encode_lock_rm64_imm8(rm64, imm8),
+            Self::SubRm8Reg8 { rm8, reg8 } => crate::encoders::safe::sub::encode_rm8_reg8(rm8, reg8),
+            Self::SubLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::sub::encode_lock_rm8_reg8(rm8, reg8),
+            Self::SubRm16Reg16 { rm16, reg16 } => crate::encoders::safe::sub::encode_rm16_reg16(rm16, reg16),
+            Self::SubLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::sub::encode_lock_rm16_reg16(rm16, reg16),
+            Self::SubRm32Reg32 { rm32, reg32 } => crate::encoders::safe::sub::encode_rm32_reg32(rm32, reg32),
+            Self::SubLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::sub::encode_lock_rm32_reg32(rm32, reg32),
+            Self::SubRm64Reg64 { rm64, reg64 } => crate::encoders::safe::sub::encode_rm64_reg64(rm64, reg64),
+            Self::SubLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::sub::encode_lock_rm64_reg64(rm64, reg64),
+            Self::SubReg8Rm8 { reg8, rm8 } => crate::encoders::safe::sub::encode_reg8_rm8(reg8, rm8),
+            Self::SubReg16Rm16 { reg16, rm16 } => crate::encoders::safe::sub::encode_reg16_rm16(reg16, rm16),
+            Self::SubReg32Rm32 { reg32, rm32 } => crate::encoders::safe::sub::encode_reg32_rm32(reg32, rm32),
+            Self::SubReg64Rm64 { reg64, rm64 } => crate::encoders::safe::sub::encode_reg64_rm64(reg64, rm64),
+            Self::XorAlImm8 { imm8 } => crate::encoders::safe::xor::encode_al_imm8(imm8),
+            Self::XorAxImm16 { imm16 } => crate::encoders::safe::xor::encode_ax_imm16(imm16),
+            Self::XorEaxImm32 { imm32 } => crate::encoders::safe::xor::encode_eax_imm32(imm32),
+            Self::XorRaxImm32 { imm32 } => crate::encoders::safe::xor::encode_rax_imm32(imm32),
+            Self::XorRm8Imm8 { rm8, imm8 } => crate::encoders::safe::xor::encode_rm8_imm8(rm8, imm8),
+            Self::XorLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::xor::encode_lock_rm8_imm8(rm8, imm8),
+            Self::XorRm16Imm16 { rm16, imm16 } => crate::encoders::safe::xor::encode_rm16_imm16(rm16, imm16),
+            Self::XorLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::xor::encode_lock_rm16_imm16(rm16, imm16),
+            Self::XorRm32Imm32 { rm32, imm32 } => crate::encoders::safe::xor::encode_rm32_imm32(rm32, imm32),
+            Self::XorLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::xor::encode_lock_rm32_imm32(rm32, imm32),
+            Self::XorRm64Imm32 { rm64, imm32 } => crate::encoders::safe::xor::encode_rm64_imm32(rm64, imm32),
+            Self::XorLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::xor::encode_lock_rm64_imm32(rm64, imm32),
+            Self::XorRm16Imm8 { rm16, imm8 } => crate::encoders::safe::xor::encode_rm16_imm8(rm16, imm8),
+            Self::XorLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::xor::encode_lock_rm16_imm8(rm16, imm8),
+            Self::XorRm32Imm8 { rm32, imm8 } => crate::encoders::safe::xor::encode_rm32_imm8(rm32, imm8),
+            Self::XorLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::xor::encode_lock_rm32_imm8(rm32, imm8),
+            Self::XorRm64Imm8 { rm64, imm8 } => crate::encoders::safe::xor::encode_rm64_imm8(rm64, imm8),
+            Self::XorLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::xor::encode_lock_rm64_imm8(rm64, imm8),
+            Self::XorRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xor::encode_rm8_reg8(rm8, reg8),
+            Self::XorLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xor::encode_lock_rm8_reg8(rm8, reg8),
+            Self::XorRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xor::encode_rm16_reg16(rm16, reg16),
+            Self::XorLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xor::encode_lock_rm16_reg16(rm16, reg16),
+            Self::XorRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xor::encode_rm32_reg32(rm32, reg32),
+            Self::XorLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xor::encode_lock_rm32_reg32(rm32, reg32),
+            Self::XorRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xor::encode_rm64_reg64(rm64, reg64),
+            Self::XorLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xor::encode_lock_rm64_reg64(rm64, reg64),
+            Self::XorReg8Rm8 { reg8, rm8 } => crate::encoders::safe::xor::encode_reg8_rm8(reg8, rm8),
+            Self::XorReg16Rm16 { reg16, rm16 } => crate::encoders::safe::xor::encode_reg16_rm16(reg16, rm16),
+            Self::XorReg32Rm32 { reg32, rm32 } => crate::encoders::safe::xor::encode_reg32_rm32(reg32, rm32),
+            Self::XorReg64Rm64 { reg64, rm64 } => crate::encoders::safe::xor::encode_reg64_rm64(reg64, rm64),
+            Self::IntImm8 { imm8 } => crate::encoders::safe::int::encode_imm8(imm8),
+            Self::Int1 => crate::encoders::safe::int::encode_1(),
+            Self::Int3 => crate::encoders::safe::int::encode_3(),
+            Self::RolRm8One { rm8 } => crate::encoders::safe::rol::encode_rm8_one(rm8),
+            Self::RolRm16One { rm16 } => crate::encoders::safe::rol::encode_rm16_one(rm16),
+            Self::RolRm32One { rm32 } => crate::encoders::safe::rol::encode_rm32_one(rm32),
+            Self::RolRm64One { rm64 } => crate::encoders::safe::rol::encode_rm64_one(rm64),
+            Self::RolRm8Imm8 { rm8, imm8 } => crate::encoders::safe::rol::encode_rm8_imm8(rm8, imm8),
+            Self::RolRm16Imm8 { rm16, imm8 } => crate::encoders::safe::rol::encode_rm16_imm8(rm16, imm8),
+            Self::RolRm32Imm8 { rm32, imm8 } => crate::encoders::safe::rol::encode_rm32_imm8(rm32, imm8),
+            Self::RolRm64Imm8 { rm64, imm8 } => crate::encoders::safe::rol::encode_rm64_imm8(rm64, imm8),
+            Self::RolRm8Cl { rm8 } => crate::encoders::safe::rol::encode_rm8_cl(rm8),
+            Self::RolRm16Cl { rm16 } => crate::encoders::safe::rol::encode_rm16_cl(rm16),
+            Self::RolRm32Cl { rm32 } => crate::encoders::safe::rol::encode_rm32_cl(rm32),
+            Self::RolRm64Cl { rm64 } => crate::encoders::safe::rol::encode_rm64_cl(rm64),
+            Self::RorRm8One { rm8 } => crate::encoders::safe::ror::encode_rm8_one(rm8),
+            Self::RorRm16One { rm16 } => crate::encoders::safe::ror::encode_rm16_one(rm16),
+            Self::RorRm32One { rm32 } => crate::encoders::safe::ror::encode_rm32_one(rm32),
+            Self::RorRm64One { rm64 } => crate::encoders::safe::ror::encode_rm64_one(rm64),
+            Self::RorRm8Imm8 { rm8, imm8 } => crate::encoders::safe::ror::encode_rm8_imm8(rm8, imm8),
+            Self::RorRm16Imm8 { rm16, imm8 } => crate::encoders::safe::ror::encode_rm16_imm8(rm16, imm8),
+            Self::RorRm32Imm8 { rm32, imm8 } => crate::encoders::safe::ror::encode_rm32_imm8(rm32, imm8),
+            Self::RorRm64Imm8 { rm64, imm8 } => crate::encoders::safe::ror::encode_rm64_imm8(rm64, imm8),
+            Self::RorRm8Cl { rm8 } => crate::encoders::safe::ror::encode_rm8_cl(rm8),
+            Self::RorRm16Cl { rm16 } => crate::encoders::safe::ror::encode_rm16_cl(rm16),
+            Self::RorRm32Cl { rm32 } => crate::encoders::safe::ror::encode_rm32_cl(rm32),
+            Self::RorRm64Cl { rm64 } => crate::encoders::safe::ror::encode_rm64_cl(rm64),
+            Self::RclRm8One { rm8 } => crate::encoders::safe::rcl::encode_rm8_one(rm8),
+            Self::RclRm16One { rm16 } => crate::encoders::safe::rcl::encode_rm16_one(rm16),
+            Self::RclRm32One { rm32 } => crate::encoders::safe::rcl::encode_rm32_one(rm32),
+            Self::RclRm64One { rm64 } => crate::encoders::safe::rcl::encode_rm64_one(rm64),
+            Self::RclRm8Imm8 { rm8, imm8 } => crate::encoders::safe::rcl::encode_rm8_imm8(rm8, imm8),
+            Self::RclRm16Imm8 { rm16, imm8 } => crate::encoders::safe::rcl::encode_rm16_imm8(rm16, imm8),
+            Self::RclRm32Imm8 { rm32, imm8 } => crate::encoders::safe::rcl::encode_rm32_imm8(rm32, imm8),
+            Self::RclRm64Imm8 { rm64, imm8 } => crate::encoders::safe::rcl::encode_rm64_imm8(rm64, imm8),
+            Self::RclRm8Cl { rm8 } => crate::encoders::safe::rcl::encode_rm8_cl(rm8),
+            Self::RclRm16Cl { rm16 } => crate::encoders::safe::rcl::encode_rm16_cl(rm16),
+            Self::RclRm32Cl { rm32 } => crate::encoders::safe::rcl::encode_rm32_cl(rm32),
+            Self::RclRm64Cl { rm64 } => crate::encoders::safe::rcl::encode_rm64_cl(rm64),
+            Self::RcrRm8One { rm8 } => crate::encoders::safe::rcr::encode_rm8_one(rm8),
+            Self::RcrRm16One { rm16 } => crate::encoders::safe::rcr::encode_rm16_one(rm16),
+            Self::RcrRm32One { rm32 } => crate::encoders::safe::rcr::encode_rm32_one(rm32),
+            Self::RcrRm64One { rm64 } => crate::encoders::safe::rcr::encode_rm64_one(rm64),
+            Self::RcrRm8Imm8 { rm8, imm8 } => crate::encoders::safe::rcr::encode_rm8_imm8(rm8, imm8),
+            Self::RcrRm16Imm8 { rm16, imm8 } => crate::encoders::safe::rcr::encode_rm16_imm8(rm16, imm8),
+            Self::RcrRm32Imm8 { rm32, imm8 } => crate::encoders::safe::rcr::encode_rm32_imm8(rm32, imm8),
+            Self::RcrRm64Imm8 { rm64, imm8 } => crate::encoders::safe::rcr::encode_rm64_imm8(rm64, imm8),
+            Self::RcrRm8Cl { rm8 } => crate::encoders::safe::rcr::encode_rm8_cl(rm8),
+            Self::RcrRm16Cl { rm16 } => crate::encoders::safe::rcr::encode_rm16_cl(rm16),
+            Self::RcrRm32Cl { rm32 } => crate::encoders::safe::rcr::encode_rm32_cl(rm32),
+            Self::RcrRm64Cl { rm64 } => crate::encoders::safe::rcr::encode_rm64_cl(rm64),
+            Self::ShlRm8One { rm8 } => crate::encoders::safe::shl::encode_rm8_one(rm8),
+            Self::ShlRm16One { rm16 } => crate::encoders::safe::shl::encode_rm16_one(rm16),
+            Self::ShlRm32One { rm32 } => crate::encoders::safe::shl::encode_rm32_one(rm32),
+            Self::ShlRm64One { rm64 } => crate::encoders::safe::shl::encode_rm64_one(rm64),
+            Self::ShlRm8Imm8 { rm8, imm8 } => crate::encoders::safe::shl::encode_rm8_imm8(rm8, imm8),
+            Self::ShlRm16Imm8 { rm16, imm8 } => crate::encoders::safe::shl::encode_rm16_imm8(rm16, imm8),
+            Self::ShlRm32Imm8 { rm32, imm8 } => crate::encoders::safe::shl::encode_rm32_imm8(rm32, imm8),
+            Self::ShlRm64Imm8 { rm64, imm8 } => crate::encoders::safe::shl::encode_rm64_imm8(rm64, imm8),
+            Self::ShlRm8Cl { rm8 } => crate::encoders::safe::shl::encode_rm8_cl(rm8),
+            Self::ShlRm16Cl { rm16 } => crate::encoders::safe::shl::encode_rm16_cl(rm16),
+            Self::ShlRm32Cl { rm32 } => crate::encoders::safe::shl::encode_rm32_cl(rm32),
+            Self::ShlRm64Cl { rm64 } => crate::encoders::safe::shl::encode_rm64_cl(rm64),
+            Self::ShrRm8One { rm8 } => crate::encoders::safe::shr::encode_rm8_one(rm8),
+            Self::ShrRm16One { rm16 } => crate::encoders::safe::shr::encode_rm16_one(rm16),
+            Self::ShrRm32One { rm32 } => crate::encoders::safe::shr::encode_rm32_one(rm32),
+            Self::ShrRm64One { rm64 } => crate::encoders::safe::shr::encode_rm64_one(rm64),
+            Self::ShrRm8Imm8 { rm8, imm8 } => crate::encoders::safe::shr::encode_rm8_imm8(rm8, imm8),
+            Self::ShrRm16Imm8 { rm16, imm8 } => crate::encoders::safe::shr::encode_rm16_imm8(rm16, imm8),
+            Self::ShrRm32Imm8 { rm32, imm8 } => crate::encoders::safe::shr::encode_rm32_imm8(rm32, imm8),
+            Self::ShrRm64Imm8 { rm64, imm8 } => crate::encoders::safe::shr::encode_rm64_imm8(rm64, imm8),
+            Self::ShrRm8Cl { rm8 } => crate::encoders::safe::shr::encode_rm8_cl(rm8),
+            Self::ShrRm16Cl { rm16 } => crate::encoders::safe::shr::encode_rm16_cl(rm16),
+            Self::ShrRm32Cl { rm32 } => crate::encoders::safe::shr::encode_rm32_cl(rm32),
+            Self::ShrRm64Cl { rm64 } => crate::encoders::safe::shr::encode_rm64_cl(rm64),
+            Self::SarRm8One { rm8 } => crate::encoders::safe::sar::encode_rm8_one(rm8),
+            Self::SarRm16One { rm16 } => crate::encoders::safe::sar::encode_rm16_one(rm16),
+            Self::SarRm32One { rm32 } => crate::encoders::safe::sar::encode_rm32_one(rm32),
+            Self::SarRm64One { rm64 } => crate::encoders::safe::sar::encode_rm64_one(rm64),
+            Self::SarRm8Imm8 { rm8, imm8 } => crate::encoders::safe::sar::encode_rm8_imm8(rm8, imm8),
+            Self::SarRm16Imm8 { rm16, imm8 } => crate::encoders::safe::sar::encode_rm16_imm8(rm16, imm8),
+            Self::SarRm32Imm8 { rm32, imm8 } => crate::encoders::safe::sar::encode_rm32_imm8(rm32, imm8),
+            Self::SarRm64Imm8 { rm64, imm8 } => crate::encoders::safe::sar::encode_rm64_imm8(rm64, imm8),
+            Self::SarRm8Cl { rm8 } => crate::encoders::safe::sar::encode_rm8_cl(rm8),
+            Self::SarRm16Cl { rm16 } => crate::encoders::safe::sar::encode_rm16_cl(rm16),
+            Self::SarRm32Cl { rm32 } => crate::encoders::safe::sar::encode_rm32_cl(rm32),
+            Self::SarRm64Cl { rm64 } => crate::encoders::safe::sar::encode_rm64_cl(rm64),
+            Self::MulRm8 { rm8 } => crate::encoders::safe::mul::encode_rm8(rm8),
+            Self::MulRm16 { rm16 } => crate::encoders::safe::mul::encode_rm16(rm16),
+            Self::MulRm32 { rm32 } => crate::encoders::safe::mul::encode_rm32(rm32),
+            Self::MulRm64 { rm64 } => crate::encoders::safe::mul::encode_rm64(rm64),
+            Self::ImulRm8 { rm8 } => crate::encoders::safe::imul::encode_rm8(rm8),
+            Self::ImulRm16 { rm16 } => crate::encoders::safe::imul::encode_rm16(rm16),
+            Self::ImulRm32 { rm32 } => crate::encoders::safe::imul::encode_rm32(rm32),
+            Self::ImulRm64 { rm64 } => crate::encoders::safe::imul::encode_rm64(rm64),
+            Self::ImulReg16Rm16 { reg16, rm16 } => crate::encoders::safe::imul::encode_reg16_rm16(reg16, rm16),
+            Self::ImulReg32Rm32 { reg32, rm32 } => crate::encoders::safe::imul::encode_reg32_rm32(reg32, rm32),
+            Self::ImulReg64Rm64 { reg64, rm64 } => crate::encoders::safe::imul::encode_reg64_rm64(reg64, rm64),
+            Self::ImulReg16Rm16Imm8 { reg16, rm16, imm8 } => {
+                crate::encoders::safe::imul::encode_reg16_rm16_imm8(reg16, rm16, imm8)
+            }
+            Self::ImulReg32Rm32Imm8 { reg32, rm32, imm8 } => {
+                crate::encoders::safe::imul::encode_reg32_rm32_imm8(reg32, rm32, imm8)
+            }
+            Self::ImulReg64Rm64Imm8 { reg64, rm64, imm8 } => {
+                crate::encoders::safe::imul::encode_reg64_rm64_imm8(reg64, rm64, imm8)
+            }
+            Self::ImulReg16Rm16Imm16 { reg16, rm16, imm16 } => {
+                crate::encoders::safe::imul::encode_reg16_rm16_imm16(reg16, rm16, imm16)
+            }
+            Self::ImulReg32Rm32Imm32 { reg32, rm32, imm32 } => {
+                crate::encoders::safe::imul::encode_reg32_rm32_imm32(reg32, rm32, imm32)
+            }
+            Self::ImulReg64Rm64Imm32 { reg64, rm64, imm32 } => {
+                crate::encoders::safe::imul::encode_reg64_rm64_imm32(reg64, rm64, imm32)
+            }
+            Self::DivRm8 { rm8 } => crate::encoders::safe::div::encode_rm8(rm8),
+            Self::DivRm16 { rm16 } => crate::encoders::safe::div::encode_rm16(rm16),
+            Self::DivRm32 { rm32 } => crate::encoders::safe::div::encode_rm32(rm32),
+            Self::DivRm64 { rm64 } => crate::encoders::safe::div::encode_rm64(rm64),
+            Self::IdivRm8 { rm8 } => crate::encoders::safe::idiv::encode_rm8(rm8),
+            Self::IdivRm16 { rm16 } => crate::encoders::safe::idiv::encode_rm16(rm16),
+            Self::IdivRm32 { rm32 } => crate::encoders::safe::idiv::encode_rm32(rm32),
+            Self::IdivRm64 { rm64 } => crate::encoders::safe::idiv::encode_rm64(rm64),
+            Self::AndAlImm8 { imm8 } => crate::encoders::safe::and::encode_al_imm8(imm8),
+            Self::AndAxImm16 { imm16 } => crate::encoders::safe::and::encode_ax_imm16(imm16),
+            Self::AndEaxImm32 { imm32 } => crate::encoders::safe::and::encode_eax_imm32(imm32),
+            Self::AndRaxImm32 { imm32 } => crate::encoders::safe::and::encode_rax_imm32(imm32),
+            Self::AndRm8Imm8 { rm8, imm8 } => crate::encoders::safe::and::encode_rm8_imm8(rm8, imm8),
+            Self::AndLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::and::encode_lock_rm8_imm8(rm8, imm8),
+            Self::AndRm16Imm16 { rm16, imm16 } => crate::encoders::safe::and::encode_rm16_imm16(rm16, imm16),
+            Self::AndLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::and::encode_lock_rm16_imm16(rm16, imm16),
+            Self::AndRm32Imm32 { rm32, imm32 } => crate::encoders::safe::and::encode_rm32_imm32(rm32, imm32),
+            Self::AndLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::and::encode_lock_rm32_imm32(rm32, imm32),
+            Self::AndRm64Imm32 { rm64, imm32 } => crate::encoders::safe::and::encode_rm64_imm32(rm64, imm32),
+            Self::AndLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::and::encode_lock_rm64_imm32(rm64, imm32),
+            Self::AndRm16Imm8 { rm16, imm8 } => crate::encoders::safe::and::encode_rm16_imm8(rm16, imm8),
+            Self::AndLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::and::encode_lock_rm16_imm8(rm16, imm8),
+            Self::AndRm32Imm8 { rm32, imm8 } => crate::encoders::safe::and::encode_rm32_imm8(rm32, imm8),
+            Self::AndLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::and::encode_lock_rm32_imm8(rm32, imm8),
+            Self::AndRm64Imm8 { rm64, imm8 } => crate::encoders::safe::and::encode_rm64_imm8(rm64, imm8),
+            Self::AndLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::and::encode_lock_rm64_imm8(rm64, imm8),
+            Self::AndRm8Reg8 { rm8, reg8 } => crate::encoders::safe::and::encode_rm8_reg8(rm8, reg8),
+            Self::AndLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::and::encode_lock_rm8_reg8(rm8, reg8),
+            Self::AndRm16Reg16 { rm16, reg16 } => crate::encoders::safe::and::encode_rm16_reg16(rm16, reg16),
+            Self::AndLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::and::encode_lock_rm16_reg16(rm16, reg16),
+            Self::AndRm32Reg32 { rm32, reg32 } => crate::encoders::safe::and::encode_rm32_reg32(rm32, reg32),
+            Self::AndLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::and::encode_lock_rm32_reg32(rm32, reg32),
+            Self::AndRm64Reg64 { rm64, reg64 } => crate::encoders::safe::and::encode_rm64_reg64(rm64, reg64),
+            Self::AndLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::and::encode_lock_rm64_reg64(rm64, reg64),
+            Self::AndReg8Rm8 { reg8, rm8 } => crate::encoders::safe::and::encode_reg8_rm8(reg8, rm8),
+            Self::AndReg16Rm16 { reg16, rm16 } => crate::encoders::safe::and::encode_reg16_rm16(reg16, rm16),
+            Self::AndReg32Rm32 { reg32, rm32 } => crate::encoders::safe::and::encode_reg32_rm32(reg32, rm32),
+            Self::AndReg64Rm64 { reg64, rm64 } => crate::encoders::safe::and::encode_reg64_rm64(reg64, rm64),
+            Self::OrAlImm8 { imm8 } => crate::encoders::safe::or::encode_al_imm8(imm8),
+            Self::OrAxImm16 { imm16 } => crate::encoders::safe::or::encode_ax_imm16(imm16),
+            Self::OrEaxImm32 { imm32 } => crate::encoders::safe::or::encode_eax_imm32(imm32),
+            Self::OrRaxImm32 { imm32 } => crate::encoders::safe::or::encode_rax_imm32(imm32),
+            Self::OrRm8Imm8 { rm8, imm8 } => crate::encoders::safe::or::encode_rm8_imm8(rm8, imm8),
+            Self::OrLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::or::encode_lock_rm8_imm8(rm8, imm8),
+            Self::OrRm16Imm16 { rm16, imm16 } => crate::encoders::safe::or::encode_rm16_imm16(rm16, imm16),
+            Self::OrLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::or::encode_lock_rm16_imm16(rm16, imm16),
+            Self::OrRm32Imm32 { rm32, imm32 } => crate::encoders::safe::or::encode_rm32_imm32(rm32, imm32),
+            Self::OrLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::or::encode_lock_rm32_imm32(rm32, imm32),
+            Self::OrRm64Imm32 { rm64, imm32 } => crate::encoders::safe::or::encode_rm64_imm32(rm64, imm32),
+            Self::OrLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::or::encode_lock_rm64_imm32(rm64, imm32),
+            Self::OrRm16Imm8 { rm16, imm8 } => crate::encoders::safe::or::encode_rm16_imm8(rm16, imm8),
+            Self::OrLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::or::encode_lock_rm16_imm8(rm16, imm8),
+            Self::OrRm32Imm8 { rm32, imm8 } => crate::encoders::safe::or::encode_rm32_imm8(rm32, imm8),
+            Self::OrLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::or::encode_lock_rm32_imm8(rm32, imm8),
+            Self::OrRm64Imm8 { rm64, imm8 } => crate::encoders::safe::or::encode_rm64_imm8(rm64, imm8),
+            Self::OrLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::or::encode_lock_rm64_imm8(rm64, imm8),
+            Self::OrRm8Reg8 { rm8, reg8 } => crate::encoders::safe::or::encode_rm8_reg8(rm8, reg8),
+            Self::OrLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::or::encode_lock_rm8_reg8(rm8, reg8),
+            Self::OrRm16Reg16 { rm16, reg16 } => crate::encoders::safe::or::encode_rm16_reg16(rm16, reg16),
+            Self::OrLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::or::encode_lock_rm16_reg16(rm16, reg16),
+            Self::OrRm32Reg32 { rm32, reg32 } => crate::encoders::safe::or::encode_rm32_reg32(rm32, reg32),
+            Self::OrLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::or::encode_lock_rm32_reg32(rm32, reg32),
+            Self::OrRm64Reg64 { rm64, reg64 } => crate::encoders::safe::or::encode_rm64_reg64(rm64, reg64),
+            Self::OrLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::or::encode_lock_rm64_reg64(rm64, reg64),
+            Self::OrReg8Rm8 { reg8, rm8 } => crate::encoders::safe::or::encode_reg8_rm8(reg8, rm8),
+            Self::OrReg16Rm16 { reg16, rm16 } => crate::encoders::safe::or::encode_reg16_rm16(reg16, rm16),
+            Self::OrReg32Rm32 { reg32, rm32 } => crate::encoders::safe::or::encode_reg32_rm32(reg32, rm32),
+            Self::OrReg64Rm64 { reg64, rm64 } => crate::encoders::safe::or::encode_reg64_rm64(reg64, rm64),
+            Self::AdcAlImm8 { imm8 } => crate::encoders::safe::adc::encode_al_imm8(imm8),
+            Self::AdcAxImm16 { imm16 } => crate::encoders::safe::adc::encode_ax_imm16(imm16),
+            Self::AdcEaxImm32 { imm32 } => crate::encoders::safe::adc::encode_eax_imm32(imm32),
+            Self::AdcRaxImm32 { imm32 } => crate::encoders::safe::adc::encode_rax_imm32(imm32),
+            Self::AdcRm8Imm8 { rm8, imm8 } => crate::encoders::safe::adc::encode_rm8_imm8(rm8, imm8),
+            Self::AdcLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::adc::encode_lock_rm8_imm8(rm8, imm8),
+            Self::AdcRm16Imm16 { rm16, imm16 } => crate::encoders::safe::adc::encode_rm16_imm16(rm16, imm16),
+            Self::AdcLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::adc::encode_lock_rm16_imm16(rm16, imm16),
+            Self::AdcRm32Imm32 { rm32, imm32 } => crate::encoders::safe::adc::encode_rm32_imm32(rm32, imm32),
+            Self::AdcLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::adc::encode_lock_rm32_imm32(rm32, imm32),
+            Self::AdcRm64Imm32 { rm64, imm32 } => crate::encoders::safe::adc::encode_rm64_imm32(rm64, imm32),
+            Self::AdcLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::adc::encode_lock_rm64_imm32(rm64, imm32),
+            Self::AdcRm16Imm8 { rm16, imm8 } => crate::encoders::safe::adc::encode_rm16_imm8(rm16, imm8),
+            Self::AdcLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::adc::encode_lock_rm16_imm8(rm16, imm8),
+            Self::AdcRm32Imm8 { rm32, imm8 } => crate::encoders::safe::adc::encode_rm32_imm8(rm32, imm8),
+            Self::AdcLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::adc::encode_lock_rm32_imm8(rm32, imm8),
+            Self::AdcRm64Imm8 { rm64, imm8 } => crate::encoders::safe::adc::encode_rm64_imm8(rm64, imm8),
+            Self::AdcLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::adc::encode_lock_rm64_imm8(rm64, imm8),
+            Self::AdcRm8Reg8 { rm8, reg8 } => crate::encoders::safe::adc::encode_rm8_reg8(rm8, reg8),
+            Self::AdcLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::adc::encode_lock_rm8_reg8(rm8, reg8),
+            Self::AdcRm16Reg16 { rm16, reg16 } => crate::encoders::safe::adc::encode_rm16_reg16(rm16, reg16),
+            Self::AdcLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::adc::encode_lock_rm16_reg16(rm16, reg16),
+            Self::AdcRm32Reg32 { rm32, reg32 } => crate::encoders::safe::adc::encode_rm32_reg32(rm32, reg32),
+            Self::AdcLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::adc::encode_lock_rm32_reg32(rm32, reg32),
+            Self::AdcRm64Reg64 { rm64, reg64 } => crate::encoders::safe::adc::encode_rm64_reg64(rm64, reg64),
+            Self::AdcLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::adc::encode_lock_rm64_reg64(rm64, reg64),
+            Self::AdcReg8Rm8 { reg8, rm8 } => crate::encoders::safe::adc::encode_reg8_rm8(reg8, rm8),
+            Self::AdcReg16Rm16 { reg16, rm16 } => crate::encoders::safe::adc::encode_reg16_rm16(reg16, rm16),
+            Self::AdcReg32Rm32 { reg32, rm32 } => crate::encoders::safe::adc::encode_reg32_rm32(reg32, rm32),
+            Self::AdcReg64Rm64 { reg64, rm64 } => crate::encoders::safe::adc::encode_reg64_rm64(reg64, rm64),
+            Self::SbbAlImm8 { imm8 } => crate::encoders::safe::sbb::encode_al_imm8(imm8),
+            Self::SbbAxImm16 { imm16 } => crate::encoders::safe::sbb::encode_ax_imm16(imm16),
+            Self::SbbEaxImm32 { imm32 } => crate::encoders::safe::sbb::encode_eax_imm32(imm32),
+            Self::SbbRaxImm32 { imm32 } => crate::encoders::safe::sbb::encode_rax_imm32(imm32),
+            Self::SbbRm8Imm8 { rm8, imm8 } => crate::encoders::safe::sbb::encode_rm8_imm8(rm8, imm8),
+            Self::SbbLockRm8Imm8 { rm8, imm8 } => crate::encoders::safe::sbb::encode_lock_rm8_imm8(rm8, imm8),
+            Self::SbbRm16Imm16 { rm16, imm16 } => crate::encoders::safe::sbb::encode_rm16_imm16(rm16, imm16),
+            Self::SbbLockRm16Imm16 { rm16, imm16 } => crate::encoders::safe::sbb::encode_lock_rm16_imm16(rm16, imm16),
+            Self::SbbRm32Imm32 { rm32, imm32 } => crate::encoders::safe::sbb::encode_rm32_imm32(rm32, imm32),
+            Self::SbbLockRm32Imm32 { rm32, imm32 } => crate::encoders::safe::sbb::encode_lock_rm32_imm32(rm32, imm32),
+            Self::SbbRm64Imm32 { rm64, imm32 } => crate::encoders::safe::sbb::encode_rm64_imm32(rm64, imm32),
+            Self::SbbLockRm64Imm32 { rm64, imm32 } => crate::encoders::safe::sbb::encode_lock_rm64_imm32(rm64, imm32),
+            Self::SbbRm16Imm8 { rm16, imm8 } => crate::encoders::safe::sbb::encode_rm16_imm8(rm16, imm8),
+            Self::SbbLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::sbb::encode_lock_rm16_imm8(rm16, imm8),
+            Self::SbbRm32Imm8 { rm32, imm8 } => crate::encoders::safe::sbb::encode_rm32_imm8(rm32, imm8),
+            Self::SbbLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::sbb::encode_lock_rm32_imm8(rm32, imm8),
+            Self::SbbRm64Imm8 { rm64, imm8 } => crate::encoders::safe::sbb::encode_rm64_imm8(rm64, imm8),
+            Self::SbbLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::sbb::encode_lock_rm64_imm8(rm64, imm8),
+            Self::SbbRm8Reg8 { rm8, reg8 } => crate::encoders::safe::sbb::encode_rm8_reg8(rm8, reg8),
+            Self::SbbLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::sbb::encode_lock_rm8_reg8(rm8, reg8),
+            Self::SbbRm16Reg16 { rm16, reg16 } => crate::encoders::safe::sbb::encode_rm16_reg16(rm16, reg16),
+            Self::SbbLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::sbb::encode_lock_rm16_reg16(rm16, reg16),
+            Self::SbbRm32Reg32 { rm32, reg32 } => crate::encoders::safe::sbb::encode_rm32_reg32(rm32, reg32),
+            Self::SbbLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::sbb::encode_lock_rm32_reg32(rm32, reg32),
+            Self::SbbRm64Reg64 { rm64, reg64 } => crate::encoders::safe::sbb::encode_rm64_reg64(rm64, reg64),
+            Self::SbbLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::sbb::encode_lock_rm64_reg64(rm64, reg64),
+            Self::SbbReg8Rm8 { reg8, rm8 } => crate::encoders::safe::sbb::encode_reg8_rm8(reg8, rm8),
+            Self::SbbReg16Rm16 { reg16, rm16 } => crate::encoders::safe::sbb::encode_reg16_rm16(reg16, rm16),
+            Self::SbbReg32Rm32 { reg32, rm32 } => crate::encoders::safe::sbb::encode_reg32_rm32(reg32, rm32),
+            Self::SbbReg64Rm64 { reg64, rm64 } => crate::encoders::safe::sbb::encode_reg64_rm64(reg64, rm64),
+            Self::TestAlImm8 { imm8 } => crate::encoders::safe::test::encode_al_imm8(imm8),
+            Self::TestAxImm16 { imm16 } => crate::encoders::safe::test::encode_ax_imm16(imm16),
+            Self::TestEaxImm32 { imm32 } => crate::encoders::safe::test::encode_eax_imm32(imm32),
+            Self::TestRaxImm32 { imm32 } => crate::encoders::safe::test::encode_rax_imm32(imm32),
+            Self::TestRm8Imm8 { rm8, imm8 } => crate::encoders::safe::test::encode_rm8_imm8(rm8, imm8),
+            Self::TestRm16Imm16 { rm16, imm16 } => crate::encoders::safe::test::encode_rm16_imm16(rm16, imm16),
+            Self::TestRm32Imm32 { rm32, imm32 } => crate::encoders::safe::test::encode_rm32_imm32(rm32, imm32),
+            Self::TestRm64Imm32 { rm64, imm32 } => crate::encoders::safe::test::encode_rm64_imm32(rm64, imm32),
+            Self::TestRm8Reg8 { rm8, reg8 } => crate::encoders::safe::test::encode_rm8_reg8(rm8, reg8),
+            Self::TestRm16Reg16 { rm16, reg16 } => crate::encoders::safe::test::encode_rm16_reg16(rm16, reg16),
+            Self::TestRm32Reg32 { rm32, reg32 } => crate::encoders::safe::test::encode_rm32_reg32(rm32, reg32),
+            Self::TestRm64Reg64 { rm64, reg64 } => crate::encoders::safe::test::encode_rm64_reg64(rm64, reg64),
+            Self::NotRm8 { rm8 } => crate::encoders::safe::not::encode_rm8(rm8),
+            Self::NotLockRm8 { rm8 } => crate::encoders::safe::not::encode_lock_rm8(rm8),
+            Self::NotRm16 { rm16 } => crate::encoders::safe::not::encode_rm16(rm16),
+            Self::NotLockRm16 { rm16 } => crate::encoders::safe::not::encode_lock_rm16(rm16),
+            Self::NotRm32 { rm32 } => crate::encoders::safe::not::encode_rm32(rm32),
+            Self::NotLockRm32 { rm32 } => crate::encoders::safe::not::encode_lock_rm32(rm32),
+            Self::NotRm64 { rm64 } => crate::encoders::safe::not::encode_rm64(rm64),
+            Self::NotLockRm64 { rm64 } => crate::encoders::safe::not::encode_lock_rm64(rm64),
+            Self::NegRm8 { rm8 } => crate::encoders::safe::neg::encode_rm8(rm8),
+            Self::NegLockRm8 { rm8 } => crate::encoders::safe::neg::encode_lock_rm8(rm8),
+            Self::NegRm16 { rm16 } => crate::encoders::safe::neg::encode_rm16(rm16),
+            Self::NegLockRm16 { rm16 } => crate::encoders::safe::neg::encode_lock_rm16(rm16),
+            Self::NegRm32 { rm32 } => crate::encoders::safe::neg::encode_rm32(rm32),
+            Self::NegLockRm32 { rm32 } => crate::encoders::safe::neg::encode_lock_rm32(rm32),
+            Self::NegRm64 { rm64 } => crate::encoders::safe::neg::encode_rm64(rm64),
+            Self::NegLockRm64 { rm64 } => crate::encoders::safe::neg::encode_lock_rm64(rm64),
+            Self::IncRm8 { rm8 } => crate::encoders::safe::inc::encode_rm8(rm8),
+            Self::IncLockRm8 { rm8 } => crate::encoders::safe::inc::encode_lock_rm8(rm8),
+            Self::IncRm16 { rm16 } => crate::encoders::safe::inc::encode_rm16(rm16),
+            Self::IncLockRm16 { rm16 } => crate::encoders::safe::inc::encode_lock_rm16(rm16),
+            Self::IncRm32 { rm32 } => crate::encoders::safe::inc::encode_rm32(rm32),
+            Self::IncLockRm32 { rm32 } => crate::encoders::safe::inc::encode_lock_rm32(rm32),
+            Self::IncRm64 { rm64 } => crate::encoders::safe::inc::encode_rm64(rm64),
+            Self::IncLockRm64 { rm64 } => crate::encoders::safe::inc::encode_lock_rm64(rm64),
+            Self::DecRm8 { rm8 } => crate::encoders::safe::dec::encode_rm8(rm8),
+            Self::DecLockRm8 { rm8 } => crate::encoders::safe::dec::encode_lock_rm8(rm8),
+            Self::DecRm16 { rm16 } => crate::encoders::safe::dec::encode_rm16(rm16),
+            Self::DecLockRm16 { rm16 } => crate::encoders::safe::dec::encode_lock_rm16(rm16),
+            Self::DecRm32 { rm32 } => crate::encoders::safe::dec::encode_rm32(rm32),
+            Self::DecLockRm32 { rm32 } => crate::encoders::safe::dec::encode_lock_rm32(rm32),
+            Self::DecRm64 { rm64 } => crate::encoders::safe::dec::encode_rm64(rm64),
+            Self::DecLockRm64 { rm64 } => crate::encoders::safe::dec::encode_lock_rm64(rm64),
+            Self::MovzxReg16Rm8 { reg16, rm8 } => crate::encoders::safe::movzx::encode_reg16_rm8(reg16, rm8),
+            Self::MovzxReg32Rm8 { reg32, rm8 } => crate::encoders::safe::movzx::encode_reg32_rm8(reg32, rm8),
+            Self::MovzxReg64Rm8 { reg64, rm8 } => crate::encoders::safe::movzx::encode_reg64_rm8(reg64, rm8),
+            Self::MovzxReg32Rm16 { reg32, rm16 } => crate::encoders::safe::movzx::encode_reg32_rm16(reg32, rm16),
+            Self::MovzxReg64Rm16 { reg64, rm16 } => crate::encoders::safe::movzx::encode_reg64_rm16(reg64, rm16),
+            Self::MovsxReg16Rm8 { reg16, rm8 } => crate::encoders::safe::movsx::encode_reg16_rm8(reg16, rm8),
+            Self::MovsxReg32Rm8 { reg32, rm8 } => crate::encoders::safe::movsx::encode_reg32_rm8(reg32, rm8),
+            Self::MovsxReg64Rm8 { reg64, rm8 } => crate::encoders::safe::movsx::encode_reg64_rm8(reg64, rm8),
+            Self::MovsxReg32Rm16 { reg32, rm16 } => crate::encoders::safe::movsx::encode_reg32_rm16(reg32, rm16),
+            Self::MovsxReg64Rm16 { reg64, rm16 } => crate::encoders::safe::movsx::encode_reg64_rm16(reg64, rm16),
+            Self::MovsxdReg64Rm32 { reg64, rm32 } => crate::encoders::safe::movsxd::encode_reg64_rm32(reg64, rm32),
+            Self::SetccARm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::A, rm8),
+            Self::SetccAeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::AE, rm8),
+            Self::SetccBRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::B, rm8),
+            Self::SetccBeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::BE, rm8),
+            Self::SetccERm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::E, rm8),
+            Self::SetccGRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::G, rm8),
+            Self::SetccGeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::GE, rm8),
+            Self::SetccLRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::L, rm8),
+            Self::SetccLeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::LE, rm8),
+            Self::SetccNeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::NE, rm8),
+            Self::SetccNoRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::NO, rm8),
+            Self::SetccNpRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::NP, rm8),
+            Self::SetccNsRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::NS, rm8),
+            Self::SetccORm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::O, rm8),
+            Self::SetccPRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::P, rm8),
+            Self::SetccPeRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::PE, rm8),
+            Self::SetccPoRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::PO, rm8),
+            Self::SetccSRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::S, rm8),
+            Self::SetccCRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::C, rm8),
+            Self::SetccNcRm8 { rm8 } => crate::encoders::safe::setcc::encode_rm8(Condition::NC, rm8),
+            Self::CmovccAReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::A, reg16, rm16)
+            }
+            Self::CmovccAeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::AE, reg16, rm16)
+            }
+            Self::CmovccBReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::B, reg16, rm16)
+            }
+            Self::CmovccBeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::BE, reg16, rm16)
+            }
+            Self::CmovccEReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::E, reg16, rm16)
+            }
+            Self::CmovccGReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::G, reg16, rm16)
+            }
+            Self::CmovccGeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::GE, reg16, rm16)
+            }
+            Self::CmovccLReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::L, reg16, rm16)
+            }
+            Self::CmovccLeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::LE, reg16, rm16)
+            }
+            Self::CmovccNeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::NE, reg16, rm16)
+            }
+            Self::CmovccNoReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::NO, reg16, rm16)
+            }
+            Self::CmovccNpReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::NP, reg16, rm16)
+            }
+            Self::CmovccNsReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::NS, reg16, rm16)
+            }
+            Self::CmovccOReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::O, reg16, rm16)
+            }
+            Self::CmovccPReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::P, reg16, rm16)
+            }
+            Self::CmovccPeReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::PE, reg16, rm16)
+            }
+            Self::CmovccPoReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::PO, reg16, rm16)
+            }
+            Self::CmovccSReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::S, reg16, rm16)
+            }
+            Self::CmovccCReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::C, reg16, rm16)
+            }
+            Self::CmovccNcReg16Rm16 { reg16, rm16 } => {
+                crate::encoders::safe::cmovcc::encode_reg16_rm16(Condition::NC, reg16, rm16)
+            }
+            Self::CmovccAReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::A, reg32, rm32)
+            }
+            Self::CmovccAeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::AE, reg32, rm32)
+            }
+            Self::CmovccBReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::B, reg32, rm32)
+            }
+            Self::CmovccBeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::BE, reg32, rm32)
+            }
+            Self::CmovccEReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::E, reg32, rm32)
+            }
+            Self::CmovccGReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::G, reg32, rm32)
+            }
+            Self::CmovccGeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::GE, reg32, rm32)
+            }
+            Self::CmovccLReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::L, reg32, rm32)
+            }
+            Self::CmovccLeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::LE, reg32, rm32)
+            }
+            Self::CmovccNeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::NE, reg32, rm32)
+            }
+            Self::CmovccNoReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::NO, reg32, rm32)
+            }
+            Self::CmovccNpReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::NP, reg32, rm32)
+            }
+            Self::CmovccNsReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::NS, reg32, rm32)
+            }
+            Self::CmovccOReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::O, reg32, rm32)
+            }
+            Self::CmovccPReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::P, reg32, rm32)
+            }
+            Self::CmovccPeReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::PE, reg32, rm32)
+            }
+            Self::CmovccPoReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::PO, reg32, rm32)
+            }
+            Self::CmovccSReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::S, reg32, rm32)
+            }
+            Self::CmovccCReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::C, reg32, rm32)
+            }
+            Self::CmovccNcReg32Rm32 { reg32, rm32 } => {
+                crate::encoders::safe::cmovcc::encode_reg32_rm32(Condition::NC, reg32, rm32)
+            }
+            Self::CmovccAReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::A, reg64, rm64)
+            }
+            Self::CmovccAeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::AE, reg64, rm64)
+            }
+            Self::CmovccBReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::B, reg64, rm64)
+            }
+            Self::CmovccBeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::BE, reg64, rm64)
+            }
+            Self::CmovccEReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::E, reg64, rm64)
+            }
+            Self::CmovccGReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::G, reg64, rm64)
+            }
+            Self::CmovccGeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::GE, reg64, rm64)
+            }
+            Self::CmovccLReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::L, reg64, rm64)
+            }
+            Self::CmovccLeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::LE, reg64, rm64)
+            }
+            Self::CmovccNeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::NE, reg64, rm64)
+            }
+            Self::CmovccNoReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::NO, reg64, rm64)
+            }
+            Self::CmovccNpReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::NP, reg64, rm64)
+            }
+            Self::CmovccNsReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::NS, reg64, rm64)
+            }
+            Self::CmovccOReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::O, reg64, rm64)
+            }
+            Self::CmovccPReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::P, reg64, rm64)
+            }
+            Self::CmovccPeReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::PE, reg64, rm64)
+            }
+            Self::CmovccPoReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::PO, reg64, rm64)
+            }
+            Self::CmovccSReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::S, reg64, rm64)
+            }
+            Self::CmovccCReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::C, reg64, rm64)
+            }
+            Self::CmovccNcReg64Rm64 { reg64, rm64 } => {
+                crate::encoders::safe::cmovcc::encode_reg64_rm64(Condition::NC, reg64, rm64)
+            }
+            Self::XchgRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xchg::encode_rm8_reg8(rm8, reg8),
+            Self::XchgLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xchg::encode_lock_rm8_reg8(rm8, reg8),
+            Self::XchgRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xchg::encode_rm16_reg16(rm16, reg16),
+            Self::XchgLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xchg::encode_lock_rm16_reg16(rm16, reg16),
+            Self::XchgRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xchg::encode_rm32_reg32(rm32, reg32),
+            Self::XchgLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xchg::encode_lock_rm32_reg32(rm32, reg32),
+            Self::XchgRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xchg::encode_rm64_reg64(rm64, reg64),
+            Self::XchgLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xchg::encode_lock_rm64_reg64(rm64, reg64),
+            Self::XchgReg8Rm8 { reg8, rm8 } => crate::encoders::safe::xchg::encode_reg8_rm8(reg8, rm8),
+            Self::XchgReg16Rm16 { reg16, rm16 } => crate::encoders::safe::xchg::encode_reg16_rm16(reg16, rm16),
+            Self::XchgReg32Rm32 { reg32, rm32 } => crate::encoders::safe::xchg::encode_reg32_rm32(reg32, rm32),
+            Self::XchgReg64Rm64 { reg64, rm64 } => crate::encoders::safe::xchg::encode_reg64_rm64(reg64, rm64),
+            Self::CmpxchgRm8Reg8 { rm8, reg8 } => crate::encoders::safe::cmpxchg::encode_rm8_reg8(rm8, reg8),
+            Self::CmpxchgLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::cmpxchg::encode_lock_rm8_reg8(rm8, reg8),
+            Self::CmpxchgRm16Reg16 { rm16, reg16 } => crate::encoders::safe::cmpxchg::encode_rm16_reg16(rm16, reg16),
+            Self::CmpxchgLockRm16Reg16 { rm16, reg16 } => {
+                crate::encoders::safe::cmpxchg::encode_lock_rm16_reg16(rm16, reg16)
+            }
+            Self::CmpxchgRm32Reg32 { rm32, reg32 } => crate::encoders::safe::cmpxchg::encode_rm32_reg32(rm32, reg32),
+            Self::CmpxchgLockRm32Reg32 { rm32, reg32 } => {
+                crate::encoders::safe::cmpxchg::encode_lock_rm32_reg32(rm32, reg32)
+            }
+            Self::CmpxchgRm64Reg64 { rm64, reg64 } => crate::encoders::safe::cmpxchg::encode_rm64_reg64(rm64, reg64),
+            Self::CmpxchgLockRm64Reg64 { rm64, reg64 } => {
+                crate::encoders::safe::cmpxchg::encode_lock_rm64_reg64(rm64, reg64)
+            }
+            Self::Cmpxchg8bMem64 { mem64 } => crate::encoders::safe::cmpxchg8b::encode_mem64(mem64),
+            Self::Cmpxchg8bLockMem64 { mem64 } => crate::encoders::safe::cmpxchg8b::encode_lock_mem64(mem64),
+            Self::Cmpxchg16bMem128 { mem128 } => crate::encoders::safe::cmpxchg16b::encode_mem128(mem128),
+            Self::Cmpxchg16bLockMem128 { mem128 } => crate::encoders::safe::cmpxchg16b::encode_lock_mem128(mem128),
+            Self::XaddRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xadd::encode_rm8_reg8(rm8, reg8),
+            Self::XaddLockRm8Reg8 { rm8, reg8 } => crate::encoders::safe::xadd::encode_lock_rm8_reg8(rm8, reg8),
+            Self::XaddRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xadd::encode_rm16_reg16(rm16, reg16),
+            Self::XaddLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::xadd::encode_lock_rm16_reg16(rm16, reg16),
+            Self::XaddRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xadd::encode_rm32_reg32(rm32, reg32),
+            Self::XaddLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::xadd::encode_lock_rm32_reg32(rm32, reg32),
+            Self::XaddRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xadd::encode_rm64_reg64(rm64, reg64),
+            Self::XaddLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::xadd::encode_lock_rm64_reg64(rm64, reg64),
+            Self::MovsB => crate::encoders::safe::movs::encode_b(),
+            Self::MovsW => crate::encoders::safe::movs::encode_w(),
+            Self::MovsD => crate::encoders::safe::movs::encode_d(),
+            Self::MovsQ => crate::encoders::safe::movs::encode_q(),
+            Self::MovsRepB => crate::encoders::safe::movs::encode_rep_b(),
+            Self::MovsRepW => crate::encoders::safe::movs::encode_rep_w(),
+            Self::MovsRepD => crate::encoders::safe::movs::encode_rep_d(),
+            Self::MovsRepQ => crate::encoders::safe::movs::encode_rep_q(),
+            Self::StosB => crate::encoders::safe::stos::encode_b(),
+            Self::StosW => crate::encoders::safe::stos::encode_w(),
+            Self::StosD => crate::encoders::safe::stos::encode_d(),
+            Self::StosQ => crate::encoders::safe::stos::encode_q(),
+            Self::StosRepB => crate::encoders::safe::stos::encode_rep_b(),
+            Self::StosRepW => crate::encoders::safe::stos::encode_rep_w(),
+            Self::StosRepD => crate::encoders::safe::stos::encode_rep_d(),
+            Self::StosRepQ => crate::encoders::safe::stos::encode_rep_q(),
+            Self::LodsB => crate::encoders::safe::lods::encode_b(),
+            Self::LodsW => crate::encoders::safe::lods::encode_w(),
+            Self::LodsD => crate::encoders::safe::lods::encode_d(),
+            Self::LodsQ => crate::encoders::safe::lods::encode_q(),
+            Self::LodsRepB => crate::encoders::safe::lods::encode_rep_b(),
+            Self::LodsRepW => crate::encoders::safe::lods::encode_rep_w(),
+            Self::LodsRepD => crate::encoders::safe::lods::encode_rep_d(),
+            Self::LodsRepQ => crate::encoders::safe::lods::encode_rep_q(),
+            Self::CmpsB => crate::encoders::safe::cmps::encode_b(),
+            Self::CmpsW => crate::encoders::safe::cmps::encode_w(),
+            Self::CmpsD => crate::encoders::safe::cmps::encode_d(),
+            Self::CmpsQ => crate::encoders::safe::cmps::encode_q(),
+            Self::CmpsRepeB => crate::encoders::safe::cmps::encode_repe_b(),
+            Self::CmpsRepeW => crate::encoders::safe::cmps::encode_repe_w(),
+            Self::CmpsRepeD => crate::encoders::safe::cmps::encode_repe_d(),
+            Self::CmpsRepeQ => crate::encoders::safe::cmps::encode_repe_q(),
+            Self::CmpsRepneB => crate::encoders::safe::cmps::encode_repne_b(),
+            Self::CmpsRepneW => crate::encoders::safe::cmps::encode_repne_w(),
+            Self::CmpsRepneD => crate::encoders::safe::cmps::encode_repne_d(),
+            Self::CmpsRepneQ => crate::encoders::safe::cmps::encode_repne_q(),
+            Self::ScasB => crate::encoders::safe::scas::encode_b(),
+            Self::ScasW => crate::encoders::safe::scas::encode_w(),
+            Self::ScasD => crate::encoders::safe::scas::encode_d(),
+            Self::ScasQ => crate::encoders::safe::scas::encode_q(),
+            Self::ScasRepeB => crate::encoders::safe::scas::encode_repe_b(),
+            Self::ScasRepeW => crate::encoders::safe::scas::encode_repe_w(),
+            Self::ScasRepeD => crate::encoders::safe::scas::encode_repe_d(),
+            Self::ScasRepeQ => crate::encoders::safe::scas::encode_repe_q(),
+            Self::ScasRepneB => crate::encoders::safe::scas::encode_repne_b(),
+            Self::ScasRepneW => crate::encoders::safe::scas::encode_repne_w(),
+            Self::ScasRepneD => crate::encoders::safe::scas::encode_repne_d(),
+            Self::ScasRepneQ => crate::encoders::safe::scas::encode_repne_q(),
+            Self::BtRm16Reg16 { rm16, reg16 } => crate::encoders::safe::bt::encode_rm16_reg16(rm16, reg16),
+            Self::BtRm32Reg32 { rm32, reg32 } => crate::encoders::safe::bt::encode_rm32_reg32(rm32, reg32),
+            Self::BtRm64Reg64 { rm64, reg64 } => crate::encoders::safe::bt::encode_rm64_reg64(rm64, reg64),
+            Self::BtRm16Imm8 { rm16, imm8 } => crate::encoders::safe::bt::encode_rm16_imm8(rm16, imm8),
+            Self::BtRm32Imm8 { rm32, imm8 } => crate::encoders::safe::bt::encode_rm32_imm8(rm32, imm8),
+            Self::BtRm64Imm8 { rm64, imm8 } => crate::encoders::safe::bt::encode_rm64_imm8(rm64, imm8),
+            Self::BtsRm16Reg16 { rm16, reg16 } => crate::encoders::safe::bts::encode_rm16_reg16(rm16, reg16),
+            Self::BtsLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::bts::encode_lock_rm16_reg16(rm16, reg16),
+            Self::BtsRm32Reg32 { rm32, reg32 } => crate::encoders::safe::bts::encode_rm32_reg32(rm32, reg32),
+            Self::BtsLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::bts::encode_lock_rm32_reg32(rm32, reg32),
+            Self::BtsRm64Reg64 { rm64, reg64 } => crate::encoders::safe::bts::encode_rm64_reg64(rm64, reg64),
+            Self::BtsLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::bts::encode_lock_rm64_reg64(rm64, reg64),
+            Self::BtsRm16Imm8 { rm16, imm8 } => crate::encoders::safe::bts::encode_rm16_imm8(rm16, imm8),
+            Self::BtsLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::bts::encode_lock_rm16_imm8(rm16, imm8),
+            Self::BtsRm32Imm8 { rm32, imm8 } => crate::encoders::safe::bts::encode_rm32_imm8(rm32, imm8),
+            Self::BtsLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::bts::encode_lock_rm32_imm8(rm32, imm8),
+            Self::BtsRm64Imm8 { rm64, imm8 } => crate::encoders::safe::bts::encode_rm64_imm8(rm64, imm8),
+            Self::BtsLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::bts::encode_lock_rm64_imm8(rm64, imm8),
+            Self::BtrRm16Reg16 { rm16, reg16 } => crate::encoders::safe::btr::encode_rm16_reg16(rm16, reg16),
+            Self::BtrLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::btr::encode_lock_rm16_reg16(rm16, reg16),
+            Self::BtrRm32Reg32 { rm32, reg32 } => crate::encoders::safe::btr::encode_rm32_reg32(rm32, reg32),
+            Self::BtrLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::btr::encode_lock_rm32_reg32(rm32, reg32),
+            Self::BtrRm64Reg64 { rm64, reg64 } => crate::encoders::safe::btr::encode_rm64_reg64(rm64, reg64),
+            Self::BtrLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::btr::encode_lock_rm64_reg64(rm64, reg64),
+            Self::BtrRm16Imm8 { rm16, imm8 } => crate::encoders::safe::btr::encode_rm16_imm8(rm16, imm8),
+            Self::BtrLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::btr::encode_lock_rm16_imm8(rm16, imm8),
+            Self::BtrRm32Imm8 { rm32, imm8 } => crate::encoders::safe::btr::encode_rm32_imm8(rm32, imm8),
+            Self::BtrLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::btr::encode_lock_rm32_imm8(rm32, imm8),
+            Self::BtrRm64Imm8 { rm64, imm8 } => crate::encoders::safe::btr::encode_rm64_imm8(rm64, imm8),
+            Self::BtrLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::btr::encode_lock_rm64_imm8(rm64, imm8),
+            Self::BtcRm16Reg16 { rm16, reg16 } => crate::encoders::safe::btc::encode_rm16_reg16(rm16, reg16),
+            Self::BtcLockRm16Reg16 { rm16, reg16 } => crate::encoders::safe::btc::encode_lock_rm16_reg16(rm16, reg16),
+            Self::BtcRm32Reg32 { rm32, reg32 } => crate::encoders::safe::btc::encode_rm32_reg32(rm32, reg32),
+            Self::BtcLockRm32Reg32 { rm32, reg32 } => crate::encoders::safe::btc::encode_lock_rm32_reg32(rm32, reg32),
+            Self::BtcRm64Reg64 { rm64, reg64 } => crate::encoders::safe::btc::encode_rm64_reg64(rm64, reg64),
+            Self::BtcLockRm64Reg64 { rm64, reg64 } => crate::encoders::safe::btc::encode_lock_rm64_reg64(rm64, reg64),
+            Self::BtcRm16Imm8 { rm16, imm8 } => crate::encoders::safe::btc::encode_rm16_imm8(rm16, imm8),
+            Self::BtcLockRm16Imm8 { rm16, imm8 } => crate::encoders::safe::btc::encode_lock_rm16_imm8(rm16, imm8),
+            Self::BtcRm32Imm8 { rm32, imm8 } => crate::encoders::safe::btc::encode_rm32_imm8(rm32, imm8),
+            Self::BtcLockRm32Imm8 { rm32, imm8 } => crate::encoders::safe::btc::encode_lock_rm32_imm8(rm32, imm8),
+            Self::BtcRm64Imm8 { rm64, imm8 } => crate::encoders::safe::btc::encode_rm64_imm8(rm64, imm8),
+            Self::BtcLockRm64Imm8 { rm64, imm8 } => crate::encoders::safe::btc::encode_lock_rm64_imm8(rm64, imm8),
+            Self::BsfReg16Rm16 { reg16, rm16 } => crate::encoders::safe::bsf::encode_reg16_rm16(reg16, rm16),
+            Self::BsfReg32Rm32 { reg32, rm32 } => crate::encoders::safe::bsf::encode_reg32_rm32(reg32, rm32),
+            Self::BsfReg64Rm64 { reg64, rm64 } => crate::encoders::safe::bsf::encode_reg64_rm64(reg64, rm64),
+            Self::BsrReg16Rm16 { reg16, rm16 } => crate::encoders::safe::bsr::encode_reg16_rm16(reg16, rm16),
+            Self::BsrReg32Rm32 { reg32, rm32 } => crate::encoders::safe::bsr::encode_reg32_rm32(reg32, rm32),
+            Self::BsrReg64Rm64 { reg64, rm64 } => crate::encoders::safe::bsr::encode_reg64_rm64(reg64, rm64),
+            Self::BswapReg32 { reg32 } => crate::encoders::safe::bswap::encode_reg32(reg32),
+            Self::BswapReg64 { reg64 } => crate::encoders::safe::bswap::encode_reg64(reg64),
+            Self::PopcntReg16Rm16 { reg16, rm16 } => crate::encoders::safe::popcnt::encode_reg16_rm16(reg16, rm16),
+            Self::PopcntReg32Rm32 { reg32, rm32 } => crate::encoders::safe::popcnt::encode_reg32_rm32(reg32, rm32),
+            Self::PopcntReg64Rm64 { reg64, rm64 } => crate::encoders::safe::popcnt::encode_reg64_rm64(reg64, rm64),
+            Self::LzcntReg16Rm16 { reg16, rm16 } => crate::encoders::safe::lzcnt::encode_reg16_rm16(reg16, rm16),
+            Self::LzcntReg32Rm32 { reg32, rm32 } => crate::encoders::safe::lzcnt::encode_reg32_rm32(reg32, rm32),
+            Self::LzcntReg64Rm64 { reg64, rm64 } => crate::encoders::safe::lzcnt::encode_reg64_rm64(reg64, rm64),
+            Self::TzcntReg16Rm16 { reg16, rm16 } => crate::encoders::safe::tzcnt::encode_reg16_rm16(reg16, rm16),
+            Self::TzcntReg32Rm32 { reg32, rm32 } => crate::encoders::safe::tzcnt::encode_reg32_rm32(reg32, rm32),
+            Self::TzcntReg64Rm64 { reg64, rm64 } => crate::encoders::safe::tzcnt::encode_reg64_rm64(reg64, rm64),
+            Self::AndnReg32Vreg32Rm32 { reg32, vreg32, rm32 } => {
+                crate::encoders::safe::andn::encode_reg32_vreg32_rm32(reg32, vreg32, rm32)
+            }
+            Self::AndnReg64Vreg64Rm64 { reg64, vreg64, rm64 } => {
+                crate::encoders::safe::andn::encode_reg64_vreg64_rm64(reg64, vreg64, rm64)
+            }
+            Self::BextrReg32Rm32Vreg32 { reg32, rm32, vreg32 } => {
+                crate::encoders::safe::bextr::encode_reg32_rm32_vreg32(reg32, rm32, vreg32)
+            }
+            Self::BextrReg64Rm64Vreg64 { reg64, rm64, vreg64 } => {
+                crate::encoders::safe::bextr::encode_reg64_rm64_vreg64(reg64, rm64, vreg64)
+            }
+            Self::BlsiVreg32Rm32 { vreg32, rm32 } => crate::encoders::safe::blsi::encode_vreg32_rm32(vreg32, rm32),
+            Self::BlsiVreg64Rm64 { vreg64, rm64 } => crate::encoders::safe::blsi::encode_vreg64_rm64(vreg64, rm64),
+            Self::BlsmskVreg32Rm32 { vreg32, rm32 } => crate::encoders::safe::blsmsk::encode_vreg32_rm32(vreg32, rm32),
+            Self::BlsmskVreg64Rm64 { vreg64, rm64 } => crate::encoders::safe::blsmsk::encode_vreg64_rm64(vreg64, rm64),
+            Self::BlsrVreg32Rm32 { vreg32, rm32 } => crate::encoders::safe::blsr::encode_vreg32_rm32(vreg32, rm32),
+            Self::BlsrVreg64Rm64 { vreg64, rm64 } => crate::encoders::safe::blsr::encode_vreg64_rm64(vreg64, rm64),
+            Self::BzhiReg32Rm32Vreg32 { reg32, rm32, vreg32 } => {
+                crate::encoders::safe::bzhi::encode_reg32_rm32_vreg32(reg32, rm32, vreg32)
+            }
+            Self::BzhiReg64Rm64Vreg64 { reg64, rm64, vreg64 } => {
+                crate::encoders::safe::bzhi::encode_reg64_rm64_vreg64(reg64, rm64, vreg64)
+            }
+            Self::PdepReg32Vreg32Rm32 { reg32, vreg32, rm32 } => {
+                crate::encoders::safe::pdep::encode_reg32_vreg32_rm32(reg32, vreg32, rm32)
+            }
+            Self::PdepReg64Vreg64Rm64 { reg64, vreg64, rm64 } => {
+                crate::encoders::safe::pdep::encode_reg64_vreg64_rm64(reg64, vreg64, rm64)
+            }
+            Self::PextReg32Vreg32Rm32 { reg32, vreg32, rm32 } => {
+                crate::encoders::safe::pext::encode_reg32_vreg32_rm32(reg32, vreg32, rm32)
+            }
+            Self::PextReg64Vreg64Rm64 { reg64, vreg64, rm64 } => {
+                crate::encoders::safe::pext::encode_reg64_vreg64_rm64(reg64, vreg64, rm64)
+            }
+            Self::MulxReg32Vreg32Rm32 { reg32, vreg32, rm32 } => {
+                crate::encoders::safe::mulx::encode_reg32_vreg32_rm32(reg32, vreg32, rm32)
+            }
+            Self::MulxReg64Vreg64Rm64 { reg64, vreg64, rm64 } => {
+                crate::encoders::safe::mulx::encode_reg64_vreg64_rm64(reg64, vreg64, rm64)
+            }
+            Self::RorxReg32Rm32Imm8 { reg32, rm32, imm8 } => {
+                crate::encoders::safe::rorx::encode_reg32_rm32_imm8(reg32, rm32, imm8)
+            }
+            Self::RorxReg64Rm64Imm8 { reg64, rm64, imm8 } => {
+                crate::encoders::safe::rorx::encode_reg64_rm64_imm8(reg64, rm64, imm8)
+            }
+            Self::SarxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => {
+                crate::encoders::safe::sarx::encode_reg32_rm32_vreg32(reg32, rm32, vreg32)
+            }
+            Self::SarxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => {
+                crate::encoders::safe::sarx::encode_reg64_rm64_vreg64(reg64, rm64, vreg64)
+            }
+            Self::ShlxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => {
+                crate::encoders::safe::shlx::encode_reg32_rm32_vreg32(reg32, rm32, vreg32)
+            }
+            Self::ShlxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => {
+                crate::encoders::safe::shlx::encode_reg64_rm64_vreg64(reg64, rm64, vreg64)
+            }
+            Self::ShrxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => {
+                crate::encoders::safe::shrx::encode_reg32_rm32_vreg32(reg32, rm32, vreg32)
+            }
+            Self::ShrxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => {
+                crate::encoders::safe::shrx::encode_reg64_rm64_vreg64(reg64, rm64, vreg64)
+            }
+            Self::AdcxReg32Rm32 { reg32, rm32 } => crate::encoders::safe::adcx::encode_reg32_rm32(reg32, rm32),
+            Self::AdcxReg64Rm64 { reg64, rm64 } => crate::encoders::safe::adcx::encode_reg64_rm64(reg64, rm64),
+            Self::AdoxReg32Rm32 { reg32, rm32 } => crate::encoders::safe::adox::encode_reg32_rm32(reg32, rm32),
+            Self::AdoxReg64Rm64 { reg64, rm64 } => crate::encoders::safe::adox::encode_reg64_rm64(reg64, rm64),
+            Self::MovssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::movss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::MovssXmmm32Xmm { xmmm32, xmm } => crate::encoders::safe::movss::encode_xmmm32_xmm(xmmm32, xmm),
+            Self::MovsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::movsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::MovsdXmmm64Xmm { xmmm64, xmm } => crate::encoders::safe::movsd::encode_xmmm64_xmm(xmmm64, xmm),
+            Self::AddssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::addss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::AddsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::addsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::SubssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::subss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::SubsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::subsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::MulssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::mulss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::MulsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::mulsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::DivssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::divss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::DivsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::divsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::SqrtssXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::sqrtss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::SqrtsdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::sqrtsd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::UcomissXmmXmmm32 { xmm, xmmm32 } => crate::encoders::safe::ucomiss::encode_xmm_xmmm32(xmm, xmmm32),
+            Self::UcomisdXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::ucomisd::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::Cvtsi2sdXmmRm32 { xmm, rm32 } => crate::encoders::safe::cvtsi2sd::encode_xmm_rm32(xmm, rm32),
+            Self::Cvtsi2sdXmmRm64 { xmm, rm64 } => crate::encoders::safe::cvtsi2sd::encode_xmm_rm64(xmm, rm64),
+            Self::Cvttsd2siReg32Xmmm64 { reg32, xmmm64 } => {
+                crate::encoders::safe::cvttsd2si::encode_reg32_xmmm64(reg32, xmmm64)
+            }
+            Self::Cvttsd2siReg64Xmmm64 { reg64, xmmm64 } => {
+                crate::encoders::safe::cvttsd2si::encode_reg64_xmmm64(reg64, xmmm64)
+            }
+            Self::MovdXmmRm32 { xmm, rm32 } => crate::encoders::safe::movd::encode_xmm_rm32(xmm, rm32),
+            Self::MovdRm32Xmm { rm32, xmm } => crate::encoders::safe::movd::encode_rm32_xmm(rm32, xmm),
+            Self::MovqXmmRm64 { xmm, rm64 } => crate::encoders::safe::movq::encode_xmm_rm64(xmm, rm64),
+            Self::MovqRm64Xmm { rm64, xmm } => crate::encoders::safe::movq::encode_rm64_xmm(rm64, xmm),
+            Self::MovapsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::movaps::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MovapsXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::movaps::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::MovupsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::movups::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MovupsXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::movups::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::MovdqaXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::movdqa::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MovdqaXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::movdqa::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::MovdquXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::movdqu::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MovdquXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::movdqu::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::AddpsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::addps::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::AddpdXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::addpd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MulpsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::mulps::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::MulpdXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::mulpd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PaddbXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::paddb::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PaddwXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::paddw::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PadddXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::paddd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PaddqXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::paddq::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PsubbXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::psubb::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PsubwXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::psubw::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PsubdXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::psubd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PsubqXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::psubq::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PandXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pand::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PorXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::por::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PxorXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pxor::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpeqbXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpeqb::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpeqwXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpeqw::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpeqdXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpeqd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpeqqXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpeqq::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpgtbXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpgtb::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpgtwXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpgtw::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpgtdXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpgtd::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PcmpgtqXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pcmpgtq::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PshufdXmmXmmm128Imm8 { xmm, xmmm128, imm8 } => {
+                crate::encoders::safe::pshufd::encode_xmm_xmmm128_imm8(xmm, xmmm128, imm8)
+            }
+            Self::PshufbXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::pshufb::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::PunpcklbwXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpcklbw::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpcklwdXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpcklwd::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpckldqXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpckldq::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpcklqdqXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpcklqdq::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpckhbwXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpckhbw::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpckhwdXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpckhwd::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpckhdqXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpckhdq::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PunpckhqdqXmmXmmm128 { xmm, xmmm128 } => {
+                crate::encoders::safe::punpckhqdq::encode_xmm_xmmm128(xmm, xmmm128)
+            }
+            Self::PmovmskbReg32Xmm { reg32, xmm } => crate::encoders::safe::pmovmskb::encode_reg32_xmm(reg32, xmm),
+            Self::PinsrbXmmR32m8Imm8 { xmm, r32m8, imm8 } => {
+                crate::encoders::safe::pinsrb::encode_xmm_r32m8_imm8(xmm, r32m8, imm8)
+            }
+            Self::PinsrwXmmR32m16Imm8 { xmm, r32m16, imm8 } => {
+                crate::encoders::safe::pinsrw::encode_xmm_r32m16_imm8(xmm, r32m16, imm8)
+            }
+            Self::PinsrdXmmRm32Imm8 { xmm, rm32, imm8 } => {
+                crate::encoders::safe::pinsrd::encode_xmm_rm32_imm8(xmm, rm32, imm8)
+            }
+            Self::PinsrqXmmRm64Imm8 { xmm, rm64, imm8 } => {
+                crate::encoders::safe::pinsrq::encode_xmm_rm64_imm8(xmm, rm64, imm8)
+            }
+            Self::PextrbR32m8XmmImm8 { r32m8, xmm, imm8 } => {
+                crate::encoders::safe::pextrb::encode_r32m8_xmm_imm8(r32m8, xmm, imm8)
+            }
+            Self::PextrwReg32XmmImm8 { reg32, xmm, imm8 } => {
+                crate::encoders::safe::pextrw::encode_reg32_xmm_imm8(reg32, xmm, imm8)
+            }
+            Self::PextrwR32m16XmmImm8 { r32m16, xmm, imm8 } => {
+                crate::encoders::safe::pextrw::encode_r32m16_xmm_imm8(r32m16, xmm, imm8)
+            }
+            Self::PextrdRm32XmmImm8 { rm32, xmm, imm8 } => {
+                crate::encoders::safe::pextrd::encode_rm32_xmm_imm8(rm32, xmm, imm8)
+            }
+            Self::PextrqRm64XmmImm8 { rm64, xmm, imm8 } => {
+                crate::encoders::safe::pextrq::encode_rm64_xmm_imm8(rm64, xmm, imm8)
+            }
+            Self::BlendvpsXmmXmmm128Xmm0 { xmm, xmmm128 } => {
+                crate::encoders::safe::blendvps::encode_xmm_xmmm128_xmm0(xmm, xmmm128)
+            }
+            Self::BlendvpdXmmXmmm128Xmm0 { xmm, xmmm128 } => {
+                crate::encoders::safe::blendvpd::encode_xmm_xmmm128_xmm0(xmm, xmmm128)
+            }
+            Self::PblendvbXmmXmmm128Xmm0 { xmm, xmmm128 } => {
+                crate::encoders::safe::pblendvb::encode_xmm_xmmm128_xmm0(xmm, xmmm128)
+            }
+            Self::PtestXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::ptest::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::VaddpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vaddps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VaddpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vaddps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VaddpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vaddps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VaddpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vaddps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VaddpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vaddpd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VaddpdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vaddpd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VaddpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vaddpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VaddpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vaddpd::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VmulpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vmulps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VmulpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vmulps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VmulpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vmulps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VmulpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vmulps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VmulpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vmulpd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VmulpdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vmulpd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VmulpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vmulpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VmulpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vmulpd::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VpaddbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpaddb::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpaddbYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpaddb::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpaddwXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpaddw::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpaddwYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpaddw::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpadddXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpaddd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpadddYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpaddd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpadddZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpaddd::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VpaddqXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpaddq::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpaddqYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpaddq::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpaddqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpaddq::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VpandXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpand::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpandYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpand::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VporXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpor::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VporYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpor::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpxorXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpxor::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpxorYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpxor::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpshufbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vpshufb::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::VpshufbYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpshufb::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpermdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpermd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpermpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vpermps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::VpbroadcastbXmmXmmm8 { xmm, xmmm8 } => {
+                crate::encoders::safe::vpbroadcastb::encode_xmm_xmmm8(xmm, xmmm8)
+            }
+            Self::VpbroadcastbYmmXmmm8 { ymm, xmmm8 } => {
+                crate::encoders::safe::vpbroadcastb::encode_ymm_xmmm8(ymm, xmmm8)
+            }
+            Self::VpbroadcastwXmmXmmm16 { xmm, xmmm16 } => {
+                crate::encoders::safe::vpbroadcastw::encode_xmm_xmmm16(xmm, xmmm16)
+            }
+            Self::VpbroadcastwYmmXmmm16 { ymm, xmmm16 } => {
+                crate::encoders::safe::vpbroadcastw::encode_ymm_xmmm16(ymm, xmmm16)
+            }
+            Self::VpbroadcastdXmmXmmm32 { xmm, xmmm32 } => {
+                crate::encoders::safe::vpbroadcastd::encode_xmm_xmmm32(xmm, xmmm32)
+            }
+            Self::VpbroadcastdYmmXmmm32 { ymm, xmmm32 } => {
+                crate::encoders::safe::vpbroadcastd::encode_ymm_xmmm32(ymm, xmmm32)
+            }
+            Self::VpbroadcastqXmmXmmm64 { xmm, xmmm64 } => {
+                crate::encoders::safe::vpbroadcastq::encode_xmm_xmmm64(xmm, xmmm64)
+            }
+            Self::VpbroadcastqYmmXmmm64 { ymm, xmmm64 } => {
+                crate::encoders::safe::vpbroadcastq::encode_ymm_xmmm64(ymm, xmmm64)
+            }
+            Self::VmovapsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::vmovaps::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::VmovapsXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::vmovaps::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::VmovapsYmmYmmm256 { ymm, ymmm256 } => crate::encoders::safe::vmovaps::encode_ymm_ymmm256(ymm, ymmm256),
+            Self::VmovapsYmmm256Ymm { ymmm256, ymm } => crate::encoders::safe::vmovaps::encode_ymmm256_ymm(ymmm256, ymm),
+            Self::VmovupsXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::vmovups::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::VmovupsXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::vmovups::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::VmovupsYmmYmmm256 { ymm, ymmm256 } => crate::encoders::safe::vmovups::encode_ymm_ymmm256(ymm, ymmm256),
+            Self::VmovupsYmmm256Ymm { ymmm256, ymm } => crate::encoders::safe::vmovups::encode_ymmm256_ymm(ymmm256, ymm),
+            Self::VmovupsZmmMaskZmmm512 { zmm, mask, zmmm512 } => {
+                crate::encoders::safe::vmovups::encode_zmm_mask_zmmm512(zmm, mask, zmmm512)
+            }
+            Self::VmovupsZmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => crate::encoders::safe::vmovups::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm),
+            Self::VmovdqaXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::vmovdqa::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::VmovdqaXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::vmovdqa::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::VmovdqaYmmYmmm256 { ymm, ymmm256 } => crate::encoders::safe::vmovdqa::encode_ymm_ymmm256(ymm, ymmm256),
+            Self::VmovdqaYmmm256Ymm { ymmm256, ymm } => crate::encoders::safe::vmovdqa::encode_ymmm256_ymm(ymmm256, ymm),
+            Self::VmovdquXmmXmmm128 { xmm, xmmm128 } => crate::encoders::safe::vmovdqu::encode_xmm_xmmm128(xmm, xmmm128),
+            Self::VmovdquXmmm128Xmm { xmmm128, xmm } => crate::encoders::safe::vmovdqu::encode_xmmm128_xmm(xmmm128, xmm),
+            Self::VmovdquYmmYmmm256 { ymm, ymmm256 } => crate::encoders::safe::vmovdqu::encode_ymm_ymmm256(ymm, ymmm256),
+            Self::VmovdquYmmm256Ymm { ymmm256, ymm } => crate::encoders::safe::vmovdqu::encode_ymmm256_ymm(ymmm256, ymm),
+            Self::Vzeroupper => crate::encoders::safe::vzeroupper::encode(),
+            Self::VsubpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vsubps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VsubpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vsubps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VsubpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vsubpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VsubpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vsubpd::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VdivpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vdivps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VdivpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vdivps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VdivpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vdivpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VdivpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => crate::encoders::safe::vdivpd::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er),
+            Self::VminpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vminps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VminpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => {
+                crate::encoders::safe::vminps::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr)
+            }
+            Self::VminpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vminpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VminpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => {
+                crate::encoders::safe::vminpd::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr)
+            }
+            Self::VmaxpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vmaxps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VmaxpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => {
+                crate::encoders::safe::vmaxps::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr)
+            }
+            Self::VmaxpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vmaxpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VmaxpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => {
+                crate::encoders::safe::vmaxpd::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr)
+            }
+            Self::VsqrtpsZmmMaskZmmm512b32 { zmm, mask, zmmm512b32 } => {
+                crate::encoders::safe::vsqrtps::encode_zmm_mask_zmmm512b32(zmm, mask, zmmm512b32)
+            }
+            Self::VsqrtpsZmmMaskZmmrEr { zmm, mask, zmmr, er } => {
+                crate::encoders::safe::vsqrtps::encode_zmm_mask_zmmr_er(zmm, mask, zmmr, er)
+            }
+            Self::VsqrtpdZmmMaskZmmm512b64 { zmm, mask, zmmm512b64 } => {
+                crate::encoders::safe::vsqrtpd::encode_zmm_mask_zmmm512b64(zmm, mask, zmmm512b64)
+            }
+            Self::VsqrtpdZmmMaskZmmrEr { zmm, mask, zmmr, er } => {
+                crate::encoders::safe::vsqrtpd::encode_zmm_mask_zmmr_er(zmm, mask, zmmr, er)
+            }
+            Self::VaddssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => crate::encoders::safe::vaddss::encode_xmm_mask_vxmm_xmmm32(xmm, mask, vxmm, xmmm32),
+            Self::VaddssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vaddss::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VaddsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => crate::encoders::safe::vaddsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64),
+            Self::VaddsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vaddsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VsubssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => crate::encoders::safe::vsubss::encode_xmm_mask_vxmm_xmmm32(xmm, mask, vxmm, xmmm32),
+            Self::VsubssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vsubss::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VsubsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => crate::encoders::safe::vsubsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64),
+            Self::VsubsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vsubsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VmulssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => crate::encoders::safe::vmulss::encode_xmm_mask_vxmm_xmmm32(xmm, mask, vxmm, xmmm32),
+            Self::VmulssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vmulss::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VmulsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => crate::encoders::safe::vmulsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64),
+            Self::VmulsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vmulsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VdivssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => crate::encoders::safe::vdivss::encode_xmm_mask_vxmm_xmmm32(xmm, mask, vxmm, xmmm32),
+            Self::VdivssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vdivss::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VdivsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => crate::encoders::safe::vdivsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64),
+            Self::VdivsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => crate::encoders::safe::vdivsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er),
+            Self::VpsubdZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpsubd::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VpsubqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpsubq::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VpmulldZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpmulld::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VpanddZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpandd::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VpandqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpandq::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VpordZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpord::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VporqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vporq::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::VpxordZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpxord::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32),
+            Self::VpxorqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpxorq::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64),
+            Self::Vmovdqu32ZmmMaskZmmm512 { zmm, mask, zmmm512 } => {
+                crate::encoders::safe::vmovdqu32::encode_zmm_mask_zmmm512(zmm, mask, zmmm512)
+            }
+            Self::Vmovdqu32Zmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => crate::encoders::safe::vmovdqu32::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm),
+            Self::Vmovdqu64ZmmMaskZmmm512 { zmm, mask, zmmm512 } => {
+                crate::encoders::safe::vmovdqu64::encode_zmm_mask_zmmm512(zmm, mask, zmmm512)
+            }
+            Self::Vmovdqu64Zmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => crate::encoders::safe::vmovdqu64::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm),
+            Self::VcmppsKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => crate::encoders::safe::vcmpps::encode_k_mergemask_vzmm_zmmm512b32_imm8(
+                k, mergemask, vzmm, zmmm512b32, imm8,
+            ),
+            Self::VcmppsKMergemaskVzmmZmmrSaeImm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmr,
+                imm8,
+            } => crate::encoders::safe::vcmpps::encode_k_mergemask_vzmm_zmmr_sae_imm8(k, mergemask, vzmm, zmmr, imm8),
+            Self::VcmppdKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => crate::encoders::safe::vcmppd::encode_k_mergemask_vzmm_zmmm512b64_imm8(
+                k, mergemask, vzmm, zmmm512b64, imm8,
+            ),
+            Self::VcmppdKMergemaskVzmmZmmrSaeImm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmr,
+                imm8,
+            } => crate::encoders::safe::vcmppd::encode_k_mergemask_vzmm_zmmr_sae_imm8(k, mergemask, vzmm, zmmr, imm8),
+            Self::VpcmpeqdKMergemaskVzmmZmmm512b32 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpcmpeqd::encode_k_mergemask_vzmm_zmmm512b32(k, mergemask, vzmm, zmmm512b32),
+            Self::VpcmpgtdKMergemaskVzmmZmmm512b32 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+            } => crate::encoders::safe::vpcmpgtd::encode_k_mergemask_vzmm_zmmm512b32(k, mergemask, vzmm, zmmm512b32),
+            Self::VpcmpeqqKMergemaskVzmmZmmm512b64 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpcmpeqq::encode_k_mergemask_vzmm_zmmm512b64(k, mergemask, vzmm, zmmm512b64),
+            Self::VpcmpgtqKMergemaskVzmmZmmm512b64 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+            } => crate::encoders::safe::vpcmpgtq::encode_k_mergemask_vzmm_zmmm512b64(k, mergemask, vzmm, zmmm512b64),
+            Self::VpcmpdKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => crate::encoders::safe::vpcmpd::encode_k_mergemask_vzmm_zmmm512b32_imm8(
+                k, mergemask, vzmm, zmmm512b32, imm8,
+            ),
+            Self::VpcmpudKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => crate::encoders::safe::vpcmpud::encode_k_mergemask_vzmm_zmmm512b32_imm8(
+                k, mergemask, vzmm, zmmm512b32, imm8,
+            ),
+            Self::VpcmpqKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => crate::encoders::safe::vpcmpq::encode_k_mergemask_vzmm_zmmm512b64_imm8(
+                k, mergemask, vzmm, zmmm512b64, imm8,
+            ),
+            Self::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => crate::encoders::safe::vpcmpuq::encode_k_mergemask_vzmm_zmmm512b64_imm8(
+                k, mergemask, vzmm, zmmm512b64, imm8,
+            ),
+            Self::KmovwKKm16 { k, km16 } => crate::encoders::safe::kmovw::encode_k_km16(k, km16),
+            Self::KmovwMem16K { mem16, k } => crate::encoders::safe::kmovw::encode_mem16_k(mem16, k),
+            Self::KmovwKReg32 { k, reg32 } => crate::encoders::safe::kmovw::encode_k_reg32(k, reg32),
+            Self::KmovwReg32Kr { reg32, kr } => crate::encoders::safe::kmovw::encode_reg32_kr(reg32, kr),
+            Self::KandwKVkKr { k, vk, kr } => crate::encoders::safe::kandw::encode_k_vk_kr(k, vk, kr),
+            Self::KandnwKVkKr { k, vk, kr } => crate::encoders::safe::kandnw::encode_k_vk_kr(k, vk, kr),
+            Self::KorwKVkKr { k, vk, kr } => crate::encoders::safe::korw::encode_k_vk_kr(k, vk, kr),
+            Self::KxnorwKVkKr { k, vk, kr } => crate::encoders::safe::kxnorw::encode_k_vk_kr(k, vk, kr),
+            Self::KxorwKVkKr { k, vk, kr } => crate::encoders::safe::kxorw::encode_k_vk_kr(k, vk, kr),
+            Self::KnotwKKr { k, kr } => crate::encoders::safe::knotw::encode_k_kr(k, kr),
+            Self::KortestwKKr { k, kr } => crate::encoders::safe::kortestw::encode_k_kr(k, kr),
+            Self::Vfmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd132ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd132ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd132pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd132pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmadd132ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmadd132sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd213ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd213ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd213pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd213pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmadd213ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmadd213sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd231ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd231ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmadd231pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmadd231pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmadd231ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmadd231sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub132ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub132ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub132pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub132pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmsub132ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmsub132sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub213ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub213ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub213pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub213pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmsub213ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmsub213sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub231ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub231ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfmsub231pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfmsub231pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfmsub231ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfmsub231sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd132ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd132ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd132pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd132pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmadd132ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmadd132sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd213ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd213ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd213pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd213pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmadd213ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmadd213sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd231ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd231ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmadd231pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmadd231pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmadd231ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmadd231sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub132ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub132ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub132pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub132pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmsub132ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmsub132sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub213ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub213ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub213pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub213pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmsub213ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmsub213sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vfnmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub231ps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub231ps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => {
+                crate::encoders::safe::vfnmsub231pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128)
+            }
+            Self::Vfnmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => {
+                crate::encoders::safe::vfnmsub231pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256)
+            }
+            Self::Vfnmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => {
+                crate::encoders::safe::vfnmsub231ss::encode_xmm_vxmm_xmmm32(xmm, vxmm, xmmm32)
+            }
+            Self::Vfnmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => {
+                crate::encoders::safe::vfnmsub231sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64)
+            }
+            Self::Vcvtph2psXmmXmmm64 { xmm, xmmm64 } => crate::encoders::safe::vcvtph2ps::encode_xmm_xmmm64(xmm, xmmm64),
+            Self::Vcvtph2psYmmXmmm128 { ymm, xmmm128 } => {
+                crate::encoders::safe::vcvtph2ps::encode_ymm_xmmm128(ymm, xmmm128)
+            }
+            Self::Vcvtps2phXmmm64XmmImm8 { xmmm64, xmm, imm8 } => {
+                crate::encoders::safe::vcvtps2ph::encode_xmmm64_xmm_imm8(xmmm64, xmm, imm8)
+            }
+            Self::Vcvtps2phXmmm128YmmImm8 { xmmm128, ymm, imm8 } => {
+                crate::encoders::safe::vcvtps2ph::encode_xmmm128_ymm_imm8(xmmm128, ymm, imm8)
+            }
+            Self::VpgatherddXmmVm32xVxmm { xmm, vm32x, vxmm } => {
+                crate::encoders::safe::vpgatherdd::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm)
+            }
+            Self::VpgatherddYmmVm32yVymm { ymm, vm32y, vymm } => {
+                crate::encoders::safe::vpgatherdd::encode_ymm_vm32y_vymm(ymm, vm32y, vymm)
+            }
+            Self::VpgatherddZmmMergemaskVm32z { zmm, mergemask, vm32z } => {
+                crate::encoders::safe::vpgatherdd::encode_zmm_mergemask_vm32z(zmm, mergemask, vm32z)
+            }
+            Self::VpgatherdqXmmVm32xVxmm { xmm, vm32x, vxmm } => {
+                crate::encoders::safe::vpgatherdq::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm)
+            }
+            Self::VpgatherdqYmmVm32xVymm { ymm, vm32x, vymm } => {
+                crate::encoders::safe::vpgatherdq::encode_ymm_vm32x_vymm(ymm, vm32x, vymm)
+            }
+            Self::VpgatherdqZmmMergemaskVm32y { zmm, mergemask, vm32y } => {
+                crate::encoders::safe::vpgatherdq::encode_zmm_mergemask_vm32y(zmm, mergemask, vm32y)
+            }
+            Self::VpgatherqdXmmVm64xVxmm { xmm, vm64x, vxmm } => {
+                crate::encoders::safe::vpgatherqd::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm)
+            }
+            Self::VpgatherqdXmmVm64yVxmm { xmm, vm64y, vxmm } => {
+                crate::encoders::safe::vpgatherqd::encode_xmm_vm64y_vxmm(xmm, vm64y, vxmm)
+            }
+            Self::VpgatherqdYmmMergemaskVm64z { ymm, mergemask, vm64z } => {
+                crate::encoders::safe::vpgatherqd::encode_ymm_mergemask_vm64z(ymm, mergemask, vm64z)
+            }
+            Self::VpgatherqqXmmVm64xVxmm { xmm, vm64x, vxmm } => {
+                crate::encoders::safe::vpgatherqq::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm)
+            }
+            Self::VpgatherqqYmmVm64yVymm { ymm, vm64y, vymm } => {
+                crate::encoders::safe::vpgatherqq::encode_ymm_vm64y_vymm(ymm, vm64y, vymm)
+            }
+            Self::VpgatherqqZmmMergemaskVm64z { zmm, mergemask, vm64z } => {
+                crate::encoders::safe::vpgatherqq::encode_zmm_mergemask_vm64z(zmm, mergemask, vm64z)
+            }
+            Self::VgatherdpsXmmVm32xVxmm { xmm, vm32x, vxmm } => {
+                crate::encoders::safe::vgatherdps::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm)
+            }
+            Self::VgatherdpsYmmVm32yVymm { ymm, vm32y, vymm } => {
+                crate::encoders::safe::vgatherdps::encode_ymm_vm32y_vymm(ymm, vm32y, vymm)
+            }
+            Self::VgatherdpsZmmMergemaskVm32z { zmm, mergemask, vm32z } => {
+                crate::encoders::safe::vgatherdps::encode_zmm_mergemask_vm32z(zmm, mergemask, vm32z)
+            }
+            Self::VgatherdpdXmmVm32xVxmm { xmm, vm32x, vxmm } => {
+                crate::encoders::safe::vgatherdpd::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm)
+            }
+            Self::VgatherdpdYmmVm32xVymm { ymm, vm32x, vymm } => {
+                crate::encoders::safe::vgatherdpd::encode_ymm_vm32x_vymm(ymm, vm32x, vymm)
+            }
+            Self::VgatherdpdZmmMergemaskVm32y { zmm, mergemask, vm32y } => {
+                crate::encoders::safe::vgatherdpd::encode_zmm_mergemask_vm32y(zmm, mergemask, vm32y)
+            }
+            Self::VgatherqpsXmmVm64xVxmm { xmm, vm64x, vxmm } => {
+                crate::encoders::safe::vgatherqps::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm)
+            }
+            Self::VgatherqpsXmmVm64yVxmm { xmm, vm64y, vxmm } => {
+                crate::encoders::safe::vgatherqps::encode_xmm_vm64y_vxmm(xmm, vm64y, vxmm)
+            }
+            Self::VgatherqpsYmmMergemaskVm64z { ymm, mergemask, vm64z } => {
+                crate::encoders::safe::vgatherqps::encode_ymm_mergemask_vm64z(ymm, mergemask, vm64z)
+            }
+            Self::VgatherqpdXmmVm64xVxmm { xmm, vm64x, vxmm } => {
+                crate::encoders::safe::vgatherqpd::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm)
+            }
+            Self::VgatherqpdYmmVm64yVymm { ymm, vm64y, vymm } => {
+                crate::encoders::safe::vgatherqpd::encode_ymm_vm64y_vymm(ymm, vm64y, vymm)
+            }
+            Self::VgatherqpdZmmMergemaskVm64z { zmm, mergemask, vm64z } => {
+                crate::encoders::safe::vgatherqpd::encode_zmm_mergemask_vm64z(zmm, mergemask, vm64z)
+            }
+            Self::VpscatterddVm32zMergemaskZmm { vm32z, mergemask, zmm } => {
+                crate::encoders::safe::vpscatterdd::encode_vm32z_mergemask_zmm(vm32z, mergemask, zmm)
+            }
+            Self::VpscatterdqVm32yMergemaskZmm { vm32y, mergemask, zmm } => {
+                crate::encoders::safe::vpscatterdq::encode_vm32y_mergemask_zmm(vm32y, mergemask, zmm)
+            }
+            Self::VpscatterqdVm64zMergemaskYmm { vm64z, mergemask, ymm } => {
+                crate::encoders::safe::vpscatterqd::encode_vm64z_mergemask_ymm(vm64z, mergemask, ymm)
+            }
+            Self::VpscatterqqVm64zMergemaskZmm { vm64z, mergemask, zmm } => {
+                crate::encoders::safe::vpscatterqq::encode_vm64z_mergemask_zmm(vm64z, mergemask, zmm)
+            }
+            Self::VscatterdpsVm32zMergemaskZmm { vm32z, mergemask, zmm } => {
+                crate::encoders::safe::vscatterdps::encode_vm32z_mergemask_zmm(vm32z, mergemask, zmm)
+            }
+            Self::VscatterdpdVm32yMergemaskZmm { vm32y, mergemask, zmm } => {
+                crate::encoders::safe::vscatterdpd::encode_vm32y_mergemask_zmm(vm32y, mergemask, zmm)
+            }
+            Self::VscatterqpsVm64zMergemaskYmm { vm64z, mergemask, ymm } => {
+                crate::encoders::safe::vscatterqps::encode_vm64z_mergemask_ymm(vm64z, mergemask, ymm)
+            }
+            Self::VscatterqpdVm64zMergemaskZmm { vm64z, mergemask, zmm } => {
+                crate::encoders::safe::vscatterqpd::encode_vm64z_mergemask_zmm(vm64z, mergemask, zmm)
+            }
+        }
+    }
+
+    /// Returns the mnemonic of the instruction, as used by assemblers, e.g. `"mov"`.
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub const fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Lock => "lock",
+            Self::Nop => "nop",
+            Self::Cpuid => "cpuid",
+            Self::Sysenter => "sysenter",
+            Self::Syscall => "syscall",
+            Self::Ret | Self::RetImm16 { .. } => "ret",
+            Self::MovRm8Imm8 { .. }
+            | Self::MovRm16Imm16 { .. }
+            | Self::MovRm32Imm32 { .. }
+            | Self::MovRm64Imm32 { .. }
+            | Self::MovReg8Imm8 { .. }
+            | Self::MovReg16Imm16 { .. }
+            | Self::MovReg32Imm32 { .. }
+            | Self::MovReg64Imm64 { .. }
+            | Self::MovRm8Reg8 { .. }
+            | Self::MovRm16Reg16 { .. }
+            | Self::MovRm32Reg32 { .. }
+            | Self::MovRm64Reg64 { .. }
+            | Self::MovReg8Rm8 { .. }
+            | Self::MovReg16Rm16 { .. }
+            | Self::MovReg32Rm32 { .. }
+            | Self::MovReg64Rm64 { .. } => "mov",
+            Self::AddAlImm8 { .. }
+            | Self::AddAxImm16 { .. }
+            | Self::AddEaxImm32 { .. }
+            | Self::AddRaxImm32 { .. }
+            | Self::AddRm8Imm8 { .. }
+            | Self::AddLockRm8Imm8 { .. }
+            | Self::AddRm16Imm16 { .. }
+            | Self::AddLockRm16Imm16 { .. }
+            | Self::AddRm32Imm32 { .. }
+            | Self::AddLockRm32Imm32 { .. }
+            | Self::AddRm64Imm32 { .. }
+            | Self::AddLockRm64Imm32 { .. }
+            | Self::AddRm16Imm8 { .. }
+            | Self::AddLockRm16Imm8 { .. }
+            | Self::AddRm32Imm8 { .. }
+            | Self::AddLockRm32Imm8 { .. }
+            | Self::AddRm64Imm8 { .. }
+            | Self::AddLockRm64Imm8 { .. }
+            | Self::AddRm8Reg8 { .. }
+            | Self::AddLockRm8Reg8 { .. }
+            | Self::AddRm16Reg16 { .. }
+            | Self::AddLockRm16Reg16 { .. }
+            | Self::AddRm32Reg32 { .. }
+            | Self::AddLockRm32Reg32 { .. }
+            | Self::AddRm64Reg64 { .. }
+            | Self::AddLockRm64Reg64 { .. }
+            | Self::AddReg8Rm8 { .. }
+            | Self::AddReg16Rm16 { .. }
+            | Self::AddReg32Rm32 { .. }
+            | Self::AddReg64Rm64 { .. } => "add",
+            Self::JmpImm8 { .. } | Self::JmpImm32 { .. } | Self::JmpRm64 { .. } => "jmp",
+            Self::JccAImm8 { .. } | Self::JccAImm32 { .. } => "ja",
+            Self::JccAeImm8 { .. } | Self::JccAeImm32 { .. } => "jae",
+            Self::JccBImm8 { .. } | Self::JccBImm32 { .. } => "jb",
+            Self::JccBeImm8 { .. } | Self::JccBeImm32 { .. } => "jbe",
+            Self::JccEImm8 { .. } | Self::JccEImm32 { .. } => "je",
+            Self::JccGImm8 { .. } | Self::JccGImm32 { .. } => "jg",
+            Self::JccGeImm8 { .. } | Self::JccGeImm32 { .. } => "jge",
+            Self::JccLImm8 { .. } | Self::JccLImm32 { .. } => "jl",
+            Self::JccLeImm8 { .. } | Self::JccLeImm32 { .. } => "jle",
+            Self::JccNeImm8 { .. } | Self::JccNeImm32 { .. } => "jne",
+            Self::JccNoImm8 { .. } | Self::JccNoImm32 { .. } => "jno",
+            Self::JccNpImm8 { .. } | Self::JccNpImm32 { .. } => "jnp",
+            Self::JccNsImm8 { .. } | Self::JccNsImm32 { .. } => "jns",
+            Self::JccOImm8 { .. } | Self::JccOImm32 { .. } => "jo",
+            Self::JccPImm8 { .. } | Self::JccPImm32 { .. } => "jp",
+            Self::JccPeImm8 { .. } | Self::JccPeImm32 { .. } => "jpe",
+            Self::JccPoImm8 { .. } | Self::JccPoImm32 { .. } => "jpo",
+            Self::JccSImm8 { .. } | Self::JccSImm32 { .. } => "js",
+            Self::JccCImm8 { .. } | Self::JccCImm32 { .. } => "jc",
+            Self::JccNcImm8 { .. } | Self::JccNcImm32 { .. } => "jnc",
+            Self::CallImm32 { .. } | Self::CallRm64 { .. } => "call",
+            Self::CmpAlImm8 { .. }
+            | Self::CmpAxImm16 { .. }
+            | Self::CmpEaxImm32 { .. }
+            | Self::CmpRaxImm32 { .. }
+            | Self::CmpRm8Imm8 { .. }
+            | Self::CmpRm16Imm16 { .. }
+            | Self::CmpRm32Imm32 { .. }
+            | Self::CmpRm64Imm32 { .. }
+            | Self::CmpRm16Imm8 { .. }
+            | Self::CmpRm32Imm8 { .. }
+            | Self::CmpRm64Imm8 { .. }
+            | Self::CmpRm8Reg8 { .. }
+            | Self::CmpRm16Reg16 { .. }
+            | Self::CmpRm32Reg32 { .. }
+            | Self::CmpRm64Reg64 { .. }
+            | Self::CmpReg8Rm8 { .. }
+            | Self::CmpReg16Rm16 { .. }
+            | Self::CmpReg32Rm32 { .. }
+            | Self::CmpReg64Rm64 { .. } => "cmp",
+            Self::LeaReg16Mem64 { .. } | Self::LeaReg32Mem64 { .. } | Self::LeaReg64Mem64 { .. } => "lea",
+            Self::PushRm64 { .. }
+            | Self::PushReg64 { .. }
+            | Self::PushImm8 { .. }
+            | Self::PushImm16 { .. }
+            | Self::PushImm32 { .. } => "push",
+            Self::PopRm64 { .. } | Self::PopReg64 { .. } => "pop",
+            Self::SubAlImm8 { .. }
+            | Self::SubAxImm16 { .. }
+            | Self::SubEaxImm32 { .. }
+            | Self::SubRaxImm32 { .. }
+            | Self::SubRm8Imm8 { .. }
+            | Self::SubLockRm8Imm8 { .. }
+            | Self::SubRm16Imm16 { .. }
+            | Self::SubLockRm16Imm16 { .. }
+            | Self::SubRm32Imm32 { .. }
+            | Self::SubLockRm32Imm32 { .. }
+            | Self::SubRm64Imm32 { .. }
+            | Self::SubLockRm64Imm32 { .. }
+            | Self::SubRm16Imm8 { .. }
+            | Self::SubLockRm16Imm8 { .. }
+            | Self::SubRm32Imm8 { .. }
+            | Self::SubLockRm32Imm8 { .. }
+            | Self::SubRm64Imm8 { .. }
+            | Self::SubLockRm64Imm8 { .. }
+            | Self::SubRm8Reg8 { .. }
+            | Self::SubLockRm8Reg8 { .. }
+            | Self::SubRm16Reg16 { .. }
+            | Self::SubLockRm16Reg16 { .. }
+            | Self::SubRm32Reg32 { .. }
+            | Self::SubLockRm32Reg32 { .. }
+            | Self::SubRm64Reg64 { .. }
+            | Self::SubLockRm64Reg64 { .. }
+            | Self::SubReg8Rm8 { .. }
+            | Self::SubReg16Rm16 { .. }
+            | Self::SubReg32Rm32 { .. }
+            | Self::SubReg64Rm64 { .. } => "sub",
+            Self::XorAlImm8 { .. }
+            | Self::XorAxImm16 { .. }
+            | Self::XorEaxImm32 { .. }
+            | Self::XorRaxImm32 { .. }
+            | Self::XorRm8Imm8 { .. }
+            | Self::XorLockRm8Imm8 { .. }
+            | Self::XorRm16Imm16 { .. }
+            | Self::XorLockRm16Imm16 { .. }
+            | Self::XorRm32Imm32 { .. }
+            | Self::XorLockRm32Imm32 { .. }
+            | Self::XorRm64Imm32 { .. }
+            | Self::XorLockRm64Imm32 { .. }
+            | Self::XorRm16Imm8 { .. }
+            | Self::XorLockRm16Imm8 { .. }
+            | Self::XorRm32Imm8 { .. }
+            | Self::XorLockRm32Imm8 { .. }
+            | Self::XorRm64Imm8 { .. }
+            | Self::XorLockRm64Imm8 { .. }
+            | Self::XorRm8Reg8 { .. }
+            | Self::XorLockRm8Reg8 { .. }
+            | Self::XorRm16Reg16 { .. }
+            | Self::XorLockRm16Reg16 { .. }
+            | Self::XorRm32Reg32 { .. }
+            | Self::XorLockRm32Reg32 { .. }
+            | Self::XorRm64Reg64 { .. }
+            | Self::XorLockRm64Reg64 { .. }
+            | Self::XorReg8Rm8 { .. }
+            | Self::XorReg16Rm16 { .. }
+            | Self::XorReg32Rm32 { .. }
+            | Self::XorReg64Rm64 { .. } => "xor",
+            Self::IntImm8 { .. } => "int",
+            Self::Int1 => "int1",
+            Self::Int3 => "int3",
+            Self::RolRm8One { .. }
+            | Self::RolRm16One { .. }
+            | Self::RolRm32One { .. }
+            | Self::RolRm64One { .. }
+            | Self::RolRm8Imm8 { .. }
+            | Self::RolRm16Imm8 { .. }
+            | Self::RolRm32Imm8 { .. }
+            | Self::RolRm64Imm8 { .. }
+            | Self::RolRm8Cl { .. }
+            | Self::RolRm16Cl { .. }
+            | Self::RolRm32Cl { .. }
+            | Self::RolRm64Cl { .. } => "rol",
+            Self::RorRm8One { .. }
+            | Self::RorRm16One { .. }
+            | Self::RorRm32One { .. }
+            | Self::RorRm64One { .. }
+            | Self::RorRm8Imm8 { .. }
+            | Self::RorRm16Imm8 { .. }
+            | Self::RorRm32Imm8 { .. }
+            | Self::RorRm64Imm8 { .. }
+            | Self::RorRm8Cl { .. }
+            | Self::RorRm16Cl { .. }
+            | Self::RorRm32Cl { .. }
+            | Self::RorRm64Cl { .. } => "ror",
+            Self::RclRm8One { .. }
+            | Self::RclRm16One { .. }
+            | Self::RclRm32One { .. }
+            | Self::RclRm64One { .. }
+            | Self::RclRm8Imm8 { .. }
+            | Self::RclRm16Imm8 { .. }
+            | Self::RclRm32Imm8 { .. }
+            | Self::RclRm64Imm8 { .. }
+            | Self::RclRm8Cl { .. }
+            | Self::RclRm16Cl { .. }
+            | Self::RclRm32Cl { .. }
+            | Self::RclRm64Cl { .. } => "rcl",
+            Self::RcrRm8One { .. }
+            | Self::RcrRm16One { .. }
+            | Self::RcrRm32One { .. }
+            | Self::RcrRm64One { .. }
+            | Self::RcrRm8Imm8 { .. }
+            | Self::RcrRm16Imm8 { .. }
+            | Self::RcrRm32Imm8 { .. }
+            | Self::RcrRm64Imm8 { .. }
+            | Self::RcrRm8Cl { .. }
+            | Self::RcrRm16Cl { .. }
+            | Self::RcrRm32Cl { .. }
+            | Self::RcrRm64Cl { .. } => "rcr",
+            Self::ShlRm8One { .. }
+            | Self::ShlRm16One { .. }
+            | Self::ShlRm32One { .. }
+            | Self::ShlRm64One { .. }
+            | Self::ShlRm8Imm8 { .. }
+            | Self::ShlRm16Imm8 { .. }
+            | Self::ShlRm32Imm8 { .. }
+            | Self::ShlRm64Imm8 { .. }
+            | Self::ShlRm8Cl { .. }
+            | Self::ShlRm16Cl { .. }
+            | Self::ShlRm32Cl { .. }
+            | Self::ShlRm64Cl { .. } => "shl",
+            Self::ShrRm8One { .. }
+            | Self::ShrRm16One { .. }
+            | Self::ShrRm32One { .. }
+            | Self::ShrRm64One { .. }
+            | Self::ShrRm8Imm8 { .. }
+            | Self::ShrRm16Imm8 { .. }
+            | Self::ShrRm32Imm8 { .. }
+            | Self::ShrRm64Imm8 { .. }
+            | Self::ShrRm8Cl { .. }
+            | Self::ShrRm16Cl { .. }
+            | Self::ShrRm32Cl { .. }
+            | Self::ShrRm64Cl { .. } => "shr",
+            Self::SarRm8One { .. }
+            | Self::SarRm16One { .. }
+            | Self::SarRm32One { .. }
+            | Self::SarRm64One { .. }
+            | Self::SarRm8Imm8 { .. }
+            | Self::SarRm16Imm8 { .. }
+            | Self::SarRm32Imm8 { .. }
+            | Self::SarRm64Imm8 { .. }
+            | Self::SarRm8Cl { .. }
+            | Self::SarRm16Cl { .. }
+            | Self::SarRm32Cl { .. }
+            | Self::SarRm64Cl { .. } => "sar",
+            Self::MulRm8 { .. } | Self::MulRm16 { .. } | Self::MulRm32 { .. } | Self::MulRm64 { .. } => "mul",
+            Self::ImulRm8 { .. }
+            | Self::ImulRm16 { .. }
+            | Self::ImulRm32 { .. }
+            | Self::ImulRm64 { .. }
+            | Self::ImulReg16Rm16 { .. }
+            | Self::ImulReg32Rm32 { .. }
+            | Self::ImulReg64Rm64 { .. }
+            | Self::ImulReg16Rm16Imm8 { .. }
+            | Self::ImulReg32Rm32Imm8 { .. }
+            | Self::ImulReg64Rm64Imm8 { .. }
+            | Self::ImulReg16Rm16Imm16 { .. }
+            | Self::ImulReg32Rm32Imm32 { .. }
+            | Self::ImulReg64Rm64Imm32 { .. } => "imul",
+            Self::DivRm8 { .. } | Self::DivRm16 { .. } | Self::DivRm32 { .. } | Self::DivRm64 { .. } => "div",
+            Self::IdivRm8 { .. } | Self::IdivRm16 { .. } | Self::IdivRm32 { .. } | Self::IdivRm64 { .. } => "idiv",
+            Self::AndAlImm8 { .. }
+            | Self::AndAxImm16 { .. }
+            | Self::AndEaxImm32 { .. }
+            | Self::AndRaxImm32 { .. }
+            | Self::AndRm8Imm8 { .. }
+            | Self::AndLockRm8Imm8 { .. }
+            | Self::AndRm16Imm16 { .. }
+            | Self::AndLockRm16Imm16 { .. }
+            | Self::AndRm32Imm32 { .. }
+            | Self::AndLockRm32Imm32 { .. }
+            | Self::AndRm64Imm32 { .. }
+            | Self::AndLockRm64Imm32 { .. }
+            | Self::AndRm16Imm8 { .. }
+            | Self::AndLockRm16Imm8 { .. }
+            | Self::AndRm32Imm8 { .. }
+            | Self::AndLockRm32Imm8 { .. }
+            | Self::AndRm64Imm8 { .. }
+            | Self::AndLockRm64Imm8 { .. }
+            | Self::AndRm8Reg8 { .. }
+            | Self::AndLockRm8Reg8 { .. }
+            | Self::AndRm16Reg16 { .. }
+            | Self::AndLockRm16Reg16 { .. }
+            | Self::AndRm32Reg32 { .. }
+            | Self::AndLockRm32Reg32 { .. }
+            | Self::AndRm64Reg64 { .. }
+            | Self::AndLockRm64Reg64 { .. }
+            | Self::AndReg8Rm8 { .. }
+            | Self::AndReg16Rm16 { .. }
+            | Self::AndReg32Rm32 { .. }
+            | Self::AndReg64Rm64 { .. } => "and",
+            Self::OrAlImm8 { .. }
+            | Self::OrAxImm16 { .. }
+            | Self::OrEaxImm32 { .. }
+            | Self::OrRaxImm32 { .. }
+            | Self::OrRm8Imm8 { .. }
+            | Self::OrLockRm8Imm8 { .. }
+            | Self::OrRm16Imm16 { .. }
+            | Self::OrLockRm16Imm16 { .. }
+            | Self::OrRm32Imm32 { .. }
+            | Self::OrLockRm32Imm32 { .. }
+            | Self::OrRm64Imm32 { .. }
+            | Self::OrLockRm64Imm32 { .. }
+            | Self::OrRm16Imm8 { .. }
+            | Self::OrLockRm16Imm8 { .. }
+            | Self::OrRm32Imm8 { .. }
+            | Self::OrLockRm32Imm8 { .. }
+            | Self::OrRm64Imm8 { .. }
+            | Self::OrLockRm64Imm8 { .. }
+            | Self::OrRm8Reg8 { .. }
+            | Self::OrLockRm8Reg8 { .. }
+            | Self::OrRm16Reg16 { .. }
+            | Self::OrLockRm16Reg16 { .. }
+            | Self::OrRm32Reg32 { .. }
+            | Self::OrLockRm32Reg32 { .. }
+            | Self::OrRm64Reg64 { .. }
+            | Self::OrLockRm64Reg64 { .. }
+            | Self::OrReg8Rm8 { .. }
+            | Self::OrReg16Rm16 { .. }
+            | Self::OrReg32Rm32 { .. }
+            | Self::OrReg64Rm64 { .. } => "or",
+            Self::AdcAlImm8 { .. }
+            | Self::AdcAxImm16 { .. }
+            | Self::AdcEaxImm32 { .. }
+            | Self::AdcRaxImm32 { .. }
+            | Self::AdcRm8Imm8 { .. }
+            | Self::AdcLockRm8Imm8 { .. }
+            | Self::AdcRm16Imm16 { .. }
+            | Self::AdcLockRm16Imm16 { .. }
+            | Self::AdcRm32Imm32 { .. }
+            | Self::AdcLockRm32Imm32 { .. }
+            | Self::AdcRm64Imm32 { .. }
+            | Self::AdcLockRm64Imm32 { .. }
+            | Self::AdcRm16Imm8 { .. }
+            | Self::AdcLockRm16Imm8 { .. }
+            | Self::AdcRm32Imm8 { .. }
+            | Self::AdcLockRm32Imm8 { .. }
+            | Self::AdcRm64Imm8 { .. }
+            | Self::AdcLockRm64Imm8 { .. }
+            | Self::AdcRm8Reg8 { .. }
+            | Self::AdcLockRm8Reg8 { .. }
+            | Self::AdcRm16Reg16 { .. }
+            | Self::AdcLockRm16Reg16 { .. }
+            | Self::AdcRm32Reg32 { .. }
+            | Self::AdcLockRm32Reg32 { .. }
+            | Self::AdcRm64Reg64 { .. }
+            | Self::AdcLockRm64Reg64 { .. }
+            | Self::AdcReg8Rm8 { .. }
+            | Self::AdcReg16Rm16 { .. }
+            | Self::AdcReg32Rm32 { .. }
+            | Self::AdcReg64Rm64 { .. } => "adc",
+            Self::SbbAlImm8 { .. }
+            | Self::SbbAxImm16 { .. }
+            | Self::SbbEaxImm32 { .. }
+            | Self::SbbRaxImm32 { .. }
+            | Self::SbbRm8Imm8 { .. }
+            | Self::SbbLockRm8Imm8 { .. }
+            | Self::SbbRm16Imm16 { .. }
+            | Self::SbbLockRm16Imm16 { .. }
+            | Self::SbbRm32Imm32 { .. }
+            | Self::SbbLockRm32Imm32 { .. }
+            | Self::SbbRm64Imm32 { .. }
+            | Self::SbbLockRm64Imm32 { .. }
+            | Self::SbbRm16Imm8 { .. }
+            | Self::SbbLockRm16Imm8 { .. }
+            | Self::SbbRm32Imm8 { .. }
+            | Self::SbbLockRm32Imm8 { .. }
+            | Self::SbbRm64Imm8 { .. }
+            | Self::SbbLockRm64Imm8 { .. }
+            | Self::SbbRm8Reg8 { .. }
+            | Self::SbbLockRm8Reg8 { .. }
+            | Self::SbbRm16Reg16 { .. }
+            | Self::SbbLockRm16Reg16 { .. }
+            | Self::SbbRm32Reg32 { .. }
+            | Self::SbbLockRm32Reg32 { .. }
+            | Self::SbbRm64Reg64 { .. }
+            | Self::SbbLockRm64Reg64 { .. }
+            | Self::SbbReg8Rm8 { .. }
+            | Self::SbbReg16Rm16 { .. }
+            | Self::SbbReg32Rm32 { .. }
+            | Self::SbbReg64Rm64 { .. } => "sbb",
+            Self::TestAlImm8 { .. }
+            | Self::TestAxImm16 { .. }
+            | Self::TestEaxImm32 { .. }
+            | Self::TestRaxImm32 { .. }
+            | Self::TestRm8Imm8 { .. }
+            | Self::TestRm16Imm16 { .. }
+            | Self::TestRm32Imm32 { .. }
+            | Self::TestRm64Imm32 { .. }
+            | Self::TestRm8Reg8 { .. }
+            | Self::TestRm16Reg16 { .. }
+            | Self::TestRm32Reg32 { .. }
+            | Self::TestRm64Reg64 { .. } => "test",
+            Self::NotRm8 { .. }
+            | Self::NotLockRm8 { .. }
+            | Self::NotRm16 { .. }
+            | Self::NotLockRm16 { .. }
+            | Self::NotRm32 { .. }
+            | Self::NotLockRm32 { .. }
+            | Self::NotRm64 { .. }
+            | Self::NotLockRm64 { .. } => "not",
+            Self::NegRm8 { .. }
+            | Self::NegLockRm8 { .. }
+            | Self::NegRm16 { .. }
+            | Self::NegLockRm16 { .. }
+            | Self::NegRm32 { .. }
+            | Self::NegLockRm32 { .. }
+            | Self::NegRm64 { .. }
+            | Self::NegLockRm64 { .. } => "neg",
+            Self::IncRm8 { .. }
+            | Self::IncLockRm8 { .. }
+            | Self::IncRm16 { .. }
+            | Self::IncLockRm16 { .. }
+            | Self::IncRm32 { .. }
+            | Self::IncLockRm32 { .. }
+            | Self::IncRm64 { .. }
+            | Self::IncLockRm64 { .. } => "inc",
+            Self::DecRm8 { .. }
+            | Self::DecLockRm8 { .. }
+            | Self::DecRm16 { .. }
+            | Self::DecLockRm16 { .. }
+            | Self::DecRm32 { .. }
+            | Self::DecLockRm32 { .. }
+            | Self::DecRm64 { .. }
+            | Self::DecLockRm64 { .. } => "dec",
+            Self::MovzxReg16Rm8 { .. }
+            | Self::MovzxReg32Rm8 { .. }
+            | Self::MovzxReg64Rm8 { .. }
+            | Self::MovzxReg32Rm16 { .. }
+            | Self::MovzxReg64Rm16 { .. } => "movzx",
+            Self::MovsxReg16Rm8 { .. }
+            | Self::MovsxReg32Rm8 { .. }
+            | Self::MovsxReg64Rm8 { .. }
+            | Self::MovsxReg32Rm16 { .. }
+            | Self::MovsxReg64Rm16 { .. } => "movsx",
+            Self::MovsxdReg64Rm32 { .. } => "movsxd",
+            Self::SetccARm8 { .. } => "seta",
+            Self::SetccAeRm8 { .. } => "setae",
+            Self::SetccBRm8 { .. } => "setb",
+            Self::SetccBeRm8 { .. } => "setbe",
+            Self::SetccERm8 { .. } => "sete",
+            Self::SetccGRm8 { .. } => "setg",
+            Self::SetccGeRm8 { .. } => "setge",
+            Self::SetccLRm8 { .. } => "setl",
+            Self::SetccLeRm8 { .. } => "setle",
+            Self::SetccNeRm8 { .. } => "setne",
+            Self::SetccNoRm8 { .. } => "setno",
+            Self::SetccNpRm8 { .. } => "setnp",
+            Self::SetccNsRm8 { .. } => "setns",
+            Self::SetccORm8 { .. } => "seto",
+            Self::SetccPRm8 { .. } => "setp",
+            Self::SetccPeRm8 { .. } => "setpe",
+            Self::SetccPoRm8 { .. } => "setpo",
+            Self::SetccSRm8 { .. } => "sets",
+            Self::SetccCRm8 { .. } => "setc",
+            Self::SetccNcRm8 { .. } => "setnc",
+            Self::CmovccAReg16Rm16 { .. } | Self::CmovccAReg32Rm32 { .. } | Self::CmovccAReg64Rm64 { .. } => "cmova",
+            Self::CmovccAeReg16Rm16 { .. } | Self::CmovccAeReg32Rm32 { .. } | Self::CmovccAeReg64Rm64 { .. } => "cmovae",
+            Self::CmovccBReg16Rm16 { .. } | Self::CmovccBReg32Rm32 { .. } | Self::CmovccBReg64Rm64 { .. } => "cmovb",
+            Self::CmovccBeReg16Rm16 { .. } | Self::CmovccBeReg32Rm32 { .. } | Self::CmovccBeReg64Rm64 { .. } => "cmovbe",
+            Self::CmovccEReg16Rm16 { .. } | Self::CmovccEReg32Rm32 { .. } | Self::CmovccEReg64Rm64 { .. } => "cmove",
+            Self::CmovccGReg16Rm16 { .. } | Self::CmovccGReg32Rm32 { .. } | Self::CmovccGReg64Rm64 { .. } => "cmovg",
+            Self::CmovccGeReg16Rm16 { .. } | Self::CmovccGeReg32Rm32 { .. } | Self::CmovccGeReg64Rm64 { .. } => "cmovge",
+            Self::CmovccLReg16Rm16 { .. } | Self::CmovccLReg32Rm32 { .. } | Self::CmovccLReg64Rm64 { .. } => "cmovl",
+            Self::CmovccLeReg16Rm16 { .. } | Self::CmovccLeReg32Rm32 { .. } | Self::CmovccLeReg64Rm64 { .. } => "cmovle",
+            Self::CmovccNeReg16Rm16 { .. } | Self::CmovccNeReg32Rm32 { .. } | Self::CmovccNeReg64Rm64 { .. } => "cmovne",
+            Self::CmovccNoReg16Rm16 { .. } | Self::CmovccNoReg32Rm32 { .. } | Self::CmovccNoReg64Rm64 { .. } => "cmovno",
+            Self::CmovccNpReg16Rm16 { .. } | Self::CmovccNpReg32Rm32 { .. } | Self::CmovccNpReg64Rm64 { .. } => "cmovnp",
+            Self::CmovccNsReg16Rm16 { .. } | Self::CmovccNsReg32Rm32 { .. } | Self::CmovccNsReg64Rm64 { .. } => "cmovns",
+            Self::CmovccOReg16Rm16 { .. } | Self::CmovccOReg32Rm32 { .. } | Self::CmovccOReg64Rm64 { .. } => "cmovo",
+            Self::CmovccPReg16Rm16 { .. } | Self::CmovccPReg32Rm32 { .. } | Self::CmovccPReg64Rm64 { .. } => "cmovp",
+            Self::CmovccPeReg16Rm16 { .. } | Self::CmovccPeReg32Rm32 { .. } | Self::CmovccPeReg64Rm64 { .. } => "cmovpe",
+            Self::CmovccPoReg16Rm16 { .. } | Self::CmovccPoReg32Rm32 { .. } | Self::CmovccPoReg64Rm64 { .. } => "cmovpo",
+            Self::CmovccSReg16Rm16 { .. } | Self::CmovccSReg32Rm32 { .. } | Self::CmovccSReg64Rm64 { .. } => "cmovs",
+            Self::CmovccCReg16Rm16 { .. } | Self::CmovccCReg32Rm32 { .. } | Self::CmovccCReg64Rm64 { .. } => "cmovc",
+            Self::CmovccNcReg16Rm16 { .. } | Self::CmovccNcReg32Rm32 { .. } | Self::CmovccNcReg64Rm64 { .. } => "cmovnc",
+            Self::XchgRm8Reg8 { .. }
+            | Self::XchgLockRm8Reg8 { .. }
+            | Self::XchgRm16Reg16 { .. }
+            | Self::XchgLockRm16Reg16 { .. }
+            | Self::XchgRm32Reg32 { .. }
+            | Self::XchgLockRm32Reg32 { .. }
+            | Self::XchgRm64Reg64 { .. }
+            | Self::XchgLockRm64Reg64 { .. }
+            | Self::XchgReg8Rm8 { .. }
+            | Self::XchgReg16Rm16 { .. }
+            | Self::XchgReg32Rm32 { .. }
+            | Self::XchgReg64Rm64 { .. } => "xchg",
+            Self::CmpxchgRm8Reg8 { .. }
+            | Self::CmpxchgLockRm8Reg8 { .. }
+            | Self::CmpxchgRm16Reg16 { .. }
+            | Self::CmpxchgLockRm16Reg16 { .. }
+            | Self::CmpxchgRm32Reg32 { .. }
+            | Self::CmpxchgLockRm32Reg32 { .. }
+            | Self::CmpxchgRm64Reg64 { .. }
+            | Self::CmpxchgLockRm64Reg64 { .. } => "cmpxchg",
+            Self::Cmpxchg8bMem64 { .. } | Self::Cmpxchg8bLockMem64 { .. } => "cmpxchg8b",
+            Self::Cmpxchg16bMem128 { .. } | Self::Cmpxchg16bLockMem128 { .. } => "cmpxchg16b",
+            Self::XaddRm8Reg8 { .. }
+            | Self::XaddLockRm8Reg8 { .. }
+            | Self::XaddRm16Reg16 { .. }
+            | Self::XaddLockRm16Reg16 { .. }
+            | Self::XaddRm32Reg32 { .. }
+            | Self::XaddLockRm32Reg32 { .. }
+            | Self::XaddRm64Reg64 { .. }
+            | Self::XaddLockRm64Reg64 { .. } => "xadd",
+            Self::MovsB | Self::MovsRepB => "movsb",
+            Self::MovsW | Self::MovsRepW => "movsw",
+            Self::MovsD | Self::MovsRepD | Self::MovsdXmmXmmm64 { .. } | Self::MovsdXmmm64Xmm { .. } => "movsd",
+            Self::MovsQ | Self::MovsRepQ => "movsq",
+            Self::StosB | Self::StosRepB => "stosb",
+            Self::StosW | Self::StosRepW => "stosw",
+            Self::StosD | Self::StosRepD => "stosd",
+            Self::StosQ | Self::StosRepQ => "stosq",
+            Self::LodsB | Self::LodsRepB => "lodsb",
+            Self::LodsW | Self::LodsRepW => "lodsw",
+            Self::LodsD | Self::LodsRepD => "lodsd",
+            Self::LodsQ | Self::LodsRepQ => "lodsq",
+            Self::CmpsB | Self::CmpsRepeB | Self::CmpsRepneB => "cmpsb",
+            Self::CmpsW | Self::CmpsRepeW | Self::CmpsRepneW => "cmpsw",
+            Self::CmpsD | Self::CmpsRepeD | Self::CmpsRepneD => "cmpsd",
+            Self::CmpsQ | Self::CmpsRepeQ | Self::CmpsRepneQ => "cmpsq",
+            Self::ScasB | Self::ScasRepeB | Self::ScasRepneB => "scasb",
+            Self::ScasW | Self::ScasRepeW | Self::ScasRepneW => "scasw",
+            Self::ScasD | Self::ScasRepeD | Self::ScasRepneD => "scasd",
+            Self::ScasQ | Self::ScasRepeQ | Self::ScasRepneQ => "scasq",
+            Self::BtRm16Reg16 { .. }
+            | Self::BtRm32Reg32 { .. }
+            | Self::BtRm64Reg64 { .. }
+            | Self::BtRm16Imm8 { .. }
+            | Self::BtRm32Imm8 { .. }
+            | Self::BtRm64Imm8 { .. } => "bt",
+            Self::BtsRm16Reg16 { .. }
+            | Self::BtsLockRm16Reg16 { .. }
+            | Self::BtsRm32Reg32 { .. }
+            | Self::BtsLockRm32Reg32 { .. }
+            | Self::BtsRm64Reg64 { .. }
+            | Self::BtsLockRm64Reg64 { .. }
+            | Self::BtsRm16Imm8 { .. }
+            | Self::BtsLockRm16Imm8 { .. }
+            | Self::BtsRm32Imm8 { .. }
+            | Self::BtsLockRm32Imm8 { .. }
+            | Self::BtsRm64Imm8 { .. }
+            | Self::BtsLockRm64Imm8 { .. } => "bts",
+            Self::BtrRm16Reg16 { .. }
+            | Self::BtrLockRm16Reg16 { .. }
+            | Self::BtrRm32Reg32 { .. }
+            | Self::BtrLockRm32Reg32 { .. }
+            | Self::BtrRm64Reg64 { .. }
+            | Self::BtrLockRm64Reg64 { .. }
+            | Self::BtrRm16Imm8 { .. }
+            | Self::BtrLockRm16Imm8 { .. }
+            | Self::BtrRm32Imm8 { .. }
+            | Self::BtrLockRm32Imm8 { .. }
+            | Self::BtrRm64Imm8 { .. }
+            | Self::BtrLockRm64Imm8 { .. } => "btr",
+            Self::BtcRm16Reg16 { .. }
+            | Self::BtcLockRm16Reg16 { .. }
+            | Self::BtcRm32Reg32 { .. }
+            | Self::BtcLockRm32Reg32 { .. }
+            | Self::BtcRm64Reg64 { .. }
+            | Self::BtcLockRm64Reg64 { .. }
+            | Self::BtcRm16Imm8 { .. }
+            | Self::BtcLockRm16Imm8 { .. }
+            | Self::BtcRm32Imm8 { .. }
+            | Self::BtcLockRm32Imm8 { .. }
+            | Self::BtcRm64Imm8 { .. }
+            | Self::BtcLockRm64Imm8 { .. } => "btc",
+            Self::BsfReg16Rm16 { .. } | Self::BsfReg32Rm32 { .. } | Self::BsfReg64Rm64 { .. } => "bsf",
+            Self::BsrReg16Rm16 { .. } | Self::BsrReg32Rm32 { .. } | Self::BsrReg64Rm64 { .. } => "bsr",
+            Self::BswapReg32 { .. } | Self::BswapReg64 { .. } => "bswap",
+            Self::PopcntReg16Rm16 { .. } | Self::PopcntReg32Rm32 { .. } | Self::PopcntReg64Rm64 { .. } => "popcnt",
+            Self::LzcntReg16Rm16 { .. } | Self::LzcntReg32Rm32 { .. } | Self::LzcntReg64Rm64 { .. } => "lzcnt",
+            Self::TzcntReg16Rm16 { .. } | Self::TzcntReg32Rm32 { .. } | Self::TzcntReg64Rm64 { .. } => "tzcnt",
+            Self::AndnReg32Vreg32Rm32 { .. } | Self::AndnReg64Vreg64Rm64 { .. } => "andn",
+            Self::BextrReg32Rm32Vreg32 { .. } | Self::BextrReg64Rm64Vreg64 { .. } => "bextr",
+            Self::BlsiVreg32Rm32 { .. } | Self::BlsiVreg64Rm64 { .. } => "blsi",
+            Self::BlsmskVreg32Rm32 { .. } | Self::BlsmskVreg64Rm64 { .. } => "blsmsk",
+            Self::BlsrVreg32Rm32 { .. } | Self::BlsrVreg64Rm64 { .. } => "blsr",
+            Self::BzhiReg32Rm32Vreg32 { .. } | Self::BzhiReg64Rm64Vreg64 { .. } => "bzhi",
+            Self::PdepReg32Vreg32Rm32 { .. } | Self::PdepReg64Vreg64Rm64 { .. } => "pdep",
+            Self::PextReg32Vreg32Rm32 { .. } | Self::PextReg64Vreg64Rm64 { .. } => "pext",
+            Self::MulxReg32Vreg32Rm32 { .. } | Self::MulxReg64Vreg64Rm64 { .. } => "mulx",
+            Self::RorxReg32Rm32Imm8 { .. } | Self::RorxReg64Rm64Imm8 { .. } => "rorx",
+            Self::SarxReg32Rm32Vreg32 { .. } | Self::SarxReg64Rm64Vreg64 { .. } => "sarx",
+            Self::ShlxReg32Rm32Vreg32 { .. } | Self::ShlxReg64Rm64Vreg64 { .. } => "shlx",
+            Self::ShrxReg32Rm32Vreg32 { .. } | Self::ShrxReg64Rm64Vreg64 { .. } => "shrx",
+            Self::AdcxReg32Rm32 { .. } | Self::AdcxReg64Rm64 { .. } => "adcx",
+            Self::AdoxReg32Rm32 { .. } | Self::AdoxReg64Rm64 { .. } => "adox",
+            Self::MovssXmmXmmm32 { .. } | Self::MovssXmmm32Xmm { .. } => "movss",
+            Self::AddssXmmXmmm32 { .. } => "addss",
+            Self::AddsdXmmXmmm64 { .. } => "addsd",
+            Self::SubssXmmXmmm32 { .. } => "subss",
+            Self::SubsdXmmXmmm64 { .. } => "subsd",
+            Self::MulssXmmXmmm32 { .. } => "mulss",
+            Self::MulsdXmmXmmm64 { .. } => "mulsd",
+            Self::DivssXmmXmmm32 { .. } => "divss",
+            Self::DivsdXmmXmmm64 { .. } => "divsd",
+            Self::SqrtssXmmXmmm32 { .. } => "sqrtss",
+            Self::SqrtsdXmmXmmm64 { .. } => "sqrtsd",
+            Self::UcomissXmmXmmm32 { .. } => "ucomiss",
+            Self::UcomisdXmmXmmm64 { .. } => "ucomisd",
+            Self::Cvtsi2sdXmmRm32 { .. } | Self::Cvtsi2sdXmmRm64 { .. } => "cvtsi2sd",
+            Self::Cvttsd2siReg32Xmmm64 { .. } | Self::Cvttsd2siReg64Xmmm64 { .. } => "cvttsd2si",
+            Self::MovdXmmRm32 { .. } | Self::MovdRm32Xmm { .. } => "movd",
+            Self::MovqXmmRm64 { .. } | Self::MovqRm64Xmm { .. } => "movq",
+            Self::MovapsXmmXmmm128 { .. } | Self::MovapsXmmm128Xmm { .. } => "movaps",
+            Self::MovupsXmmXmmm128 { .. } | Self::MovupsXmmm128Xmm { .. } => "movups",
+            Self::MovdqaXmmXmmm128 { .. } | Self::MovdqaXmmm128Xmm { .. } => "movdqa",
+            Self::MovdquXmmXmmm128 { .. } | Self::MovdquXmmm128Xmm { .. } => "movdqu",
+            Self::AddpsXmmXmmm128 { .. } => "addps",
+            Self::AddpdXmmXmmm128 { .. } => "addpd",
+            Self::MulpsXmmXmmm128 { .. } => "mulps",
+            Self::MulpdXmmXmmm128 { .. } => "mulpd",
+            Self::PaddbXmmXmmm128 { .. } => "paddb",
+            Self::PaddwXmmXmmm128 { .. } => "paddw",
+            Self::PadddXmmXmmm128 { .. } => "paddd",
+            Self::PaddqXmmXmmm128 { .. } => "paddq",
+            Self::PsubbXmmXmmm128 { .. } => "psubb",
+            Self::PsubwXmmXmmm128 { .. } => "psubw",
+            Self::PsubdXmmXmmm128 { .. } => "psubd",
+            Self::PsubqXmmXmmm128 { .. } => "psubq",
+            Self::PandXmmXmmm128 { .. } => "pand",
+            Self::PorXmmXmmm128 { .. } => "por",
+            Self::PxorXmmXmmm128 { .. } => "pxor",
+            Self::PcmpeqbXmmXmmm128 { .. } => "pcmpeqb",
+            Self::PcmpeqwXmmXmmm128 { .. } => "pcmpeqw",
+            Self::PcmpeqdXmmXmmm128 { .. } => "pcmpeqd",
+            Self::PcmpeqqXmmXmmm128 { .. } => "pcmpeqq",
+            Self::PcmpgtbXmmXmmm128 { .. } => "pcmpgtb",
+            Self::PcmpgtwXmmXmmm128 { .. } => "pcmpgtw",
+            Self::PcmpgtdXmmXmmm128 { .. } => "pcmpgtd",
+            Self::PcmpgtqXmmXmmm128 { .. } => "pcmpgtq",
+            Self::PshufdXmmXmmm128Imm8 { .. } => "pshufd",
+            Self::PshufbXmmXmmm128 { .. } => "pshufb",
+            Self::PunpcklbwXmmXmmm128 { .. } => "punpcklbw",
+            Self::PunpcklwdXmmXmmm128 { .. } => "punpcklwd",
+            Self::PunpckldqXmmXmmm128 { .. } => "punpckldq",
+            Self::PunpcklqdqXmmXmmm128 { .. } => "punpcklqdq",
+            Self::PunpckhbwXmmXmmm128 { .. } => "punpckhbw",
+            Self::PunpckhwdXmmXmmm128 { .. } => "punpckhwd",
+            Self::PunpckhdqXmmXmmm128 { .. } => "punpckhdq",
+            Self::PunpckhqdqXmmXmmm128 { .. } => "punpckhqdq",
+            Self::PmovmskbReg32Xmm { .. } => "pmovmskb",
+            Self::PinsrbXmmR32m8Imm8 { .. } => "pinsrb",
+            Self::PinsrwXmmR32m16Imm8 { .. } => "pinsrw",
+            Self::PinsrdXmmRm32Imm8 { .. } => "pinsrd",
+            Self::PinsrqXmmRm64Imm8 { .. } => "pinsrq",
+            Self::PextrbR32m8XmmImm8 { .. } => "pextrb",
+            Self::PextrwReg32XmmImm8 { .. } | Self::PextrwR32m16XmmImm8 { .. } => "pextrw",
+            Self::PextrdRm32XmmImm8 { .. } => "pextrd",
+            Self::PextrqRm64XmmImm8 { .. } => "pextrq",
+            Self::BlendvpsXmmXmmm128Xmm0 { .. } => "blendvps",
+            Self::BlendvpdXmmXmmm128Xmm0 { .. } => "blendvpd",
+            Self::PblendvbXmmXmmm128Xmm0 { .. } => "pblendvb",
+            Self::PtestXmmXmmm128 { .. } => "ptest",
+            Self::VaddpsXmmVxmmXmmm128 { .. }
+            | Self::VaddpsYmmVymmYmmm256 { .. }
+            | Self::VaddpsZmmMaskVzmmZmmm512b32 { .. }
+            | Self::VaddpsZmmMaskVzmmZmmrEr { .. } => "vaddps",
+            Self::VaddpdXmmVxmmXmmm128 { .. }
+            | Self::VaddpdYmmVymmYmmm256 { .. }
+            | Self::VaddpdZmmMaskVzmmZmmm512b64 { .. }
+            | Self::VaddpdZmmMaskVzmmZmmrEr { .. } => "vaddpd",
+            Self::VmulpsXmmVxmmXmmm128 { .. }
+            | Self::VmulpsYmmVymmYmmm256 { .. }
+            | Self::VmulpsZmmMaskVzmmZmmm512b32 { .. }
+            | Self::VmulpsZmmMaskVzmmZmmrEr { .. } => "vmulps",
+            Self::VmulpdXmmVxmmXmmm128 { .. }
+            | Self::VmulpdYmmVymmYmmm256 { .. }
+            | Self::VmulpdZmmMaskVzmmZmmm512b64 { .. }
+            | Self::VmulpdZmmMaskVzmmZmmrEr { .. } => "vmulpd",
+            Self::VpaddbXmmVxmmXmmm128 { .. } | Self::VpaddbYmmVymmYmmm256 { .. } => "vpaddb",
+            Self::VpaddwXmmVxmmXmmm128 { .. } | Self::VpaddwYmmVymmYmmm256 { .. } => "vpaddw",
+            Self::VpadddXmmVxmmXmmm128 { .. }
+            | Self::VpadddYmmVymmYmmm256 { .. }
+            | Self::VpadddZmmMaskVzmmZmmm512b32 { .. } => "vpaddd",
+            Self::VpaddqXmmVxmmXmmm128 { .. }
+            | Self::VpaddqYmmVymmYmmm256 { .. }
+            | Self::VpaddqZmmMaskVzmmZmmm512b64 { .. } => "vpaddq",
+            Self::VpandXmmVxmmXmmm128 { .. } | Self::VpandYmmVymmYmmm256 { .. } => "vpand",
+            Self::VporXmmVxmmXmmm128 { .. } | Self::VporYmmVymmYmmm256 { .. } => "vpor",
+            Self::VpxorXmmVxmmXmmm128 { .. } | Self::VpxorYmmVymmYmmm256 { .. } => "vpxor",
+            Self::VpshufbXmmVxmmXmmm128 { .. } | Self::VpshufbYmmVymmYmmm256 { .. } => "vpshufb",
+            Self::VpermdYmmVymmYmmm256 { .. } => "vpermd",
+            Self::VpermpsYmmVymmYmmm256 { .. } => "vpermps",
+            Self::VpbroadcastbXmmXmmm8 { .. } | Self::VpbroadcastbYmmXmmm8 { .. } => "vpbroadcastb",
+            Self::VpbroadcastwXmmXmmm16 { .. } | Self::VpbroadcastwYmmXmmm16 { .. } => "vpbroadcastw",
+            Self::VpbroadcastdXmmXmmm32 { .. } | Self::VpbroadcastdYmmXmmm32 { .. } => "vpbroadcastd",
+            Self::VpbroadcastqXmmXmmm64 { .. } | Self::VpbroadcastqYmmXmmm64 { .. } => "vpbroadcastq",
+            Self::VmovapsXmmXmmm128 { .. }
+            | Self::VmovapsXmmm128Xmm { .. }
+            | Self::VmovapsYmmYmmm256 { .. }
+            | Self::VmovapsYmmm256Ymm { .. } => "vmovaps",
+            Self::VmovupsXmmXmmm128 { .. }
+            | Self::VmovupsXmmm128Xmm { .. }
+            | Self::VmovupsYmmYmmm256 { .. }
+            | Self::VmovupsYmmm256Ymm { .. }
+            | Self::VmovupsZmmMaskZmmm512 { .. }
+            | Self::VmovupsZmmm512MergemaskZmm { .. } => "vmovups",
+            Self::VmovdqaXmmXmmm128 { .. }
+            | Self::VmovdqaXmmm128Xmm { .. }
+            | Self::VmovdqaYmmYmmm256 { .. }
+            | Self::VmovdqaYmmm256Ymm { .. } => "vmovdqa",
+            Self::VmovdquXmmXmmm128 { .. }
+            | Self::VmovdquXmmm128Xmm { .. }
+            | Self::VmovdquYmmYmmm256 { .. }
+            | Self::VmovdquYmmm256Ymm { .. } => "vmovdqu",
+            Self::Vzeroupper => "vzeroupper",
+            Self::VsubpsZmmMaskVzmmZmmm512b32 { .. } | Self::VsubpsZmmMaskVzmmZmmrEr { .. } => "vsubps",
+            Self::VsubpdZmmMaskVzmmZmmm512b64 { .. } | Self::VsubpdZmmMaskVzmmZmmrEr { .. } => "vsubpd",
+            Self::VdivpsZmmMaskVzmmZmmm512b32 { .. } | Self::VdivpsZmmMaskVzmmZmmrEr { .. } => "vdivps",
+            Self::VdivpdZmmMaskVzmmZmmm512b64 { .. } | Self::VdivpdZmmMaskVzmmZmmrEr { .. } => "vdivpd",
+            Self::VminpsZmmMaskVzmmZmmm512b32 { .. } | Self::VminpsZmmMaskVzmmZmmrSae { .. } => "vminps",
+            Self::VminpdZmmMaskVzmmZmmm512b64 { .. } | Self::VminpdZmmMaskVzmmZmmrSae { .. } => "vminpd",
+            Self::VmaxpsZmmMaskVzmmZmmm512b32 { .. } | Self::VmaxpsZmmMaskVzmmZmmrSae { .. } => "vmaxps",
+            Self::VmaxpdZmmMaskVzmmZmmm512b64 { .. } | Self::VmaxpdZmmMaskVzmmZmmrSae { .. } => "vmaxpd",
+            Self::VsqrtpsZmmMaskZmmm512b32 { .. } | Self::VsqrtpsZmmMaskZmmrEr { .. } => "vsqrtps",
+            Self::VsqrtpdZmmMaskZmmm512b64 { .. } | Self::VsqrtpdZmmMaskZmmrEr { .. } => "vsqrtpd",
+            Self::VaddssXmmMaskVxmmXmmm32 { .. } | Self::VaddssXmmMaskVxmmXmmrEr { .. } => "vaddss",
+            Self::VaddsdXmmMaskVxmmXmmm64 { .. } | Self::VaddsdXmmMaskVxmmXmmrEr { .. } => "vaddsd",
+            Self::VsubssXmmMaskVxmmXmmm32 { .. } | Self::VsubssXmmMaskVxmmXmmrEr { .. } => "vsubss",
+            Self::VsubsdXmmMaskVxmmXmmm64 { .. } | Self::VsubsdXmmMaskVxmmXmmrEr { .. } => "vsubsd",
+            Self::VmulssXmmMaskVxmmXmmm32 { .. } | Self::VmulssXmmMaskVxmmXmmrEr { .. } => "vmulss",
+            Self::VmulsdXmmMaskVxmmXmmm64 { .. } | Self::VmulsdXmmMaskVxmmXmmrEr { .. } => "vmulsd",
+            Self::VdivssXmmMaskVxmmXmmm32 { .. } | Self::VdivssXmmMaskVxmmXmmrEr { .. } => "vdivss",
+            Self::VdivsdXmmMaskVxmmXmmm64 { .. } | Self::VdivsdXmmMaskVxmmXmmrEr { .. } => "vdivsd",
+            Self::VpsubdZmmMaskVzmmZmmm512b32 { .. } => "vpsubd",
+            Self::VpsubqZmmMaskVzmmZmmm512b64 { .. } => "vpsubq",
+            Self::VpmulldZmmMaskVzmmZmmm512b32 { .. } => "vpmulld",
+            Self::VpanddZmmMaskVzmmZmmm512b32 { .. } => "vpandd",
+            Self::VpandqZmmMaskVzmmZmmm512b64 { .. } => "vpandq",
+            Self::VpordZmmMaskVzmmZmmm512b32 { .. } => "vpord",
+            Self::VporqZmmMaskVzmmZmmm512b64 { .. } => "vporq",
+            Self::VpxordZmmMaskVzmmZmmm512b32 { .. } => "vpxord",
+            Self::VpxorqZmmMaskVzmmZmmm512b64 { .. } => "vpxorq",
+            Self::Vmovdqu32ZmmMaskZmmm512 { .. } | Self::Vmovdqu32Zmmm512MergemaskZmm { .. } => "vmovdqu32",
+            Self::Vmovdqu64ZmmMaskZmmm512 { .. } | Self::Vmovdqu64Zmmm512MergemaskZmm { .. } => "vmovdqu64",
+            Self::VcmppsKMergemaskVzmmZmmm512b32Imm8 { .. } | Self::VcmppsKMergemaskVzmmZmmrSaeImm8 { .. } => "vcmpps",
+            Self::VcmppdKMergemaskVzmmZmmm512b64Imm8 { .. } | Self::VcmppdKMergemaskVzmmZmmrSaeImm8 { .. } => "vcmppd",
+            Self::VpcmpeqdKMergemaskVzmmZmmm512b32 { .. } => "vpcmpeqd",
+            Self::VpcmpgtdKMergemaskVzmmZmmm512b32 { .. } => "vpcmpgtd",
+            Self::VpcmpeqqKMergemaskVzmmZmmm512b64 { .. } => "vpcmpeqq",
+            Self::VpcmpgtqKMergemaskVzmmZmmm512b64 { .. } => "vpcmpgtq",
+            Self::VpcmpdKMergemaskVzmmZmmm512b32Imm8 { .. } => "vpcmpd",
+            Self::VpcmpudKMergemaskVzmmZmmm512b32Imm8 { .. } => "vpcmpud",
+            Self::VpcmpqKMergemaskVzmmZmmm512b64Imm8 { .. } => "vpcmpq",
+            Self::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 { .. } => "vpcmpuq",
+            Self::KmovwKKm16 { .. }
+            | Self::KmovwMem16K { .. }
+            | Self::KmovwKReg32 { .. }
+            | Self::KmovwReg32Kr { .. } => "kmovw",
+            Self::KandwKVkKr { .. } => "kandw",
+            Self::KandnwKVkKr { .. } => "kandnw",
+            Self::KorwKVkKr { .. } => "korw",
+            Self::KxnorwKVkKr { .. } => "kxnorw",
+            Self::KxorwKVkKr { .. } => "kxorw",
+            Self::KnotwKKr { .. } => "knotw",
+            Self::KortestwKKr { .. } => "kortestw",
+            Self::Vfmadd132psXmmVxmmXmmm128 { .. } | Self::Vfmadd132psYmmVymmYmmm256 { .. } => "vfmadd132ps",
+            Self::Vfmadd132pdXmmVxmmXmmm128 { .. } | Self::Vfmadd132pdYmmVymmYmmm256 { .. } => "vfmadd132pd",
+            Self::Vfmadd132ssXmmVxmmXmmm32 { .. } => "vfmadd132ss",
+            Self::Vfmadd132sdXmmVxmmXmmm64 { .. } => "vfmadd132sd",
+            Self::Vfmadd213psXmmVxmmXmmm128 { .. } | Self::Vfmadd213psYmmVymmYmmm256 { .. } => "vfmadd213ps",
+            Self::Vfmadd213pdXmmVxmmXmmm128 { .. } | Self::Vfmadd213pdYmmVymmYmmm256 { .. } => "vfmadd213pd",
+            Self::Vfmadd213ssXmmVxmmXmmm32 { .. } => "vfmadd213ss",
+            Self::Vfmadd213sdXmmVxmmXmmm64 { .. } => "vfmadd213sd",
+            Self::Vfmadd231psXmmVxmmXmmm128 { .. } | Self::Vfmadd231psYmmVymmYmmm256 { .. } => "vfmadd231ps",
+            Self::Vfmadd231pdXmmVxmmXmmm128 { .. } | Self::Vfmadd231pdYmmVymmYmmm256 { .. } => "vfmadd231pd",
+            Self::Vfmadd231ssXmmVxmmXmmm32 { .. } => "vfmadd231ss",
+            Self::Vfmadd231sdXmmVxmmXmmm64 { .. } => "vfmadd231sd",
+            Self::Vfmsub132psXmmVxmmXmmm128 { .. } | Self::Vfmsub132psYmmVymmYmmm256 { .. } => "vfmsub132ps",
+            Self::Vfmsub132pdXmmVxmmXmmm128 { .. } | Self::Vfmsub132pdYmmVymmYmmm256 { .. } => "vfmsub132pd",
+            Self::Vfmsub132ssXmmVxmmXmmm32 { .. } => "vfmsub132ss",
+            Self::Vfmsub132sdXmmVxmmXmmm64 { .. } => "vfmsub132sd",
+            Self::Vfmsub213psXmmVxmmXmmm128 { .. } | Self::Vfmsub213psYmmVymmYmmm256 { .. } => "vfmsub213ps",
+            Self::Vfmsub213pdXmmVxmmXmmm128 { .. } | Self::Vfmsub213pdYmmVymmYmmm256 { .. } => "vfmsub213pd",
+            Self::Vfmsub213ssXmmVxmmXmmm32 { .. } => "vfmsub213ss",
+            Self::Vfmsub213sdXmmVxmmXmmm64 { .. } => "vfmsub213sd",
+            Self::Vfmsub231psXmmVxmmXmmm128 { .. } | Self::Vfmsub231psYmmVymmYmmm256 { .. } => "vfmsub231ps",
+            Self::Vfmsub231pdXmmVxmmXmmm128 { .. } | Self::Vfmsub231pdYmmVymmYmmm256 { .. } => "vfmsub231pd",
+            Self::Vfmsub231ssXmmVxmmXmmm32 { .. } => "vfmsub231ss",
+            Self::Vfmsub231sdXmmVxmmXmmm64 { .. } => "vfmsub231sd",
+            Self::Vfnmadd132psXmmVxmmXmmm128 { .. } | Self::Vfnmadd132psYmmVymmYmmm256 { .. } => "vfnmadd132ps",
+            Self::Vfnmadd132pdXmmVxmmXmmm128 { .. } | Self::Vfnmadd132pdYmmVymmYmmm256 { .. } => "vfnmadd132pd",
+            Self::Vfnmadd132ssXmmVxmmXmmm32 { .. } => "vfnmadd132ss",
+            Self::Vfnmadd132sdXmmVxmmXmmm64 { .. } => "vfnmadd132sd",
+            Self::Vfnmadd213psXmmVxmmXmmm128 { .. } | Self::Vfnmadd213psYmmVymmYmmm256 { .. } => "vfnmadd213ps",
+            Self::Vfnmadd213pdXmmVxmmXmmm128 { .. } | Self::Vfnmadd213pdYmmVymmYmmm256 { .. } => "vfnmadd213pd",
+            Self::Vfnmadd213ssXmmVxmmXmmm32 { .. } => "vfnmadd213ss",
+            Self::Vfnmadd213sdXmmVxmmXmmm64 { .. } => "vfnmadd213sd",
+            Self::Vfnmadd231psXmmVxmmXmmm128 { .. } | Self::Vfnmadd231psYmmVymmYmmm256 { .. } => "vfnmadd231ps",
+            Self::Vfnmadd231pdXmmVxmmXmmm128 { .. } | Self::Vfnmadd231pdYmmVymmYmmm256 { .. } => "vfnmadd231pd",
+            Self::Vfnmadd231ssXmmVxmmXmmm32 { .. } => "vfnmadd231ss",
+            Self::Vfnmadd231sdXmmVxmmXmmm64 { .. } => "vfnmadd231sd",
+            Self::Vfnmsub132psXmmVxmmXmmm128 { .. } | Self::Vfnmsub132psYmmVymmYmmm256 { .. } => "vfnmsub132ps",
+            Self::Vfnmsub132pdXmmVxmmXmmm128 { .. } | Self::Vfnmsub132pdYmmVymmYmmm256 { .. } => "vfnmsub132pd",
+            Self::Vfnmsub132ssXmmVxmmXmmm32 { .. } => "vfnmsub132ss",
+            Self::Vfnmsub132sdXmmVxmmXmmm64 { .. } => "vfnmsub132sd",
+            Self::Vfnmsub213psXmmVxmmXmmm128 { .. } | Self::Vfnmsub213psYmmVymmYmmm256 { .. } => "vfnmsub213ps",
+            Self::Vfnmsub213pdXmmVxmmXmmm128 { .. } | Self::Vfnmsub213pdYmmVymmYmmm256 { .. } => "vfnmsub213pd",
+            Self::Vfnmsub213ssXmmVxmmXmmm32 { .. } => "vfnmsub213ss",
+            Self::Vfnmsub213sdXmmVxmmXmmm64 { .. } => "vfnmsub213sd",
+            Self::Vfnmsub231psXmmVxmmXmmm128 { .. } | Self::Vfnmsub231psYmmVymmYmmm256 { .. } => "vfnmsub231ps",
+            Self::Vfnmsub231pdXmmVxmmXmmm128 { .. } | Self::Vfnmsub231pdYmmVymmYmmm256 { .. } => "vfnmsub231pd",
+            Self::Vfnmsub231ssXmmVxmmXmmm32 { .. } => "vfnmsub231ss",
+            Self::Vfnmsub231sdXmmVxmmXmmm64 { .. } => "vfnmsub231sd",
+            Self::Vcvtph2psXmmXmmm64 { .. } | Self::Vcvtph2psYmmXmmm128 { .. } => "vcvtph2ps",
+            Self::Vcvtps2phXmmm64XmmImm8 { .. } | Self::Vcvtps2phXmmm128YmmImm8 { .. } => "vcvtps2ph",
+            Self::VpgatherddXmmVm32xVxmm { .. }
+            | Self::VpgatherddYmmVm32yVymm { .. }
+            | Self::VpgatherddZmmMergemaskVm32z { .. } => "vpgatherdd",
+            Self::VpgatherdqXmmVm32xVxmm { .. }
+            | Self::VpgatherdqYmmVm32xVymm { .. }
+            | Self::VpgatherdqZmmMergemaskVm32y { .. } => "vpgatherdq",
+            Self::VpgatherqdXmmVm64xVxmm { .. }
+            | Self::VpgatherqdXmmVm64yVxmm { .. }
+            | Self::VpgatherqdYmmMergemaskVm64z { .. } => "vpgatherqd",
+            Self::VpgatherqqXmmVm64xVxmm { .. }
+            | Self::VpgatherqqYmmVm64yVymm { .. }
+            | Self::VpgatherqqZmmMergemaskVm64z { .. } => "vpgatherqq",
+            Self::VgatherdpsXmmVm32xVxmm { .. }
+            | Self::VgatherdpsYmmVm32yVymm { .. }
+            | Self::VgatherdpsZmmMergemaskVm32z { .. } => "vgatherdps",
+            Self::VgatherdpdXmmVm32xVxmm { .. }
+            | Self::VgatherdpdYmmVm32xVymm { .. }
+            | Self::VgatherdpdZmmMergemaskVm32y { .. } => "vgatherdpd",
+            Self::VgatherqpsXmmVm64xVxmm { .. }
+            | Self::VgatherqpsXmmVm64yVxmm { .. }
+            | Self::VgatherqpsYmmMergemaskVm64z { .. } => "vgatherqps",
+            Self::VgatherqpdXmmVm64xVxmm { .. }
+            | Self::VgatherqpdYmmVm64yVymm { .. }
+            | Self::VgatherqpdZmmMergemaskVm64z { .. } => "vgatherqpd",
+            Self::VpscatterddVm32zMergemaskZmm { .. } => "vpscatterdd",
+            Self::VpscatterdqVm32yMergemaskZmm { .. } => "vpscatterdq",
+            Self::VpscatterqdVm64zMergemaskYmm { .. } => "vpscatterqd",
+            Self::VpscatterqqVm64zMergemaskZmm { .. } => "vpscatterqq",
+            Self::VscatterdpsVm32zMergemaskZmm { .. } => "vscatterdps",
+            Self::VscatterdpdVm32yMergemaskZmm { .. } => "vscatterdpd",
+            Self::VscatterqpsVm64zMergemaskYmm { .. } => "vscatterqps",
+            Self::VscatterqpdVm64zMergemaskZmm { .. } => "vscatterqpd",
+        }
+    }
+
+    /// Returns the operands of the instruction (including the implicit ones)
+    /// in Intel order, i.e. destination first.
+    #[allow(clippy::too_many_lines, clippy::match_same_arms)]
+    pub const fn operands(self) -> Operands {
+        match self {
+            Self::Lock => Operands::from_array([]),
+            Self::Nop => Operands::from_array([]),
+            Self::Cpuid => Operands::from_array([]),
+            Self::Sysenter => Operands::from_array([]),
+            Self::Syscall => Operands::from_array([]),
+            Self::Ret => Operands::from_array([]),
+            Self::RetImm16 { imm16 } => Operands::from_array([Operand::from_unsigned_imm16(imm16)]),
+            Self::MovRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::MovRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::MovRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::MovRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::MovReg8Imm8 { reg8, imm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_imm8(imm8)])
+            }
+            Self::MovReg16Imm16 { reg16, imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: reg16 }, Operand::from_imm16(imm16)])
+            }
+            Self::MovReg32Imm32 { reg32, imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: reg32 }, Operand::from_imm32(imm32)])
+            }
+            Self::MovReg64Imm64 { reg64, imm64 } => {
+                Operands::from_array([Operand::GPR { gpr: reg64 }, Operand::from_imm64(imm64)])
+            }
+            Self::MovRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::MovRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::MovRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::MovRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::MovReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::MovReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::MovReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::MovReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::AddAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::AddAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::AddEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AddRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AddRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AddLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AddRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AddLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AddRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AddLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AddRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AddLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AddRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AddLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AddRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AddLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AddRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AddLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AddRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AddLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AddRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AddLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AddRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AddLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AddRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AddLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AddReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::AddReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::AddReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AddReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::JmpImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JmpImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JmpRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::JccAImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccAeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccBImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccBeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccEImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccGImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccGeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccLImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccLeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccNeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccNoImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccNpImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccNsImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccOImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccPImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccPeImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccPoImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccSImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccCImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccNcImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::JccAImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccAeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccBImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccBeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccEImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccGImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccGeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccLImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccLeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccNeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccNoImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccNpImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccNsImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccOImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccPImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccPeImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccPoImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccSImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccCImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::JccNcImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::CallImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::CallRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::CmpAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::CmpAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::CmpEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::CmpRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::CmpRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::CmpRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::CmpRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::CmpRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::CmpRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::CmpRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::CmpRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::CmpRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::CmpRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::CmpRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::CmpRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::CmpReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::CmpReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmpReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmpReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::LeaReg16Mem64 { reg16, mem64 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::Memory {
+                    memory: mem64,
+                    size: None,
+                },
+            ]),
+            Self::LeaReg32Mem64 { reg32, mem64 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::Memory {
+                    memory: mem64,
+                    size: None,
+                },
+            ]),
+            Self::LeaReg64Mem64 { reg64, mem64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::Memory {
+                    memory: mem64,
+                    size: None,
+                },
+            ]),
+            Self::PushRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::PushReg64 { reg64 } => Operands::from_array([Operand::GPR { gpr: reg64 }]),
+            Self::PushImm8 { imm8 } => Operands::from_array([Operand::from_imm8(imm8)]),
+            Self::PushImm16 { imm16 } => Operands::from_array([Operand::from_imm16(imm16)]),
+            Self::PushImm32 { imm32 } => Operands::from_array([Operand::from_imm32(imm32)]),
+            Self::PopRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::PopReg64 { reg64 } => Operands::from_array([Operand::GPR { gpr: reg64 }]),
+            Self::SubAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::SubAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::SubEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::SubRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::SubRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::SubLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::SubRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::SubLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::SubRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SubLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SubRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SubLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SubRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::SubLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::SubRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::SubLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::SubRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::SubLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::SubRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::SubLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::SubRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::SubLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::SubRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::SubLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::SubRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::SubLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::SubReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::SubReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::SubReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::SubReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::XorAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::XorAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::XorEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::XorRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::XorRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::XorLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::XorRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::XorLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::XorRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::XorLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::XorRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::XorLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::XorRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::XorLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::XorRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::XorLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::XorRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::XorLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::XorRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XorLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XorRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XorLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XorRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XorLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XorRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::XorLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::XorReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::XorReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::XorReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::XorReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::IntImm8 { imm8 } => Operands::from_array([Operand::from_unsigned_imm8(imm8)]),
+            Self::Int1 => Operands::from_array([]),
+            Self::Int3 => Operands::from_array([]),
+            Self::RolRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RolRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RolRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RolRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RolRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RolRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RolRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RolRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RolRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RolRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RolRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RolRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RorRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RorRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RorRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RorRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RorRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RorRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RorRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RorRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RorRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RorRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RorRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RorRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RclRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RclRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RclRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RclRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RclRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RclRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RclRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RclRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RclRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RclRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RclRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RclRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RcrRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RcrRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RcrRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RcrRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::RcrRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RcrRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RcrRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RcrRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RcrRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RcrRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RcrRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::RcrRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShlRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShlRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShlRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShlRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShlRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShlRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShlRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShlRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShlRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShlRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShlRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShlRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShrRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShrRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShrRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShrRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::ShrRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShrRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShrRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShrRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::ShrRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShrRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShrRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::ShrRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::SarRm8One { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::SarRm16One { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::SarRm32One { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::SarRm64One { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(Immediate8::from_i8(1)),
+            ]),
+            Self::SarRm8Imm8 { rm8, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::SarRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::SarRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::SarRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::SarRm8Cl { rm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::SarRm16Cl { rm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::SarRm32Cl { rm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::SarRm64Cl { rm64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: GPR::CL },
+            ]),
+            Self::MulRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::MulRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::MulRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::MulRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::ImulRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::ImulRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::ImulRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::ImulRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::ImulReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::ImulReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::ImulReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::ImulReg16Rm16Imm8 { reg16, rm16, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm8(imm8),
+            ]),
+            Self::ImulReg32Rm32Imm8 { reg32, rm32, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm8(imm8),
+            ]),
+            Self::ImulReg64Rm64Imm8 { reg64, rm64, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm8(imm8),
+            ]),
+            Self::ImulReg16Rm16Imm16 { reg16, rm16, imm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::ImulReg32Rm32Imm32 { reg32, rm32, imm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::ImulReg64Rm64Imm32 { reg64, rm64, imm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::DivRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::DivRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::DivRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::DivRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::IdivRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::IdivRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::IdivRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::IdivRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::AndAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::AndAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::AndEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AndRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AndRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AndLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AndRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AndLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AndRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AndLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AndRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AndLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AndRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AndLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AndRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AndLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AndRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AndLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AndRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AndLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AndRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AndLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AndRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AndLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AndRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AndLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AndReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::AndReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::AndReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AndReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::OrAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::OrAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::OrEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::OrRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::OrRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::OrLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::OrRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::OrLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::OrRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::OrLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::OrRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::OrLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::OrRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::OrLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::OrRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::OrLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::OrRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::OrLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::OrRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::OrLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::OrRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::OrLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::OrRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::OrLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::OrRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::OrLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::OrReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::OrReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::OrReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::OrReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::AdcAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::AdcAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::AdcEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AdcRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::AdcRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AdcLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::AdcRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AdcLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::AdcRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AdcLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AdcRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AdcLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::AdcRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AdcLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::AdcRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AdcLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::AdcRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AdcLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::AdcRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AdcLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::AdcRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AdcLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::AdcRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AdcLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::AdcRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AdcLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::AdcReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::AdcReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::AdcReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AdcReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::SbbAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::SbbAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::SbbEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::SbbRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::SbbRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::SbbLockRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::SbbRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::SbbLockRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::SbbRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SbbLockRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SbbRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SbbLockRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::SbbRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::SbbLockRm16Imm8 { rm16, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16), Operand::from_imm8(imm8)])
+            }
+            Self::SbbRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::SbbLockRm32Imm8 { rm32, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::from_imm8(imm8)])
+            }
+            Self::SbbRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::SbbLockRm64Imm8 { rm64, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::from_imm8(imm8)])
+            }
+            Self::SbbRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::SbbLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::SbbRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::SbbLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::SbbRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::SbbLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::SbbRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::SbbLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::SbbReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::SbbReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::SbbReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::SbbReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::TestAlImm8 { imm8 } => Operands::from_array([Operand::GPR { gpr: GPR::AL }, Operand::from_imm8(imm8)]),
+            Self::TestAxImm16 { imm16 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::AX }, Operand::from_imm16(imm16)])
+            }
+            Self::TestEaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::EAX }, Operand::from_imm32(imm32)])
+            }
+            Self::TestRaxImm32 { imm32 } => {
+                Operands::from_array([Operand::GPR { gpr: GPR::RAX }, Operand::from_imm32(imm32)])
+            }
+            Self::TestRm8Imm8 { rm8, imm8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::from_imm8(imm8)])
+            }
+            Self::TestRm16Imm16 { rm16, imm16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_imm16(imm16),
+            ]),
+            Self::TestRm32Imm32 { rm32, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::TestRm64Imm32 { rm64, imm32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_imm32(imm32),
+            ]),
+            Self::TestRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::TestRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::TestRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::TestRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::NotRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::NotLockRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::NotRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::NotLockRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::NotRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::NotLockRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::NotRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::NotLockRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::NegRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::NegLockRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::NegRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::NegLockRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::NegRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::NegLockRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::NegRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::NegLockRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::IncRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::IncLockRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::IncRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::IncLockRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::IncRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::IncLockRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::IncRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::IncLockRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::DecRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::DecLockRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::DecRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::DecLockRm16 { rm16 } => Operands::from_array([Operand::from_gpr_or_memory(rm16, Size::Bit16)]),
+            Self::DecRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::DecLockRm32 { rm32 } => Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32)]),
+            Self::DecRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::DecLockRm64 { rm64 } => Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64)]),
+            Self::MovzxReg16Rm8 { reg16, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovzxReg32Rm8 { reg32, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovzxReg64Rm8 { reg64, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovzxReg32Rm16 { reg32, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::MovzxReg64Rm16 { reg64, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::MovsxReg16Rm8 { reg16, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovsxReg32Rm8 { reg32, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovsxReg64Rm8 { reg64, rm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm8, Size::Bit8),
+            ]),
+            Self::MovsxReg32Rm16 { reg32, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::MovsxReg64Rm16 { reg64, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::MovsxdReg64Rm32 { reg64, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::SetccARm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccAeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccBRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccBeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccERm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccGRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccGeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccLRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccLeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccNeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccNoRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccNpRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccNsRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccORm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccPRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccPeRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccPoRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccSRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccCRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::SetccNcRm8 { rm8 } => Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8)]),
+            Self::CmovccAReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccAeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccBReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccBeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccEReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccGReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccGeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccLReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccLeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccNeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccNoReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccNpReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccNsReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccOReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccPReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccPeReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccPoReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccSReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccCReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccNcReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::CmovccAReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccAeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccBReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccBeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccEReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccGReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccGeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccLReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccLeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccNeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccNoReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccNpReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccNsReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccOReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccPReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccPeReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccPoReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccSReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccCReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccNcReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::CmovccAReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccAeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccBReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccBeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccEReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccGReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccGeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccLReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccLeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccNeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccNoReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccNpReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccNsReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccOReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccPReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccPeReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccPoReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccSReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccCReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmovccNcReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::XchgRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XchgLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XchgRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XchgLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XchgRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XchgLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XchgRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::XchgLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::XchgReg8Rm8 { reg8, rm8 } => {
+                Operands::from_array([Operand::GPR { gpr: reg8 }, Operand::from_gpr_or_memory(rm8, Size::Bit8)])
+            }
+            Self::XchgReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::XchgReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::XchgReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::CmpxchgRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::CmpxchgLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::CmpxchgRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::CmpxchgLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::CmpxchgRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::CmpxchgLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::CmpxchgRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::CmpxchgLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::Cmpxchg8bMem64 { mem64 } => Operands::from_array([Operand::Memory {
+                memory: mem64,
+                size: Some(Size::Bit64),
+            }]),
+            Self::Cmpxchg8bLockMem64 { mem64 } => Operands::from_array([Operand::Memory {
+                memory: mem64,
+                size: Some(Size::Bit64),
+            }]),
+            Self::Cmpxchg16bMem128 { mem128 } => Operands::from_array([Operand::Memory {
+                memory: mem128,
+                size: Some(Size::Bit128),
+            }]),
+            Self::Cmpxchg16bLockMem128 { mem128 } => Operands::from_array([Operand::Memory {
+                memory: mem128,
+                size: Some(Size::Bit128),
+            }]),
+            Self::XaddRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XaddLockRm8Reg8 { rm8, reg8 } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm8, Size::Bit8), Operand::GPR { gpr: reg8 }])
+            }
+            Self::XaddRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XaddLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::XaddRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XaddLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::XaddRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::XaddLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::MovsB => Operands::from_array([]),
+            Self::MovsW => Operands::from_array([]),
+            Self::MovsD => Operands::from_array([]),
+            Self::MovsQ => Operands::from_array([]),
+            Self::MovsRepB => Operands::from_array([]),
+            Self::MovsRepW => Operands::from_array([]),
+            Self::MovsRepD => Operands::from_array([]),
+            Self::MovsRepQ => Operands::from_array([]),
+            Self::StosB => Operands::from_array([]),
+            Self::StosW => Operands::from_array([]),
+            Self::StosD => Operands::from_array([]),
+            Self::StosQ => Operands::from_array([]),
+            Self::StosRepB => Operands::from_array([]),
+            Self::StosRepW => Operands::from_array([]),
+            Self::StosRepD => Operands::from_array([]),
+            Self::StosRepQ => Operands::from_array([]),
+            Self::LodsB => Operands::from_array([]),
+            Self::LodsW => Operands::from_array([]),
+            Self::LodsD => Operands::from_array([]),
+            Self::LodsQ => Operands::from_array([]),
+            Self::LodsRepB => Operands::from_array([]),
+            Self::LodsRepW => Operands::from_array([]),
+            Self::LodsRepD => Operands::from_array([]),
+            Self::LodsRepQ => Operands::from_array([]),
+            Self::CmpsB => Operands::from_array([]),
+            Self::CmpsW => Operands::from_array([]),
+            Self::CmpsD => Operands::from_array([]),
+            Self::CmpsQ => Operands::from_array([]),
+            Self::CmpsRepeB => Operands::from_array([]),
+            Self::CmpsRepeW => Operands::from_array([]),
+            Self::CmpsRepeD => Operands::from_array([]),
+            Self::CmpsRepeQ => Operands::from_array([]),
+            Self::CmpsRepneB => Operands::from_array([]),
+            Self::CmpsRepneW => Operands::from_array([]),
+            Self::CmpsRepneD => Operands::from_array([]),
+            Self::CmpsRepneQ => Operands::from_array([]),
+            Self::ScasB => Operands::from_array([]),
+            Self::ScasW => Operands::from_array([]),
+            Self::ScasD => Operands::from_array([]),
+            Self::ScasQ => Operands::from_array([]),
+            Self::ScasRepeB => Operands::from_array([]),
+            Self::ScasRepeW => Operands::from_array([]),
+            Self::ScasRepeD => Operands::from_array([]),
+            Self::ScasRepeQ => Operands::from_array([]),
+            Self::ScasRepneB => Operands::from_array([]),
+            Self::ScasRepneW => Operands::from_array([]),
+            Self::ScasRepneD => Operands::from_array([]),
+            Self::ScasRepneQ => Operands::from_array([]),
+            Self::BtRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtsLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtsRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtsLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtsRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtsLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtsRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsLockRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsLockRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtsLockRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtrLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtrRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtrLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtrRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtrLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtrRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrLockRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrLockRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtrLockRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtcLockRm16Reg16 { rm16, reg16 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::GPR { gpr: reg16 },
+            ]),
+            Self::BtcRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtcLockRm32Reg32 { rm32, reg32 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: reg32 },
+            ]),
+            Self::BtcRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtcLockRm64Reg64 { rm64, reg64 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: reg64 },
+            ]),
+            Self::BtcRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcLockRm16Imm8 { rm16, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcLockRm32Imm8 { rm32, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BtcLockRm64Imm8 { rm64, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BsfReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::BsfReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::BsfReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BsrReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::BsrReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::BsrReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BswapReg32 { reg32 } => Operands::from_array([Operand::GPR { gpr: reg32 }]),
+            Self::BswapReg64 { reg64 } => Operands::from_array([Operand::GPR { gpr: reg64 }]),
+            Self::PopcntReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::PopcntReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::PopcntReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::LzcntReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::LzcntReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::LzcntReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::TzcntReg16Rm16 { reg16, rm16 } => Operands::from_array([
+                Operand::GPR { gpr: reg16 },
+                Operand::from_gpr_or_memory(rm16, Size::Bit16),
+            ]),
+            Self::TzcntReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::TzcntReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::AndnReg32Vreg32Rm32 { reg32, vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AndnReg64Vreg64Rm64 { reg64, vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BextrReg32Rm32Vreg32 { reg32, rm32, vreg32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: vreg32 },
+            ]),
+            Self::BextrReg64Rm64Vreg64 { reg64, rm64, vreg64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: vreg64 },
+            ]),
+            Self::BlsiVreg32Rm32 { vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::BlsiVreg64Rm64 { vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BlsmskVreg32Rm32 { vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::BlsmskVreg64Rm64 { vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BlsrVreg32Rm32 { vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::BlsrVreg64Rm64 { vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::BzhiReg32Rm32Vreg32 { reg32, rm32, vreg32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: vreg32 },
+            ]),
+            Self::BzhiReg64Rm64Vreg64 { reg64, rm64, vreg64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: vreg64 },
+            ]),
+            Self::PdepReg32Vreg32Rm32 { reg32, vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::PdepReg64Vreg64Rm64 { reg64, vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::PextReg32Vreg32Rm32 { reg32, vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::PextReg64Vreg64Rm64 { reg64, vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::MulxReg32Vreg32Rm32 { reg32, vreg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::GPR { gpr: vreg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::MulxReg64Vreg64Rm64 { reg64, vreg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::GPR { gpr: vreg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::RorxReg32Rm32Imm8 { reg32, rm32, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::RorxReg64Rm64Imm8 { reg64, rm64, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::SarxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: vreg32 },
+            ]),
+            Self::SarxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: vreg64 },
+            ]),
+            Self::ShlxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: vreg32 },
+            ]),
+            Self::ShlxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: vreg64 },
+            ]),
+            Self::ShrxReg32Rm32Vreg32 { reg32, rm32, vreg32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::GPR { gpr: vreg32 },
+            ]),
+            Self::ShrxReg64Rm64Vreg64 { reg64, rm64, vreg64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::GPR { gpr: vreg64 },
+            ]),
+            Self::AdcxReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AdcxReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::AdoxReg32Rm32 { reg32, rm32 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+            ]),
+            Self::AdoxReg64Rm64 { reg64, rm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+            ]),
+            Self::MovssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::MovssXmmm32Xmm { xmmm32, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm32, Size::Bit32), Operand::XMM { xmm }])
+            }
+            Self::MovsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::MovsdXmmm64Xmm { xmmm64, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm64, Size::Bit64), Operand::XMM { xmm }])
+            }
+            Self::AddssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::AddsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::SubssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::SubsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::MulssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::MulsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::DivssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::DivsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::SqrtssXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::SqrtsdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::UcomissXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::UcomisdXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::Cvtsi2sdXmmRm32 { xmm, rm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_gpr_or_memory(rm32, Size::Bit32)])
+            }
+            Self::Cvtsi2sdXmmRm64 { xmm, rm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_gpr_or_memory(rm64, Size::Bit64)])
+            }
+            Self::Cvttsd2siReg32Xmmm64 { reg32, xmmm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Cvttsd2siReg64Xmmm64 { reg64, xmmm64 } => Operands::from_array([
+                Operand::GPR { gpr: reg64 },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::MovdXmmRm32 { xmm, rm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_gpr_or_memory(rm32, Size::Bit32)])
+            }
+            Self::MovdRm32Xmm { rm32, xmm } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm32, Size::Bit32), Operand::XMM { xmm }])
+            }
+            Self::MovqXmmRm64 { xmm, rm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_gpr_or_memory(rm64, Size::Bit64)])
+            }
+            Self::MovqRm64Xmm { rm64, xmm } => {
+                Operands::from_array([Operand::from_gpr_or_memory(rm64, Size::Bit64), Operand::XMM { xmm }])
+            }
+            Self::MovapsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MovapsXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::MovupsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MovupsXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::MovdqaXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MovdqaXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::MovdquXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MovdquXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::AddpsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::AddpdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MulpsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::MulpdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PaddbXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PaddwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PadddXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PaddqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PsubbXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PsubwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PsubdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PsubqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PandXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PorXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PxorXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpeqbXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpeqwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpeqdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpeqqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpgtbXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpgtwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpgtdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PcmpgtqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PshufdXmmXmmm128Imm8 { xmm, xmmm128, imm8 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PshufbXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpcklbwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpcklwdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpckldqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpcklqdqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpckhbwXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpckhwdXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpckhdqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PunpckhqdqXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::PmovmskbReg32Xmm { reg32, xmm } => {
+                Operands::from_array([Operand::GPR { gpr: reg32 }, Operand::XMM { xmm }])
+            }
+            Self::PinsrbXmmR32m8Imm8 { xmm, r32m8, imm8 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_gpr_or_memory(r32m8, Size::Bit8),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PinsrwXmmR32m16Imm8 { xmm, r32m16, imm8 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_gpr_or_memory(r32m16, Size::Bit16),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PinsrdXmmRm32Imm8 { xmm, rm32, imm8 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PinsrqXmmRm64Imm8 { xmm, rm64, imm8 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PextrbR32m8XmmImm8 { r32m8, xmm, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(r32m8, Size::Bit8),
+                Operand::XMM { xmm },
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PextrwReg32XmmImm8 { reg32, xmm, imm8 } => Operands::from_array([
+                Operand::GPR { gpr: reg32 },
+                Operand::XMM { xmm },
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PextrwR32m16XmmImm8 { r32m16, xmm, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(r32m16, Size::Bit16),
+                Operand::XMM { xmm },
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PextrdRm32XmmImm8 { rm32, xmm, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm32, Size::Bit32),
+                Operand::XMM { xmm },
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::PextrqRm64XmmImm8 { rm64, xmm, imm8 } => Operands::from_array([
+                Operand::from_gpr_or_memory(rm64, Size::Bit64),
+                Operand::XMM { xmm },
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::BlendvpsXmmXmmm128Xmm0 { xmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+                Operand::XMM { xmm: XMM::XMM0 },
+            ]),
+            Self::BlendvpdXmmXmmm128Xmm0 { xmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+                Operand::XMM { xmm: XMM::XMM0 },
+            ]),
+            Self::PblendvbXmmXmmm128Xmm0 { xmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+                Operand::XMM { xmm: XMM::XMM0 },
+            ]),
+            Self::PtestXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::VaddpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VaddpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VaddpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VaddpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VaddpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VaddpdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VaddpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VaddpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VmulpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VmulpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VmulpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VmulpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VmulpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VmulpdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VmulpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VmulpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VpaddbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpaddbYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpaddwXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpaddwYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpadddXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpadddYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpadddZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpaddqXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpaddqYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpaddqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpandXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpandYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VporXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VporYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpxorXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpxorYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpshufbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::VpshufbYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpermdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpermpsYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::VpbroadcastbXmmXmmm8 { xmm, xmmm8 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm8, Size::Bit8)])
+            }
+            Self::VpbroadcastbYmmXmmm8 { ymm, xmmm8 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_xmm_or_memory(xmmm8, Size::Bit8)])
+            }
+            Self::VpbroadcastwXmmXmmm16 { xmm, xmmm16 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm16, Size::Bit16)])
+            }
+            Self::VpbroadcastwYmmXmmm16 { ymm, xmmm16 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_xmm_or_memory(xmmm16, Size::Bit16)])
+            }
+            Self::VpbroadcastdXmmXmmm32 { xmm, xmmm32 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::VpbroadcastdYmmXmmm32 { ymm, xmmm32 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_xmm_or_memory(xmmm32, Size::Bit32)])
+            }
+            Self::VpbroadcastqXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::VpbroadcastqYmmXmmm64 { ymm, xmmm64 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::VmovapsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::VmovapsXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::VmovapsYmmYmmm256 { ymm, ymmm256 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_ymm_or_memory(ymmm256, Size::Bit256)])
+            }
+            Self::VmovapsYmmm256Ymm { ymmm256, ymm } => {
+                Operands::from_array([Operand::from_ymm_or_memory(ymmm256, Size::Bit256), Operand::YMM { ymm }])
+            }
+            Self::VmovupsXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::VmovupsXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::VmovupsYmmYmmm256 { ymm, ymmm256 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_ymm_or_memory(ymmm256, Size::Bit256)])
+            }
+            Self::VmovupsYmmm256Ymm { ymmm256, ymm } => {
+                Operands::from_array([Operand::from_ymm_or_memory(ymmm256, Size::Bit256), Operand::YMM { ymm }])
+            }
+            Self::VmovupsZmmMaskZmmm512 { zmm, mask, zmmm512 } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit32),
+            ]),
+            Self::VmovupsZmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => Operands::from_array([
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit32),
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VmovdqaXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::VmovdqaXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::VmovdqaYmmYmmm256 { ymm, ymmm256 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_ymm_or_memory(ymmm256, Size::Bit256)])
+            }
+            Self::VmovdqaYmmm256Ymm { ymmm256, ymm } => {
+                Operands::from_array([Operand::from_ymm_or_memory(ymmm256, Size::Bit256), Operand::YMM { ymm }])
+            }
+            Self::VmovdquXmmXmmm128 { xmm, xmmm128 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::VmovdquXmmm128Xmm { xmmm128, xmm } => {
+                Operands::from_array([Operand::from_xmm_or_memory(xmmm128, Size::Bit128), Operand::XMM { xmm }])
+            }
+            Self::VmovdquYmmYmmm256 { ymm, ymmm256 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_ymm_or_memory(ymmm256, Size::Bit256)])
+            }
+            Self::VmovdquYmmm256Ymm { ymmm256, ymm } => {
+                Operands::from_array([Operand::from_ymm_or_memory(ymmm256, Size::Bit256), Operand::YMM { ymm }])
+            }
+            Self::Vzeroupper => Operands::from_array([]),
+            Self::VsubpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VsubpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VsubpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VsubpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VdivpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VdivpsZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VdivpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VdivpdZmmMaskVzmmZmmrEr {
+                zmm,
+                mask,
+                vzmm,
+                zmmr,
+                er,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VminpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VminpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+            ]),
+            Self::VminpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VminpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+            ]),
+            Self::VmaxpsZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VmaxpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+            ]),
+            Self::VmaxpdZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VmaxpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+            ]),
+            Self::VsqrtpsZmmMaskZmmm512b32 { zmm, mask, zmmm512b32 } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VsqrtpsZmmMaskZmmrEr { zmm, mask, zmmr, er } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VsqrtpdZmmMaskZmmm512b64 { zmm, mask, zmmm512b64 } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VsqrtpdZmmMaskZmmrEr { zmm, mask, zmmr, er } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VaddssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::VaddssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VaddsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::VaddsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VsubssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::VsubssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VsubsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::VsubsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VmulssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::VmulssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VmulsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::VmulsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VdivssXmmMaskVxmmXmmm32 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm32,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::VdivssXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VdivsdXmmMaskVxmmXmmm64 {
+                xmm,
+                mask,
+                vxmm,
+                xmmm64,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::VdivsdXmmMaskVxmmXmmrEr {
+                xmm,
+                mask,
+                vxmm,
+                xmmr,
+                er,
+            } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::Masking { masking: mask },
+                Operand::XMM { xmm: vxmm },
+                Operand::XMM { xmm: xmmr },
+                Operand::Rounding { rounding: er },
+            ]),
+            Self::VpsubdZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpsubqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpmulldZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpanddZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpandqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpordZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VporqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpxordZmmMaskVzmmZmmm512b32 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpxorqZmmMaskVzmmZmmm512b64 {
+                zmm,
+                mask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::Vmovdqu32ZmmMaskZmmm512 { zmm, mask, zmmm512 } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit32),
+            ]),
+            Self::Vmovdqu32Zmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => Operands::from_array([
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit32),
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::Vmovdqu64ZmmMaskZmmm512 { zmm, mask, zmmm512 } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mask },
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit64),
+            ]),
+            Self::Vmovdqu64Zmmm512MergemaskZmm {
+                zmmm512,
+                mergemask,
+                zmm,
+            } => Operands::from_array([
+                Operand::from_zmm_or_memory(zmmm512, Size::Bit64),
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VcmppsKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VcmppsKMergemaskVzmmZmmrSaeImm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmr,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VcmppdKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VcmppdKMergemaskVzmmZmmrSaeImm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmr,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::ZMM { zmm: zmmr },
+                Operand::Sae,
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VpcmpeqdKMergemaskVzmmZmmm512b32 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpcmpgtdKMergemaskVzmmZmmm512b32 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+            ]),
+            Self::VpcmpeqqKMergemaskVzmmZmmm512b64 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpcmpgtqKMergemaskVzmmZmmm512b64 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+            ]),
+            Self::VpcmpdKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VpcmpudKMergemaskVzmmZmmm512b32Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b32,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b32, Size::Bit32),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VpcmpqKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 {
+                k,
+                mergemask,
+                vzmm,
+                zmmm512b64,
+                imm8,
+            } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm: vzmm },
+                Operand::from_zmm_or_memory(zmmm512b64, Size::Bit64),
+                Operand::from_unsigned_imm8(imm8),
+            ]),
+            Self::KmovwKKm16 { k, km16 } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::from_opmask_or_memory(km16, Size::Bit16),
+            ]),
+            Self::KmovwMem16K { mem16, k } => Operands::from_array([
+                Operand::Memory {
+                    memory: mem16,
+                    size: Some(Size::Bit16),
+                },
+                Operand::Opmask { opmask: k },
+            ]),
+            Self::KmovwKReg32 { k, reg32 } => {
+                Operands::from_array([Operand::Opmask { opmask: k }, Operand::GPR { gpr: reg32 }])
+            }
+            Self::KmovwReg32Kr { reg32, kr } => {
+                Operands::from_array([Operand::GPR { gpr: reg32 }, Operand::Opmask { opmask: kr }])
+            }
+            Self::KandwKVkKr { k, vk, kr } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Opmask { opmask: vk },
+                Operand::Opmask { opmask: kr },
+            ]),
+            Self::KandnwKVkKr { k, vk, kr } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Opmask { opmask: vk },
+                Operand::Opmask { opmask: kr },
+            ]),
+            Self::KorwKVkKr { k, vk, kr } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Opmask { opmask: vk },
+                Operand::Opmask { opmask: kr },
+            ]),
+            Self::KxnorwKVkKr { k, vk, kr } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Opmask { opmask: vk },
+                Operand::Opmask { opmask: kr },
+            ]),
+            Self::KxorwKVkKr { k, vk, kr } => Operands::from_array([
+                Operand::Opmask { opmask: k },
+                Operand::Opmask { opmask: vk },
+                Operand::Opmask { opmask: kr },
+            ]),
+            Self::KnotwKKr { k, kr } => {
+                Operands::from_array([Operand::Opmask { opmask: k }, Operand::Opmask { opmask: kr }])
+            }
+            Self::KortestwKKr { k, kr } => {
+                Operands::from_array([Operand::Opmask { opmask: k }, Operand::Opmask { opmask: kr }])
+            }
+            Self::Vfmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vfnmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+            ]),
+            Self::Vfnmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::YMM { ymm: vymm },
+                Operand::from_ymm_or_memory(ymmm256, Size::Bit256),
+            ]),
+            Self::Vfnmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm32, Size::Bit32),
+            ]),
+            Self::Vfnmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::XMM { xmm: vxmm },
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+            ]),
+            Self::Vcvtph2psXmmXmmm64 { xmm, xmmm64 } => {
+                Operands::from_array([Operand::XMM { xmm }, Operand::from_xmm_or_memory(xmmm64, Size::Bit64)])
+            }
+            Self::Vcvtph2psYmmXmmm128 { ymm, xmmm128 } => {
+                Operands::from_array([Operand::YMM { ymm }, Operand::from_xmm_or_memory(xmmm128, Size::Bit128)])
+            }
+            Self::Vcvtps2phXmmm64XmmImm8 { xmmm64, xmm, imm8 } => Operands::from_array([
+                Operand::from_xmm_or_memory(xmmm64, Size::Bit64),
+                Operand::XMM { xmm },
+                Operand::from_imm8(imm8),
+            ]),
+            Self::Vcvtps2phXmmm128YmmImm8 { xmmm128, ymm, imm8 } => Operands::from_array([
+                Operand::from_xmm_or_memory(xmmm128, Size::Bit128),
+                Operand::YMM { ymm },
+                Operand::from_imm8(imm8),
+            ]),
+            Self::VpgatherddXmmVm32xVxmm { xmm, vm32x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VpgatherddYmmVm32yVymm { ymm, vm32y, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit32,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VpgatherddZmmMergemaskVm32z { zmm, mergemask, vm32z } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm32z,
+                    size: Size::Bit32,
+                },
+            ]),
+            Self::VpgatherdqXmmVm32xVxmm { xmm, vm32x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit64,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VpgatherdqYmmVm32xVymm { ymm, vm32x, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit64,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VpgatherdqZmmMergemaskVm32y { zmm, mergemask, vm32y } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit64,
+                },
+            ]),
+            Self::VpgatherqdXmmVm64xVxmm { xmm, vm64x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64x,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VpgatherqdXmmVm64yVxmm { xmm, vm64y, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64y,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VpgatherqdYmmMergemaskVm64z { ymm, mergemask, vm64z } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit32,
+                },
+            ]),
+            Self::VpgatherqqXmmVm64xVxmm { xmm, vm64x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64x,
+                    size: Size::Bit64,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VpgatherqqYmmVm64yVymm { ymm, vm64y, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm64y,
+                    size: Size::Bit64,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VpgatherqqZmmMergemaskVm64z { zmm, mergemask, vm64z } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit64,
+                },
+            ]),
+            Self::VgatherdpsXmmVm32xVxmm { xmm, vm32x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VgatherdpsYmmVm32yVymm { ymm, vm32y, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit32,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VgatherdpsZmmMergemaskVm32z { zmm, mergemask, vm32z } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm32z,
+                    size: Size::Bit32,
+                },
+            ]),
+            Self::VgatherdpdXmmVm32xVxmm { xmm, vm32x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit64,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VgatherdpdYmmVm32xVymm { ymm, vm32x, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm32x,
+                    size: Size::Bit64,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VgatherdpdZmmMergemaskVm32y { zmm, mergemask, vm32y } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit64,
+                },
+            ]),
+            Self::VgatherqpsXmmVm64xVxmm { xmm, vm64x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64x,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VgatherqpsXmmVm64yVxmm { xmm, vm64y, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64y,
+                    size: Size::Bit32,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VgatherqpsYmmMergemaskVm64z { ymm, mergemask, vm64z } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit32,
+                },
+            ]),
+            Self::VgatherqpdXmmVm64xVxmm { xmm, vm64x, vxmm } => Operands::from_array([
+                Operand::XMM { xmm },
+                Operand::VSIBMemory {
+                    memory: vm64x,
+                    size: Size::Bit64,
+                },
+                Operand::XMM { xmm: vxmm },
+            ]),
+            Self::VgatherqpdYmmVm64yVymm { ymm, vm64y, vymm } => Operands::from_array([
+                Operand::YMM { ymm },
+                Operand::VSIBMemory {
+                    memory: vm64y,
+                    size: Size::Bit64,
+                },
+                Operand::YMM { ymm: vymm },
+            ]),
+            Self::VgatherqpdZmmMergemaskVm64z { zmm, mergemask, vm64z } => Operands::from_array([
+                Operand::ZMM { zmm },
+                Operand::Masking { masking: mergemask },
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit64,
+                },
+            ]),
+            Self::VpscatterddVm32zMergemaskZmm { vm32z, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm32z,
+                    size: Size::Bit32,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VpscatterdqVm32yMergemaskZmm { vm32y, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit64,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VpscatterqdVm64zMergemaskYmm { vm64z, mergemask, ymm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit32,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::YMM { ymm },
+            ]),
+            Self::VpscatterqqVm64zMergemaskZmm { vm64z, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit64,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VscatterdpsVm32zMergemaskZmm { vm32z, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm32z,
+                    size: Size::Bit32,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VscatterdpdVm32yMergemaskZmm { vm32y, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm32y,
+                    size: Size::Bit64,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+            Self::VscatterqpsVm64zMergemaskYmm { vm64z, mergemask, ymm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit32,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::YMM { ymm },
+            ]),
+            Self::VscatterqpdVm64zMergemaskZmm { vm64z, mergemask, zmm } => Operands::from_array([
+                Operand::VSIBMemory {
+                    memory: vm64z,
+                    size: Size::Bit64,
+                },
+                Operand::Masking { masking: mergemask },
+                Operand::ZMM { zmm },
+            ]),
+        }
+    }
+}
@@ -0,0 +1,14041 @@
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use super::Instruction;
+use crate::models::{Operand, Size};
+
+/// Represents the kind of an instruction operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum OperandKind {
+    /// 8-bit immediate value.
+    Imm8,
+
+    /// 16-bit immediate value.
+    Imm16,
+
+    /// 32-bit immediate value.
+    Imm32,
+
+    /// 64-bit immediate value.
+    Imm64,
+
+    /// 8-bit general purpose register or memory operand.
+    RM8,
+
+    /// 16-bit general purpose register or memory operand.
+    RM16,
+
+    /// 32-bit general purpose register or memory operand.
+    RM32,
+
+    /// 64-bit general purpose register or memory operand.
+    RM64,
+
+    /// 32-bit general purpose register or 8-bit memory operand.
+    R32M8,
+
+    /// 32-bit general purpose register or 16-bit memory operand.
+    R32M16,
+
+    /// 8-bit general purpose register.
+    Reg8,
+
+    /// 16-bit general purpose register.
+    Reg16,
+
+    /// 32-bit general purpose register.
+    Reg32,
+
+    /// 64-bit general purpose register.
+    Reg64,
+
+    /// 32-bit general purpose register encoded in VEX.vvvv.
+    VReg32,
+
+    /// 64-bit general purpose register encoded in VEX.vvvv.
+    VReg64,
+
+    /// 128-bit XMM register.
+    Xmm,
+
+    /// 128-bit XMM register encoded in VEX.vvvv.
+    VXmm,
+
+    /// XMM register or 8-bit memory operand.
+    XmmM8,
+
+    /// XMM register or 16-bit memory operand.
+    XmmM16,
+
+    /// XMM register or 32-bit memory operand.
+    XmmM32,
+
+    /// XMM register or 64-bit memory operand.
+    XmmM64,
+
+    /// XMM register or 128-bit memory operand.
+    XmmM128,
+
+    /// 256-bit YMM register.
+    Ymm,
+
+    /// 256-bit YMM register encoded in VEX.vvvv.
+    VYmm,
+
+    /// YMM register or 256-bit memory operand.
+    YmmM256,
+
+    /// 512-bit ZMM register.
+    Zmm,
+
+    /// 512-bit ZMM register encoded in EVEX.vvvv.
+    VZmm,
+
+    /// 512-bit ZMM register in the r/m field, used by static rounding and SAE forms.
+    ZmmR,
+
+    /// ZMM register or 512-bit memory operand.
+    ZmmM512,
+
+    /// ZMM register, 512-bit memory operand or 32-bit memory operand broadcast to all elements.
+    ZmmM512B32,
+
+    /// ZMM register, 512-bit memory operand or 64-bit memory operand broadcast to all elements.
+    ZmmM512B64,
+
+    /// XMM register in the r/m field, used by static rounding forms of EVEX scalar instructions.
+    XmmR,
+
+    /// VSIB memory operand with 32-bit indexes in XMM register.
+    Vm32X,
+
+    /// VSIB memory operand with 32-bit indexes in YMM register.
+    Vm32Y,
+
+    /// VSIB memory operand with 32-bit indexes in ZMM register.
+    Vm32Z,
+
+    /// VSIB memory operand with 64-bit indexes in XMM register.
+    Vm64X,
+
+    /// VSIB memory operand with 64-bit indexes in YMM register.
+    Vm64Y,
+
+    /// VSIB memory operand with 64-bit indexes in ZMM register.
+    Vm64Z,
+
+    /// Opmask register.
+    K,
+
+    /// Opmask register encoded in VEX.vvvv.
+    VK,
+
+    /// Opmask register in the r/m field.
+    KR,
+
+    /// Opmask register or 16-bit memory operand.
+    KM16,
+
+    /// EVEX write masking of the destination, i.e. {k} with optional {z}.
+    Mask,
+
+    /// EVEX merge masking of the destination, i.e. {k} without {z}.
+    MergeMask,
+
+    /// EVEX static rounding, i.e. {rn-sae}, {rd-sae}, {ru-sae} or {rz-sae}.
+    Er,
+
+    /// 16-bit memory operand.
+    Mem16,
+
+    /// 64-bit memory operand (note: we support 64-bit memories only)
+    Mem64,
+
+    /// 128-bit memory operand.
+    Mem128,
+
+    /// Implicit AL register. Not passed to encoders.
+    AL,
+
+    /// Implicit AX register. Not passed to encoders.
+    AX,
+
+    /// Implicit EAX register. Not passed to encoders.
+    EAX,
+
+    /// Implicit RAX register. Not passed to encoders.
+    RAX,
+
+    /// Implicit CL register. Not passed to encoders.
+    CL,
+
+    /// Implicit XMM0 register. Not passed to encoders.
+    XMM0,
+
+    /// Implicit constant 1. Not passed to encoders.
+    One,
+
+    /// Implicit EVEX suppression of all floating-point exceptions, i.e. {sae}. Not passed to encoders.
+    Sae,
+}
+
+impl OperandKind {
+    /// Returns true if the operand is implicit, i.e. it is not passed to the encoders.
+    #[inline]
+    #[must_use]
+    pub const fn is_implicit(self) -> bool {
+        matches!(
+            self,
+            Self::AL | Self::AX | Self::EAX | Self::RAX | Self::CL | Self::XMM0 | Self::One | Self::Sae
+        )
+    }
+}
+
+/// Describes a single instruction variant: its mnemonic, the kinds of its operands
+/// (in Intel order, including the implicit ones) and the way to build [`Instruction`].
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct InstructionForm {
+    /// The name of the corresponding [`Instruction`] variant.
+    pub name: &'static str,
+    pub mnemonic: &'static str,
+    /// The legacy prefix written before the mnemonic, e.g. `rep` in `rep movsb`.
+    pub prefix: Option<&'static str>,
+    pub operands: &'static [OperandKind],
+    /// The size of the operation, taken from the first register or memory operand.
+    /// If there are no such operands, then it is implied by the prefixes (if any).
+    pub operation_size: Option<Size>,
+    /// Whether the immediate operand is unsigned, i.e. neither sign-extended nor relative.
+    pub unsigned_immediate: bool,
+    pub(crate) build: fn(&[Operand]) -> Instruction,
+}
+
+/// All the instruction forms, in the order of `x86.yaml`.
+pub static INSTRUCTION_FORMS: [InstructionForm; 1045] = [
+    InstructionForm {
+        name: "Lock",
+        mnemonic: "lock",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Lock,
+    },
+    InstructionForm {
+        name: "Nop",
+        mnemonic: "nop",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Nop,
+    },
+    InstructionForm {
+        name: "Cpuid",
+        mnemonic: "cpuid",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Cpuid,
+    },
+    InstructionForm {
+        name: "Sysenter",
+        mnemonic: "sysenter",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Sysenter,
+    },
+    InstructionForm {
+        name: "Syscall",
+        mnemonic: "syscall",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Syscall,
+    },
+    InstructionForm {
+        name: "Ret",
+        mnemonic: "ret",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Ret,
+    },
+    InstructionForm {
+        name: "RetImm16",
+        mnemonic: "ret",
+        prefix: None,
+        operands: &[OperandKind::Imm16],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::RetImm16 {
+            imm16: ops[0].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm8Imm8",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm16Imm16",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm32Imm32",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm64Imm32",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg8Imm8",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg8Imm8 {
+            reg8: ops[0].to_gpr(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg16Imm16",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg16Imm16 {
+            reg16: ops[0].to_gpr(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg32Imm32",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg32Imm32 {
+            reg32: ops[0].to_gpr(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg64Imm64",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::Imm64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg64Imm64 {
+            reg64: ops[0].to_gpr(),
+            imm64: ops[1].to_imm64(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm8Reg8",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm16Reg16",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm32Reg32",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "MovRm64Reg64",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg8Rm8",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg16Rm16",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg32Rm32",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovReg64Rm64",
+        mnemonic: "mov",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddAlImm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "AddAxImm16",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AddEaxImm32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddRaxImm32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm8Imm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm8Imm8",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm16Imm16",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm16Imm16",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm32Imm32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm32Imm32",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm64Imm32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm64Imm32",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm16Imm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm16Imm8",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm32Imm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm32Imm8",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm64Imm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm64Imm8",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm8Reg8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm8Reg8",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm16Reg16",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm16Reg16",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm32Reg32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm32Reg32",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddRm64Reg64",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddLockRm64Reg64",
+        mnemonic: "add",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AddReg8Rm8",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddReg16Rm16",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddReg32Rm32",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddReg64Rm64",
+        mnemonic: "add",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "JmpImm8",
+        mnemonic: "jmp",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JmpImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JmpImm32",
+        mnemonic: "jmp",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JmpImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JmpRm64",
+        mnemonic: "jmp",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::JmpRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "JccAImm8",
+        mnemonic: "ja",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccAImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccAeImm8",
+        mnemonic: "jae",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccAeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccBImm8",
+        mnemonic: "jb",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccBImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccBeImm8",
+        mnemonic: "jbe",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccBeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccEImm8",
+        mnemonic: "je",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccEImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccGImm8",
+        mnemonic: "jg",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccGImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccGeImm8",
+        mnemonic: "jge",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccGeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccLImm8",
+        mnemonic: "jl",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccLImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccLeImm8",
+        mnemonic: "jle",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccLeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccNeImm8",
+        mnemonic: "jne",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccNoImm8",
+        mnemonic: "jno",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNoImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccNpImm8",
+        mnemonic: "jnp",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNpImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccNsImm8",
+        mnemonic: "jns",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNsImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccOImm8",
+        mnemonic: "jo",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccOImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccPImm8",
+        mnemonic: "jp",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccPeImm8",
+        mnemonic: "jpe",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPeImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccPoImm8",
+        mnemonic: "jpo",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPoImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccSImm8",
+        mnemonic: "js",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccSImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccCImm8",
+        mnemonic: "jc",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccCImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccNcImm8",
+        mnemonic: "jnc",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNcImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "JccAImm32",
+        mnemonic: "ja",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccAImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccAeImm32",
+        mnemonic: "jae",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccAeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccBImm32",
+        mnemonic: "jb",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccBImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccBeImm32",
+        mnemonic: "jbe",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccBeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccEImm32",
+        mnemonic: "je",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccEImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccGImm32",
+        mnemonic: "jg",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccGImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccGeImm32",
+        mnemonic: "jge",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccGeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccLImm32",
+        mnemonic: "jl",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccLImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccLeImm32",
+        mnemonic: "jle",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccLeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccNeImm32",
+        mnemonic: "jne",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccNoImm32",
+        mnemonic: "jno",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNoImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccNpImm32",
+        mnemonic: "jnp",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNpImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccNsImm32",
+        mnemonic: "jns",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNsImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccOImm32",
+        mnemonic: "jo",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccOImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccPImm32",
+        mnemonic: "jp",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccPeImm32",
+        mnemonic: "jpe",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPeImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccPoImm32",
+        mnemonic: "jpo",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccPoImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccSImm32",
+        mnemonic: "js",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccSImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccCImm32",
+        mnemonic: "jc",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccCImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "JccNcImm32",
+        mnemonic: "jnc",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::JccNcImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CallImm32",
+        mnemonic: "call",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::CallImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CallRm64",
+        mnemonic: "call",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CallRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmpAlImm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "CmpAxImm16",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "CmpEaxImm32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRaxImm32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm8Imm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm16Imm16",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm32Imm32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm64Imm32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm16Imm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm32Imm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm64Imm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm8Reg8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm16Reg16",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm32Reg32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpRm64Reg64",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpReg8Rm8",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmpReg16Rm16",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmpReg32Rm32",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmpReg64Rm64",
+        mnemonic: "cmp",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LeaReg16Mem64",
+        mnemonic: "lea",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::Mem64],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LeaReg16Mem64 {
+            reg16: ops[0].to_gpr(),
+            mem64: ops[1].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LeaReg32Mem64",
+        mnemonic: "lea",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::Mem64],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LeaReg32Mem64 {
+            reg32: ops[0].to_gpr(),
+            mem64: ops[1].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LeaReg64Mem64",
+        mnemonic: "lea",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::Mem64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LeaReg64Mem64 {
+            reg64: ops[0].to_gpr(),
+            mem64: ops[1].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PushRm64",
+        mnemonic: "push",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PushRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PushReg64",
+        mnemonic: "push",
+        prefix: None,
+        operands: &[OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PushReg64 { reg64: ops[0].to_gpr() },
+    },
+    InstructionForm {
+        name: "PushImm8",
+        mnemonic: "push",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PushImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "PushImm16",
+        mnemonic: "push",
+        prefix: None,
+        operands: &[OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PushImm16 {
+            imm16: ops[0].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "PushImm32",
+        mnemonic: "push",
+        prefix: None,
+        operands: &[OperandKind::Imm32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PushImm32 {
+            imm32: ops[0].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "PopRm64",
+        mnemonic: "pop",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PopRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PopReg64",
+        mnemonic: "pop",
+        prefix: None,
+        operands: &[OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PopReg64 { reg64: ops[0].to_gpr() },
+    },
+    InstructionForm {
+        name: "SubAlImm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "SubAxImm16",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SubEaxImm32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubRaxImm32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm8Imm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm8Imm8",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm16Imm16",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm16Imm16",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm32Imm32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm32Imm32",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm64Imm32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm64Imm32",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm16Imm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm16Imm8",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm32Imm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm32Imm8",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm64Imm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm64Imm8",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm8Reg8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm8Reg8",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm16Reg16",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm16Reg16",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm32Reg32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm32Reg32",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubRm64Reg64",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubLockRm64Reg64",
+        mnemonic: "sub",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SubReg8Rm8",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SubReg16Rm16",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SubReg32Rm32",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SubReg64Rm64",
+        mnemonic: "sub",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XorAlImm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "XorAxImm16",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "XorEaxImm32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorRaxImm32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm8Imm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm8Imm8",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm16Imm16",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm16Imm16",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm32Imm32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm32Imm32",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm64Imm32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm64Imm32",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm16Imm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm16Imm8",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm32Imm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm32Imm8",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm64Imm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm64Imm8",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm8Reg8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm8Reg8",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm16Reg16",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm16Reg16",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm32Reg32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm32Reg32",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorRm64Reg64",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorLockRm64Reg64",
+        mnemonic: "xor",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XorReg8Rm8",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XorReg16Rm16",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XorReg32Rm32",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XorReg64Rm64",
+        mnemonic: "xor",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XorReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IntImm8",
+        mnemonic: "int",
+        prefix: None,
+        operands: &[OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::IntImm8 { imm8: ops[0].to_imm8() },
+    },
+    InstructionForm {
+        name: "Int1",
+        mnemonic: "int1",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Int1,
+    },
+    InstructionForm {
+        name: "Int3",
+        mnemonic: "int3",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Int3,
+    },
+    InstructionForm {
+        name: "RolRm8One",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm16One",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm32One",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm64One",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm8Imm8",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RolRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm16Imm8",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RolRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm32Imm8",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RolRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm64Imm8",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RolRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm8Cl",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm16Cl",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm32Cl",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RolRm64Cl",
+        mnemonic: "rol",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RolRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm8One",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm16One",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm32One",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm64One",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm8Imm8",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm16Imm8",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm32Imm8",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm64Imm8",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm8Cl",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm16Cl",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm32Cl",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorRm64Cl",
+        mnemonic: "ror",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RorRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm8One",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm16One",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm32One",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm64One",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm8Imm8",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RclRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm16Imm8",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RclRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm32Imm8",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RclRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm64Imm8",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RclRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm8Cl",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm16Cl",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm32Cl",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RclRm64Cl",
+        mnemonic: "rcl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RclRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm8One",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm16One",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm32One",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm64One",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm8Imm8",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RcrRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm16Imm8",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RcrRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm32Imm8",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RcrRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm64Imm8",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RcrRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm8Cl",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm16Cl",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm32Cl",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RcrRm64Cl",
+        mnemonic: "rcr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::RcrRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm8One",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm16One",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm32One",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm64One",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm8Imm8",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShlRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm16Imm8",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShlRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm32Imm8",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShlRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm64Imm8",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShlRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm8Cl",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm16Cl",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm32Cl",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShlRm64Cl",
+        mnemonic: "shl",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm8One",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm16One",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm32One",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm64One",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm8Imm8",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShrRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm16Imm8",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShrRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm32Imm8",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShrRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm64Imm8",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::ShrRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm8Cl",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm16Cl",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm32Cl",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ShrRm64Cl",
+        mnemonic: "shr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm8One",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::One],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm8One {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm16One",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::One],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm16One {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm32One",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::One],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm32One {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm64One",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::One],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm64One {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm8Imm8",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: true,
+        build: |ops| Instruction::SarRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm16Imm8",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::SarRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm32Imm8",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::SarRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm64Imm8",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::SarRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm8Cl",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::CL],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm8Cl {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm16Cl",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::CL],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm16Cl {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm32Cl",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::CL],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm32Cl {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SarRm64Cl",
+        mnemonic: "sar",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::CL],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarRm64Cl {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulRm8",
+        mnemonic: "mul",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulRm16",
+        mnemonic: "mul",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulRm32",
+        mnemonic: "mul",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulRm64",
+        mnemonic: "mul",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulRm8",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulRm16",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulRm32",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulRm64",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg16Rm16",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg32Rm32",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg64Rm64",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg16Rm16Imm8",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg16Rm16Imm8 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg32Rm32Imm8",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg32Rm32Imm8 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg64Rm64Imm8",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg64Rm64Imm8 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg16Rm16Imm16",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg16Rm16Imm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+            imm16: ops[2].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg32Rm32Imm32",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg32Rm32Imm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            imm32: ops[2].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "ImulReg64Rm64Imm32",
+        mnemonic: "imul",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ImulReg64Rm64Imm32 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            imm32: ops[2].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "DivRm8",
+        mnemonic: "div",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DivRm16",
+        mnemonic: "div",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DivRm32",
+        mnemonic: "div",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DivRm64",
+        mnemonic: "div",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IdivRm8",
+        mnemonic: "idiv",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IdivRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IdivRm16",
+        mnemonic: "idiv",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IdivRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IdivRm32",
+        mnemonic: "idiv",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IdivRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IdivRm64",
+        mnemonic: "idiv",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IdivRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndAlImm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "AndAxImm16",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AndEaxImm32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndRaxImm32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm8Imm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm8Imm8",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm16Imm16",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm16Imm16",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm32Imm32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm32Imm32",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm64Imm32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm64Imm32",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm16Imm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm16Imm8",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm32Imm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm32Imm8",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm64Imm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm64Imm8",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm8Reg8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm8Reg8",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm16Reg16",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm16Reg16",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm32Reg32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm32Reg32",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndRm64Reg64",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndLockRm64Reg64",
+        mnemonic: "and",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AndReg8Rm8",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndReg16Rm16",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndReg32Rm32",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndReg64Rm64",
+        mnemonic: "and",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "OrAlImm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "OrAxImm16",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "OrEaxImm32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrRaxImm32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm8Imm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm8Imm8",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm16Imm16",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm16Imm16",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm32Imm32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm32Imm32",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm64Imm32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm64Imm32",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm16Imm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm16Imm8",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm32Imm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm32Imm8",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm64Imm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm64Imm8",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm8Reg8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm8Reg8",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm16Reg16",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm16Reg16",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm32Reg32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm32Reg32",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrRm64Reg64",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrLockRm64Reg64",
+        mnemonic: "or",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "OrReg8Rm8",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "OrReg16Rm16",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "OrReg32Rm32",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "OrReg64Rm64",
+        mnemonic: "or",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::OrReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdcAlImm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "AdcAxImm16",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AdcEaxImm32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRaxImm32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm8Imm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm8Imm8",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm16Imm16",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm16Imm16",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm32Imm32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm32Imm32",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm64Imm32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm64Imm32",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm16Imm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm16Imm8",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm32Imm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm32Imm8",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm64Imm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm64Imm8",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm8Reg8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm8Reg8",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm16Reg16",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm16Reg16",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm32Reg32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm32Reg32",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcRm64Reg64",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcLockRm64Reg64",
+        mnemonic: "adc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcReg8Rm8",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdcReg16Rm16",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdcReg32Rm32",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdcReg64Rm64",
+        mnemonic: "adc",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SbbAlImm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "SbbAxImm16",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SbbEaxImm32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRaxImm32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm8Imm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm8Imm8",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm16Imm16",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm16Imm16",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm32Imm32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm32Imm32",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm64Imm32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm64Imm32",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm16Imm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm16Imm8",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm32Imm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm32Imm8",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm64Imm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm64Imm8",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm8Reg8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm8Reg8",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm16Reg16",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm16Reg16",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm32Reg32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm32Reg32",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbRm64Reg64",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbLockRm64Reg64",
+        mnemonic: "sbb",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SbbReg8Rm8",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SbbReg16Rm16",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SbbReg32Rm32",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SbbReg64Rm64",
+        mnemonic: "sbb",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SbbReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "TestAlImm8",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::AL, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestAlImm8 { imm8: ops[1].to_imm8() },
+    },
+    InstructionForm {
+        name: "TestAxImm16",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::AX, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestAxImm16 {
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "TestEaxImm32",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::EAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestEaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "TestRaxImm32",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RAX, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRaxImm32 {
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm8Imm8",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm8Imm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm16Imm16",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm16Imm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm16: ops[1].to_imm16(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm32Imm32",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm32Imm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm64Imm32",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm64Imm32 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm32: ops[1].to_imm32(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm8Reg8",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm16Reg16",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm32Reg32",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "TestRm64Reg64",
+        mnemonic: "test",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TestRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "NotRm8",
+        mnemonic: "not",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotLockRm8",
+        mnemonic: "not",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotLockRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotRm16",
+        mnemonic: "not",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotLockRm16",
+        mnemonic: "not",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotLockRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotRm32",
+        mnemonic: "not",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotLockRm32",
+        mnemonic: "not",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotLockRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotRm64",
+        mnemonic: "not",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NotLockRm64",
+        mnemonic: "not",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NotLockRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegRm8",
+        mnemonic: "neg",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegLockRm8",
+        mnemonic: "neg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegLockRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegRm16",
+        mnemonic: "neg",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegLockRm16",
+        mnemonic: "neg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegLockRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegRm32",
+        mnemonic: "neg",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegLockRm32",
+        mnemonic: "neg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegLockRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegRm64",
+        mnemonic: "neg",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "NegLockRm64",
+        mnemonic: "neg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::NegLockRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncRm8",
+        mnemonic: "inc",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncLockRm8",
+        mnemonic: "inc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncLockRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncRm16",
+        mnemonic: "inc",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncLockRm16",
+        mnemonic: "inc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncLockRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncRm32",
+        mnemonic: "inc",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncLockRm32",
+        mnemonic: "inc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncLockRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncRm64",
+        mnemonic: "inc",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "IncLockRm64",
+        mnemonic: "inc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::IncLockRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecRm8",
+        mnemonic: "dec",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecLockRm8",
+        mnemonic: "dec",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecLockRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecRm16",
+        mnemonic: "dec",
+        prefix: None,
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecLockRm16",
+        mnemonic: "dec",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecLockRm16 {
+            rm16: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecRm32",
+        mnemonic: "dec",
+        prefix: None,
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecLockRm32",
+        mnemonic: "dec",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecLockRm32 {
+            rm32: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecRm64",
+        mnemonic: "dec",
+        prefix: None,
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DecLockRm64",
+        mnemonic: "dec",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::DecLockRm64 {
+            rm64: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovzxReg16Rm8",
+        mnemonic: "movzx",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovzxReg16Rm8 {
+            reg16: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovzxReg32Rm8",
+        mnemonic: "movzx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovzxReg32Rm8 {
+            reg32: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovzxReg64Rm8",
+        mnemonic: "movzx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovzxReg64Rm8 {
+            reg64: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovzxReg32Rm16",
+        mnemonic: "movzx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM16],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovzxReg32Rm16 {
+            reg32: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovzxReg64Rm16",
+        mnemonic: "movzx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM16],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovzxReg64Rm16 {
+            reg64: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxReg16Rm8",
+        mnemonic: "movsx",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxReg16Rm8 {
+            reg16: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxReg32Rm8",
+        mnemonic: "movsx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxReg32Rm8 {
+            reg32: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxReg64Rm8",
+        mnemonic: "movsx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxReg64Rm8 {
+            reg64: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxReg32Rm16",
+        mnemonic: "movsx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM16],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxReg32Rm16 {
+            reg32: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxReg64Rm16",
+        mnemonic: "movsx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM16],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxReg64Rm16 {
+            reg64: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsxdReg64Rm32",
+        mnemonic: "movsxd",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM32],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsxdReg64Rm32 {
+            reg64: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccARm8",
+        mnemonic: "seta",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccARm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccAeRm8",
+        mnemonic: "setae",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccAeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccBRm8",
+        mnemonic: "setb",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccBRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccBeRm8",
+        mnemonic: "setbe",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccBeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccERm8",
+        mnemonic: "sete",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccERm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccGRm8",
+        mnemonic: "setg",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccGRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccGeRm8",
+        mnemonic: "setge",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccGeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccLRm8",
+        mnemonic: "setl",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccLRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccLeRm8",
+        mnemonic: "setle",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccLeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccNeRm8",
+        mnemonic: "setne",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccNeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccNoRm8",
+        mnemonic: "setno",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccNoRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccNpRm8",
+        mnemonic: "setnp",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccNpRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccNsRm8",
+        mnemonic: "setns",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccNsRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccORm8",
+        mnemonic: "seto",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccORm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccPRm8",
+        mnemonic: "setp",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccPRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccPeRm8",
+        mnemonic: "setpe",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccPeRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccPoRm8",
+        mnemonic: "setpo",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccPoRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccSRm8",
+        mnemonic: "sets",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccSRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccCRm8",
+        mnemonic: "setc",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccCRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SetccNcRm8",
+        mnemonic: "setnc",
+        prefix: None,
+        operands: &[OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SetccNcRm8 {
+            rm8: ops[0].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAReg16Rm16",
+        mnemonic: "cmova",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAeReg16Rm16",
+        mnemonic: "cmovae",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBReg16Rm16",
+        mnemonic: "cmovb",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBeReg16Rm16",
+        mnemonic: "cmovbe",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccEReg16Rm16",
+        mnemonic: "cmove",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccEReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGReg16Rm16",
+        mnemonic: "cmovg",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGeReg16Rm16",
+        mnemonic: "cmovge",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLReg16Rm16",
+        mnemonic: "cmovl",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLeReg16Rm16",
+        mnemonic: "cmovle",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNeReg16Rm16",
+        mnemonic: "cmovne",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNoReg16Rm16",
+        mnemonic: "cmovno",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNoReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNpReg16Rm16",
+        mnemonic: "cmovnp",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNpReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNsReg16Rm16",
+        mnemonic: "cmovns",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNsReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccOReg16Rm16",
+        mnemonic: "cmovo",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccOReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPReg16Rm16",
+        mnemonic: "cmovp",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPeReg16Rm16",
+        mnemonic: "cmovpe",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPeReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPoReg16Rm16",
+        mnemonic: "cmovpo",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPoReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccSReg16Rm16",
+        mnemonic: "cmovs",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccSReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccCReg16Rm16",
+        mnemonic: "cmovc",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccCReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNcReg16Rm16",
+        mnemonic: "cmovnc",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNcReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAReg32Rm32",
+        mnemonic: "cmova",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAeReg32Rm32",
+        mnemonic: "cmovae",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBReg32Rm32",
+        mnemonic: "cmovb",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBeReg32Rm32",
+        mnemonic: "cmovbe",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccEReg32Rm32",
+        mnemonic: "cmove",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccEReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGReg32Rm32",
+        mnemonic: "cmovg",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGeReg32Rm32",
+        mnemonic: "cmovge",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLReg32Rm32",
+        mnemonic: "cmovl",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLeReg32Rm32",
+        mnemonic: "cmovle",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNeReg32Rm32",
+        mnemonic: "cmovne",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNoReg32Rm32",
+        mnemonic: "cmovno",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNoReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNpReg32Rm32",
+        mnemonic: "cmovnp",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNpReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNsReg32Rm32",
+        mnemonic: "cmovns",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNsReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccOReg32Rm32",
+        mnemonic: "cmovo",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccOReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPReg32Rm32",
+        mnemonic: "cmovp",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPeReg32Rm32",
+        mnemonic: "cmovpe",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPeReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPoReg32Rm32",
+        mnemonic: "cmovpo",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPoReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccSReg32Rm32",
+        mnemonic: "cmovs",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccSReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccCReg32Rm32",
+        mnemonic: "cmovc",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccCReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNcReg32Rm32",
+        mnemonic: "cmovnc",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNcReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAReg64Rm64",
+        mnemonic: "cmova",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccAeReg64Rm64",
+        mnemonic: "cmovae",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccAeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBReg64Rm64",
+        mnemonic: "cmovb",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccBeReg64Rm64",
+        mnemonic: "cmovbe",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccBeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccEReg64Rm64",
+        mnemonic: "cmove",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccEReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGReg64Rm64",
+        mnemonic: "cmovg",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccGeReg64Rm64",
+        mnemonic: "cmovge",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccGeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLReg64Rm64",
+        mnemonic: "cmovl",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccLeReg64Rm64",
+        mnemonic: "cmovle",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccLeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNeReg64Rm64",
+        mnemonic: "cmovne",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNoReg64Rm64",
+        mnemonic: "cmovno",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNoReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNpReg64Rm64",
+        mnemonic: "cmovnp",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNpReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNsReg64Rm64",
+        mnemonic: "cmovns",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNsReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccOReg64Rm64",
+        mnemonic: "cmovo",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccOReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPReg64Rm64",
+        mnemonic: "cmovp",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPeReg64Rm64",
+        mnemonic: "cmovpe",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPeReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccPoReg64Rm64",
+        mnemonic: "cmovpo",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccPoReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccSReg64Rm64",
+        mnemonic: "cmovs",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccSReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccCReg64Rm64",
+        mnemonic: "cmovc",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccCReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmovccNcReg64Rm64",
+        mnemonic: "cmovnc",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmovccNcReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XchgRm8Reg8",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgLockRm8Reg8",
+        mnemonic: "xchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgRm16Reg16",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgLockRm16Reg16",
+        mnemonic: "xchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgRm32Reg32",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgLockRm32Reg32",
+        mnemonic: "xchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgRm64Reg64",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgLockRm64Reg64",
+        mnemonic: "xchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XchgReg8Rm8",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::Reg8, OperandKind::RM8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgReg8Rm8 {
+            reg8: ops[0].to_gpr(),
+            rm8: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XchgReg16Rm16",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XchgReg32Rm32",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XchgReg64Rm64",
+        mnemonic: "xchg",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XchgReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgRm8Reg8",
+        mnemonic: "cmpxchg",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgLockRm8Reg8",
+        mnemonic: "cmpxchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgRm16Reg16",
+        mnemonic: "cmpxchg",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgLockRm16Reg16",
+        mnemonic: "cmpxchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgRm32Reg32",
+        mnemonic: "cmpxchg",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgLockRm32Reg32",
+        mnemonic: "cmpxchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgRm64Reg64",
+        mnemonic: "cmpxchg",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "CmpxchgLockRm64Reg64",
+        mnemonic: "cmpxchg",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::CmpxchgLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "Cmpxchg8bMem64",
+        mnemonic: "cmpxchg8b",
+        prefix: None,
+        operands: &[OperandKind::Mem64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cmpxchg8bMem64 {
+            mem64: ops[0].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cmpxchg8bLockMem64",
+        mnemonic: "cmpxchg8b",
+        prefix: Some("lock"),
+        operands: &[OperandKind::Mem64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cmpxchg8bLockMem64 {
+            mem64: ops[0].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cmpxchg16bMem128",
+        mnemonic: "cmpxchg16b",
+        prefix: None,
+        operands: &[OperandKind::Mem128],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cmpxchg16bMem128 {
+            mem128: ops[0].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cmpxchg16bLockMem128",
+        mnemonic: "cmpxchg16b",
+        prefix: Some("lock"),
+        operands: &[OperandKind::Mem128],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cmpxchg16bLockMem128 {
+            mem128: ops[0].to_memory(),
+        },
+    },
+    InstructionForm {
+        name: "XaddRm8Reg8",
+        mnemonic: "xadd",
+        prefix: None,
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddLockRm8Reg8",
+        mnemonic: "xadd",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM8, OperandKind::Reg8],
+        operation_size: Some(Size::Bit8),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddLockRm8Reg8 {
+            rm8: ops[0].to_gpr_or_memory(),
+            reg8: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddRm16Reg16",
+        mnemonic: "xadd",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddLockRm16Reg16",
+        mnemonic: "xadd",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddRm32Reg32",
+        mnemonic: "xadd",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddLockRm32Reg32",
+        mnemonic: "xadd",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddRm64Reg64",
+        mnemonic: "xadd",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "XaddLockRm64Reg64",
+        mnemonic: "xadd",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::XaddLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "MovsB",
+        mnemonic: "movsb",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsB,
+    },
+    InstructionForm {
+        name: "MovsW",
+        mnemonic: "movsw",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsW,
+    },
+    InstructionForm {
+        name: "MovsD",
+        mnemonic: "movsd",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsD,
+    },
+    InstructionForm {
+        name: "MovsQ",
+        mnemonic: "movsq",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsQ,
+    },
+    InstructionForm {
+        name: "MovsRepB",
+        mnemonic: "movsb",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsRepB,
+    },
+    InstructionForm {
+        name: "MovsRepW",
+        mnemonic: "movsw",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsRepW,
+    },
+    InstructionForm {
+        name: "MovsRepD",
+        mnemonic: "movsd",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsRepD,
+    },
+    InstructionForm {
+        name: "MovsRepQ",
+        mnemonic: "movsq",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::MovsRepQ,
+    },
+    InstructionForm {
+        name: "StosB",
+        mnemonic: "stosb",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::StosB,
+    },
+    InstructionForm {
+        name: "StosW",
+        mnemonic: "stosw",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::StosW,
+    },
+    InstructionForm {
+        name: "StosD",
+        mnemonic: "stosd",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::StosD,
+    },
+    InstructionForm {
+        name: "StosQ",
+        mnemonic: "stosq",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::StosQ,
+    },
+    InstructionForm {
+        name: "StosRepB",
+        mnemonic: "stosb",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::StosRepB,
+    },
+    InstructionForm {
+        name: "StosRepW",
+        mnemonic: "stosw",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::StosRepW,
+    },
+    InstructionForm {
+        name: "StosRepD",
+        mnemonic: "stosd",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::StosRepD,
+    },
+    InstructionForm {
+        name: "StosRepQ",
+        mnemonic: "stosq",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::StosRepQ,
+    },
+    InstructionForm {
+        name: "LodsB",
+        mnemonic: "lodsb",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsB,
+    },
+    InstructionForm {
+        name: "LodsW",
+        mnemonic: "lodsw",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsW,
+    },
+    InstructionForm {
+        name: "LodsD",
+        mnemonic: "lodsd",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsD,
+    },
+    InstructionForm {
+        name: "LodsQ",
+        mnemonic: "lodsq",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsQ,
+    },
+    InstructionForm {
+        name: "LodsRepB",
+        mnemonic: "lodsb",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsRepB,
+    },
+    InstructionForm {
+        name: "LodsRepW",
+        mnemonic: "lodsw",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsRepW,
+    },
+    InstructionForm {
+        name: "LodsRepD",
+        mnemonic: "lodsd",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsRepD,
+    },
+    InstructionForm {
+        name: "LodsRepQ",
+        mnemonic: "lodsq",
+        prefix: Some("rep"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::LodsRepQ,
+    },
+    InstructionForm {
+        name: "CmpsB",
+        mnemonic: "cmpsb",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsB,
+    },
+    InstructionForm {
+        name: "CmpsW",
+        mnemonic: "cmpsw",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsW,
+    },
+    InstructionForm {
+        name: "CmpsD",
+        mnemonic: "cmpsd",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsD,
+    },
+    InstructionForm {
+        name: "CmpsQ",
+        mnemonic: "cmpsq",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsQ,
+    },
+    InstructionForm {
+        name: "CmpsRepeB",
+        mnemonic: "cmpsb",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepeB,
+    },
+    InstructionForm {
+        name: "CmpsRepeW",
+        mnemonic: "cmpsw",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepeW,
+    },
+    InstructionForm {
+        name: "CmpsRepeD",
+        mnemonic: "cmpsd",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepeD,
+    },
+    InstructionForm {
+        name: "CmpsRepeQ",
+        mnemonic: "cmpsq",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepeQ,
+    },
+    InstructionForm {
+        name: "CmpsRepneB",
+        mnemonic: "cmpsb",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepneB,
+    },
+    InstructionForm {
+        name: "CmpsRepneW",
+        mnemonic: "cmpsw",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepneW,
+    },
+    InstructionForm {
+        name: "CmpsRepneD",
+        mnemonic: "cmpsd",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepneD,
+    },
+    InstructionForm {
+        name: "CmpsRepneQ",
+        mnemonic: "cmpsq",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::CmpsRepneQ,
+    },
+    InstructionForm {
+        name: "ScasB",
+        mnemonic: "scasb",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasB,
+    },
+    InstructionForm {
+        name: "ScasW",
+        mnemonic: "scasw",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasW,
+    },
+    InstructionForm {
+        name: "ScasD",
+        mnemonic: "scasd",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasD,
+    },
+    InstructionForm {
+        name: "ScasQ",
+        mnemonic: "scasq",
+        prefix: None,
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasQ,
+    },
+    InstructionForm {
+        name: "ScasRepeB",
+        mnemonic: "scasb",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepeB,
+    },
+    InstructionForm {
+        name: "ScasRepeW",
+        mnemonic: "scasw",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepeW,
+    },
+    InstructionForm {
+        name: "ScasRepeD",
+        mnemonic: "scasd",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepeD,
+    },
+    InstructionForm {
+        name: "ScasRepeQ",
+        mnemonic: "scasq",
+        prefix: Some("repe"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepeQ,
+    },
+    InstructionForm {
+        name: "ScasRepneB",
+        mnemonic: "scasb",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepneB,
+    },
+    InstructionForm {
+        name: "ScasRepneW",
+        mnemonic: "scasw",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepneW,
+    },
+    InstructionForm {
+        name: "ScasRepneD",
+        mnemonic: "scasd",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepneD,
+    },
+    InstructionForm {
+        name: "ScasRepneQ",
+        mnemonic: "scasq",
+        prefix: Some("repne"),
+        operands: &[],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |_| Instruction::ScasRepneQ,
+    },
+    InstructionForm {
+        name: "BtRm16Reg16",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtRm32Reg32",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtRm64Reg64",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtRm16Imm8",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtRm32Imm8",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtRm64Imm8",
+        mnemonic: "bt",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm16Reg16",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm16Reg16",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm32Reg32",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm32Reg32",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm64Reg64",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm64Reg64",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtsLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm16Imm8",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm16Imm8",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm32Imm8",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm32Imm8",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsRm64Imm8",
+        mnemonic: "bts",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtsLockRm64Imm8",
+        mnemonic: "bts",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtsLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm16Reg16",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm16Reg16",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm32Reg32",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm32Reg32",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm64Reg64",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm64Reg64",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtrLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm16Imm8",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm16Imm8",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm32Imm8",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm32Imm8",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrRm64Imm8",
+        mnemonic: "btr",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtrLockRm64Imm8",
+        mnemonic: "btr",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtrLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm16Reg16",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm16Reg16",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Reg16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcLockRm16Reg16 {
+            rm16: ops[0].to_gpr_or_memory(),
+            reg16: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm32Reg32",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm32Reg32",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcLockRm32Reg32 {
+            rm32: ops[0].to_gpr_or_memory(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm64Reg64",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm64Reg64",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BtcLockRm64Reg64 {
+            rm64: ops[0].to_gpr_or_memory(),
+            reg64: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm16Imm8",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm16Imm8",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcLockRm16Imm8 {
+            rm16: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm32Imm8",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm32Imm8",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcLockRm32Imm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcRm64Imm8",
+        mnemonic: "btc",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BtcLockRm64Imm8",
+        mnemonic: "btc",
+        prefix: Some("lock"),
+        operands: &[OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::BtcLockRm64Imm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            imm8: ops[1].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BsfReg16Rm16",
+        mnemonic: "bsf",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsfReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BsfReg32Rm32",
+        mnemonic: "bsf",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsfReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BsfReg64Rm64",
+        mnemonic: "bsf",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsfReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BsrReg16Rm16",
+        mnemonic: "bsr",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsrReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BsrReg32Rm32",
+        mnemonic: "bsr",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsrReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BsrReg64Rm64",
+        mnemonic: "bsr",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BsrReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BswapReg32",
+        mnemonic: "bswap",
+        prefix: None,
+        operands: &[OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BswapReg32 { reg32: ops[0].to_gpr() },
+    },
+    InstructionForm {
+        name: "BswapReg64",
+        mnemonic: "bswap",
+        prefix: None,
+        operands: &[OperandKind::Reg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BswapReg64 { reg64: ops[0].to_gpr() },
+    },
+    InstructionForm {
+        name: "PopcntReg16Rm16",
+        mnemonic: "popcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PopcntReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PopcntReg32Rm32",
+        mnemonic: "popcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PopcntReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PopcntReg64Rm64",
+        mnemonic: "popcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PopcntReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LzcntReg16Rm16",
+        mnemonic: "lzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LzcntReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LzcntReg32Rm32",
+        mnemonic: "lzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LzcntReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "LzcntReg64Rm64",
+        mnemonic: "lzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::LzcntReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "TzcntReg16Rm16",
+        mnemonic: "tzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg16, OperandKind::RM16],
+        operation_size: Some(Size::Bit16),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TzcntReg16Rm16 {
+            reg16: ops[0].to_gpr(),
+            rm16: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "TzcntReg32Rm32",
+        mnemonic: "tzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TzcntReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "TzcntReg64Rm64",
+        mnemonic: "tzcnt",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::TzcntReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndnReg32Vreg32Rm32",
+        mnemonic: "andn",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndnReg32Vreg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            vreg32: ops[1].to_gpr(),
+            rm32: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AndnReg64Vreg64Rm64",
+        mnemonic: "andn",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AndnReg64Vreg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            vreg64: ops[1].to_gpr(),
+            rm64: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BextrReg32Rm32Vreg32",
+        mnemonic: "bextr",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::VReg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BextrReg32Rm32Vreg32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            vreg32: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BextrReg64Rm64Vreg64",
+        mnemonic: "bextr",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::VReg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BextrReg64Rm64Vreg64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            vreg64: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BlsiVreg32Rm32",
+        mnemonic: "blsi",
+        prefix: None,
+        operands: &[OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsiVreg32Rm32 {
+            vreg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlsiVreg64Rm64",
+        mnemonic: "blsi",
+        prefix: None,
+        operands: &[OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsiVreg64Rm64 {
+            vreg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlsmskVreg32Rm32",
+        mnemonic: "blsmsk",
+        prefix: None,
+        operands: &[OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsmskVreg32Rm32 {
+            vreg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlsmskVreg64Rm64",
+        mnemonic: "blsmsk",
+        prefix: None,
+        operands: &[OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsmskVreg64Rm64 {
+            vreg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlsrVreg32Rm32",
+        mnemonic: "blsr",
+        prefix: None,
+        operands: &[OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsrVreg32Rm32 {
+            vreg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlsrVreg64Rm64",
+        mnemonic: "blsr",
+        prefix: None,
+        operands: &[OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlsrVreg64Rm64 {
+            vreg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BzhiReg32Rm32Vreg32",
+        mnemonic: "bzhi",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::VReg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BzhiReg32Rm32Vreg32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            vreg32: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "BzhiReg64Rm64Vreg64",
+        mnemonic: "bzhi",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::VReg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::BzhiReg64Rm64Vreg64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            vreg64: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "PdepReg32Vreg32Rm32",
+        mnemonic: "pdep",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PdepReg32Vreg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            vreg32: ops[1].to_gpr(),
+            rm32: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PdepReg64Vreg64Rm64",
+        mnemonic: "pdep",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PdepReg64Vreg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            vreg64: ops[1].to_gpr(),
+            rm64: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PextReg32Vreg32Rm32",
+        mnemonic: "pext",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PextReg32Vreg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            vreg32: ops[1].to_gpr(),
+            rm32: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PextReg64Vreg64Rm64",
+        mnemonic: "pext",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PextReg64Vreg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            vreg64: ops[1].to_gpr(),
+            rm64: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulxReg32Vreg32Rm32",
+        mnemonic: "mulx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::VReg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulxReg32Vreg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            vreg32: ops[1].to_gpr(),
+            rm32: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulxReg64Vreg64Rm64",
+        mnemonic: "mulx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::VReg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulxReg64Vreg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            vreg64: ops[1].to_gpr(),
+            rm64: ops[2].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "RorxReg32Rm32Imm8",
+        mnemonic: "rorx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorxReg32Rm32Imm8 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "RorxReg64Rm64Imm8",
+        mnemonic: "rorx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::RorxReg64Rm64Imm8 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "SarxReg32Rm32Vreg32",
+        mnemonic: "sarx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::VReg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarxReg32Rm32Vreg32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            vreg32: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "SarxReg64Rm64Vreg64",
+        mnemonic: "sarx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::VReg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::SarxReg64Rm64Vreg64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            vreg64: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "ShlxReg32Rm32Vreg32",
+        mnemonic: "shlx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::VReg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlxReg32Rm32Vreg32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            vreg32: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "ShlxReg64Rm64Vreg64",
+        mnemonic: "shlx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::VReg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShlxReg64Rm64Vreg64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            vreg64: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "ShrxReg32Rm32Vreg32",
+        mnemonic: "shrx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32, OperandKind::VReg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrxReg32Rm32Vreg32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+            vreg32: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "ShrxReg64Rm64Vreg64",
+        mnemonic: "shrx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64, OperandKind::VReg64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::ShrxReg64Rm64Vreg64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+            vreg64: ops[2].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "AdcxReg32Rm32",
+        mnemonic: "adcx",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcxReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdcxReg64Rm64",
+        mnemonic: "adcx",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdcxReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdoxReg32Rm32",
+        mnemonic: "adox",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdoxReg32Rm32 {
+            reg32: ops[0].to_gpr(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AdoxReg64Rm64",
+        mnemonic: "adox",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::AdoxReg64Rm64 {
+            reg64: ops[0].to_gpr(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovssXmmXmmm32",
+        mnemonic: "movss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovssXmmm32Xmm",
+        mnemonic: "movss",
+        prefix: None,
+        operands: &[OperandKind::XmmM32, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovssXmmm32Xmm {
+            xmmm32: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovsdXmmXmmm64",
+        mnemonic: "movsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovsdXmmm64Xmm",
+        mnemonic: "movsd",
+        prefix: None,
+        operands: &[OperandKind::XmmM64, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovsdXmmm64Xmm {
+            xmmm64: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "AddssXmmXmmm32",
+        mnemonic: "addss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddsdXmmXmmm64",
+        mnemonic: "addsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SubssXmmXmmm32",
+        mnemonic: "subss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SubsdXmmXmmm64",
+        mnemonic: "subsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::SubsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulssXmmXmmm32",
+        mnemonic: "mulss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulsdXmmXmmm64",
+        mnemonic: "mulsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DivssXmmXmmm32",
+        mnemonic: "divss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "DivsdXmmXmmm64",
+        mnemonic: "divsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::DivsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SqrtssXmmXmmm32",
+        mnemonic: "sqrtss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::SqrtssXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "SqrtsdXmmXmmm64",
+        mnemonic: "sqrtsd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::SqrtsdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "UcomissXmmXmmm32",
+        mnemonic: "ucomiss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::UcomissXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "UcomisdXmmXmmm64",
+        mnemonic: "ucomisd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::UcomisdXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cvtsi2sdXmmRm32",
+        mnemonic: "cvtsi2sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cvtsi2sdXmmRm32 {
+            xmm: ops[0].to_xmm(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cvtsi2sdXmmRm64",
+        mnemonic: "cvtsi2sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cvtsi2sdXmmRm64 {
+            xmm: ops[0].to_xmm(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cvttsd2siReg32Xmmm64",
+        mnemonic: "cvttsd2si",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::XmmM64],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cvttsd2siReg32Xmmm64 {
+            reg32: ops[0].to_gpr(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Cvttsd2siReg64Xmmm64",
+        mnemonic: "cvttsd2si",
+        prefix: None,
+        operands: &[OperandKind::Reg64, OperandKind::XmmM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::Cvttsd2siReg64Xmmm64 {
+            reg64: ops[0].to_gpr(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovdXmmRm32",
+        mnemonic: "movd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdXmmRm32 {
+            xmm: ops[0].to_xmm(),
+            rm32: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovdRm32Xmm",
+        mnemonic: "movd",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Xmm],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdRm32Xmm {
+            rm32: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovqXmmRm64",
+        mnemonic: "movq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM64],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovqXmmRm64 {
+            xmm: ops[0].to_xmm(),
+            rm64: ops[1].to_gpr_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovqRm64Xmm",
+        mnemonic: "movq",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Xmm],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovqRm64Xmm {
+            rm64: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovapsXmmXmmm128",
+        mnemonic: "movaps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovapsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovapsXmmm128Xmm",
+        mnemonic: "movaps",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovapsXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovupsXmmXmmm128",
+        mnemonic: "movups",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovupsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovupsXmmm128Xmm",
+        mnemonic: "movups",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovupsXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovdqaXmmXmmm128",
+        mnemonic: "movdqa",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdqaXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovdqaXmmm128Xmm",
+        mnemonic: "movdqa",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdqaXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "MovdquXmmXmmm128",
+        mnemonic: "movdqu",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdquXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MovdquXmmm128Xmm",
+        mnemonic: "movdqu",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MovdquXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "AddpsXmmXmmm128",
+        mnemonic: "addps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddpsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "AddpdXmmXmmm128",
+        mnemonic: "addpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::AddpdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulpsXmmXmmm128",
+        mnemonic: "mulps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulpsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "MulpdXmmXmmm128",
+        mnemonic: "mulpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::MulpdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PaddbXmmXmmm128",
+        mnemonic: "paddb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PaddbXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PaddwXmmXmmm128",
+        mnemonic: "paddw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PaddwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PadddXmmXmmm128",
+        mnemonic: "paddd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PadddXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PaddqXmmXmmm128",
+        mnemonic: "paddq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PaddqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PsubbXmmXmmm128",
+        mnemonic: "psubb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PsubbXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PsubwXmmXmmm128",
+        mnemonic: "psubw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PsubwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PsubdXmmXmmm128",
+        mnemonic: "psubd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PsubdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PsubqXmmXmmm128",
+        mnemonic: "psubq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PsubqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PandXmmXmmm128",
+        mnemonic: "pand",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PandXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PorXmmXmmm128",
+        mnemonic: "por",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PorXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PxorXmmXmmm128",
+        mnemonic: "pxor",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PxorXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpeqbXmmXmmm128",
+        mnemonic: "pcmpeqb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpeqbXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpeqwXmmXmmm128",
+        mnemonic: "pcmpeqw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpeqwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpeqdXmmXmmm128",
+        mnemonic: "pcmpeqd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpeqdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpeqqXmmXmmm128",
+        mnemonic: "pcmpeqq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpeqqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpgtbXmmXmmm128",
+        mnemonic: "pcmpgtb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpgtbXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpgtwXmmXmmm128",
+        mnemonic: "pcmpgtw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpgtwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpgtdXmmXmmm128",
+        mnemonic: "pcmpgtd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpgtdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PcmpgtqXmmXmmm128",
+        mnemonic: "pcmpgtq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PcmpgtqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PshufdXmmXmmm128Imm8",
+        mnemonic: "pshufd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128, OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::PshufdXmmXmmm128Imm8 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PshufbXmmXmmm128",
+        mnemonic: "pshufb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PshufbXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpcklbwXmmXmmm128",
+        mnemonic: "punpcklbw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpcklbwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpcklwdXmmXmmm128",
+        mnemonic: "punpcklwd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpcklwdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpckldqXmmXmmm128",
+        mnemonic: "punpckldq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpckldqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpcklqdqXmmXmmm128",
+        mnemonic: "punpcklqdq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpcklqdqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpckhbwXmmXmmm128",
+        mnemonic: "punpckhbw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpckhbwXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpckhwdXmmXmmm128",
+        mnemonic: "punpckhwd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpckhwdXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpckhdqXmmXmmm128",
+        mnemonic: "punpckhdq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpckhdqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PunpckhqdqXmmXmmm128",
+        mnemonic: "punpckhqdq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PunpckhqdqXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PmovmskbReg32Xmm",
+        mnemonic: "pmovmskb",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::Xmm],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::PmovmskbReg32Xmm {
+            reg32: ops[0].to_gpr(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "PinsrbXmmR32m8Imm8",
+        mnemonic: "pinsrb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::R32M8, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PinsrbXmmR32m8Imm8 {
+            xmm: ops[0].to_xmm(),
+            r32m8: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PinsrwXmmR32m16Imm8",
+        mnemonic: "pinsrw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::R32M16, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PinsrwXmmR32m16Imm8 {
+            xmm: ops[0].to_xmm(),
+            r32m16: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PinsrdXmmRm32Imm8",
+        mnemonic: "pinsrd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM32, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PinsrdXmmRm32Imm8 {
+            xmm: ops[0].to_xmm(),
+            rm32: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PinsrqXmmRm64Imm8",
+        mnemonic: "pinsrq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::RM64, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PinsrqXmmRm64Imm8 {
+            xmm: ops[0].to_xmm(),
+            rm64: ops[1].to_gpr_or_memory(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PextrbR32m8XmmImm8",
+        mnemonic: "pextrb",
+        prefix: None,
+        operands: &[OperandKind::R32M8, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PextrbR32m8XmmImm8 {
+            r32m8: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PextrwReg32XmmImm8",
+        mnemonic: "pextrw",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PextrwReg32XmmImm8 {
+            reg32: ops[0].to_gpr(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PextrwR32m16XmmImm8",
+        mnemonic: "pextrw",
+        prefix: None,
+        operands: &[OperandKind::R32M16, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PextrwR32m16XmmImm8 {
+            r32m16: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PextrdRm32XmmImm8",
+        mnemonic: "pextrd",
+        prefix: None,
+        operands: &[OperandKind::RM32, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PextrdRm32XmmImm8 {
+            rm32: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "PextrqRm64XmmImm8",
+        mnemonic: "pextrq",
+        prefix: None,
+        operands: &[OperandKind::RM64, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: Some(Size::Bit64),
+        unsigned_immediate: true,
+        build: |ops| Instruction::PextrqRm64XmmImm8 {
+            rm64: ops[0].to_gpr_or_memory(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "BlendvpsXmmXmmm128Xmm0",
+        mnemonic: "blendvps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128, OperandKind::XMM0],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlendvpsXmmXmmm128Xmm0 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "BlendvpdXmmXmmm128Xmm0",
+        mnemonic: "blendvpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128, OperandKind::XMM0],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::BlendvpdXmmXmmm128Xmm0 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PblendvbXmmXmmm128Xmm0",
+        mnemonic: "pblendvb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128, OperandKind::XMM0],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PblendvbXmmXmmm128Xmm0 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "PtestXmmXmmm128",
+        mnemonic: "ptest",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::PtestXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpsXmmVxmmXmmm128",
+        mnemonic: "vaddps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpsXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpsYmmVymmYmmm256",
+        mnemonic: "vaddps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpsYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vaddps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpsZmmMaskVzmmZmmrEr",
+        mnemonic: "vaddps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpsZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpdXmmVxmmXmmm128",
+        mnemonic: "vaddpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpdYmmVymmYmmm256",
+        mnemonic: "vaddpd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vaddpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddpdZmmMaskVzmmZmmrEr",
+        mnemonic: "vaddpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddpdZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpsXmmVxmmXmmm128",
+        mnemonic: "vmulps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpsXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpsYmmVymmYmmm256",
+        mnemonic: "vmulps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpsYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vmulps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpsZmmMaskVzmmZmmrEr",
+        mnemonic: "vmulps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpsZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpdXmmVxmmXmmm128",
+        mnemonic: "vmulpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpdYmmVymmYmmm256",
+        mnemonic: "vmulpd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vmulpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulpdZmmMaskVzmmZmmrEr",
+        mnemonic: "vmulpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulpdZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddbXmmVxmmXmmm128",
+        mnemonic: "vpaddb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddbXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddbYmmVymmYmmm256",
+        mnemonic: "vpaddb",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddbYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddwXmmVxmmXmmm128",
+        mnemonic: "vpaddw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddwXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddwYmmVymmYmmm256",
+        mnemonic: "vpaddw",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddwYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpadddXmmVxmmXmmm128",
+        mnemonic: "vpaddd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpadddXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpadddYmmVymmYmmm256",
+        mnemonic: "vpaddd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpadddYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpadddZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpaddd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpadddZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddqXmmVxmmXmmm128",
+        mnemonic: "vpaddq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddqXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddqYmmVymmYmmm256",
+        mnemonic: "vpaddq",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddqYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpaddqZmmMaskVzmmZmmm512b64",
+        mnemonic: "vpaddq",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpaddqZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpandXmmVxmmXmmm128",
+        mnemonic: "vpand",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpandXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpandYmmVymmYmmm256",
+        mnemonic: "vpand",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpandYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VporXmmVxmmXmmm128",
+        mnemonic: "vpor",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VporXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VporYmmVymmYmmm256",
+        mnemonic: "vpor",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VporYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpxorXmmVxmmXmmm128",
+        mnemonic: "vpxor",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpxorXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpxorYmmVymmYmmm256",
+        mnemonic: "vpxor",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpxorYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpshufbXmmVxmmXmmm128",
+        mnemonic: "vpshufb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpshufbXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpshufbYmmVymmYmmm256",
+        mnemonic: "vpshufb",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpshufbYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpermdYmmVymmYmmm256",
+        mnemonic: "vpermd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpermdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpermpsYmmVymmYmmm256",
+        mnemonic: "vpermps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpermpsYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastbXmmXmmm8",
+        mnemonic: "vpbroadcastb",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastbXmmXmmm8 {
+            xmm: ops[0].to_xmm(),
+            xmmm8: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastbYmmXmmm8",
+        mnemonic: "vpbroadcastb",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::XmmM8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastbYmmXmmm8 {
+            ymm: ops[0].to_ymm(),
+            xmmm8: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastwXmmXmmm16",
+        mnemonic: "vpbroadcastw",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM16],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastwXmmXmmm16 {
+            xmm: ops[0].to_xmm(),
+            xmmm16: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastwYmmXmmm16",
+        mnemonic: "vpbroadcastw",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::XmmM16],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastwYmmXmmm16 {
+            ymm: ops[0].to_ymm(),
+            xmmm16: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastdXmmXmmm32",
+        mnemonic: "vpbroadcastd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastdXmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastdYmmXmmm32",
+        mnemonic: "vpbroadcastd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastdYmmXmmm32 {
+            ymm: ops[0].to_ymm(),
+            xmmm32: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastqXmmXmmm64",
+        mnemonic: "vpbroadcastq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastqXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpbroadcastqYmmXmmm64",
+        mnemonic: "vpbroadcastq",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpbroadcastqYmmXmmm64 {
+            ymm: ops[0].to_ymm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovapsXmmXmmm128",
+        mnemonic: "vmovaps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovapsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovapsXmmm128Xmm",
+        mnemonic: "vmovaps",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovapsXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovapsYmmYmmm256",
+        mnemonic: "vmovaps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovapsYmmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            ymmm256: ops[1].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovapsYmmm256Ymm",
+        mnemonic: "vmovaps",
+        prefix: None,
+        operands: &[OperandKind::YmmM256, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovapsYmmm256Ymm {
+            ymmm256: ops[0].to_ymm_or_memory(),
+            ymm: ops[1].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsXmmXmmm128",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsXmmm128Xmm",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsYmmYmmm256",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsYmmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            ymmm256: ops[1].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsYmmm256Ymm",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::YmmM256, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsYmmm256Ymm {
+            ymmm256: ops[0].to_ymm_or_memory(),
+            ymm: ops[1].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsZmmMaskZmmm512",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmM512],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsZmmMaskZmmm512 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmm512: ops[2].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovupsZmmm512MergemaskZmm",
+        mnemonic: "vmovups",
+        prefix: None,
+        operands: &[OperandKind::ZmmM512, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovupsZmmm512MergemaskZmm {
+            zmmm512: ops[0].to_zmm_or_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdqaXmmXmmm128",
+        mnemonic: "vmovdqa",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdqaXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdqaXmmm128Xmm",
+        mnemonic: "vmovdqa",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdqaXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdqaYmmYmmm256",
+        mnemonic: "vmovdqa",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdqaYmmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            ymmm256: ops[1].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdqaYmmm256Ymm",
+        mnemonic: "vmovdqa",
+        prefix: None,
+        operands: &[OperandKind::YmmM256, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdqaYmmm256Ymm {
+            ymmm256: ops[0].to_ymm_or_memory(),
+            ymm: ops[1].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdquXmmXmmm128",
+        mnemonic: "vmovdqu",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdquXmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdquXmmm128Xmm",
+        mnemonic: "vmovdqu",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Xmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdquXmmm128Xmm {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdquYmmYmmm256",
+        mnemonic: "vmovdqu",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdquYmmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            ymmm256: ops[1].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmovdquYmmm256Ymm",
+        mnemonic: "vmovdqu",
+        prefix: None,
+        operands: &[OperandKind::YmmM256, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmovdquYmmm256Ymm {
+            ymmm256: ops[0].to_ymm_or_memory(),
+            ymm: ops[1].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "Vzeroupper",
+        mnemonic: "vzeroupper",
+        prefix: None,
+        operands: &[],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |_| Instruction::Vzeroupper,
+    },
+    InstructionForm {
+        name: "VsubpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vsubps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsubpsZmmMaskVzmmZmmrEr",
+        mnemonic: "vsubps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubpsZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VsubpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vsubpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsubpdZmmMaskVzmmZmmrEr",
+        mnemonic: "vsubpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubpdZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VdivpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vdivps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VdivpsZmmMaskVzmmZmmrEr",
+        mnemonic: "vdivps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivpsZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VdivpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vdivpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VdivpdZmmMaskVzmmZmmrEr",
+        mnemonic: "vdivpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivpdZmmMaskVzmmZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VminpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vminps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VminpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VminpsZmmMaskVzmmZmmrSae",
+        mnemonic: "vminps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VminpsZmmMaskVzmmZmmrSae {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VminpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vminpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VminpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VminpdZmmMaskVzmmZmmrSae",
+        mnemonic: "vminpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VminpdZmmMaskVzmmZmmrSae {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmaxpsZmmMaskVzmmZmmm512b32",
+        mnemonic: "vmaxps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmaxpsZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmaxpsZmmMaskVzmmZmmrSae",
+        mnemonic: "vmaxps",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmaxpsZmmMaskVzmmZmmrSae {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VmaxpdZmmMaskVzmmZmmm512b64",
+        mnemonic: "vmaxpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmaxpdZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmaxpdZmmMaskVzmmZmmrSae",
+        mnemonic: "vmaxpd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmaxpdZmmMaskVzmmZmmrSae {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VsqrtpsZmmMaskZmmm512b32",
+        mnemonic: "vsqrtps",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmM512B32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsqrtpsZmmMaskZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmm512b32: ops[2].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsqrtpsZmmMaskZmmrEr",
+        mnemonic: "vsqrtps",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmR, OperandKind::Er],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsqrtpsZmmMaskZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmr: ops[2].to_zmm(),
+            er: ops[3].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VsqrtpdZmmMaskZmmm512b64",
+        mnemonic: "vsqrtpd",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmM512B64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsqrtpdZmmMaskZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmm512b64: ops[2].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsqrtpdZmmMaskZmmrEr",
+        mnemonic: "vsqrtpd",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmR, OperandKind::Er],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsqrtpdZmmMaskZmmrEr {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmr: ops[2].to_zmm(),
+            er: ops[3].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VaddssXmmMaskVxmmXmmm32",
+        mnemonic: "vaddss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddssXmmMaskVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm32: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddssXmmMaskVxmmXmmrEr",
+        mnemonic: "vaddss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddssXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VaddsdXmmMaskVxmmXmmm64",
+        mnemonic: "vaddsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddsdXmmMaskVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm64: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VaddsdXmmMaskVxmmXmmrEr",
+        mnemonic: "vaddsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VaddsdXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VsubssXmmMaskVxmmXmmm32",
+        mnemonic: "vsubss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubssXmmMaskVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm32: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsubssXmmMaskVxmmXmmrEr",
+        mnemonic: "vsubss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubssXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VsubsdXmmMaskVxmmXmmm64",
+        mnemonic: "vsubsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubsdXmmMaskVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm64: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VsubsdXmmMaskVxmmXmmrEr",
+        mnemonic: "vsubsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VsubsdXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VmulssXmmMaskVxmmXmmm32",
+        mnemonic: "vmulss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulssXmmMaskVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm32: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulssXmmMaskVxmmXmmrEr",
+        mnemonic: "vmulss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulssXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VmulsdXmmMaskVxmmXmmm64",
+        mnemonic: "vmulsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulsdXmmMaskVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm64: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VmulsdXmmMaskVxmmXmmrEr",
+        mnemonic: "vmulsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VmulsdXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VdivssXmmMaskVxmmXmmm32",
+        mnemonic: "vdivss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivssXmmMaskVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm32: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VdivssXmmMaskVxmmXmmrEr",
+        mnemonic: "vdivss",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivssXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VdivsdXmmMaskVxmmXmmm64",
+        mnemonic: "vdivsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmM64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivsdXmmMaskVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmm64: ops[3].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VdivsdXmmMaskVxmmXmmrEr",
+        mnemonic: "vdivsd",
+        prefix: None,
+        operands: &[
+            OperandKind::Xmm,
+            OperandKind::Mask,
+            OperandKind::VXmm,
+            OperandKind::XmmR,
+            OperandKind::Er,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VdivsdXmmMaskVxmmXmmrEr {
+            xmm: ops[0].to_xmm(),
+            mask: ops[1].to_masking(),
+            vxmm: ops[2].to_xmm(),
+            xmmr: ops[3].to_xmm(),
+            er: ops[4].to_rounding(),
+        },
+    },
+    InstructionForm {
+        name: "VpsubdZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpsubd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpsubdZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpsubqZmmMaskVzmmZmmm512b64",
+        mnemonic: "vpsubq",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpsubqZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpmulldZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpmulld",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpmulldZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpanddZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpandd",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpanddZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpandqZmmMaskVzmmZmmm512b64",
+        mnemonic: "vpandq",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpandqZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpordZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpord",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpordZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VporqZmmMaskVzmmZmmm512b64",
+        mnemonic: "vporq",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VporqZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpxordZmmMaskVzmmZmmm512b32",
+        mnemonic: "vpxord",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpxordZmmMaskVzmmZmmm512b32 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpxorqZmmMaskVzmmZmmm512b64",
+        mnemonic: "vpxorq",
+        prefix: None,
+        operands: &[
+            OperandKind::Zmm,
+            OperandKind::Mask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpxorqZmmMaskVzmmZmmm512b64 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vmovdqu32ZmmMaskZmmm512",
+        mnemonic: "vmovdqu32",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmM512],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vmovdqu32ZmmMaskZmmm512 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmm512: ops[2].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vmovdqu32Zmmm512MergemaskZmm",
+        mnemonic: "vmovdqu32",
+        prefix: None,
+        operands: &[OperandKind::ZmmM512, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vmovdqu32Zmmm512MergemaskZmm {
+            zmmm512: ops[0].to_zmm_or_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "Vmovdqu64ZmmMaskZmmm512",
+        mnemonic: "vmovdqu64",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::Mask, OperandKind::ZmmM512],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vmovdqu64ZmmMaskZmmm512 {
+            zmm: ops[0].to_zmm(),
+            mask: ops[1].to_masking(),
+            zmmm512: ops[2].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vmovdqu64Zmmm512MergemaskZmm",
+        mnemonic: "vmovdqu64",
+        prefix: None,
+        operands: &[OperandKind::ZmmM512, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vmovdqu64Zmmm512MergemaskZmm {
+            zmmm512: ops[0].to_zmm_or_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VcmppsKMergemaskVzmmZmmm512b32Imm8",
+        mnemonic: "vcmpps",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VcmppsKMergemaskVzmmZmmm512b32Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VcmppsKMergemaskVzmmZmmrSaeImm8",
+        mnemonic: "vcmpps",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VcmppsKMergemaskVzmmZmmrSaeImm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            imm8: ops[5].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VcmppdKMergemaskVzmmZmmm512b64Imm8",
+        mnemonic: "vcmppd",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VcmppdKMergemaskVzmmZmmm512b64Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VcmppdKMergemaskVzmmZmmrSaeImm8",
+        mnemonic: "vcmppd",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmR,
+            OperandKind::Sae,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VcmppdKMergemaskVzmmZmmrSaeImm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmr: ops[3].to_zmm(),
+            imm8: ops[5].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpeqdKMergemaskVzmmZmmm512b32",
+        mnemonic: "vpcmpeqd",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpcmpeqdKMergemaskVzmmZmmm512b32 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpgtdKMergemaskVzmmZmmm512b32",
+        mnemonic: "vpcmpgtd",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpcmpgtdKMergemaskVzmmZmmm512b32 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpeqqKMergemaskVzmmZmmm512b64",
+        mnemonic: "vpcmpeqq",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpcmpeqqKMergemaskVzmmZmmm512b64 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpgtqKMergemaskVzmmZmmm512b64",
+        mnemonic: "vpcmpgtq",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+        ],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpcmpgtqKMergemaskVzmmZmmm512b64 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpdKMergemaskVzmmZmmm512b32Imm8",
+        mnemonic: "vpcmpd",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VpcmpdKMergemaskVzmmZmmm512b32Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpudKMergemaskVzmmZmmm512b32Imm8",
+        mnemonic: "vpcmpud",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B32,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VpcmpudKMergemaskVzmmZmmm512b32Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b32: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpqKMergemaskVzmmZmmm512b64Imm8",
+        mnemonic: "vpcmpq",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VpcmpqKMergemaskVzmmZmmm512b64Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VpcmpuqKMergemaskVzmmZmmm512b64Imm8",
+        mnemonic: "vpcmpuq",
+        prefix: None,
+        operands: &[
+            OperandKind::K,
+            OperandKind::MergeMask,
+            OperandKind::VZmm,
+            OperandKind::ZmmM512B64,
+            OperandKind::Imm8,
+        ],
+        operation_size: None,
+        unsigned_immediate: true,
+        build: |ops| Instruction::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 {
+            k: ops[0].to_opmask(),
+            mergemask: ops[1].to_masking(),
+            vzmm: ops[2].to_zmm(),
+            zmmm512b64: ops[3].to_zmm_or_memory(),
+            imm8: ops[4].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "KmovwKKm16",
+        mnemonic: "kmovw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::KM16],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KmovwKKm16 {
+            k: ops[0].to_opmask(),
+            km16: ops[1].to_opmask_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "KmovwMem16K",
+        mnemonic: "kmovw",
+        prefix: None,
+        operands: &[OperandKind::Mem16, OperandKind::K],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KmovwMem16K {
+            mem16: ops[0].to_memory(),
+            k: ops[1].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KmovwKReg32",
+        mnemonic: "kmovw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::Reg32],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::KmovwKReg32 {
+            k: ops[0].to_opmask(),
+            reg32: ops[1].to_gpr(),
+        },
+    },
+    InstructionForm {
+        name: "KmovwReg32Kr",
+        mnemonic: "kmovw",
+        prefix: None,
+        operands: &[OperandKind::Reg32, OperandKind::KR],
+        operation_size: Some(Size::Bit32),
+        unsigned_immediate: false,
+        build: |ops| Instruction::KmovwReg32Kr {
+            reg32: ops[0].to_gpr(),
+            kr: ops[1].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KandwKVkKr",
+        mnemonic: "kandw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::VK, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KandwKVkKr {
+            k: ops[0].to_opmask(),
+            vk: ops[1].to_opmask(),
+            kr: ops[2].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KandnwKVkKr",
+        mnemonic: "kandnw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::VK, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KandnwKVkKr {
+            k: ops[0].to_opmask(),
+            vk: ops[1].to_opmask(),
+            kr: ops[2].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KorwKVkKr",
+        mnemonic: "korw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::VK, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KorwKVkKr {
+            k: ops[0].to_opmask(),
+            vk: ops[1].to_opmask(),
+            kr: ops[2].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KxnorwKVkKr",
+        mnemonic: "kxnorw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::VK, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KxnorwKVkKr {
+            k: ops[0].to_opmask(),
+            vk: ops[1].to_opmask(),
+            kr: ops[2].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KxorwKVkKr",
+        mnemonic: "kxorw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::VK, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KxorwKVkKr {
+            k: ops[0].to_opmask(),
+            vk: ops[1].to_opmask(),
+            kr: ops[2].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KnotwKKr",
+        mnemonic: "knotw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KnotwKKr {
+            k: ops[0].to_opmask(),
+            kr: ops[1].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "KortestwKKr",
+        mnemonic: "kortestw",
+        prefix: None,
+        operands: &[OperandKind::K, OperandKind::KR],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::KortestwKKr {
+            k: ops[0].to_opmask(),
+            kr: ops[1].to_opmask(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132psXmmVxmmXmmm128",
+        mnemonic: "vfmadd132ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132psYmmVymmYmmm256",
+        mnemonic: "vfmadd132ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132pdXmmVxmmXmmm128",
+        mnemonic: "vfmadd132pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132pdYmmVymmYmmm256",
+        mnemonic: "vfmadd132pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132ssXmmVxmmXmmm32",
+        mnemonic: "vfmadd132ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd132sdXmmVxmmXmmm64",
+        mnemonic: "vfmadd132sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd132sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213psXmmVxmmXmmm128",
+        mnemonic: "vfmadd213ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213psYmmVymmYmmm256",
+        mnemonic: "vfmadd213ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213pdXmmVxmmXmmm128",
+        mnemonic: "vfmadd213pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213pdYmmVymmYmmm256",
+        mnemonic: "vfmadd213pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213ssXmmVxmmXmmm32",
+        mnemonic: "vfmadd213ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd213sdXmmVxmmXmmm64",
+        mnemonic: "vfmadd213sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd213sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231psXmmVxmmXmmm128",
+        mnemonic: "vfmadd231ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231psYmmVymmYmmm256",
+        mnemonic: "vfmadd231ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231pdXmmVxmmXmmm128",
+        mnemonic: "vfmadd231pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231pdYmmVymmYmmm256",
+        mnemonic: "vfmadd231pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231ssXmmVxmmXmmm32",
+        mnemonic: "vfmadd231ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmadd231sdXmmVxmmXmmm64",
+        mnemonic: "vfmadd231sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmadd231sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132psXmmVxmmXmmm128",
+        mnemonic: "vfmsub132ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132psYmmVymmYmmm256",
+        mnemonic: "vfmsub132ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132pdXmmVxmmXmmm128",
+        mnemonic: "vfmsub132pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132pdYmmVymmYmmm256",
+        mnemonic: "vfmsub132pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132ssXmmVxmmXmmm32",
+        mnemonic: "vfmsub132ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub132sdXmmVxmmXmmm64",
+        mnemonic: "vfmsub132sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub132sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213psXmmVxmmXmmm128",
+        mnemonic: "vfmsub213ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213psYmmVymmYmmm256",
+        mnemonic: "vfmsub213ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213pdXmmVxmmXmmm128",
+        mnemonic: "vfmsub213pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213pdYmmVymmYmmm256",
+        mnemonic: "vfmsub213pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213ssXmmVxmmXmmm32",
+        mnemonic: "vfmsub213ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub213sdXmmVxmmXmmm64",
+        mnemonic: "vfmsub213sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub213sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231psXmmVxmmXmmm128",
+        mnemonic: "vfmsub231ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231psYmmVymmYmmm256",
+        mnemonic: "vfmsub231ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231pdXmmVxmmXmmm128",
+        mnemonic: "vfmsub231pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231pdYmmVymmYmmm256",
+        mnemonic: "vfmsub231pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231ssXmmVxmmXmmm32",
+        mnemonic: "vfmsub231ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfmsub231sdXmmVxmmXmmm64",
+        mnemonic: "vfmsub231sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfmsub231sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132psXmmVxmmXmmm128",
+        mnemonic: "vfnmadd132ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132psYmmVymmYmmm256",
+        mnemonic: "vfnmadd132ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132pdXmmVxmmXmmm128",
+        mnemonic: "vfnmadd132pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132pdYmmVymmYmmm256",
+        mnemonic: "vfnmadd132pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132ssXmmVxmmXmmm32",
+        mnemonic: "vfnmadd132ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd132sdXmmVxmmXmmm64",
+        mnemonic: "vfnmadd132sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd132sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213psXmmVxmmXmmm128",
+        mnemonic: "vfnmadd213ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213psYmmVymmYmmm256",
+        mnemonic: "vfnmadd213ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213pdXmmVxmmXmmm128",
+        mnemonic: "vfnmadd213pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213pdYmmVymmYmmm256",
+        mnemonic: "vfnmadd213pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213ssXmmVxmmXmmm32",
+        mnemonic: "vfnmadd213ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd213sdXmmVxmmXmmm64",
+        mnemonic: "vfnmadd213sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd213sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231psXmmVxmmXmmm128",
+        mnemonic: "vfnmadd231ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231psYmmVymmYmmm256",
+        mnemonic: "vfnmadd231ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231pdXmmVxmmXmmm128",
+        mnemonic: "vfnmadd231pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231pdYmmVymmYmmm256",
+        mnemonic: "vfnmadd231pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231ssXmmVxmmXmmm32",
+        mnemonic: "vfnmadd231ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmadd231sdXmmVxmmXmmm64",
+        mnemonic: "vfnmadd231sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmadd231sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132psXmmVxmmXmmm128",
+        mnemonic: "vfnmsub132ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132psYmmVymmYmmm256",
+        mnemonic: "vfnmsub132ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132pdXmmVxmmXmmm128",
+        mnemonic: "vfnmsub132pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132pdYmmVymmYmmm256",
+        mnemonic: "vfnmsub132pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132ssXmmVxmmXmmm32",
+        mnemonic: "vfnmsub132ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub132sdXmmVxmmXmmm64",
+        mnemonic: "vfnmsub132sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub132sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213psXmmVxmmXmmm128",
+        mnemonic: "vfnmsub213ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213psYmmVymmYmmm256",
+        mnemonic: "vfnmsub213ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213pdXmmVxmmXmmm128",
+        mnemonic: "vfnmsub213pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213pdYmmVymmYmmm256",
+        mnemonic: "vfnmsub213pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213ssXmmVxmmXmmm32",
+        mnemonic: "vfnmsub213ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub213sdXmmVxmmXmmm64",
+        mnemonic: "vfnmsub213sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub213sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231psXmmVxmmXmmm128",
+        mnemonic: "vfnmsub231ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231psXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231psYmmVymmYmmm256",
+        mnemonic: "vfnmsub231ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231psYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231pdXmmVxmmXmmm128",
+        mnemonic: "vfnmsub231pd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231pdXmmVxmmXmmm128 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm128: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231pdYmmVymmYmmm256",
+        mnemonic: "vfnmsub231pd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::VYmm, OperandKind::YmmM256],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231pdYmmVymmYmmm256 {
+            ymm: ops[0].to_ymm(),
+            vymm: ops[1].to_ymm(),
+            ymmm256: ops[2].to_ymm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231ssXmmVxmmXmmm32",
+        mnemonic: "vfnmsub231ss",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM32],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231ssXmmVxmmXmmm32 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm32: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vfnmsub231sdXmmVxmmXmmm64",
+        mnemonic: "vfnmsub231sd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::VXmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vfnmsub231sdXmmVxmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            vxmm: ops[1].to_xmm(),
+            xmmm64: ops[2].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vcvtph2psXmmXmmm64",
+        mnemonic: "vcvtph2ps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::XmmM64],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vcvtph2psXmmXmmm64 {
+            xmm: ops[0].to_xmm(),
+            xmmm64: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vcvtph2psYmmXmmm128",
+        mnemonic: "vcvtph2ps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::XmmM128],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vcvtph2psYmmXmmm128 {
+            ymm: ops[0].to_ymm(),
+            xmmm128: ops[1].to_xmm_or_memory(),
+        },
+    },
+    InstructionForm {
+        name: "Vcvtps2phXmmm64XmmImm8",
+        mnemonic: "vcvtps2ph",
+        prefix: None,
+        operands: &[OperandKind::XmmM64, OperandKind::Xmm, OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vcvtps2phXmmm64XmmImm8 {
+            xmmm64: ops[0].to_xmm_or_memory(),
+            xmm: ops[1].to_xmm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "Vcvtps2phXmmm128YmmImm8",
+        mnemonic: "vcvtps2ph",
+        prefix: None,
+        operands: &[OperandKind::XmmM128, OperandKind::Ymm, OperandKind::Imm8],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::Vcvtps2phXmmm128YmmImm8 {
+            xmmm128: ops[0].to_xmm_or_memory(),
+            ymm: ops[1].to_ymm(),
+            imm8: ops[2].to_imm8(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherddXmmVm32xVxmm",
+        mnemonic: "vpgatherdd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm32X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherddXmmVm32xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherddYmmVm32yVymm",
+        mnemonic: "vpgatherdd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm32Y, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherddYmmVm32yVymm {
+            ymm: ops[0].to_ymm(),
+            vm32y: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherddZmmMergemaskVm32z",
+        mnemonic: "vpgatherdd",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm32Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherddZmmMergemaskVm32z {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm32z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherdqXmmVm32xVxmm",
+        mnemonic: "vpgatherdq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm32X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherdqXmmVm32xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherdqYmmVm32xVymm",
+        mnemonic: "vpgatherdq",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm32X, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherdqYmmVm32xVymm {
+            ymm: ops[0].to_ymm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherdqZmmMergemaskVm32y",
+        mnemonic: "vpgatherdq",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm32Y],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherdqZmmMergemaskVm32y {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm32y: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqdXmmVm64xVxmm",
+        mnemonic: "vpgatherqd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqdXmmVm64xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqdXmmVm64yVxmm",
+        mnemonic: "vpgatherqd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64Y, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqdXmmVm64yVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64y: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqdYmmMergemaskVm64z",
+        mnemonic: "vpgatherqd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::MergeMask, OperandKind::Vm64Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqdYmmMergemaskVm64z {
+            ymm: ops[0].to_ymm(),
+            mergemask: ops[1].to_masking(),
+            vm64z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqqXmmVm64xVxmm",
+        mnemonic: "vpgatherqq",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqqXmmVm64xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqqYmmVm64yVymm",
+        mnemonic: "vpgatherqq",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm64Y, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqqYmmVm64yVymm {
+            ymm: ops[0].to_ymm(),
+            vm64y: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VpgatherqqZmmMergemaskVm64z",
+        mnemonic: "vpgatherqq",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm64Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpgatherqqZmmMergemaskVm64z {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm64z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpsXmmVm32xVxmm",
+        mnemonic: "vgatherdps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm32X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpsXmmVm32xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpsYmmVm32yVymm",
+        mnemonic: "vgatherdps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm32Y, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpsYmmVm32yVymm {
+            ymm: ops[0].to_ymm(),
+            vm32y: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpsZmmMergemaskVm32z",
+        mnemonic: "vgatherdps",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm32Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpsZmmMergemaskVm32z {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm32z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpdXmmVm32xVxmm",
+        mnemonic: "vgatherdpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm32X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpdXmmVm32xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpdYmmVm32xVymm",
+        mnemonic: "vgatherdpd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm32X, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpdYmmVm32xVymm {
+            ymm: ops[0].to_ymm(),
+            vm32x: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherdpdZmmMergemaskVm32y",
+        mnemonic: "vgatherdpd",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm32Y],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherdpdZmmMergemaskVm32y {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm32y: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpsXmmVm64xVxmm",
+        mnemonic: "vgatherqps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpsXmmVm64xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpsXmmVm64yVxmm",
+        mnemonic: "vgatherqps",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64Y, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpsXmmVm64yVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64y: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpsYmmMergemaskVm64z",
+        mnemonic: "vgatherqps",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::MergeMask, OperandKind::Vm64Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpsYmmMergemaskVm64z {
+            ymm: ops[0].to_ymm(),
+            mergemask: ops[1].to_masking(),
+            vm64z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpdXmmVm64xVxmm",
+        mnemonic: "vgatherqpd",
+        prefix: None,
+        operands: &[OperandKind::Xmm, OperandKind::Vm64X, OperandKind::VXmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpdXmmVm64xVxmm {
+            xmm: ops[0].to_xmm(),
+            vm64x: ops[1].to_vsib_memory(),
+            vxmm: ops[2].to_xmm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpdYmmVm64yVymm",
+        mnemonic: "vgatherqpd",
+        prefix: None,
+        operands: &[OperandKind::Ymm, OperandKind::Vm64Y, OperandKind::VYmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpdYmmVm64yVymm {
+            ymm: ops[0].to_ymm(),
+            vm64y: ops[1].to_vsib_memory(),
+            vymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VgatherqpdZmmMergemaskVm64z",
+        mnemonic: "vgatherqpd",
+        prefix: None,
+        operands: &[OperandKind::Zmm, OperandKind::MergeMask, OperandKind::Vm64Z],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VgatherqpdZmmMergemaskVm64z {
+            zmm: ops[0].to_zmm(),
+            mergemask: ops[1].to_masking(),
+            vm64z: ops[2].to_vsib_memory(),
+        },
+    },
+    InstructionForm {
+        name: "VpscatterddVm32zMergemaskZmm",
+        mnemonic: "vpscatterdd",
+        prefix: None,
+        operands: &[OperandKind::Vm32Z, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpscatterddVm32zMergemaskZmm {
+            vm32z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpscatterdqVm32yMergemaskZmm",
+        mnemonic: "vpscatterdq",
+        prefix: None,
+        operands: &[OperandKind::Vm32Y, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpscatterdqVm32yMergemaskZmm {
+            vm32y: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VpscatterqdVm64zMergemaskYmm",
+        mnemonic: "vpscatterqd",
+        prefix: None,
+        operands: &[OperandKind::Vm64Z, OperandKind::MergeMask, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpscatterqdVm64zMergemaskYmm {
+            vm64z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            ymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VpscatterqqVm64zMergemaskZmm",
+        mnemonic: "vpscatterqq",
+        prefix: None,
+        operands: &[OperandKind::Vm64Z, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VpscatterqqVm64zMergemaskZmm {
+            vm64z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VscatterdpsVm32zMergemaskZmm",
+        mnemonic: "vscatterdps",
+        prefix: None,
+        operands: &[OperandKind::Vm32Z, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VscatterdpsVm32zMergemaskZmm {
+            vm32z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VscatterdpdVm32yMergemaskZmm",
+        mnemonic: "vscatterdpd",
+        prefix: None,
+        operands: &[OperandKind::Vm32Y, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VscatterdpdVm32yMergemaskZmm {
+            vm32y: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+    InstructionForm {
+        name: "VscatterqpsVm64zMergemaskYmm",
+        mnemonic: "vscatterqps",
+        prefix: None,
+        operands: &[OperandKind::Vm64Z, OperandKind::MergeMask, OperandKind::Ymm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VscatterqpsVm64zMergemaskYmm {
+            vm64z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            ymm: ops[2].to_ymm(),
+        },
+    },
+    InstructionForm {
+        name: "VscatterqpdVm64zMergemaskZmm",
+        mnemonic: "vscatterqpd",
+        prefix: None,
+        operands: &[OperandKind::Vm64Z, OperandKind::MergeMask, OperandKind::Zmm],
+        operation_size: None,
+        unsigned_immediate: false,
+        build: |ops| Instruction::VscatterqpdVm64zMergemaskZmm {
+            vm64z: ops[0].to_vsib_memory(),
+            mergemask: ops[1].to_masking(),
+            zmm: ops[2].to_zmm(),
+        },
+    },
+];
+
+impl InstructionForm {
+    /// Builds the [`Instruction`] out of `operands`, which have to match
+    /// [`InstructionForm::operands`] kinds.
+    ///
+    /// # Panics
+    ///
+    /// If the number or the kinds of `operands` do not match the form.
+    #[inline]
+    pub fn build(&self, operands: &[Operand]) -> Instruction {
+        assert!(operands.len() == self.operands.len(), "Invalid number of operands.");
+        (self.build)(operands)
+    }
+}
+
+impl Instruction {
+    /// Returns the [`InstructionForm`] the instruction was built from.
+    #[allow(clippy::too_many_lines)]
+    pub const fn form(self) -> &'static InstructionForm {
+        let idx = match self {
+            Self::Lock => 0,
+            Self::Nop => 1,
+            Self::Cpuid => 2,
+            Self::Sysenter => 3,
+            Self::Syscall => 4,
+            Self::Ret => 5,
+            Self::RetImm16 { .. } => 6,
+            Self::MovRm8Imm8 { .. } => 7,
+            Self::MovRm16Imm16 { .. } => 8,
+            Self::MovRm32Imm32 { .. } => 9,
+            Self::MovRm64Imm32 { .. } => 10,
+            Self::MovReg8Imm8 { .. } => 11,
+            Self::MovReg16Imm16 { .. } => 12,
+            Self::MovReg32Imm32 { .. } => 13,
+            Self::MovReg64Imm64 { .. } => 14,
+            Self::MovRm8Reg8 { .. } => 15,
+            Self::MovRm16Reg16 { .. } => 16,
+            Self::MovRm32Reg32 { .. } => 17,
+            Self::MovRm64Reg64 { .. } => 18,
+            Self::MovReg8Rm8 { .. } => 19,
+            Self::MovReg16Rm16 { .. } => 20,
+            Self::MovReg32Rm32 { .. } => 21,
+            Self::MovReg64Rm64 { .. } => 22,
+            Self::AddAlImm8 { .. } => 23,
+            Self::AddAxImm16 { .. } => 24,
+            Self::AddEaxImm32 { .. } => 25,
+            Self::AddRaxImm32 { .. } => 26,
+            Self::AddRm8Imm8 { .. } => 27,
+            Self::AddLockRm8Imm8 { .. } => 28,
+            Self::AddRm16Imm16 { .. } => 29,
+            Self::AddLockRm16Imm16 { .. } => 30,
+            Self::AddRm32Imm32 { .. } => 31,
+            Self::AddLockRm32Imm32 { .. } => 32,
+            Self::AddRm64Imm32 { .. } => 33,
+            Self::AddLockRm64Imm32 { .. } => 34,
+            Self::AddRm16Imm8 { .. } => 35,
+            Self::AddLockRm16Imm8 { .. } => 36,
+            Self::AddRm32Imm8 { .. } => 37,
+            Self::AddLockRm32Imm8 { .. } => 38,
+            Self::AddRm64Imm8 { .. } => 39,
+            Self::AddLockRm64Imm8 { .. } => 40,
+            Self::AddRm8Reg8 { .. } => 41,
+            Self::AddLockRm8Reg8 { .. } => 42,
+            Self::AddRm16Reg16 { .. } => 43,
+            Self::AddLockRm16Reg16 { .. } => 44,
+            Self::AddRm32Reg32 { .. } => 45,
+            Self::AddLockRm32Reg32 { .. } => 46,
+            Self::AddRm64Reg64 { .. } => 47,
+            Self::AddLockRm64Reg64 { .. } => 48,
+            Self::AddReg8Rm8 { .. } => 49,
+            Self::AddReg16Rm16 { .. } => 50,
+            Self::AddReg32Rm32 { .. } => 51,
+            Self::AddReg64Rm64 { .. } => 52,
+            Self::JmpImm8 { .. } => 53,
+            Self::JmpImm32 { .. } => 54,
+            Self::JmpRm64 { .. } => 55,
+            Self::JccAImm8 { .. } => 56,
+            Self::JccAeImm8 { .. } => 57,
+            Self::JccBImm8 { .. } => 58,
+            Self::JccBeImm8 { .. } => 59,
+            Self::JccEImm8 { .. } => 60,
+            Self::JccGImm8 { .. } => 61,
+            Self::JccGeImm8 { .. } => 62,
+            Self::JccLImm8 { .. } => 63,
+            Self::JccLeImm8 { .. } => 64,
+            Self::JccNeImm8 { .. } => 65,
+            Self::JccNoImm8 { .. } => 66,
+            Self::JccNpImm8 { .. } => 67,
+            Self::JccNsImm8 { .. } => 68,
+            Self::JccOImm8 { .. } => 69,
+            Self::JccPImm8 { .. } => 70,
+            Self::JccPeImm8 { .. } => 71,
+            Self::JccPoImm8 { .. } => 72,
+            Self::JccSImm8 { .. } => 73,
+            Self::JccCImm8 { .. } => 74,
+            Self::JccNcImm8 { .. } => 75,
+            Self::JccAImm32 { .. } => 76,
+            Self::JccAeImm32 { .. } => 77,
+            Self::JccBImm32 { .. } => 78,
+            Self::JccBeImm32 { .. } => 79,
+            Self::JccEImm32 { .. } => 80,
+            Self::JccGImm32 { .. } => 81,
+            Self::JccGeImm32 { .. } => 82,
+            Self::JccLImm32 { .. } => 83,
+            Self::JccLeImm32 { .. } => 84,
+            Self::JccNeImm32 { .. } => 85,
+            Self::JccNoImm32 { .. } => 86,
+            Self::JccNpImm32 { .. } => 87,
+            Self::JccNsImm32 { .. } => 88,
+            Self::JccOImm32 { .. } => 89,
+            Self::JccPImm32 { .. } => 90,
+            Self::JccPeImm32 { .. } => 91,
+            Self::JccPoImm32 { .. } => 92,
+            Self::JccSImm32 { .. } => 93,
+            Self::JccCImm32 { .. } => 94,
+            Self::JccNcImm32 { .. } => 95,
+            Self::CallImm32 { .. } => 96,
+            Self::CallRm64 { .. } => 97,
+            Self::CmpAlImm8 { .. } => 98,
+            Self::CmpAxImm16 { .. } => 99,
+            Self::CmpEaxImm32 { .. } => 100,
+            Self::CmpRaxImm32 { .. } => 101,
+            Self::CmpRm8Imm8 { .. } => 102,
+            Self::CmpRm16Imm16 { .. } => 103,
+            Self::CmpRm32Imm32 { .. } => 104,
+            Self::CmpRm64Imm32 { .. } => 105,
+            Self::CmpRm16Imm8 { .. } => 106,
+            Self::CmpRm32Imm8 { .. } => 107,
+            Self::CmpRm64Imm8 { .. } => 108,
+            Self::CmpRm8Reg8 { .. } => 109,
+            Self::CmpRm16Reg16 { .. } => 110,
+            Self::CmpRm32Reg32 { .. } => 111,
+            Self::CmpRm64Reg64 { .. } => 112,
+            Self::CmpReg8Rm8 { .. } => 113,
+            Self::CmpReg16Rm16 { .. } => 114,
+            Self::CmpReg32Rm32 { .. } => 115,
+            Self::CmpReg64Rm64 { .. } => 116,
+            Self::LeaReg16Mem64 { .. } => 117,
+            Self::LeaReg32Mem64 { .. } => 118,
+            Self::LeaReg64Mem64 { .. } => 119,
+            Self::PushRm64 { .. } => 120,
+            Self::PushReg64 { .. } => 121,
+            Self::PushImm8 { .. } => 122,
+            Self::PushImm16 { .. } => 123,
+            Self::PushImm32 { .. } => 124,
+            Self::PopRm64 { .. } => 125,
+            Self::PopReg64 { .. } => 126,
+            Self::SubAlImm8 { .. } => 127,
+            Self::SubAxImm16 { .. } => 128,
+            Self::SubEaxImm32 { .. } => 129,
+            Self::SubRaxImm32 { .. } => 130,
+            Self::SubRm8Imm8 { .. } => 131,
+            Self::SubLockRm8Imm8 { .. } => 132,
+            Self::SubRm16Imm16 { .. } => 133,
+            Self::SubLockRm16Imm16 { .. } => 134,
+            Self::SubRm32Imm32 { .. } => 135,
+            Self::SubLockRm32Imm32 { .. } => 136,
+            Self::SubRm64Imm32 { .. } => 137,
+            Self::SubLockRm64Imm32 { .. } => 138,
+            Self::SubRm16Imm8 { .. } => 139,
+            Self::SubLockRm16Imm8 { .. } => 140,
+            Self::SubRm32Imm8 { .. } => 141,
+            Self::SubLockRm32Imm8 { .. } => 142,
+            Self::SubRm64Imm8 { .. } => 143,
+            Self::SubLockRm64Imm8 { .. } => 144,
+            Self::SubRm8Reg8 { .. } => 145,
+            Self::SubLockRm8Reg8 { .. } => 146,
+            Self::SubRm16Reg16 { .. } => 147,
+            Self::SubLockRm16Reg16 { .. } => 148,
+            Self::SubRm32Reg32 { .. } => 149,
+            Self::SubLockRm32Reg32 { .. } => 150,
+            Self::SubRm64Reg64 { .. } => 151,
+            Self::SubLockRm64Reg64 { .. } => 152,
+            Self::SubReg8Rm8 { .. } => 153,
+            Self::SubReg16Rm16 { .. } => 154,
+            Self::SubReg32Rm32 { .. } => 155,
+            Self::SubReg64Rm64 { .. } => 156,
+            Self::XorAlImm8 { .. } => 157,
+            Self::XorAxImm16 { .. } => 158,
+            Self::XorEaxImm32 { .. } => 159,
+            Self::XorRaxImm32 { .. } => 160,
+            Self::XorRm8Imm8 { .. } => 161,
+            Self::XorLockRm8Imm8 { .. } => 162,
+            Self::XorRm16Imm16 { .. } => 163,
+            Self::XorLockRm16Imm16 { .. } => 164,
+            Self::XorRm32Imm32 { .. } => 165,
+            Self::XorLockRm32Imm32 { .. } => 166,
+            Self::XorRm64Imm32 { .. } => 167,
+            Self::XorLockRm64Imm32 { .. } => 168,
+            Self::XorRm16Imm8 { .. } => 169,
+            Self::XorLockRm16Imm8 { .. } => 170,
+            Self::XorRm32Imm8 { .. } => 171,
+            Self::XorLockRm32Imm8 { .. } => 172,
+            Self::XorRm64Imm8 { .. } => 173,
+            Self::XorLockRm64Imm8 { .. } => 174,
+            Self::XorRm8Reg8 { .. } => 175,
+            Self::XorLockRm8Reg8 { .. } => 176,
+            Self::XorRm16Reg16 { .. } => 177,
+            Self::XorLockRm16Reg16 { .. } => 178,
+            Self::XorRm32Reg32 { .. } => 179,
+            Self::XorLockRm32Reg32 { .. } => 180,
+            Self::XorRm64Reg64 { .. } => 181,
+            Self::XorLockRm64Reg64 { .. } => 182,
+            Self::XorReg8Rm8 { .. } => 183,
+            Self::XorReg16Rm16 { .. } => 184,
+            Self::XorReg32Rm32 { .. } => 185,
+            Self::XorReg64Rm64 { .. } => 186,
+            Self::IntImm8 { .. } => 187,
+            Self::Int1 => 188,
+            Self::Int3 => 189,
+            Self::RolRm8One { .. } => 190,
+            Self::RolRm16One { .. } => 191,
+            Self::RolRm32One { .. } => 192,
+            Self::RolRm64One { .. } => 193,
+            Self::RolRm8Imm8 { .. } => 194,
+            Self::RolRm16Imm8 { .. } => 195,
+            Self::RolRm32Imm8 { .. } => 196,
+            Self::RolRm64Imm8 { .. } => 197,
+            Self::RolRm8Cl { .. } => 198,
+            Self::RolRm16Cl { .. } => 199,
+            Self::RolRm32Cl { .. } => 200,
+            Self::RolRm64Cl { .. } => 201,
+            Self::RorRm8One { .. } => 202,
+            Self::RorRm16One { .. } => 203,
+            Self::RorRm32One { .. } => 204,
+            Self::RorRm64One { .. } => 205,
+            Self::RorRm8Imm8 { .. } => 206,
+            Self::RorRm16Imm8 { .. } => 207,
+            Self::RorRm32Imm8 { .. } => 208,
+            Self::RorRm64Imm8 { .. } => 209,
+            Self::RorRm8Cl { .. } => 210,
+            Self::RorRm16Cl { .. } => 211,
+            Self::RorRm32Cl { .. } => 212,
+            Self::RorRm64Cl { .. } => 213,
+            Self::RclRm8One { .. } => 214,
+            Self::RclRm16One { .. } => 215,
+            Self::RclRm32One { .. } => 216,
+            Self::RclRm64One { .. } => 217,
+            Self::RclRm8Imm8 { .. } => 218,
+            Self::RclRm16Imm8 { .. } => 219,
+            Self::RclRm32Imm8 { .. } => 220,
+            Self::RclRm64Imm8 { .. } => 221,
+            Self::RclRm8Cl { .. } => 222,
+            Self::RclRm16Cl { .. } => 223,
+            Self::RclRm32Cl { .. } => 224,
+            Self::RclRm64Cl { .. } => 225,
+            Self::RcrRm8One { .. } => 226,
+            Self::RcrRm16One { .. } => 227,
+            Self::RcrRm32One { .. } => 228,
+            Self::RcrRm64One { .. } => 229,
+            Self::RcrRm8Imm8 { .. } => 230,
+            Self::RcrRm16Imm8 { .. } => 231,
+            Self::RcrRm32Imm8 { .. } => 232,
+            Self::RcrRm64Imm8 { .. } => 233,
+            Self::RcrRm8Cl { .. } => 234,
+            Self::RcrRm16Cl { .. } => 235,
+            Self::RcrRm32Cl { .. } => 236,
+            Self::RcrRm64Cl { .. } => 237,
+            Self::ShlRm8One { .. } => 238,
+            Self::ShlRm16One { .. } => 239,
+            Self::ShlRm32One { .. } => 240,
+            Self::ShlRm64One { .. } => 241,
+            Self::ShlRm8Imm8 { .. } => 242,
+            Self::ShlRm16Imm8 { .. } => 243,
+            Self::ShlRm32Imm8 { .. } => 244,
+            Self::ShlRm64Imm8 { .. } => 245,
+            Self::ShlRm8Cl { .. } => 246,
+            Self::ShlRm16Cl { .. } => 247,
+            Self::ShlRm32Cl { .. } => 248,
+            Self::ShlRm64Cl { .. } => 249,
+            Self::ShrRm8One { .. } => 250,
+            Self::ShrRm16One { .. } => 251,
+            Self::ShrRm32One { .. } => 252,
+            Self::ShrRm64One { .. } => 253,
+            Self::ShrRm8Imm8 { .. } => 254,
+            Self::ShrRm16Imm8 { .. } => 255,
+            Self::ShrRm32Imm8 { .. } => 256,
+            Self::ShrRm64Imm8 { .. } => 257,
+            Self::ShrRm8Cl { .. } => 258,
+            Self::ShrRm16Cl { .. } => 259,
+            Self::ShrRm32Cl { .. } => 260,
+            Self::ShrRm64Cl { .. } => 261,
+            Self::SarRm8One { .. } => 262,
+            Self::SarRm16One { .. } => 263,
+            Self::SarRm32One { .. } => 264,
+            Self::SarRm64One { .. } => 265,
+            Self::SarRm8Imm8 { .. } => 266,
+            Self::SarRm16Imm8 { .. } => 267,
+            Self::SarRm32Imm8 { .. } => 268,
+            Self::SarRm64Imm8 { .. } => 269,
+            Self::SarRm8Cl { .. } => 270,
+            Self::SarRm16Cl { .. } => 271,
+            Self::SarRm32Cl { .. } => 272,
+            Self::SarRm64Cl { .. } => 273,
+            Self::MulRm8 { .. } => 274,
+            Self::MulRm16 { .. } => 275,
+            Self::MulRm32 { .. } => 276,
+            Self::MulRm64 { .. } => 277,
+            Self::ImulRm8 { .. } => 278,
+            Self::ImulRm16 { .. } => 279,
+            Self::ImulRm32 { .. } => 280,
+            Self::ImulRm64 { .. } => 281,
+            Self::ImulReg16Rm16 { .. } => 282,
+            Self::ImulReg32Rm32 { .. } => 283,
+            Self::ImulReg64Rm64 { .. } => 284,
+            Self::ImulReg16Rm16Imm8 { .. } => 285,
+            Self::ImulReg32Rm32Imm8 { .. } => 286,
+            Self::ImulReg64Rm64Imm8 { .. } => 287,
+            Self::ImulReg16Rm16Imm16 { .. } => 288,
+            Self::ImulReg32Rm32Imm32 { .. } => 289,
+            Self::ImulReg64Rm64Imm32 { .. } => 290,
+            Self::DivRm8 { .. } => 291,
+            Self::DivRm16 { .. } => 292,
+            Self::DivRm32 { .. } => 293,
+            Self::DivRm64 { .. } => 294,
+            Self::IdivRm8 { .. } => 295,
+            Self::IdivRm16 { .. } => 296,
+            Self::IdivRm32 { .. } => 297,
+            Self::IdivRm64 { .. } => 298,
+            Self::AndAlImm8 { .. } => 299,
+            Self::AndAxImm16 { .. } => 300,
+            Self::AndEaxImm32 { .. } => 301,
+            Self::AndRaxImm32 { .. } => 302,
+            Self::AndRm8Imm8 { .. } => 303,
+            Self::AndLockRm8Imm8 { .. } => 304,
+            Self::AndRm16Imm16 { .. } => 305,
+            Self::AndLockRm16Imm16 { .. } => 306,
+            Self::AndRm32Imm32 { .. } => 307,
+            Self::AndLockRm32Imm32 { .. } => 308,
+            Self::AndRm64Imm32 { .. } => 309,
+            Self::AndLockRm64Imm32 { .. } => 310,
+            Self::AndRm16Imm8 { .. } => 311,
+            Self::AndLockRm16Imm8 { .. } => 312,
+            Self::AndRm32Imm8 { .. } => 313,
+            Self::AndLockRm32Imm8 { .. } => 314,
+            Self::AndRm64Imm8 { .. } => 315,
+            Self::AndLockRm64Imm8 { .. } => 316,
+            Self::AndRm8Reg8 { .. } => 317,
+            Self::AndLockRm8Reg8 { .. } => 318,
+            Self::AndRm16Reg16 { .. } => 319,
+            Self::AndLockRm16Reg16 { .. } => 320,
+            Self::AndRm32Reg32 { .. } => 321,
+            Self::AndLockRm32Reg32 { .. } => 322,
+            Self::AndRm64Reg64 { .. } => 323,
+            Self::AndLockRm64Reg64 { .. } => 324,
+            Self::AndReg8Rm8 { .. } => 325,
+            Self::AndReg16Rm16 { .. } => 326,
+            Self::AndReg32Rm32 { .. } => 327,
+            Self::AndReg64Rm64 { .. } => 328,
+            Self::OrAlImm8 { .. } => 329,
+            Self::OrAxImm16 { .. } => 330,
+            Self::OrEaxImm32 { .. } => 331,
+            Self::OrRaxImm32 { .. } => 332,
+            Self::OrRm8Imm8 { .. } => 333,
+            Self::OrLockRm8Imm8 { .. } => 334,
+            Self::OrRm16Imm16 { .. } => 335,
+            Self::OrLockRm16Imm16 { .. } => 336,
+            Self::OrRm32Imm32 { .. } => 337,
+            Self::OrLockRm32Imm32 { .. } => 338,
+            Self::OrRm64Imm32 { .. } => 339,
+            Self::OrLockRm64Imm32 { .. } => 340,
+            Self::OrRm16Imm8 { .. } => 341,
+            Self::OrLockRm16Imm8 { .. } => 342,
+            Self::OrRm32Imm8 { .. } => 343,
+            Self::OrLockRm32Imm8 { .. } => 344,
+            Self::OrRm64Imm8 { .. } => 345,
+            Self::OrLockRm64Imm8 { .. } => 346,
+            Self::OrRm8Reg8 { .. } => 347,
+            Self::OrLockRm8Reg8 { .. } => 348,
+            Self::OrRm16Reg16 { .. } => 349,
+            Self::OrLockRm16Reg16 { .. } => 350,
+            Self::OrRm32Reg32 { .. } => 351,
+            Self::OrLockRm32Reg32 { .. } => 352,
+            Self::OrRm64Reg64 { .. } => 353,
+            Self::OrLockRm64Reg64 { .. } => 354,
+            Self::OrReg8Rm8 { .. } => 355,
+            Self::OrReg16Rm16 { .. } => 356,
+            Self::OrReg32Rm32 { .. } => 357,
+            Self::OrReg64Rm64 { .. } => 358,
+            Self::AdcAlImm8 { .. } => 359,
+            Self::AdcAxImm16 { .. } => 360,
+            Self::AdcEaxImm32 { .. } => 361,
+            Self::AdcRaxImm32 { .. } => 362,
+            Self::AdcRm8Imm8 { .. } => 363,
+            Self::AdcLockRm8Imm8 { .. } => 364,
+            Self::AdcRm16Imm16 { .. } => 365,
+            Self::AdcLockRm16Imm16 { .. } => 366,
+            Self::AdcRm32Imm32 { .. } => 367,
+            Self::AdcLockRm32Imm32 { .. } => 368,
+            Self::AdcRm64Imm32 { .. } => 369,
+            Self::AdcLockRm64Imm32 { .. } => 370,
+            Self::AdcRm16Imm8 { .. } => 371,
+            Self::AdcLockRm16Imm8 { .. } => 372,
+            Self::AdcRm32Imm8 { .. } => 373,
+            Self::AdcLockRm32Imm8 { .. } => 374,
+            Self::AdcRm64Imm8 { .. } => 375,
+            Self::AdcLockRm64Imm8 { .. } => 376,
+            Self::AdcRm8Reg8 { .. } => 377,
+            Self::AdcLockRm8Reg8 { .. } => 378,
+            Self::AdcRm16Reg16 { .. } => 379,
+            Self::AdcLockRm16Reg16 { .. } => 380,
+            Self::AdcRm32Reg32 { .. } => 381,
+            Self::AdcLockRm32Reg32 { .. } => 382,
+            Self::AdcRm64Reg64 { .. } => 383,
+            Self::AdcLockRm64Reg64 { .. } => 384,
+            Self::AdcReg8Rm8 { .. } => 385,
+            Self::AdcReg16Rm16 { .. } => 386,
+            Self::AdcReg32Rm32 { .. } => 387,
+            Self::AdcReg64Rm64 { .. } => 388,
+            Self::SbbAlImm8 { .. } => 389,
+            Self::SbbAxImm16 { .. } => 390,
+            Self::SbbEaxImm32 { .. } => 391,
+            Self::SbbRaxImm32 { .. } => 392,
+            Self::SbbRm8Imm8 { .. } => 393,
+            Self::SbbLockRm8Imm8 { .. } => 394,
+            Self::SbbRm16Imm16 { .. } => 395,
+            Self::SbbLockRm16Imm16 { .. } => 396,
+            Self::SbbRm32Imm32 { .. } => 397,
+            Self::SbbLockRm32Imm32 { .. } => 398,
+            Self::SbbRm64Imm32 { .. } => 399,
+            Self::SbbLockRm64Imm32 { .. } => 400,
+            Self::SbbRm16Imm8 { .. } => 401,
+            Self::SbbLockRm16Imm8 { .. } => 402,
+            Self::SbbRm32Imm8 { .. } => 403,
+            Self::SbbLockRm32Imm8 { .. } => 404,
+            Self::SbbRm64Imm8 { .. } => 405,
+            Self::SbbLockRm64Imm8 { .. } => 406,
+            Self::SbbRm8Reg8 { .. } => 407,
+            Self::SbbLockRm8Reg8 { .. } => 408,
+            Self::SbbRm16Reg16 { .. } => 409,
+            Self::SbbLockRm16Reg16 { .. } => 410,
+            Self::SbbRm32Reg32 { .. } => 411,
+            Self::SbbLockRm32Reg32 { .. } => 412,
+            Self::SbbRm64Reg64 { .. } => 413,
+            Self::SbbLockRm64Reg64 { .. } => 414,
+            Self::SbbReg8Rm8 { .. } => 415,
+            Self::SbbReg16Rm16 { .. } => 416,
+            Self::SbbReg32Rm32 { .. } => 417,
+            Self::SbbReg64Rm64 { .. } => 418,
+            Self::TestAlImm8 { .. } => 419,
+            Self::TestAxImm16 { .. } => 420,
+            Self::TestEaxImm32 { .. } => 421,
+            Self::TestRaxImm32 { .. } => 422,
+            Self::TestRm8Imm8 { .. } => 423,
+            Self::TestRm16Imm16 { .. } => 424,
+            Self::TestRm32Imm32 { .. } => 425,
+            Self::TestRm64Imm32 { .. } => 426,
+            Self::TestRm8Reg8 { .. } => 427,
+            Self::TestRm16Reg16 { .. } => 428,
+            Self::TestRm32Reg32 { .. } => 429,
+            Self::TestRm64Reg64 { .. } => 430,
+            Self::NotRm8 { .. } => 431,
+            Self::NotLockRm8 { .. } => 432,
+            Self::NotRm16 { .. } => 433,
+            Self::NotLockRm16 { .. } => 434,
+            Self::NotRm32 { .. } => 435,
+            Self::NotLockRm32 { .. } => 436,
+            Self::NotRm64 { .. } => 437,
+            Self::NotLockRm64 { .. } => 438,
+            Self::NegRm8 { .. } => 439,
+            Self::NegLockRm8 { .. } => 440,
+            Self::NegRm16 { .. } => 441,
+            Self::NegLockRm16 { .. } => 442,
+            Self::NegRm32 { .. } => 443,
+            Self::NegLockRm32 { .. } => 444,
+            Self::NegRm64 { .. } => 445,
+            Self::NegLockRm64 { .. } => 446,
+            Self::IncRm8 { .. } => 447,
+            Self::IncLockRm8 { .. } => 448,
+            Self::IncRm16 { .. } => 449,
+            Self::IncLockRm16 { .. } => 450,
+            Self::IncRm32 { .. } => 451,
+            Self::IncLockRm32 { .. } => 452,
+            Self::IncRm64 { .. } => 453,
+            Self::IncLockRm64 { .. } => 454,
+            Self::DecRm8 { .. } => 455,
+            Self::DecLockRm8 { .. } => 456,
+            Self::DecRm16 { .. } => 457,
+            Self::DecLockRm16 { .. } => 458,
+            Self::DecRm32 { .. } => 459,
+            Self::DecLockRm32 { .. } => 460,
+            Self::DecRm64 { .. } => 461,
+            Self::DecLockRm64 { .. } => 462,
+            Self::MovzxReg16Rm8 { .. } => 463,
+            Self::MovzxReg32Rm8 { .. } => 464,
+            Self::MovzxReg64Rm8 { .. } => 465,
+            Self::MovzxReg32Rm16 { .. } => 466,
+            Self::MovzxReg64Rm16 { .. } => 467,
+            Self::MovsxReg16Rm8 { .. } => 468,
+            Self::MovsxReg32Rm8 { .. } => 469,
+            Self::MovsxReg64Rm8 { .. } => 470,
+            Self::MovsxReg32Rm16 { .. } => 471,
+            Self::MovsxReg64Rm16 { .. } => 472,
+            Self::MovsxdReg64Rm32 { .. } => 473,
+            Self::SetccARm8 { .. } => 474,
+            Self::SetccAeRm8 { .. } => 475,
+            Self::SetccBRm8 { .. } => 476,
+            Self::SetccBeRm8 { .. } => 477,
+            Self::SetccERm8 { .. } => 478,
+            Self::SetccGRm8 { .. } => 479,
+            Self::SetccGeRm8 { .. } => 480,
+            Self::SetccLRm8 { .. } => 481,
+            Self::SetccLeRm8 { .. } => 482,
+            Self::SetccNeRm8 { .. } => 483,
+            Self::SetccNoRm8 { .. } => 484,
+            Self::SetccNpRm8 { .. } => 485,
+            Self::SetccNsRm8 { .. } => 486,
+            Self::SetccORm8 { .. } => 487,
+            Self::SetccPRm8 { .. } => 488,
+            Self::SetccPeRm8 { .. } => 489,
+            Self::SetccPoRm8 { .. } => 490,
+            Self::SetccSRm8 { .. } => 491,
+            Self::SetccCRm8 { .. } => 492,
+            Self::SetccNcRm8 { .. } => 493,
+            Self::CmovccAReg16Rm16 { .. } => 494,
+            Self::CmovccAeReg16Rm16 { .. } => 495,
+            Self::CmovccBReg16Rm16 { .. } => 496,
+            Self::CmovccBeReg16Rm16 { .. } => 497,
+            Self::CmovccEReg16Rm16 { .. } => 498,
+            Self::CmovccGReg16Rm16 { .. } => 499,
+            Self::CmovccGeReg16Rm16 { .. } => 500,
+            Self::CmovccLReg16Rm16 { .. } => 501,
+            Self::CmovccLeReg16Rm16 { .. } => 502,
+            Self::CmovccNeReg16Rm16 { .. } => 503,
+            Self::CmovccNoReg16Rm16 { .. } => 504,
+            Self::CmovccNpReg16Rm16 { .. } => 505,
+            Self::CmovccNsReg16Rm16 { .. } => 506,
+            Self::CmovccOReg16Rm16 { .. } => 507,
+            Self::CmovccPReg16Rm16 { .. } => 508,
+            Self::CmovccPeReg16Rm16 { .. } => 509,
+            Self::CmovccPoReg16Rm16 { .. } => 510,
+            Self::CmovccSReg16Rm16 { .. } => 511,
+            Self::CmovccCReg16Rm16 { .. } => 512,
+            Self::CmovccNcReg16Rm16 { .. } => 513,
+            Self::CmovccAReg32Rm32 { .. } => 514,
+            Self::CmovccAeReg32Rm32 { .. } => 515,
+            Self::CmovccBReg32Rm32 { .. } => 516,
+            Self::CmovccBeReg32Rm32 { .. } => 517,
+            Self::CmovccEReg32Rm32 { .. } => 518,
+            Self::CmovccGReg32Rm32 { .. } => 519,
+            Self::CmovccGeReg32Rm32 { .. } => 520,
+            Self::CmovccLReg32Rm32 { .. } => 521,
+            Self::CmovccLeReg32Rm32 { .. } => 522,
+            Self::CmovccNeReg32Rm32 { .. } => 523,
+            Self::CmovccNoReg32Rm32 { .. } => 524,
+            Self::CmovccNpReg32Rm32 { .. } => 525,
+            Self::CmovccNsReg32Rm32 { .. } => 526,
+            Self::CmovccOReg32Rm32 { .. } => 527,
+            Self::CmovccPReg32Rm32 { .. } => 528,
+            Self::CmovccPeReg32Rm32 { .. } => 529,
+            Self::CmovccPoReg32Rm32 { .. } => 530,
+            Self::CmovccSReg32Rm32 { .. } => 531,
+            Self::CmovccCReg32Rm32 { .. } => 532,
+            Self::CmovccNcReg32Rm32 { .. } => 533,
+            Self::CmovccAReg64Rm64 { .. } => 534,
+            Self::CmovccAeReg64Rm64 { .. } => 535,
+            Self::CmovccBReg64Rm64 { .. } => 536,
+            Self::CmovccBeReg64Rm64 { .. } => 537,
+            Self::CmovccEReg64Rm64 { .. } => 538,
+            Self::CmovccGReg64Rm64 { .. } => 539,
+            Self::CmovccGeReg64Rm64 { .. } => 540,
+            Self::CmovccLReg64Rm64 { .. } => 541,
+            Self::CmovccLeReg64Rm64 { .. } => 542,
+            Self::CmovccNeReg64Rm64 { .. } => 543,
+            Self::CmovccNoReg64Rm64 { .. } => 544,
+            Self::CmovccNpReg64Rm64 { .. } => 545,
+            Self::CmovccNsReg64Rm64 { .. } => 546,
+            Self::CmovccOReg64Rm64 { .. } => 547,
+            Self::CmovccPReg64Rm64 { .. } => 548,
+            Self::CmovccPeReg64Rm64 { .. } => 549,
+            Self::CmovccPoReg64Rm64 { .. } => 550,
+            Self::CmovccSReg64Rm64 { .. } => 551,
+            Self::CmovccCReg64Rm64 { .. } => 552,
+            Self::CmovccNcReg64Rm64 { .. } => 553,
+            Self::XchgRm8Reg8 { .. } => 554,
+            Self::XchgLockRm8Reg8 { .. } => 555,
+            Self::XchgRm16Reg16 { .. } => 556,
+            Self::XchgLockRm16Reg16 { .. } => 557,
+            Self::XchgRm32Reg32 { .. } => 558,
+            Self::XchgLockRm32Reg32 { .. } => 559,
+            Self::XchgRm64Reg64 { .. } => 560,
+            Self::XchgLockRm64Reg64 { .. } => 561,
+            Self::XchgReg8Rm8 { .. } => 562,
+            Self::XchgReg16Rm16 { .. } => 563,
+            Self::XchgReg32Rm32 { .. } => 564,
+            Self::XchgReg64Rm64 { .. } => 565,
+            Self::CmpxchgRm8Reg8 { .. } => 566,
+            Self::CmpxchgLockRm8Reg8 { .. } => 567,
+            Self::CmpxchgRm16Reg16 { .. } => 568,
+            Self::CmpxchgLockRm16Reg16 { .. } => 569,
+            Self::CmpxchgRm32Reg32 { .. } => 570,
+            Self::CmpxchgLockRm32Reg32 { .. } => 571,
+            Self::CmpxchgRm64Reg64 { .. } => 572,
+            Self::CmpxchgLockRm64Reg64 { .. } => 573,
+            Self::Cmpxchg8bMem64 { .. } => 574,
+            Self::Cmpxchg8bLockMem64 { .. } => 575,
+            Self::Cmpxchg16bMem128 { .. } => 576,
+            Self::Cmpxchg16bLockMem128 { .. } => 577,
+            Self::XaddRm8Reg8 { .. } => 578,
+            Self::XaddLockRm8Reg8 { .. } => 579,
+            Self::XaddRm16Reg16 { .. } => 580,
+            Self::XaddLockRm16Reg16 { .. } => 581,
+            Self::XaddRm32Reg32 { .. } => 582,
+            Self::XaddLockRm32Reg32 { .. } => 583,
+            Self::XaddRm64Reg64 { .. } => 584,
+            Self::XaddLockRm64Reg64 { .. } => 585,
+            Self::MovsB => 586,
+            Self::MovsW => 587,
+            Self::MovsD => 588,
+            Self::MovsQ => 589,
+            Self::MovsRepB => 590,
+            Self::MovsRepW => 591,
+            Self::MovsRepD => 592,
+            Self::MovsRepQ => 593,
+            Self::StosB => 594,
+            Self::StosW => 595,
+            Self::StosD => 596,
+            Self::StosQ => 597,
+            Self::StosRepB => 598,
+            Self::StosRepW => 599,
+            Self::StosRepD => 600,
+            Self::StosRepQ => 601,
+            Self::LodsB => 602,
+            Self::LodsW => 603,
+            Self::LodsD => 604,
+            Self::LodsQ => 605,
+            Self::LodsRepB => 606,
+            Self::LodsRepW => 607,
+            Self::LodsRepD => 608,
+            Self::LodsRepQ => 609,
+            Self::CmpsB => 610,
+            Self::CmpsW => 611,
+            Self::CmpsD => 612,
+            Self::CmpsQ => 613,
+            Self::CmpsRepeB => 614,
+            Self::CmpsRepeW => 615,
+            Self::CmpsRepeD => 616,
+            Self::CmpsRepeQ => 617,
+            Self::CmpsRepneB => 618,
+            Self::CmpsRepneW => 619,
+            Self::CmpsRepneD => 620,
+            Self::CmpsRepneQ => 621,
+            Self::ScasB => 622,
+            Self::ScasW => 623,
+            Self::ScasD => 624,
+            Self::ScasQ => 625,
+            Self::ScasRepeB => 626,
+            Self::ScasRepeW => 627,
+            Self::ScasRepeD => 628,
+            Self::ScasRepeQ => 629,
+            Self::ScasRepneB => 630,
+            Self::ScasRepneW => 631,
+            Self::ScasRepneD => 632,
+            Self::ScasRepneQ => 633,
+            Self::BtRm16Reg16 { .. } => 634,
+            Self::BtRm32Reg32 { .. } => 635,
+            Self::BtRm64Reg64 { .. } => 636,
+            Self::BtRm16Imm8 { .. } => 637,
+            Self::BtRm32Imm8 { .. } => 638,
+            Self::BtRm64Imm8 { .. } => 639,
+            Self::BtsRm16Reg16 { .. } => 640,
+            Self::BtsLockRm16Reg16 { .. } => 641,
+            Self::BtsRm32Reg32 { .. } => 642,
+            Self::BtsLockRm32Reg32 { .. } => 643,
+            Self::BtsRm64Reg64 { .. } => 644,
+            Self::BtsLockRm64Reg64 { .. } => 645,
+            Self::BtsRm16Imm8 { .. } => 646,
+            Self::BtsLockRm16Imm8 { .. } => 647,
+            Self::BtsRm32Imm8 { .. } => 648,
+            Self::BtsLockRm32Imm8 { .. } => 649,
+            Self::BtsRm64Imm8 { .. } => 650,
+            Self::BtsLockRm64Imm8 { .. } => 651,
+            Self::BtrRm16Reg16 { .. } => 652,
+            Self::BtrLockRm16Reg16 { .. } => 653,
+            Self::BtrRm32Reg32 { .. } => 654,
+            Self::BtrLockRm32Reg32 { .. } => 655,
+            Self::BtrRm64Reg64 { .. } => 656,
+            Self::BtrLockRm64Reg64 { .. } => 657,
+            Self::BtrRm16Imm8 { .. } => 658,
+            Self::BtrLockRm16Imm8 { .. } => 659,
+            Self::BtrRm32Imm8 { .. } => 660,
+            Self::BtrLockRm32Imm8 { .. } => 661,
+            Self::BtrRm64Imm8 { .. } => 662,
+            Self::BtrLockRm64Imm8 { .. } => 663,
+            Self::BtcRm16Reg16 { .. } => 664,
+            Self::BtcLockRm16Reg16 { .. } => 665,
+            Self::BtcRm32Reg32 { .. } => 666,
+            Self::BtcLockRm32Reg32 { .. } => 667,
+            Self::BtcRm64Reg64 { .. } => 668,
+            Self::BtcLockRm64Reg64 { .. } => 669,
+            Self::BtcRm16Imm8 { .. } => 670,
+            Self::BtcLockRm16Imm8 { .. } => 671,
+            Self::BtcRm32Imm8 { .. } => 672,
+            Self::BtcLockRm32Imm8 { .. } => 673,
+            Self::BtcRm64Imm8 { .. } => 674,
+            Self::BtcLockRm64Imm8 { .. } => 675,
+            Self::BsfReg16Rm16 { .. } => 676,
+            Self::BsfReg32Rm32 { .. } => 677,
+            Self::BsfReg64Rm64 { .. } => 678,
+            Self::BsrReg16Rm16 { .. } => 679,
+            Self::BsrReg32Rm32 { .. } => 680,
+            Self::BsrReg64Rm64 { .. } => 681,
+            Self::BswapReg32 { .. } => 682,
+            Self::BswapReg64 { .. } => 683,
+            Self::PopcntReg16Rm16 { .. } => 684,
+            Self::PopcntReg32Rm32 { .. } => 685,
+            Self::PopcntReg64Rm64 { .. } => 686,
+            Self::LzcntReg16Rm16 { .. } => 687,
+            Self::LzcntReg32Rm32 { .. } => 688,
+            Self::LzcntReg64Rm64 { .. } => 689,
+            Self::TzcntReg16Rm16 { .. } => 690,
+            Self::TzcntReg32Rm32 { .. } => 691,
+            Self::TzcntReg64Rm64 { .. } => 692,
+            Self::AndnReg32Vreg32Rm32 { .. } => 693,
+            Self::AndnReg64Vreg64Rm64 { .. } => 694,
+            Self::BextrReg32Rm32Vreg32 { .. } => 695,
+            Self::BextrReg64Rm64Vreg64 { .. } => 696,
+            Self::BlsiVreg32Rm32 { .. } => 697,
+            Self::BlsiVreg64Rm64 { .. } => 698,
+            Self::BlsmskVreg32Rm32 { .. } => 699,
+            Self::BlsmskVreg64Rm64 { .. } => 700,
+            Self::BlsrVreg32Rm32 { .. } => 701,
+            Self::BlsrVreg64Rm64 { .. } => 702,
+            Self::BzhiReg32Rm32Vreg32 { .. } => 703,
+            Self::BzhiReg64Rm64Vreg64 { .. } => 704,
+            Self::PdepReg32Vreg32Rm32 { .. } => 705,
+            Self::PdepReg64Vreg64Rm64 { .. } => 706,
+            Self::PextReg32Vreg32Rm32 { .. } => 707,
+            Self::PextReg64Vreg64Rm64 { .. } => 708,
+            Self::MulxReg32Vreg32Rm32 { .. } => 709,
+            Self::MulxReg64Vreg64Rm64 { .. } => 710,
+            Self::RorxReg32Rm32Imm8 { .. } => 711,
+            Self::RorxReg64Rm64Imm8 { .. } => 712,
+            Self::SarxReg32Rm32Vreg32 { .. } => 713,
+            Self::SarxReg64Rm64Vreg64 { .. } => 714,
+            Self::ShlxReg32Rm32Vreg32 { .. } => 715,
+            Self::ShlxReg64Rm64Vreg64 { .. } => 716,
+            Self::ShrxReg32Rm32Vreg32 { .. } => 717,
+            Self::ShrxReg64Rm64Vreg64 { .. } => 718,
+            Self::AdcxReg32Rm32 { .. } => 719,
+            Self::AdcxReg64Rm64 { .. } => 720,
+            Self::AdoxReg32Rm32 { .. } => 721,
+            Self::AdoxReg64Rm64 { .. } => 722,
+            Self::MovssXmmXmmm32 { .. } => 723,
+            Self::MovssXmmm32Xmm { .. } => 724,
+            Self::MovsdXmmXmmm64 { .. } => 725,
+            Self::MovsdXmmm64Xmm { .. } => 726,
+            Self::AddssXmmXmmm32 { .. } => 727,
+            Self::AddsdXmmXmmm64 { .. } => 728,
+            Self::SubssXmmXmmm32 { .. } => 729,
+            Self::SubsdXmmXmmm64 { .. } => 730,
+            Self::MulssXmmXmmm32 { .. } => 731,
+            Self::MulsdXmmXmmm64 { .. } => 732,
+            Self::DivssXmmXmmm32 { .. } => 733,
+            Self::DivsdXmmXmmm64 { .. } => 734,
+            Self::SqrtssXmmXmmm32 { .. } => 735,
+            Self::SqrtsdXmmXmmm64 { .. } => 736,
+            Self::UcomissXmmXmmm32 { .. } => 737,
+            Self::UcomisdXmmXmmm64 { .. } => 738,
+            Self::Cvtsi2sdXmmRm32 { .. } => 739,
+            Self::Cvtsi2sdXmmRm64 { .. } => 740,
+            Self::Cvttsd2siReg32Xmmm64 { .. } => 741,
+            Self::Cvttsd2siReg64Xmmm64 { .. } => 742,
+            Self::MovdXmmRm32 { .. } => 743,
+            Self::MovdRm32Xmm { .. } => 744,
+            Self::MovqXmmRm64 { .. } => 745,
+            Self::MovqRm64Xmm { .. } => 746,
+            Self::MovapsXmmXmmm128 { .. } => 747,
+            Self::MovapsXmmm128Xmm { .. } => 748,
+            Self::MovupsXmmXmmm128 { .. } => 749,
+            Self::MovupsXmmm128Xmm { .. } => 750,
+            Self::MovdqaXmmXmmm128 { .. } => 751,
+            Self::MovdqaXmmm128Xmm { .. } => 752,
+            Self::MovdquXmmXmmm128 { .. } => 753,
+            Self::MovdquXmmm128Xmm { .. } => 754,
+            Self::AddpsXmmXmmm128 { .. } => 755,
+            Self::AddpdXmmXmmm128 { .. } => 756,
+            Self::MulpsXmmXmmm128 { .. } => 757,
+            Self::MulpdXmmXmmm128 { .. } => 758,
+            Self::PaddbXmmXmmm128 { .. } => 759,
+            Self::PaddwXmmXmmm128 { .. } => 760,
+            Self::PadddXmmXmmm128 { .. } => 761,
+            Self::PaddqXmmXmmm128 { .. } => 762,
+            Self::PsubbXmmXmmm128 { .. } => 763,
+            Self::PsubwXmmXmmm128 { .. } => 764,
+            Self::PsubdXmmXmmm128 { .. } => 765,
+            Self::PsubqXmmXmmm128 { .. } => 766,
+            Self::PandXmmXmmm128 { .. } => 767,
+            Self::PorXmmXmmm128 { .. } => 768,
+            Self::PxorXmmXmmm128 { .. } => 769,
+            Self::PcmpeqbXmmXmmm128 { .. } => 770,
+            Self::PcmpeqwXmmXmmm128 { .. } => 771,
+            Self::PcmpeqdXmmXmmm128 { .. } => 772,
+            Self::PcmpeqqXmmXmmm128 { .. } => 773,
+            Self::PcmpgtbXmmXmmm128 { .. } => 774,
+            Self::PcmpgtwXmmXmmm128 { .. } => 775,
+            Self::PcmpgtdXmmXmmm128 { .. } => 776,
+            Self::PcmpgtqXmmXmmm128 { .. } => 777,
+            Self::PshufdXmmXmmm128Imm8 { .. } => 778,
+            Self::PshufbXmmXmmm128 { .. } => 779,
+            Self::PunpcklbwXmmXmmm128 { .. } => 780,
+            Self::PunpcklwdXmmXmmm128 { .. } => 781,
+            Self::PunpckldqXmmXmmm128 { .. } => 782,
+            Self::PunpcklqdqXmmXmmm128 { .. } => 783,
+            Self::PunpckhbwXmmXmmm128 { .. } => 784,
+            Self::PunpckhwdXmmXmmm128 { .. } => 785,
+            Self::PunpckhdqXmmXmmm128 { .. } => 786,
+            Self::PunpckhqdqXmmXmmm128 { .. } => 787,
+            Self::PmovmskbReg32Xmm { .. } => 788,
+            Self::PinsrbXmmR32m8Imm8 { .. } => 789,
+            Self::PinsrwXmmR32m16Imm8 { .. } => 790,
+            Self::PinsrdXmmRm32Imm8 { .. } => 791,
+            Self::PinsrqXmmRm64Imm8 { .. } => 792,
+            Self::PextrbR32m8XmmImm8 { .. } => 793,
+            Self::PextrwReg32XmmImm8 { .. } => 794,
+            Self::PextrwR32m16XmmImm8 { .. } => 795,
+            Self::PextrdRm32XmmImm8 { .. } => 796,
+            Self::PextrqRm64XmmImm8 { .. } => 797,
+            Self::BlendvpsXmmXmmm128Xmm0 { .. } => 798,
+            Self::BlendvpdXmmXmmm128Xmm0 { .. } => 799,
+            Self::PblendvbXmmXmmm128Xmm0 { .. } => 800,
+            Self::PtestXmmXmmm128 { .. } => 801,
+            Self::VaddpsXmmVxmmXmmm128 { .. } => 802,
+            Self::VaddpsYmmVymmYmmm256 { .. } => 803,
+            Self::VaddpsZmmMaskVzmmZmmm512b32 { .. } => 804,
+            Self::VaddpsZmmMaskVzmmZmmrEr { .. } => 805,
+            Self::VaddpdXmmVxmmXmmm128 { .. } => 806,
+            Self::VaddpdYmmVymmYmmm256 { .. } => 807,
+            Self::VaddpdZmmMaskVzmmZmmm512b64 { .. } => 808,
+            Self::VaddpdZmmMaskVzmmZmmrEr { .. } => 809,
+            Self::VmulpsXmmVxmmXmmm128 { .. } => 810,
+            Self::VmulpsYmmVymmYmmm256 { .. } => 811,
+            Self::VmulpsZmmMaskVzmmZmmm512b32 { .. } => 812,
+            Self::VmulpsZmmMaskVzmmZmmrEr { .. } => 813,
+            Self::VmulpdXmmVxmmXmmm128 { .. } => 814,
+            Self::VmulpdYmmVymmYmmm256 { .. } => 815,
+            Self::VmulpdZmmMaskVzmmZmmm512b64 { .. } => 816,
+            Self::VmulpdZmmMaskVzmmZmmrEr { .. } => 817,
+            Self::VpaddbXmmVxmmXmmm128 { .. } => 818,
+            Self::VpaddbYmmVymmYmmm256 { .. } => 819,
+            Self::VpaddwXmmVxmmXmmm128 { .. } => 820,
+            Self::VpaddwYmmVymmYmmm256 { .. } => 821,
+            Self::VpadddXmmVxmmXmmm128 { .. } => 822,
+            Self::VpadddYmmVymmYmmm256 { .. } => 823,
+            Self::VpadddZmmMaskVzmmZmmm512b32 { .. } => 824,
+            Self::VpaddqXmmVxmmXmmm128 { .. } => 825,
+            Self::VpaddqYmmVymmYmmm256 { .. } => 826,
+            Self::VpaddqZmmMaskVzmmZmmm512b64 { .. } => 827,
+            Self::VpandXmmVxmmXmmm128 { .. } => 828,
+            Self::VpandYmmVymmYmmm256 { .. } => 829,
+            Self::VporXmmVxmmXmmm128 { .. } => 830,
+            Self::VporYmmVymmYmmm256 { .. } => 831,
+            Self::VpxorXmmVxmmXmmm128 { .. } => 832,
+            Self::VpxorYmmVymmYmmm256 { .. } => 833,
+            Self::VpshufbXmmVxmmXmmm128 { .. } => 834,
+            Self::VpshufbYmmVymmYmmm256 { .. } => 835,
+            Self::VpermdYmmVymmYmmm256 { .. } => 836,
+            Self::VpermpsYmmVymmYmmm256 { .. } => 837,
+            Self::VpbroadcastbXmmXmmm8 { .. } => 838,
+            Self::VpbroadcastbYmmXmmm8 { .. } => 839,
+            Self::VpbroadcastwXmmXmmm16 { .. } => 840,
+            Self::VpbroadcastwYmmXmmm16 { .. } => 841,
+            Self::VpbroadcastdXmmXmmm32 { .. } => 842,
+            Self::VpbroadcastdYmmXmmm32 { .. } => 843,
+            Self::VpbroadcastqXmmXmmm64 { .. } => 844,
+            Self::VpbroadcastqYmmXmmm64 { .. } => 845,
+            Self::VmovapsXmmXmmm128 { .. } => 846,
+            Self::VmovapsXmmm128Xmm { .. } => 847,
+            Self::VmovapsYmmYmmm256 { .. } => 848,
+            Self::VmovapsYmmm256Ymm { .. } => 849,
+            Self::VmovupsXmmXmmm128 { .. } => 850,
+            Self::VmovupsXmmm128Xmm { .. } => 851,
+            Self::VmovupsYmmYmmm256 { .. } => 852,
+            Self::VmovupsYmmm256Ymm { .. } => 853,
+            Self::VmovupsZmmMaskZmmm512 { .. } => 854,
+            Self::VmovupsZmmm512MergemaskZmm { .. } => 855,
+            Self::VmovdqaXmmXmmm128 { .. } => 856,
+            Self::VmovdqaXmmm128Xmm { .. } => 857,
+            Self::VmovdqaYmmYmmm256 { .. } => 858,
+            Self::VmovdqaYmmm256Ymm { .. } => 859,
+            Self::VmovdquXmmXmmm128 { .. } => 860,
+            Self::VmovdquXmmm128Xmm { .. } => 861,
+            Self::VmovdquYmmYmmm256 { .. } => 862,
+            Self::VmovdquYmmm256Ymm { .. } => 863,
+            Self::Vzeroupper => 864,
+            Self::VsubpsZmmMaskVzmmZmmm512b32 { .. } => 865,
+            Self::VsubpsZmmMaskVzmmZmmrEr { .. } => 866,
+            Self::VsubpdZmmMaskVzmmZmmm512b64 { .. } => 867,
+            Self::VsubpdZmmMaskVzmmZmmrEr { .. } => 868,
+            Self::VdivpsZmmMaskVzmmZmmm512b32 { .. } => 869,
+            Self::VdivpsZmmMaskVzmmZmmrEr { .. } => 870,
+            Self::VdivpdZmmMaskVzmmZmmm512b64 { .. } => 871,
+            Self::VdivpdZmmMaskVzmmZmmrEr { .. } => 872,
+            Self::VminpsZmmMaskVzmmZmmm512b32 { .. } => 873,
+            Self::VminpsZmmMaskVzmmZmmrSae { .. } => 874,
+            Self::VminpdZmmMaskVzmmZmmm512b64 { .. } => 875,
+            Self::VminpdZmmMaskVzmmZmmrSae { .. } => 876,
+            Self::VmaxpsZmmMaskVzmmZmmm512b32 { .. } => 877,
+            Self::VmaxpsZmmMaskVzmmZmmrSae { .. } => 878,
+            Self::VmaxpdZmmMaskVzmmZmmm512b64 { .. } => 879,
+            Self::VmaxpdZmmMaskVzmmZmmrSae { .. } => 880,
+            Self::VsqrtpsZmmMaskZmmm512b32 { .. } => 881,
+            Self::VsqrtpsZmmMaskZmmrEr { .. } => 882,
+            Self::VsqrtpdZmmMaskZmmm512b64 { .. } => 883,
+            Self::VsqrtpdZmmMaskZmmrEr { .. } => 884,
+            Self::VaddssXmmMaskVxmmXmmm32 { .. } => 885,
+            Self::VaddssXmmMaskVxmmXmmrEr { .. } => 886,
+            Self::VaddsdXmmMaskVxmmXmmm64 { .. } => 887,
+            Self::VaddsdXmmMaskVxmmXmmrEr { .. } => 888,
+            Self::VsubssXmmMaskVxmmXmmm32 { .. } => 889,
+            Self::VsubssXmmMaskVxmmXmmrEr { .. } => 890,
+            Self::VsubsdXmmMaskVxmmXmmm64 { .. } => 891,
+            Self::VsubsdXmmMaskVxmmXmmrEr { .. } => 892,
+            Self::VmulssXmmMaskVxmmXmmm32 { .. } => 893,
+            Self::VmulssXmmMaskVxmmXmmrEr { .. } => 894,
+            Self::VmulsdXmmMaskVxmmXmmm64 { .. } => 895,
+            Self::VmulsdXmmMaskVxmmXmmrEr { .. } => 896,
+            Self::VdivssXmmMaskVxmmXmmm32 { .. } => 897,
+            Self::VdivssXmmMaskVxmmXmmrEr { .. } => 898,
+            Self::VdivsdXmmMaskVxmmXmmm64 { .. } => 899,
+            Self::VdivsdXmmMaskVxmmXmmrEr { .. } => 900,
+            Self::VpsubdZmmMaskVzmmZmmm512b32 { .. } => 901,
+            Self::VpsubqZmmMaskVzmmZmmm512b64 { .. } => 902,
+            Self::VpmulldZmmMaskVzmmZmmm512b32 { .. } => 903,
+            Self::VpanddZmmMaskVzmmZmmm512b32 { .. } => 904,
+            Self::VpandqZmmMaskVzmmZmmm512b64 { .. } => 905,
+            Self::VpordZmmMaskVzmmZmmm512b32 { .. } => 906,
+            Self::VporqZmmMaskVzmmZmmm512b64 { .. } => 907,
+            Self::VpxordZmmMaskVzmmZmmm512b32 { .. } => 908,
+            Self::VpxorqZmmMaskVzmmZmmm512b64 { .. } => 909,
+            Self::Vmovdqu32ZmmMaskZmmm512 { .. } => 910,
+            Self::Vmovdqu32Zmmm512MergemaskZmm { .. } => 911,
+            Self::Vmovdqu64ZmmMaskZmmm512 { .. } => 912,
+            Self::Vmovdqu64Zmmm512MergemaskZmm { .. } => 913,
+            Self::VcmppsKMergemaskVzmmZmmm512b32Imm8 { .. } => 914,
+            Self::VcmppsKMergemaskVzmmZmmrSaeImm8 { .. } => 915,
+            Self::VcmppdKMergemaskVzmmZmmm512b64Imm8 { .. } => 916,
+            Self::VcmppdKMergemaskVzmmZmmrSaeImm8 { .. } => 917,
+            Self::VpcmpeqdKMergemaskVzmmZmmm512b32 { .. } => 918,
+            Self::VpcmpgtdKMergemaskVzmmZmmm512b32 { .. } => 919,
+            Self::VpcmpeqqKMergemaskVzmmZmmm512b64 { .. } => 920,
+            Self::VpcmpgtqKMergemaskVzmmZmmm512b64 { .. } => 921,
+            Self::VpcmpdKMergemaskVzmmZmmm512b32Imm8 { .. } => 922,
+            Self::VpcmpudKMergemaskVzmmZmmm512b32Imm8 { .. } => 923,
+            Self::VpcmpqKMergemaskVzmmZmmm512b64Imm8 { .. } => 924,
+            Self::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 { .. } => 925,
+            Self::KmovwKKm16 { .. } => 926,
+            Self::KmovwMem16K { .. } => 927,
+            Self::KmovwKReg32 { .. } => 928,
+            Self::KmovwReg32Kr { .. } => 929,
+            Self::KandwKVkKr { .. } => 930,
+            Self::KandnwKVkKr { .. } => 931,
+            Self::KorwKVkKr { .. } => 932,
+            Self::KxnorwKVkKr { .. } => 933,
+            Self::KxorwKVkKr { .. } => 934,
+            Self::KnotwKKr { .. } => 935,
+            Self::KortestwKKr { .. } => 936,
+            Self::Vfmadd132psXmmVxmmXmmm128 { .. } => 937,
+            Self::Vfmadd132psYmmVymmYmmm256 { .. } => 938,
+            Self::Vfmadd132pdXmmVxmmXmmm128 { .. } => 939,
+            Self::Vfmadd132pdYmmVymmYmmm256 { .. } => 940,
+            Self::Vfmadd132ssXmmVxmmXmmm32 { .. } => 941,
+            Self::Vfmadd132sdXmmVxmmXmmm64 { .. } => 942,
+            Self::Vfmadd213psXmmVxmmXmmm128 { .. } => 943,
+            Self::Vfmadd213psYmmVymmYmmm256 { .. } => 944,
+            Self::Vfmadd213pdXmmVxmmXmmm128 { .. } => 945,
+            Self::Vfmadd213pdYmmVymmYmmm256 { .. } => 946,
+            Self::Vfmadd213ssXmmVxmmXmmm32 { .. } => 947,
+            Self::Vfmadd213sdXmmVxmmXmmm64 { .. } => 948,
+            Self::Vfmadd231psXmmVxmmXmmm128 { .. } => 949,
+            Self::Vfmadd231psYmmVymmYmmm256 { .. } => 950,
+            Self::Vfmadd231pdXmmVxmmXmmm128 { .. } => 951,
+            Self::Vfmadd231pdYmmVymmYmmm256 { .. } => 952,
+            Self::Vfmadd231ssXmmVxmmXmmm32 { .. } => 953,
+            Self::Vfmadd231sdXmmVxmmXmmm64 { .. } => 954,
+            Self::Vfmsub132psXmmVxmmXmmm128 { .. } => 955,
+            Self::Vfmsub132psYmmVymmYmmm256 { .. } => 956,
+            Self::Vfmsub132pdXmmVxmmXmmm128 { .. } => 957,
+            Self::Vfmsub132pdYmmVymmYmmm256 { .. } => 958,
+            Self::Vfmsub132ssXmmVxmmXmmm32 { .. } => 959,
+            Self::Vfmsub132sdXmmVxmmXmmm64 { .. } => 960,
+            Self::Vfmsub213psXmmVxmmXmmm128 { .. } => 961,
+            Self::Vfmsub213psYmmVymmYmmm256 { .. } => 962,
+            Self::Vfmsub213pdXmmVxmmXmmm128 { .. } => 963,
+            Self::Vfmsub213pdYmmVymmYmmm256 { .. } => 964,
+            Self::Vfmsub213ssXmmVxmmXmmm32 { .. } => 965,
+            Self::Vfmsub213sdXmmVxmmXmmm64 { .. } => 966,
+            Self::Vfmsub231psXmmVxmmXmmm128 { .. } => 967,
+            Self::Vfmsub231psYmmVymmYmmm256 { .. } => 968,
+            Self::Vfmsub231pdXmmVxmmXmmm128 { .. } => 969,
+            Self::Vfmsub231pdYmmVymmYmmm256 { .. } => 970,
+            Self::Vfmsub231ssXmmVxmmXmmm32 { .. } => 971,
+            Self::Vfmsub231sdXmmVxmmXmmm64 { .. } => 972,
+            Self::Vfnmadd132psXmmVxmmXmmm128 { .. } => 973,
+            Self::Vfnmadd132psYmmVymmYmmm256 { .. } => 974,
+            Self::Vfnmadd132pdXmmVxmmXmmm128 { .. } => 975,
+            Self::Vfnmadd132pdYmmVymmYmmm256 { .. } => 976,
+            Self::Vfnmadd132ssXmmVxmmXmmm32 { .. } => 977,
+            Self::Vfnmadd132sdXmmVxmmXmmm64 { .. } => 978,
+            Self::Vfnmadd213psXmmVxmmXmmm128 { .. } => 979,
+            Self::Vfnmadd213psYmmVymmYmmm256 { .. } => 980,
+            Self::Vfnmadd213pdXmmVxmmXmmm128 { .. } => 981,
+            Self::Vfnmadd213pdYmmVymmYmmm256 { .. } => 982,
+            Self::Vfnmadd213ssXmmVxmmXmmm32 { .. } => 983,
+            Self::Vfnmadd213sdXmmVxmmXmmm64 { .. } => 984,
+            Self::Vfnmadd231psXmmVxmmXmmm128 { .. } => 985,
+            Self::Vfnmadd231psYmmVymmYmmm256 { .. } => 986,
+            Self::Vfnmadd231pdXmmVxmmXmmm128 { .. } => 987,
+            Self::Vfnmadd231pdYmmVymmYmmm256 { .. } => 988,
+            Self::Vfnmadd231ssXmmVxmmXmmm32 { .. } => 989,
+            Self::Vfnmadd231sdXmmVxmmXmmm64 { .. } => 990,
+            Self::Vfnmsub132psXmmVxmmXmmm128 { .. } => 991,
+            Self::Vfnmsub132psYmmVymmYmmm256 { .. } => 992,
+            Self::Vfnmsub132pdXmmVxmmXmmm128 { .. } => 993,
+            Self::Vfnmsub132pdYmmVymmYmmm256 { .. } => 994,
+            Self::Vfnmsub132ssXmmVxmmXmmm32 { .. } => 995,
+            Self::Vfnmsub132sdXmmVxmmXmmm64 { .. } => 996,
+            Self::Vfnmsub213psXmmVxmmXmmm128 { .. } => 997,
+            Self::Vfnmsub213psYmmVymmYmmm256 { .. } => 998,
+            Self::Vfnmsub213pdXmmVxmmXmmm128 { .. } => 999,
+            Self::Vfnmsub213pdYmmVymmYmmm256 { .. } => 1000,
+            Self::Vfnmsub213ssXmmVxmmXmmm32 { .. } => 1001,
+            Self::Vfnmsub213sdXmmVxmmXmmm64 { .. } => 1002,
+            Self::Vfnmsub231psXmmVxmmXmmm128 { .. } => 1003,
+            Self::Vfnmsub231psYmmVymmYmmm256 { .. } => 1004,
+            Self::Vfnmsub231pdXmmVxmmXmmm128 { .. } => 1005,
+            Self::Vfnmsub231pdYmmVymmYmmm256 { .. } => 1006,
+            Self::Vfnmsub231ssXmmVxmmXmmm32 { .. } => 1007,
+            Self::Vfnmsub231sdXmmVxmmXmmm64 { .. } => 1008,
+            Self::Vcvtph2psXmmXmmm64 { .. } => 1009,
+            Self::Vcvtph2psYmmXmmm128 { .. } => 1010,
+            Self::Vcvtps2phXmmm64XmmImm8 { .. } => 1011,
+            Self::Vcvtps2phXmmm128YmmImm8 { .. } => 1012,
+            Self::VpgatherddXmmVm32xVxmm { .. } => 1013,
+            Self::VpgatherddYmmVm32yVymm { .. } => 1014,
+            Self::VpgatherddZmmMergemaskVm32z { .. } => 1015,
+            Self::VpgatherdqXmmVm32xVxmm { .. } => 1016,
+            Self::VpgatherdqYmmVm32xVymm { .. } => 1017,
+            Self::VpgatherdqZmmMergemaskVm32y { .. } => 1018,
+            Self::VpgatherqdXmmVm64xVxmm { .. } => 1019,
+            Self::VpgatherqdXmmVm64yVxmm { .. } => 1020,
+            Self::VpgatherqdYmmMergemaskVm64z { .. } => 1021,
+            Self::VpgatherqqXmmVm64xVxmm { .. } => 1022,
+            Self::VpgatherqqYmmVm64yVymm { .. } => 1023,
+            Self::VpgatherqqZmmMergemaskVm64z { .. } => 1024,
+            Self::VgatherdpsXmmVm32xVxmm { .. } => 1025,
+            Self::VgatherdpsYmmVm32yVymm { .. } => 1026,
+            Self::VgatherdpsZmmMergemaskVm32z { .. } => 1027,
+            Self::VgatherdpdXmmVm32xVxmm { .. } => 1028,
+            Self::VgatherdpdYmmVm32xVymm { .. } => 1029,
+            Self::VgatherdpdZmmMergemaskVm32y { .. } => 1030,
+            Self::VgatherqpsXmmVm64xVxmm { .. } => 1031,
+            Self::VgatherqpsXmmVm64yVxmm { .. } => 1032,
+            Self::VgatherqpsYmmMergemaskVm64z { .. } => 1033,
+            Self::VgatherqpdXmmVm64xVxmm { .. } => 1034,
+            Self::VgatherqpdYmmVm64yVymm { .. } => 1035,
+            Self::VgatherqpdZmmMergemaskVm64z { .. } => 1036,
+            Self::VpscatterddVm32zMergemaskZmm { .. } => 1037,
+            Self::VpscatterdqVm32yMergemaskZmm { .. } => 1038,
+            Self::VpscatterqdVm64zMergemaskYmm { .. } => 1039,
+            Self::VpscatterqqVm64zMergemaskZmm { .. } => 1040,
+            Self::VscatterdpsVm32zMergemaskZmm { .. } => 1041,
+            Self::VscatterdpdVm32yMergemaskZmm { .. } => 1042,
+            Self::VscatterqpsVm64zMergemaskYmm { .. } => 1043,
+            Self::VscatterqpdVm64zMergemaskZmm { .. } => 1044,
+        };
+        &INSTRUCTION_FORMS[idx]
+    }
+}
@@ -0,0 +1,7284 @@
+//! This module contains the decoders for the `X86_64` instruction set,
+//! i.e. the inverse of the [`encoders`][crate::encoders] module.
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+mod instruction;
+pub use instruction::*;
+
+mod instruction_form;
+pub use instruction_form::*;
+
+use crate::models::{DecodeError, Size};
+use crate::partial_decoders::{Decoder, const_try, evex, i, m, mi, mr, o, oi, rmi, vex, xmm, zo};
+
+/// Decodes a single instruction from the beginning of `bytes`. Returns the decoded
+/// [`Instruction`] together with the number of bytes it occupies.
+///
+/// Whenever multiple instructions share the same encoding (e.g. `jcc::encode_p_imm8`
+/// and `jcc::encode_pe_imm8`), the one described first in `x86.yaml` is returned.
+///
+/// # Errors
+///
+/// Returns [`DecodeError`] if `bytes` do not start with a supported instruction.
+#[allow(clippy::too_many_lines)]
+pub const fn decode(bytes: &[u8]) -> Result<(Instruction, usize), DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    match const_try!(decoder.opcode_byte()) {
+        0x00 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x00], Size::Bit8)) {
+                return Ok((Instruction::AddRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x00], Size::Bit8)) {
+                return Ok((Instruction::AddLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x00],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpshufbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x00],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpshufbYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x01 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit16)) {
+                return Ok((Instruction::AddRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit16)) {
+                return Ok((Instruction::AddLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit32)) {
+                return Ok((Instruction::AddRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit32)) {
+                return Ok((Instruction::AddLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit64)) {
+                return Ok((Instruction::AddRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x01], Size::Bit64)) {
+                return Ok((Instruction::AddLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x02 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x02], Size::Bit8)) {
+                return Ok((Instruction::AddReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x03 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x03], Size::Bit16)) {
+                return Ok((Instruction::AddReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x03], Size::Bit32)) {
+                return Ok((Instruction::AddReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x03], Size::Bit64)) {
+                return Ok((Instruction::AddReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x04 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x04])) {
+                return Ok((Instruction::AddAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x05 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x05])) {
+                return Ok((Instruction::AddAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x05])) {
+                return Ok((Instruction::AddEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x05])) {
+                return Ok((Instruction::AddRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x08 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x08], Size::Bit8)) {
+                return Ok((Instruction::OrRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x08], Size::Bit8)) {
+                return Ok((Instruction::OrLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x09 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit16)) {
+                return Ok((Instruction::OrRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit16)) {
+                return Ok((Instruction::OrLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit32)) {
+                return Ok((Instruction::OrRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit32)) {
+                return Ok((Instruction::OrLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit64)) {
+                return Ok((Instruction::OrRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x09], Size::Bit64)) {
+                return Ok((Instruction::OrLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x0A => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x0A], Size::Bit8)) {
+                return Ok((Instruction::OrReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x0B => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0B], Size::Bit16)) {
+                return Ok((Instruction::OrReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0B], Size::Bit32)) {
+                return Ok((Instruction::OrReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0B], Size::Bit64)) {
+                return Ok((Instruction::OrReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x0C => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x0C])) {
+                return Ok((Instruction::OrAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x0D => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x0D])) {
+                return Ok((Instruction::OrAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0D])) {
+                return Ok((Instruction::OrEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x0D])) {
+                return Ok((Instruction::OrRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x0F => {
+            if const_try!(zo::decode(&mut decoder, [0x0F, 0xA2])) {
+                return Ok((Instruction::Cpuid, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0x0F, 0x34])) {
+                return Ok((Instruction::Sysenter, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0x0F, 0x05])) {
+                return Ok((Instruction::Syscall, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x87])) {
+                return Ok((Instruction::JccAImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x83])) {
+                return Ok((Instruction::JccAeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x82])) {
+                return Ok((Instruction::JccBImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x86])) {
+                return Ok((Instruction::JccBeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x84])) {
+                return Ok((Instruction::JccEImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8F])) {
+                return Ok((Instruction::JccGImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8D])) {
+                return Ok((Instruction::JccGeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8C])) {
+                return Ok((Instruction::JccLImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8E])) {
+                return Ok((Instruction::JccLeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x85])) {
+                return Ok((Instruction::JccNeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x81])) {
+                return Ok((Instruction::JccNoImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8B])) {
+                return Ok((Instruction::JccNpImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x89])) {
+                return Ok((Instruction::JccNsImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x80])) {
+                return Ok((Instruction::JccOImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8A])) {
+                return Ok((Instruction::JccPImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8A])) {
+                return Ok((Instruction::JccPeImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x8B])) {
+                return Ok((Instruction::JccPoImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x88])) {
+                return Ok((Instruction::JccSImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x82])) {
+                return Ok((Instruction::JccCImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x0F, 0x83])) {
+                return Ok((Instruction::JccNcImm32 { imm32 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAF], Size::Bit16)) {
+                return Ok((Instruction::ImulReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAF], Size::Bit32)) {
+                return Ok((Instruction::ImulReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAF], Size::Bit64)) {
+                return Ok((Instruction::ImulReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm8, reg16)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xB6], Size::Bit16, Size::Bit8))
+            {
+                return Ok((Instruction::MovzxReg16Rm8 { reg16, rm8 }, decoder.length()));
+            }
+            if let Some((rm8, reg32)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xB6], Size::Bit32, Size::Bit8))
+            {
+                return Ok((Instruction::MovzxReg32Rm8 { reg32, rm8 }, decoder.length()));
+            }
+            if let Some((rm8, reg64)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xB6], Size::Bit64, Size::Bit8))
+            {
+                return Ok((Instruction::MovzxReg64Rm8 { reg64, rm8 }, decoder.length()));
+            }
+            if let Some((rm16, reg32)) =
+                const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xB7], Size::Bit32, Size::Bit16))
+            {
+                return Ok((Instruction::MovzxReg32Rm16 { reg32, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg64)) =
+                const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xB7], Size::Bit64, Size::Bit16))
+            {
+                return Ok((Instruction::MovzxReg64Rm16 { reg64, rm16 }, decoder.length()));
+            }
+            if let Some((rm8, reg16)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xBE], Size::Bit16, Size::Bit8))
+            {
+                return Ok((Instruction::MovsxReg16Rm8 { reg16, rm8 }, decoder.length()));
+            }
+            if let Some((rm8, reg32)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xBE], Size::Bit32, Size::Bit8))
+            {
+                return Ok((Instruction::MovsxReg32Rm8 { reg32, rm8 }, decoder.length()));
+            }
+            if let Some((rm8, reg64)) = const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xBE], Size::Bit64, Size::Bit8))
+            {
+                return Ok((Instruction::MovsxReg64Rm8 { reg64, rm8 }, decoder.length()));
+            }
+            if let Some((rm16, reg32)) =
+                const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xBF], Size::Bit32, Size::Bit16))
+            {
+                return Ok((Instruction::MovsxReg32Rm16 { reg32, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg64)) =
+                const_try!(mr::decode_mixed(&mut decoder, [0x0F, 0xBF], Size::Bit64, Size::Bit16))
+            {
+                return Ok((Instruction::MovsxReg64Rm16 { reg64, rm16 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x97],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccARm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x93],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccAeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x92],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccBRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x96],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccBeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x94],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccERm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9F],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccGRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9D],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccGeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9C],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccLRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9E],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccLeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x95],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccNeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x91],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccNoRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9B],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccNpRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x99],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccNsRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x90],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccORm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9A],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccPRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9A],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccPeRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x9B],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccPoRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x98],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccSRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x92],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccCRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x93],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SetccNcRm8 { rm8 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x47], Size::Bit16)) {
+                return Ok((Instruction::CmovccAReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit16)) {
+                return Ok((Instruction::CmovccAeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit16)) {
+                return Ok((Instruction::CmovccBReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x46], Size::Bit16)) {
+                return Ok((Instruction::CmovccBeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x44], Size::Bit16)) {
+                return Ok((Instruction::CmovccEReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4F], Size::Bit16)) {
+                return Ok((Instruction::CmovccGReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4D], Size::Bit16)) {
+                return Ok((Instruction::CmovccGeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4C], Size::Bit16)) {
+                return Ok((Instruction::CmovccLReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4E], Size::Bit16)) {
+                return Ok((Instruction::CmovccLeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x45], Size::Bit16)) {
+                return Ok((Instruction::CmovccNeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x41], Size::Bit16)) {
+                return Ok((Instruction::CmovccNoReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit16)) {
+                return Ok((Instruction::CmovccNpReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x49], Size::Bit16)) {
+                return Ok((Instruction::CmovccNsReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x40], Size::Bit16)) {
+                return Ok((Instruction::CmovccOReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit16)) {
+                return Ok((Instruction::CmovccPReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit16)) {
+                return Ok((Instruction::CmovccPeReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit16)) {
+                return Ok((Instruction::CmovccPoReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x48], Size::Bit16)) {
+                return Ok((Instruction::CmovccSReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit16)) {
+                return Ok((Instruction::CmovccCReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit16)) {
+                return Ok((Instruction::CmovccNcReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x47], Size::Bit32)) {
+                return Ok((Instruction::CmovccAReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit32)) {
+                return Ok((Instruction::CmovccAeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit32)) {
+                return Ok((Instruction::CmovccBReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x46], Size::Bit32)) {
+                return Ok((Instruction::CmovccBeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x44], Size::Bit32)) {
+                return Ok((Instruction::CmovccEReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4F], Size::Bit32)) {
+                return Ok((Instruction::CmovccGReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4D], Size::Bit32)) {
+                return Ok((Instruction::CmovccGeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4C], Size::Bit32)) {
+                return Ok((Instruction::CmovccLReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4E], Size::Bit32)) {
+                return Ok((Instruction::CmovccLeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x45], Size::Bit32)) {
+                return Ok((Instruction::CmovccNeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x41], Size::Bit32)) {
+                return Ok((Instruction::CmovccNoReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit32)) {
+                return Ok((Instruction::CmovccNpReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x49], Size::Bit32)) {
+                return Ok((Instruction::CmovccNsReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x40], Size::Bit32)) {
+                return Ok((Instruction::CmovccOReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit32)) {
+                return Ok((Instruction::CmovccPReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit32)) {
+                return Ok((Instruction::CmovccPeReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit32)) {
+                return Ok((Instruction::CmovccPoReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x48], Size::Bit32)) {
+                return Ok((Instruction::CmovccSReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit32)) {
+                return Ok((Instruction::CmovccCReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit32)) {
+                return Ok((Instruction::CmovccNcReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x47], Size::Bit64)) {
+                return Ok((Instruction::CmovccAReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit64)) {
+                return Ok((Instruction::CmovccAeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit64)) {
+                return Ok((Instruction::CmovccBReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x46], Size::Bit64)) {
+                return Ok((Instruction::CmovccBeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x44], Size::Bit64)) {
+                return Ok((Instruction::CmovccEReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4F], Size::Bit64)) {
+                return Ok((Instruction::CmovccGReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4D], Size::Bit64)) {
+                return Ok((Instruction::CmovccGeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4C], Size::Bit64)) {
+                return Ok((Instruction::CmovccLReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4E], Size::Bit64)) {
+                return Ok((Instruction::CmovccLeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x45], Size::Bit64)) {
+                return Ok((Instruction::CmovccNeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x41], Size::Bit64)) {
+                return Ok((Instruction::CmovccNoReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit64)) {
+                return Ok((Instruction::CmovccNpReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x49], Size::Bit64)) {
+                return Ok((Instruction::CmovccNsReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x40], Size::Bit64)) {
+                return Ok((Instruction::CmovccOReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit64)) {
+                return Ok((Instruction::CmovccPReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4A], Size::Bit64)) {
+                return Ok((Instruction::CmovccPeReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x4B], Size::Bit64)) {
+                return Ok((Instruction::CmovccPoReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x48], Size::Bit64)) {
+                return Ok((Instruction::CmovccSReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x42], Size::Bit64)) {
+                return Ok((Instruction::CmovccCReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x43], Size::Bit64)) {
+                return Ok((Instruction::CmovccNcReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB0], Size::Bit8)) {
+                return Ok((Instruction::CmpxchgRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB0], Size::Bit8)) {
+                return Ok((Instruction::CmpxchgLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit16)) {
+                return Ok((Instruction::CmpxchgRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit16)) {
+                return Ok((Instruction::CmpxchgLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit32)) {
+                return Ok((Instruction::CmpxchgRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit32)) {
+                return Ok((Instruction::CmpxchgLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit64)) {
+                return Ok((Instruction::CmpxchgRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB1], Size::Bit64)) {
+                return Ok((Instruction::CmpxchgLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(mem64) = const_try!(m::decode_memory(&mut decoder, [0x0F, 0xC7], 0x01, false)) {
+                return Ok((Instruction::Cmpxchg8bMem64 { mem64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(mem64) = const_try!(m::decode_memory(&mut decoder, [0x0F, 0xC7], 0x01, false)) {
+                return Ok((Instruction::Cmpxchg8bLockMem64 { mem64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(mem128) = const_try!(m::decode_memory(&mut decoder, [0x0F, 0xC7], 0x01, true)) {
+                return Ok((Instruction::Cmpxchg16bMem128 { mem128 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(mem128) = const_try!(m::decode_memory(&mut decoder, [0x0F, 0xC7], 0x01, true)) {
+                return Ok((Instruction::Cmpxchg16bLockMem128 { mem128 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC0], Size::Bit8)) {
+                return Ok((Instruction::XaddRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC0], Size::Bit8)) {
+                return Ok((Instruction::XaddLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit16)) {
+                return Ok((Instruction::XaddRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit16)) {
+                return Ok((Instruction::XaddLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit32)) {
+                return Ok((Instruction::XaddRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit32)) {
+                return Ok((Instruction::XaddLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit64)) {
+                return Ok((Instruction::XaddRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xC1], Size::Bit64)) {
+                return Ok((Instruction::XaddLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xA3], Size::Bit16)) {
+                return Ok((Instruction::BtRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xA3], Size::Bit32)) {
+                return Ok((Instruction::BtRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xA3], Size::Bit64)) {
+                return Ok((Instruction::BtRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x04)) {
+                return Ok((Instruction::BtRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x04)) {
+                return Ok((Instruction::BtRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x04)) {
+                return Ok((Instruction::BtRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit16)) {
+                return Ok((Instruction::BtsRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit16)) {
+                return Ok((Instruction::BtsLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit32)) {
+                return Ok((Instruction::BtsRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit32)) {
+                return Ok((Instruction::BtsLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit64)) {
+                return Ok((Instruction::BtsRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xAB], Size::Bit64)) {
+                return Ok((Instruction::BtsLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x05)) {
+                return Ok((Instruction::BtsLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit16)) {
+                return Ok((Instruction::BtrRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit16)) {
+                return Ok((Instruction::BtrLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit32)) {
+                return Ok((Instruction::BtrRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit32)) {
+                return Ok((Instruction::BtrLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit64)) {
+                return Ok((Instruction::BtrRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB3], Size::Bit64)) {
+                return Ok((Instruction::BtrLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x06)) {
+                return Ok((Instruction::BtrLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit16)) {
+                return Ok((Instruction::BtcRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit16)) {
+                return Ok((Instruction::BtcLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit32)) {
+                return Ok((Instruction::BtcRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit32)) {
+                return Ok((Instruction::BtcLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit64)) {
+                return Ok((Instruction::BtcRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBB], Size::Bit64)) {
+                return Ok((Instruction::BtcLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x0F, 0xBA], 0x07)) {
+                return Ok((Instruction::BtcLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit16)) {
+                return Ok((Instruction::BsfReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit32)) {
+                return Ok((Instruction::BsfReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit64)) {
+                return Ok((Instruction::BsfReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit16)) {
+                return Ok((Instruction::BsrReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit32)) {
+                return Ok((Instruction::BsrReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit64)) {
+                return Ok((Instruction::BsrReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some(reg32) = const_try!(o::decode(&mut decoder, [0x0F, 0xC8], Size::Bit32)) {
+                return Ok((Instruction::BswapReg32 { reg32 }, decoder.length()));
+            }
+            if let Some(reg64) = const_try!(o::decode_rexw(&mut decoder, [0x0F, 0xC8], Size::Bit64)) {
+                return Ok((Instruction::BswapReg64 { reg64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB8], Size::Bit16)) {
+                return Ok((Instruction::PopcntReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB8], Size::Bit32)) {
+                return Ok((Instruction::PopcntReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xB8], Size::Bit64)) {
+                return Ok((Instruction::PopcntReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit16)) {
+                return Ok((Instruction::LzcntReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit32)) {
+                return Ok((Instruction::LzcntReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBD], Size::Bit64)) {
+                return Ok((Instruction::LzcntReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit16)) {
+                return Ok((Instruction::TzcntReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit32)) {
+                return Ok((Instruction::TzcntReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0xBC], Size::Bit64)) {
+                return Ok((Instruction::TzcntReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x38, 0xF6], Size::Bit32)) {
+                return Ok((Instruction::AdcxReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x38, 0xF6], Size::Bit64)) {
+                return Ok((Instruction::AdcxReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x38, 0xF6], Size::Bit32)) {
+                return Ok((Instruction::AdoxReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x0F, 0x38, 0xF6], Size::Bit64)) {
+                return Ok((Instruction::AdoxReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x10], false)) {
+                return Ok((Instruction::MovssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x11], false)) {
+                return Ok((Instruction::MovssXmmm32Xmm { xmmm32, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x10], false)) {
+                return Ok((Instruction::MovsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x11], false)) {
+                return Ok((Instruction::MovsdXmmm64Xmm { xmmm64, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x58], false)) {
+                return Ok((Instruction::AddssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x58], false)) {
+                return Ok((Instruction::AddsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x5C], false)) {
+                return Ok((Instruction::SubssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x5C], false)) {
+                return Ok((Instruction::SubsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x59], false)) {
+                return Ok((Instruction::MulssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x59], false)) {
+                return Ok((Instruction::MulsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x5E], false)) {
+                return Ok((Instruction::DivssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x5E], false)) {
+                return Ok((Instruction::DivsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x51], false)) {
+                return Ok((Instruction::SqrtssXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x51], false)) {
+                return Ok((Instruction::SqrtsdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            if let Some((xmm, xmmm32)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x2E], false)) {
+                return Ok((Instruction::UcomissXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm64)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x2E], false)) {
+                return Ok((Instruction::UcomisdXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, rm32)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x2A],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::Cvtsi2sdXmmRm32 { xmm, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((xmm, rm64)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x2A],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::Cvtsi2sdXmmRm64 { xmm, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((reg32, xmmm64)) = const_try!(xmm::decode_gpr_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x2C],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::Cvttsd2siReg32Xmmm64 { reg32, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF2);
+            if let Some((reg64, xmmm64)) = const_try!(xmm::decode_gpr_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x2C],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::Cvttsd2siReg64Xmmm64 { reg64, xmmm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm32)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x6E],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::MovdXmmRm32 { xmm, rm32 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm32)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x7E],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::MovdRm32Xmm { rm32, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm64)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x6E],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::MovqXmmRm64 { xmm, rm64 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm64)) = const_try!(xmm::decode_xmm_gpr_or_memory(
+                &mut decoder,
+                [0x0F, 0x7E],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::MovqRm64Xmm { rm64, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x28], false)) {
+                return Ok((Instruction::MovapsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x29], false)) {
+                return Ok((Instruction::MovapsXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x10], false)) {
+                return Ok((Instruction::MovupsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x11], false)) {
+                return Ok((Instruction::MovupsXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6F], false)) {
+                return Ok((Instruction::MovdqaXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x7F], false)) {
+                return Ok((Instruction::MovdqaXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6F], false)) {
+                return Ok((Instruction::MovdquXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0xF3);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x7F], false)) {
+                return Ok((Instruction::MovdquXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x58], false)) {
+                return Ok((Instruction::AddpsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x58], false)) {
+                return Ok((Instruction::AddpdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x59], false)) {
+                return Ok((Instruction::MulpsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x59], false)) {
+                return Ok((Instruction::MulpdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xFC], false)) {
+                return Ok((Instruction::PaddbXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xFD], false)) {
+                return Ok((Instruction::PaddwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xFE], false)) {
+                return Ok((Instruction::PadddXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xD4], false)) {
+                return Ok((Instruction::PaddqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xF8], false)) {
+                return Ok((Instruction::PsubbXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xF9], false)) {
+                return Ok((Instruction::PsubwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xFA], false)) {
+                return Ok((Instruction::PsubdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xFB], false)) {
+                return Ok((Instruction::PsubqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xDB], false)) {
+                return Ok((Instruction::PandXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xEB], false)) {
+                return Ok((Instruction::PorXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0xEF], false)) {
+                return Ok((Instruction::PxorXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x74], false)) {
+                return Ok((Instruction::PcmpeqbXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x75], false)) {
+                return Ok((Instruction::PcmpeqwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x76], false)) {
+                return Ok((Instruction::PcmpeqdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x29], false))
+            {
+                return Ok((Instruction::PcmpeqqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x64], false)) {
+                return Ok((Instruction::PcmpgtbXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x65], false)) {
+                return Ok((Instruction::PcmpgtwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x66], false)) {
+                return Ok((Instruction::PcmpgtdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x37], false))
+            {
+                return Ok((Instruction::PcmpgtqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128, imm8)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory_imm8(&mut decoder, [0x0F, 0x70], false))
+            {
+                return Ok((
+                    Instruction::PshufdXmmXmmm128Imm8 { xmm, xmmm128, imm8 },
+                    decoder.length(),
+                ));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x00], false))
+            {
+                return Ok((Instruction::PshufbXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x60], false)) {
+                return Ok((Instruction::PunpcklbwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x61], false)) {
+                return Ok((Instruction::PunpcklwdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x62], false)) {
+                return Ok((Instruction::PunpckldqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6C], false)) {
+                return Ok((Instruction::PunpcklqdqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x68], false)) {
+                return Ok((Instruction::PunpckhbwXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x69], false)) {
+                return Ok((Instruction::PunpckhwdXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6A], false)) {
+                return Ok((Instruction::PunpckhdqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) = const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6D], false)) {
+                return Ok((Instruction::PunpckhqdqXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((reg32, xmm)) = const_try!(xmm::decode_gpr_xmm(&mut decoder, [0x0F, 0xD7], false, Size::Bit32)) {
+                return Ok((Instruction::PmovmskbReg32Xmm { reg32, xmm }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, r32m8, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x20],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PinsrbXmmR32m8Imm8 { xmm, r32m8, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, r32m16, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0xC4],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PinsrwXmmR32m16Imm8 { xmm, r32m16, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm32, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x22],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PinsrdXmmRm32Imm8 { xmm, rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm64, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x22],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::PinsrqXmmRm64Imm8 { xmm, rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, r32m8, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x14],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PextrbR32m8XmmImm8 { r32m8, xmm, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((reg32, xmm, imm8)) =
+                const_try!(xmm::decode_gpr_xmm_imm8(&mut decoder, [0x0F, 0xC5], false, Size::Bit32))
+            {
+                return Ok((Instruction::PextrwReg32XmmImm8 { reg32, xmm, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, r32m16, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x15],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PextrwR32m16XmmImm8 { r32m16, xmm, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm32, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x16],
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::PextrdRm32XmmImm8 { rm32, xmm, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, rm64, imm8)) = const_try!(xmm::decode_xmm_gpr_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x16],
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::PextrqRm64XmmImm8 { rm64, xmm, imm8 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x14], false))
+            {
+                return Ok((Instruction::BlendvpsXmmXmmm128Xmm0 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x15], false))
+            {
+                return Ok((Instruction::BlendvpdXmmXmmm128Xmm0 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x10], false))
+            {
+                return Ok((Instruction::PblendvbXmmXmmm128Xmm0 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+            decoder.set_mandatory_prefix(0x66);
+            if let Some((xmm, xmmm128)) =
+                const_try!(xmm::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x38, 0x17], false))
+            {
+                return Ok((Instruction::PtestXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            decoder.set_mandatory_prefix(0);
+        }
+        0x10 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x10], Size::Bit8)) {
+                return Ok((Instruction::AdcRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x10], Size::Bit8)) {
+                return Ok((Instruction::AdcLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x10], 0x00, false))
+            {
+                return Ok((Instruction::VmovupsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x10], 0x00, false))
+            {
+                return Ok((Instruction::VmovupsYmmYmmm256 { ymm, ymmm256 }, decoder.length()));
+            }
+            if let Some((zmm, mask, zmmm512)) = const_try!(evex::decode_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x10],
+                0x00,
+                false,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmovupsZmmMaskZmmm512 { zmm, mask, zmmm512 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x11 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit16)) {
+                return Ok((Instruction::AdcRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit16)) {
+                return Ok((Instruction::AdcLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit32)) {
+                return Ok((Instruction::AdcRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit32)) {
+                return Ok((Instruction::AdcLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit64)) {
+                return Ok((Instruction::AdcRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x11], Size::Bit64)) {
+                return Ok((Instruction::AdcLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x11], 0x00, false))
+            {
+                return Ok((Instruction::VmovupsXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x11], 0x00, false))
+            {
+                return Ok((Instruction::VmovupsYmmm256Ymm { ymmm256, ymm }, decoder.length()));
+            }
+            if let Some((zmm, mergemask, zmmm512)) =
+                const_try!(evex::decode_zmm_or_memory_zmm(&mut decoder, [0x0F, 0x11], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VmovupsZmmm512MergemaskZmm {
+                        zmmm512,
+                        mergemask,
+                        zmm,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x12 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x12], Size::Bit8)) {
+                return Ok((Instruction::AdcReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x13 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x13], Size::Bit16)) {
+                return Ok((Instruction::AdcReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x13], Size::Bit32)) {
+                return Ok((Instruction::AdcReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x13], Size::Bit64)) {
+                return Ok((Instruction::AdcReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x13],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::Vcvtph2psXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            if let Some((ymm, xmmm128)) = const_try!(vex::decode_ymm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x13],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::Vcvtph2psYmmXmmm128 { ymm, xmmm128 }, decoder.length()));
+            }
+        }
+        0x14 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x14])) {
+                return Ok((Instruction::AdcAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x15 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x15])) {
+                return Ok((Instruction::AdcAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x15])) {
+                return Ok((Instruction::AdcEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x15])) {
+                return Ok((Instruction::AdcRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x16 => {
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x16],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpermpsYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x18 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x18], Size::Bit8)) {
+                return Ok((Instruction::SbbRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x18], Size::Bit8)) {
+                return Ok((Instruction::SbbLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x19 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit16)) {
+                return Ok((Instruction::SbbRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit16)) {
+                return Ok((Instruction::SbbLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit32)) {
+                return Ok((Instruction::SbbRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit32)) {
+                return Ok((Instruction::SbbLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit64)) {
+                return Ok((Instruction::SbbRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x19], Size::Bit64)) {
+                return Ok((Instruction::SbbLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x1A => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x1A], Size::Bit8)) {
+                return Ok((Instruction::SbbReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x1B => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x1B], Size::Bit16)) {
+                return Ok((Instruction::SbbReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x1B], Size::Bit32)) {
+                return Ok((Instruction::SbbReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x1B], Size::Bit64)) {
+                return Ok((Instruction::SbbReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x1C => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x1C])) {
+                return Ok((Instruction::SbbAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x1D => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x1D])) {
+                return Ok((Instruction::SbbAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x1D])) {
+                return Ok((Instruction::SbbEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x1D])) {
+                return Ok((Instruction::SbbRaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm64, imm8)) = const_try!(vex::decode_xmm_xmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1D],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vcvtps2phXmmm64XmmImm8 { xmmm64, xmm, imm8 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, xmmm128, imm8)) = const_try!(vex::decode_ymm_xmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1D],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vcvtps2phXmmm128YmmImm8 { xmmm128, ymm, imm8 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x1E => {
+            if let Some((k, mergemask, vzmm, zmmm512b32, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1E],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpudKMergemaskVzmmZmmm512b32Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b32,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b64, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1E],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpuqKMergemaskVzmmZmmm512b64Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b64,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x1F => {
+            if let Some((k, mergemask, vzmm, zmmm512b32, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1F],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpdKMergemaskVzmmZmmm512b32Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b32,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b64, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0x3A, 0x1F],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpqKMergemaskVzmmZmmm512b64Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b64,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x20 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x20], Size::Bit8)) {
+                return Ok((Instruction::AndRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x20], Size::Bit8)) {
+                return Ok((Instruction::AndLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x21 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit16)) {
+                return Ok((Instruction::AndRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit16)) {
+                return Ok((Instruction::AndLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit32)) {
+                return Ok((Instruction::AndRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit32)) {
+                return Ok((Instruction::AndLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit64)) {
+                return Ok((Instruction::AndRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x21], Size::Bit64)) {
+                return Ok((Instruction::AndLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x22 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x22], Size::Bit8)) {
+                return Ok((Instruction::AndReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x23 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x23], Size::Bit16)) {
+                return Ok((Instruction::AndReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x23], Size::Bit32)) {
+                return Ok((Instruction::AndReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x23], Size::Bit64)) {
+                return Ok((Instruction::AndReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x24 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x24])) {
+                return Ok((Instruction::AndAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x25 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x25])) {
+                return Ok((Instruction::AndAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x25])) {
+                return Ok((Instruction::AndEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x25])) {
+                return Ok((Instruction::AndRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x28 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x28], Size::Bit8)) {
+                return Ok((Instruction::SubRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x28], Size::Bit8)) {
+                return Ok((Instruction::SubLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x28], 0x00, false))
+            {
+                return Ok((Instruction::VmovapsXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x28], 0x00, false))
+            {
+                return Ok((Instruction::VmovapsYmmYmmm256 { ymm, ymmm256 }, decoder.length()));
+            }
+        }
+        0x29 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit16)) {
+                return Ok((Instruction::SubRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit16)) {
+                return Ok((Instruction::SubLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit32)) {
+                return Ok((Instruction::SubRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit32)) {
+                return Ok((Instruction::SubLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit64)) {
+                return Ok((Instruction::SubRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x29], Size::Bit64)) {
+                return Ok((Instruction::SubLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x29], 0x00, false))
+            {
+                return Ok((Instruction::VmovapsXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x29], 0x00, false))
+            {
+                return Ok((Instruction::VmovapsYmmm256Ymm { ymmm256, ymm }, decoder.length()));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b64)) = const_try!(evex::decode_k_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x29],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpeqqKMergemaskVzmmZmmm512b64 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x2A => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x2A], Size::Bit8)) {
+                return Ok((Instruction::SubReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x2B => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x2B], Size::Bit16)) {
+                return Ok((Instruction::SubReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x2B], Size::Bit32)) {
+                return Ok((Instruction::SubReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x2B], Size::Bit64)) {
+                return Ok((Instruction::SubReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x2C => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x2C])) {
+                return Ok((Instruction::SubAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x2D => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x2D])) {
+                return Ok((Instruction::SubAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x2D])) {
+                return Ok((Instruction::SubEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x2D])) {
+                return Ok((Instruction::SubRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x30 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x30], Size::Bit8)) {
+                return Ok((Instruction::XorRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x30], Size::Bit8)) {
+                return Ok((Instruction::XorLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x31 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit16)) {
+                return Ok((Instruction::XorRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit16)) {
+                return Ok((Instruction::XorLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit32)) {
+                return Ok((Instruction::XorRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit32)) {
+                return Ok((Instruction::XorLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit64)) {
+                return Ok((Instruction::XorRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x31], Size::Bit64)) {
+                return Ok((Instruction::XorLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x32 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x32], Size::Bit8)) {
+                return Ok((Instruction::XorReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x33 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x33], Size::Bit16)) {
+                return Ok((Instruction::XorReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x33], Size::Bit32)) {
+                return Ok((Instruction::XorReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x33], Size::Bit64)) {
+                return Ok((Instruction::XorReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x34 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x34])) {
+                return Ok((Instruction::XorAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x35 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x35])) {
+                return Ok((Instruction::XorAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x35])) {
+                return Ok((Instruction::XorEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x35])) {
+                return Ok((Instruction::XorRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x36 => {
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x36],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpermdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x37 => {
+            if let Some((k, mergemask, vzmm, zmmm512b64)) = const_try!(evex::decode_k_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x37],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpgtqKMergemaskVzmmZmmm512b64 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x38 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x38], Size::Bit8)) {
+                return Ok((Instruction::CmpRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+        }
+        0x39 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x39], Size::Bit16)) {
+                return Ok((Instruction::CmpRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x39], Size::Bit32)) {
+                return Ok((Instruction::CmpRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x39], Size::Bit64)) {
+                return Ok((Instruction::CmpRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+        }
+        0x3A => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x3A], Size::Bit8)) {
+                return Ok((Instruction::CmpReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x3B => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x3B], Size::Bit16)) {
+                return Ok((Instruction::CmpReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x3B], Size::Bit32)) {
+                return Ok((Instruction::CmpReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x3B], Size::Bit64)) {
+                return Ok((Instruction::CmpReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x3C => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x3C])) {
+                return Ok((Instruction::CmpAlImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x3D => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x3D])) {
+                return Ok((Instruction::CmpAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x3D])) {
+                return Ok((Instruction::CmpEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0x3D])) {
+                return Ok((Instruction::CmpRaxImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x40 => {
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x40],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpmulldZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x41 => {
+            if let Some((k, vk, kr)) = const_try!(vex::decode_k_k_k(&mut decoder, [0x0F, 0x41], 0x00, false, true)) {
+                return Ok((Instruction::KandwKVkKr { k, vk, kr }, decoder.length()));
+            }
+        }
+        0x42 => {
+            if let Some((k, vk, kr)) = const_try!(vex::decode_k_k_k(&mut decoder, [0x0F, 0x42], 0x00, false, true)) {
+                return Ok((Instruction::KandnwKVkKr { k, vk, kr }, decoder.length()));
+            }
+        }
+        0x44 => {
+            if let Some((k, kr)) = const_try!(vex::decode_k_k(&mut decoder, [0x0F, 0x44], 0x00, false, false)) {
+                return Ok((Instruction::KnotwKKr { k, kr }, decoder.length()));
+            }
+        }
+        0x45 => {
+            if let Some((k, vk, kr)) = const_try!(vex::decode_k_k_k(&mut decoder, [0x0F, 0x45], 0x00, false, true)) {
+                return Ok((Instruction::KorwKVkKr { k, vk, kr }, decoder.length()));
+            }
+        }
+        0x46 => {
+            if let Some((k, vk, kr)) = const_try!(vex::decode_k_k_k(&mut decoder, [0x0F, 0x46], 0x00, false, true)) {
+                return Ok((Instruction::KxnorwKVkKr { k, vk, kr }, decoder.length()));
+            }
+        }
+        0x47 => {
+            if let Some((k, vk, kr)) = const_try!(vex::decode_k_k_k(&mut decoder, [0x0F, 0x47], 0x00, false, true)) {
+                return Ok((Instruction::KxorwKVkKr { k, vk, kr }, decoder.length()));
+            }
+        }
+        0x50 | 0x52..=0x57 => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x50], Size::Bit64)) {
+                return Ok((Instruction::PushReg64 { reg64 }, decoder.length()));
+            }
+        }
+        0x51 => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x50], Size::Bit64)) {
+                return Ok((Instruction::PushReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((zmm, mask, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x51],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VsqrtpsZmmMaskZmmm512b32 { zmm, mask, zmmm512b32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_er(&mut decoder, [0x0F, 0x51], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VsqrtpsZmmMaskZmmrEr { zmm, mask, zmmr, er },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x51],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VsqrtpdZmmMaskZmmm512b64 { zmm, mask, zmmm512b64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_er(&mut decoder, [0x0F, 0x51], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VsqrtpdZmmMaskZmmrEr { zmm, mask, zmmr, er },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x58 => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x00,
+                false
+            )) {
+                return Ok((
+                    Instruction::VaddpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x00,
+                false
+            )) {
+                return Ok((
+                    Instruction::VaddpsYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VaddpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x58], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VaddpsZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VaddpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VaddpdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VaddpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x58], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VaddpdZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x58],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastdXmmXmmm32 { xmm, xmmm32 }, decoder.length()));
+            }
+            if let Some((ymm, xmmm32)) = const_try!(vex::decode_ymm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x58],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastdYmmXmmm32 { ymm, xmmm32 }, decoder.length()));
+            }
+            if let Some((xmm, mask, vxmm, xmmm32)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0xF3,
+                false
+            )) {
+                return Ok((
+                    Instruction::VaddssXmmMaskVxmmXmmm32 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x58], 0xF3, false))
+            {
+                return Ok((
+                    Instruction::VaddssXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm64)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x58],
+                0xF2,
+                true
+            )) {
+                return Ok((
+                    Instruction::VaddsdXmmMaskVxmmXmmm64 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x58], 0xF2, true))
+            {
+                return Ok((
+                    Instruction::VaddsdXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x59 => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x00,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmulpsXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x00,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmulpsYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VmulpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x59], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VmulpsZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmulpdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmulpdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VmulpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x59], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VmulpdZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x59],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastqXmmXmmm64 { xmm, xmmm64 }, decoder.length()));
+            }
+            if let Some((ymm, xmmm64)) = const_try!(vex::decode_ymm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x59],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastqYmmXmmm64 { ymm, xmmm64 }, decoder.length()));
+            }
+            if let Some((xmm, mask, vxmm, xmmm32)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0xF3,
+                false
+            )) {
+                return Ok((
+                    Instruction::VmulssXmmMaskVxmmXmmm32 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x59], 0xF3, false))
+            {
+                return Ok((
+                    Instruction::VmulssXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm64)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x59],
+                0xF2,
+                true
+            )) {
+                return Ok((
+                    Instruction::VmulsdXmmMaskVxmmXmmm64 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x59], 0xF2, true))
+            {
+                return Ok((
+                    Instruction::VmulsdXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x5A..=0x5B => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+        }
+        0x5C => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5C],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VsubpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x5C], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VsubpsZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5C],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VsubpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x5C], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VsubpdZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm32)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5C],
+                0xF3,
+                false
+            )) {
+                return Ok((
+                    Instruction::VsubssXmmMaskVxmmXmmm32 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x5C], 0xF3, false))
+            {
+                return Ok((
+                    Instruction::VsubssXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm64)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5C],
+                0xF2,
+                true
+            )) {
+                return Ok((
+                    Instruction::VsubsdXmmMaskVxmmXmmm64 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x5C], 0xF2, true))
+            {
+                return Ok((
+                    Instruction::VsubsdXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x5D => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5D],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VminpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr)) =
+                const_try!(evex::decode_zmm_zmm_zmm_sae(&mut decoder, [0x0F, 0x5D], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VminpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5D],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VminpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr)) =
+                const_try!(evex::decode_zmm_zmm_zmm_sae(&mut decoder, [0x0F, 0x5D], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VminpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x5E => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5E],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VdivpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x5E], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VdivpsZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5E],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VdivpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr, er)) =
+                const_try!(evex::decode_zmm_zmm_zmm_er(&mut decoder, [0x0F, 0x5E], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VdivpdZmmMaskVzmmZmmrEr {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm32)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5E],
+                0xF3,
+                false
+            )) {
+                return Ok((
+                    Instruction::VdivssXmmMaskVxmmXmmm32 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x5E], 0xF3, false))
+            {
+                return Ok((
+                    Instruction::VdivssXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmm64)) = const_try!(evex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5E],
+                0xF2,
+                true
+            )) {
+                return Ok((
+                    Instruction::VdivsdXmmMaskVxmmXmmm64 {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmm64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, mask, vxmm, xmmr, er)) =
+                const_try!(evex::decode_xmm_xmm_xmm_er(&mut decoder, [0x0F, 0x5E], 0xF2, true))
+            {
+                return Ok((
+                    Instruction::VdivsdXmmMaskVxmmXmmrEr {
+                        xmm,
+                        mask,
+                        vxmm,
+                        xmmr,
+                        er,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x5F => {
+            if let Some(reg64) = const_try!(o::decode(&mut decoder, [0x58], Size::Bit64)) {
+                return Ok((Instruction::PopReg64 { reg64 }, decoder.length()));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5F],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VmaxpsZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr)) =
+                const_try!(evex::decode_zmm_zmm_zmm_sae(&mut decoder, [0x0F, 0x5F], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VmaxpsZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x5F],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VmaxpdZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmr)) =
+                const_try!(evex::decode_zmm_zmm_zmm_sae(&mut decoder, [0x0F, 0x5F], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VmaxpdZmmMaskVzmmZmmrSae { zmm, mask, vzmm, zmmr },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x63 => {
+            if let Some((rm32, reg64)) = const_try!(mr::decode_mixed(&mut decoder, [0x63], Size::Bit64, Size::Bit32)) {
+                return Ok((Instruction::MovsxdReg64Rm32 { reg64, rm32 }, decoder.length()));
+            }
+        }
+        0x66 => {
+            if let Some((k, mergemask, vzmm, zmmm512b32)) = const_try!(evex::decode_k_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x66],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpgtdKMergemaskVzmmZmmm512b32 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x68 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0x68])) {
+                return Ok((Instruction::PushImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0x68])) {
+                return Ok((Instruction::PushImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0x69 => {
+            if let Some((reg16, rm16, imm16)) = const_try!(rmi::decode_imm16(&mut decoder, [0x69], Size::Bit16)) {
+                return Ok((Instruction::ImulReg16Rm16Imm16 { reg16, rm16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, rm32, imm32)) = const_try!(rmi::decode_imm32(&mut decoder, [0x69], Size::Bit32)) {
+                return Ok((Instruction::ImulReg32Rm32Imm32 { reg32, rm32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, rm64, imm32)) = const_try!(rmi::decode_imm32(&mut decoder, [0x69], Size::Bit64)) {
+                return Ok((Instruction::ImulReg64Rm64Imm32 { reg64, rm64, imm32 }, decoder.length()));
+            }
+        }
+        0x6A => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x6A])) {
+                return Ok((Instruction::PushImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x6B => {
+            if let Some((reg16, rm16, imm8)) = const_try!(rmi::decode_imm8(&mut decoder, [0x6B], Size::Bit16)) {
+                return Ok((Instruction::ImulReg16Rm16Imm8 { reg16, rm16, imm8 }, decoder.length()));
+            }
+            if let Some((reg32, rm32, imm8)) = const_try!(rmi::decode_imm8(&mut decoder, [0x6B], Size::Bit32)) {
+                return Ok((Instruction::ImulReg32Rm32Imm8 { reg32, rm32, imm8 }, decoder.length()));
+            }
+            if let Some((reg64, rm64, imm8)) = const_try!(rmi::decode_imm8(&mut decoder, [0x6B], Size::Bit64)) {
+                return Ok((Instruction::ImulReg64Rm64Imm8 { reg64, rm64, imm8 }, decoder.length()));
+            }
+        }
+        0x6F => {
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6F], 0x66, false))
+            {
+                return Ok((Instruction::VmovdqaXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x6F], 0x66, false))
+            {
+                return Ok((Instruction::VmovdqaYmmYmmm256 { ymm, ymmm256 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x6F], 0xF3, false))
+            {
+                return Ok((Instruction::VmovdquXmmXmmm128 { xmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x6F], 0xF3, false))
+            {
+                return Ok((Instruction::VmovdquYmmYmmm256 { ymm, ymmm256 }, decoder.length()));
+            }
+            if let Some((zmm, mask, zmmm512)) = const_try!(evex::decode_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x6F],
+                0xF3,
+                false,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vmovdqu32ZmmMaskZmmm512 { zmm, mask, zmmm512 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, zmmm512)) = const_try!(evex::decode_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x6F],
+                0xF3,
+                true,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vmovdqu64ZmmMaskZmmm512 { zmm, mask, zmmm512 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x70 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x70])) {
+                return Ok((Instruction::JccOImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x71 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x71])) {
+                return Ok((Instruction::JccNoImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x72 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x72])) {
+                return Ok((Instruction::JccBImm8 { imm8 }, decoder.length()));
+            }
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x72])) {
+                return Ok((Instruction::JccCImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x73 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x73])) {
+                return Ok((Instruction::JccAeImm8 { imm8 }, decoder.length()));
+            }
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x73])) {
+                return Ok((Instruction::JccNcImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x74 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x74])) {
+                return Ok((Instruction::JccEImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x75 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x75])) {
+                return Ok((Instruction::JccNeImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x76 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x76])) {
+                return Ok((Instruction::JccBeImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b32)) = const_try!(evex::decode_k_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x76],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpcmpeqdKMergemaskVzmmZmmm512b32 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x77 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x77])) {
+                return Ok((Instruction::JccAImm8 { imm8 }, decoder.length()));
+            }
+            if const_try!(vex::decode_zo(&mut decoder, [0x0F, 0x77], 0x00, false, false)) {
+                return Ok((Instruction::Vzeroupper, decoder.length()));
+            }
+        }
+        0x78 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x78])) {
+                return Ok((Instruction::JccSImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm8)) = const_try!(vex::decode_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x78],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastbXmmXmmm8 { xmm, xmmm8 }, decoder.length()));
+            }
+            if let Some((ymm, xmmm8)) = const_try!(vex::decode_ymm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x78],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastbYmmXmmm8 { ymm, xmmm8 }, decoder.length()));
+            }
+        }
+        0x79 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x79])) {
+                return Ok((Instruction::JccNsImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm16)) = const_try!(vex::decode_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x79],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastwXmmXmmm16 { xmm, xmmm16 }, decoder.length()));
+            }
+            if let Some((ymm, xmmm16)) = const_try!(vex::decode_ymm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x79],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VpbroadcastwYmmXmmm16 { ymm, xmmm16 }, decoder.length()));
+            }
+        }
+        0x7A => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7A])) {
+                return Ok((Instruction::JccPImm8 { imm8 }, decoder.length()));
+            }
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7A])) {
+                return Ok((Instruction::JccPeImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x7B => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7B])) {
+                return Ok((Instruction::JccNpImm8 { imm8 }, decoder.length()));
+            }
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7B])) {
+                return Ok((Instruction::JccPoImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x7C => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7C])) {
+                return Ok((Instruction::JccLImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x7D => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7D])) {
+                return Ok((Instruction::JccGeImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x7E => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7E])) {
+                return Ok((Instruction::JccLeImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0x7F => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0x7F])) {
+                return Ok((Instruction::JccGImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x7F], 0x66, false))
+            {
+                return Ok((Instruction::VmovdqaXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x7F], 0x66, false))
+            {
+                return Ok((Instruction::VmovdqaYmmm256Ymm { ymmm256, ymm }, decoder.length()));
+            }
+            if let Some((xmm, xmmm128)) =
+                const_try!(vex::decode_xmm_xmm_or_memory(&mut decoder, [0x0F, 0x7F], 0xF3, false))
+            {
+                return Ok((Instruction::VmovdquXmmm128Xmm { xmmm128, xmm }, decoder.length()));
+            }
+            if let Some((ymm, ymmm256)) =
+                const_try!(vex::decode_ymm_ymm_or_memory(&mut decoder, [0x0F, 0x7F], 0xF3, false))
+            {
+                return Ok((Instruction::VmovdquYmmm256Ymm { ymmm256, ymm }, decoder.length()));
+            }
+            if let Some((zmm, mergemask, zmmm512)) =
+                const_try!(evex::decode_zmm_or_memory_zmm(&mut decoder, [0x0F, 0x7F], 0xF3, false))
+            {
+                return Ok((
+                    Instruction::Vmovdqu32Zmmm512MergemaskZmm {
+                        zmmm512,
+                        mergemask,
+                        zmm,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, zmmm512)) =
+                const_try!(evex::decode_zmm_or_memory_zmm(&mut decoder, [0x0F, 0x7F], 0xF3, true))
+            {
+                return Ok((
+                    Instruction::Vmovdqu64Zmmm512MergemaskZmm {
+                        zmmm512,
+                        mergemask,
+                        zmm,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x80 => {
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x00)) {
+                return Ok((Instruction::AddRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x00)) {
+                return Ok((Instruction::AddLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x07)) {
+                return Ok((Instruction::CmpRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x05)) {
+                return Ok((Instruction::SubRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x05)) {
+                return Ok((Instruction::SubLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x06)) {
+                return Ok((Instruction::XorRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x06)) {
+                return Ok((Instruction::XorLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x04)) {
+                return Ok((Instruction::AndRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x04)) {
+                return Ok((Instruction::AndLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x01)) {
+                return Ok((Instruction::OrRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x01)) {
+                return Ok((Instruction::OrLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x02)) {
+                return Ok((Instruction::AdcRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x02)) {
+                return Ok((Instruction::AdcLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x03)) {
+                return Ok((Instruction::SbbRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0x80], 0x03)) {
+                return Ok((Instruction::SbbLockRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x81 => {
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x00)) {
+                return Ok((Instruction::AddLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x07)) {
+                return Ok((Instruction::CmpRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x07)) {
+                return Ok((Instruction::CmpRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x07)) {
+                return Ok((Instruction::CmpRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x05)) {
+                return Ok((Instruction::SubLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x06)) {
+                return Ok((Instruction::XorLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x04)) {
+                return Ok((Instruction::AndLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x01)) {
+                return Ok((Instruction::OrLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x02)) {
+                return Ok((Instruction::AdcLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbLockRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbLockRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0x81], 0x03)) {
+                return Ok((Instruction::SbbLockRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x83 => {
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x00)) {
+                return Ok((Instruction::AddLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x07)) {
+                return Ok((Instruction::CmpRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x07)) {
+                return Ok((Instruction::CmpRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x07)) {
+                return Ok((Instruction::CmpRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x05)) {
+                return Ok((Instruction::SubLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x06)) {
+                return Ok((Instruction::XorLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x04)) {
+                return Ok((Instruction::AndLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x01)) {
+                return Ok((Instruction::OrLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x02)) {
+                return Ok((Instruction::AdcLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbLockRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbLockRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0x83], 0x03)) {
+                return Ok((Instruction::SbbLockRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        0x84 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x84], Size::Bit8)) {
+                return Ok((Instruction::TestRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+        }
+        0x85 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x85], Size::Bit16)) {
+                return Ok((Instruction::TestRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x85], Size::Bit32)) {
+                return Ok((Instruction::TestRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x85], Size::Bit64)) {
+                return Ok((Instruction::TestRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+        }
+        0x86 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x86], Size::Bit8)) {
+                return Ok((Instruction::XchgRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x86], Size::Bit8)) {
+                return Ok((Instruction::XchgLockRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x86], Size::Bit8)) {
+                return Ok((Instruction::XchgReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x87 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit16)) {
+                return Ok((Instruction::XchgRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit16)) {
+                return Ok((Instruction::XchgLockRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit32)) {
+                return Ok((Instruction::XchgRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit32)) {
+                return Ok((Instruction::XchgLockRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit64)) {
+                return Ok((Instruction::XchgRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit64)) {
+                return Ok((Instruction::XchgLockRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit16)) {
+                return Ok((Instruction::XchgReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit32)) {
+                return Ok((Instruction::XchgReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x87], Size::Bit64)) {
+                return Ok((Instruction::XchgReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x88 => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x88], Size::Bit8)) {
+                return Ok((Instruction::MovRm8Reg8 { rm8, reg8 }, decoder.length()));
+            }
+        }
+        0x89 => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x89], Size::Bit16)) {
+                return Ok((Instruction::MovRm16Reg16 { rm16, reg16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x89], Size::Bit32)) {
+                return Ok((Instruction::MovRm32Reg32 { rm32, reg32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x89], Size::Bit64)) {
+                return Ok((Instruction::MovRm64Reg64 { rm64, reg64 }, decoder.length()));
+            }
+        }
+        0x8A => {
+            if let Some((rm8, reg8)) = const_try!(mr::decode(&mut decoder, [0x8A], Size::Bit8)) {
+                return Ok((Instruction::MovReg8Rm8 { reg8, rm8 }, decoder.length()));
+            }
+        }
+        0x8B => {
+            if let Some((rm16, reg16)) = const_try!(mr::decode(&mut decoder, [0x8B], Size::Bit16)) {
+                return Ok((Instruction::MovReg16Rm16 { reg16, rm16 }, decoder.length()));
+            }
+            if let Some((rm32, reg32)) = const_try!(mr::decode(&mut decoder, [0x8B], Size::Bit32)) {
+                return Ok((Instruction::MovReg32Rm32 { reg32, rm32 }, decoder.length()));
+            }
+            if let Some((rm64, reg64)) = const_try!(mr::decode(&mut decoder, [0x8B], Size::Bit64)) {
+                return Ok((Instruction::MovReg64Rm64 { reg64, rm64 }, decoder.length()));
+            }
+        }
+        0x8D => {
+            if let Some((mem64, reg16)) = const_try!(mr::decode_mem(&mut decoder, [0x8D], Size::Bit16)) {
+                return Ok((Instruction::LeaReg16Mem64 { reg16, mem64 }, decoder.length()));
+            }
+            if let Some((mem64, reg32)) = const_try!(mr::decode_mem(&mut decoder, [0x8D], Size::Bit32)) {
+                return Ok((Instruction::LeaReg32Mem64 { reg32, mem64 }, decoder.length()));
+            }
+            if let Some((mem64, reg64)) = const_try!(mr::decode_mem(&mut decoder, [0x8D], Size::Bit64)) {
+                return Ok((Instruction::LeaReg64Mem64 { reg64, mem64 }, decoder.length()));
+            }
+        }
+        0x8F => {
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0x8F],
+                0x00,
+                Size::Bit64,
+                false,
+                false
+            )) {
+                return Ok((Instruction::PopRm64 { rm64 }, decoder.length()));
+            }
+        }
+        0x90 => {
+            if const_try!(zo::decode(&mut decoder, [0x90])) {
+                return Ok((Instruction::Nop, decoder.length()));
+            }
+            if let Some((k, km16)) = const_try!(vex::decode_k_k_or_memory(
+                &mut decoder,
+                [0x0F, 0x90],
+                0x00,
+                false,
+                false
+            )) {
+                return Ok((Instruction::KmovwKKm16 { k, km16 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, vm32x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                false,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VpgatherddXmmVm32xVxmm { xmm, vm32x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm32y)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                false,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VpgatherddYmmVm32yVymm { ymm, vm32y, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32z)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpgatherddZmmMergemaskVm32z { zmm, mergemask, vm32z },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm32x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VpgatherdqXmmVm32xVxmm { xmm, vm32x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm32x)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VpgatherdqYmmVm32xVymm { ymm, vm32x, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32y)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x90],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VpgatherdqZmmMergemaskVm32y { zmm, mergemask, vm32y },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x91 => {
+            if let Some((k, mem16)) = const_try!(vex::decode_k_memory(&mut decoder, [0x0F, 0x91], 0x00, false, false)) {
+                return Ok((Instruction::KmovwMem16K { mem16, k }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, vm64x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                false,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VpgatherqdXmmVm64xVxmm { xmm, vm64x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm64y)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                false,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VpgatherqdXmmVm64yVxmm { xmm, vm64y, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, mergemask, vm64z)) = const_try!(evex::decode_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpgatherqdYmmMergemaskVm64z { ymm, mergemask, vm64z },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm64x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VpgatherqqXmmVm64xVxmm { xmm, vm64x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm64y)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VpgatherqqYmmVm64yVymm { ymm, vm64y, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm64z)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x91],
+                0x66,
+                true,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpgatherqqZmmMergemaskVm64z { zmm, mergemask, vm64z },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x92 => {
+            if let Some((k, reg32)) = const_try!(vex::decode_k_gpr(&mut decoder, [0x0F, 0x92], 0x00, false, false)) {
+                return Ok((Instruction::KmovwKReg32 { k, reg32 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, vm32x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                false,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VgatherdpsXmmVm32xVxmm { xmm, vm32x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm32y)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                false,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VgatherdpsYmmVm32yVymm { ymm, vm32y, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32z)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VgatherdpsZmmMergemaskVm32z { zmm, mergemask, vm32z },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm32x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VgatherdpdXmmVm32xVxmm { xmm, vm32x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm32x)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VgatherdpdYmmVm32xVymm { ymm, vm32x, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32y)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x92],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VgatherdpdZmmMergemaskVm32y { zmm, mergemask, vm32y },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x93 => {
+            if let Some((reg32, kr)) = const_try!(vex::decode_gpr_k(&mut decoder, [0x0F, 0x93], 0x00, false, false)) {
+                return Ok((Instruction::KmovwReg32Kr { reg32, kr }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, vm64x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                false,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VgatherqpsXmmVm64xVxmm { xmm, vm64x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm64y)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                false,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VgatherqpsXmmVm64yVxmm { xmm, vm64y, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, mergemask, vm64z)) = const_try!(evex::decode_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VgatherqpsYmmMergemaskVm64z { ymm, mergemask, vm64z },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, vm64x)) = const_try!(vex::decode_xmm_xmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                true,
+                Size::Bit128
+            )) {
+                return Ok((
+                    Instruction::VgatherqpdXmmVm64xVxmm { xmm, vm64x, vxmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, vm64y)) = const_try!(vex::decode_ymm_ymm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VgatherqpdYmmVm64yVymm { ymm, vm64y, vymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm64z)) = const_try!(evex::decode_zmm_vsib(
+                &mut decoder,
+                [0x0F, 0x38, 0x93],
+                0x66,
+                true,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VgatherqpdZmmMergemaskVm64z { zmm, mergemask, vm64z },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x98 => {
+            if let Some((k, kr)) = const_try!(vex::decode_k_k(&mut decoder, [0x0F, 0x98], 0x00, false, false)) {
+                return Ok((Instruction::KortestwKKr { k, kr }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x98],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x98],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x98],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x98],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x99 => {
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x99],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x99],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9A => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9A],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9A],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9A],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9A],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9B => {
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9B],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9B],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9C => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9C],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9C],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9C],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9C],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9D => {
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9D],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9D],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9E => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9E],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9E],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9E],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9E],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0x9F => {
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9F],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0x9F],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub132sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA0 => {
+            if let Some((zmm, mergemask, vm32z)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA0],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpscatterddVm32zMergemaskZmm { vm32z, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32y)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA0],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VpscatterdqVm32yMergemaskZmm { vm32y, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA1 => {
+            if let Some((ymm, mergemask, vm64z)) = const_try!(evex::decode_vsib_ymm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA1],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpscatterqdVm64zMergemaskYmm { vm64z, mergemask, ymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm64z)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA1],
+                0x66,
+                true,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VpscatterqqVm64zMergemaskZmm { vm64z, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA2 => {
+            if let Some((zmm, mergemask, vm32z)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA2],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VscatterdpsVm32zMergemaskZmm { vm32z, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm32y)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA2],
+                0x66,
+                true,
+                Size::Bit256
+            )) {
+                return Ok((
+                    Instruction::VscatterdpdVm32yMergemaskZmm { vm32y, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA3 => {
+            if let Some((ymm, mergemask, vm64z)) = const_try!(evex::decode_vsib_ymm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA3],
+                0x66,
+                false,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VscatterqpsVm64zMergemaskYmm { vm64z, mergemask, ymm },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mergemask, vm64z)) = const_try!(evex::decode_vsib_zmm(
+                &mut decoder,
+                [0x0F, 0x38, 0xA3],
+                0x66,
+                true,
+                Size::Bit512
+            )) {
+                return Ok((
+                    Instruction::VscatterqpdVm64zMergemaskZmm { vm64z, mergemask, zmm },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA4 => {
+            if const_try!(zo::decode(&mut decoder, [0xA4])) {
+                return Ok((Instruction::MovsB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA4], 0xF3, false, false)) {
+                return Ok((Instruction::MovsRepB, decoder.length()));
+            }
+        }
+        0xA5 => {
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA5], 0x00, true, false)) {
+                return Ok((Instruction::MovsW, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0xA5])) {
+                return Ok((Instruction::MovsD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA5], 0x00, false, true)) {
+                return Ok((Instruction::MovsQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA5], 0xF3, true, false)) {
+                return Ok((Instruction::MovsRepW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA5], 0xF3, false, false)) {
+                return Ok((Instruction::MovsRepD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA5], 0xF3, false, true)) {
+                return Ok((Instruction::MovsRepQ, decoder.length()));
+            }
+        }
+        0xA6 => {
+            if const_try!(zo::decode(&mut decoder, [0xA6])) {
+                return Ok((Instruction::CmpsB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA6], 0xF3, false, false)) {
+                return Ok((Instruction::CmpsRepeB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA6], 0xF2, false, false)) {
+                return Ok((Instruction::CmpsRepneB, decoder.length()));
+            }
+        }
+        0xA7 => {
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0x00, true, false)) {
+                return Ok((Instruction::CmpsW, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0xA7])) {
+                return Ok((Instruction::CmpsD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0x00, false, true)) {
+                return Ok((Instruction::CmpsQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF3, true, false)) {
+                return Ok((Instruction::CmpsRepeW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF3, false, false)) {
+                return Ok((Instruction::CmpsRepeD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF3, false, true)) {
+                return Ok((Instruction::CmpsRepeQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF2, true, false)) {
+                return Ok((Instruction::CmpsRepneW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF2, false, false)) {
+                return Ok((Instruction::CmpsRepneD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xA7], 0xF2, false, true)) {
+                return Ok((Instruction::CmpsRepneQ, decoder.length()));
+            }
+        }
+        0xA8 => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0xA8])) {
+                return Ok((Instruction::TestAlImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA8],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA8],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA8],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA8],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xA9 => {
+            if let Some(imm16) = const_try!(i::decode_imm16_oso(&mut decoder, [0xA9])) {
+                return Ok((Instruction::TestAxImm16 { imm16 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0xA9])) {
+                return Ok((Instruction::TestEaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some(imm32) = const_try!(i::decode_imm32_rexw(&mut decoder, [0xA9])) {
+                return Ok((Instruction::TestRaxImm32 { imm32 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA9],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xA9],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAA => {
+            if const_try!(zo::decode(&mut decoder, [0xAA])) {
+                return Ok((Instruction::StosB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAA], 0xF3, false, false)) {
+                return Ok((Instruction::StosRepB, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAA],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAA],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAA],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAA],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAB => {
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAB], 0x00, true, false)) {
+                return Ok((Instruction::StosW, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0xAB])) {
+                return Ok((Instruction::StosD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAB], 0x00, false, true)) {
+                return Ok((Instruction::StosQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAB], 0xF3, true, false)) {
+                return Ok((Instruction::StosRepW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAB], 0xF3, false, false)) {
+                return Ok((Instruction::StosRepD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAB], 0xF3, false, true)) {
+                return Ok((Instruction::StosRepQ, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAB],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAB],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAC => {
+            if const_try!(zo::decode(&mut decoder, [0xAC])) {
+                return Ok((Instruction::LodsB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAC], 0xF3, false, false)) {
+                return Ok((Instruction::LodsRepB, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAC],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAC],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAD => {
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAD], 0x00, true, false)) {
+                return Ok((Instruction::LodsW, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0xAD])) {
+                return Ok((Instruction::LodsD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAD], 0x00, false, true)) {
+                return Ok((Instruction::LodsQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAD], 0xF3, true, false)) {
+                return Ok((Instruction::LodsRepW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAD], 0xF3, false, false)) {
+                return Ok((Instruction::LodsRepD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAD], 0xF3, false, true)) {
+                return Ok((Instruction::LodsRepQ, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAD],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAD],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAE => {
+            if const_try!(zo::decode(&mut decoder, [0xAE])) {
+                return Ok((Instruction::ScasB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAE], 0xF3, false, false)) {
+                return Ok((Instruction::ScasRepeB, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAE], 0xF2, false, false)) {
+                return Ok((Instruction::ScasRepneB, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAE],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAE],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xAF => {
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0x00, true, false)) {
+                return Ok((Instruction::ScasW, decoder.length()));
+            }
+            if const_try!(zo::decode(&mut decoder, [0xAF])) {
+                return Ok((Instruction::ScasD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0x00, false, true)) {
+                return Ok((Instruction::ScasQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF3, true, false)) {
+                return Ok((Instruction::ScasRepeW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF3, false, false)) {
+                return Ok((Instruction::ScasRepeD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF3, false, true)) {
+                return Ok((Instruction::ScasRepeQ, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF2, true, false)) {
+                return Ok((Instruction::ScasRepneW, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF2, false, false)) {
+                return Ok((Instruction::ScasRepneD, decoder.length()));
+            }
+            if const_try!(zo::decode_with_prefixes(&mut decoder, [0xAF], 0xF2, false, true)) {
+                return Ok((Instruction::ScasRepneQ, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAF],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xAF],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub213sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xB0..=0xB7 => {
+            if let Some((reg8, imm8)) = const_try!(oi::decode_reg8_imm8(&mut decoder, 0xB0)) {
+                return Ok((Instruction::MovReg8Imm8 { reg8, imm8 }, decoder.length()));
+            }
+        }
+        0xB8 => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB8],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB8],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB8],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB8],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xB9 => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB9],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xB9],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBA => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBA],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBA],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBA],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBA],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBB => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBB],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBB],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBC => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBC],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBC],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBD => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBD],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBD],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmadd231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBE => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231psXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231psYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBE],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231pdXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBE],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231pdYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xBF => {
+            if let Some((reg16, imm16)) = const_try!(oi::decode_reg16_imm16(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg16Imm16 { reg16, imm16 }, decoder.length()));
+            }
+            if let Some((reg32, imm32)) = const_try!(oi::decode_reg32_imm32(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg32Imm32 { reg32, imm32 }, decoder.length()));
+            }
+            if let Some((reg64, imm64)) = const_try!(oi::decode_reg64_imm64(&mut decoder, 0xB8)) {
+                return Ok((Instruction::MovReg64Imm64 { reg64, imm64 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm32)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBF],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231ssXmmVxmmXmmm32 { xmm, vxmm, xmmm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((xmm, vxmm, xmmm64)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0x38, 0xBF],
+                0x66,
+                true
+            )) {
+                return Ok((
+                    Instruction::Vfnmsub231sdXmmVxmmXmmm64 { xmm, vxmm, xmmm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xC0 => {
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x00)) {
+                return Ok((Instruction::RolRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x01)) {
+                return Ok((Instruction::RorRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x02)) {
+                return Ok((Instruction::RclRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x03)) {
+                return Ok((Instruction::RcrRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x04)) {
+                return Ok((Instruction::ShlRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x05)) {
+                return Ok((Instruction::ShrRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC0], 0x07)) {
+                return Ok((Instruction::SarRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+        }
+        0xC1 => {
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x00)) {
+                return Ok((Instruction::RolRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x00)) {
+                return Ok((Instruction::RolRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x00)) {
+                return Ok((Instruction::RolRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x01)) {
+                return Ok((Instruction::RorRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x01)) {
+                return Ok((Instruction::RorRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x01)) {
+                return Ok((Instruction::RorRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x02)) {
+                return Ok((Instruction::RclRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x02)) {
+                return Ok((Instruction::RclRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x02)) {
+                return Ok((Instruction::RclRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x03)) {
+                return Ok((Instruction::RcrRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x03)) {
+                return Ok((Instruction::RcrRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x03)) {
+                return Ok((Instruction::RcrRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x04)) {
+                return Ok((Instruction::ShlRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x04)) {
+                return Ok((Instruction::ShlRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x04)) {
+                return Ok((Instruction::ShlRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x05)) {
+                return Ok((Instruction::ShrRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x05)) {
+                return Ok((Instruction::ShrRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x05)) {
+                return Ok((Instruction::ShrRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+            if let Some((rm16, imm8)) = const_try!(mi::decode_rm16_imm8(&mut decoder, [0xC1], 0x07)) {
+                return Ok((Instruction::SarRm16Imm8 { rm16, imm8 }, decoder.length()));
+            }
+            if let Some((rm32, imm8)) = const_try!(mi::decode_rm32_imm8(&mut decoder, [0xC1], 0x07)) {
+                return Ok((Instruction::SarRm32Imm8 { rm32, imm8 }, decoder.length()));
+            }
+            if let Some((rm64, imm8)) = const_try!(mi::decode_rm64_imm8(&mut decoder, [0xC1], 0x07)) {
+                return Ok((Instruction::SarRm64Imm8 { rm64, imm8 }, decoder.length()));
+            }
+        }
+        0xC2 => {
+            if let Some(imm16) = const_try!(i::decode_imm16(&mut decoder, [0xC2])) {
+                return Ok((Instruction::RetImm16 { imm16 }, decoder.length()));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b32, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0xC2],
+                0x00,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VcmppsKMergemaskVzmmZmmm512b32Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b32,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((k, mergemask, vzmm, zmmr, imm8)) =
+                const_try!(evex::decode_k_zmm_zmm_sae_imm8(&mut decoder, [0x0F, 0xC2], 0x00, false))
+            {
+                return Ok((
+                    Instruction::VcmppsKMergemaskVzmmZmmrSaeImm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmr,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((k, mergemask, vzmm, zmmm512b64, imm8)) = const_try!(evex::decode_k_zmm_zmm_or_memory_imm8(
+                &mut decoder,
+                [0x0F, 0xC2],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VcmppdKMergemaskVzmmZmmm512b64Imm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmm512b64,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((k, mergemask, vzmm, zmmr, imm8)) =
+                const_try!(evex::decode_k_zmm_zmm_sae_imm8(&mut decoder, [0x0F, 0xC2], 0x66, true))
+            {
+                return Ok((
+                    Instruction::VcmppdKMergemaskVzmmZmmrSaeImm8 {
+                        k,
+                        mergemask,
+                        vzmm,
+                        zmmr,
+                        imm8,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xC3 => {
+            if const_try!(zo::decode(&mut decoder, [0xC3])) {
+                return Ok((Instruction::Ret, decoder.length()));
+            }
+        }
+        0xC6 => {
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xC6], 0x00)) {
+                return Ok((Instruction::MovRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+        }
+        0xC7 => {
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0xC7], 0x00)) {
+                return Ok((Instruction::MovRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0xC7], 0x00)) {
+                return Ok((Instruction::MovRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0xC7], 0x00)) {
+                return Ok((Instruction::MovRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+        }
+        0xCC => {
+            if const_try!(zo::decode(&mut decoder, [0xCC])) {
+                return Ok((Instruction::Int3, decoder.length()));
+            }
+        }
+        0xCD => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0xCD])) {
+                return Ok((Instruction::IntImm8 { imm8 }, decoder.length()));
+            }
+        }
+        0xD0 => {
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RolRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x01,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RorRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x02,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RclRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x03,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RcrRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x04,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShlRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x05,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShrRm8One { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD0],
+                0x07,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SarRm8One { rm8 }, decoder.length()));
+            }
+        }
+        0xD1 => {
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x00,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RolRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x00,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RolRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x00,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RolRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x01,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RorRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x01,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RorRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x01,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RorRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x02,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RclRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x02,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RclRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x02,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RclRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x03,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RcrRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x03,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RcrRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x03,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RcrRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x04,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::ShlRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x04,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShlRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x04,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::ShlRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x05,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::ShrRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x05,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShrRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x05,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::ShrRm64One { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x07,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::SarRm16One { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x07,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SarRm32One { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD1],
+                0x07,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::SarRm64One { rm64 }, decoder.length()));
+            }
+        }
+        0xD2 => {
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RolRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x01,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RorRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x02,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RclRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x03,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RcrRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x04,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShlRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x05,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShrRm8Cl { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD2],
+                0x07,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SarRm8Cl { rm8 }, decoder.length()));
+            }
+        }
+        0xD3 => {
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x00,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RolRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x00,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RolRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x00,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RolRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x01,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RorRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x01,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RorRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x01,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RorRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x02,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RclRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x02,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RclRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x02,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RclRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x03,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::RcrRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x03,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::RcrRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x03,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::RcrRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x04,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::ShlRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x04,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShlRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x04,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::ShlRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x05,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::ShrRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x05,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ShrRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x05,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::ShrRm64Cl { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x07,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::SarRm16Cl { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x07,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::SarRm32Cl { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xD3],
+                0x07,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::SarRm64Cl { rm64 }, decoder.length()));
+            }
+        }
+        0xD4 => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xD4],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddqXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xD4],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddqYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xD4],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpaddqZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xDB => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xDB],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpandXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xDB],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpandYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xDB],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpanddZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xDB],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpandqZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xE8 => {
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0xE8])) {
+                return Ok((Instruction::CallImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0xE9 => {
+            if let Some(imm32) = const_try!(i::decode_imm32(&mut decoder, [0xE9])) {
+                return Ok((Instruction::JmpImm32 { imm32 }, decoder.length()));
+            }
+        }
+        0xEB => {
+            if let Some(imm8) = const_try!(i::decode_imm8(&mut decoder, [0xEB])) {
+                return Ok((Instruction::JmpImm8 { imm8 }, decoder.length()));
+            }
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEB],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VporXmmVxmmXmmm128 { xmm, vxmm, xmmm128 }, decoder.length()));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEB],
+                0x66,
+                false
+            )) {
+                return Ok((Instruction::VporYmmVymmYmmm256 { ymm, vymm, ymmm256 }, decoder.length()));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEB],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpordZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEB],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VporqZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xEF => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEF],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpxorXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEF],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpxorYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEF],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpxordZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xEF],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpxorqZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xF0 => {
+            if let Some((reg32, rm32, imm8)) = const_try!(vex::decode_rmi(
+                &mut decoder,
+                [0x0F, 0x3A, 0xF0],
+                0xF2,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::RorxReg32Rm32Imm8 { reg32, rm32, imm8 }, decoder.length()));
+            }
+            if let Some((reg64, rm64, imm8)) = const_try!(vex::decode_rmi(
+                &mut decoder,
+                [0x0F, 0x3A, 0xF0],
+                0xF2,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::RorxReg64Rm64Imm8 { reg64, rm64, imm8 }, decoder.length()));
+            }
+        }
+        0xF1 => {
+            if const_try!(zo::decode(&mut decoder, [0xF1])) {
+                return Ok((Instruction::Int1, decoder.length()));
+            }
+        }
+        0xF2 => {
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF2],
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::AndnReg32Vreg32Rm32 { reg32, vreg32, rm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF2],
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::AndnReg64Vreg64Rm64 { reg64, vreg64, rm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xF3 => {
+            if let Some((vreg32, rm32)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x03,
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::BlsiVreg32Rm32 { vreg32, rm32 }, decoder.length()));
+            }
+            if let Some((vreg64, rm64)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x03,
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::BlsiVreg64Rm64 { vreg64, rm64 }, decoder.length()));
+            }
+            if let Some((vreg32, rm32)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x02,
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::BlsmskVreg32Rm32 { vreg32, rm32 }, decoder.length()));
+            }
+            if let Some((vreg64, rm64)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x02,
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::BlsmskVreg64Rm64 { vreg64, rm64 }, decoder.length()));
+            }
+            if let Some((vreg32, rm32)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x01,
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((Instruction::BlsrVreg32Rm32 { vreg32, rm32 }, decoder.length()));
+            }
+            if let Some((vreg64, rm64)) = const_try!(vex::decode_vm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF3],
+                0x01,
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((Instruction::BlsrVreg64Rm64 { vreg64, rm64 }, decoder.length()));
+            }
+        }
+        0xF5 => {
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::BzhiReg32Rm32Vreg32 { reg32, rm32, vreg32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::BzhiReg64Rm64Vreg64 { reg64, rm64, vreg64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0xF2,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::PdepReg32Vreg32Rm32 { reg32, vreg32, rm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0xF2,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::PdepReg64Vreg64Rm64 { reg64, vreg64, rm64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0xF3,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::PextReg32Vreg32Rm32 { reg32, vreg32, rm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF5],
+                0xF3,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::PextReg64Vreg64Rm64 { reg64, vreg64, rm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xF6 => {
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x04,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::MulRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x05,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ImulRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x06,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DivRm8 { rm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x07,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IdivRm8 { rm8 }, decoder.length()));
+            }
+            if let Some((rm8, imm8)) = const_try!(mi::decode_rm8_imm8(&mut decoder, [0xF6], 0x00)) {
+                return Ok((Instruction::TestRm8Imm8 { rm8, imm8 }, decoder.length()));
+            }
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x02,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NotRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x02,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NotLockRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x03,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NegRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF6],
+                0x03,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NegLockRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF6],
+                0xF2,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::MulxReg32Vreg32Rm32 { reg32, vreg32, rm32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF6],
+                0xF2,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::MulxReg64Vreg64Rm64 { reg64, vreg64, rm64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xF7 => {
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x04,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::MulRm16 { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x04,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::MulRm32 { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x04,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::MulRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x05,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::ImulRm16 { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x05,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::ImulRm32 { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x05,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::ImulRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x06,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::DivRm16 { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x06,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DivRm32 { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x06,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::DivRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x07,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::IdivRm16 { rm16 }, decoder.length()));
+            }
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x07,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IdivRm32 { rm32 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x07,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::IdivRm64 { rm64 }, decoder.length()));
+            }
+            if let Some((rm16, imm16)) = const_try!(mi::decode_rm16_imm16(&mut decoder, [0xF7], 0x00)) {
+                return Ok((Instruction::TestRm16Imm16 { rm16, imm16 }, decoder.length()));
+            }
+            if let Some((rm32, imm32)) = const_try!(mi::decode_rm32_imm32(&mut decoder, [0xF7], 0x00)) {
+                return Ok((Instruction::TestRm32Imm32 { rm32, imm32 }, decoder.length()));
+            }
+            if let Some((rm64, imm32)) = const_try!(mi::decode_rm64_imm32(&mut decoder, [0xF7], 0x00)) {
+                return Ok((Instruction::TestRm64Imm32 { rm64, imm32 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::NotRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::NotLockRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NotRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NotLockRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::NotRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x02,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::NotLockRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::NegRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::NegLockRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NegRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::NegLockRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::NegRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xF7],
+                0x03,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::NegLockRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0x00,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::BextrReg32Rm32Vreg32 { reg32, rm32, vreg32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0x00,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::BextrReg64Rm64Vreg64 { reg64, rm64, vreg64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0xF3,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::SarxReg32Rm32Vreg32 { reg32, rm32, vreg32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0xF3,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::SarxReg64Rm64Vreg64 { reg64, rm64, vreg64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0x66,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::ShlxReg32Rm32Vreg32 { reg32, rm32, vreg32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0x66,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::ShlxReg64Rm64Vreg64 { reg64, rm64, vreg64 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg32, vreg32, rm32)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0xF2,
+                false,
+                Size::Bit32
+            )) {
+                return Ok((
+                    Instruction::ShrxReg32Rm32Vreg32 { reg32, rm32, vreg32 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((reg64, vreg64, rm64)) = const_try!(vex::decode_rvm(
+                &mut decoder,
+                [0x0F, 0x38, 0xF7],
+                0xF2,
+                true,
+                Size::Bit64
+            )) {
+                return Ok((
+                    Instruction::ShrxReg64Rm64Vreg64 { reg64, rm64, vreg64 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFA => {
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFA],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpsubdZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFB => {
+            if let Some((zmm, mask, vzmm, zmmm512b64)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFB],
+                0x66,
+                true,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpsubqZmmMaskVzmmZmmm512b64 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b64,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFC => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddbXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFC],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddbYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFD => {
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFD],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddwXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFD],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpaddwYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFE => {
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFE],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IncRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFE],
+                0x00,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IncLockRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFE],
+                0x01,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DecRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm8) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFE],
+                0x01,
+                Size::Bit8,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DecLockRm8 { rm8 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some((xmm, vxmm, xmmm128)) = const_try!(vex::decode_xmm_xmm_xmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpadddXmmVxmmXmmm128 { xmm, vxmm, xmmm128 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((ymm, vymm, ymmm256)) = const_try!(vex::decode_ymm_ymm_ymm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFE],
+                0x66,
+                false
+            )) {
+                return Ok((
+                    Instruction::VpadddYmmVymmYmmm256 { ymm, vymm, ymmm256 },
+                    decoder.length(),
+                ));
+            }
+            if let Some((zmm, mask, vzmm, zmmm512b32)) = const_try!(evex::decode_zmm_zmm_zmm_or_memory(
+                &mut decoder,
+                [0x0F, 0xFE],
+                0x66,
+                false,
+                true
+            )) {
+                return Ok((
+                    Instruction::VpadddZmmMaskVzmmZmmm512b32 {
+                        zmm,
+                        mask,
+                        vzmm,
+                        zmmm512b32,
+                    },
+                    decoder.length(),
+                ));
+            }
+        }
+        0xFF => {
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x04,
+                Size::Bit64,
+                false,
+                false
+            )) {
+                return Ok((Instruction::JmpRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x02,
+                Size::Bit64,
+                false,
+                false
+            )) {
+                return Ok((Instruction::CallRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x06,
+                Size::Bit64,
+                false,
+                false
+            )) {
+                return Ok((Instruction::PushRm64 { rm64 }, decoder.length()));
+            }
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::IncRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::IncLockRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IncRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::IncLockRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::IncRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x00,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::IncLockRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::DecRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm16) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit16,
+                false,
+                true
+            )) {
+                return Ok((Instruction::DecLockRm16 { rm16 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DecRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm32) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit32,
+                false,
+                false
+            )) {
+                return Ok((Instruction::DecLockRm32 { rm32 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::DecRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(true);
+            if let Some(rm64) = const_try!(m::decode_gpr_or_memory(
+                &mut decoder,
+                [0xFF],
+                0x01,
+                Size::Bit64,
+                true,
+                false
+            )) {
+                return Ok((Instruction::DecLockRm64 { rm64 }, decoder.length()));
+            }
+            decoder.set_lock_prefix(false);
+        }
+        _ => {}
+    }
+    Err(DecodeError::UnknownInstruction)
+}
@@ -0,0 +1,39 @@
+use core::fmt;
+
+/// Represents an error raised by [`ElfWriter`][super::ElfWriter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum ElfError {
+    /// A symbol with the same name already exists.
+    DuplicateSymbol,
+
+    /// The symbol name is empty or contains a nul character.
+    InvalidSymbolName,
+
+    /// The symbol does not fit into the code.
+    SymbolOutOfRange { offset: usize, size: usize },
+
+    /// The relocated value does not fit into the code.
+    RelocationOutOfRange { offset: usize },
+
+    /// The symbol was not created by this writer.
+    InvalidSymbol,
+}
+
+impl fmt::Display for ElfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateSymbol => write!(f, "symbol is already defined"),
+            Self::InvalidSymbolName => write!(f, "symbol name is empty or contains nul character"),
+            Self::SymbolOutOfRange { offset, size } => {
+                write!(f, "symbol at offset {offset} of size {size} does not fit into the code")
+            }
+            Self::RelocationOutOfRange { offset } => {
+                write!(f, "relocation at offset {offset} does not fit into the code")
+            }
+            Self::InvalidSymbol => write!(f, "symbol does not belong to the writer"),
+        }
+    }
+}
+
+impl core::error::Error for ElfError {}
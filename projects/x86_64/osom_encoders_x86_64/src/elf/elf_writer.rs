@@ -0,0 +1,413 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::assembler::LabelReference;
+
+use super::{ElfError, RelocationKind, SymbolBinding, SymbolId, SymbolKind};
+
+const SECTION_TEXT: u16 = 1;
+const SECTION_SYMTAB: u16 = 3;
+const SECTION_STRTAB: u16 = 4;
+const SECTION_SHSTRTAB: u16 = 5;
+const SECTIONS_COUNT: u16 = 7;
+
+const ELF_HEADER_SIZE: usize = 64;
+const SECTION_HEADER_SIZE: usize = 64;
+const SYMBOL_SIZE: usize = 24;
+const RELA_SIZE: usize = 24;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+const STT_SECTION: u8 = 3;
+
+#[derive(Debug, Clone)]
+struct Symbol {
+    name: String,
+
+    /// The offset and size in `.text`, or `None` for external symbols.
+    definition: Option<(usize, usize)>,
+    kind: SymbolKind,
+    binding: SymbolBinding,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Relocation {
+    offset: usize,
+    symbol: SymbolId,
+    kind: RelocationKind,
+    addend: i64,
+}
+
+/// Writes `X86_64` code as ELF64 relocatable object file, with `.text`,
+/// `.rela.text`, `.symtab` and `.strtab` sections.
+///
+/// The code is typically produced by [`Assembler::finalize_relocatable`][crate::assembler::Assembler::finalize_relocatable],
+/// whose [`LabelReference`]s become relocations against external symbols.
+/// See the [module documentation][super] for an example.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ElfWriter {
+    code: Vec<u8>,
+    symbols: Vec<Symbol>,
+    relocations: Vec<Relocation>,
+}
+
+impl ElfWriter {
+    /// Creates a new [`ElfWriter`] with `code` as the content of `.text` section.
+    #[inline]
+    pub const fn new(code: Vec<u8>) -> Self {
+        Self {
+            code,
+            symbols: Vec::new(),
+            relocations: Vec::new(),
+        }
+    }
+
+    /// Defines a new symbol at `offset` of `.text` section.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfError::InvalidSymbolName`] if `name` is empty or contains nul
+    /// character, [`ElfError::DuplicateSymbol`] if the symbol already exists and
+    /// [`ElfError::SymbolOutOfRange`] if the symbol does not fit into the code.
+    pub fn define_symbol(
+        &mut self,
+        name: &str,
+        offset: usize,
+        size: usize,
+        kind: SymbolKind,
+        binding: SymbolBinding,
+    ) -> Result<SymbolId, ElfError> {
+        if offset.checked_add(size).is_none_or(|end| end > self.code.len()) {
+            return Err(ElfError::SymbolOutOfRange { offset, size });
+        }
+        self.add_symbol(Symbol {
+            name: String::from(name),
+            definition: Some((offset, size)),
+            kind,
+            binding,
+        })
+    }
+
+    /// Declares a new external symbol, i.e. one defined in a different object file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfError::InvalidSymbolName`] if `name` is empty or contains nul
+    /// character and [`ElfError::DuplicateSymbol`] if the symbol already exists.
+    pub fn declare_external(&mut self, name: &str) -> Result<SymbolId, ElfError> {
+        self.add_symbol(Symbol {
+            name: String::from(name),
+            definition: None,
+            kind: SymbolKind::NoType,
+            binding: SymbolBinding::Global,
+        })
+    }
+
+    /// Returns the symbol with given `name`, if any.
+    #[must_use]
+    pub fn symbol(&self, name: &str) -> Option<SymbolId> {
+        self.symbols
+            .iter()
+            .position(|symbol| symbol.name == name)
+            .map(symbol_id)
+    }
+
+    /// Adds a relocation of `kind` at `offset` of `.text` section, which the
+    /// linker fills with the address of `symbol` plus `addend`. For PC-relative
+    /// relocations the address is relative to `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfError::InvalidSymbol`] if `symbol` was not created by this writer
+    /// and [`ElfError::RelocationOutOfRange`] if the relocated value does not fit
+    /// into the code.
+    pub fn add_relocation(
+        &mut self,
+        offset: usize,
+        symbol: SymbolId,
+        kind: RelocationKind,
+        addend: i64,
+    ) -> Result<(), ElfError> {
+        if symbol.index() >= self.symbols.len() {
+            return Err(ElfError::InvalidSymbol);
+        }
+        if offset.checked_add(kind.size()).is_none_or(|end| end > self.code.len()) {
+            return Err(ElfError::RelocationOutOfRange { offset });
+        }
+        self.relocations.push(Relocation {
+            offset,
+            symbol,
+            kind,
+            addend,
+        });
+        Ok(())
+    }
+
+    /// Adds a PC-relative relocation for `reference`, i.e. for a displacement
+    /// in `call`, `jmp`, `jcc` or RIP-relative memory operand. The `kind` is
+    /// typically [`RelocationKind::PLT32`] for calls and jumps to functions,
+    /// and [`RelocationKind::PC32`] otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ElfWriter::add_relocation`].
+    pub fn add_label_reference(
+        &mut self,
+        reference: &LabelReference,
+        symbol: SymbolId,
+        kind: RelocationKind,
+    ) -> Result<(), ElfError> {
+        self.add_relocation(reference.offset, symbol, kind, reference.addend())
+    }
+
+    /// Writes the object file.
+    ///
+    /// # Panics
+    ///
+    /// If the object file exceeds `u32::MAX` symbols or string table bytes.
+    #[must_use]
+    pub fn write(&self) -> Vec<u8> {
+        let (symtab, strtab, symbol_indexes, first_global) = self.write_symbols();
+        let rela = self.write_relocations(&symbol_indexes);
+
+        let mut shstrtab = alloc::vec![0u8];
+        let mut section_name = |name: &str| {
+            let result = to_u32(shstrtab.len());
+            shstrtab.extend_from_slice(name.as_bytes());
+            shstrtab.push(0);
+            result
+        };
+        let names = [
+            section_name(".text"),
+            section_name(".rela.text"),
+            section_name(".symtab"),
+            section_name(".strtab"),
+            section_name(".shstrtab"),
+            section_name(".note.GNU-stack"),
+        ];
+
+        let mut result = alloc::vec![0u8; ELF_HEADER_SIZE];
+        let text_offset = append_aligned(&mut result, &self.code, 16);
+        let rela_offset = append_aligned(&mut result, &rela, 8);
+        let symtab_offset = append_aligned(&mut result, &symtab, 8);
+        let strtab_offset = append_aligned(&mut result, &strtab, 1);
+        let shstrtab_offset = append_aligned(&mut result, &shstrtab, 1);
+        let section_headers_offset = append_aligned(&mut result, &[], 8);
+        write_elf_header(&mut result, section_headers_offset);
+
+        let symtab_index = u32::from(SECTION_SYMTAB);
+        let headers = [
+            SectionHeader::default(),
+            SectionHeader {
+                name: names[0],
+                kind: SHT_PROGBITS,
+                flags: SHF_ALLOC | SHF_EXECINSTR,
+                offset: text_offset,
+                size: self.code.len(),
+                align: 16,
+                ..SectionHeader::default()
+            },
+            SectionHeader {
+                name: names[1],
+                kind: SHT_RELA,
+                flags: SHF_INFO_LINK,
+                offset: rela_offset,
+                size: rela.len(),
+                link: symtab_index,
+                info: u32::from(SECTION_TEXT),
+                align: 8,
+                entry_size: RELA_SIZE,
+            },
+            SectionHeader {
+                name: names[2],
+                kind: SHT_SYMTAB,
+                offset: symtab_offset,
+                size: symtab.len(),
+                link: u32::from(SECTION_STRTAB),
+                info: to_u32(first_global),
+                align: 8,
+                entry_size: SYMBOL_SIZE,
+                ..SectionHeader::default()
+            },
+            SectionHeader {
+                name: names[3],
+                kind: SHT_STRTAB,
+                offset: strtab_offset,
+                size: strtab.len(),
+                align: 1,
+                ..SectionHeader::default()
+            },
+            SectionHeader {
+                name: names[4],
+                kind: SHT_STRTAB,
+                offset: shstrtab_offset,
+                size: shstrtab.len(),
+                align: 1,
+                ..SectionHeader::default()
+            },
+            // Marks the stack as non-executable.
+            SectionHeader {
+                name: names[5],
+                kind: SHT_PROGBITS,
+                offset: section_headers_offset,
+                align: 1,
+                ..SectionHeader::default()
+            },
+        ];
+        for header in &headers {
+            header.write(&mut result);
+        }
+        result
+    }
+
+    /// Writes `.symtab` and `.strtab` sections. Returns them together with
+    /// the final indexes of the symbols and the index of the first global symbol.
+    fn write_symbols(&self) -> (Vec<u8>, Vec<u8>, Vec<u32>, usize) {
+        // ELF requires local symbols to precede all the others. The symbol at
+        // index 0 is the null symbol, followed by the `.text` section symbol.
+        let mut order: Vec<usize> = (0..self.symbols.len()).collect();
+        order.sort_by_key(|idx| self.symbols[*idx].binding != SymbolBinding::Local);
+        let mut symbol_indexes = alloc::vec![0u32; self.symbols.len()];
+        for (position, idx) in order.iter().enumerate() {
+            symbol_indexes[*idx] = to_u32(position + 2);
+        }
+        let first_global = order
+            .iter()
+            .position(|idx| self.symbols[*idx].binding != SymbolBinding::Local)
+            .unwrap_or(order.len())
+            + 2;
+
+        let mut strtab = alloc::vec![0u8];
+        let mut symtab = alloc::vec![0u8; 2 * SYMBOL_SIZE];
+        write_symbol(&mut symtab[SYMBOL_SIZE..], 0, STT_SECTION, SECTION_TEXT, 0, 0);
+        for idx in &order {
+            let symbol = &self.symbols[*idx];
+            let name = to_u32(strtab.len());
+            strtab.extend_from_slice(symbol.name.as_bytes());
+            strtab.push(0);
+            let info = ((symbol.binding as u8) << 4) | symbol.kind as u8;
+            let (section, offset, size) = match symbol.definition {
+                Some((offset, size)) => (SECTION_TEXT, offset, size),
+                None => (0, 0, 0),
+            };
+            let start = symtab.len();
+            symtab.resize(start + SYMBOL_SIZE, 0);
+            write_symbol(&mut symtab[start..], name, info, section, offset, size);
+        }
+        (symtab, strtab, symbol_indexes, first_global)
+    }
+
+    /// Writes `.rela.text` section.
+    fn write_relocations(&self, symbol_indexes: &[u32]) -> Vec<u8> {
+        let mut rela = Vec::with_capacity(self.relocations.len() * RELA_SIZE);
+        for relocation in &self.relocations {
+            let info = (u64::from(symbol_indexes[relocation.symbol.index()]) << 32) | relocation.kind as u64;
+            rela.extend_from_slice(&to_u64(relocation.offset).to_le_bytes());
+            rela.extend_from_slice(&info.to_le_bytes());
+            rela.extend_from_slice(&relocation.addend.to_le_bytes());
+        }
+        rela
+    }
+
+    fn add_symbol(&mut self, symbol: Symbol) -> Result<SymbolId, ElfError> {
+        if symbol.name.is_empty() || symbol.name.contains('\0') {
+            return Err(ElfError::InvalidSymbolName);
+        }
+        if self.symbol(&symbol.name).is_some() {
+            return Err(ElfError::DuplicateSymbol);
+        }
+        self.symbols.push(symbol);
+        Ok(symbol_id(self.symbols.len() - 1))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: usize,
+    size: usize,
+    link: u32,
+    info: u32,
+    align: usize,
+    entry_size: usize,
+}
+
+impl SectionHeader {
+    fn write(&self, output: &mut Vec<u8>) {
+        output.extend_from_slice(&self.name.to_le_bytes());
+        output.extend_from_slice(&self.kind.to_le_bytes());
+        output.extend_from_slice(&self.flags.to_le_bytes());
+        output.extend_from_slice(&0u64.to_le_bytes()); // sh_addr
+        output.extend_from_slice(&to_u64(self.offset).to_le_bytes());
+        output.extend_from_slice(&to_u64(self.size).to_le_bytes());
+        output.extend_from_slice(&self.link.to_le_bytes());
+        output.extend_from_slice(&self.info.to_le_bytes());
+        output.extend_from_slice(&to_u64(self.align).to_le_bytes());
+        output.extend_from_slice(&to_u64(self.entry_size).to_le_bytes());
+    }
+}
+
+fn write_elf_header(output: &mut [u8], section_headers_offset: usize) {
+    const ET_REL: u16 = 1;
+    const EM_X86_64: u16 = 62;
+    let mut header = Vec::with_capacity(ELF_HEADER_SIZE);
+    // e_ident: magic, 64-bit, little endian, version 1, System V ABI.
+    header.extend_from_slice(&[0x7F, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    header.extend_from_slice(&ET_REL.to_le_bytes());
+    header.extend_from_slice(&EM_X86_64.to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes()); // e_version
+    header.extend_from_slice(&0u64.to_le_bytes()); // e_entry
+    header.extend_from_slice(&0u64.to_le_bytes()); // e_phoff
+    header.extend_from_slice(&to_u64(section_headers_offset).to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes()); // e_flags
+    #[allow(clippy::cast_possible_truncation)]
+    {
+        header.extend_from_slice(&(ELF_HEADER_SIZE as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // e_phentsize
+        header.extend_from_slice(&0u16.to_le_bytes()); // e_phnum
+        header.extend_from_slice(&(SECTION_HEADER_SIZE as u16).to_le_bytes());
+    }
+    header.extend_from_slice(&SECTIONS_COUNT.to_le_bytes());
+    header.extend_from_slice(&SECTION_SHSTRTAB.to_le_bytes());
+    output[..ELF_HEADER_SIZE].copy_from_slice(&header);
+}
+
+fn write_symbol(output: &mut [u8], name: u32, info: u8, section: u16, offset: usize, size: usize) {
+    output[0..4].copy_from_slice(&name.to_le_bytes());
+    output[4] = info;
+    output[5] = 0; // st_other, i.e. default visibility
+    output[6..8].copy_from_slice(&section.to_le_bytes());
+    output[8..16].copy_from_slice(&to_u64(offset).to_le_bytes());
+    output[16..24].copy_from_slice(&to_u64(size).to_le_bytes());
+}
+
+/// Pads `output` to `align` and appends `data`. Returns the offset of `data`.
+fn append_aligned(output: &mut Vec<u8>, data: &[u8], align: usize) -> usize {
+    output.resize(output.len().next_multiple_of(align), 0);
+    let offset = output.len();
+    output.extend_from_slice(data);
+    offset
+}
+
+#[inline]
+fn symbol_id(index: usize) -> SymbolId {
+    SymbolId::from_index(to_u32(index))
+}
+
+#[inline]
+fn to_u32(value: usize) -> u32 {
+    u32::try_from(value).expect("Value exceeds u32.")
+}
+
+#[inline]
+fn to_u64(value: usize) -> u64 {
+    value as u64
+}
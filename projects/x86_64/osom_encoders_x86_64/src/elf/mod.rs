@@ -0,0 +1,44 @@
+//! This module contains the [`ElfWriter`], which produces ELF64 relocatable
+//! object files (`.o`) out of the assembled code, ready to be linked with
+//! the system linker.
+//!
+//! # Examples
+//!
+//! ```
+//! use osom_encoders_x86_64::assembler::Assembler;
+//! use osom_encoders_x86_64::elf::{ElfWriter, RelocationKind, SymbolBinding, SymbolKind};
+//! use osom_encoders_x86_64::encoders::ret;
+//!
+//! let mut assembler = Assembler::new();
+//! let function = assembler.create_label();
+//! let external = assembler.create_label();
+//! assembler.bind_label(function).unwrap();
+//! assembler.call(external);
+//! assembler.emit(unsafe { ret::encode() });
+//! let code = assembler.finalize_relocatable().unwrap();
+//!
+//! let mut writer = ElfWriter::new(code.code().to_vec());
+//! let position = code.label_position(function).unwrap();
+//! writer
+//!     .define_symbol("function", position, code.code().len(), SymbolKind::Function, SymbolBinding::Global)
+//!     .unwrap();
+//! let external_symbol = writer.declare_external("external").unwrap();
+//! for reference in code.references() {
+//!     writer.add_label_reference(reference, external_symbol, RelocationKind::PLT32).unwrap();
+//! }
+//!
+//! let object = writer.write();
+//! assert_eq!(&object[..4], b"\x7FELF");
+//! ```
+
+mod elf_error;
+pub use elf_error::*;
+
+mod symbol;
+pub use symbol::*;
+
+mod relocation;
+pub use relocation::*;
+
+mod elf_writer;
+pub use elf_writer::*;
@@ -0,0 +1,29 @@
+/// Represents the kind of a relocation, i.e. how the address of the symbol
+/// is stored in the code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[must_use]
+pub enum RelocationKind {
+    /// `R_X86_64_64`: 64-bit absolute address, e.g. used by `mov reg64, imm64`.
+    Absolute64 = 1,
+
+    /// `R_X86_64_PC32`: 32-bit PC-relative address, e.g. used by
+    /// RIP-relative memory operands.
+    PC32 = 2,
+
+    /// `R_X86_64_PLT32`: 32-bit PC-relative address of the symbol's PLT entry,
+    /// used by `call rel32` and `jmp rel32` to functions.
+    PLT32 = 4,
+}
+
+impl RelocationKind {
+    /// Returns the size in bytes of the relocated value.
+    #[inline]
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Absolute64 => 8,
+            Self::PC32 | Self::PLT32 => 4,
+        }
+    }
+}
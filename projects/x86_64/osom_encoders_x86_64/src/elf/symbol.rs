@@ -0,0 +1,51 @@
+/// Represents a symbol of [`ElfWriter`][super::ElfWriter], either
+/// defined or external.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[must_use]
+pub struct SymbolId {
+    index: u32,
+}
+
+impl SymbolId {
+    #[inline(always)]
+    pub(crate) const fn from_index(index: u32) -> Self {
+        Self { index }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> usize {
+        self.index as usize
+    }
+}
+
+/// Represents the visibility of a symbol to the linker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[must_use]
+pub enum SymbolBinding {
+    /// The symbol is visible only inside the object file.
+    Local = 0,
+
+    /// The symbol is visible to all object files.
+    Global = 1,
+
+    /// Same as `Global`, but can be overridden by other definitions.
+    Weak = 2,
+}
+
+/// Represents the kind of a symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[must_use]
+pub enum SymbolKind {
+    /// The symbol kind is not specified.
+    NoType = 0,
+
+    /// The symbol is a data object, e.g. a constant.
+    Object = 1,
+
+    /// The symbol is a function.
+    Function = 2,
+}
@@ -0,0 +1,389 @@
+//! This module contains the encoders for the `adc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Add with carry 8-bit immediate to AL register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_al_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x14], imm8) }
+}
+
+/// Add with carry 16-bit immediate to AX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ax_imm16(imm16: Immediate16) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm16_oso([0x15], imm16) }
+}
+
+/// Add with carry 32-bit immediate to EAX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_eax_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x15], imm32) }
+}
+
+/// Add with carry 32-bit immediate to RAX register (sign-extended to 64 bits).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rax_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32_rexw([0x15], imm32) }
+}
+
+/// Add with carry 8-bit immediate to 8-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm8_imm8([0x80], 0x02, rm8, imm8) }
+}
+
+/// Add with carry 8-bit immediate to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm8_imm8(
+            [0x80],
+            0x02,
+            rm8,
+            imm8,
+        ))
+    }
+}
+
+/// Add with carry 16-bit immediate to 16-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm16_imm16([0x81], 0x02, rm16, imm16) }
+}
+
+/// Add with carry 16-bit immediate to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm16_imm16(
+            [0x81],
+            0x02,
+            rm16,
+            imm16,
+        ))
+    }
+}
+
+/// Add with carry 32-bit immediate to 32-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm32_imm32([0x81], 0x02, rm32, imm32) }
+}
+
+/// Add with carry 32-bit immediate to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm32_imm32(
+            [0x81],
+            0x02,
+            rm32,
+            imm32,
+        ))
+    }
+}
+
+/// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm64_imm32([0x81], 0x02, rm64, imm32) }
+}
+
+/// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm64_imm32(
+            [0x81],
+            0x02,
+            rm64,
+            imm32,
+        ))
+    }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm16_imm8([0x83], 0x02, rm16, imm8) }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm16_imm8(
+            [0x83],
+            0x02,
+            rm16,
+            imm8,
+        ))
+    }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm32_imm8([0x83], 0x02, rm32, imm8) }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm32_imm8(
+            [0x83],
+            0x02,
+            rm32,
+            imm8,
+        ))
+    }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm64_imm8([0x83], 0x02, rm64, imm8) }
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm64_imm8(
+            [0x83],
+            0x02,
+            rm64,
+            imm8,
+        ))
+    }
+}
+
+/// Add with carry 8-bit register to 8-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x10], rm8, reg8) }
+}
+
+/// Add with carry 8-bit register to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x10], rm8, reg8)) }
+}
+
+/// Add with carry 16-bit register to 16-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x11], rm16, reg16) }
+}
+
+/// Add with carry 16-bit register to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x11], rm16, reg16)) }
+}
+
+/// Add with carry 32-bit register to 32-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x11], rm32, reg32) }
+}
+
+/// Add with carry 32-bit register to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x11], rm32, reg32)) }
+}
+
+/// Add with carry 64-bit register to 64-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x11], rm64, reg64) }
+}
+
+/// Add with carry 64-bit register to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x11], rm64, reg64)) }
+}
+
+/// Add with carry 8-bit register or memory to 8-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg8_rm8(reg8: GPR, rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x12], rm8, reg8) }
+}
+
+/// Add with carry 16-bit register or memory to 16-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x13], rm16, reg16) }
+}
+
+/// Add with carry 32-bit register or memory to 32-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x13], rm32, reg32) }
+}
+
+/// Add with carry 64-bit register or memory to 64-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x13], rm64, reg64) }
+}
@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `adcx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Unsigned add 32-bit register or memory with carry flag into 32-bit register, affecting only carry flag.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode_with_mandatory_prefix([0x0F, 0x38, 0xF6], 0x66, rm32, reg32) }
+}
+
+/// Unsigned add 64-bit register or memory with carry flag into 64-bit register, affecting only carry flag.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode_with_mandatory_prefix([0x0F, 0x38, 0xF6], 0x66, rm64, reg64) }
+}
@@ -0,0 +1,21 @@
+//! This module contains the encoders for the `addpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Add packed double-precision values in XMM register or 128-bit memory to XMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_xmmm128(xmm: XMM, xmmm128: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_xmm_xmm_or_memory([0x0F, 0x58], 0x66, false, xmm, xmmm128) }
+}
@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `andn` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Bitwise AND of inverted 32-bit VEX register with 32-bit register or memory into 32-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_vreg32_rm32(reg32: GPR, vreg32: GPR, rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF2], 0x00, false, reg32, vreg32, rm32) }
+}
+
+/// Bitwise AND of inverted 64-bit VEX register with 64-bit register or memory into 64-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_vreg64_rm64(reg64: GPR, vreg64: GPR, rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF2], 0x00, true, reg64, vreg64, rm64) }
+}
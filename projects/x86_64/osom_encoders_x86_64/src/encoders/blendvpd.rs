@@ -0,0 +1,21 @@
+//! This module contains the encoders for the `blendvpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Select packed double-precision values from XMM register and XMM register or 128-bit memory, as specified by the sign bits in implicit XMM0 register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_xmmm128_xmm0(xmm: XMM, xmmm128: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_xmm_xmm_or_memory([0x0F, 0x38, 0x15], 0x66, false, xmm, xmmm128) }
+}
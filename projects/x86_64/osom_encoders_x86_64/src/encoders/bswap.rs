@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `bswap` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Reverse the byte order of 32-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32(reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::o::encode([0x0F, 0xC8], reg32) }
+}
+
+/// Reverse the byte order of 64-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64(reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::o::encode_rexw([0x0F, 0xC8], reg64) }
+}
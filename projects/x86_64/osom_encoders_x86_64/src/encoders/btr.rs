@@ -0,0 +1,169 @@
+//! This module contains the encoders for the `btr` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xB3], rm16, reg16) }
+}
+
+/// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x0F, 0xB3], rm16, reg16))
+    }
+}
+
+/// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xB3], rm32, reg32) }
+}
+
+/// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x0F, 0xB3], rm32, reg32))
+    }
+}
+
+/// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xB3], rm64, reg64) }
+}
+
+/// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x0F, 0xB3], rm64, reg64))
+    }
+}
+
+/// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm16_imm8([0x0F, 0xBA], 0x06, rm16, imm8) }
+}
+
+/// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm16_imm8(
+            [0x0F, 0xBA],
+            0x06,
+            rm16,
+            imm8,
+        ))
+    }
+}
+
+/// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm32_imm8([0x0F, 0xBA], 0x06, rm32, imm8) }
+}
+
+/// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm32_imm8(
+            [0x0F, 0xBA],
+            0x06,
+            rm32,
+            imm8,
+        ))
+    }
+}
+
+/// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and clear it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mi::encode_rm64_imm8([0x0F, 0xBA], 0x06, rm64, imm8) }
+}
+
+/// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and clear it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm64_imm8(
+            [0x0F, 0xBA],
+            0x06,
+            rm64,
+            imm8,
+        ))
+    }
+}
@@ -4,8 +4,9 @@
 // ** This file is automatically generated from x86.yaml schema. Do not modify! **
 
 use crate::models::{
-    EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Memory, Offset,
-    Scale, Size,
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
 };
 
 /// Compare 8-bit immediate with AL register.
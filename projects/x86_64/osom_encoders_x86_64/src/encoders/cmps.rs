@@ -0,0 +1,130 @@
+//! This module contains the encoders for the `cmps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare byte at address RSI with byte at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xA6]) }
+}
+
+/// Compare word at address RSI with word at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0x00, true, false) }
+}
+
+/// Compare doubleword at address RSI with doubleword at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xA7]) }
+}
+
+/// Compare quadword at address RSI with quadword at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0x00, false, true) }
+}
+
+/// Find non-matching bytes in RCX bytes at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA6], 0xF3, false, false) }
+}
+
+/// Find non-matching words in RCX words at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF3, true, false) }
+}
+
+/// Find non-matching doublewords in RCX doublewords at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF3, false, false) }
+}
+
+/// Find non-matching quadwords in RCX quadwords at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF3, false, true) }
+}
+
+/// Find matching bytes in RCX bytes at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA6], 0xF2, false, false) }
+}
+
+/// Find matching words in RCX words at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF2, true, false) }
+}
+
+/// Find matching doublewords in RCX doublewords at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF2, false, false) }
+}
+
+/// Find matching quadwords in RCX quadwords at addresses RSI and RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA7], 0xF2, false, true) }
+}
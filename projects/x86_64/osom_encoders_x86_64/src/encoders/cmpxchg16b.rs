@@ -0,0 +1,39 @@
+//! This module contains the encoders for the `cmpxchg16b` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_mem128(mem128: Memory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_memory([0x0F, 0xC7], 0x01, mem128, true) }
+}
+
+/// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_mem128(mem128: Memory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_memory(
+            [0x0F, 0xC7],
+            0x01,
+            mem128,
+            true,
+        ))
+    }
+}
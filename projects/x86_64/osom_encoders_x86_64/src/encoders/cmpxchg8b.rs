@@ -0,0 +1,39 @@
+//! This module contains the encoders for the `cmpxchg8b` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_mem64(mem64: Memory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_memory([0x0F, 0xC7], 0x01, mem64, false) }
+}
+
+/// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_mem64(mem64: Memory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_memory(
+            [0x0F, 0xC7],
+            0x01,
+            mem64,
+            false,
+        ))
+    }
+}
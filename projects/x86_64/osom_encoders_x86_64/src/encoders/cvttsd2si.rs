@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `cvttsd2si` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Convert scalar double-precision value in XMM register or 64-bit memory into signed 32-bit register, with truncation.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_xmmm64(reg32: GPR, xmmm64: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_gpr_xmm_or_memory([0x0F, 0x2C], 0xF2, false, reg32, xmmm64) }
+}
+
+/// Convert scalar double-precision value in XMM register or 64-bit memory into signed 64-bit register, with truncation.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_xmmm64(reg64: GPR, xmmm64: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_gpr_xmm_or_memory([0x0F, 0x2C], 0xF2, true, reg64, xmmm64) }
+}
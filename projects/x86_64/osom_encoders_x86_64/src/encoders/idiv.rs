@@ -0,0 +1,54 @@
+//! This module contains the encoders for the `idiv` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Signed divide AX by 8-bit register or memory (AL = quotient, AH = remainder).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8(rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF6], 0x07, rm8, Size::Bit8, false, false) }
+}
+
+/// Signed divide DX:AX by 16-bit register or memory (AX = quotient, DX = remainder).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16(rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x07, rm16, Size::Bit16, false, true) }
+}
+
+/// Signed divide EDX:EAX by 32-bit register or memory (EAX = quotient, EDX = remainder).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32(rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x07, rm32, Size::Bit32, false, false) }
+}
+
+/// Signed divide RDX:RAX by 64-bit register or memory (RAX = quotient, RDX = remainder).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64(rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x07, rm64, Size::Bit64, true, false) }
+}
@@ -0,0 +1,165 @@
+//! This module contains the encoders for the `imul` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Signed multiply AL by 8-bit register or memory (AX = AL * r/m8).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8(rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF6], 0x05, rm8, Size::Bit8, false, false) }
+}
+
+/// Signed multiply AX by 16-bit register or memory (DX:AX = AX * r/m16).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16(rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x05, rm16, Size::Bit16, false, true) }
+}
+
+/// Signed multiply EAX by 32-bit register or memory (EDX:EAX = EAX * r/m32).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32(rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x05, rm32, Size::Bit32, false, false) }
+}
+
+/// Signed multiply RAX by 64-bit register or memory (RDX:RAX = RAX * r/m64).
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64(rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xF7], 0x05, rm64, Size::Bit64, true, false) }
+}
+
+/// Signed multiply 16-bit register by 16-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xAF], rm16, reg16) }
+}
+
+/// Signed multiply 32-bit register by 32-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xAF], rm32, reg32) }
+}
+
+/// Signed multiply 64-bit register by 64-bit register or memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::mr::encode([0x0F, 0xAF], rm64, reg64) }
+}
+
+/// Signed multiply 16-bit register or memory by 8-bit immediate (sign-extended), storing the result in 16-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg16_rm16_imm8(reg16: GPR, rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm8([0x6B], reg16, rm16, imm8) }
+}
+
+/// Signed multiply 32-bit register or memory by 8-bit immediate (sign-extended), storing the result in 32-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32_imm8(reg32: GPR, rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm8([0x6B], reg32, rm32, imm8) }
+}
+
+/// Signed multiply 64-bit register or memory by 8-bit immediate (sign-extended), storing the result in 64-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64_imm8(reg64: GPR, rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm8([0x6B], reg64, rm64, imm8) }
+}
+
+/// Signed multiply 16-bit register or memory by 16-bit immediate, storing the result in 16-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg16_rm16_imm16(
+    reg16: GPR,
+    rm16: GPROrMemory,
+    imm16: Immediate16,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm16([0x69], reg16, rm16, imm16) }
+}
+
+/// Signed multiply 32-bit register or memory by 32-bit immediate, storing the result in 32-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32_imm32(
+    reg32: GPR,
+    rm32: GPROrMemory,
+    imm32: Immediate32,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm32([0x69], reg32, rm32, imm32) }
+}
+
+/// Signed multiply 64-bit register or memory by 32-bit immediate (sign-extended), storing the result in 64-bit register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64_imm32(
+    reg64: GPR,
+    rm64: GPROrMemory,
+    imm32: Immediate32,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::rmi::encode_imm32([0x69], reg64, rm64, imm32) }
+}
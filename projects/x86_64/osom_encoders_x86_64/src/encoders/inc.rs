@@ -0,0 +1,134 @@
+//! This module contains the encoders for the `inc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Increment 8-bit register or memory by 1.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8(rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFE], 0x00, rm8, Size::Bit8, false, false) }
+}
+
+/// Increment 8-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm8(rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_gpr_or_memory(
+            [0xFE],
+            0x00,
+            rm8,
+            Size::Bit8,
+            false,
+            false,
+        ))
+    }
+}
+
+/// Increment 16-bit register or memory by 1.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm16(rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFF], 0x00, rm16, Size::Bit16, false, true) }
+}
+
+/// Increment 16-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16(rm16: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_gpr_or_memory(
+            [0xFF],
+            0x00,
+            rm16,
+            Size::Bit16,
+            false,
+            true,
+        ))
+    }
+}
+
+/// Increment 32-bit register or memory by 1.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm32(rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFF], 0x00, rm32, Size::Bit32, false, false) }
+}
+
+/// Increment 32-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32(rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_gpr_or_memory(
+            [0xFF],
+            0x00,
+            rm32,
+            Size::Bit32,
+            false,
+            false,
+        ))
+    }
+}
+
+/// Increment 64-bit register or memory by 1.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm64(rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFF], 0x00, rm64, Size::Bit64, true, false) }
+}
+
+/// Increment 64-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64(rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::m::encode_gpr_or_memory(
+            [0xFF],
+            0x00,
+            rm64,
+            Size::Bit64,
+            true,
+            false,
+        ))
+    }
+}
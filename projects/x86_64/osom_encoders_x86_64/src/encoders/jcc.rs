@@ -4,33 +4,34 @@
 // ** This file is automatically generated from x86.yaml schema. Do not modify! **
 
 use crate::models::{
-    EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Memory, Offset,
-    Scale, Size,
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
 };
 
-/// Conditional short jump to RIP-relative address. Condition: Above (in unsigned sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_a_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x77], imm8) }
+pub const unsafe fn encode_imm8(condition: Condition, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x70 | condition.code()], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Above (in unsigned sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_a_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x87], imm32) }
+pub const unsafe fn encode_a_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x77], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Above Or Equal (in unsigned sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above Or Equal (in unsigned sense).
 ///
 /// # Safety
 ///
@@ -41,403 +42,425 @@ pub const unsafe fn encode_ae_imm8(imm8: Immediate8) -> EncodedX86_64Instruction
     unsafe { crate::partial_encoders::i::encode_imm8([0x73], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Above Or Equal (in unsigned sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ae_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x83], imm32) }
+pub const unsafe fn encode_b_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x72], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Below (in unsigned sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below Or Equal (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_b_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x72], imm8) }
+pub const unsafe fn encode_be_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x76], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Below (in unsigned sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Equal.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_b_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x82], imm32) }
+pub const unsafe fn encode_e_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x74], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Below Or Equal (in unsigned sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_be_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x76], imm8) }
+pub const unsafe fn encode_g_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7F], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Below Or Equal (in unsigned sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater Or Equal (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_be_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x86], imm32) }
+pub const unsafe fn encode_ge_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7D], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Equal. Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_e_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x74], imm8) }
+pub const unsafe fn encode_l_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7C], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Equal. Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less Or Equal (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_e_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x84], imm32) }
+pub const unsafe fn encode_le_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7E], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Greater (in signed sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Equal.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_g_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7F], imm8) }
+pub const unsafe fn encode_ne_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x75], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Greater (in signed sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Overflow.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_g_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8F], imm32) }
+pub const unsafe fn encode_no_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x71], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Greater Or Equal (in signed sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Parity.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ge_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7D], imm8) }
+pub const unsafe fn encode_np_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7B], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Greater Or Equal (in signed sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Sign.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ge_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8D], imm32) }
+pub const unsafe fn encode_ns_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x79], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Less (in signed sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Overflow.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_l_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7C], imm8) }
+pub const unsafe fn encode_o_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x70], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Less (in signed sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_l_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8C], imm32) }
+pub const unsafe fn encode_p_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7A], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Less Or Equal (in signed sense). Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Even.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_le_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7E], imm8) }
+pub const unsafe fn encode_pe_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7A], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Less Or Equal (in signed sense). Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Odd.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_le_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8E], imm32) }
+pub const unsafe fn encode_po_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x7B], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Not Equal. Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Sign.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ne_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x75], imm8) }
+pub const unsafe fn encode_s_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x78], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Not Equal. Takes 6 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Carry.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ne_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x85], imm32) }
+pub const unsafe fn encode_c_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x72], imm8) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: No Overflow. Takes 2 bytes.
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Carry.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_no_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x71], imm8) }
+pub const unsafe fn encode_nc_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm8([0x73], imm8) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: No Overflow. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_no_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x81], imm32) }
+pub const unsafe fn encode_imm32(condition: Condition, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x80 | condition.code()], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: No Parity. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_np_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7B], imm8) }
+pub const unsafe fn encode_a_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x87], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: No Parity. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above Or Equal (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_np_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8B], imm32) }
+pub const unsafe fn encode_ae_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x83], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: No Sign. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ns_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x79], imm8) }
+pub const unsafe fn encode_b_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x82], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: No Sign. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below Or Equal (in unsigned sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_ns_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x89], imm32) }
+pub const unsafe fn encode_be_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x86], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Overflow. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Equal.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_o_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x70], imm8) }
+pub const unsafe fn encode_e_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x84], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Overflow. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_o_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x80], imm32) }
+pub const unsafe fn encode_g_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8F], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Parity. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater Or Equal (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_p_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7A], imm8) }
+pub const unsafe fn encode_ge_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8D], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Parity. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_p_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8A], imm32) }
+pub const unsafe fn encode_l_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8C], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Parity Even. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less Or Equal (in signed sense).
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_pe_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7A], imm8) }
+pub const unsafe fn encode_le_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8E], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Parity Even. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Equal.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_pe_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8A], imm32) }
+pub const unsafe fn encode_ne_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x85], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Parity Odd. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Overflow.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_po_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x7B], imm8) }
+pub const unsafe fn encode_no_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x81], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Parity Odd. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Parity.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_po_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+pub const unsafe fn encode_np_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
     unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8B], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Sign. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Sign.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_s_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x78], imm8) }
+pub const unsafe fn encode_ns_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x89], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Sign. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Overflow.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_s_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x88], imm32) }
+pub const unsafe fn encode_o_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x80], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Carry. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_c_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x72], imm8) }
+pub const unsafe fn encode_p_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8A], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Carry. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Even.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_c_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x82], imm32) }
+pub const unsafe fn encode_pe_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8A], imm32) }
 }
 
-/// Conditional short jump to RIP-relative address. Condition: Not Carry. Takes 2 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Odd.
 ///
 /// # Safety
 ///
 /// It is the caller's responsibility to ensure that the operands are valid.
 /// Otherwise the behavior is undefined.
 #[inline]
-pub const unsafe fn encode_nc_imm8(imm8: Immediate8) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::i::encode_imm8([0x73], imm8) }
+pub const unsafe fn encode_po_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x8B], imm32) }
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Sign.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_s_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x88], imm32) }
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Carry.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_c_imm32(imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::i::encode_imm32([0x0F, 0x82], imm32) }
 }
 
-/// Conditional long jump to RIP-relative address. Condition: Not Carry. Takes 6 bytes.
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Carry.
 ///
 /// # Safety
 ///
@@ -0,0 +1,21 @@
+//! This module contains the encoders for the `kandnw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Bitwise AND of 16-bit opmask of opmask register and inverted 16-bit opmask of opmask VEX register into opmask register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_vk_kr(k: Opmask, vk: Opmask, kr: Opmask) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_k_k_k([0x0F, 0x42], 0x00, false, true, k, vk, kr) }
+}
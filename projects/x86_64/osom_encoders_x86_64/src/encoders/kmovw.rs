@@ -0,0 +1,54 @@
+//! This module contains the encoders for the `kmovw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Move 16-bit opmask from opmask register or 16-bit memory to opmask register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_km16(k: Opmask, km16: OpmaskOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_k_k_or_memory([0x0F, 0x90], 0x00, false, false, k, km16) }
+}
+
+/// Move 16-bit opmask from opmask register to 16-bit memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_mem16_k(mem16: Memory, k: Opmask) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_k_memory([0x0F, 0x91], 0x00, false, false, k, mem16) }
+}
+
+/// Move 16-bit opmask from 32-bit register to opmask register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_reg32(k: Opmask, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_k_gpr([0x0F, 0x92], 0x00, false, false, k, reg32) }
+}
+
+/// Move 16-bit opmask from opmask register to 32-bit register, zero-extending it.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_kr(reg32: GPR, kr: Opmask) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_gpr_k([0x0F, 0x93], 0x00, false, false, reg32, kr) }
+}
@@ -0,0 +1,21 @@
+//! This module contains the encoders for the `kortestw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Set zero flag if bitwise OR of 16-bit opmasks of both opmask registers is all zeros, and carry flag if it is all ones.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_kr(k: Opmask, kr: Opmask) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_k_k([0x0F, 0x98], 0x00, false, false, k, kr) }
+}
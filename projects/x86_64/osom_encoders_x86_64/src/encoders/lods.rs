@@ -0,0 +1,90 @@
+//! This module contains the encoders for the `lods` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Load byte at address RSI into AL.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAC]) }
+}
+
+/// Load word at address RSI into AX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAD], 0x00, true, false) }
+}
+
+/// Load doubleword at address RSI into EAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAD]) }
+}
+
+/// Load quadword at address RSI into RAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAD], 0x00, false, true) }
+}
+
+/// Load RCX bytes at address RSI into AL.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAC], 0xF3, false, false) }
+}
+
+/// Load RCX words at address RSI into AX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAD], 0xF3, true, false) }
+}
+
+/// Load RCX doublewords at address RSI into EAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAD], 0xF3, false, false) }
+}
+
+/// Load RCX quadwords at address RSI into RAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAD], 0xF3, false, true) }
+}
@@ -0,0 +1,90 @@
+//! This module contains the encoders for the `movs` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Move byte from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xA4]) }
+}
+
+/// Move word from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA5], 0x00, true, false) }
+}
+
+/// Move doubleword from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xA5]) }
+}
+
+/// Move quadword from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA5], 0x00, false, true) }
+}
+
+/// Move RCX bytes from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA4], 0xF3, false, false) }
+}
+
+/// Move RCX words from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA5], 0xF3, true, false) }
+}
+
+/// Move RCX doublewords from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA5], 0xF3, false, false) }
+}
+
+/// Move RCX quadwords from address RSI to address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xA5], 0xF3, false, true) }
+}
@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `mulx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Unsigned multiply of implicit EDX by 32-bit register or memory, high half into 32-bit register and low half into 32-bit VEX register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_vreg32_rm32(reg32: GPR, vreg32: GPR, rm32: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF6], 0xF2, false, reg32, vreg32, rm32) }
+}
+
+/// Unsigned multiply of implicit RDX by 64-bit register or memory, high half into 64-bit register and low half into 64-bit VEX register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_vreg64_rm64(reg64: GPR, vreg64: GPR, rm64: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF6], 0xF2, true, reg64, vreg64, rm64) }
+}
@@ -0,0 +1,23 @@
+//! This module contains the encoders for the `pextrb` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Extract byte selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 8-bit memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_r32m8_xmm_imm8(r32m8: GPROrMemory, xmm: XMM, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::xmm::encode_xmm_gpr_or_memory_imm8([0x0F, 0x3A, 0x14], 0x66, false, xmm, r32m8, imm8)
+    }
+}
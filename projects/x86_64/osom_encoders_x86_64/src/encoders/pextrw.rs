@@ -0,0 +1,34 @@
+//! This module contains the encoders for the `pextrw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Extract word selected by 8-bit immediate from XMM register into 32-bit register, zero extended.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_xmm_imm8(reg32: GPR, xmm: XMM, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_gpr_xmm_imm8([0x0F, 0xC5], 0x66, false, reg32, xmm, imm8) }
+}
+
+/// Extract word selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 16-bit memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_r32m16_xmm_imm8(r32m16: GPROrMemory, xmm: XMM, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::xmm::encode_xmm_gpr_or_memory_imm8([0x0F, 0x3A, 0x15], 0x66, false, xmm, r32m16, imm8)
+    }
+}
@@ -0,0 +1,23 @@
+//! This module contains the encoders for the `pinsrb` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Insert byte from 32-bit register or 8-bit memory into XMM register, at position selected by 8-bit immediate.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_r32m8_imm8(xmm: XMM, r32m8: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::xmm::encode_xmm_gpr_or_memory_imm8([0x0F, 0x3A, 0x20], 0x66, false, xmm, r32m8, imm8)
+    }
+}
@@ -0,0 +1,25 @@
+//! This module contains the encoders for the `pshufd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Shuffle doublewords in XMM register or 128-bit memory into XMM register, as selected by 8-bit immediate.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_xmmm128_imm8(
+    xmm: XMM,
+    xmmm128: XMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::xmm::encode_xmm_xmm_or_memory_imm8([0x0F, 0x70], 0x66, false, xmm, xmmm128, imm8) }
+}
@@ -4,8 +4,9 @@
 // ** This file is automatically generated from x86.yaml schema. Do not modify! **
 
 use crate::models::{
-    EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Memory, Offset,
-    Scale, Size,
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
 };
 
 /// Push 64-bit register or memory onto the stack.
@@ -16,7 +17,7 @@ use crate::models::{
 /// Otherwise the behavior is undefined.
 #[inline]
 pub const unsafe fn encode_rm64(rm64: GPROrMemory) -> EncodedX86_64Instruction {
-    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFF], 0x06, rm64, false, false) }
+    unsafe { crate::partial_encoders::m::encode_gpr_or_memory([0xFF], 0x06, rm64, Size::Bit64, false, false) }
 }
 
 /// Push 64-bit register onto the stack.
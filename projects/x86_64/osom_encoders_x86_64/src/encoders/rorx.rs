@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `rorx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Rotate 32-bit register or memory right by 8-bit immediate into 32-bit register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32_imm8(reg32: GPR, rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rmi([0x0F, 0x3A, 0xF0], 0xF2, false, reg32, rm32, imm8) }
+}
+
+/// Rotate 64-bit register or memory right by 8-bit immediate into 64-bit register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64_imm8(reg64: GPR, rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rmi([0x0F, 0x3A, 0xF0], 0xF2, true, reg64, rm64, imm8) }
+}
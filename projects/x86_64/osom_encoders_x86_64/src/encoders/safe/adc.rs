@@ -0,0 +1,545 @@
+//! This module contains the safe encoders for the `adc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Add with carry 8-bit immediate to AL register.
+///
+/// Safe variant of [`adc::encode_al_imm8`][crate::encoders::adc::encode_al_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_al_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::adc::encode_al_imm8(imm8) })
+}
+
+/// Add with carry 16-bit immediate to AX register.
+///
+/// Safe variant of [`adc::encode_ax_imm16`][crate::encoders::adc::encode_ax_imm16].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ax_imm16(imm16: Immediate16) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::adc::encode_ax_imm16(imm16) })
+}
+
+/// Add with carry 32-bit immediate to EAX register.
+///
+/// Safe variant of [`adc::encode_eax_imm32`][crate::encoders::adc::encode_eax_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_eax_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::adc::encode_eax_imm32(imm32) })
+}
+
+/// Add with carry 32-bit immediate to RAX register (sign-extended to 64 bits).
+///
+/// Safe variant of [`adc::encode_rax_imm32`][crate::encoders::adc::encode_rax_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rax_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::adc::encode_rax_imm32(imm32) })
+}
+
+/// Add with carry 8-bit immediate to 8-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm8_imm8`][crate::encoders::adc::encode_rm8_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm8_imm8(rm8, imm8) })
+}
+
+/// Add with carry 8-bit immediate to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm8_imm8`][crate::encoders::adc::encode_lock_rm8_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm8_imm8(rm8, imm8) })
+}
+
+/// Add with carry 16-bit immediate to 16-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm16_imm16`][crate::encoders::adc::encode_rm16_imm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm16_imm16(rm16, imm16) })
+}
+
+/// Add with carry 16-bit immediate to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm16_imm16`][crate::encoders::adc::encode_lock_rm16_imm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_imm16(
+    rm16: GPROrMemory,
+    imm16: Immediate16,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm16_imm16(rm16, imm16) })
+}
+
+/// Add with carry 32-bit immediate to 32-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm32_imm32`][crate::encoders::adc::encode_rm32_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm32_imm32(rm32, imm32) })
+}
+
+/// Add with carry 32-bit immediate to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm32_imm32`][crate::encoders::adc::encode_lock_rm32_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_imm32(
+    rm32: GPROrMemory,
+    imm32: Immediate32,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm32_imm32(rm32, imm32) })
+}
+
+/// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits).
+///
+/// Safe variant of [`adc::encode_rm64_imm32`][crate::encoders::adc::encode_rm64_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm64_imm32(rm64, imm32) })
+}
+
+/// Add with carry 32-bit immediate to 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm64_imm32`][crate::encoders::adc::encode_lock_rm64_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_imm32(
+    rm64: GPROrMemory,
+    imm32: Immediate32,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm64_imm32(rm64, imm32) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm16_imm8`][crate::encoders::adc::encode_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm16_imm8(rm16, imm8) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm16_imm8`][crate::encoders::adc::encode_lock_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_imm8(
+    rm16: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm16_imm8(rm16, imm8) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm32_imm8`][crate::encoders::adc::encode_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm32_imm8(rm32, imm8) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm32_imm8`][crate::encoders::adc::encode_lock_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_imm8(
+    rm32: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm32_imm8(rm32, imm8) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm64_imm8`][crate::encoders::adc::encode_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm64_imm8(rm64, imm8) })
+}
+
+/// Add with carry 8-bit immediate (sign-extended) to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm64_imm8`][crate::encoders::adc::encode_lock_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_imm8(
+    rm64: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm64_imm8(rm64, imm8) })
+}
+
+/// Add with carry 8-bit register to 8-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm8_reg8`][crate::encoders::adc::encode_rm8_reg8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm8_reg8(rm8, reg8) })
+}
+
+/// Add with carry 8-bit register to 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm8_reg8`][crate::encoders::adc::encode_lock_rm8_reg8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm8_reg8(rm8, reg8) })
+}
+
+/// Add with carry 16-bit register to 16-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm16_reg16`][crate::encoders::adc::encode_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm16_reg16(rm16, reg16) })
+}
+
+/// Add with carry 16-bit register to 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm16_reg16`][crate::encoders::adc::encode_lock_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm16_reg16(rm16, reg16) })
+}
+
+/// Add with carry 32-bit register to 32-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm32_reg32`][crate::encoders::adc::encode_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm32_reg32(rm32, reg32) })
+}
+
+/// Add with carry 32-bit register to 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm32_reg32`][crate::encoders::adc::encode_lock_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm32_reg32(rm32, reg32) })
+}
+
+/// Add with carry 64-bit register to 64-bit register or memory.
+///
+/// Safe variant of [`adc::encode_rm64_reg64`][crate::encoders::adc::encode_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_rm64_reg64(rm64, reg64) })
+}
+
+/// Add with carry 64-bit register to 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`adc::encode_lock_rm64_reg64`][crate::encoders::adc::encode_lock_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_lock_rm64_reg64(rm64, reg64) })
+}
+
+/// Add with carry 8-bit register or memory to 8-bit register.
+///
+/// Safe variant of [`adc::encode_reg8_rm8`][crate::encoders::adc::encode_reg8_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg8_rm8(reg8: GPR, rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_reg8_rm8(reg8, rm8) })
+}
+
+/// Add with carry 16-bit register or memory to 16-bit register.
+///
+/// Safe variant of [`adc::encode_reg16_rm16`][crate::encoders::adc::encode_reg16_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_reg16_rm16(reg16, rm16) })
+}
+
+/// Add with carry 32-bit register or memory to 32-bit register.
+///
+/// Safe variant of [`adc::encode_reg32_rm32`][crate::encoders::adc::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_reg32_rm32(reg32, rm32) })
+}
+
+/// Add with carry 64-bit register or memory to 64-bit register.
+///
+/// Safe variant of [`adc::encode_reg64_rm64`][crate::encoders::adc::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adc::encode_reg64_rm64(reg64, rm64) })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `adcx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Unsigned add 32-bit register or memory with carry flag into 32-bit register, affecting only carry flag.
+///
+/// Safe variant of [`adcx::encode_reg32_rm32`][crate::encoders::adcx::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adcx::encode_reg32_rm32(reg32, rm32) })
+}
+
+/// Unsigned add 64-bit register or memory with carry flag into 64-bit register, affecting only carry flag.
+///
+/// Safe variant of [`adcx::encode_reg64_rm64`][crate::encoders::adcx::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::adcx::encode_reg64_rm64(reg64, rm64) })
+}
@@ -0,0 +1,29 @@
+//! This module contains the safe encoders for the `blendvpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Select packed double-precision values from XMM register and XMM register or 128-bit memory, as specified by the sign bits in implicit XMM0 register.
+///
+/// Safe variant of [`blendvpd::encode_xmm_xmmm128_xmm0`][crate::encoders::blendvpd::encode_xmm_xmmm128_xmm0].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm128_xmm0(xmm: XMM, xmmm128: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::blendvpd::encode_xmm_xmmm128_xmm0(xmm, xmmm128) })
+}
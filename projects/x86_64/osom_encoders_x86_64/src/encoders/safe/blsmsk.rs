@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `blsmsk` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Set all lower bits up to lowest set bit of 32-bit register or memory into 32-bit VEX register.
+///
+/// Safe variant of [`blsmsk::encode_vreg32_rm32`][crate::encoders::blsmsk::encode_vreg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_vreg32_rm32(vreg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(vreg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::blsmsk::encode_vreg32_rm32(vreg32, rm32) })
+}
+
+/// Set all lower bits up to lowest set bit of 64-bit register or memory into 64-bit VEX register.
+///
+/// Safe variant of [`blsmsk::encode_vreg64_rm64`][crate::encoders::blsmsk::encode_vreg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_vreg64_rm64(vreg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(vreg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::blsmsk::encode_vreg64_rm64(vreg64, rm64) })
+}
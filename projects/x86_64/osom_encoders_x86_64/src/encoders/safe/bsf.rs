@@ -0,0 +1,65 @@
+//! This module contains the safe encoders for the `bsf` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Bit scan forward on 16-bit register or memory, i.e. load the index of the least significant set bit into 16-bit register.
+///
+/// Safe variant of [`bsf::encode_reg16_rm16`][crate::encoders::bsf::encode_reg16_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bsf::encode_reg16_rm16(reg16, rm16) })
+}
+
+/// Bit scan forward on 32-bit register or memory, i.e. load the index of the least significant set bit into 32-bit register.
+///
+/// Safe variant of [`bsf::encode_reg32_rm32`][crate::encoders::bsf::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bsf::encode_reg32_rm32(reg32, rm32) })
+}
+
+/// Bit scan forward on 64-bit register or memory, i.e. load the index of the least significant set bit into 64-bit register.
+///
+/// Safe variant of [`bsf::encode_reg64_rm64`][crate::encoders::bsf::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bsf::encode_reg64_rm64(reg64, rm64) })
+}
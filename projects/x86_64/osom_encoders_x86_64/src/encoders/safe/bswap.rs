@@ -0,0 +1,41 @@
+//! This module contains the safe encoders for the `bswap` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Reverse the byte order of 32-bit register.
+///
+/// Safe variant of [`bswap::encode_reg32`][crate::encoders::bswap::encode_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32(reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bswap::encode_reg32(reg32) })
+}
+
+/// Reverse the byte order of 64-bit register.
+///
+/// Safe variant of [`bswap::encode_reg64`][crate::encoders::bswap::encode_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64(reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bswap::encode_reg64(reg64) })
+}
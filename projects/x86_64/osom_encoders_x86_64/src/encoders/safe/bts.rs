@@ -0,0 +1,236 @@
+//! This module contains the safe encoders for the `bts` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm16_reg16`][crate::encoders::bts::encode_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm16_reg16(rm16, reg16) })
+}
+
+/// Store bit of 16-bit register or memory, selected by 16-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm16_reg16`][crate::encoders::bts::encode_lock_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm16_reg16(rm16, reg16) })
+}
+
+/// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm32_reg32`][crate::encoders::bts::encode_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm32_reg32(rm32, reg32) })
+}
+
+/// Store bit of 32-bit register or memory, selected by 32-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm32_reg32`][crate::encoders::bts::encode_lock_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm32_reg32(rm32, reg32) })
+}
+
+/// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm64_reg64`][crate::encoders::bts::encode_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm64_reg64(rm64, reg64) })
+}
+
+/// Store bit of 64-bit register or memory, selected by 64-bit register, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm64_reg64`][crate::encoders::bts::encode_lock_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm64_reg64(rm64, reg64) })
+}
+
+/// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm16_imm8`][crate::encoders::bts::encode_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm16_imm8(rm16, imm8) })
+}
+
+/// Store bit of 16-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm16_imm8`][crate::encoders::bts::encode_lock_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_imm8(
+    rm16: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm16_imm8(rm16, imm8) })
+}
+
+/// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm32_imm8`][crate::encoders::bts::encode_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm32_imm8(rm32, imm8) })
+}
+
+/// Store bit of 32-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm32_imm8`][crate::encoders::bts::encode_lock_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_imm8(
+    rm32: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm32_imm8(rm32, imm8) })
+}
+
+/// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and set it.
+///
+/// Safe variant of [`bts::encode_rm64_imm8`][crate::encoders::bts::encode_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_rm64_imm8(rm64, imm8) })
+}
+
+/// Store bit of 64-bit register or memory, selected by 8-bit immediate, in CF flag and set it. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`bts::encode_lock_rm64_imm8`][crate::encoders::bts::encode_lock_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_imm8(
+    rm64: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::bts::encode_lock_rm64_imm8(rm64, imm8) })
+}
@@ -0,0 +1,38 @@
+//! This module contains the safe encoders for the `call` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Call to RIP-relative address.
+///
+/// Safe variant of [`call::encode_imm32`][crate::encoders::call::encode_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::call::encode_imm32(imm32) })
+}
+
+/// Call to address in 64-bit register or memory.
+///
+/// Safe variant of [`call::encode_rm64`][crate::encoders::call::encode_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::call::encode_rm64(rm64) })
+}
@@ -0,0 +1,77 @@
+//! This module contains the safe encoders for the `cmovcc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move 16-bit register or memory to 16-bit register if condition is met.
+///
+/// Safe variant of [`cmovcc::encode_reg16_rm16`][crate::encoders::cmovcc::encode_reg16_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16(
+    condition: Condition,
+    reg16: GPR,
+    rm16: GPROrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmovcc::encode_reg16_rm16(condition, reg16, rm16) })
+}
+
+/// Move 32-bit register or memory to 32-bit register if condition is met.
+///
+/// Safe variant of [`cmovcc::encode_reg32_rm32`][crate::encoders::cmovcc::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(
+    condition: Condition,
+    reg32: GPR,
+    rm32: GPROrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmovcc::encode_reg32_rm32(condition, reg32, rm32) })
+}
+
+/// Move 64-bit register or memory to 64-bit register if condition is met.
+///
+/// Safe variant of [`cmovcc::encode_reg64_rm64`][crate::encoders::cmovcc::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(
+    condition: Condition,
+    reg64: GPR,
+    rm64: GPROrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmovcc::encode_reg64_rm64(condition, reg64, rm64) })
+}
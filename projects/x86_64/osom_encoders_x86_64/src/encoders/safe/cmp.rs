@@ -0,0 +1,314 @@
+//! This module contains the safe encoders for the `cmp` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare 8-bit immediate with AL register.
+///
+/// Safe variant of [`cmp::encode_al_imm8`][crate::encoders::cmp::encode_al_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_al_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmp::encode_al_imm8(imm8) })
+}
+
+/// Compare 16-bit immediate with AX register.
+///
+/// Safe variant of [`cmp::encode_ax_imm16`][crate::encoders::cmp::encode_ax_imm16].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ax_imm16(imm16: Immediate16) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmp::encode_ax_imm16(imm16) })
+}
+
+/// Compare 32-bit immediate with EAX register.
+///
+/// Safe variant of [`cmp::encode_eax_imm32`][crate::encoders::cmp::encode_eax_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_eax_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmp::encode_eax_imm32(imm32) })
+}
+
+/// Compare 32-bit immediate sign-extended with RAX register.
+///
+/// Safe variant of [`cmp::encode_rax_imm32`][crate::encoders::cmp::encode_rax_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rax_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmp::encode_rax_imm32(imm32) })
+}
+
+/// Compare 8-bit immediate with 8-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm8_imm8`][crate::encoders::cmp::encode_rm8_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm8_imm8(rm8, imm8) })
+}
+
+/// Compare 16-bit immediate with 16-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm16_imm16`][crate::encoders::cmp::encode_rm16_imm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm16_imm16(rm16, imm16) })
+}
+
+/// Compare 32-bit immediate with 32-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm32_imm32`][crate::encoders::cmp::encode_rm32_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm32_imm32(rm32, imm32) })
+}
+
+/// Compare 32-bit immediate with 64-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm64_imm32`][crate::encoders::cmp::encode_rm64_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm64_imm32(rm64, imm32) })
+}
+
+/// Compare 8-bit immediate (sign-extended) with 16-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm16_imm8`][crate::encoders::cmp::encode_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm16_imm8(rm16, imm8) })
+}
+
+/// Compare 8-bit immediate (sign-extended) with 32-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm32_imm8`][crate::encoders::cmp::encode_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm32_imm8(rm32, imm8) })
+}
+
+/// Compare 8-bit immediate (sign-extended) with 64-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm64_imm8`][crate::encoders::cmp::encode_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm64_imm8(rm64, imm8) })
+}
+
+/// Compare 8-bit register with 8-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm8_reg8`][crate::encoders::cmp::encode_rm8_reg8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm8_reg8(rm8, reg8) })
+}
+
+/// Compare 16-bit register with 16-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm16_reg16`][crate::encoders::cmp::encode_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm16_reg16(rm16, reg16) })
+}
+
+/// Compare 32-bit register with 32-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm32_reg32`][crate::encoders::cmp::encode_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm32_reg32(rm32, reg32) })
+}
+
+/// Compare 64-bit register with 64-bit register or memory.
+///
+/// Safe variant of [`cmp::encode_rm64_reg64`][crate::encoders::cmp::encode_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_rm64_reg64(rm64, reg64) })
+}
+
+/// Compare 8-bit register or memory with 8-bit register.
+///
+/// Safe variant of [`cmp::encode_reg8_rm8`][crate::encoders::cmp::encode_reg8_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg8_rm8(reg8: GPR, rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_reg8_rm8(reg8, rm8) })
+}
+
+/// Compare 16-bit register or memory with 16-bit register.
+///
+/// Safe variant of [`cmp::encode_reg16_rm16`][crate::encoders::cmp::encode_reg16_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_reg16_rm16(reg16, rm16) })
+}
+
+/// Compare 32-bit register or memory with 32-bit register.
+///
+/// Safe variant of [`cmp::encode_reg32_rm32`][crate::encoders::cmp::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_reg32_rm32(reg32, rm32) })
+}
+
+/// Compare 64-bit register or memory with 64-bit register.
+///
+/// Safe variant of [`cmp::encode_reg64_rm64`][crate::encoders::cmp::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmp::encode_reg64_rm64(reg64, rm64) })
+}
@@ -0,0 +1,155 @@
+//! This module contains the safe encoders for the `cmps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare byte at address RSI with byte at address RDI and set the status flags.
+///
+/// Safe variant of [`cmps::encode_b`][crate::encoders::cmps::encode_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_b() })
+}
+
+/// Compare word at address RSI with word at address RDI and set the status flags.
+///
+/// Safe variant of [`cmps::encode_w`][crate::encoders::cmps::encode_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_w() })
+}
+
+/// Compare doubleword at address RSI with doubleword at address RDI and set the status flags.
+///
+/// Safe variant of [`cmps::encode_d`][crate::encoders::cmps::encode_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_d() })
+}
+
+/// Compare quadword at address RSI with quadword at address RDI and set the status flags.
+///
+/// Safe variant of [`cmps::encode_q`][crate::encoders::cmps::encode_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_q() })
+}
+
+/// Find non-matching bytes in RCX bytes at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repe_b`][crate::encoders::cmps::encode_repe_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repe_b() })
+}
+
+/// Find non-matching words in RCX words at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repe_w`][crate::encoders::cmps::encode_repe_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repe_w() })
+}
+
+/// Find non-matching doublewords in RCX doublewords at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repe_d`][crate::encoders::cmps::encode_repe_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repe_d() })
+}
+
+/// Find non-matching quadwords in RCX quadwords at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repe_q`][crate::encoders::cmps::encode_repe_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repe_q() })
+}
+
+/// Find matching bytes in RCX bytes at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repne_b`][crate::encoders::cmps::encode_repne_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repne_b() })
+}
+
+/// Find matching words in RCX words at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repne_w`][crate::encoders::cmps::encode_repne_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repne_w() })
+}
+
+/// Find matching doublewords in RCX doublewords at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repne_d`][crate::encoders::cmps::encode_repne_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repne_d() })
+}
+
+/// Find matching quadwords in RCX quadwords at addresses RSI and RDI.
+///
+/// Safe variant of [`cmps::encode_repne_q`][crate::encoders::cmps::encode_repne_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::cmps::encode_repne_q() })
+}
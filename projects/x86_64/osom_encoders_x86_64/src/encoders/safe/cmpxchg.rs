@@ -0,0 +1,173 @@
+//! This module contains the safe encoders for the `cmpxchg` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare AL with 8-bit register or memory. If equal, load 8-bit register into register or memory, otherwise load register or memory into AL.
+///
+/// Safe variant of [`cmpxchg::encode_rm8_reg8`][crate::encoders::cmpxchg::encode_rm8_reg8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_rm8_reg8(rm8, reg8) })
+}
+
+/// Compare AL with 8-bit register or memory. If equal, load 8-bit register into register or memory, otherwise load register or memory into AL. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg::encode_lock_rm8_reg8`][crate::encoders::cmpxchg::encode_lock_rm8_reg8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg8, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_lock_rm8_reg8(rm8, reg8) })
+}
+
+/// Compare AX with 16-bit register or memory. If equal, load 16-bit register into register or memory, otherwise load register or memory into AX.
+///
+/// Safe variant of [`cmpxchg::encode_rm16_reg16`][crate::encoders::cmpxchg::encode_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_rm16_reg16(rm16, reg16) })
+}
+
+/// Compare AX with 16-bit register or memory. If equal, load 16-bit register into register or memory, otherwise load register or memory into AX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg::encode_lock_rm16_reg16`][crate::encoders::cmpxchg::encode_lock_rm16_reg16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_lock_rm16_reg16(rm16, reg16) })
+}
+
+/// Compare EAX with 32-bit register or memory. If equal, load 32-bit register into register or memory, otherwise load register or memory into EAX.
+///
+/// Safe variant of [`cmpxchg::encode_rm32_reg32`][crate::encoders::cmpxchg::encode_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_rm32_reg32(rm32, reg32) })
+}
+
+/// Compare EAX with 32-bit register or memory. If equal, load 32-bit register into register or memory, otherwise load register or memory into EAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg::encode_lock_rm32_reg32`][crate::encoders::cmpxchg::encode_lock_rm32_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_lock_rm32_reg32(rm32, reg32) })
+}
+
+/// Compare RAX with 64-bit register or memory. If equal, load 64-bit register into register or memory, otherwise load register or memory into RAX.
+///
+/// Safe variant of [`cmpxchg::encode_rm64_reg64`][crate::encoders::cmpxchg::encode_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_rm64_reg64(rm64, reg64) })
+}
+
+/// Compare RAX with 64-bit register or memory. If equal, load 64-bit register into register or memory, otherwise load register or memory into RAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg::encode_lock_rm64_reg64`][crate::encoders::cmpxchg::encode_lock_rm64_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg::encode_lock_rm64_reg64(rm64, reg64) })
+}
@@ -0,0 +1,41 @@
+//! This module contains the safe encoders for the `cmpxchg16b` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX.
+///
+/// Safe variant of [`cmpxchg16b::encode_mem128`][crate::encoders::cmpxchg16b::encode_mem128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_mem128(mem128: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_memory(mem128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg16b::encode_mem128(mem128) })
+}
+
+/// Compare RDX:RAX with 128-bit memory (16-byte aligned). If equal, load RCX:RBX into memory, otherwise load memory into RDX:RAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg16b::encode_lock_mem128`][crate::encoders::cmpxchg16b::encode_lock_mem128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_mem128(mem128: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_memory(mem128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg16b::encode_lock_mem128(mem128) })
+}
@@ -0,0 +1,41 @@
+//! This module contains the safe encoders for the `cmpxchg8b` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX.
+///
+/// Safe variant of [`cmpxchg8b::encode_mem64`][crate::encoders::cmpxchg8b::encode_mem64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_mem64(mem64: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_memory(mem64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg8b::encode_mem64(mem64) })
+}
+
+/// Compare EDX:EAX with 64-bit memory. If equal, load ECX:EBX into memory, otherwise load memory into EDX:EAX. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`cmpxchg8b::encode_lock_mem64`][crate::encoders::cmpxchg8b::encode_lock_mem64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_mem64(mem64: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_memory(mem64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cmpxchg8b::encode_lock_mem64(mem64) })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `cvttsd2si` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Convert scalar double-precision value in XMM register or 64-bit memory into signed 32-bit register, with truncation.
+///
+/// Safe variant of [`cvttsd2si::encode_reg32_xmmm64`][crate::encoders::cvttsd2si::encode_reg32_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_xmmm64(reg32: GPR, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cvttsd2si::encode_reg32_xmmm64(reg32, xmmm64) })
+}
+
+/// Convert scalar double-precision value in XMM register or 64-bit memory into signed 64-bit register, with truncation.
+///
+/// Safe variant of [`cvttsd2si::encode_reg64_xmmm64`][crate::encoders::cvttsd2si::encode_reg64_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_xmmm64(reg64: GPR, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::cvttsd2si::encode_reg64_xmmm64(reg64, xmmm64) })
+}
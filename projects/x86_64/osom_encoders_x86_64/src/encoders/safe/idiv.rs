@@ -0,0 +1,71 @@
+//! This module contains the safe encoders for the `idiv` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Signed divide AX by 8-bit register or memory (AL = quotient, AH = remainder).
+///
+/// Safe variant of [`idiv::encode_rm8`][crate::encoders::idiv::encode_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::idiv::encode_rm8(rm8) })
+}
+
+/// Signed divide DX:AX by 16-bit register or memory (AX = quotient, DX = remainder).
+///
+/// Safe variant of [`idiv::encode_rm16`][crate::encoders::idiv::encode_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::idiv::encode_rm16(rm16) })
+}
+
+/// Signed divide EDX:EAX by 32-bit register or memory (EAX = quotient, EDX = remainder).
+///
+/// Safe variant of [`idiv::encode_rm32`][crate::encoders::idiv::encode_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::idiv::encode_rm32(rm32) })
+}
+
+/// Signed divide RDX:RAX by 64-bit register or memory (RAX = quotient, RDX = remainder).
+///
+/// Safe variant of [`idiv::encode_rm64`][crate::encoders::idiv::encode_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::idiv::encode_rm64(rm64) })
+}
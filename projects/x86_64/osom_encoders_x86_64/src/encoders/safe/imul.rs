@@ -0,0 +1,257 @@
+//! This module contains the safe encoders for the `imul` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Signed multiply AL by 8-bit register or memory (AX = AL * r/m8).
+///
+/// Safe variant of [`imul::encode_rm8`][crate::encoders::imul::encode_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_rm8(rm8) })
+}
+
+/// Signed multiply AX by 16-bit register or memory (DX:AX = AX * r/m16).
+///
+/// Safe variant of [`imul::encode_rm16`][crate::encoders::imul::encode_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_rm16(rm16) })
+}
+
+/// Signed multiply EAX by 32-bit register or memory (EDX:EAX = EAX * r/m32).
+///
+/// Safe variant of [`imul::encode_rm32`][crate::encoders::imul::encode_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_rm32(rm32) })
+}
+
+/// Signed multiply RAX by 64-bit register or memory (RDX:RAX = RAX * r/m64).
+///
+/// Safe variant of [`imul::encode_rm64`][crate::encoders::imul::encode_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_rm64(rm64) })
+}
+
+/// Signed multiply 16-bit register by 16-bit register or memory.
+///
+/// Safe variant of [`imul::encode_reg16_rm16`][crate::encoders::imul::encode_reg16_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16(reg16: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg16_rm16(reg16, rm16) })
+}
+
+/// Signed multiply 32-bit register by 32-bit register or memory.
+///
+/// Safe variant of [`imul::encode_reg32_rm32`][crate::encoders::imul::encode_reg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32(reg32: GPR, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg32_rm32(reg32, rm32) })
+}
+
+/// Signed multiply 64-bit register by 64-bit register or memory.
+///
+/// Safe variant of [`imul::encode_reg64_rm64`][crate::encoders::imul::encode_reg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64(reg64: GPR, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg64_rm64(reg64, rm64) })
+}
+
+/// Signed multiply 16-bit register or memory by 8-bit immediate (sign-extended), storing the result in 16-bit register.
+///
+/// Safe variant of [`imul::encode_reg16_rm16_imm8`][crate::encoders::imul::encode_reg16_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16_imm8(
+    reg16: GPR,
+    rm16: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg16_rm16_imm8(reg16, rm16, imm8) })
+}
+
+/// Signed multiply 32-bit register or memory by 8-bit immediate (sign-extended), storing the result in 32-bit register.
+///
+/// Safe variant of [`imul::encode_reg32_rm32_imm8`][crate::encoders::imul::encode_reg32_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32_imm8(
+    reg32: GPR,
+    rm32: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg32_rm32_imm8(reg32, rm32, imm8) })
+}
+
+/// Signed multiply 64-bit register or memory by 8-bit immediate (sign-extended), storing the result in 64-bit register.
+///
+/// Safe variant of [`imul::encode_reg64_rm64_imm8`][crate::encoders::imul::encode_reg64_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64_imm8(
+    reg64: GPR,
+    rm64: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg64_rm64_imm8(reg64, rm64, imm8) })
+}
+
+/// Signed multiply 16-bit register or memory by 16-bit immediate, storing the result in 16-bit register.
+///
+/// Safe variant of [`imul::encode_reg16_rm16_imm16`][crate::encoders::imul::encode_reg16_rm16_imm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm16_imm16(
+    reg16: GPR,
+    rm16: GPROrMemory,
+    imm16: Immediate16,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg16_rm16_imm16(reg16, rm16, imm16) })
+}
+
+/// Signed multiply 32-bit register or memory by 32-bit immediate, storing the result in 32-bit register.
+///
+/// Safe variant of [`imul::encode_reg32_rm32_imm32`][crate::encoders::imul::encode_reg32_rm32_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32_imm32(
+    reg32: GPR,
+    rm32: GPROrMemory,
+    imm32: Immediate32,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg32_rm32_imm32(reg32, rm32, imm32) })
+}
+
+/// Signed multiply 64-bit register or memory by 32-bit immediate (sign-extended), storing the result in 64-bit register.
+///
+/// Safe variant of [`imul::encode_reg64_rm64_imm32`][crate::encoders::imul::encode_reg64_rm64_imm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64_imm32(
+    reg64: GPR,
+    rm64: GPROrMemory,
+    imm32: Immediate32,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::imul::encode_reg64_rm64_imm32(reg64, rm64, imm32) })
+}
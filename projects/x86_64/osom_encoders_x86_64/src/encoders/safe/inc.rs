@@ -0,0 +1,143 @@
+//! This module contains the safe encoders for the `inc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Increment 8-bit register or memory by 1.
+///
+/// Safe variant of [`inc::encode_rm8`][crate::encoders::inc::encode_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_rm8(rm8) })
+}
+
+/// Increment 8-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`inc::encode_lock_rm8`][crate::encoders::inc::encode_lock_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm8(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_lock_rm8(rm8) })
+}
+
+/// Increment 16-bit register or memory by 1.
+///
+/// Safe variant of [`inc::encode_rm16`][crate::encoders::inc::encode_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_rm16(rm16) })
+}
+
+/// Increment 16-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`inc::encode_lock_rm16`][crate::encoders::inc::encode_lock_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm16(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_lock_rm16(rm16) })
+}
+
+/// Increment 32-bit register or memory by 1.
+///
+/// Safe variant of [`inc::encode_rm32`][crate::encoders::inc::encode_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_rm32(rm32) })
+}
+
+/// Increment 32-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`inc::encode_lock_rm32`][crate::encoders::inc::encode_lock_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm32(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_lock_rm32(rm32) })
+}
+
+/// Increment 64-bit register or memory by 1.
+///
+/// Safe variant of [`inc::encode_rm64`][crate::encoders::inc::encode_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_rm64(rm64) })
+}
+
+/// Increment 64-bit register or memory by 1. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// Safe variant of [`inc::encode_lock_rm64`][crate::encoders::inc::encode_lock_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_lock_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_lock_destination(rm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::inc::encode_lock_rm64(rm64) })
+}
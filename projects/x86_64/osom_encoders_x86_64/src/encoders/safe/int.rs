@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `int` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Generate software interrupt with vector specified by immediate byte.
+///
+/// Safe variant of [`int::encode_imm8`][crate::encoders::int::encode_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::int::encode_imm8(imm8) })
+}
+
+/// Generate debug trap. More or less equivalent to `int 1`.
+///
+/// Safe variant of [`int::encode_1`][crate::encoders::int::encode_1].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_1() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::int::encode_1() })
+}
+
+/// Generate breakpoint trap. More or less equivalent to `int 3`.
+///
+/// Safe variant of [`int::encode_3`][crate::encoders::int::encode_3].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_3() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::int::encode_3() })
+}
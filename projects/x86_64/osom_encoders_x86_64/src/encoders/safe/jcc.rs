@@ -0,0 +1,515 @@
+//! This module contains the safe encoders for the `jcc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes.
+///
+/// Safe variant of [`jcc::encode_imm8`][crate::encoders::jcc::encode_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm8(condition: Condition, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_imm8(condition, imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_a_imm8`][crate::encoders::jcc::encode_a_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_a_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_a_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Above Or Equal (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_ae_imm8`][crate::encoders::jcc::encode_ae_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ae_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ae_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_b_imm8`][crate::encoders::jcc::encode_b_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_b_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Below Or Equal (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_be_imm8`][crate::encoders::jcc::encode_be_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_be_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_be_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Equal.
+///
+/// Safe variant of [`jcc::encode_e_imm8`][crate::encoders::jcc::encode_e_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_e_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_e_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater (in signed sense).
+///
+/// Safe variant of [`jcc::encode_g_imm8`][crate::encoders::jcc::encode_g_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_g_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_g_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Greater Or Equal (in signed sense).
+///
+/// Safe variant of [`jcc::encode_ge_imm8`][crate::encoders::jcc::encode_ge_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ge_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ge_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less (in signed sense).
+///
+/// Safe variant of [`jcc::encode_l_imm8`][crate::encoders::jcc::encode_l_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_l_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_l_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Less Or Equal (in signed sense).
+///
+/// Safe variant of [`jcc::encode_le_imm8`][crate::encoders::jcc::encode_le_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_le_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_le_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Equal.
+///
+/// Safe variant of [`jcc::encode_ne_imm8`][crate::encoders::jcc::encode_ne_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ne_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ne_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Overflow.
+///
+/// Safe variant of [`jcc::encode_no_imm8`][crate::encoders::jcc::encode_no_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_no_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_no_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Parity.
+///
+/// Safe variant of [`jcc::encode_np_imm8`][crate::encoders::jcc::encode_np_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_np_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_np_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: No Sign.
+///
+/// Safe variant of [`jcc::encode_ns_imm8`][crate::encoders::jcc::encode_ns_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ns_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ns_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Overflow.
+///
+/// Safe variant of [`jcc::encode_o_imm8`][crate::encoders::jcc::encode_o_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_o_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_o_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity.
+///
+/// Safe variant of [`jcc::encode_p_imm8`][crate::encoders::jcc::encode_p_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_p_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_p_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Even.
+///
+/// Safe variant of [`jcc::encode_pe_imm8`][crate::encoders::jcc::encode_pe_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_pe_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_pe_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Parity Odd.
+///
+/// Safe variant of [`jcc::encode_po_imm8`][crate::encoders::jcc::encode_po_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_po_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_po_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Sign.
+///
+/// Safe variant of [`jcc::encode_s_imm8`][crate::encoders::jcc::encode_s_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_s_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_s_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Carry.
+///
+/// Safe variant of [`jcc::encode_c_imm8`][crate::encoders::jcc::encode_c_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_c_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_c_imm8(imm8) })
+}
+
+/// Conditional short jump to RIP-relative address. Takes 2 bytes. Condition: Not Carry.
+///
+/// Safe variant of [`jcc::encode_nc_imm8`][crate::encoders::jcc::encode_nc_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_nc_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_nc_imm8(imm8) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes.
+///
+/// Safe variant of [`jcc::encode_imm32`][crate::encoders::jcc::encode_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm32(condition: Condition, imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_imm32(condition, imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_a_imm32`][crate::encoders::jcc::encode_a_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_a_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_a_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Above Or Equal (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_ae_imm32`][crate::encoders::jcc::encode_ae_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ae_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ae_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_b_imm32`][crate::encoders::jcc::encode_b_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_b_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Below Or Equal (in unsigned sense).
+///
+/// Safe variant of [`jcc::encode_be_imm32`][crate::encoders::jcc::encode_be_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_be_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_be_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Equal.
+///
+/// Safe variant of [`jcc::encode_e_imm32`][crate::encoders::jcc::encode_e_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_e_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_e_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater (in signed sense).
+///
+/// Safe variant of [`jcc::encode_g_imm32`][crate::encoders::jcc::encode_g_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_g_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_g_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Greater Or Equal (in signed sense).
+///
+/// Safe variant of [`jcc::encode_ge_imm32`][crate::encoders::jcc::encode_ge_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ge_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ge_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less (in signed sense).
+///
+/// Safe variant of [`jcc::encode_l_imm32`][crate::encoders::jcc::encode_l_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_l_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_l_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Less Or Equal (in signed sense).
+///
+/// Safe variant of [`jcc::encode_le_imm32`][crate::encoders::jcc::encode_le_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_le_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_le_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Equal.
+///
+/// Safe variant of [`jcc::encode_ne_imm32`][crate::encoders::jcc::encode_ne_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ne_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ne_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Overflow.
+///
+/// Safe variant of [`jcc::encode_no_imm32`][crate::encoders::jcc::encode_no_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_no_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_no_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Parity.
+///
+/// Safe variant of [`jcc::encode_np_imm32`][crate::encoders::jcc::encode_np_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_np_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_np_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: No Sign.
+///
+/// Safe variant of [`jcc::encode_ns_imm32`][crate::encoders::jcc::encode_ns_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_ns_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_ns_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Overflow.
+///
+/// Safe variant of [`jcc::encode_o_imm32`][crate::encoders::jcc::encode_o_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_o_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_o_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity.
+///
+/// Safe variant of [`jcc::encode_p_imm32`][crate::encoders::jcc::encode_p_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_p_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_p_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Even.
+///
+/// Safe variant of [`jcc::encode_pe_imm32`][crate::encoders::jcc::encode_pe_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_pe_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_pe_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Parity Odd.
+///
+/// Safe variant of [`jcc::encode_po_imm32`][crate::encoders::jcc::encode_po_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_po_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_po_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Sign.
+///
+/// Safe variant of [`jcc::encode_s_imm32`][crate::encoders::jcc::encode_s_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_s_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_s_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Carry.
+///
+/// Safe variant of [`jcc::encode_c_imm32`][crate::encoders::jcc::encode_c_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_c_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_c_imm32(imm32) })
+}
+
+/// Conditional long jump to RIP-relative address. Takes 6 bytes. Condition: Not Carry.
+///
+/// Safe variant of [`jcc::encode_nc_imm32`][crate::encoders::jcc::encode_nc_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_nc_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::jcc::encode_nc_imm32(imm32) })
+}
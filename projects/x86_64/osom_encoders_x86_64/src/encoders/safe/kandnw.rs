@@ -0,0 +1,23 @@
+//! This module contains the safe encoders for the `kandnw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Bitwise AND of 16-bit opmask of opmask register and inverted 16-bit opmask of opmask VEX register into opmask register.
+///
+/// Safe variant of [`kandnw::encode_k_vk_kr`][crate::encoders::kandnw::encode_k_vk_kr].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_k_vk_kr(k: Opmask, vk: Opmask, kr: Opmask) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::kandnw::encode_k_vk_kr(k, vk, kr) })
+}
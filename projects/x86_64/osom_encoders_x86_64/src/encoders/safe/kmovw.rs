@@ -0,0 +1,71 @@
+//! This module contains the safe encoders for the `kmovw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move 16-bit opmask from opmask register or 16-bit memory to opmask register.
+///
+/// Safe variant of [`kmovw::encode_k_km16`][crate::encoders::kmovw::encode_k_km16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_k_km16(k: Opmask, km16: OpmaskOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_opmask_or_memory(km16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::kmovw::encode_k_km16(k, km16) })
+}
+
+/// Move 16-bit opmask from opmask register to 16-bit memory.
+///
+/// Safe variant of [`kmovw::encode_mem16_k`][crate::encoders::kmovw::encode_mem16_k].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_mem16_k(mem16: Memory, k: Opmask) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_memory(mem16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::kmovw::encode_mem16_k(mem16, k) })
+}
+
+/// Move 16-bit opmask from 32-bit register to opmask register.
+///
+/// Safe variant of [`kmovw::encode_k_reg32`][crate::encoders::kmovw::encode_k_reg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_k_reg32(k: Opmask, reg32: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::kmovw::encode_k_reg32(k, reg32) })
+}
+
+/// Move 16-bit opmask from opmask register to 32-bit register, zero-extending it.
+///
+/// Safe variant of [`kmovw::encode_reg32_kr`][crate::encoders::kmovw::encode_reg32_kr].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_kr(reg32: GPR, kr: Opmask) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::kmovw::encode_reg32_kr(reg32, kr) })
+}
@@ -0,0 +1,23 @@
+//! This module contains the safe encoders for the `kortestw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Set zero flag if bitwise OR of 16-bit opmasks of both opmask registers is all zeros, and carry flag if it is all ones.
+///
+/// Safe variant of [`kortestw::encode_k_kr`][crate::encoders::kortestw::encode_k_kr].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_k_kr(k: Opmask, kr: Opmask) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::kortestw::encode_k_kr(k, kr) })
+}
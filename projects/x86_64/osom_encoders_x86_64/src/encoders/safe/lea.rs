@@ -0,0 +1,65 @@
+//! This module contains the safe encoders for the `lea` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Load effective address from memory into 16-bit register.
+///
+/// Safe variant of [`lea::encode_reg16_mem64`][crate::encoders::lea::encode_reg16_mem64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_mem64(reg16: GPR, mem64: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_memory(mem64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::lea::encode_reg16_mem64(reg16, mem64) })
+}
+
+/// Load effective address from memory into 32-bit register.
+///
+/// Safe variant of [`lea::encode_reg32_mem64`][crate::encoders::lea::encode_reg32_mem64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_mem64(reg32: GPR, mem64: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_memory(mem64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::lea::encode_reg32_mem64(reg32, mem64) })
+}
+
+/// Load effective address from memory into 64-bit register.
+///
+/// Safe variant of [`lea::encode_reg64_mem64`][crate::encoders::lea::encode_reg64_mem64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_mem64(reg64: GPR, mem64: Memory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_memory(mem64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::lea::encode_reg64_mem64(reg64, mem64) })
+}
@@ -0,0 +1,107 @@
+//! This module contains the safe encoders for the `lods` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Load byte at address RSI into AL.
+///
+/// Safe variant of [`lods::encode_b`][crate::encoders::lods::encode_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_b() })
+}
+
+/// Load word at address RSI into AX.
+///
+/// Safe variant of [`lods::encode_w`][crate::encoders::lods::encode_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_w() })
+}
+
+/// Load doubleword at address RSI into EAX.
+///
+/// Safe variant of [`lods::encode_d`][crate::encoders::lods::encode_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_d() })
+}
+
+/// Load quadword at address RSI into RAX.
+///
+/// Safe variant of [`lods::encode_q`][crate::encoders::lods::encode_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_q() })
+}
+
+/// Load RCX bytes at address RSI into AL.
+///
+/// Safe variant of [`lods::encode_rep_b`][crate::encoders::lods::encode_rep_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_rep_b() })
+}
+
+/// Load RCX words at address RSI into AX.
+///
+/// Safe variant of [`lods::encode_rep_w`][crate::encoders::lods::encode_rep_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_rep_w() })
+}
+
+/// Load RCX doublewords at address RSI into EAX.
+///
+/// Safe variant of [`lods::encode_rep_d`][crate::encoders::lods::encode_rep_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_rep_d() })
+}
+
+/// Load RCX quadwords at address RSI into RAX.
+///
+/// Safe variant of [`lods::encode_rep_q`][crate::encoders::lods::encode_rep_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::lods::encode_rep_q() })
+}
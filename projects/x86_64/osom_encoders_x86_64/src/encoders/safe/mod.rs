@@ -0,0 +1,290 @@
+//! This module contains the safe encoders for the `X86_64` instruction set.
+//!
+//! Unlike the encoders in the parent module, these validate their operands
+//! (in release builds as well) and return [`EncodeError`][crate::models::EncodeError]
+//! instead of producing invalid encodings.
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+pub mod adc;
+pub mod adcx;
+pub mod add;
+pub mod addpd;
+pub mod addps;
+pub mod addsd;
+pub mod addss;
+pub mod adox;
+pub mod and;
+pub mod andn;
+pub mod bextr;
+pub mod blendvpd;
+pub mod blendvps;
+pub mod blsi;
+pub mod blsmsk;
+pub mod blsr;
+pub mod bsf;
+pub mod bsr;
+pub mod bswap;
+pub mod bt;
+pub mod btc;
+pub mod btr;
+pub mod bts;
+pub mod bzhi;
+pub mod call;
+pub mod cmovcc;
+pub mod cmp;
+pub mod cmps;
+pub mod cmpxchg;
+pub mod cmpxchg16b;
+pub mod cmpxchg8b;
+pub mod cpuid;
+pub mod cvtsi2sd;
+pub mod cvttsd2si;
+pub mod dec;
+pub mod div;
+pub mod divsd;
+pub mod divss;
+pub mod idiv;
+pub mod imul;
+pub mod inc;
+pub mod int;
+pub mod jcc;
+pub mod jmp;
+pub mod kandnw;
+pub mod kandw;
+pub mod kmovw;
+pub mod knotw;
+pub mod kortestw;
+pub mod korw;
+pub mod kxnorw;
+pub mod kxorw;
+pub mod lea;
+pub mod lock;
+pub mod lods;
+pub mod lzcnt;
+pub mod mov;
+pub mod movaps;
+pub mod movd;
+pub mod movdqa;
+pub mod movdqu;
+pub mod movq;
+pub mod movs;
+pub mod movsd;
+pub mod movss;
+pub mod movsx;
+pub mod movsxd;
+pub mod movups;
+pub mod movzx;
+pub mod mul;
+pub mod mulpd;
+pub mod mulps;
+pub mod mulsd;
+pub mod mulss;
+pub mod mulx;
+pub mod neg;
+pub mod nop;
+pub mod not;
+pub mod or;
+pub mod paddb;
+pub mod paddd;
+pub mod paddq;
+pub mod paddw;
+pub mod pand;
+pub mod pblendvb;
+pub mod pcmpeqb;
+pub mod pcmpeqd;
+pub mod pcmpeqq;
+pub mod pcmpeqw;
+pub mod pcmpgtb;
+pub mod pcmpgtd;
+pub mod pcmpgtq;
+pub mod pcmpgtw;
+pub mod pdep;
+pub mod pext;
+pub mod pextrb;
+pub mod pextrd;
+pub mod pextrq;
+pub mod pextrw;
+pub mod pinsrb;
+pub mod pinsrd;
+pub mod pinsrq;
+pub mod pinsrw;
+pub mod pmovmskb;
+pub mod pop;
+pub mod popcnt;
+pub mod por;
+pub mod pshufb;
+pub mod pshufd;
+pub mod psubb;
+pub mod psubd;
+pub mod psubq;
+pub mod psubw;
+pub mod ptest;
+pub mod punpckhbw;
+pub mod punpckhdq;
+pub mod punpckhqdq;
+pub mod punpckhwd;
+pub mod punpcklbw;
+pub mod punpckldq;
+pub mod punpcklqdq;
+pub mod punpcklwd;
+pub mod push;
+pub mod pxor;
+pub mod rcl;
+pub mod rcr;
+pub mod ret;
+pub mod rol;
+pub mod ror;
+pub mod rorx;
+pub mod sar;
+pub mod sarx;
+pub mod sbb;
+pub mod scas;
+pub mod setcc;
+pub mod shl;
+pub mod shlx;
+pub mod shr;
+pub mod shrx;
+pub mod sqrtsd;
+pub mod sqrtss;
+pub mod stos;
+pub mod sub;
+pub mod subsd;
+pub mod subss;
+pub mod syscall;
+pub mod sysenter;
+pub mod test;
+pub mod tzcnt;
+pub mod ucomisd;
+pub mod ucomiss;
+pub mod vaddpd;
+pub mod vaddps;
+pub mod vaddsd;
+pub mod vaddss;
+pub mod vcmppd;
+pub mod vcmpps;
+pub mod vcvtph2ps;
+pub mod vcvtps2ph;
+pub mod vdivpd;
+pub mod vdivps;
+pub mod vdivsd;
+pub mod vdivss;
+pub mod vfmadd132pd;
+pub mod vfmadd132ps;
+pub mod vfmadd132sd;
+pub mod vfmadd132ss;
+pub mod vfmadd213pd;
+pub mod vfmadd213ps;
+pub mod vfmadd213sd;
+pub mod vfmadd213ss;
+pub mod vfmadd231pd;
+pub mod vfmadd231ps;
+pub mod vfmadd231sd;
+pub mod vfmadd231ss;
+pub mod vfmsub132pd;
+pub mod vfmsub132ps;
+pub mod vfmsub132sd;
+pub mod vfmsub132ss;
+pub mod vfmsub213pd;
+pub mod vfmsub213ps;
+pub mod vfmsub213sd;
+pub mod vfmsub213ss;
+pub mod vfmsub231pd;
+pub mod vfmsub231ps;
+pub mod vfmsub231sd;
+pub mod vfmsub231ss;
+pub mod vfnmadd132pd;
+pub mod vfnmadd132ps;
+pub mod vfnmadd132sd;
+pub mod vfnmadd132ss;
+pub mod vfnmadd213pd;
+pub mod vfnmadd213ps;
+pub mod vfnmadd213sd;
+pub mod vfnmadd213ss;
+pub mod vfnmadd231pd;
+pub mod vfnmadd231ps;
+pub mod vfnmadd231sd;
+pub mod vfnmadd231ss;
+pub mod vfnmsub132pd;
+pub mod vfnmsub132ps;
+pub mod vfnmsub132sd;
+pub mod vfnmsub132ss;
+pub mod vfnmsub213pd;
+pub mod vfnmsub213ps;
+pub mod vfnmsub213sd;
+pub mod vfnmsub213ss;
+pub mod vfnmsub231pd;
+pub mod vfnmsub231ps;
+pub mod vfnmsub231sd;
+pub mod vfnmsub231ss;
+pub mod vgatherdpd;
+pub mod vgatherdps;
+pub mod vgatherqpd;
+pub mod vgatherqps;
+pub mod vmaxpd;
+pub mod vmaxps;
+pub mod vminpd;
+pub mod vminps;
+pub mod vmovaps;
+pub mod vmovdqa;
+pub mod vmovdqu;
+pub mod vmovdqu32;
+pub mod vmovdqu64;
+pub mod vmovups;
+pub mod vmulpd;
+pub mod vmulps;
+pub mod vmulsd;
+pub mod vmulss;
+pub mod vpaddb;
+pub mod vpaddd;
+pub mod vpaddq;
+pub mod vpaddw;
+pub mod vpand;
+pub mod vpandd;
+pub mod vpandq;
+pub mod vpbroadcastb;
+pub mod vpbroadcastd;
+pub mod vpbroadcastq;
+pub mod vpbroadcastw;
+pub mod vpcmpd;
+pub mod vpcmpeqd;
+pub mod vpcmpeqq;
+pub mod vpcmpgtd;
+pub mod vpcmpgtq;
+pub mod vpcmpq;
+pub mod vpcmpud;
+pub mod vpcmpuq;
+pub mod vpermd;
+pub mod vpermps;
+pub mod vpgatherdd;
+pub mod vpgatherdq;
+pub mod vpgatherqd;
+pub mod vpgatherqq;
+pub mod vpmulld;
+pub mod vpor;
+pub mod vpord;
+pub mod vporq;
+pub mod vpscatterdd;
+pub mod vpscatterdq;
+pub mod vpscatterqd;
+pub mod vpscatterqq;
+pub mod vpshufb;
+pub mod vpsubd;
+pub mod vpsubq;
+pub mod vpxor;
+pub mod vpxord;
+pub mod vpxorq;
+pub mod vscatterdpd;
+pub mod vscatterdps;
+pub mod vscatterqpd;
+pub mod vscatterqps;
+pub mod vsqrtpd;
+pub mod vsqrtps;
+pub mod vsubpd;
+pub mod vsubps;
+pub mod vsubsd;
+pub mod vsubss;
+pub mod vzeroupper;
+pub mod xadd;
+pub mod xchg;
+pub mod xor;
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `movaps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move aligned packed single-precision values from XMM register or 128-bit memory into XMM register.
+///
+/// Safe variant of [`movaps::encode_xmm_xmmm128`][crate::encoders::movaps::encode_xmm_xmmm128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm128(xmm: XMM, xmmm128: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movaps::encode_xmm_xmmm128(xmm, xmmm128) })
+}
+
+/// Move aligned packed single-precision values from XMM register into XMM register or 128-bit memory.
+///
+/// Safe variant of [`movaps::encode_xmmm128_xmm`][crate::encoders::movaps::encode_xmmm128_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm128_xmm(xmmm128: XMMOrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movaps::encode_xmmm128_xmm(xmmm128, xmm) })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `movd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move 32-bit register or memory into XMM register, zeroing upper bits.
+///
+/// Safe variant of [`movd::encode_xmm_rm32`][crate::encoders::movd::encode_xmm_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_rm32(xmm: XMM, rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movd::encode_xmm_rm32(xmm, rm32) })
+}
+
+/// Move low 32 bits of XMM register into 32-bit register or memory.
+///
+/// Safe variant of [`movd::encode_rm32_xmm`][crate::encoders::movd::encode_rm32_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_xmm(rm32: GPROrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movd::encode_rm32_xmm(rm32, xmm) })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `movq` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move 64-bit register or memory into XMM register, zeroing upper bits.
+///
+/// Safe variant of [`movq::encode_xmm_rm64`][crate::encoders::movq::encode_xmm_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_rm64(xmm: XMM, rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movq::encode_xmm_rm64(xmm, rm64) })
+}
+
+/// Move low 64 bits of XMM register into 64-bit register or memory.
+///
+/// Safe variant of [`movq::encode_rm64_xmm`][crate::encoders::movq::encode_rm64_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_xmm(rm64: GPROrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movq::encode_rm64_xmm(rm64, xmm) })
+}
@@ -0,0 +1,107 @@
+//! This module contains the safe encoders for the `movs` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move byte from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_b`][crate::encoders::movs::encode_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_b() })
+}
+
+/// Move word from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_w`][crate::encoders::movs::encode_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_w() })
+}
+
+/// Move doubleword from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_d`][crate::encoders::movs::encode_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_d() })
+}
+
+/// Move quadword from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_q`][crate::encoders::movs::encode_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_q() })
+}
+
+/// Move RCX bytes from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_rep_b`][crate::encoders::movs::encode_rep_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_rep_b() })
+}
+
+/// Move RCX words from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_rep_w`][crate::encoders::movs::encode_rep_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_rep_w() })
+}
+
+/// Move RCX doublewords from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_rep_d`][crate::encoders::movs::encode_rep_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_rep_d() })
+}
+
+/// Move RCX quadwords from address RSI to address RDI.
+///
+/// Safe variant of [`movs::encode_rep_q`][crate::encoders::movs::encode_rep_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::movs::encode_rep_q() })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `movsd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move scalar double-precision value from XMM register or 64-bit memory into XMM register.
+///
+/// Safe variant of [`movsd::encode_xmm_xmmm64`][crate::encoders::movsd::encode_xmm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm64(xmm: XMM, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsd::encode_xmm_xmmm64(xmm, xmmm64) })
+}
+
+/// Move scalar double-precision value from XMM register into XMM register or 64-bit memory.
+///
+/// Safe variant of [`movsd::encode_xmmm64_xmm`][crate::encoders::movsd::encode_xmmm64_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm64_xmm(xmmm64: XMMOrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsd::encode_xmmm64_xmm(xmmm64, xmm) })
+}
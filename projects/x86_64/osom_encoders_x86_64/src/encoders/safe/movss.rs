@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `movss` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move scalar single-precision value from XMM register or 32-bit memory into XMM register.
+///
+/// Safe variant of [`movss::encode_xmm_xmmm32`][crate::encoders::movss::encode_xmm_xmmm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm32(xmm: XMM, xmmm32: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movss::encode_xmm_xmmm32(xmm, xmmm32) })
+}
+
+/// Move scalar single-precision value from XMM register into XMM register or 32-bit memory.
+///
+/// Safe variant of [`movss::encode_xmmm32_xmm`][crate::encoders::movss::encode_xmmm32_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm32_xmm(xmmm32: XMMOrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movss::encode_xmmm32_xmm(xmmm32, xmm) })
+}
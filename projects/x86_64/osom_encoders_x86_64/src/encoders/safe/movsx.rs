@@ -0,0 +1,110 @@
+//! This module contains the safe encoders for the `movsx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move 8-bit register or memory to 16-bit register with sign extension.
+///
+/// Safe variant of [`movsx::encode_reg16_rm8`][crate::encoders::movsx::encode_reg16_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg16_rm8(reg16: GPR, rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg16, Size::Bit16) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg16, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsx::encode_reg16_rm8(reg16, rm8) })
+}
+
+/// Move 8-bit register or memory to 32-bit register with sign extension.
+///
+/// Safe variant of [`movsx::encode_reg32_rm8`][crate::encoders::movsx::encode_reg32_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm8(reg32: GPR, rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg32, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsx::encode_reg32_rm8(reg32, rm8) })
+}
+
+/// Move 8-bit register or memory to 64-bit register with sign extension.
+///
+/// Safe variant of [`movsx::encode_reg64_rm8`][crate::encoders::movsx::encode_reg64_rm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm8(reg64: GPR, rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_bit8_high_with_rex(rm8, reg64, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsx::encode_reg64_rm8(reg64, rm8) })
+}
+
+/// Move 16-bit register or memory to 32-bit register with sign extension.
+///
+/// Safe variant of [`movsx::encode_reg32_rm16`][crate::encoders::movsx::encode_reg32_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm16(reg32: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsx::encode_reg32_rm16(reg32, rm16) })
+}
+
+/// Move 16-bit register or memory to 64-bit register with sign extension.
+///
+/// Safe variant of [`movsx::encode_reg64_rm16`][crate::encoders::movsx::encode_reg64_rm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm16(reg64: GPR, rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::movsx::encode_reg64_rm16(reg64, rm16) })
+}
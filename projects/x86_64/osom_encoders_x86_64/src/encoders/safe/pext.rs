@@ -0,0 +1,61 @@
+//! This module contains the safe encoders for the `pext` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Parallel extract of bits from 32-bit VEX register using mask in 32-bit register or memory into 32-bit register.
+///
+/// Safe variant of [`pext::encode_reg32_vreg32_rm32`][crate::encoders::pext::encode_reg32_vreg32_rm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_vreg32_rm32(
+    reg32: GPR,
+    vreg32: GPR,
+    rm32: GPROrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(vreg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pext::encode_reg32_vreg32_rm32(reg32, vreg32, rm32) })
+}
+
+/// Parallel extract of bits from 64-bit VEX register using mask in 64-bit register or memory into 64-bit register.
+///
+/// Safe variant of [`pext::encode_reg64_vreg64_rm64`][crate::encoders::pext::encode_reg64_vreg64_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_vreg64_rm64(
+    reg64: GPR,
+    vreg64: GPR,
+    rm64: GPROrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(vreg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pext::encode_reg64_vreg64_rm64(reg64, vreg64, rm64) })
+}
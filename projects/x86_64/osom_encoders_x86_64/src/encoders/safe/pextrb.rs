@@ -0,0 +1,33 @@
+//! This module contains the safe encoders for the `pextrb` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Extract byte selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 8-bit memory.
+///
+/// Safe variant of [`pextrb::encode_r32m8_xmm_imm8`][crate::encoders::pextrb::encode_r32m8_xmm_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_r32m8_xmm_imm8(
+    r32m8: GPROrMemory,
+    xmm: XMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(r32m8, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pextrb::encode_r32m8_xmm_imm8(r32m8, xmm, imm8) })
+}
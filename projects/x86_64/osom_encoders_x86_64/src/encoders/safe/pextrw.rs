@@ -0,0 +1,55 @@
+//! This module contains the safe encoders for the `pextrw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Extract word selected by 8-bit immediate from XMM register into 32-bit register, zero extended.
+///
+/// Safe variant of [`pextrw::encode_reg32_xmm_imm8`][crate::encoders::pextrw::encode_reg32_xmm_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_xmm_imm8(
+    reg32: GPR,
+    xmm: XMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pextrw::encode_reg32_xmm_imm8(reg32, xmm, imm8) })
+}
+
+/// Extract word selected by 8-bit immediate from XMM register into 32-bit register (zero extended) or 16-bit memory.
+///
+/// Safe variant of [`pextrw::encode_r32m16_xmm_imm8`][crate::encoders::pextrw::encode_r32m16_xmm_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_r32m16_xmm_imm8(
+    r32m16: GPROrMemory,
+    xmm: XMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(r32m16, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pextrw::encode_r32m16_xmm_imm8(r32m16, xmm, imm8) })
+}
@@ -0,0 +1,33 @@
+//! This module contains the safe encoders for the `pinsrw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Insert word from 32-bit register or 16-bit memory into XMM register, at position selected by 8-bit immediate.
+///
+/// Safe variant of [`pinsrw::encode_xmm_r32m16_imm8`][crate::encoders::pinsrw::encode_xmm_r32m16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_r32m16_imm8(
+    xmm: XMM,
+    r32m16: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(r32m16, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pinsrw::encode_xmm_r32m16_imm8(xmm, r32m16, imm8) })
+}
@@ -0,0 +1,33 @@
+//! This module contains the safe encoders for the `pshufd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Shuffle doublewords in XMM register or 128-bit memory into XMM register, as selected by 8-bit immediate.
+///
+/// Safe variant of [`pshufd::encode_xmm_xmmm128_imm8`][crate::encoders::pshufd::encode_xmm_xmmm128_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm128_imm8(
+    xmm: XMM,
+    xmmm128: XMMOrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::pshufd::encode_xmm_xmmm128_imm8(xmm, xmmm128, imm8) })
+}
@@ -0,0 +1,77 @@
+//! This module contains the safe encoders for the `push` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Push 64-bit register or memory onto the stack.
+///
+/// Safe variant of [`push::encode_rm64`][crate::encoders::push::encode_rm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::push::encode_rm64(rm64) })
+}
+
+/// Push 64-bit register onto the stack.
+///
+/// Safe variant of [`push::encode_reg64`][crate::encoders::push::encode_reg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64(reg64: GPR) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::push::encode_reg64(reg64) })
+}
+
+/// Push 8-bit immediate onto the stack (sign-extended).
+///
+/// Safe variant of [`push::encode_imm8`][crate::encoders::push::encode_imm8].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm8(imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::push::encode_imm8(imm8) })
+}
+
+/// Push 16-bit immediate onto the stack (sign-extended).
+///
+/// Safe variant of [`push::encode_imm16`][crate::encoders::push::encode_imm16].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm16(imm16: Immediate16) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::push::encode_imm16(imm16) })
+}
+
+/// Push 32-bit immediate onto the stack (sign-extended).
+///
+/// Safe variant of [`push::encode_imm32`][crate::encoders::push::encode_imm32].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_imm32(imm32: Immediate32) -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::push::encode_imm32(imm32) })
+}
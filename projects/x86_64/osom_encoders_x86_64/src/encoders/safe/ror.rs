@@ -0,0 +1,191 @@
+//! This module contains the safe encoders for the `ror` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Rotate 8-bit register or memory right once.
+///
+/// Safe variant of [`ror::encode_rm8_one`][crate::encoders::ror::encode_rm8_one].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_one(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm8_one(rm8) })
+}
+
+/// Rotate 16-bit register or memory right once.
+///
+/// Safe variant of [`ror::encode_rm16_one`][crate::encoders::ror::encode_rm16_one].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_one(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm16_one(rm16) })
+}
+
+/// Rotate 32-bit register or memory right once.
+///
+/// Safe variant of [`ror::encode_rm32_one`][crate::encoders::ror::encode_rm32_one].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_one(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm32_one(rm32) })
+}
+
+/// Rotate 64-bit register or memory right once.
+///
+/// Safe variant of [`ror::encode_rm64_one`][crate::encoders::ror::encode_rm64_one].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_one(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm64_one(rm64) })
+}
+
+/// Rotate 8-bit register or memory right by 8-bit immediate.
+///
+/// Safe variant of [`ror::encode_rm8_imm8`][crate::encoders::ror::encode_rm8_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm8_imm8(rm8, imm8) })
+}
+
+/// Rotate 16-bit register or memory right by 8-bit immediate.
+///
+/// Safe variant of [`ror::encode_rm16_imm8`][crate::encoders::ror::encode_rm16_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm16_imm8(rm16, imm8) })
+}
+
+/// Rotate 32-bit register or memory right by 8-bit immediate.
+///
+/// Safe variant of [`ror::encode_rm32_imm8`][crate::encoders::ror::encode_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm32_imm8(rm32, imm8) })
+}
+
+/// Rotate 64-bit register or memory right by 8-bit immediate.
+///
+/// Safe variant of [`ror::encode_rm64_imm8`][crate::encoders::ror::encode_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm64_imm8(rm64, imm8) })
+}
+
+/// Rotate 8-bit register or memory right by CL register.
+///
+/// Safe variant of [`ror::encode_rm8_cl`][crate::encoders::ror::encode_rm8_cl].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm8_cl(rm8: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm8, Size::Bit8) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm8_cl(rm8) })
+}
+
+/// Rotate 16-bit register or memory right by CL register.
+///
+/// Safe variant of [`ror::encode_rm16_cl`][crate::encoders::ror::encode_rm16_cl].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm16_cl(rm16: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm16, Size::Bit16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm16_cl(rm16) })
+}
+
+/// Rotate 32-bit register or memory right by CL register.
+///
+/// Safe variant of [`ror::encode_rm32_cl`][crate::encoders::ror::encode_rm32_cl].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm32_cl(rm32: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm32_cl(rm32) })
+}
+
+/// Rotate 64-bit register or memory right by CL register.
+///
+/// Safe variant of [`ror::encode_rm64_cl`][crate::encoders::ror::encode_rm64_cl].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_rm64_cl(rm64: GPROrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::ror::encode_rm64_cl(rm64) })
+}
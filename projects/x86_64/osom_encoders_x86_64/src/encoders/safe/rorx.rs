@@ -0,0 +1,55 @@
+//! This module contains the safe encoders for the `rorx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Rotate 32-bit register or memory right by 8-bit immediate into 32-bit register, without affecting flags.
+///
+/// Safe variant of [`rorx::encode_reg32_rm32_imm8`][crate::encoders::rorx::encode_reg32_rm32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32_imm8(
+    reg32: GPR,
+    rm32: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::rorx::encode_reg32_rm32_imm8(reg32, rm32, imm8) })
+}
+
+/// Rotate 64-bit register or memory right by 8-bit immediate into 64-bit register, without affecting flags.
+///
+/// Safe variant of [`rorx::encode_reg64_rm64_imm8`][crate::encoders::rorx::encode_reg64_rm64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64_imm8(
+    reg64: GPR,
+    rm64: GPROrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::rorx::encode_reg64_rm64_imm8(reg64, rm64, imm8) })
+}
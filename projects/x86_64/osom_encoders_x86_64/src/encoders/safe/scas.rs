@@ -0,0 +1,155 @@
+//! This module contains the safe encoders for the `scas` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare AL with byte at address RDI and set the status flags.
+///
+/// Safe variant of [`scas::encode_b`][crate::encoders::scas::encode_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_b() })
+}
+
+/// Compare AX with word at address RDI and set the status flags.
+///
+/// Safe variant of [`scas::encode_w`][crate::encoders::scas::encode_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_w() })
+}
+
+/// Compare EAX with doubleword at address RDI and set the status flags.
+///
+/// Safe variant of [`scas::encode_d`][crate::encoders::scas::encode_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_d() })
+}
+
+/// Compare RAX with quadword at address RDI and set the status flags.
+///
+/// Safe variant of [`scas::encode_q`][crate::encoders::scas::encode_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_q() })
+}
+
+/// Find non-AL byte in RCX bytes at address RDI.
+///
+/// Safe variant of [`scas::encode_repe_b`][crate::encoders::scas::encode_repe_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repe_b() })
+}
+
+/// Find non-AX word in RCX words at address RDI.
+///
+/// Safe variant of [`scas::encode_repe_w`][crate::encoders::scas::encode_repe_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repe_w() })
+}
+
+/// Find non-EAX doubleword in RCX doublewords at address RDI.
+///
+/// Safe variant of [`scas::encode_repe_d`][crate::encoders::scas::encode_repe_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repe_d() })
+}
+
+/// Find non-RAX quadword in RCX quadwords at address RDI.
+///
+/// Safe variant of [`scas::encode_repe_q`][crate::encoders::scas::encode_repe_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repe_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repe_q() })
+}
+
+/// Find AL in RCX bytes at address RDI.
+///
+/// Safe variant of [`scas::encode_repne_b`][crate::encoders::scas::encode_repne_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repne_b() })
+}
+
+/// Find AX in RCX words at address RDI.
+///
+/// Safe variant of [`scas::encode_repne_w`][crate::encoders::scas::encode_repne_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repne_w() })
+}
+
+/// Find EAX in RCX doublewords at address RDI.
+///
+/// Safe variant of [`scas::encode_repne_d`][crate::encoders::scas::encode_repne_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repne_d() })
+}
+
+/// Find RAX in RCX quadwords at address RDI.
+///
+/// Safe variant of [`scas::encode_repne_q`][crate::encoders::scas::encode_repne_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_repne_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::scas::encode_repne_q() })
+}
@@ -0,0 +1,61 @@
+//! This module contains the safe encoders for the `shlx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Shift 32-bit register or memory left by 32-bit VEX register into 32-bit register, without affecting flags.
+///
+/// Safe variant of [`shlx::encode_reg32_rm32_vreg32`][crate::encoders::shlx::encode_reg32_rm32_vreg32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg32_rm32_vreg32(
+    reg32: GPR,
+    rm32: GPROrMemory,
+    vreg32: GPR,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm32, Size::Bit32) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(vreg32, Size::Bit32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::shlx::encode_reg32_rm32_vreg32(reg32, rm32, vreg32) })
+}
+
+/// Shift 64-bit register or memory left by 64-bit VEX register into 64-bit register, without affecting flags.
+///
+/// Safe variant of [`shlx::encode_reg64_rm64_vreg64`][crate::encoders::shlx::encode_reg64_rm64_vreg64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_reg64_rm64_vreg64(
+    reg64: GPR,
+    rm64: GPROrMemory,
+    vreg64: GPR,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_gpr(reg64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr_or_memory(rm64, Size::Bit64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gpr(vreg64, Size::Bit64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::shlx::encode_reg64_rm64_vreg64(reg64, rm64, vreg64) })
+}
@@ -0,0 +1,107 @@
+//! This module contains the safe encoders for the `stos` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Store AL at address RDI.
+///
+/// Safe variant of [`stos::encode_b`][crate::encoders::stos::encode_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_b() })
+}
+
+/// Store AX at address RDI.
+///
+/// Safe variant of [`stos::encode_w`][crate::encoders::stos::encode_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_w() })
+}
+
+/// Store EAX at address RDI.
+///
+/// Safe variant of [`stos::encode_d`][crate::encoders::stos::encode_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_d() })
+}
+
+/// Store RAX at address RDI.
+///
+/// Safe variant of [`stos::encode_q`][crate::encoders::stos::encode_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_q() })
+}
+
+/// Fill RCX bytes at address RDI with AL.
+///
+/// Safe variant of [`stos::encode_rep_b`][crate::encoders::stos::encode_rep_b].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_b() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_rep_b() })
+}
+
+/// Fill RCX words at address RDI with AX.
+///
+/// Safe variant of [`stos::encode_rep_w`][crate::encoders::stos::encode_rep_w].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_w() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_rep_w() })
+}
+
+/// Fill RCX doublewords at address RDI with EAX.
+///
+/// Safe variant of [`stos::encode_rep_d`][crate::encoders::stos::encode_rep_d].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_d() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_rep_d() })
+}
+
+/// Fill RCX quadwords at address RDI with RAX.
+///
+/// Safe variant of [`stos::encode_rep_q`][crate::encoders::stos::encode_rep_q].
+///
+/// # Errors
+///
+/// This function never fails. It returns [`Result`] for consistency with other safe encoders.
+#[inline]
+pub const fn encode_rep_q() -> Result<EncodedX86_64Instruction, EncodeError> {
+    Ok(unsafe { crate::encoders::stos::encode_rep_q() })
+}
@@ -0,0 +1,105 @@
+//! This module contains the safe encoders for the `vaddps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Add packed single-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+///
+/// Safe variant of [`vaddps::encode_xmm_vxmm_xmmm128`][crate::encoders::vaddps::encode_xmm_vxmm_xmmm128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vxmm_xmmm128(
+    xmm: XMM,
+    vxmm: XMM,
+    xmmm128: XMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vaddps::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128) })
+}
+
+/// Add packed single-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+///
+/// Safe variant of [`vaddps::encode_ymm_vymm_ymmm256`][crate::encoders::vaddps::encode_ymm_vymm_ymmm256].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_vymm_ymmm256(
+    ymm: YMM,
+    vymm: YMM,
+    ymmm256: YMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(vymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm_or_memory(ymmm256) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vaddps::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256) })
+}
+
+/// Add packed single-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+///
+/// Safe variant of [`vaddps::encode_zmm_mask_vzmm_zmmm512b32`][crate::encoders::vaddps::encode_zmm_mask_vzmm_zmmm512b32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_vzmm_zmmm512b32(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmm512b32: ZMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512b32, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vaddps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32) })
+}
+
+/// Add packed single-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+///
+/// Safe variant of [`vaddps::encode_zmm_mask_vzmm_zmmr_er`][crate::encoders::vaddps::encode_zmm_mask_vzmm_zmmr_er].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_vzmm_zmmr_er(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+    er: Rounding,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vaddps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er) })
+}
@@ -0,0 +1,60 @@
+//! This module contains the safe encoders for the `vcmppd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+///
+/// Safe variant of [`vcmppd::encode_k_mergemask_vzmm_zmmm512b64_imm8`][crate::encoders::vcmppd::encode_k_mergemask_vzmm_zmmm512b64_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_k_mergemask_vzmm_zmmm512b64_imm8(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mergemask, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512b64, true) {
+        return Err(err);
+    }
+    Ok(
+        unsafe {
+            crate::encoders::vcmppd::encode_k_mergemask_vzmm_zmmm512b64_imm8(k, mergemask, vzmm, zmmm512b64, imm8)
+        },
+    )
+}
+
+/// Compare packed double-precision values of ZMM EVEX register and ZMM register, using predicate selected by 8-bit immediate, into opmask register with merge masking and suppressed floating-point exceptions.
+///
+/// Safe variant of [`vcmppd::encode_k_mergemask_vzmm_zmmr_sae_imm8`][crate::encoders::vcmppd::encode_k_mergemask_vzmm_zmmr_sae_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_k_mergemask_vzmm_zmmr_sae_imm8(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mergemask, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vcmppd::encode_k_mergemask_vzmm_zmmr_sae_imm8(k, mergemask, vzmm, zmmr, imm8) })
+}
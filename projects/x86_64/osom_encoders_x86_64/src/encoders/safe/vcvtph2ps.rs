@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `vcvtph2ps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Convert four packed half-precision values of XMM register or 64-bit memory to packed single-precision values in XMM register.
+///
+/// Safe variant of [`vcvtph2ps::encode_xmm_xmmm64`][crate::encoders::vcvtph2ps::encode_xmm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm64(xmm: XMM, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vcvtph2ps::encode_xmm_xmmm64(xmm, xmmm64) })
+}
+
+/// Convert eight packed half-precision values of XMM register or 128-bit memory to packed single-precision values in YMM register.
+///
+/// Safe variant of [`vcvtph2ps::encode_ymm_xmmm128`][crate::encoders::vcvtph2ps::encode_ymm_xmmm128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_xmmm128(ymm: YMM, xmmm128: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vcvtph2ps::encode_ymm_xmmm128(ymm, xmmm128) })
+}
@@ -0,0 +1,55 @@
+//! This module contains the safe encoders for the `vcvtps2ph` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Convert four packed single-precision values of XMM register to packed half-precision values in XMM register or 64-bit memory, with rounding control in the immediate.
+///
+/// Safe variant of [`vcvtps2ph::encode_xmmm64_xmm_imm8`][crate::encoders::vcvtps2ph::encode_xmmm64_xmm_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm64_xmm_imm8(
+    xmmm64: XMMOrMemory,
+    xmm: XMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vcvtps2ph::encode_xmmm64_xmm_imm8(xmmm64, xmm, imm8) })
+}
+
+/// Convert eight packed single-precision values of YMM register to packed half-precision values in XMM register or 128-bit memory, with rounding control in the immediate.
+///
+/// Safe variant of [`vcvtps2ph::encode_xmmm128_ymm_imm8`][crate::encoders::vcvtps2ph::encode_xmmm128_ymm_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm128_ymm_imm8(
+    xmmm128: XMMOrMemory,
+    ymm: YMM,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vcvtps2ph::encode_xmmm128_ymm_imm8(xmmm128, ymm, imm8) })
+}
@@ -0,0 +1,55 @@
+//! This module contains the safe encoders for the `vdivsd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Divide low double-precision value of XMM EVEX register by XMM register or 64-bit memory into XMM register with write masking.
+///
+/// Safe variant of [`vdivsd::encode_xmm_mask_vxmm_xmmm64`][crate::encoders::vdivsd::encode_xmm_mask_vxmm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_mask_vxmm_xmmm64(
+    xmm: XMM,
+    mask: Masking,
+    vxmm: XMM,
+    xmmm64: XMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_evex_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vdivsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64) })
+}
+
+/// Divide low double-precision value of XMM EVEX register by XMM register into XMM register with write masking and static rounding.
+///
+/// Safe variant of [`vdivsd::encode_xmm_mask_vxmm_xmmr_er`][crate::encoders::vdivsd::encode_xmm_mask_vxmm_xmmr_er].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_mask_vxmm_xmmr_er(
+    xmm: XMM,
+    mask: Masking,
+    vxmm: XMM,
+    xmmr: XMM,
+    er: Rounding,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vdivsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er) })
+}
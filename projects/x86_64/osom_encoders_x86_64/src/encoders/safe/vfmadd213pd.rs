@@ -0,0 +1,61 @@
+//! This module contains the safe encoders for the `vfmadd213pd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Multiply packed double-precision values of XMM VEX register and XMM register, add XMM register or 128-bit memory and store into XMM register.
+///
+/// Safe variant of [`vfmadd213pd::encode_xmm_vxmm_xmmm128`][crate::encoders::vfmadd213pd::encode_xmm_vxmm_xmmm128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vxmm_xmmm128(
+    xmm: XMM,
+    vxmm: XMM,
+    xmmm128: XMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vfmadd213pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128) })
+}
+
+/// Multiply packed double-precision values of YMM VEX register and YMM register, add YMM register or 256-bit memory and store into YMM register.
+///
+/// Safe variant of [`vfmadd213pd::encode_ymm_vymm_ymmm256`][crate::encoders::vfmadd213pd::encode_ymm_vymm_ymmm256].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_vymm_ymmm256(
+    ymm: YMM,
+    vymm: YMM,
+    ymmm256: YMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(vymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm_or_memory(ymmm256) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vfmadd213pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256) })
+}
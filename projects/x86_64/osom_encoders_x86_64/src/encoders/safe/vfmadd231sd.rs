@@ -0,0 +1,36 @@
+//! This module contains the safe encoders for the `vfmadd231sd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Multiply low double-precision values of XMM VEX register and XMM register or 64-bit memory, add XMM register and store into XMM register.
+///
+/// Safe variant of [`vfmadd231sd::encode_xmm_vxmm_xmmm64`][crate::encoders::vfmadd231sd::encode_xmm_vxmm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vxmm_xmmm64(
+    xmm: XMM,
+    vxmm: XMM,
+    xmmm64: XMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vfmadd231sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64) })
+}
@@ -0,0 +1,92 @@
+//! This module contains the safe encoders for the `vgatherdps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Gather packed single-precision values from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// Safe variant of [`vgatherdps::encode_xmm_vm32x_vxmm`][crate::encoders::vgatherdps::encode_xmm_vm32x_vxmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vm32x_vxmm(
+    xmm: XMM,
+    vm32x: VSIBMemory,
+    vxmm: XMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm32x, Size::Bit128, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(xmm.index(), vm32x, Some(vxmm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherdps::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm) })
+}
+
+/// Gather packed single-precision values from memory addressed by doubleword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+///
+/// Safe variant of [`vgatherdps::encode_ymm_vm32y_vymm`][crate::encoders::vgatherdps::encode_ymm_vm32y_vymm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_vm32y_vymm(
+    ymm: YMM,
+    vm32y: VSIBMemory,
+    vymm: YMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm32y, Size::Bit256, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(vymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(ymm.index(), vm32y, Some(vymm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherdps::encode_ymm_vm32y_vymm(ymm, vm32y, vymm) })
+}
+
+/// Gather packed single-precision values from memory addressed by doubleword indexes of ZMM register into ZMM register with merge masking.
+///
+/// Safe variant of [`vgatherdps::encode_zmm_mergemask_vm32z`][crate::encoders::vgatherdps::encode_zmm_mergemask_vm32z].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mergemask_vm32z(
+    zmm: ZMM,
+    mergemask: Masking,
+    vm32z: VSIBMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_vsib_masking(mergemask) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm32z, Size::Bit512, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(zmm.index(), vm32z, None) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherdps::encode_zmm_mergemask_vm32z(zmm, mergemask, vm32z) })
+}
@@ -0,0 +1,92 @@
+//! This module contains the safe encoders for the `vgatherqpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// Safe variant of [`vgatherqpd::encode_xmm_vm64x_vxmm`][crate::encoders::vgatherqpd::encode_xmm_vm64x_vxmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vm64x_vxmm(
+    xmm: XMM,
+    vm64x: VSIBMemory,
+    vxmm: XMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64x, Size::Bit128, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(xmm.index(), vm64x, Some(vxmm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherqpd::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm) })
+}
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+///
+/// Safe variant of [`vgatherqpd::encode_ymm_vm64y_vymm`][crate::encoders::vgatherqpd::encode_ymm_vm64y_vymm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_vm64y_vymm(
+    ymm: YMM,
+    vm64y: VSIBMemory,
+    vymm: YMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64y, Size::Bit256, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(vymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(ymm.index(), vm64y, Some(vymm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherqpd::encode_ymm_vm64y_vymm(ymm, vm64y, vymm) })
+}
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of ZMM register into ZMM register with merge masking.
+///
+/// Safe variant of [`vgatherqpd::encode_zmm_mergemask_vm64z`][crate::encoders::vgatherqpd::encode_zmm_mergemask_vm64z].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mergemask_vm64z(
+    zmm: ZMM,
+    mergemask: Masking,
+    vm64z: VSIBMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_vsib_masking(mergemask) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64z, Size::Bit512, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(zmm.index(), vm64z, None) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vgatherqpd::encode_zmm_mergemask_vm64z(zmm, mergemask, vm64z) })
+}
@@ -0,0 +1,54 @@
+//! This module contains the safe encoders for the `vmaxpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Select maximum packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+///
+/// Safe variant of [`vmaxpd::encode_zmm_mask_vzmm_zmmm512b64`][crate::encoders::vmaxpd::encode_zmm_mask_vzmm_zmmm512b64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_vzmm_zmmm512b64(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512b64, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmaxpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64) })
+}
+
+/// Select maximum packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and suppressed floating-point exceptions.
+///
+/// Safe variant of [`vmaxpd::encode_zmm_mask_vzmm_zmmr_sae`][crate::encoders::vmaxpd::encode_zmm_mask_vzmm_zmmr_sae].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_vzmm_zmmr_sae(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmaxpd::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr) })
+}
@@ -0,0 +1,83 @@
+//! This module contains the safe encoders for the `vmovdqa` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move aligned packed integer values from XMM register or 128-bit memory to XMM register.
+///
+/// Safe variant of [`vmovdqa::encode_xmm_xmmm128`][crate::encoders::vmovdqa::encode_xmm_xmmm128].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm128(xmm: XMM, xmmm128: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqa::encode_xmm_xmmm128(xmm, xmmm128) })
+}
+
+/// Move aligned packed integer values from XMM register to XMM register or 128-bit memory.
+///
+/// Safe variant of [`vmovdqa::encode_xmmm128_xmm`][crate::encoders::vmovdqa::encode_xmmm128_xmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmmm128_xmm(xmmm128: XMMOrMemory, xmm: XMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm128) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqa::encode_xmmm128_xmm(xmmm128, xmm) })
+}
+
+/// Move aligned packed integer values from YMM register or 256-bit memory to YMM register.
+///
+/// Safe variant of [`vmovdqa::encode_ymm_ymmm256`][crate::encoders::vmovdqa::encode_ymm_ymmm256].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_ymmm256(ymm: YMM, ymmm256: YMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm_or_memory(ymmm256) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqa::encode_ymm_ymmm256(ymm, ymmm256) })
+}
+
+/// Move aligned packed integer values from YMM register to YMM register or 256-bit memory.
+///
+/// Safe variant of [`vmovdqa::encode_ymmm256_ymm`][crate::encoders::vmovdqa::encode_ymmm256_ymm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymmm256_ymm(ymmm256: YMMOrMemory, ymm: YMM) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm_or_memory(ymmm256) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqa::encode_ymmm256_ymm(ymmm256, ymm) })
+}
@@ -0,0 +1,55 @@
+//! This module contains the safe encoders for the `vmovdqu32` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Move unaligned packed doublewords from ZMM register or 512-bit memory to ZMM register with write masking.
+///
+/// Safe variant of [`vmovdqu32::encode_zmm_mask_zmmm512`][crate::encoders::vmovdqu32::encode_zmm_mask_zmmm512].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_zmmm512(
+    zmm: ZMM,
+    mask: Masking,
+    zmmm512: ZMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqu32::encode_zmm_mask_zmmm512(zmm, mask, zmmm512) })
+}
+
+/// Move unaligned packed doublewords from ZMM register to ZMM register or 512-bit memory with merge masking.
+///
+/// Safe variant of [`vmovdqu32::encode_zmmm512_mergemask_zmm`][crate::encoders::vmovdqu32::encode_zmmm512_mergemask_zmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmmm512_mergemask_zmm(
+    zmmm512: ZMMOrMemory,
+    mergemask: Masking,
+    zmm: ZMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_masking(mergemask, false) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vmovdqu32::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm) })
+}
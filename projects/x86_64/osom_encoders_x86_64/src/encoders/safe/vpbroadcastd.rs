@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `vpbroadcastd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Broadcast doubleword of XMM register or 32-bit memory to all elements of XMM register.
+///
+/// Safe variant of [`vpbroadcastd::encode_xmm_xmmm32`][crate::encoders::vpbroadcastd::encode_xmm_xmmm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm32(xmm: XMM, xmmm32: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastd::encode_xmm_xmmm32(xmm, xmmm32) })
+}
+
+/// Broadcast doubleword of XMM register or 32-bit memory to all elements of YMM register.
+///
+/// Safe variant of [`vpbroadcastd::encode_ymm_xmmm32`][crate::encoders::vpbroadcastd::encode_ymm_xmmm32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_xmmm32(ymm: YMM, xmmm32: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm32) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastd::encode_ymm_xmmm32(ymm, xmmm32) })
+}
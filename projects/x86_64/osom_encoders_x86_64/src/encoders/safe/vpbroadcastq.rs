@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `vpbroadcastq` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Broadcast quadword of XMM register or 64-bit memory to all elements of XMM register.
+///
+/// Safe variant of [`vpbroadcastq::encode_xmm_xmmm64`][crate::encoders::vpbroadcastq::encode_xmm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm64(xmm: XMM, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastq::encode_xmm_xmmm64(xmm, xmmm64) })
+}
+
+/// Broadcast quadword of XMM register or 64-bit memory to all elements of YMM register.
+///
+/// Safe variant of [`vpbroadcastq::encode_ymm_xmmm64`][crate::encoders::vpbroadcastq::encode_ymm_xmmm64].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_xmmm64(ymm: YMM, xmmm64: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm64) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastq::encode_ymm_xmmm64(ymm, xmmm64) })
+}
@@ -0,0 +1,47 @@
+//! This module contains the safe encoders for the `vpbroadcastw` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Broadcast word of XMM register or 16-bit memory to all elements of XMM register.
+///
+/// Safe variant of [`vpbroadcastw::encode_xmm_xmmm16`][crate::encoders::vpbroadcastw::encode_xmm_xmmm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_xmmm16(xmm: XMM, xmmm16: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastw::encode_xmm_xmmm16(xmm, xmmm16) })
+}
+
+/// Broadcast word of XMM register or 16-bit memory to all elements of YMM register.
+///
+/// Safe variant of [`vpbroadcastw::encode_ymm_xmmm16`][crate::encoders::vpbroadcastw::encode_ymm_xmmm16].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_xmmm16(ymm: YMM, xmmm16: XMMOrMemory) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_ymm(ymm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm_or_memory(xmmm16) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpbroadcastw::encode_ymm_xmmm16(ymm, xmmm16) })
+}
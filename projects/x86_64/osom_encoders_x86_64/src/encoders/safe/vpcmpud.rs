@@ -0,0 +1,37 @@
+//! This module contains the safe encoders for the `vpcmpud` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compare packed unsigned doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+///
+/// Safe variant of [`vpcmpud::encode_k_mergemask_vzmm_zmmm512b32_imm8`][crate::encoders::vpcmpud::encode_k_mergemask_vzmm_zmmm512b32_imm8].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_k_mergemask_vzmm_zmmm512b32_imm8(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmm512b32: ZMMOrMemory,
+    imm8: Immediate8,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mergemask, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512b32, true) {
+        return Err(err);
+    }
+    Ok(unsafe {
+        crate::encoders::vpcmpud::encode_k_mergemask_vzmm_zmmm512b32_imm8(k, mergemask, vzmm, zmmm512b32, imm8)
+    })
+}
@@ -0,0 +1,92 @@
+//! This module contains the safe encoders for the `vpgatherqd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Gather packed doublewords from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// Safe variant of [`vpgatherqd::encode_xmm_vm64x_vxmm`][crate::encoders::vpgatherqd::encode_xmm_vm64x_vxmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vm64x_vxmm(
+    xmm: XMM,
+    vm64x: VSIBMemory,
+    vxmm: XMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64x, Size::Bit128, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(xmm.index(), vm64x, Some(vxmm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpgatherqd::encode_xmm_vm64x_vxmm(xmm, vm64x, vxmm) })
+}
+
+/// Gather packed doublewords from memory addressed by quadword indexes of YMM register into XMM register, under the mask of XMM VEX register.
+///
+/// Safe variant of [`vpgatherqd::encode_xmm_vm64y_vxmm`][crate::encoders::vpgatherqd::encode_xmm_vm64y_vxmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_xmm_vm64y_vxmm(
+    xmm: XMM,
+    vm64y: VSIBMemory,
+    vxmm: XMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_xmm(xmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64y, Size::Bit256, false) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_xmm(vxmm) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(xmm.index(), vm64y, Some(vxmm.index())) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpgatherqd::encode_xmm_vm64y_vxmm(xmm, vm64y, vxmm) })
+}
+
+/// Gather packed doublewords from memory addressed by quadword indexes of ZMM register into YMM register with merge masking.
+///
+/// Safe variant of [`vpgatherqd::encode_ymm_mergemask_vm64z`][crate::encoders::vpgatherqd::encode_ymm_mergemask_vm64z].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_ymm_mergemask_vm64z(
+    ymm: YMM,
+    mergemask: Masking,
+    vm64z: VSIBMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_vsib_masking(mergemask) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_memory(vm64z, Size::Bit512, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_gather_registers(ymm.index(), vm64z, None) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpgatherqd::encode_ymm_mergemask_vm64z(ymm, mergemask, vm64z) })
+}
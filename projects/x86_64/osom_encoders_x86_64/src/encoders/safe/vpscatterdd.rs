@@ -0,0 +1,33 @@
+//! This module contains the safe encoders for the `vpscatterdd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Scatter packed doublewords of ZMM register to memory addressed by doubleword indexes of ZMM register with merge masking.
+///
+/// Safe variant of [`vpscatterdd::encode_vm32z_mergemask_zmm`][crate::encoders::vpscatterdd::encode_vm32z_mergemask_zmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_vm32z_mergemask_zmm(
+    vm32z: VSIBMemory,
+    mergemask: Masking,
+    zmm: ZMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_vsib_memory(vm32z, Size::Bit512, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_masking(mergemask) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vpscatterdd::encode_vm32z_mergemask_zmm(vm32z, mergemask, zmm) })
+}
@@ -0,0 +1,33 @@
+//! This module contains the safe encoders for the `vscatterdpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Scatter packed double-precision values of ZMM register to memory addressed by doubleword indexes of YMM register with merge masking.
+///
+/// Safe variant of [`vscatterdpd::encode_vm32y_mergemask_zmm`][crate::encoders::vscatterdpd::encode_vm32y_mergemask_zmm].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_vm32y_mergemask_zmm(
+    vm32y: VSIBMemory,
+    mergemask: Masking,
+    zmm: ZMM,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_vsib_memory(vm32y, Size::Bit256, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_vsib_masking(mergemask) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vscatterdpd::encode_vm32y_mergemask_zmm(vm32y, mergemask, zmm) })
+}
@@ -0,0 +1,53 @@
+//! This module contains the safe encoders for the `vsqrtps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodeError, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32,
+    Immediate64, Masking, Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory,
+    YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+use crate::validation;
+
+/// Compute square roots of packed single-precision values of ZMM register, 512-bit memory or broadcast 32-bit memory into ZMM register with write masking.
+///
+/// Safe variant of [`vsqrtps::encode_zmm_mask_zmmm512b32`][crate::encoders::vsqrtps::encode_zmm_mask_zmmm512b32].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_zmmm512b32(
+    zmm: ZMM,
+    mask: Masking,
+    zmmm512b32: ZMMOrMemory,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    if let Err(err) = validation::validate_zmm_or_memory(zmmm512b32, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vsqrtps::encode_zmm_mask_zmmm512b32(zmm, mask, zmmm512b32) })
+}
+
+/// Compute square roots of packed single-precision values of ZMM register into ZMM register with write masking and static rounding.
+///
+/// Safe variant of [`vsqrtps::encode_zmm_mask_zmmr_er`][crate::encoders::vsqrtps::encode_zmm_mask_zmmr_er].
+///
+/// # Errors
+///
+/// Returns [`EncodeError`] if the operands are not valid for this instruction.
+#[inline]
+pub const fn encode_zmm_mask_zmmr_er(
+    zmm: ZMM,
+    mask: Masking,
+    zmmr: ZMM,
+    er: Rounding,
+) -> Result<EncodedX86_64Instruction, EncodeError> {
+    if let Err(err) = validation::validate_masking(mask, true) {
+        return Err(err);
+    }
+    Ok(unsafe { crate::encoders::vsqrtps::encode_zmm_mask_zmmr_er(zmm, mask, zmmr, er) })
+}
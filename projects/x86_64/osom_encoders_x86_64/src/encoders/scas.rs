@@ -0,0 +1,130 @@
+//! This module contains the encoders for the `scas` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare AL with byte at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAE]) }
+}
+
+/// Compare AX with word at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0x00, true, false) }
+}
+
+/// Compare EAX with doubleword at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAF]) }
+}
+
+/// Compare RAX with quadword at address RDI and set the status flags.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0x00, false, true) }
+}
+
+/// Find non-AL byte in RCX bytes at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAE], 0xF3, false, false) }
+}
+
+/// Find non-AX word in RCX words at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF3, true, false) }
+}
+
+/// Find non-EAX doubleword in RCX doublewords at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF3, false, false) }
+}
+
+/// Find non-RAX quadword in RCX quadwords at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repe_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF3, false, true) }
+}
+
+/// Find AL in RCX bytes at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAE], 0xF2, false, false) }
+}
+
+/// Find AX in RCX words at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF2, true, false) }
+}
+
+/// Find EAX in RCX doublewords at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF2, false, false) }
+}
+
+/// Find RAX in RCX quadwords at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_repne_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAF], 0xF2, false, true) }
+}
@@ -0,0 +1,30 @@
+//! This module contains the encoders for the `setcc` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Set 8-bit register or memory to 1 if condition is met, 0 otherwise.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_rm8(condition: Condition, rm8: GPROrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::m::encode_gpr_or_memory(
+            [0x0F, 0x90 | condition.code()],
+            0x00,
+            rm8,
+            Size::Bit8,
+            false,
+            false,
+        )
+    }
+}
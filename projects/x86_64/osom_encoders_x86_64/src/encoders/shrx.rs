@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `shrx` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Shift 32-bit register or memory logically right by 32-bit VEX register into 32-bit register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg32_rm32_vreg32(reg32: GPR, rm32: GPROrMemory, vreg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF7], 0xF2, false, reg32, vreg32, rm32) }
+}
+
+/// Shift 64-bit register or memory logically right by 64-bit VEX register into 64-bit register, without affecting flags.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_reg64_rm64_vreg64(reg64: GPR, rm64: GPROrMemory, vreg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_rvm([0x0F, 0x38, 0xF7], 0xF2, true, reg64, vreg64, rm64) }
+}
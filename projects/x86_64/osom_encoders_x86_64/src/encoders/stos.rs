@@ -0,0 +1,90 @@
+//! This module contains the encoders for the `stos` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Store AL at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAA]) }
+}
+
+/// Store AX at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAB], 0x00, true, false) }
+}
+
+/// Store EAX at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode([0xAB]) }
+}
+
+/// Store RAX at address RDI.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAB], 0x00, false, true) }
+}
+
+/// Fill RCX bytes at address RDI with AL.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_b() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAA], 0xF3, false, false) }
+}
+
+/// Fill RCX words at address RDI with AX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_w() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAB], 0xF3, true, false) }
+}
+
+/// Fill RCX doublewords at address RDI with EAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_d() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAB], 0xF3, false, false) }
+}
+
+/// Fill RCX quadwords at address RDI with RAX.
+///
+/// # Safety
+///
+/// This function is safe to call. It is marked as unsafe for consistency with other encoders.
+#[inline]
+pub const unsafe fn encode_rep_q() -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::zo::encode_with_prefixes([0xAB], 0xF3, false, true) }
+}
@@ -4,8 +4,9 @@
 // ** This file is automatically generated from x86.yaml schema. Do not modify! **
 
 use crate::models::{
-    EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Memory, Offset,
-    Scale, Size,
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
 };
 
 /// Subtract 8-bit immediate from AL register.
@@ -63,6 +64,24 @@ pub const unsafe fn encode_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> Encod
     unsafe { crate::partial_encoders::mi::encode_rm8_imm8([0x80], 0x05, rm8, imm8) }
 }
 
+/// Subtract 8-bit immediate from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm8_imm8(rm8: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm8_imm8(
+            [0x80],
+            0x05,
+            rm8,
+            imm8,
+        ))
+    }
+}
+
 /// Subtract 16-bit immediate from 16-bit register or memory.
 ///
 /// # Safety
@@ -74,6 +93,24 @@ pub const unsafe fn encode_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) ->
     unsafe { crate::partial_encoders::mi::encode_rm16_imm16([0x81], 0x05, rm16, imm16) }
 }
 
+/// Subtract 16-bit immediate from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_imm16(rm16: GPROrMemory, imm16: Immediate16) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm16_imm16(
+            [0x81],
+            0x05,
+            rm16,
+            imm16,
+        ))
+    }
+}
+
 /// Subtract 32-bit immediate from 32-bit register or memory.
 ///
 /// # Safety
@@ -85,6 +122,24 @@ pub const unsafe fn encode_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) ->
     unsafe { crate::partial_encoders::mi::encode_rm32_imm32([0x81], 0x05, rm32, imm32) }
 }
 
+/// Subtract 32-bit immediate from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_imm32(rm32: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm32_imm32(
+            [0x81],
+            0x05,
+            rm32,
+            imm32,
+        ))
+    }
+}
+
 /// Subtract 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits).
 ///
 /// # Safety
@@ -96,6 +151,24 @@ pub const unsafe fn encode_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) ->
     unsafe { crate::partial_encoders::mi::encode_rm64_imm32([0x81], 0x05, rm64, imm32) }
 }
 
+/// Subtract 32-bit immediate from 64-bit register or memory (sign-extended to 64 bits). LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_imm32(rm64: GPROrMemory, imm32: Immediate32) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm64_imm32(
+            [0x81],
+            0x05,
+            rm64,
+            imm32,
+        ))
+    }
+}
+
 /// Subtract 8-bit immediate (sign-extended) from 16-bit register or memory.
 ///
 /// # Safety
@@ -107,6 +180,24 @@ pub const unsafe fn encode_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> Enc
     unsafe { crate::partial_encoders::mi::encode_rm16_imm8([0x83], 0x05, rm16, imm8) }
 }
 
+/// Subtract 8-bit immediate (sign-extended) from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_imm8(rm16: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm16_imm8(
+            [0x83],
+            0x05,
+            rm16,
+            imm8,
+        ))
+    }
+}
+
 /// Subtract 8-bit immediate (sign-extended) from 32-bit register or memory.
 ///
 /// # Safety
@@ -118,6 +209,24 @@ pub const unsafe fn encode_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> Enc
     unsafe { crate::partial_encoders::mi::encode_rm32_imm8([0x83], 0x05, rm32, imm8) }
 }
 
+/// Subtract 8-bit immediate (sign-extended) from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_imm8(rm32: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm32_imm8(
+            [0x83],
+            0x05,
+            rm32,
+            imm8,
+        ))
+    }
+}
+
 /// Subtract 8-bit immediate (sign-extended) from 64-bit register or memory.
 ///
 /// # Safety
@@ -129,6 +238,24 @@ pub const unsafe fn encode_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> Enc
     unsafe { crate::partial_encoders::mi::encode_rm64_imm8([0x83], 0x05, rm64, imm8) }
 }
 
+/// Subtract 8-bit immediate (sign-extended) from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_imm8(rm64: GPROrMemory, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mi::encode_rm64_imm8(
+            [0x83],
+            0x05,
+            rm64,
+            imm8,
+        ))
+    }
+}
+
 /// Subtract 8-bit register from 8-bit register or memory.
 ///
 /// # Safety
@@ -140,6 +267,17 @@ pub const unsafe fn encode_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> EncodedX86_6
     unsafe { crate::partial_encoders::mr::encode([0x28], rm8, reg8) }
 }
 
+/// Subtract 8-bit register from 8-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm8_reg8(rm8: GPROrMemory, reg8: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x28], rm8, reg8)) }
+}
+
 /// Subtract 16-bit register from 16-bit register or memory.
 ///
 /// # Safety
@@ -151,6 +289,17 @@ pub const unsafe fn encode_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX
     unsafe { crate::partial_encoders::mr::encode([0x29], rm16, reg16) }
 }
 
+/// Subtract 16-bit register from 16-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm16_reg16(rm16: GPROrMemory, reg16: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x29], rm16, reg16)) }
+}
+
 /// Subtract 32-bit register from 32-bit register or memory.
 ///
 /// # Safety
@@ -162,6 +311,17 @@ pub const unsafe fn encode_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX
     unsafe { crate::partial_encoders::mr::encode([0x29], rm32, reg32) }
 }
 
+/// Subtract 32-bit register from 32-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm32_reg32(rm32: GPROrMemory, reg32: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x29], rm32, reg32)) }
+}
+
 /// Subtract 64-bit register from 64-bit register or memory.
 ///
 /// # Safety
@@ -173,6 +333,17 @@ pub const unsafe fn encode_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX
     unsafe { crate::partial_encoders::mr::encode([0x29], rm64, reg64) }
 }
 
+/// Subtract 64-bit register from 64-bit register or memory. LOCK-prefixed, i.e. atomic. The destination has to be memory.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid
+/// and that the destination is memory. Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_lock_rm64_reg64(rm64: GPROrMemory, reg64: GPR) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::core::with_lock_prefix(&crate::partial_encoders::mr::encode([0x29], rm64, reg64)) }
+}
+
 /// Subtract 8-bit register or memory from 8-bit register.
 ///
 /// # Safety
@@ -0,0 +1,75 @@
+//! This module contains the encoders for the `vaddpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Add packed double-precision values of XMM VEX register and XMM register or 128-bit memory into XMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vxmm_xmmm128(xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_xmm_or_memory([0x0F, 0x58], 0x66, false, xmm, vxmm, xmmm128) }
+}
+
+/// Add packed double-precision values of YMM VEX register and YMM register or 256-bit memory into YMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_vymm_ymmm256(ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_ymm_ymm_ymm_or_memory([0x0F, 0x58], 0x66, false, ymm, vymm, ymmm256) }
+}
+
+/// Add packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mask_vzmm_zmmm512b64(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_zmm_zmm_zmm_or_memory(
+            [0x0F, 0x58],
+            0x66,
+            true,
+            zmm,
+            mask,
+            vzmm,
+            zmmm512b64,
+        )
+    }
+}
+
+/// Add packed double-precision values of ZMM EVEX register and ZMM register into ZMM register with write masking and static rounding.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mask_vzmm_zmmr_er(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+    er: Rounding,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_zmm_zmm_zmm_er([0x0F, 0x58], 0x66, true, zmm, mask, vzmm, zmmr, er) }
+}
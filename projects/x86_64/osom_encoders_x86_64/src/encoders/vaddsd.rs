@@ -0,0 +1,45 @@
+//! This module contains the encoders for the `vaddsd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Add low double-precision values of XMM EVEX register and XMM register or 64-bit memory into XMM register with write masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_mask_vxmm_xmmm64(
+    xmm: XMM,
+    mask: Masking,
+    vxmm: XMM,
+    xmmm64: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_xmm_xmm_xmm_or_memory([0x0F, 0x58], 0xF2, true, xmm, mask, vxmm, xmmm64)
+    }
+}
+
+/// Add low double-precision values of XMM EVEX register and XMM register into XMM register with write masking and static rounding.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_mask_vxmm_xmmr_er(
+    xmm: XMM,
+    mask: Masking,
+    vxmm: XMM,
+    xmmr: XMM,
+    er: Rounding,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_xmm_xmm_xmm_er([0x0F, 0x58], 0xF2, true, xmm, mask, vxmm, xmmr, er) }
+}
@@ -0,0 +1,66 @@
+//! This module contains the encoders for the `vcmppd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare packed double-precision values of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory, using predicate selected by 8-bit immediate, into opmask register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_mergemask_vzmm_zmmm512b64_imm8(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_k_zmm_zmm_or_memory_imm8(
+            [0x0F, 0xC2],
+            0x66,
+            true,
+            k,
+            mergemask,
+            vzmm,
+            zmmm512b64,
+            imm8,
+        )
+    }
+}
+
+/// Compare packed double-precision values of ZMM EVEX register and ZMM register, using predicate selected by 8-bit immediate, into opmask register with merge masking and suppressed floating-point exceptions.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_mergemask_vzmm_zmmr_sae_imm8(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_k_zmm_zmm_sae_imm8(
+            [0x0F, 0xC2],
+            0x66,
+            true,
+            k,
+            mergemask,
+            vzmm,
+            zmmr,
+            imm8,
+        )
+    }
+}
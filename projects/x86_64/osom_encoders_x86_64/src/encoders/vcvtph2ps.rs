@@ -0,0 +1,32 @@
+//! This module contains the encoders for the `vcvtph2ps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Convert four packed half-precision values of XMM register or 64-bit memory to packed single-precision values in XMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_xmmm64(xmm: XMM, xmmm64: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_or_memory([0x0F, 0x38, 0x13], 0x66, false, xmm, xmmm64) }
+}
+
+/// Convert eight packed half-precision values of XMM register or 128-bit memory to packed single-precision values in YMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_xmmm128(ymm: YMM, xmmm128: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_ymm_xmm_or_memory([0x0F, 0x38, 0x13], 0x66, false, ymm, xmmm128) }
+}
@@ -0,0 +1,40 @@
+//! This module contains the encoders for the `vcvtps2ph` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Convert four packed single-precision values of XMM register to packed half-precision values in XMM register or 64-bit memory, with rounding control in the immediate.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmmm64_xmm_imm8(xmmm64: XMMOrMemory, xmm: XMM, imm8: Immediate8) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::vex::encode_xmm_xmm_or_memory_imm8([0x0F, 0x3A, 0x1D], 0x66, false, xmm, xmmm64, imm8)
+    }
+}
+
+/// Convert eight packed single-precision values of YMM register to packed half-precision values in XMM register or 128-bit memory, with rounding control in the immediate.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmmm128_ymm_imm8(
+    xmmm128: XMMOrMemory,
+    ymm: YMM,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::vex::encode_ymm_xmm_or_memory_imm8([0x0F, 0x3A, 0x1D], 0x66, false, ymm, xmmm128, imm8)
+    }
+}
@@ -0,0 +1,53 @@
+//! This module contains the encoders for the `vdivpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Divide packed double-precision values of ZMM EVEX register by ZMM register, 512-bit memory or broadcast 64-bit memory into ZMM register with write masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mask_vzmm_zmmm512b64(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_zmm_zmm_zmm_or_memory(
+            [0x0F, 0x5E],
+            0x66,
+            true,
+            zmm,
+            mask,
+            vzmm,
+            zmmm512b64,
+        )
+    }
+}
+
+/// Divide packed double-precision values of ZMM EVEX register by ZMM register into ZMM register with write masking and static rounding.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mask_vzmm_zmmr_er(
+    zmm: ZMM,
+    mask: Masking,
+    vzmm: ZMM,
+    zmmr: ZMM,
+    er: Rounding,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_zmm_zmm_zmm_er([0x0F, 0x5E], 0x66, true, zmm, mask, vzmm, zmmr, er) }
+}
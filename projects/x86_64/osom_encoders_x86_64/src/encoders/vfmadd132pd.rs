@@ -0,0 +1,36 @@
+//! This module contains the encoders for the `vfmadd132pd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Multiply packed double-precision values of XMM register and XMM register or 128-bit memory, add XMM VEX register and store into XMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vxmm_xmmm128(xmm: XMM, vxmm: XMM, xmmm128: XMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::vex::encode_xmm_xmm_xmm_or_memory([0x0F, 0x38, 0x98], 0x66, true, xmm, vxmm, xmmm128)
+    }
+}
+
+/// Multiply packed double-precision values of YMM register and YMM register or 256-bit memory, add YMM VEX register and store into YMM register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_vymm_ymmm256(ymm: YMM, vymm: YMM, ymmm256: YMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::vex::encode_ymm_ymm_ymm_or_memory([0x0F, 0x38, 0x98], 0x66, true, ymm, vymm, ymmm256)
+    }
+}
@@ -0,0 +1,47 @@
+//! This module contains the encoders for the `vgatherdpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Gather packed double-precision values from memory addressed by doubleword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vm32x_vxmm(xmm: XMM, vm32x: VSIBMemory, vxmm: XMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_vsib([0x0F, 0x38, 0x92], 0x66, true, xmm, vxmm, vm32x) }
+}
+
+/// Gather packed double-precision values from memory addressed by doubleword indexes of XMM register into YMM register, under the mask of YMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_vm32x_vymm(ymm: YMM, vm32x: VSIBMemory, vymm: YMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_ymm_ymm_vsib([0x0F, 0x38, 0x92], 0x66, true, ymm, vymm, vm32x) }
+}
+
+/// Gather packed double-precision values from memory addressed by doubleword indexes of YMM register into ZMM register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mergemask_vm32y(
+    zmm: ZMM,
+    mergemask: Masking,
+    vm32y: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_zmm_vsib([0x0F, 0x38, 0x92], 0x66, true, zmm, mergemask, vm32y) }
+}
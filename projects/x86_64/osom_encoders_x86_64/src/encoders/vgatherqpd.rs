@@ -0,0 +1,47 @@
+//! This module contains the encoders for the `vgatherqpd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vm64x_vxmm(xmm: XMM, vm64x: VSIBMemory, vxmm: XMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_vsib([0x0F, 0x38, 0x93], 0x66, true, xmm, vxmm, vm64x) }
+}
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of YMM register into YMM register, under the mask of YMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_vm64y_vymm(ymm: YMM, vm64y: VSIBMemory, vymm: YMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_ymm_ymm_vsib([0x0F, 0x38, 0x93], 0x66, true, ymm, vymm, vm64y) }
+}
+
+/// Gather packed double-precision values from memory addressed by quadword indexes of ZMM register into ZMM register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mergemask_vm64z(
+    zmm: ZMM,
+    mergemask: Masking,
+    vm64z: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_zmm_vsib([0x0F, 0x38, 0x93], 0x66, true, zmm, mergemask, vm64z) }
+}
@@ -0,0 +1,47 @@
+//! This module contains the encoders for the `vgatherqps` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Gather packed single-precision values from memory addressed by quadword indexes of XMM register into XMM register, under the mask of XMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vm64x_vxmm(xmm: XMM, vm64x: VSIBMemory, vxmm: XMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_vsib([0x0F, 0x38, 0x93], 0x66, false, xmm, vxmm, vm64x) }
+}
+
+/// Gather packed single-precision values from memory addressed by quadword indexes of YMM register into XMM register, under the mask of XMM VEX register.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_xmm_vm64y_vxmm(xmm: XMM, vm64y: VSIBMemory, vxmm: XMM) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::vex::encode_xmm_xmm_vsib([0x0F, 0x38, 0x93], 0x66, false, xmm, vxmm, vm64y) }
+}
+
+/// Gather packed single-precision values from memory addressed by quadword indexes of ZMM register into YMM register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_ymm_mergemask_vm64z(
+    ymm: YMM,
+    mergemask: Masking,
+    vm64z: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_ymm_vsib([0x0F, 0x38, 0x93], 0x66, false, ymm, mergemask, vm64z) }
+}
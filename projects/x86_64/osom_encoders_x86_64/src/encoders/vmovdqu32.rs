@@ -0,0 +1,38 @@
+//! This module contains the encoders for the `vmovdqu32` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Move unaligned packed doublewords from ZMM register or 512-bit memory to ZMM register with write masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmm_mask_zmmm512(zmm: ZMM, mask: Masking, zmmm512: ZMMOrMemory) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_zmm_zmm_or_memory([0x0F, 0x6F], 0xF3, false, zmm, mask, zmmm512) }
+}
+
+/// Move unaligned packed doublewords from ZMM register to ZMM register or 512-bit memory with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_zmmm512_mergemask_zmm(
+    zmmm512: ZMMOrMemory,
+    mergemask: Masking,
+    zmm: ZMM,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_zmm_zmm_or_memory([0x0F, 0x7F], 0xF3, false, zmm, mergemask, zmmm512)
+    }
+}
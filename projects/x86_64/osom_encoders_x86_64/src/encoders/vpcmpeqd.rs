@@ -0,0 +1,36 @@
+//! This module contains the encoders for the `vpcmpeqd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare packed doublewords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 32-bit memory for equality into opmask register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_mergemask_vzmm_zmmm512b32(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmm512b32: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_k_zmm_zmm_or_memory(
+            [0x0F, 0x76],
+            0x66,
+            false,
+            k,
+            mergemask,
+            vzmm,
+            zmmm512b32,
+        )
+    }
+}
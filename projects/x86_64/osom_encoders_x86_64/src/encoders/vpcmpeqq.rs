@@ -0,0 +1,36 @@
+//! This module contains the encoders for the `vpcmpeqq` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Compare packed quadwords of ZMM EVEX register and ZMM register, 512-bit memory or broadcast 64-bit memory for equality into opmask register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_k_mergemask_vzmm_zmmm512b64(
+    k: Opmask,
+    mergemask: Masking,
+    vzmm: ZMM,
+    zmmm512b64: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        crate::partial_encoders::evex::encode_k_zmm_zmm_or_memory(
+            [0x0F, 0x38, 0x29],
+            0x66,
+            true,
+            k,
+            mergemask,
+            vzmm,
+            zmmm512b64,
+        )
+    }
+}
@@ -0,0 +1,25 @@
+//! This module contains the encoders for the `vpscatterqd` instruction group.
+#![allow(unused_imports)]
+
+// ** This file is automatically generated from x86.yaml schema. Do not modify! **
+
+use crate::models::{
+    Condition, EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM,
+    ZMMOrMemory,
+};
+
+/// Scatter packed doublewords of YMM register to memory addressed by quadword indexes of ZMM register with merge masking.
+///
+/// # Safety
+///
+/// It is the caller's responsibility to ensure that the operands are valid.
+/// Otherwise the behavior is undefined.
+#[inline]
+pub const unsafe fn encode_vm64z_mergemask_ymm(
+    vm64z: VSIBMemory,
+    mergemask: Masking,
+    ymm: YMM,
+) -> EncodedX86_64Instruction {
+    unsafe { crate::partial_encoders::evex::encode_ymm_vsib([0x0F, 0x38, 0xA1], 0x66, false, ymm, mergemask, vm64z) }
+}
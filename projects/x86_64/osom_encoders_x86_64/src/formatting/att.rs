@@ -0,0 +1,223 @@
+use core::fmt::{self, Write};
+
+use crate::decoders::Instruction;
+use crate::models::{Masking, Memory, Offset, Operand, Size, VSIBMemory};
+
+/// Writes `instruction` in AT&T syntax, e.g. `movq $5, -8(%rbp,%rax,4)`.
+///
+/// The mnemonic is suffixed with the operation size, taken from the first
+/// register or sized memory operand. Instructions with immediate operands only
+/// (e.g. relative jumps), `setcc` (byte-sized only) and `cmpxchg8b` do not get a suffix,
+/// unless the size is implied by prefixes, e.g. `pushw $1`.
+/// Zero and sign extending moves are suffixed with both sizes instead,
+/// e.g. `movzbl %bl, %eax`. String instructions keep their size letter,
+/// except for doublewords which use `l`, e.g. `rep movsl`. Instructions with
+/// vector or opmask operands do not get a suffix either, unless the size is ambiguous,
+/// e.g. `cvtsi2sdl (%rax), %xmm0`.
+pub(crate) fn write_instruction<W: Write>(writer: &mut W, instruction: Instruction) -> fmt::Result {
+    let operands = instruction.operands();
+    let operands = operands.as_slice();
+    if let Some(prefix) = instruction.form().prefix {
+        writer.write_str(prefix)?;
+        writer.write_char(' ')?;
+    }
+    if let Some(mnemonic) = string_mnemonic(instruction.mnemonic(), operands) {
+        writer.write_str(mnemonic)?;
+    } else if let Some(mnemonic) = extension_mnemonic(instruction.mnemonic()) {
+        writer.write_str(mnemonic)?;
+        for operand in operands.iter().rev() {
+            if let Some(size) = operation_size(core::slice::from_ref(operand)) {
+                writer.write_char(size_suffix(size))?;
+            }
+        }
+    } else if has_xmm_operand(operands) {
+        writer.write_str(instruction.mnemonic())?;
+        if let Some(size) = sse_operation_size(instruction.mnemonic(), operands) {
+            writer.write_char(size_suffix(size))?;
+        }
+    } else {
+        writer.write_str(instruction.mnemonic())?;
+        if let Some(size) = operation_size(operands).or_else(|| implied_operation_size(instruction, operands))
+            && !instruction.mnemonic().starts_with("set")
+            && instruction.mnemonic() != "cmpxchg8b"
+        {
+            writer.write_char(size_suffix(size))?;
+        }
+    }
+
+    // The masking follows the operand it applies to in Intel order, and so
+    // in the reversed order it has to be written after the next operand.
+    let is_indirect = matches!(instruction, Instruction::JmpRm64 { .. } | Instruction::CallRm64 { .. });
+    let mut pending_masking = None;
+    for (idx, operand) in operands.iter().rev().enumerate() {
+        if let Operand::Masking { masking } = operand {
+            pending_masking = Some(*masking);
+            continue;
+        }
+        writer.write_str(if idx == 0 { " " } else { ", " })?;
+        if is_indirect {
+            writer.write_char('*')?;
+        }
+        write_operand(writer, *operand)?;
+        if let Some(masking) = pending_masking.take() {
+            write_masking(writer, masking)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the masking of an operand, e.g. ` {%k1} {z}`.
+fn write_masking<W: Write>(writer: &mut W, masking: Masking) -> fmt::Result {
+    match masking {
+        Masking::None => Ok(()),
+        Masking::Merge { opmask } => write!(writer, " {{%{opmask}}}"),
+        Masking::Zero { opmask } => write!(writer, " {{%{opmask}}} {{z}}"),
+    }
+}
+
+/// Returns the AT&T mnemonic (without suffixes) of zero and sign extending moves.
+fn extension_mnemonic(mnemonic: &str) -> Option<&'static str> {
+    match mnemonic {
+        "movzx" => Some("movz"),
+        "movsx" | "movsxd" => Some("movs"),
+        _ => None,
+    }
+}
+
+/// Returns the AT&T mnemonic of doubleword string instructions.
+fn string_mnemonic(mnemonic: &str, operands: &[Operand]) -> Option<&'static str> {
+    if !operands.is_empty() {
+        return None;
+    }
+    match mnemonic {
+        "movsd" => Some("movsl"),
+        "stosd" => Some("stosl"),
+        "lodsd" => Some("lodsl"),
+        "cmpsd" => Some("cmpsl"),
+        "scasd" => Some("scasl"),
+        _ => None,
+    }
+}
+
+fn operation_size(operands: &[Operand]) -> Option<Size> {
+    operands.iter().find_map(|operand| match operand {
+        Operand::GPR { gpr } => Some(gpr.size()),
+        // Vector sized memory (e.g. `m128` of `cmpxchg16b`) has no size suffix.
+        Operand::Memory { size, .. } => size.filter(|size| !matches!(size, Size::Bit128 | Size::Bit256 | Size::Bit512)),
+        _ => None,
+    })
+}
+
+/// Returns the operation size of instructions with immediate operands only,
+/// whose size is implied by prefixes, e.g. `pushw $1` (encoded as `66 68 01 00`).
+fn implied_operation_size(instruction: Instruction, operands: &[Operand]) -> Option<Size> {
+    instruction.form().operation_size.filter(|_| {
+        !operands.is_empty()
+            && operands
+                .iter()
+                .all(|operand| matches!(operand, Operand::Immediate { .. }))
+    })
+}
+
+fn has_xmm_operand(operands: &[Operand]) -> bool {
+    operands.iter().any(|operand| {
+        matches!(
+            operand,
+            Operand::XMM { .. } | Operand::YMM { .. } | Operand::ZMM { .. } | Operand::Opmask { .. }
+        )
+    })
+}
+
+/// Returns the suffix size of SSE instructions, which is needed only by
+/// integer conversions with memory source, e.g. `cvtsi2sdq (%rax), %xmm0`.
+fn sse_operation_size(mnemonic: &str, operands: &[Operand]) -> Option<Size> {
+    if !mnemonic.starts_with("cvtsi2") {
+        return None;
+    }
+    operands.iter().find_map(|operand| match operand {
+        Operand::Memory { size, .. } => *size,
+        _ => None,
+    })
+}
+
+fn write_operand<W: Write>(writer: &mut W, operand: Operand) -> fmt::Result {
+    match operand {
+        Operand::GPR { gpr } => write!(writer, "%{}", gpr.name()),
+        Operand::Memory { memory, .. } => write_memory(writer, memory),
+        Operand::Immediate { value, .. } => write!(writer, "${value}"),
+        Operand::XMM { xmm } => write!(writer, "%{}", xmm.name()),
+        Operand::YMM { ymm } => write!(writer, "%{}", ymm.name()),
+        Operand::ZMM { zmm } => write!(writer, "%{}", zmm.name()),
+        Operand::Opmask { opmask } => write!(writer, "%{}", opmask.name()),
+        Operand::Masking { masking } => write_masking(writer, masking),
+        Operand::Broadcast { memory, count, .. } => {
+            write_memory(writer, memory)?;
+            write!(writer, "{{1to{count}}}")
+        }
+        Operand::Rounding { rounding } => write!(writer, "{{{rounding}}}"),
+        Operand::Sae => writer.write_str("{sae}"),
+        Operand::VSIBMemory { memory, .. } => write_vsib_memory(writer, memory),
+    }
+}
+
+fn write_memory<W: Write>(writer: &mut W, memory: Memory) -> fmt::Result {
+    match memory {
+        Memory::Based { base, offset } => {
+            write_offset(writer, offset)?;
+            write!(writer, "(%{})", base.name())
+        }
+        Memory::Scaled { index, scale, offset } => {
+            write_offset(writer, offset)?;
+            write!(writer, "(,%{},{})", index.name(), scale.factor())
+        }
+        Memory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            write_offset(writer, offset)?;
+            write!(writer, "(%{},%{},{})", base.name(), index.name(), scale.factor())
+        }
+        Memory::RelativeToRIP { offset } => {
+            write_offset(writer, offset)?;
+            writer.write_str("(%rip)")
+        }
+    }
+}
+
+fn write_vsib_memory<W: Write>(writer: &mut W, memory: VSIBMemory) -> fmt::Result {
+    match memory {
+        VSIBMemory::Scaled { index, scale, offset } => {
+            write_offset(writer, offset)?;
+            write!(writer, "(,%{},{})", index.name(), scale.factor())
+        }
+        VSIBMemory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            write_offset(writer, offset)?;
+            write!(writer, "(%{},%{},{})", base.name(), index.name(), scale.factor())
+        }
+    }
+}
+
+fn write_offset<W: Write>(writer: &mut W, offset: Offset) -> fmt::Result {
+    let value = offset.to_sign_extended_imm32().as_i32();
+    if value == 0 {
+        return Ok(());
+    }
+    write!(writer, "{value}")
+}
+
+const fn size_suffix(size: Size) -> char {
+    match size {
+        Size::Bit8 => 'b',
+        Size::Bit16 => 'w',
+        Size::Bit32 => 'l',
+        Size::Bit64 => 'q',
+        Size::Bit128 | Size::Bit256 | Size::Bit512 => panic!("Vector operations have no size suffix."),
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt::{self, Write};
+
+use crate::decoders::Instruction;
+use crate::models::{Masking, Memory, Offset, Operand, Scale, Size, VSIBMemory};
+
+/// Writes `instruction` in Intel syntax, e.g. `mov qword ptr [rbp + rax*4 - 8], 5`.
+pub(crate) fn write_instruction<W: Write>(writer: &mut W, instruction: Instruction) -> fmt::Result {
+    if let Some(prefix) = instruction.form().prefix {
+        writer.write_str(prefix)?;
+        writer.write_char(' ')?;
+    }
+    writer.write_str(instruction.mnemonic())?;
+    let operands = instruction.operands();
+    let implied_size = implied_operation_size(instruction.form().operation_size, operands.as_slice());
+    for (idx, operand) in operands.as_slice().iter().enumerate() {
+        if let Operand::Masking { masking } = operand {
+            write_masking(writer, *masking)?;
+            continue;
+        }
+        writer.write_str(if idx == 0 { " " } else { ", " })?;
+        if let Some(size) = implied_size {
+            writer.write_str(size_keyword(size))?;
+            writer.write_char(' ')?;
+        }
+        write_operand(writer, *operand)?;
+    }
+    Ok(())
+}
+
+/// Returns the operation size of instructions with immediate operands only,
+/// whose size is implied by prefixes, e.g. `push word 1` (encoded as `66 68 01 00`).
+/// Such a size has to be explicit, otherwise it reads as a different instruction.
+fn implied_operation_size(operation_size: Option<Size>, operands: &[Operand]) -> Option<Size> {
+    operation_size.filter(|_| {
+        !operands.is_empty()
+            && operands
+                .iter()
+                .all(|operand| matches!(operand, Operand::Immediate { .. }))
+    })
+}
+
+/// Writes the masking of the preceding operand, e.g. ` {k1} {z}`.
+fn write_masking<W: Write>(writer: &mut W, masking: Masking) -> fmt::Result {
+    match masking {
+        Masking::None => Ok(()),
+        Masking::Merge { opmask } => write!(writer, " {{{opmask}}}"),
+        Masking::Zero { opmask } => write!(writer, " {{{opmask}}} {{z}}"),
+    }
+}
+
+fn write_operand<W: Write>(writer: &mut W, operand: Operand) -> fmt::Result {
+    match operand {
+        Operand::GPR { gpr } => writer.write_str(gpr.name()),
+        Operand::Memory { memory, size } => {
+            if let Some(size) = size {
+                writer.write_str(size_keyword(size))?;
+                writer.write_str(" ptr ")?;
+            }
+            write_memory(writer, memory)
+        }
+        Operand::Immediate { value, .. } => write!(writer, "{value}"),
+        Operand::XMM { xmm } => writer.write_str(xmm.name()),
+        Operand::YMM { ymm } => writer.write_str(ymm.name()),
+        Operand::ZMM { zmm } => writer.write_str(zmm.name()),
+        Operand::Opmask { opmask } => writer.write_str(opmask.name()),
+        Operand::Masking { masking } => write_masking(writer, masking),
+        Operand::Broadcast { memory, size, count } => {
+            writer.write_str(size_keyword(size))?;
+            writer.write_str(" ptr ")?;
+            write_memory(writer, memory)?;
+            write!(writer, "{{1to{count}}}")
+        }
+        Operand::Rounding { rounding } => write!(writer, "{{{rounding}}}"),
+        Operand::Sae => writer.write_str("{sae}"),
+        Operand::VSIBMemory { memory, size } => {
+            writer.write_str(size_keyword(size))?;
+            writer.write_str(" ptr ")?;
+            write_vsib_memory(writer, memory)
+        }
+    }
+}
+
+fn write_memory<W: Write>(writer: &mut W, memory: Memory) -> fmt::Result {
+    writer.write_char('[')?;
+    let offset = match memory {
+        Memory::Based { base, offset } => {
+            writer.write_str(base.name())?;
+            offset
+        }
+        Memory::Scaled { index, scale, offset } => {
+            write_index(writer, index.name(), scale)?;
+            offset
+        }
+        Memory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            writer.write_str(base.name())?;
+            writer.write_str(" + ")?;
+            write_index(writer, index.name(), scale)?;
+            offset
+        }
+        Memory::RelativeToRIP { offset } => {
+            writer.write_str("rip")?;
+            offset
+        }
+    };
+    write_offset(writer, offset)?;
+    writer.write_char(']')
+}
+
+fn write_vsib_memory<W: Write>(writer: &mut W, memory: VSIBMemory) -> fmt::Result {
+    writer.write_char('[')?;
+    let offset = match memory {
+        VSIBMemory::Scaled { index, scale, offset } => {
+            write_index(writer, index.name(), scale)?;
+            offset
+        }
+        VSIBMemory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            writer.write_str(base.name())?;
+            writer.write_str(" + ")?;
+            write_index(writer, index.name(), scale)?;
+            offset
+        }
+    };
+    write_offset(writer, offset)?;
+    writer.write_char(']')
+}
+
+fn write_index<W: Write>(writer: &mut W, index: &str, scale: Scale) -> fmt::Result {
+    writer.write_str(index)?;
+    if scale.equals(Scale::Scale1) {
+        return Ok(());
+    }
+    write!(writer, "*{}", scale.factor())
+}
+
+fn write_offset<W: Write>(writer: &mut W, offset: Offset) -> fmt::Result {
+    let value = offset.to_sign_extended_imm32().as_i32();
+    match value {
+        0 => Ok(()),
+        1.. => write!(writer, " + {value}"),
+        _ => write!(writer, " - {}", value.unsigned_abs()),
+    }
+}
+
+const fn size_keyword(size: Size) -> &'static str {
+    match size {
+        Size::Bit8 => "byte",
+        Size::Bit16 => "word",
+        Size::Bit32 => "dword",
+        Size::Bit64 => "qword",
+        Size::Bit128 => "xmmword",
+        Size::Bit256 => "ymmword",
+        Size::Bit512 => "zmmword",
+    }
+}
@@ -0,0 +1,83 @@
+//! This module contains text formatters for [`Instruction`], e.g. rendering
+//! `mov qword ptr [rbp + rax*4 - 8], 5` in Intel syntax or `movq $5, -8(%rbp,%rax,4)`
+//! in AT&T syntax.
+//!
+//! Mnemonics and operands are generated from `x86.yaml` (see
+//! [`Instruction::mnemonic`] and [`Instruction::operands`]), so new instructions
+//! get formatting for free.
+//!
+//! # Notes
+//!
+//! Immediates are rendered as signed decimal numbers. In particular relative jumps
+//! and calls are rendered with the raw displacement, not the target address.
+
+mod att;
+mod intel;
+
+use core::fmt;
+
+use crate::decoders::Instruction;
+
+/// Represents the assembly syntax used for formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[must_use]
+pub enum Syntax {
+    /// Intel syntax, e.g. `mov qword ptr [rbp + rax*4 - 8], 5`.
+    #[default]
+    Intel,
+
+    /// AT&T syntax, as used by GNU `as` and `objdump`, e.g. `movq $5, -8(%rbp,%rax,4)`.
+    Att,
+}
+
+/// Represents the options of the instruction formatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[must_use]
+pub struct FormatterOptions {
+    pub syntax: Syntax,
+}
+
+impl FormatterOptions {
+    /// Creates new [`FormatterOptions`] with given `syntax`.
+    #[inline]
+    pub const fn new(syntax: Syntax) -> Self {
+        Self { syntax }
+    }
+}
+
+/// Helper struct for formatting [`Instruction`] with [`FormatterOptions`].
+/// Created by [`Instruction::display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct InstructionDisplay {
+    instruction: Instruction,
+    options: FormatterOptions,
+}
+
+impl Instruction {
+    /// Returns an object implementing [`Display`][fmt::Display], which formats
+    /// the instruction according to `options`.
+    #[inline]
+    pub const fn display(self, options: FormatterOptions) -> InstructionDisplay {
+        InstructionDisplay {
+            instruction: self,
+            options,
+        }
+    }
+}
+
+impl fmt::Display for InstructionDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.options.syntax {
+            Syntax::Intel => intel::write_instruction(f, self.instruction),
+            Syntax::Att => att::write_instruction(f, self.instruction),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    /// Formats the instruction in Intel syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        intel::write_instruction(f, *self)
+    }
+}
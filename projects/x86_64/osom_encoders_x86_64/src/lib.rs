@@ -6,7 +6,18 @@
 #![allow(clippy::inline_always)]
 #![no_std]
 
+extern crate alloc;
+
+pub mod assembler;
 pub mod constants;
+pub mod decoders;
+pub mod elf;
 pub mod encoders;
+pub mod formatting;
+#[doc(hidden)]
+pub mod macro_support;
 pub mod models;
+pub mod parsing;
+mod partial_decoders;
 mod partial_encoders;
+mod validation;
@@ -0,0 +1,77 @@
+//! Helpers used by the code generated by the `x86_64_asm!` macro from the
+//! `osom_encoders_x86_64_macros` crate. This is not a public API.
+
+use crate::decoders::Instruction;
+use crate::models::{EncodeError, EncodedX86_64Instruction};
+
+/// Represents a part of `x86_64_asm!` output.
+#[derive(Debug, Clone, Copy)]
+pub enum Chunk {
+    /// Bytes already encoded by the macro.
+    Bytes(&'static [u8]),
+
+    /// Instruction with interpolated operands, encoded at compile time.
+    Instruction(Instruction),
+}
+
+/// Returns the total length of encoded `chunks`.
+///
+/// # Panics
+///
+/// If any of the instructions cannot be encoded.
+#[must_use]
+pub const fn encoded_len(chunks: &[Chunk]) -> usize {
+    let mut result = 0;
+    let mut idx = 0;
+    while idx < chunks.len() {
+        result += match chunks[idx] {
+            Chunk::Bytes(bytes) => bytes.len(),
+            Chunk::Instruction(instruction) => encode_instruction(instruction).as_slice().len(),
+        };
+        idx += 1;
+    }
+    result
+}
+
+/// Encodes `chunks` into a single array.
+///
+/// # Panics
+///
+/// If any of the instructions cannot be encoded or if `N` is not
+/// equal to [`encoded_len`] of `chunks`.
+#[must_use]
+pub const fn encode<const N: usize>(chunks: &[Chunk]) -> [u8; N] {
+    let mut result = [0u8; N];
+    let mut position = 0;
+    let mut idx = 0;
+    while idx < chunks.len() {
+        let encoded;
+        let bytes = match chunks[idx] {
+            Chunk::Bytes(bytes) => bytes,
+            Chunk::Instruction(instruction) => {
+                encoded = encode_instruction(instruction);
+                encoded.as_slice()
+            }
+        };
+        let mut byte_idx = 0;
+        while byte_idx < bytes.len() {
+            result[position] = bytes[byte_idx];
+            position += 1;
+            byte_idx += 1;
+        }
+        idx += 1;
+    }
+    assert!(position == N, "Invalid length of the encoded chunks.");
+    result
+}
+
+const fn encode_instruction(instruction: Instruction) -> EncodedX86_64Instruction {
+    match instruction.encode() {
+        Ok(encoded) => encoded,
+        Err(EncodeError::InvalidGPRSize { .. }) => panic!("Interpolated register has invalid size."),
+        Err(EncodeError::Bit8HighRegisterWithRex { .. }) => {
+            panic!("Interpolated AH, BH, CH or DH register cannot be encoded in instruction requiring REX prefix.")
+        }
+        Err(_) => panic!("Instruction cannot be encoded."),
+    }
+}
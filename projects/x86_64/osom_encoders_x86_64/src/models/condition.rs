@@ -0,0 +1,228 @@
+use core::mem::transmute;
+
+/// Represents the condition of `jcc`, `setcc` and `cmovcc` instructions.
+/// The value of each variant is the condition code, i.e. the lower 4 bits
+/// of the opcode.
+///
+/// Conditions with multiple names (e.g. `e` and `z`) are available
+/// as associated constants, see [`Condition::Z`] and others.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum Condition {
+    /// Overflow, i.e. `OF = 1`.
+    O = 0x0,
+
+    /// No overflow, i.e. `OF = 0`.
+    NO = 0x1,
+
+    /// Below (in unsigned sense), i.e. `CF = 1`.
+    B = 0x2,
+
+    /// Above or equal (in unsigned sense), i.e. `CF = 0`.
+    AE = 0x3,
+
+    /// Equal, i.e. `ZF = 1`.
+    E = 0x4,
+
+    /// Not equal, i.e. `ZF = 0`.
+    NE = 0x5,
+
+    /// Below or equal (in unsigned sense), i.e. `CF = 1 or ZF = 1`.
+    BE = 0x6,
+
+    /// Above (in unsigned sense), i.e. `CF = 0 and ZF = 0`.
+    A = 0x7,
+
+    /// Sign, i.e. `SF = 1`.
+    S = 0x8,
+
+    /// No sign, i.e. `SF = 0`.
+    NS = 0x9,
+
+    /// Parity (even), i.e. `PF = 1`.
+    P = 0xA,
+
+    /// No parity (parity odd), i.e. `PF = 0`.
+    NP = 0xB,
+
+    /// Less (in signed sense), i.e. `SF != OF`.
+    L = 0xC,
+
+    /// Greater or equal (in signed sense), i.e. `SF = OF`.
+    GE = 0xD,
+
+    /// Less or equal (in signed sense), i.e. `ZF = 1 or SF != OF`.
+    LE = 0xE,
+
+    /// Greater (in signed sense), i.e. `ZF = 0 and SF = OF`.
+    G = 0xF,
+}
+
+impl Condition {
+    /// Carry, alias of [`Condition::B`].
+    pub const C: Self = Self::B;
+
+    /// Not above or equal, alias of [`Condition::B`].
+    pub const NAE: Self = Self::B;
+
+    /// Not carry, alias of [`Condition::AE`].
+    pub const NC: Self = Self::AE;
+
+    /// Not below, alias of [`Condition::AE`].
+    pub const NB: Self = Self::AE;
+
+    /// Zero, alias of [`Condition::E`].
+    pub const Z: Self = Self::E;
+
+    /// Not zero, alias of [`Condition::NE`].
+    pub const NZ: Self = Self::NE;
+
+    /// Not above, alias of [`Condition::BE`].
+    pub const NA: Self = Self::BE;
+
+    /// Not below or equal, alias of [`Condition::A`].
+    pub const NBE: Self = Self::A;
+
+    /// Parity even, alias of [`Condition::P`].
+    pub const PE: Self = Self::P;
+
+    /// Parity odd, alias of [`Condition::NP`].
+    pub const PO: Self = Self::NP;
+
+    /// Not greater or equal, alias of [`Condition::L`].
+    pub const NGE: Self = Self::L;
+
+    /// Not less, alias of [`Condition::GE`].
+    pub const NL: Self = Self::GE;
+
+    /// Not greater, alias of [`Condition::LE`].
+    pub const NG: Self = Self::LE;
+
+    /// Not less or equal, alias of [`Condition::G`].
+    pub const NLE: Self = Self::G;
+
+    /// All conditions, ordered by their condition code.
+    pub const ALL: [Self; 16] = [
+        Self::O,
+        Self::NO,
+        Self::B,
+        Self::AE,
+        Self::E,
+        Self::NE,
+        Self::BE,
+        Self::A,
+        Self::S,
+        Self::NS,
+        Self::P,
+        Self::NP,
+        Self::L,
+        Self::GE,
+        Self::LE,
+        Self::G,
+    ];
+
+    /// Compares two [`Condition`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.code() == other.code()
+    }
+
+    /// Returns the condition code, i.e. a value in `0..=15` range.
+    #[inline(always)]
+    #[must_use]
+    pub const fn code(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result <= 0xF);
+            result
+        }
+    }
+
+    /// Creates a [`Condition`] from its condition code. Returns `None`
+    /// if `code` is not in `0..=15` range.
+    #[inline]
+    #[must_use]
+    pub const fn from_code(code: u8) -> Option<Self> {
+        if code > 0xF {
+            return None;
+        }
+        Some(unsafe { transmute::<u8, Self>(code) })
+    }
+
+    /// Returns the opposite condition, e.g. [`Condition::NE`]
+    /// for [`Condition::E`]. It is met exactly when `self` is not.
+    #[inline(always)]
+    pub const fn negate(self) -> Self {
+        unsafe { transmute::<u8, Self>(self.code() ^ 1) }
+    }
+
+    /// Returns `true` if the condition is an unsigned comparison,
+    /// i.e. one of [`Condition::A`], [`Condition::AE`], [`Condition::B`]
+    /// and [`Condition::BE`].
+    #[inline]
+    #[must_use]
+    pub const fn is_unsigned(self) -> bool {
+        matches!(self, Self::A | Self::AE | Self::B | Self::BE)
+    }
+
+    /// Returns `true` if the condition is a signed comparison,
+    /// i.e. one of [`Condition::G`], [`Condition::GE`], [`Condition::L`]
+    /// and [`Condition::LE`].
+    #[inline]
+    #[must_use]
+    pub const fn is_signed(self) -> bool {
+        matches!(self, Self::G | Self::GE | Self::L | Self::LE)
+    }
+
+    /// Returns the canonical name of the condition, i.e. the mnemonic
+    /// suffix, e.g. `"ae"` for [`Condition::AE`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::O => "o",
+            Self::NO => "no",
+            Self::B => "b",
+            Self::AE => "ae",
+            Self::E => "e",
+            Self::NE => "ne",
+            Self::BE => "be",
+            Self::A => "a",
+            Self::S => "s",
+            Self::NS => "ns",
+            Self::P => "p",
+            Self::NP => "np",
+            Self::L => "l",
+            Self::GE => "ge",
+            Self::LE => "le",
+            Self::G => "g",
+        }
+    }
+
+    /// Parses the condition from its name or any of its aliases,
+    /// e.g. `"z"` gives [`Condition::E`]. The name has to be lowercase.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let condition = match name {
+            "o" => Self::O,
+            "no" => Self::NO,
+            "b" | "c" | "nae" => Self::B,
+            "ae" | "nc" | "nb" => Self::AE,
+            "e" | "z" => Self::E,
+            "ne" | "nz" => Self::NE,
+            "be" | "na" => Self::BE,
+            "a" | "nbe" => Self::A,
+            "s" => Self::S,
+            "ns" => Self::NS,
+            "p" | "pe" => Self::P,
+            "np" | "po" => Self::NP,
+            "l" | "nge" => Self::L,
+            "ge" | "nl" => Self::GE,
+            "le" | "ng" => Self::LE,
+            "g" | "nle" => Self::G,
+            _ => return None,
+        };
+        Some(condition)
+    }
+}
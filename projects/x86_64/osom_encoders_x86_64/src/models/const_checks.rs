@@ -1,8 +1,9 @@
 #![allow(clippy::unreadable_literal)]
 
 use super::{
-    EncodedX86_64Instruction, GPR, GPRKind, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Memory,
-    Offset, Scale, Size,
+    EncodedX86_64Instruction, GPR, GPRKind, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking,
+    Memory, Offset, Opmask, OpmaskOrMemory, Rounding, Scale, Size, VSIBIndex, VSIBMemory, XMM, XMMOrMemory, YMM,
+    YMMOrMemory, ZMM, ZMMOrMemory,
 };
 
 const _VALIDATE_IMMEDIATES: () = const {
@@ -213,10 +214,54 @@ const _VALIDATE_SIZES: () = const {
         size_of::<Option<Memory>>() <= 16,
         "Option<Memory> size is greater than 16"
     );
+    assert!(size_of::<VSIBIndex>() == 2, "VSIBIndex size is not 2");
+    assert!(size_of::<VSIBMemory>() <= 16, "VSIBMemory size is greater than 16");
+    assert!(
+        size_of::<Option<VSIBMemory>>() <= 16,
+        "Option<VSIBMemory> size is greater than 16"
+    );
 
     assert!(size_of::<GPROrMemory>() <= 16, "GPROrMemory size is greater than 16");
     assert!(
         size_of::<Option<GPROrMemory>>() <= 16,
         "Option<GPROrMemory> size is greater than 16"
     );
+
+    assert!(size_of::<XMM>() == 1, "XMM size is not 1");
+    assert!(size_of::<Option<XMM>>() == 1, "Option<XMM> size is not 1");
+    assert!(size_of::<XMMOrMemory>() <= 16, "XMMOrMemory size is greater than 16");
+    assert!(
+        size_of::<Option<XMMOrMemory>>() <= 16,
+        "Option<XMMOrMemory> size is greater than 16"
+    );
+
+    assert!(size_of::<YMM>() == 1, "YMM size is not 1");
+    assert!(size_of::<Option<YMM>>() == 1, "Option<YMM> size is not 1");
+    assert!(size_of::<YMMOrMemory>() <= 16, "YMMOrMemory size is greater than 16");
+    assert!(
+        size_of::<Option<YMMOrMemory>>() <= 16,
+        "Option<YMMOrMemory> size is greater than 16"
+    );
+
+    assert!(size_of::<ZMM>() == 1, "ZMM size is not 1");
+    assert!(size_of::<Option<ZMM>>() == 1, "Option<ZMM> size is not 1");
+    assert!(size_of::<ZMMOrMemory>() <= 16, "ZMMOrMemory size is greater than 16");
+    assert!(
+        size_of::<Option<ZMMOrMemory>>() <= 16,
+        "Option<ZMMOrMemory> size is greater than 16"
+    );
+
+    assert!(size_of::<Opmask>() == 1, "Opmask size is not 1");
+    assert!(size_of::<Option<Opmask>>() == 1, "Option<Opmask> size is not 1");
+    assert!(
+        size_of::<OpmaskOrMemory>() <= 16,
+        "OpmaskOrMemory size is greater than 16"
+    );
+    assert!(
+        size_of::<Option<OpmaskOrMemory>>() <= 16,
+        "Option<OpmaskOrMemory> size is greater than 16"
+    );
+    assert!(size_of::<Masking>() <= 2, "Masking size is greater than 2");
+    assert!(size_of::<Rounding>() == 1, "Rounding size is not 1");
+    assert!(size_of::<Option<Rounding>>() == 1, "Option<Rounding> size is not 1");
 };
@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Represents an error returned by the decoders, whenever
+/// passed bytes cannot be decoded into a supported instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[must_use]
+pub enum DecodeError {
+    /// The bytes end in the middle of an instruction.
+    UnexpectedEnd = 1,
+
+    /// The bytes do not correspond to any supported instruction.
+    UnknownInstruction = 2,
+
+    /// The memory operand uses addressing that cannot be represented
+    /// by [`Memory`][super::Memory], e.g. absolute 32-bit address.
+    UnsupportedAddressing = 3,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of instruction"),
+            Self::UnknownInstruction => write!(f, "unknown instruction"),
+            Self::UnsupportedAddressing => write!(f, "unsupported memory addressing"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
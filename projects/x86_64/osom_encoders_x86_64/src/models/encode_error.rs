@@ -0,0 +1,101 @@
+use core::fmt;
+
+use super::{GPR, Size, VSIBIndex, XMM, YMM};
+
+/// Represents an error returned by the safe encoders, whenever
+/// passed operands cannot be encoded by a given instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[must_use]
+pub enum EncodeError {
+    /// The register does not have the size required by the instruction.
+    InvalidGPRSize { gpr: GPR, expected: Size } = 1,
+
+    /// The memory operand uses a base or index register that is not 64-bit wide.
+    InvalidMemoryRegisterSize { gpr: GPR } = 2,
+
+    /// The memory operand uses `RSP` as index, which cannot be encoded.
+    InvalidMemoryIndex { index: GPR } = 3,
+
+    /// One of AH, BH, CH or DH registers is used in an instruction
+    /// that requires REX prefix. Those registers are not encodable
+    /// in the presence of REX prefix.
+    Bit8HighRegisterWithRex { gpr: GPR } = 4,
+
+    /// LOCK prefix is used with a register destination, which
+    /// the processor rejects with #UD.
+    LockWithRegisterDestination { gpr: GPR } = 5,
+
+    /// One of XMM16 to XMM31 registers is used in an instruction
+    /// that is not EVEX encoded.
+    XMMRequiresEvex { xmm: XMM } = 6,
+
+    /// One of YMM16 to YMM31 registers is used in an instruction
+    /// that is not EVEX encoded.
+    YMMRequiresEvex { ymm: YMM } = 7,
+
+    /// K0 is used as a write mask. Its encoding means "no masking".
+    InvalidWriteMask = 8,
+
+    /// Zeroing masking is used in an instruction that only supports
+    /// merge masking, e.g. stores and compares into a mask.
+    ZeroingMaskingNotAllowed = 9,
+
+    /// Embedded broadcast is used in an instruction that does not support it.
+    BroadcastNotAllowed = 10,
+
+    /// The instruction requires a write mask, e.g. gathers and scatters
+    /// use it to track the elements that are still to be processed.
+    WriteMaskRequired = 11,
+
+    /// The vector index of the VSIB memory operand does not have the size
+    /// required by the instruction.
+    InvalidVSIBIndex { index: VSIBIndex, expected: Size } = 12,
+
+    /// The destination, the vector index and the mask of a gather are not
+    /// distinct registers, which the processor rejects with #UD.
+    GatherRegistersNotDistinct = 13,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGPRSize { gpr, expected } => {
+                write!(f, "register {gpr:?} has invalid size, expected {expected:?}")
+            }
+            Self::InvalidMemoryRegisterSize { gpr } => {
+                write!(f, "memory operand register {gpr:?} is not 64-bit wide")
+            }
+            Self::InvalidMemoryIndex { index } => {
+                write!(f, "register {index:?} cannot be used as memory index")
+            }
+            Self::Bit8HighRegisterWithRex { gpr } => {
+                write!(
+                    f,
+                    "register {gpr:?} cannot be encoded in instruction requiring REX prefix"
+                )
+            }
+            Self::LockWithRegisterDestination { gpr } => {
+                write!(f, "LOCK prefix cannot be used with register {gpr:?} as destination")
+            }
+            Self::XMMRequiresEvex { xmm } => {
+                write!(f, "register {xmm:?} can only be used in EVEX encoded instructions")
+            }
+            Self::YMMRequiresEvex { ymm } => {
+                write!(f, "register {ymm:?} can only be used in EVEX encoded instructions")
+            }
+            Self::InvalidWriteMask => f.write_str("register K0 cannot be used as write mask"),
+            Self::ZeroingMaskingNotAllowed => f.write_str("zeroing masking is not allowed in this instruction"),
+            Self::BroadcastNotAllowed => f.write_str("embedded broadcast is not allowed in this instruction"),
+            Self::WriteMaskRequired => f.write_str("write mask is required by this instruction"),
+            Self::InvalidVSIBIndex { index, expected } => {
+                write!(f, "vector index {index:?} has invalid size, expected {expected:?}")
+            }
+            Self::GatherRegistersNotDistinct => {
+                f.write_str("destination, vector index and mask registers of gather have to be distinct")
+            }
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
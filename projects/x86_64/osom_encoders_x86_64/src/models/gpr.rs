@@ -1,4 +1,4 @@
-use core::mem::transmute;
+use core::{fmt, mem::transmute};
 
 use super::{GPRKind, Size};
 
@@ -234,6 +234,29 @@ impl GPR {
         }
     }
 
+    /// Returns the lowercase name of the [`GPR`], as used by assemblers,
+    /// e.g. `"rax"` or `"r8d"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 68] = [
+            "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12", "r13", "r14",
+            "r15", "eax", "ebx", "ecx", "edx", "esi", "edi", "ebp", "esp", "r8d", "r9d", "r10d", "r11d", "r12d", "r13d",
+            "r14d", "r15d", "ax", "bx", "cx", "dx", "si", "di", "bp", "sp", "r8w", "r9w", "r10w", "r11w", "r12w",
+            "r13w", "r14w", "r15w", "al", "bl", "cl", "dl", "sil", "dil", "bpl", "spl", "r8b", "r9b", "r10b", "r11b",
+            "r12b", "r13b", "r14b", "r15b", "ah", "bh", "ch", "dh",
+        ];
+        NAMES[(self.as_u8() - 1) as usize]
+    }
+
+    /// Finds the [`GPR`] by its `name` (case insensitive), which is the inverse
+    /// of [`GPR::name`]. Returns `None` if there is no such register.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (1..=68)
+            .map(Self::from_u8)
+            .find(|gpr| gpr.name().eq_ignore_ascii_case(name))
+    }
+
     /// Returns the index of the [`GPR`]. The purpose of the index is to be used
     /// as a part of binary encoding, as used by Intel.
     #[must_use]
@@ -286,6 +309,112 @@ impl GPR {
         idx >= 4 && idx <= 7
     }
 
+    /// Returns true if encoding the [`GPR`] requires REX prefix,
+    /// regardless of the instruction it is used in. This is the case
+    /// for extended registers and for SPL, BPL, SIL and DIL registers.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn requires_rex(self) -> bool {
+        self.is_extended() || (self.index_matches_bit8_high() && self.kind().equals(GPRKind::Bit8))
+    }
+
+    /// Creates a [`GPR`] out of its `size` and `index`, which is the inverse of
+    /// [`GPR::index`]. For 8-bit registers with index in `4..=7` range,
+    /// the `has_rex` flag decides between SPL, BPL, SIL, DIL (with REX prefix)
+    /// and AH, CH, DH, BH (without REX prefix).
+    #[inline]
+    pub(crate) const fn from_index(index: u8, size: Size, has_rex: bool) -> Self {
+        const BIT64: [GPR; 16] = [
+            GPR::RAX,
+            GPR::RCX,
+            GPR::RDX,
+            GPR::RBX,
+            GPR::RSP,
+            GPR::RBP,
+            GPR::RSI,
+            GPR::RDI,
+            GPR::R8,
+            GPR::R9,
+            GPR::R10,
+            GPR::R11,
+            GPR::R12,
+            GPR::R13,
+            GPR::R14,
+            GPR::R15,
+        ];
+        const BIT32: [GPR; 16] = [
+            GPR::EAX,
+            GPR::ECX,
+            GPR::EDX,
+            GPR::EBX,
+            GPR::ESP,
+            GPR::EBP,
+            GPR::ESI,
+            GPR::EDI,
+            GPR::R8D,
+            GPR::R9D,
+            GPR::R10D,
+            GPR::R11D,
+            GPR::R12D,
+            GPR::R13D,
+            GPR::R14D,
+            GPR::R15D,
+        ];
+        const BIT16: [GPR; 16] = [
+            GPR::AX,
+            GPR::CX,
+            GPR::DX,
+            GPR::BX,
+            GPR::SP,
+            GPR::BP,
+            GPR::SI,
+            GPR::DI,
+            GPR::R8W,
+            GPR::R9W,
+            GPR::R10W,
+            GPR::R11W,
+            GPR::R12W,
+            GPR::R13W,
+            GPR::R14W,
+            GPR::R15W,
+        ];
+        const BIT8: [GPR; 16] = [
+            GPR::AL,
+            GPR::CL,
+            GPR::DL,
+            GPR::BL,
+            GPR::SPL,
+            GPR::BPL,
+            GPR::SIL,
+            GPR::DIL,
+            GPR::R8B,
+            GPR::R9B,
+            GPR::R10B,
+            GPR::R11B,
+            GPR::R12B,
+            GPR::R13B,
+            GPR::R14B,
+            GPR::R15B,
+        ];
+        const BIT8_HIGH: [GPR; 4] = [GPR::AH, GPR::CH, GPR::DH, GPR::BH];
+
+        debug_assert!(index < 16, "Invalid GPR index");
+        let index = (index & 0b1111) as usize;
+        match size {
+            Size::Bit64 => BIT64[index],
+            Size::Bit32 => BIT32[index],
+            Size::Bit16 => BIT16[index],
+            Size::Bit8 => {
+                if !has_rex && index >= 4 && index <= 7 {
+                    BIT8_HIGH[index - 4]
+                } else {
+                    BIT8[index]
+                }
+            }
+            Size::Bit128 | Size::Bit256 | Size::Bit512 => panic!("There are no vector-sized general purpose registers."),
+        }
+    }
+
     #[inline(always)]
     #[must_use]
     pub(crate) const fn as_u8(self) -> u8 {
@@ -304,3 +433,9 @@ impl GPR {
         unsafe { transmute(value) }
     }
 }
+
+impl fmt::Display for GPR {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
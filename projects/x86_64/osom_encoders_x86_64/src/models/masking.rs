@@ -0,0 +1,43 @@
+use super::Opmask;
+
+/// Represents the write masking of an EVEX encoded instruction, i.e.
+/// the `{k}` and `{z}` decorations of its destination.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum Masking {
+    /// No masking, all the elements of the destination are written.
+    None = 1,
+
+    /// Merge masking (`{k}`), elements with a zero mask bit keep
+    /// their previous value.
+    Merge { opmask: Opmask } = 2,
+
+    /// Zero masking (`{k}{z}`), elements with a zero mask bit are zeroed.
+    Zero { opmask: Opmask } = 3,
+}
+
+impl Masking {
+    /// Returns the [`Opmask`] of the [`Masking`], if any.
+    #[inline]
+    #[must_use]
+    pub const fn opmask(self) -> Option<Opmask> {
+        match self {
+            Self::None => None,
+            Self::Merge { opmask } | Self::Zero { opmask } => Some(opmask),
+        }
+    }
+
+    /// Returns `true` if the [`Masking`] zeroes masked out elements.
+    #[inline]
+    #[must_use]
+    pub const fn is_zeroing(self) -> bool {
+        matches!(self, Self::Zero { .. })
+    }
+}
+
+impl From<Opmask> for Masking {
+    fn from(opmask: Opmask) -> Self {
+        Masking::Merge { opmask }
+    }
+}
@@ -1,5 +1,5 @@
 //! This module contains the models for the `X86_64` entities, e.g.
-//! [`GPR`], [`Memory`] and more.
+//! [`GPR`], [`XMM`], [`YMM`], [`ZMM`], [`Memory`] and more.
 
 mod immediate8;
 pub use immediate8::*;
@@ -22,6 +22,9 @@ pub use scale::*;
 mod size;
 pub use size::*;
 
+mod condition;
+pub use condition::*;
+
 mod gpr_kind;
 pub use gpr_kind::*;
 
@@ -31,10 +34,55 @@ pub use gpr::*;
 mod memory;
 pub use memory::*;
 
+mod vsib_index;
+pub use vsib_index::*;
+
+mod vsib_memory;
+pub use vsib_memory::*;
+
 mod gpr_or_memory;
 pub use gpr_or_memory::*;
 
+mod xmm;
+pub use xmm::*;
+
+mod xmm_or_memory;
+pub use xmm_or_memory::*;
+
+mod ymm;
+pub use ymm::*;
+
+mod ymm_or_memory;
+pub use ymm_or_memory::*;
+
+mod zmm;
+pub use zmm::*;
+
+mod zmm_or_memory;
+pub use zmm_or_memory::*;
+
+mod opmask;
+pub use opmask::*;
+
+mod opmask_or_memory;
+pub use opmask_or_memory::*;
+
+mod masking;
+pub use masking::*;
+
+mod rounding;
+pub use rounding::*;
+
+mod operand;
+pub use operand::*;
+
 mod encoded_instruction;
 pub use encoded_instruction::*;
 
+mod encode_error;
+pub use encode_error::*;
+
+mod decode_error;
+pub use decode_error::*;
+
 mod const_checks;
@@ -0,0 +1,449 @@
+use super::{
+    GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking, Memory, Opmask, OpmaskOrMemory,
+    Rounding, Size, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+
+/// Represents a single operand of an instruction, as used for formatting.
+///
+/// # Notes
+///
+/// Immediate values are always stored sign-extended to 64 bits, together with
+/// their original size.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum Operand {
+    /// General purpose register operand.
+    GPR { gpr: GPR } = 1,
+
+    /// Memory operand. The `size` is `None` if the operand only describes
+    /// an address, e.g. for the `lea` instruction.
+    Memory { memory: Memory, size: Option<Size> } = 2,
+
+    /// Immediate operand.
+    Immediate { value: i64, size: Size } = 3,
+
+    /// XMM register operand.
+    XMM { xmm: XMM } = 4,
+
+    /// YMM register operand.
+    YMM { ymm: YMM } = 5,
+
+    /// ZMM register operand.
+    ZMM { zmm: ZMM } = 6,
+
+    /// Opmask register operand.
+    Opmask { opmask: Opmask } = 7,
+
+    /// Write masking of the preceding (destination) operand, i.e. `{k}` and `{z}`.
+    Masking { masking: Masking } = 8,
+
+    /// Embedded broadcast memory operand, i.e. a single element of given `size`
+    /// replicated `count` times (`{1toN}` in assembly).
+    Broadcast { memory: Memory, size: Size, count: u8 } = 9,
+
+    /// Static rounding mode, i.e. `{er}`.
+    Rounding { rounding: Rounding } = 10,
+
+    /// Suppression of all floating-point exceptions, i.e. `{sae}`.
+    Sae = 11,
+
+    /// VSIB memory operand of a gather or scatter. The `size` is the size
+    /// of a single gathered (or scattered) element.
+    VSIBMemory { memory: VSIBMemory, size: Size } = 12,
+}
+
+impl Operand {
+    /// Creates a new [`Operand`] out of [`GPROrMemory`] of given `size`.
+    #[inline]
+    pub const fn from_gpr_or_memory(value: GPROrMemory, size: Size) -> Self {
+        match value {
+            GPROrMemory::GPR { gpr } => Self::GPR { gpr },
+            GPROrMemory::Memory { memory } => Self::Memory {
+                memory,
+                size: Some(size),
+            },
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`XMMOrMemory`], where the memory
+    /// is of given `size`.
+    #[inline]
+    pub const fn from_xmm_or_memory(value: XMMOrMemory, size: Size) -> Self {
+        match value {
+            XMMOrMemory::XMM { xmm } => Self::XMM { xmm },
+            XMMOrMemory::Memory { memory } => Self::Memory {
+                memory,
+                size: Some(size),
+            },
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`YMMOrMemory`], where the memory
+    /// is of given `size`.
+    #[inline]
+    pub const fn from_ymm_or_memory(value: YMMOrMemory, size: Size) -> Self {
+        match value {
+            YMMOrMemory::YMM { ymm } => Self::YMM { ymm },
+            YMMOrMemory::Memory { memory } => Self::Memory {
+                memory,
+                size: Some(size),
+            },
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`ZMMOrMemory`]. Full memory is
+    /// 512 bits wide, while a broadcast memory is of given `element_size`.
+    #[inline]
+    pub const fn from_zmm_or_memory(value: ZMMOrMemory, element_size: Size) -> Self {
+        match value {
+            ZMMOrMemory::ZMM { zmm } => Self::ZMM { zmm },
+            ZMMOrMemory::Memory { memory } => Self::Memory {
+                memory,
+                size: Some(Size::Bit512),
+            },
+            ZMMOrMemory::Broadcast { memory } => Self::Broadcast {
+                memory,
+                size: element_size,
+                count: if matches!(element_size, Size::Bit64) { 8 } else { 16 },
+            },
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`OpmaskOrMemory`], where the memory
+    /// is of given `size`.
+    #[inline]
+    pub const fn from_opmask_or_memory(value: OpmaskOrMemory, size: Size) -> Self {
+        match value {
+            OpmaskOrMemory::Opmask { opmask } => Self::Opmask { opmask },
+            OpmaskOrMemory::Memory { memory } => Self::Memory {
+                memory,
+                size: Some(size),
+            },
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`Immediate8`].
+    #[inline]
+    pub const fn from_imm8(value: Immediate8) -> Self {
+        Self::Immediate {
+            value: value.as_i8() as i64,
+            size: Size::Bit8,
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`Immediate16`].
+    #[inline]
+    pub const fn from_imm16(value: Immediate16) -> Self {
+        Self::Immediate {
+            value: value.as_i16() as i64,
+            size: Size::Bit16,
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`Immediate32`].
+    #[inline]
+    pub const fn from_imm32(value: Immediate32) -> Self {
+        Self::Immediate {
+            value: value.as_i32() as i64,
+            size: Size::Bit32,
+        }
+    }
+
+    /// Creates a new [`Operand`] out of [`Immediate64`].
+    #[inline]
+    pub const fn from_imm64(value: Immediate64) -> Self {
+        Self::Immediate {
+            value: value.as_i64(),
+            size: Size::Bit64,
+        }
+    }
+}
+
+/// Conversions back to the operand types, used by [`InstructionForm`][crate::decoders::InstructionForm].
+/// These panic whenever the operand is of a different kind.
+#[allow(clippy::cast_possible_truncation)]
+impl Operand {
+    #[track_caller]
+    pub(crate) const fn to_gpr(self) -> GPR {
+        match self {
+            Self::GPR { gpr } => gpr,
+            _ => panic!("Operand is not a GPR."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_memory(self) -> Memory {
+        match self {
+            Self::Memory { memory, .. } => memory,
+            _ => panic!("Operand is not a memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_gpr_or_memory(self) -> GPROrMemory {
+        match self {
+            Self::GPR { gpr } => GPROrMemory::GPR { gpr },
+            Self::Memory { memory, .. } => GPROrMemory::Memory { memory },
+            _ => panic!("Operand is not a GPR or memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_xmm(self) -> XMM {
+        match self {
+            Self::XMM { xmm } => xmm,
+            _ => panic!("Operand is not an XMM."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_xmm_or_memory(self) -> XMMOrMemory {
+        match self {
+            Self::XMM { xmm } => XMMOrMemory::XMM { xmm },
+            Self::Memory { memory, .. } => XMMOrMemory::Memory { memory },
+            _ => panic!("Operand is not an XMM or memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_ymm(self) -> YMM {
+        match self {
+            Self::YMM { ymm } => ymm,
+            _ => panic!("Operand is not a YMM."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_ymm_or_memory(self) -> YMMOrMemory {
+        match self {
+            Self::YMM { ymm } => YMMOrMemory::YMM { ymm },
+            Self::Memory { memory, .. } => YMMOrMemory::Memory { memory },
+            _ => panic!("Operand is not a YMM or memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_zmm(self) -> ZMM {
+        match self {
+            Self::ZMM { zmm } => zmm,
+            _ => panic!("Operand is not a ZMM."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_zmm_or_memory(self) -> ZMMOrMemory {
+        match self {
+            Self::ZMM { zmm } => ZMMOrMemory::ZMM { zmm },
+            Self::Memory { memory, .. } => ZMMOrMemory::Memory { memory },
+            Self::Broadcast { memory, .. } => ZMMOrMemory::Broadcast { memory },
+            _ => panic!("Operand is not a ZMM or memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_opmask(self) -> Opmask {
+        match self {
+            Self::Opmask { opmask } => opmask,
+            _ => panic!("Operand is not an opmask."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_opmask_or_memory(self) -> OpmaskOrMemory {
+        match self {
+            Self::Opmask { opmask } => OpmaskOrMemory::Opmask { opmask },
+            Self::Memory { memory, .. } => OpmaskOrMemory::Memory { memory },
+            _ => panic!("Operand is not an opmask or memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_vsib_memory(self) -> VSIBMemory {
+        match self {
+            Self::VSIBMemory { memory, .. } => memory,
+            _ => panic!("Operand is not a VSIB memory."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_masking(self) -> Masking {
+        match self {
+            Self::Masking { masking } => masking,
+            _ => panic!("Operand is not a masking."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_rounding(self) -> Rounding {
+        match self {
+            Self::Rounding { rounding } => rounding,
+            _ => panic!("Operand is not a rounding."),
+        }
+    }
+
+    #[track_caller]
+    const fn to_immediate(self) -> i64 {
+        match self {
+            Self::Immediate { value, .. } => value,
+            _ => panic!("Operand is not an immediate."),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_imm8(self) -> Immediate8 {
+        Immediate8::from_i8(self.to_immediate() as i8)
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_imm16(self) -> Immediate16 {
+        Immediate16::from_i16(self.to_immediate() as i16)
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_imm32(self) -> Immediate32 {
+        Immediate32::from_i32(self.to_immediate() as i32)
+    }
+
+    #[track_caller]
+    pub(crate) const fn to_imm64(self) -> Immediate64 {
+        Immediate64::from_i64(self.to_immediate())
+    }
+}
+
+/// Conversions from unsigned immediates, i.e. immediates which are neither
+/// sign-extended nor relative.
+impl Operand {
+    /// Creates a new [`Operand`] out of unsigned [`Immediate8`].
+    #[inline]
+    pub const fn from_unsigned_imm8(value: Immediate8) -> Self {
+        Self::Immediate {
+            value: value.as_u8() as i64,
+            size: Size::Bit8,
+        }
+    }
+
+    /// Creates a new [`Operand`] out of unsigned [`Immediate16`].
+    #[inline]
+    pub const fn from_unsigned_imm16(value: Immediate16) -> Self {
+        Self::Immediate {
+            value: value.as_u16() as i64,
+            size: Size::Bit16,
+        }
+    }
+
+    /// Creates a new [`Operand`] out of unsigned [`Immediate32`].
+    #[inline]
+    pub const fn from_unsigned_imm32(value: Immediate32) -> Self {
+        Self::Immediate {
+            value: value.as_u32() as i64,
+            size: Size::Bit32,
+        }
+    }
+}
+
+impl From<GPR> for Operand {
+    fn from(gpr: GPR) -> Self {
+        Self::GPR { gpr }
+    }
+}
+
+impl From<XMM> for Operand {
+    fn from(xmm: XMM) -> Self {
+        Self::XMM { xmm }
+    }
+}
+
+impl From<YMM> for Operand {
+    fn from(ymm: YMM) -> Self {
+        Self::YMM { ymm }
+    }
+}
+
+impl From<ZMM> for Operand {
+    fn from(zmm: ZMM) -> Self {
+        Self::ZMM { zmm }
+    }
+}
+
+impl From<Opmask> for Operand {
+    fn from(opmask: Opmask) -> Self {
+        Self::Opmask { opmask }
+    }
+}
+
+impl From<Masking> for Operand {
+    fn from(masking: Masking) -> Self {
+        Self::Masking { masking }
+    }
+}
+
+impl From<Rounding> for Operand {
+    fn from(rounding: Rounding) -> Self {
+        Self::Rounding { rounding }
+    }
+}
+
+/// The maximal number of operands of a single instruction.
+pub const MAX_OPERANDS: usize = 6;
+
+/// Represents the list of operands of an instruction, in Intel order
+/// (i.e. destination first).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[must_use]
+pub struct Operands {
+    operands: [Operand; MAX_OPERANDS],
+    len: u8,
+}
+
+impl Operands {
+    /// Creates a new [`Operands`] out of an array of `N` operands.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the array is too large.
+    #[inline]
+    pub const fn from_array<const N: usize>(operands: [Operand; N]) -> Self {
+        const {
+            assert!(N <= MAX_OPERANDS, "N must be less than or equal to MAX_OPERANDS");
+        }
+        let mut result = [Operand::GPR { gpr: GPR::RAX }; MAX_OPERANDS];
+        let mut idx = 0;
+        while idx < N {
+            result[idx] = operands[idx];
+            idx += 1;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Self {
+            operands: result,
+            len: N as u8,
+        }
+    }
+
+    /// Returns the operands as a slice.
+    #[inline]
+    pub const fn as_slice(&self) -> &[Operand] {
+        self.operands.split_at(self.len as usize).0
+    }
+
+    /// Returns the number of operands.
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if there are no operands.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl AsRef<[Operand]> for Operands {
+    fn as_ref(&self) -> &[Operand] {
+        self.as_slice()
+    }
+}
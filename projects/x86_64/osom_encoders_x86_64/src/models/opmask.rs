@@ -0,0 +1,82 @@
+use core::{fmt, mem::transmute};
+
+/// Represents an AVX-512 opmask register in the `X86_64` instruction set.
+/// [`Opmask::K0`] cannot be used as a write mask, since its encoding means
+/// "no masking".
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[must_use]
+pub enum Opmask {
+    K0 = 1, // We start from 1 to allow Option<Opmask> optimization
+    K1 = 2,
+    K2 = 3,
+    K3 = 4,
+    K4 = 5,
+    K5 = 6,
+    K6 = 7,
+    K7 = 8,
+}
+
+impl Opmask {
+    /// Compares two [`Opmask`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+
+    /// Returns the lowercase name of the [`Opmask`], as used by assemblers,
+    /// e.g. `"k0"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 8] = ["k0", "k1", "k2", "k3", "k4", "k5", "k6", "k7"];
+        NAMES[self.index() as usize]
+    }
+
+    /// Finds the [`Opmask`] by its `name` (case insensitive), which is the inverse
+    /// of [`Opmask::name`]. Returns `None` if there is no such register.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..8)
+            .map(Self::from_index)
+            .find(|opmask| opmask.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the index of the [`Opmask`], i.e. the number in its name.
+    /// The purpose of the index is to be used as a part of binary encoding.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> u8 {
+        self.as_u8() - 1
+    }
+
+    /// Creates an [`Opmask`] out of its `index`, which is the inverse of [`Opmask::index`].
+    #[inline]
+    pub(crate) const fn from_index(index: u8) -> Self {
+        debug_assert!(index < 8, "Invalid Opmask index");
+        Self::from_u8((index & 0b111) + 1)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn as_u8(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result > 0);
+            core::hint::assert_unchecked(result <= 8);
+            result
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        debug_assert!(value > 0 && value <= 8, "Invalid Opmask value");
+        unsafe { transmute(value) }
+    }
+}
+
+impl fmt::Display for Opmask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,21 @@
+use super::{Memory, Opmask};
+
+/// Represents an opmask register or memory operand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+pub enum OpmaskOrMemory {
+    Opmask { opmask: Opmask } = 1,
+    Memory { memory: Memory } = 2,
+}
+
+impl From<Opmask> for OpmaskOrMemory {
+    fn from(opmask: Opmask) -> Self {
+        OpmaskOrMemory::Opmask { opmask }
+    }
+}
+
+impl From<Memory> for OpmaskOrMemory {
+    fn from(memory: Memory) -> Self {
+        OpmaskOrMemory::Memory { memory }
+    }
+}
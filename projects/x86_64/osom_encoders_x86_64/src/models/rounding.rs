@@ -0,0 +1,70 @@
+use core::{fmt, mem::transmute};
+
+/// Represents the static rounding mode (`{er}`) of an EVEX encoded instruction.
+/// Static rounding implies suppression of all floating-point exceptions.
+///
+/// The value of each variant is the `RC` field of the encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum Rounding {
+    /// Round to nearest (even), i.e. `{rn-sae}`.
+    Nearest = 0b00,
+
+    /// Round down (toward negative infinity), i.e. `{rd-sae}`.
+    Down = 0b01,
+
+    /// Round up (toward positive infinity), i.e. `{ru-sae}`.
+    Up = 0b10,
+
+    /// Round toward zero (truncate), i.e. `{rz-sae}`.
+    Zero = 0b11,
+}
+
+impl Rounding {
+    /// Compares two [`Rounding`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+
+    /// Returns the lowercase name of the [`Rounding`], as used by assemblers,
+    /// e.g. `"rn-sae"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 4] = ["rn-sae", "rd-sae", "ru-sae", "rz-sae"];
+        NAMES[self.as_u8() as usize]
+    }
+
+    /// Finds the [`Rounding`] by its `name` (case insensitive), which is the inverse
+    /// of [`Rounding::name`]. Returns `None` if there is no such rounding mode.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..4)
+            .map(Self::from_u8)
+            .find(|rounding| rounding.name().eq_ignore_ascii_case(name))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn as_u8(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result <= 3);
+            result
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        debug_assert!(value <= 3, "Invalid Rounding value");
+        unsafe { transmute(value & 0b11) }
+    }
+}
+
+impl fmt::Display for Rounding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -43,6 +43,13 @@ impl Scale {
         unsafe { transmute(value) }
     }
 
+    /// Returns the factor the index register is multiplied by, i.e. `1`, `2`, `4` or `8`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn factor(self) -> u8 {
+        1 << self.index()
+    }
+
     #[must_use]
     pub(crate) const fn index(self) -> u8 {
         match self {
@@ -9,6 +9,9 @@ pub enum Size {
     Bit16 = 2,
     Bit32 = 3,
     Bit64 = 4,
+    Bit128 = 5,
+    Bit256 = 6,
+    Bit512 = 7,
 }
 
 impl Size {
@@ -25,7 +28,7 @@ impl Size {
         unsafe {
             let result = transmute::<Self, u8>(self);
             core::hint::assert_unchecked(result > 0);
-            core::hint::assert_unchecked(result <= 4);
+            core::hint::assert_unchecked(result <= 7);
             result
         }
     }
@@ -33,7 +36,7 @@ impl Size {
     #[inline]
     #[allow(dead_code)]
     pub(crate) const fn from_u8(value: u8) -> Self {
-        debug_assert!(value > 0 && value <= 4, "Invalid size value");
+        debug_assert!(value > 0 && value <= 7, "Invalid size value");
         unsafe { transmute(value) }
     }
 }
@@ -0,0 +1,99 @@
+use core::fmt;
+
+use super::{Size, XMM, YMM, ZMM};
+
+/// Represents the vector index register of a [`VSIBMemory`][super::VSIBMemory]
+/// operand. Each element of the index selects a separate memory location.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum VSIBIndex {
+    XMM { xmm: XMM } = 1,
+    YMM { ymm: YMM } = 2,
+    ZMM { zmm: ZMM } = 3,
+}
+
+impl VSIBIndex {
+    /// Compares two [`VSIBIndex`] values for equality.
+    #[inline]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.size().equals(other.size()) && self.index() == other.index()
+    }
+
+    /// Returns the size of the index register, i.e. [`Size::Bit128`],
+    /// [`Size::Bit256`] or [`Size::Bit512`].
+    #[inline]
+    pub const fn size(self) -> Size {
+        match self {
+            Self::XMM { .. } => Size::Bit128,
+            Self::YMM { .. } => Size::Bit256,
+            Self::ZMM { .. } => Size::Bit512,
+        }
+    }
+
+    /// Returns the lowercase name of the index register, as used by assemblers,
+    /// e.g. `"xmm0"`.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::XMM { xmm } => xmm.name(),
+            Self::YMM { ymm } => ymm.name(),
+            Self::ZMM { zmm } => zmm.name(),
+        }
+    }
+
+    /// Returns the index of the register, i.e. the number in its name.
+    /// The purpose of the index is to be used as a part of binary encoding.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn index(self) -> u8 {
+        match self {
+            Self::XMM { xmm } => xmm.index(),
+            Self::YMM { ymm } => ymm.index(),
+            Self::ZMM { zmm } => zmm.index(),
+        }
+    }
+
+    /// Creates a [`VSIBIndex`] of given `size` out of its `index`, which is
+    /// the inverse of [`VSIBIndex::index`] and [`VSIBIndex::size`].
+    #[inline]
+    pub(crate) const fn from_index(index: u8, size: Size) -> Self {
+        match size {
+            Size::Bit128 => Self::XMM {
+                xmm: XMM::from_index(index),
+            },
+            Size::Bit256 => Self::YMM {
+                ymm: YMM::from_index(index),
+            },
+            _ => Self::ZMM {
+                zmm: ZMM::from_index(index),
+            },
+        }
+    }
+}
+
+impl From<XMM> for VSIBIndex {
+    fn from(xmm: XMM) -> Self {
+        VSIBIndex::XMM { xmm }
+    }
+}
+
+impl From<YMM> for VSIBIndex {
+    fn from(ymm: YMM) -> Self {
+        VSIBIndex::YMM { ymm }
+    }
+}
+
+impl From<ZMM> for VSIBIndex {
+    fn from(zmm: ZMM) -> Self {
+        VSIBIndex::ZMM { zmm }
+    }
+}
+
+impl fmt::Display for VSIBIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
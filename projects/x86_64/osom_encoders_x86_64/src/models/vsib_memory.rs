@@ -0,0 +1,68 @@
+use super::{BaseIndexIsExtended, GPR, Offset, Scale, VSIBIndex};
+
+/// Represents a memory operand with a vector index (VSIB), as used by
+/// gathers and scatters. Each element of the index addresses a separate
+/// memory location, i.e. `[base + index[i] * scale + offset]`.
+///
+/// # Safety
+///
+/// Not all combinations of are valid for a given instruction.
+/// For specifics refer to the Intel x86 manual.
+///
+/// In particular the base has to be 64-bit wide and the index has
+/// to be of the size expected by the instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+#[must_use]
+pub enum VSIBMemory {
+    /// Represents a scaled memory operand, i.e. `[index * scale + offset]`.
+    Scaled {
+        index: VSIBIndex,
+        scale: Scale,
+        offset: Offset,
+    } = 1,
+
+    /// Represents a based scaled memory operand, i.e. `[base + index * scale + offset]`.
+    BasedAndScaled {
+        base: GPR,
+        index: VSIBIndex,
+        scale: Scale,
+        offset: Offset,
+    } = 2,
+}
+
+impl VSIBMemory {
+    /// Returns the vector index register of the [`VSIBMemory`].
+    #[inline]
+    pub const fn index(self) -> VSIBIndex {
+        match self {
+            Self::Scaled { index, .. } | Self::BasedAndScaled { index, .. } => index,
+        }
+    }
+
+    /// Returns the base register of the [`VSIBMemory`], if any.
+    #[inline]
+    #[must_use]
+    pub const fn base(self) -> Option<GPR> {
+        match self {
+            Self::Scaled { .. } => None,
+            Self::BasedAndScaled { base, .. } => Some(base),
+        }
+    }
+
+    /// Note that `index_is_extended` refers to bit 3 of the index only, bit 4
+    /// (registers 16 to 31) is encoded separately in EVEX.V'.
+    pub(crate) const fn base_index_is_extended(self) -> BaseIndexIsExtended {
+        let index_is_extended = self.index().index() & 0b1000 != 0;
+        match self {
+            Self::Scaled { .. } => BaseIndexIsExtended {
+                base_is_extended: false,
+                index_is_extended,
+            },
+            Self::BasedAndScaled { base, .. } => BaseIndexIsExtended {
+                base_is_extended: base.is_extended(),
+                index_is_extended,
+            },
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use core::{fmt, mem::transmute};
+
+/// Represents a 128-bit SSE register in the `X86_64` instruction set.
+/// Registers `XMM16` to `XMM31` are only encodable with EVEX (AVX-512).
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[must_use]
+pub enum XMM {
+    XMM0 = 1, // We start from 1 to allow Option<XMM> optimization
+    XMM1 = 2,
+    XMM2 = 3,
+    XMM3 = 4,
+    XMM4 = 5,
+    XMM5 = 6,
+    XMM6 = 7,
+    XMM7 = 8,
+    XMM8 = 9,
+    XMM9 = 10,
+    XMM10 = 11,
+    XMM11 = 12,
+    XMM12 = 13,
+    XMM13 = 14,
+    XMM14 = 15,
+    XMM15 = 16,
+    XMM16 = 17,
+    XMM17 = 18,
+    XMM18 = 19,
+    XMM19 = 20,
+    XMM20 = 21,
+    XMM21 = 22,
+    XMM22 = 23,
+    XMM23 = 24,
+    XMM24 = 25,
+    XMM25 = 26,
+    XMM26 = 27,
+    XMM27 = 28,
+    XMM28 = 29,
+    XMM29 = 30,
+    XMM30 = 31,
+    XMM31 = 32,
+}
+
+impl XMM {
+    /// Compares two [`XMM`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+
+    /// Returns the lowercase name of the [`XMM`], as used by assemblers,
+    /// e.g. `"xmm0"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 32] = [
+            "xmm0", "xmm1", "xmm2", "xmm3", "xmm4", "xmm5", "xmm6", "xmm7", "xmm8", "xmm9", "xmm10", "xmm11", "xmm12",
+            "xmm13", "xmm14", "xmm15", "xmm16", "xmm17", "xmm18", "xmm19", "xmm20", "xmm21", "xmm22", "xmm23", "xmm24",
+            "xmm25", "xmm26", "xmm27", "xmm28", "xmm29", "xmm30", "xmm31",
+        ];
+        NAMES[self.index() as usize]
+    }
+
+    /// Finds the [`XMM`] by its `name` (case insensitive), which is the inverse
+    /// of [`XMM::name`]. Returns `None` if there is no such register.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..32)
+            .map(Self::from_index)
+            .find(|xmm| xmm.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the index of the [`XMM`], i.e. the number in its name.
+    /// The purpose of the index is to be used as a part of binary encoding.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> u8 {
+        self.as_u8() - 1
+    }
+
+    /// Creates an [`XMM`] out of its `index`, which is the inverse of [`XMM::index`].
+    #[inline]
+    pub(crate) const fn from_index(index: u8) -> Self {
+        debug_assert!(index < 32, "Invalid XMM index");
+        Self::from_u8((index & 0b1_1111) + 1)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn as_u8(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result > 0);
+            core::hint::assert_unchecked(result <= 32);
+            result
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        debug_assert!(value > 0 && value <= 32, "Invalid XMM value");
+        unsafe { transmute(value) }
+    }
+}
+
+impl fmt::Display for XMM {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
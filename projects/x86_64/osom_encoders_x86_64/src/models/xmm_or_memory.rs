@@ -0,0 +1,21 @@
+use super::{Memory, XMM};
+
+/// Represents an XMM or memory operand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+pub enum XMMOrMemory {
+    XMM { xmm: XMM } = 1,
+    Memory { memory: Memory } = 2,
+}
+
+impl From<XMM> for XMMOrMemory {
+    fn from(xmm: XMM) -> Self {
+        XMMOrMemory::XMM { xmm }
+    }
+}
+
+impl From<Memory> for XMMOrMemory {
+    fn from(memory: Memory) -> Self {
+        XMMOrMemory::Memory { memory }
+    }
+}
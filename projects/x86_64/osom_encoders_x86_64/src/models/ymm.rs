@@ -0,0 +1,110 @@
+use core::{fmt, mem::transmute};
+
+/// Represents a 256-bit AVX register in the `X86_64` instruction set. The lower
+/// 128 bits of each [`YMM`] are the corresponding [`XMM`][super::XMM].
+/// Registers `YMM16` to `YMM31` are only encodable with EVEX (AVX-512).
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[must_use]
+pub enum YMM {
+    YMM0 = 1, // We start from 1 to allow Option<YMM> optimization
+    YMM1 = 2,
+    YMM2 = 3,
+    YMM3 = 4,
+    YMM4 = 5,
+    YMM5 = 6,
+    YMM6 = 7,
+    YMM7 = 8,
+    YMM8 = 9,
+    YMM9 = 10,
+    YMM10 = 11,
+    YMM11 = 12,
+    YMM12 = 13,
+    YMM13 = 14,
+    YMM14 = 15,
+    YMM15 = 16,
+    YMM16 = 17,
+    YMM17 = 18,
+    YMM18 = 19,
+    YMM19 = 20,
+    YMM20 = 21,
+    YMM21 = 22,
+    YMM22 = 23,
+    YMM23 = 24,
+    YMM24 = 25,
+    YMM25 = 26,
+    YMM26 = 27,
+    YMM27 = 28,
+    YMM28 = 29,
+    YMM29 = 30,
+    YMM30 = 31,
+    YMM31 = 32,
+}
+
+impl YMM {
+    /// Compares two [`YMM`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+
+    /// Returns the lowercase name of the [`YMM`], as used by assemblers,
+    /// e.g. `"ymm0"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 32] = [
+            "ymm0", "ymm1", "ymm2", "ymm3", "ymm4", "ymm5", "ymm6", "ymm7", "ymm8", "ymm9", "ymm10", "ymm11", "ymm12",
+            "ymm13", "ymm14", "ymm15", "ymm16", "ymm17", "ymm18", "ymm19", "ymm20", "ymm21", "ymm22", "ymm23", "ymm24",
+            "ymm25", "ymm26", "ymm27", "ymm28", "ymm29", "ymm30", "ymm31",
+        ];
+        NAMES[self.index() as usize]
+    }
+
+    /// Finds the [`YMM`] by its `name` (case insensitive), which is the inverse
+    /// of [`YMM::name`]. Returns `None` if there is no such register.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..32)
+            .map(Self::from_index)
+            .find(|ymm| ymm.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the index of the [`YMM`], i.e. the number in its name.
+    /// The purpose of the index is to be used as a part of binary encoding.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> u8 {
+        self.as_u8() - 1
+    }
+
+    /// Creates an [`YMM`] out of its `index`, which is the inverse of [`YMM::index`].
+    #[inline]
+    pub(crate) const fn from_index(index: u8) -> Self {
+        debug_assert!(index < 32, "Invalid YMM index");
+        Self::from_u8((index & 0b1_1111) + 1)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn as_u8(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result > 0);
+            core::hint::assert_unchecked(result <= 32);
+            result
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        debug_assert!(value > 0 && value <= 32, "Invalid YMM value");
+        unsafe { transmute(value) }
+    }
+}
+
+impl fmt::Display for YMM {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,21 @@
+use super::{Memory, YMM};
+
+/// Represents an YMM or memory operand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+pub enum YMMOrMemory {
+    YMM { ymm: YMM } = 1,
+    Memory { memory: Memory } = 2,
+}
+
+impl From<YMM> for YMMOrMemory {
+    fn from(ymm: YMM) -> Self {
+        YMMOrMemory::YMM { ymm }
+    }
+}
+
+impl From<Memory> for YMMOrMemory {
+    fn from(memory: Memory) -> Self {
+        YMMOrMemory::Memory { memory }
+    }
+}
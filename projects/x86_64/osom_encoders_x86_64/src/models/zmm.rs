@@ -0,0 +1,109 @@
+use core::{fmt, mem::transmute};
+
+/// Represents a 512-bit AVX-512 register in the `X86_64` instruction set. The lower
+/// 256 bits of each [`ZMM`] are the corresponding [`YMM`][super::YMM].
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[must_use]
+pub enum ZMM {
+    ZMM0 = 1, // We start from 1 to allow Option<ZMM> optimization
+    ZMM1 = 2,
+    ZMM2 = 3,
+    ZMM3 = 4,
+    ZMM4 = 5,
+    ZMM5 = 6,
+    ZMM6 = 7,
+    ZMM7 = 8,
+    ZMM8 = 9,
+    ZMM9 = 10,
+    ZMM10 = 11,
+    ZMM11 = 12,
+    ZMM12 = 13,
+    ZMM13 = 14,
+    ZMM14 = 15,
+    ZMM15 = 16,
+    ZMM16 = 17,
+    ZMM17 = 18,
+    ZMM18 = 19,
+    ZMM19 = 20,
+    ZMM20 = 21,
+    ZMM21 = 22,
+    ZMM22 = 23,
+    ZMM23 = 24,
+    ZMM24 = 25,
+    ZMM25 = 26,
+    ZMM26 = 27,
+    ZMM27 = 28,
+    ZMM28 = 29,
+    ZMM29 = 30,
+    ZMM30 = 31,
+    ZMM31 = 32,
+}
+
+impl ZMM {
+    /// Compares two [`ZMM`] values for equality.
+    #[inline(always)]
+    #[must_use]
+    pub const fn equals(self, other: Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+
+    /// Returns the lowercase name of the [`ZMM`], as used by assemblers,
+    /// e.g. `"zmm0"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 32] = [
+            "zmm0", "zmm1", "zmm2", "zmm3", "zmm4", "zmm5", "zmm6", "zmm7", "zmm8", "zmm9", "zmm10", "zmm11", "zmm12",
+            "zmm13", "zmm14", "zmm15", "zmm16", "zmm17", "zmm18", "zmm19", "zmm20", "zmm21", "zmm22", "zmm23", "zmm24",
+            "zmm25", "zmm26", "zmm27", "zmm28", "zmm29", "zmm30", "zmm31",
+        ];
+        NAMES[self.index() as usize]
+    }
+
+    /// Finds the [`ZMM`] by its `name` (case insensitive), which is the inverse
+    /// of [`ZMM::name`]. Returns `None` if there is no such register.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..32)
+            .map(Self::from_index)
+            .find(|zmm| zmm.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the index of the [`ZMM`], i.e. the number in its name.
+    /// The purpose of the index is to be used as a part of binary encoding.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn index(self) -> u8 {
+        self.as_u8() - 1
+    }
+
+    /// Creates an [`ZMM`] out of its `index`, which is the inverse of [`ZMM::index`].
+    #[inline]
+    pub(crate) const fn from_index(index: u8) -> Self {
+        debug_assert!(index < 32, "Invalid ZMM index");
+        Self::from_u8((index & 0b1_1111) + 1)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn as_u8(self) -> u8 {
+        unsafe {
+            let result = transmute::<Self, u8>(self);
+            core::hint::assert_unchecked(result > 0);
+            core::hint::assert_unchecked(result <= 32);
+            result
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        debug_assert!(value > 0 && value <= 32, "Invalid ZMM value");
+        unsafe { transmute(value) }
+    }
+}
+
+impl fmt::Display for ZMM {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
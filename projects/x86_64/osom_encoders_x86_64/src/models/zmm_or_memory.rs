@@ -0,0 +1,24 @@
+use super::{Memory, ZMM};
+
+/// Represents a ZMM or memory operand. The [`ZMMOrMemory::Broadcast`] variant
+/// is a memory operand whose single element is replicated to all the lanes
+/// (EVEX embedded broadcast, `{1toN}` in assembly).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[repr(u8)]
+pub enum ZMMOrMemory {
+    ZMM { zmm: ZMM } = 1,
+    Memory { memory: Memory } = 2,
+    Broadcast { memory: Memory } = 3,
+}
+
+impl From<ZMM> for ZMMOrMemory {
+    fn from(zmm: ZMM) -> Self {
+        ZMMOrMemory::ZMM { zmm }
+    }
+}
+
+impl From<Memory> for ZMMOrMemory {
+    fn from(memory: Memory) -> Self {
+        ZMMOrMemory::Memory { memory }
+    }
+}
@@ -0,0 +1,937 @@
+use alloc::vec::Vec;
+
+use crate::decoders::{INSTRUCTION_FORMS, Instruction, InstructionForm, OperandKind};
+use crate::models::{
+    EncodeError, EncodedX86_64Instruction, GPR, Masking, Memory, Offset, Operand, Opmask, Rounding, Scale, Size,
+    VSIBIndex, VSIBMemory, XMM, YMM, ZMM,
+};
+
+use super::lexer::{Statement, Token, TokenKind, tokenize};
+use super::{ParseError, ParseErrorKind, Span};
+
+/// Parses Intel-syntax `source` into encoded instructions. See the
+/// [module documentation][super] for the supported syntax.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] pointing at the invalid part of `source`.
+pub fn parse_intel(source: &str) -> Result<Vec<EncodedX86_64Instruction>, ParseError> {
+    let statements = tokenize(source)?;
+    let mut result = Vec::with_capacity(statements.len());
+    for statement in &statements {
+        result.push(parse_statement(statement)?.1);
+    }
+    Ok(result)
+}
+
+/// Parses Intel-syntax `source` into [`Instruction`]s. This is the same as
+/// [`parse_intel`] except that the instructions are not encoded.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] pointing at the invalid part of `source`.
+pub fn parse_intel_instructions(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    let statements = tokenize(source)?;
+    let mut result = Vec::with_capacity(statements.len());
+    for statement in &statements {
+        result.push(parse_statement(statement)?.0);
+    }
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ParsedOperandKind {
+    Register {
+        gpr: GPR,
+    },
+    Xmm {
+        xmm: XMM,
+    },
+    Ymm {
+        ymm: YMM,
+    },
+    Zmm {
+        zmm: ZMM,
+    },
+    Opmask {
+        opmask: Opmask,
+    },
+    Memory {
+        memory: Memory,
+        size: Option<Size>,
+    },
+    Broadcast {
+        memory: Memory,
+        size: Option<Size>,
+        count: u8,
+    },
+    VSIBMemory {
+        memory: VSIBMemory,
+        size: Option<Size>,
+    },
+    Immediate {
+        value: i128,
+        size: Option<Size>,
+    },
+    /// The `{k}` and `{z}` decorations of the preceding operand.
+    Masking {
+        masking: Masking,
+    },
+    Rounding {
+        rounding: Rounding,
+    },
+    Sae,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ParsedOperand {
+    kind: ParsedOperandKind,
+    span: Span,
+}
+
+struct TokenStream<'a, 'b> {
+    tokens: &'b [Token<'a>],
+    position: usize,
+    end: usize,
+}
+
+impl<'a> TokenStream<'a, '_> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, ParseError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::UnexpectedEnd, Span::new(self.end, self.end)))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+}
+
+fn parse_statement(statement: &Statement<'_>) -> Result<(Instruction, EncodedX86_64Instruction), ParseError> {
+    let first = statement[0].span;
+    let last = statement[statement.len() - 1].span;
+    let mut stream = TokenStream {
+        tokens: statement,
+        position: 0,
+        end: last.end,
+    };
+
+    let mut mnemonic_token = stream.next()?;
+    let mut prefix = None;
+    if let TokenKind::Identifier(name) = mnemonic_token.kind
+        && let Some(canonical) = parse_prefix(name)
+        && let Some(Token {
+            kind: TokenKind::Identifier(_),
+            ..
+        }) = stream.peek()
+    {
+        prefix = Some((canonical, mnemonic_token.span));
+        mnemonic_token = stream.next()?;
+    }
+    let TokenKind::Identifier(mnemonic) = mnemonic_token.kind else {
+        return Err(unexpected_token(mnemonic_token));
+    };
+
+    let mut operands = Vec::new();
+    while !stream.is_empty() {
+        let mut operand = parse_operand(&mut stream)?;
+        let masking = parse_decorations(&mut stream, &mut operand)?;
+        operands.push(operand);
+        operands.extend(masking);
+        if let Some(token) = stream.peek() {
+            if token.kind != TokenKind::Comma {
+                return Err(unexpected_token(token));
+            }
+            stream.next()?;
+            if stream.is_empty() {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedEnd,
+                    Span::new(stream.end, stream.end),
+                ));
+            }
+        }
+    }
+
+    select_form(prefix, mnemonic, mnemonic_token.span, &operands, first.join(last))
+}
+
+fn parse_operand(stream: &mut TokenStream<'_, '_>) -> Result<ParsedOperand, ParseError> {
+    let token = stream.next()?;
+    match token.kind {
+        TokenKind::Identifier(name) => {
+            if let Some(size) = parse_size_keyword(name) {
+                if let Some(Token {
+                    kind: TokenKind::Identifier(ptr),
+                    ..
+                }) = stream.peek()
+                    && ptr.eq_ignore_ascii_case("ptr")
+                {
+                    stream.next()?;
+                    let open_bracket = stream.next()?;
+                    if open_bracket.kind != TokenKind::OpenBracket {
+                        return Err(unexpected_token(open_bracket));
+                    }
+                    return parse_memory(stream, Some(size), token.span);
+                }
+                return parse_immediate(stream, Some(size), token.span);
+            }
+
+            if let Some(xmm) = XMM::from_name(name) {
+                return Ok(ParsedOperand {
+                    kind: ParsedOperandKind::Xmm { xmm },
+                    span: token.span,
+                });
+            }
+
+            if let Some(ymm) = YMM::from_name(name) {
+                return Ok(ParsedOperand {
+                    kind: ParsedOperandKind::Ymm { ymm },
+                    span: token.span,
+                });
+            }
+
+            if let Some(zmm) = ZMM::from_name(name) {
+                return Ok(ParsedOperand {
+                    kind: ParsedOperandKind::Zmm { zmm },
+                    span: token.span,
+                });
+            }
+
+            if let Some(opmask) = Opmask::from_name(name) {
+                return Ok(ParsedOperand {
+                    kind: ParsedOperandKind::Opmask { opmask },
+                    span: token.span,
+                });
+            }
+
+            let gpr =
+                GPR::from_name(name).ok_or_else(|| ParseError::new(ParseErrorKind::UnknownRegister, token.span))?;
+            Ok(ParsedOperand {
+                kind: ParsedOperandKind::Register { gpr },
+                span: token.span,
+            })
+        }
+        TokenKind::OpenBracket => parse_memory(stream, None, token.span),
+        TokenKind::Decoration(name) if name.eq_ignore_ascii_case("sae") => Ok(ParsedOperand {
+            kind: ParsedOperandKind::Sae,
+            span: token.span,
+        }),
+        TokenKind::Decoration(name) => {
+            let rounding = Rounding::from_name(name).ok_or_else(|| unexpected_token(token))?;
+            Ok(ParsedOperand {
+                kind: ParsedOperandKind::Rounding { rounding },
+                span: token.span,
+            })
+        }
+        TokenKind::Minus | TokenKind::Number(_) => {
+            stream.position -= 1;
+            parse_immediate(stream, None, token.span)
+        }
+        _ => Err(unexpected_token(token)),
+    }
+}
+
+/// Parses the decorations following `operand`, i.e. `{1toN}` broadcast of memory
+/// operand (which is applied to `operand`) and `{k}` with optional `{z}` masking
+/// (which is returned as a separate operand).
+fn parse_decorations(
+    stream: &mut TokenStream<'_, '_>,
+    operand: &mut ParsedOperand,
+) -> Result<Option<ParsedOperand>, ParseError> {
+    let mut masking: Option<ParsedOperand> = None;
+    while let Some(token) = stream.peek() {
+        let TokenKind::Decoration(name) = token.kind else {
+            break;
+        };
+        stream.next()?;
+        let count = name.strip_prefix("1to").and_then(|count| count.parse::<u8>().ok());
+        match (count, operand.kind, &mut masking) {
+            (Some(count), ParsedOperandKind::Memory { memory, size }, None) => {
+                operand.kind = ParsedOperandKind::Broadcast { memory, size, count };
+                operand.span = operand.span.join(token.span);
+            }
+            (None, _, None) => {
+                let opmask = Opmask::from_name(name).ok_or_else(|| unexpected_token(token))?;
+                masking = Some(ParsedOperand {
+                    kind: ParsedOperandKind::Masking {
+                        masking: Masking::Merge { opmask },
+                    },
+                    span: token.span,
+                });
+            }
+            (
+                None,
+                _,
+                Some(ParsedOperand {
+                    kind: ParsedOperandKind::Masking { masking },
+                    span,
+                }),
+            ) if name.eq_ignore_ascii_case("z") && !masking.is_zeroing() => {
+                *masking = Masking::Zero {
+                    opmask: masking.opmask().unwrap(),
+                };
+                *span = span.join(token.span);
+            }
+            _ => return Err(unexpected_token(token)),
+        }
+    }
+    Ok(masking)
+}
+
+fn parse_immediate(
+    stream: &mut TokenStream<'_, '_>,
+    size: Option<Size>,
+    start: Span,
+) -> Result<ParsedOperand, ParseError> {
+    let mut token = stream.next()?;
+    let negative = token.kind == TokenKind::Minus;
+    if negative {
+        token = stream.next()?;
+    }
+    let TokenKind::Number(value) = token.kind else {
+        return Err(unexpected_token(token));
+    };
+
+    let value = i128::from(value);
+    Ok(ParsedOperand {
+        kind: ParsedOperandKind::Immediate {
+            value: if negative { -value } else { value },
+            size,
+        },
+        span: start.join(token.span),
+    })
+}
+
+/// Parses the memory operand, following the `[` token.
+fn parse_memory(stream: &mut TokenStream<'_, '_>, size: Option<Size>, start: Span) -> Result<ParsedOperand, ParseError> {
+    let mut builder = MemoryBuilder::default();
+    let mut negative = false;
+    if let Some(Token {
+        kind: TokenKind::Minus, ..
+    }) = stream.peek()
+    {
+        stream.next()?;
+        negative = true;
+    }
+
+    loop {
+        parse_memory_term(stream, negative, &mut builder)?;
+        let token = stream.next()?;
+        match token.kind {
+            TokenKind::Plus => negative = false,
+            TokenKind::Minus => negative = true,
+            TokenKind::CloseBracket => {
+                let span = start.join(token.span);
+                let kind = if builder.vector_index.is_some() {
+                    let memory = builder.build_vsib(span)?;
+                    ParsedOperandKind::VSIBMemory { memory, size }
+                } else {
+                    let memory = builder.build(span)?;
+                    ParsedOperandKind::Memory { memory, size }
+                };
+                return Ok(ParsedOperand { kind, span });
+            }
+            _ => return Err(unexpected_token(token)),
+        }
+    }
+}
+
+fn parse_memory_term(
+    stream: &mut TokenStream<'_, '_>,
+    negative: bool,
+    builder: &mut MemoryBuilder,
+) -> Result<(), ParseError> {
+    let token = stream.next()?;
+    match token.kind {
+        TokenKind::Identifier(name) => {
+            let mut span = token.span;
+            let mut scale = None;
+            if let Some(Token {
+                kind: TokenKind::Star, ..
+            }) = stream.peek()
+            {
+                stream.next()?;
+                let scale_token = stream.next()?;
+                let TokenKind::Number(value) = scale_token.kind else {
+                    return Err(unexpected_token(scale_token));
+                };
+                span = span.join(scale_token.span);
+                scale = Some(parse_scale(value, span)?);
+            }
+            if negative {
+                return Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span));
+            }
+            builder.add_register(name, scale, span)
+        }
+        TokenKind::Number(value) => {
+            if let Some(Token {
+                kind: TokenKind::Star, ..
+            }) = stream.peek()
+            {
+                stream.next()?;
+                let register_token = stream.next()?;
+                let TokenKind::Identifier(name) = register_token.kind else {
+                    return Err(unexpected_token(register_token));
+                };
+                let span = token.span.join(register_token.span);
+                if negative {
+                    return Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span));
+                }
+                let scale = parse_scale(value, span)?;
+                return builder.add_register(name, Some(scale), span);
+            }
+
+            let value = i128::from(value);
+            builder.displacement += if negative { -value } else { value };
+            Ok(())
+        }
+        _ => Err(unexpected_token(token)),
+    }
+}
+
+#[derive(Default)]
+struct MemoryBuilder {
+    base: Option<GPR>,
+    index: Option<(GPR, Scale)>,
+    /// The vector index of VSIB memory operands, e.g. `[rax + xmm1*4]`.
+    vector_index: Option<(VSIBIndex, Scale)>,
+    relative_to_rip: bool,
+    displacement: i128,
+}
+
+impl MemoryBuilder {
+    fn add_register(&mut self, name: &str, scale: Option<Scale>, span: Span) -> Result<(), ParseError> {
+        let invalid = ParseError::new(ParseErrorKind::InvalidMemoryOperand, span);
+        if name.eq_ignore_ascii_case("rip") {
+            if self.relative_to_rip || scale.is_some() {
+                return Err(invalid);
+            }
+            self.relative_to_rip = true;
+            return Ok(());
+        }
+
+        if let Some(index) = parse_vector_index(name) {
+            if self.index.is_some() || self.vector_index.is_some() {
+                return Err(invalid);
+            }
+            self.vector_index = Some((index, scale.unwrap_or(Scale::Scale1)));
+            return Ok(());
+        }
+
+        let gpr = GPR::from_name(name).ok_or_else(|| ParseError::new(ParseErrorKind::UnknownRegister, span))?;
+        if !gpr.size().equals(Size::Bit64) {
+            return Err(invalid);
+        }
+
+        match (scale, self.base, self.index) {
+            (None, None, _) => self.base = Some(gpr),
+            (None, Some(_), None) if self.vector_index.is_none() => self.index = Some((gpr, Scale::Scale1)),
+            (Some(scale), _, None) if self.vector_index.is_none() => self.index = Some((gpr, scale)),
+            _ => return Err(invalid),
+        }
+        Ok(())
+    }
+
+    /// Builds the VSIB memory operand, i.e. with [`MemoryBuilder::vector_index`] set.
+    fn build_vsib(self, span: Span) -> Result<VSIBMemory, ParseError> {
+        let out_of_range = ParseError::new(ParseErrorKind::ValueOutOfRange, span);
+        let displacement = i32::try_from(self.displacement).map_err(|_| out_of_range)?;
+        let (Some((index, scale)), false) = (self.vector_index, self.relative_to_rip) else {
+            return Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span));
+        };
+
+        let Some(base) = self.base else {
+            return Ok(VSIBMemory::Scaled {
+                index,
+                scale,
+                offset: Offset::from_i32(displacement),
+            });
+        };
+        let offset = match displacement {
+            0 => Offset::None,
+            _ => i8::try_from(displacement).map_or(Offset::from_i32(displacement), Offset::from_i8),
+        };
+        Ok(VSIBMemory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        })
+    }
+
+    fn build(self, span: Span) -> Result<Memory, ParseError> {
+        let out_of_range = ParseError::new(ParseErrorKind::ValueOutOfRange, span);
+        let displacement = i32::try_from(self.displacement).map_err(|_| out_of_range)?;
+
+        // RSP cannot be encoded as index, but it can be swapped with the base when not scaled.
+        let (base, index) = match (self.base, self.index) {
+            (Some(base), Some((GPR::RSP, Scale::Scale1))) if !base.equals(GPR::RSP) => {
+                (Some(GPR::RSP), Some((base, Scale::Scale1)))
+            }
+            other => other,
+        };
+
+        let offset = match displacement {
+            0 => Offset::None,
+            _ => i8::try_from(displacement).map_or(Offset::from_i32(displacement), Offset::from_i8),
+        };
+
+        if self.relative_to_rip {
+            if base.is_some() || index.is_some() {
+                return Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span));
+            }
+            return Ok(Memory::RelativeToRIP {
+                offset: Offset::from_i32(displacement),
+            });
+        }
+
+        match (base, index) {
+            (Some(base), None) => Ok(Memory::Based { base, offset }),
+            (Some(base), Some((index, scale))) => Ok(Memory::BasedAndScaled {
+                base,
+                index,
+                scale,
+                offset,
+            }),
+            (None, Some((index, scale))) => Ok(Memory::Scaled {
+                index,
+                scale,
+                offset: Offset::from_i32(displacement),
+            }),
+            (None, None) => Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span)),
+        }
+    }
+}
+
+/// Picks the shortest encoding among the instruction forms matching `prefix`,
+/// `mnemonic` and `operands`.
+fn select_form(
+    prefix: Option<(&str, Span)>,
+    mnemonic: &str,
+    mnemonic_span: Span,
+    operands: &[ParsedOperand],
+    span: Span,
+) -> Result<(Instruction, EncodedX86_64Instruction), ParseError> {
+    let mut known_mnemonic = false;
+    let mut known_prefix = false;
+    let mut encode_error: Option<EncodeError> = None;
+    let mut best: Option<(Instruction, EncodedX86_64Instruction, &InstructionForm)> = None;
+
+    for form in &INSTRUCTION_FORMS {
+        if !form.mnemonic.eq_ignore_ascii_case(mnemonic) {
+            continue;
+        }
+        known_mnemonic = true;
+        if form.prefix != prefix.map(|(name, _)| name) {
+            continue;
+        }
+        known_prefix = true;
+
+        let Some(converted) = match_form(form, operands) else {
+            continue;
+        };
+        let instruction = form.build(&converted);
+        if !vsib_size_matches(operands, instruction) {
+            continue;
+        }
+        let encoded = match instruction.encode() {
+            Ok(encoded) => encoded,
+            Err(err) => {
+                encode_error.get_or_insert(err);
+                continue;
+            }
+        };
+
+        if let Some((_, best_encoded, best_form)) = &best {
+            check_memory_size(operands, form, best_form)?;
+            if best_encoded.as_slice().len() <= encoded.as_slice().len() {
+                continue;
+            }
+        }
+        best = Some((instruction, encoded, form));
+    }
+
+    if let Some((instruction, encoded, _)) = best {
+        return Ok((instruction, encoded));
+    }
+    if let Some(err) = encode_error {
+        return Err(ParseError::new(ParseErrorKind::Encode(err), span));
+    }
+    if !known_mnemonic {
+        return Err(ParseError::new(ParseErrorKind::UnknownMnemonic, mnemonic_span));
+    }
+    if !known_prefix {
+        let prefix_span = prefix.map_or(mnemonic_span, |(_, span)| span);
+        return Err(ParseError::new(ParseErrorKind::InvalidPrefix, prefix_span));
+    }
+
+    let operands_span = match (operands.first(), operands.last()) {
+        (Some(first), Some(last)) => first.span.join(last.span),
+        _ => mnemonic_span,
+    };
+    Err(ParseError::new(ParseErrorKind::InvalidOperands, operands_span))
+}
+
+/// Verifies that memory operands without explicit size have the same size in both forms.
+fn check_memory_size(
+    operands: &[ParsedOperand],
+    left: &InstructionForm,
+    right: &InstructionForm,
+) -> Result<(), ParseError> {
+    // The masking is optional, and so it is skipped in both operands and forms.
+    let operands = operands
+        .iter()
+        .filter(|operand| !matches!(operand.kind, ParsedOperandKind::Masking { .. }));
+    let left = left.operands.iter().filter(|kind| !is_masking(**kind));
+    let right = right.operands.iter().filter(|kind| !is_masking(**kind));
+    for ((operand, left), right) in operands.zip(left).zip(right) {
+        if let ParsedOperandKind::Memory { size: None, .. } = operand.kind
+            && memory_size(*left) != memory_size(*right)
+        {
+            return Err(ParseError::new(ParseErrorKind::AmbiguousOperandSize, operand.span));
+        }
+    }
+    Ok(())
+}
+
+const fn is_masking(kind: OperandKind) -> bool {
+    matches!(kind, OperandKind::Mask | OperandKind::MergeMask)
+}
+
+/// Converts `operands` to the kinds required by `form`. Returns `None` if they don't match.
+/// The masking is optional, i.e. [`Masking::None`] is used when it is not specified.
+fn match_form(form: &InstructionForm, operands: &[ParsedOperand]) -> Option<Vec<Operand>> {
+    let mut result = Vec::with_capacity(form.operands.len());
+    let mut remaining = operands;
+    for kind in form.operands {
+        let operand = remaining.first();
+        if is_masking(*kind) {
+            if let Some(ParsedOperand {
+                kind: ParsedOperandKind::Masking { masking },
+                ..
+            }) = operand
+            {
+                result.push(Operand::Masking { masking: *masking });
+                remaining = &remaining[1..];
+            } else {
+                result.push(Operand::Masking { masking: Masking::None });
+            }
+            continue;
+        }
+        result.push(match_operand(form, *kind, operand?)?);
+        remaining = &remaining[1..];
+    }
+    remaining.is_empty().then_some(result)
+}
+
+/// Converts `operand` to `kind` of `form`. Returns `None` if it doesn't match.
+fn match_operand(form: &InstructionForm, kind: OperandKind, operand: &ParsedOperand) -> Option<Operand> {
+    match operand.kind {
+        ParsedOperandKind::Register { gpr } => match kind {
+            OperandKind::AL | OperandKind::AX | OperandKind::EAX | OperandKind::RAX | OperandKind::CL => {
+                (implicit_register(kind) == Some(gpr)).then_some(Operand::GPR { gpr })
+            }
+            OperandKind::Mem64 | OperandKind::XmmM32 | OperandKind::XmmM64 | OperandKind::XmmM128 => None,
+            _ => (register_or_memory_size(kind) == Some(gpr.size())).then_some(Operand::GPR { gpr }),
+        },
+        ParsedOperandKind::Xmm { xmm } => match kind {
+            OperandKind::Xmm
+            | OperandKind::VXmm
+            | OperandKind::XmmR
+            | OperandKind::XmmM8
+            | OperandKind::XmmM16
+            | OperandKind::XmmM32
+            | OperandKind::XmmM64
+            | OperandKind::XmmM128 => Some(Operand::XMM { xmm }),
+            OperandKind::XMM0 => xmm.equals(XMM::XMM0).then_some(Operand::XMM { xmm }),
+            _ => None,
+        },
+        ParsedOperandKind::Ymm { ymm } => match kind {
+            OperandKind::Ymm | OperandKind::VYmm | OperandKind::YmmM256 => Some(Operand::YMM { ymm }),
+            _ => None,
+        },
+        ParsedOperandKind::Zmm { zmm } => match kind {
+            OperandKind::Zmm
+            | OperandKind::VZmm
+            | OperandKind::ZmmR
+            | OperandKind::ZmmM512
+            | OperandKind::ZmmM512B32
+            | OperandKind::ZmmM512B64 => Some(Operand::ZMM { zmm }),
+            _ => None,
+        },
+        ParsedOperandKind::Opmask { opmask } => match kind {
+            OperandKind::K | OperandKind::VK | OperandKind::KR | OperandKind::KM16 => Some(Operand::Opmask { opmask }),
+            _ => None,
+        },
+        ParsedOperandKind::Memory { memory, size } => match kind {
+            OperandKind::Mem64 => {
+                (size.is_none() || size == Some(Size::Bit64)).then_some(Operand::Memory { memory, size: None })
+            }
+            OperandKind::RM8
+            | OperandKind::RM16
+            | OperandKind::RM32
+            | OperandKind::RM64
+            | OperandKind::R32M8
+            | OperandKind::R32M16
+            | OperandKind::XmmM8
+            | OperandKind::XmmM16
+            | OperandKind::XmmM32
+            | OperandKind::XmmM64
+            | OperandKind::XmmM128
+            | OperandKind::YmmM256
+            | OperandKind::ZmmM512
+            | OperandKind::ZmmM512B32
+            | OperandKind::ZmmM512B64
+            | OperandKind::KM16
+            | OperandKind::Mem16
+            | OperandKind::Mem128 => {
+                let kind_size = memory_size(kind);
+                (size.is_none() || size == kind_size).then_some(Operand::Memory {
+                    memory,
+                    size: kind_size,
+                })
+            }
+            _ => None,
+        },
+        ParsedOperandKind::Broadcast { memory, size, count } => {
+            let element_size = match (kind, count) {
+                (OperandKind::ZmmM512B32, 16) => Size::Bit32,
+                (OperandKind::ZmmM512B64, 8) => Size::Bit64,
+                _ => return None,
+            };
+            (size.is_none() || size == Some(element_size)).then_some(Operand::Broadcast {
+                memory,
+                size: element_size,
+                count,
+            })
+        }
+        // The explicit size is verified by `vsib_size_matches`, since it depends
+        // on the element size, which is not known here.
+        ParsedOperandKind::VSIBMemory { memory, .. } => (vsib_index_size(kind) == Some(memory.index().size()))
+            .then_some(Operand::VSIBMemory {
+                memory,
+                size: Size::Bit128,
+            }),
+        ParsedOperandKind::Immediate { value, size } => match_immediate(form, kind, value, size),
+        ParsedOperandKind::Rounding { rounding } => (kind == OperandKind::Er).then_some(Operand::Rounding { rounding }),
+        ParsedOperandKind::Sae => (kind == OperandKind::Sae).then_some(Operand::Sae),
+        ParsedOperandKind::Masking { .. } => None,
+    }
+}
+
+/// Converts immediate `value` of explicit `size` (if any) to `kind` of `form`.
+/// Returns `None` if it doesn't match.
+fn match_immediate(form: &InstructionForm, kind: OperandKind, value: i128, size: Option<Size>) -> Option<Operand> {
+    if kind == OperandKind::One {
+        return (value == 1 && size.is_none()).then_some(Operand::Immediate {
+            value: 1,
+            size: Size::Bit8,
+        });
+    }
+
+    // Forms without register or memory operands whose size is implied by prefixes
+    // (e.g. `push imm16`) are picked only when the size is explicit (e.g. `push word 1`).
+    let size_is_implied = form.operation_size.is_some()
+        && form
+            .operands
+            .iter()
+            .all(|kind| register_or_memory_size(*kind).is_none() && *kind != OperandKind::Mem64);
+
+    let immediate_size = immediate_size(kind)?;
+    if size.is_some() && size != form.operation_size || size.is_none() && size_is_implied {
+        return None;
+    }
+    let allows_unsigned = form.unsigned_immediate || form.operation_size == Some(immediate_size);
+    fits(value, immediate_size, allows_unsigned).then_some(Operand::Immediate {
+        #[allow(clippy::cast_possible_truncation)]
+        value: value as i64,
+        size: immediate_size,
+    })
+}
+
+fn fits(value: i128, size: Size, allows_unsigned: bool) -> bool {
+    let bits = match size {
+        Size::Bit8 => 8,
+        Size::Bit16 => 16,
+        Size::Bit32 => 32,
+        Size::Bit64 => 64,
+        Size::Bit128 | Size::Bit256 | Size::Bit512 => unreachable!("There are no vector-sized immediates."),
+    };
+    let signed = -(1i128 << (bits - 1))..(1i128 << (bits - 1));
+    let unsigned = 0..(1i128 << bits);
+    signed.contains(&value) || allows_unsigned && unsigned.contains(&value)
+}
+
+const fn register_or_memory_size(kind: OperandKind) -> Option<Size> {
+    match kind {
+        OperandKind::Reg8 | OperandKind::RM8 | OperandKind::AL | OperandKind::CL => Some(Size::Bit8),
+        OperandKind::Reg16 | OperandKind::RM16 | OperandKind::AX => Some(Size::Bit16),
+        OperandKind::Reg32
+        | OperandKind::VReg32
+        | OperandKind::RM32
+        | OperandKind::R32M8
+        | OperandKind::R32M16
+        | OperandKind::XmmM32
+        | OperandKind::EAX => Some(Size::Bit32),
+        OperandKind::Reg64
+        | OperandKind::VReg64
+        | OperandKind::RM64
+        | OperandKind::XmmM64
+        | OperandKind::RAX
+        | OperandKind::Mem64 => Some(Size::Bit64),
+        OperandKind::XmmM128 => Some(Size::Bit128),
+        OperandKind::Imm8
+        | OperandKind::Imm16
+        | OperandKind::Imm32
+        | OperandKind::Imm64
+        | OperandKind::One
+        | OperandKind::Xmm
+        | OperandKind::VXmm
+        | OperandKind::XmmM8
+        | OperandKind::XmmM16
+        | OperandKind::XMM0
+        | OperandKind::Ymm
+        | OperandKind::VYmm
+        | OperandKind::YmmM256
+        | OperandKind::Zmm
+        | OperandKind::VZmm
+        | OperandKind::ZmmR
+        | OperandKind::ZmmM512
+        | OperandKind::ZmmM512B32
+        | OperandKind::ZmmM512B64
+        | OperandKind::XmmR
+        | OperandKind::K
+        | OperandKind::VK
+        | OperandKind::KR
+        | OperandKind::KM16
+        | OperandKind::Mask
+        | OperandKind::MergeMask
+        | OperandKind::Er
+        | OperandKind::Mem16
+        | OperandKind::Mem128
+        | OperandKind::Sae
+        | OperandKind::Vm32X
+        | OperandKind::Vm32Y
+        | OperandKind::Vm32Z
+        | OperandKind::Vm64X
+        | OperandKind::Vm64Y
+        | OperandKind::Vm64Z => None,
+    }
+}
+
+/// Returns the size of the vector index accepted by VSIB memory `kind`.
+const fn vsib_index_size(kind: OperandKind) -> Option<Size> {
+    match kind {
+        OperandKind::Vm32X | OperandKind::Vm64X => Some(Size::Bit128),
+        OperandKind::Vm32Y | OperandKind::Vm64Y => Some(Size::Bit256),
+        OperandKind::Vm32Z | OperandKind::Vm64Z => Some(Size::Bit512),
+        _ => None,
+    }
+}
+
+/// Verifies that the explicit size of VSIB memory operand (if any) is the size
+/// of a single element gathered (or scattered) by `instruction`.
+fn vsib_size_matches(operands: &[ParsedOperand], instruction: Instruction) -> bool {
+    let explicit_size = operands.iter().find_map(|operand| match operand.kind {
+        ParsedOperandKind::VSIBMemory { size, .. } => size,
+        _ => None,
+    });
+    let Some(explicit_size) = explicit_size else {
+        return true;
+    };
+    instruction.operands().as_slice().iter().any(|operand| match operand {
+        Operand::VSIBMemory { size, .. } => *size == explicit_size,
+        _ => false,
+    })
+}
+
+/// Returns the size of memory accepted by `kind`. This differs from
+/// [`register_or_memory_size`] for kinds like `r32/m8`, where the register
+/// and memory operands have different sizes.
+const fn memory_size(kind: OperandKind) -> Option<Size> {
+    match kind {
+        OperandKind::R32M8 | OperandKind::XmmM8 => Some(Size::Bit8),
+        OperandKind::R32M16 | OperandKind::XmmM16 | OperandKind::KM16 | OperandKind::Mem16 => Some(Size::Bit16),
+        OperandKind::Mem128 => Some(Size::Bit128),
+        OperandKind::YmmM256 => Some(Size::Bit256),
+        OperandKind::ZmmM512 | OperandKind::ZmmM512B32 | OperandKind::ZmmM512B64 => Some(Size::Bit512),
+        _ => register_or_memory_size(kind),
+    }
+}
+
+const fn immediate_size(kind: OperandKind) -> Option<Size> {
+    match kind {
+        OperandKind::Imm8 => Some(Size::Bit8),
+        OperandKind::Imm16 => Some(Size::Bit16),
+        OperandKind::Imm32 => Some(Size::Bit32),
+        OperandKind::Imm64 => Some(Size::Bit64),
+        _ => None,
+    }
+}
+
+const fn implicit_register(kind: OperandKind) -> Option<GPR> {
+    match kind {
+        OperandKind::AL => Some(GPR::AL),
+        OperandKind::AX => Some(GPR::AX),
+        OperandKind::EAX => Some(GPR::EAX),
+        OperandKind::RAX => Some(GPR::RAX),
+        OperandKind::CL => Some(GPR::CL),
+        _ => None,
+    }
+}
+
+/// Parses the legacy prefix written before the mnemonic, returning its canonical
+/// name, i.e. the one used by [`InstructionForm::prefix`].
+fn parse_prefix(name: &str) -> Option<&'static str> {
+    [
+        ("lock", "lock"),
+        ("rep", "rep"),
+        ("repe", "repe"),
+        ("repz", "repe"),
+        ("repne", "repne"),
+        ("repnz", "repne"),
+    ]
+    .into_iter()
+    .find_map(|(keyword, canonical)| keyword.eq_ignore_ascii_case(name).then_some(canonical))
+}
+
+fn parse_vector_index(name: &str) -> Option<VSIBIndex> {
+    XMM::from_name(name)
+        .map(VSIBIndex::from)
+        .or_else(|| YMM::from_name(name).map(VSIBIndex::from))
+        .or_else(|| ZMM::from_name(name).map(VSIBIndex::from))
+}
+
+fn parse_size_keyword(name: &str) -> Option<Size> {
+    [
+        ("byte", Size::Bit8),
+        ("word", Size::Bit16),
+        ("dword", Size::Bit32),
+        ("qword", Size::Bit64),
+        ("xmmword", Size::Bit128),
+        ("ymmword", Size::Bit256),
+        ("zmmword", Size::Bit512),
+    ]
+    .into_iter()
+    .find_map(|(keyword, size)| keyword.eq_ignore_ascii_case(name).then_some(size))
+}
+
+fn parse_scale(value: u64, span: Span) -> Result<Scale, ParseError> {
+    match value {
+        1 => Ok(Scale::Scale1),
+        2 => Ok(Scale::Scale2),
+        4 => Ok(Scale::Scale4),
+        8 => Ok(Scale::Scale8),
+        _ => Err(ParseError::new(ParseErrorKind::InvalidMemoryOperand, span)),
+    }
+}
+
+const fn unexpected_token(token: Token<'_>) -> ParseError {
+    ParseError::new(ParseErrorKind::UnexpectedToken, token.span)
+}
@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use super::{ParseError, ParseErrorKind, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenKind<'a> {
+    Identifier(&'a str),
+    Number(u64),
+    Comma,
+    OpenBracket,
+    CloseBracket,
+    Plus,
+    Minus,
+    Star,
+    /// The content of `{...}`, e.g. `k1` of `{k1}`.
+    Decoration(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    pub span: Span,
+}
+
+/// Represents a single statement (i.e. instruction) of the source.
+pub(super) type Statement<'a> = Vec<Token<'a>>;
+
+/// Splits `source` into statements, separated by new lines and `;`. Empty
+/// statements are skipped.
+pub(super) fn tokenize(source: &str) -> Result<Vec<Statement<'_>>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut statements = Vec::new();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let start = position;
+        let current = bytes[position];
+        position += 1;
+        let kind = match current {
+            b'\n' | b';' => {
+                push_statement(&mut statements, &mut tokens);
+                continue;
+            }
+            b'#' => {
+                while position < bytes.len() && bytes[position] != b'\n' {
+                    position += 1;
+                }
+                continue;
+            }
+            b' ' | b'\t' | b'\r' => continue,
+            b',' => TokenKind::Comma,
+            b'[' => TokenKind::OpenBracket,
+            b']' => TokenKind::CloseBracket,
+            b'+' => TokenKind::Plus,
+            b'-' => TokenKind::Minus,
+            b'*' => TokenKind::Star,
+            b'{' => {
+                while position < bytes.len() && (is_identifier_char(bytes[position]) || bytes[position] == b'-') {
+                    position += 1;
+                }
+                if position >= bytes.len() || bytes[position] != b'}' {
+                    return Err(ParseError::new(
+                        ParseErrorKind::UnexpectedCharacter,
+                        Span::new(start, start + 1),
+                    ));
+                }
+                position += 1;
+                TokenKind::Decoration(&source[start + 1..position - 1])
+            }
+            b'0'..=b'9' => {
+                while position < bytes.len() && is_identifier_char(bytes[position]) {
+                    position += 1;
+                }
+                let span = Span::new(start, position);
+                TokenKind::Number(parse_number(&source[start..position], span)?)
+            }
+            _ if is_identifier_start(current) => {
+                while position < bytes.len() && is_identifier_char(bytes[position]) {
+                    position += 1;
+                }
+                TokenKind::Identifier(&source[start..position])
+            }
+            _ => {
+                let len = source[start..].chars().next().map_or(1, char::len_utf8);
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    Span::new(start, start + len),
+                ));
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, position),
+        });
+    }
+
+    push_statement(&mut statements, &mut tokens);
+    Ok(statements)
+}
+
+fn push_statement<'a>(statements: &mut Vec<Statement<'a>>, tokens: &mut Statement<'a>) {
+    if !tokens.is_empty() {
+        statements.push(core::mem::take(tokens));
+    }
+}
+
+fn parse_number(text: &str, span: Span) -> Result<u64, ParseError> {
+    let text = text.replace('_', "");
+    let (digits, radix) = if let Some(digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        (digits, 16)
+    } else if let Some(digits) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        (digits, 2)
+    } else {
+        (text.as_str(), 10)
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(ParseError::new(ParseErrorKind::InvalidNumber, span));
+    }
+
+    u64::from_str_radix(digits, radix).map_err(|_| ParseError::new(ParseErrorKind::ValueOutOfRange, span))
+}
+
+const fn is_identifier_start(value: u8) -> bool {
+    value.is_ascii_alphabetic() || value == b'_' || value == b'.'
+}
+
+const fn is_identifier_char(value: u8) -> bool {
+    value.is_ascii_alphanumeric() || value == b'_' || value == b'.'
+}
@@ -0,0 +1,49 @@
+//! This module contains the text assembler, which turns Intel-syntax source
+//! into encoded instructions, e.g.
+//!
+//! ```rust
+//! use osom_encoders_x86_64::parsing::parse_intel;
+//!
+//! let code = parse_intel("push rbp; mov rbp, rsp\nadd rax, [rdi + rsi*8 + 16]").unwrap();
+//! assert_eq!(code[0].as_slice(), &[0x55]);
+//! assert_eq!(code[1].as_slice(), &[0x48, 0x89, 0xE5]);
+//! assert_eq!(code[2].as_slice(), &[0x48, 0x03, 0x44, 0xF7, 0x10]);
+//! ```
+//!
+//! # Syntax
+//!
+//! * Instructions are separated by new lines or `;`. Everything after `#`
+//!   until the end of the line is a comment.
+//! * String instructions (e.g. `movsb`) can be preceded by `rep`, `repe`/`repz`
+//!   or `repne`/`repnz` prefix, whichever the instruction accepts.
+//! * Instructions with memory destination (e.g. `add`, `xchg` or `cmpxchg`) can be
+//!   preceded by `lock` prefix, e.g. `lock add qword ptr [rax], rcx`.
+//! * Registers are named as in [`GPR::name`][crate::models::GPR::name],
+//!   [`XMM::name`][crate::models::XMM::name], [`YMM::name`][crate::models::YMM::name],
+//!   [`ZMM::name`][crate::models::ZMM::name] and [`Opmask::name`][crate::models::Opmask::name].
+//! * Memory operands are of the `size ptr [base + index*scale + displacement]` form,
+//!   where `size` is one of `byte`, `word`, `dword`, `qword`, `xmmword`, `ymmword` and `zmmword`.
+//!   The `size ptr` part is optional, unless the size cannot be inferred from other operands.
+//!   `rip` can be used as base for RIP-relative addressing. Gathers and scatters take
+//!   a vector register as index, e.g. `vpgatherdd xmm1, [rax + xmm2*4], xmm3`.
+//! * AVX-512 decorations follow their operand: `{k1}` masking and `{z}` zeroing after
+//!   the destination, e.g. `vaddps zmm0 {k1} {z}, zmm1, zmm2`, and `{1to16}` broadcast
+//!   after the memory operand, e.g. `dword ptr [rax]{1to16}`. Static rounding (`{rn-sae}`,
+//!   `{rd-sae}`, `{ru-sae}`, `{rz-sae}`) and `{sae}` are separate operands, placed as in LLVM.
+//! * Immediates are decimal, hexadecimal (`0x`) or binary (`0b`) numbers.
+//!   Relative jumps and calls take the raw displacement.
+//!
+//! Whenever multiple encodings match the instruction, the shortest one is
+//! picked, e.g. `add rax, 1` is encoded with [`add::encode_rm64_imm8`][crate::encoders::add::encode_rm64_imm8]
+//! and `add rax, 1000` with [`add::encode_rax_imm32`][crate::encoders::add::encode_rax_imm32].
+
+mod span;
+pub use span::*;
+
+mod parse_error;
+pub use parse_error::*;
+
+mod lexer;
+
+mod intel_parser;
+pub use intel_parser::*;
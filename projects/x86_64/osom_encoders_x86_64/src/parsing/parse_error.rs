@@ -0,0 +1,91 @@
+use core::fmt;
+
+use crate::models::EncodeError;
+
+use super::Span;
+
+/// Represents the kind of [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum ParseErrorKind {
+    /// The character is not allowed in the source.
+    UnexpectedCharacter,
+
+    /// The token is not allowed at this position.
+    UnexpectedToken,
+
+    /// The statement ended, but more tokens were expected.
+    UnexpectedEnd,
+
+    /// The number literal is malformed.
+    InvalidNumber,
+
+    /// The value does not fit into the immediate or displacement.
+    ValueOutOfRange,
+
+    /// There is no instruction with such mnemonic.
+    UnknownMnemonic,
+
+    /// The instruction does not accept the prefix, e.g. `rep add`.
+    InvalidPrefix,
+
+    /// The identifier is not a register name.
+    UnknownRegister,
+
+    /// The memory operand cannot be encoded, e.g. it has two indexes.
+    InvalidMemoryOperand,
+
+    /// The size of the memory operand cannot be inferred from other operands,
+    /// it has to be specified explicitly, e.g. `qword ptr [rax]`.
+    AmbiguousOperandSize,
+
+    /// None of the instruction variants accepts given operands.
+    InvalidOperands,
+
+    /// The instruction could not be encoded.
+    Encode(EncodeError),
+}
+
+/// Represents an error raised by the parsers, together with the [`Span`]
+/// of the source it refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Span,
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`].
+    #[inline]
+    pub const fn new(kind: ParseErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedCharacter => write!(f, "unexpected character"),
+            Self::UnexpectedToken => write!(f, "unexpected token"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of instruction"),
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::ValueOutOfRange => write!(f, "value out of range"),
+            Self::UnknownMnemonic => write!(f, "unknown mnemonic"),
+            Self::InvalidPrefix => write!(f, "invalid prefix"),
+            Self::UnknownRegister => write!(f, "unknown register"),
+            Self::InvalidMemoryOperand => write!(f, "invalid memory operand"),
+            Self::AmbiguousOperandSize => write!(f, "ambiguous operand size"),
+            Self::InvalidOperands => write!(f, "invalid operands"),
+            Self::Encode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl core::error::Error for ParseError {}
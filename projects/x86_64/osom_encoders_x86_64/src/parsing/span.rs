@@ -0,0 +1,28 @@
+/// Represents a range of bytes in the parsed source, i.e. `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new [`Span`].
+    #[inline]
+    pub const fn new(start: usize, end: usize) -> Self {
+        debug_assert!(start <= end, "Span start must not be greater than its end.");
+        Self { start, end }
+    }
+
+    /// Returns the smallest [`Span`] covering both `self` and `other`.
+    #[inline]
+    pub const fn join(self, other: Self) -> Self {
+        let start = if self.start < other.start {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end > other.end { self.end } else { other.end };
+        Self { start, end }
+    }
+}
@@ -0,0 +1,871 @@
+use crate::models::{
+    DecodeError, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Immediate64, Masking, Memory, Offset, Opmask,
+    OpmaskOrMemory, Rounding, Scale, Size, VSIBIndex, VSIBMemory, XMM, XMMOrMemory, YMM, YMMOrMemory, ZMM, ZMMOrMemory,
+};
+
+use crate::partial_encoders::core::{LOCK_PREFIX, OPERAND_SIZE_OVERRIDE_PREFIX, REP_PREFIX, REPNE_PREFIX};
+use crate::partial_encoders::evex::EVEX_PREFIX;
+use crate::partial_encoders::vex::{VEX2_PREFIX, VEX3_PREFIX, implied_prefix_field, opcode_map};
+
+pub const REX_W_BIT: u8 = 0b1000;
+pub const REX_R_BIT: u8 = 0b0100;
+pub const REX_X_BIT: u8 = 0b0010;
+pub const REX_B_BIT: u8 = 0b0001;
+
+/// Represents the mod/rm byte split into fields.
+#[derive(Debug, Clone, Copy)]
+pub struct ModRM {
+    pub mode: u8,
+    pub reg: u8,
+    pub rm: u8,
+}
+
+impl ModRM {
+    #[inline(always)]
+    const fn from_u8(value: u8) -> Self {
+        Self {
+            mode: value >> 6,
+            reg: (value >> 3) & 0b111,
+            rm: value & 0b111,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_register(self) -> bool {
+        self.mode == 0b11
+    }
+}
+
+/// Holds the state of decoding a single instruction. The prefixes
+/// (LOCK, operand size override, REP/REPNE, REX, VEX and EVEX) are parsed eagerly, everything
+/// else is read on demand by partial decoders.
+#[derive(Debug)]
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    has_oso: bool,
+
+    /// The LOCK prefix, or 0 if there is none.
+    lock_prefix: u8,
+
+    /// The LOCK prefix expected by [`Decoder::matches`], see [`Decoder::set_lock_prefix`].
+    expected_lock_prefix: u8,
+
+    /// The REP or REPNE prefix, or 0 if there is none.
+    prefix: u8,
+
+    /// The prefix expected by [`Decoder::matches`], see [`Decoder::set_mandatory_prefix`].
+    mandatory_prefix: u8,
+
+    /// The REX prefix, or 0 if there is none. The R, X, B and W bits
+    /// of VEX and EVEX prefixes are stored here as well.
+    rex: u8,
+
+    /// The VEX (or EVEX) opcode map (1, 2 or 3), or 0 if there is no such prefix.
+    vex_map: u8,
+
+    /// The VEX.pp (or EVEX.pp) field.
+    vex_pp: u8,
+
+    /// The VEX.L bit.
+    vex_l: bool,
+
+    /// The register index encoded in VEX.vvvv (already not inverted).
+    /// With EVEX prefix this is the full 5-bit index, extended by EVEX.V'.
+    vex_vvvv: u8,
+
+    /// True if the prefix is EVEX rather than VEX.
+    is_evex: bool,
+
+    /// The EVEX.R' bit (already not inverted) shifted to bit 4, i.e. either
+    /// 0 or `0b1_0000`. It extends the reg field of mod/rm to 5 bits.
+    evex_r_prime: u8,
+
+    /// The EVEX.z, EVEX.L'L, EVEX.b and EVEX.aaa fields, at their positions
+    /// in the last byte of EVEX prefix (with EVEX.V' cleared).
+    evex_p2: u8,
+
+    /// The N of compressed 8-bit displacement (disp8*N), 1 outside of EVEX.
+    disp8_scale: u8,
+
+    /// The position of the next byte to read.
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        let mut position = 0;
+        let mut has_oso = false;
+        let mut lock_prefix = 0;
+        let mut prefix = 0;
+        while position < bytes.len() {
+            let byte = bytes[position];
+            if byte == OPERAND_SIZE_OVERRIDE_PREFIX.get() && !has_oso {
+                has_oso = true;
+            } else if byte == LOCK_PREFIX.get() && lock_prefix == 0 {
+                lock_prefix = byte;
+            } else if (byte == REP_PREFIX.get() || byte == REPNE_PREFIX.get()) && prefix == 0 {
+                prefix = byte;
+            } else {
+                break;
+            }
+            position += 1;
+        }
+
+        let mut decoder = Self {
+            bytes,
+            has_oso,
+            lock_prefix,
+            expected_lock_prefix: 0,
+            prefix,
+            mandatory_prefix: 0,
+            rex: 0,
+            vex_map: 0,
+            vex_pp: 0,
+            vex_l: false,
+            vex_vvvv: 0,
+            is_evex: false,
+            evex_r_prime: 0,
+            evex_p2: 0,
+            disp8_scale: 1,
+            position,
+        };
+
+        if position < bytes.len() && (bytes[position] == VEX2_PREFIX || bytes[position] == VEX3_PREFIX) {
+            decoder.read_vex();
+        } else if position < bytes.len() && bytes[position] == EVEX_PREFIX {
+            decoder.read_evex();
+        } else if position < bytes.len() && bytes[position] & 0xF0 == 0x40 {
+            decoder.rex = bytes[position];
+            decoder.position += 1;
+        }
+
+        decoder
+    }
+
+    /// Reads 2-byte or 3-byte VEX prefix at the current position. If the opcode
+    /// map of the 3-byte prefix is invalid, nothing is consumed, and so `C4`
+    /// is treated as an (unknown) opcode.
+    const fn read_vex(&mut self) {
+        let is_vex3 = self.bytes[self.position] == VEX3_PREFIX;
+        let length = if is_vex3 { 3 } else { 2 };
+        if self.position + length > self.bytes.len() {
+            // Truncated VEX prefix, consume everything, so that reading
+            // the opcode fails with unexpected end.
+            self.position = self.bytes.len();
+            return;
+        }
+
+        // R, X, B and vvvv are stored inverted.
+        let first = self.bytes[self.position + 1];
+        let map = first & 0b1_1111;
+        if is_vex3 && (map == 0 || map > 3) {
+            return;
+        }
+
+        let mut rex = 0b0100_0000 | (!first >> 5) & REX_R_BIT;
+        let last = if is_vex3 {
+            rex |= (!first >> 5) & (REX_X_BIT | REX_B_BIT);
+            self.vex_map = map;
+            let second = self.bytes[self.position + 2];
+            rex |= (second >> 4) & REX_W_BIT;
+            second
+        } else {
+            self.vex_map = 1;
+            first
+        };
+
+        self.rex = rex;
+        self.vex_vvvv = (!last >> 3) & 0b1111;
+        self.vex_l = last & 0b100 != 0;
+        self.vex_pp = last & 0b11;
+        self.position += length;
+    }
+
+    /// Reads 4-byte EVEX prefix at the current position. If the fixed bits
+    /// of the prefix are invalid, nothing is consumed, and so `62` is treated
+    /// as an (unknown) opcode.
+    const fn read_evex(&mut self) {
+        if self.position + 4 > self.bytes.len() {
+            self.position = self.bytes.len();
+            return;
+        }
+
+        let p0 = self.bytes[self.position + 1];
+        let p1 = self.bytes[self.position + 2];
+        let p2 = self.bytes[self.position + 3];
+        let map = p0 & 0b11;
+        if p0 & 0b1100 != 0 || map == 0 || p1 & 0b100 == 0 {
+            return;
+        }
+
+        // R, X, B, R', vvvv and V' are stored inverted.
+        self.rex = 0b0100_0000 | (!p0 >> 5) & (REX_R_BIT | REX_X_BIT | REX_B_BIT) | (p1 >> 4) & REX_W_BIT;
+        self.evex_r_prime = !p0 & 0b1_0000;
+        self.vex_map = map;
+        self.vex_vvvv = ((!p1 >> 3) & 0b1111) | if p2 & 0b1000 == 0 { 0b1_0000 } else { 0 };
+        self.vex_pp = p1 & 0b11;
+        self.is_evex = true;
+        self.evex_p2 = p2 & !0b1000;
+        self.position += 4;
+    }
+
+    /// Returns the first byte of the opcode.
+    pub const fn opcode_byte(&self) -> Result<u8, DecodeError> {
+        self.peek(0)
+    }
+
+    /// Returns true if the REX (or VEX) prefix is present.
+    #[inline(always)]
+    pub const fn has_rex(&self) -> bool {
+        self.rex != 0
+    }
+
+    /// Checks whether prefixes and opcode match the current instruction.
+    /// Nothing is consumed.
+    ///
+    /// The REX prefix, if present, has to have W bit equal to `rex_w`
+    /// and can have only `allowed_rex_bits` (out of R, X and B) set.
+    /// The REP/REPNE prefix has to be equal to the mandatory prefix, i.e.
+    /// by default the instruction cannot have it. Similarly the LOCK prefix
+    /// has to be present if and only if it is expected.
+    #[inline(always)]
+    pub const fn matches(
+        &self,
+        opcode: &[u8],
+        oso: bool,
+        rex_w: bool,
+        allowed_rex_bits: u8,
+    ) -> Result<bool, DecodeError> {
+        self.matches_with_prefix(opcode, self.mandatory_prefix, oso, rex_w, allowed_rex_bits)
+    }
+
+    /// Sets the 66/F2/F3 `prefix` (or 0 for none) which is a part of the opcode
+    /// of the instructions matched next, e.g. `F3` of `popcnt`.
+    #[inline(always)]
+    pub const fn set_mandatory_prefix(&mut self, prefix: u8) {
+        self.mandatory_prefix = prefix;
+    }
+
+    /// Sets whether the instructions matched next are LOCK-prefixed. Since LOCK
+    /// is allowed with memory destination only, their mod/rm byte (which follows
+    /// the opcode) cannot encode a register.
+    #[inline(always)]
+    pub const fn set_lock_prefix(&mut self, lock_prefix: bool) {
+        self.expected_lock_prefix = if lock_prefix { LOCK_PREFIX.get() } else { 0 };
+    }
+
+    /// Like [`Decoder::matches`], but the instruction has to have
+    /// the REP/REPNE `prefix` (or none if `prefix` is 0). The mandatory
+    /// `66` prefix is treated as operand size override, and so `oso` is ignored.
+    pub const fn matches_with_prefix(
+        &self,
+        opcode: &[u8],
+        prefix: u8,
+        oso: bool,
+        rex_w: bool,
+        allowed_rex_bits: u8,
+    ) -> Result<bool, DecodeError> {
+        if self.vex_map != 0 {
+            return Ok(false);
+        }
+
+        let (oso, prefix) = if prefix == OPERAND_SIZE_OVERRIDE_PREFIX.get() {
+            (true, 0)
+        } else {
+            (oso, prefix)
+        };
+
+        if self.has_oso != oso || self.prefix != prefix || self.lock_prefix != self.expected_lock_prefix {
+            return Ok(false);
+        }
+
+        let rex_w_bit = if rex_w { REX_W_BIT } else { 0 };
+        if self.rex & REX_W_BIT != rex_w_bit {
+            return Ok(false);
+        }
+
+        if self.rex & (REX_R_BIT | REX_X_BIT | REX_B_BIT) & !allowed_rex_bits != 0 {
+            return Ok(false);
+        }
+
+        let mut index = 0;
+        while index < opcode.len() {
+            let position = self.position + index;
+            if position >= self.bytes.len() {
+                return Err(DecodeError::UnexpectedEnd);
+            }
+            if self.bytes[position] != opcode[index] {
+                return Ok(false);
+            }
+            index += 1;
+        }
+
+        if self.expected_lock_prefix != 0 && const_try!(self.peek_mod_rm(opcode.len())).is_register() {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Checks whether VEX prefix and opcode match the current instruction.
+    /// Nothing is consumed.
+    ///
+    /// The leading bytes of `opcode` have to match the VEX opcode map,
+    /// the mandatory `prefix` (0, `66`, `F3` or `F2`) has to match VEX.pp,
+    /// VEX.W has to equal `w` and VEX.L has to equal `l`. The legacy prefixes
+    /// are not allowed together with VEX.
+    pub const fn matches_vex(&self, opcode: &[u8], prefix: u8, w: bool, l: bool) -> Result<bool, DecodeError> {
+        if self.is_evex
+            || self.vex_map == 0
+            || self.has_oso
+            || self.lock_prefix != 0
+            || self.prefix != 0
+            || self.vex_l != l
+        {
+            return Ok(false);
+        }
+
+        if self.vex_map != opcode_map(opcode) || self.vex_pp != implied_prefix_field(prefix) {
+            return Ok(false);
+        }
+
+        let rex_w_bit = if w { REX_W_BIT } else { 0 };
+        if self.rex & REX_W_BIT != rex_w_bit {
+            return Ok(false);
+        }
+
+        let opcode_byte = const_try!(self.peek(0));
+        Ok(opcode_byte == opcode[opcode.len() - 1])
+    }
+
+    /// Checks whether EVEX prefix and opcode match the current instruction.
+    /// Nothing is consumed.
+    ///
+    /// The `opcode`, `prefix` and `w` are matched exactly as in [`Decoder::matches_vex`].
+    /// The vector length, EVEX.b and masking are checked by the partial decoders.
+    pub const fn matches_evex(&self, opcode: &[u8], prefix: u8, w: bool) -> Result<bool, DecodeError> {
+        if !self.is_evex || self.has_oso || self.lock_prefix != 0 || self.prefix != 0 {
+            return Ok(false);
+        }
+
+        if self.vex_map != opcode_map(opcode) || self.vex_pp != implied_prefix_field(prefix) {
+            return Ok(false);
+        }
+
+        let rex_w_bit = if w { REX_W_BIT } else { 0 };
+        if self.rex & REX_W_BIT != rex_w_bit {
+            return Ok(false);
+        }
+
+        let opcode_byte = const_try!(self.peek(0));
+        Ok(opcode_byte == opcode[opcode.len() - 1])
+    }
+
+    /// Returns the EVEX.L'L field. With register operands and EVEX.b set
+    /// this is the static rounding control instead.
+    #[inline(always)]
+    pub const fn evex_vector_length(&self) -> u8 {
+        (self.evex_p2 >> 5) & 0b11
+    }
+
+    /// Returns true if the EVEX.b bit is set.
+    #[inline(always)]
+    pub const fn evex_b(&self) -> bool {
+        self.evex_p2 & 0b1_0000 != 0
+    }
+
+    /// Returns the static rounding encoded in EVEX.L'L.
+    #[inline(always)]
+    pub const fn evex_rounding(&self) -> Rounding {
+        Rounding::from_u8(self.evex_vector_length())
+    }
+
+    /// Returns the masking encoded in EVEX.aaa and EVEX.z, or `None`
+    /// if zeroing is requested without a mask.
+    pub const fn evex_masking(&self) -> Option<Masking> {
+        let aaa = self.evex_p2 & 0b111;
+        let zeroing = self.evex_p2 & 0b1000_0000 != 0;
+        match (aaa, zeroing) {
+            (0, false) => Some(Masking::None),
+            (0, true) => None,
+            (_, false) => Some(Masking::Merge {
+                opmask: Opmask::from_index(aaa),
+            }),
+            (_, true) => Some(Masking::Zero {
+                opmask: Opmask::from_index(aaa),
+            }),
+        }
+    }
+
+    /// Sets the N of compressed 8-bit displacement (disp8*N) of the memory
+    /// operand read next.
+    #[inline(always)]
+    pub const fn set_disp8_scale(&mut self, disp8_scale: u8) {
+        self.disp8_scale = disp8_scale;
+    }
+
+    /// Returns the ZMM register encoded in EVEX.vvvv and EVEX.V'.
+    #[inline(always)]
+    pub const fn evex_zmm(&self) -> ZMM {
+        ZMM::from_index(self.vex_vvvv)
+    }
+
+    /// Returns the opmask register encoded in VEX.vvvv.
+    #[inline(always)]
+    pub const fn vex_opmask(&self) -> Opmask {
+        Opmask::from_index(self.vex_vvvv & 0b111)
+    }
+
+    /// Returns true if the REX.R bit (or its VEX equivalent) is set.
+    #[inline(always)]
+    pub const fn has_rex_r(&self) -> bool {
+        self.rex & REX_R_BIT != 0
+    }
+
+    /// Returns the register encoded in VEX.vvvv.
+    #[inline(always)]
+    pub const fn vex_gpr(&self, size: Size) -> GPR {
+        GPR::from_index(self.vex_vvvv, size, true)
+    }
+
+    /// Returns the XMM register encoded in VEX.vvvv.
+    #[inline(always)]
+    pub const fn vex_xmm(&self) -> XMM {
+        XMM::from_index(self.vex_vvvv)
+    }
+
+    /// Returns the YMM register encoded in VEX.vvvv.
+    #[inline(always)]
+    pub const fn vex_ymm(&self) -> YMM {
+        YMM::from_index(self.vex_vvvv)
+    }
+
+    /// Returns the register index encoded in VEX.vvvv.
+    #[inline(always)]
+    pub const fn vex_vvvv(&self) -> u8 {
+        self.vex_vvvv
+    }
+
+    /// Like [`Decoder::matches`], but additionally checks that the reg field
+    /// of the mod/rm byte following the opcode equals `extended_opcode`.
+    pub const fn matches_extended(
+        &self,
+        opcode: &[u8],
+        extended_opcode: u8,
+        oso: bool,
+        rex_w: bool,
+        allowed_rex_bits: u8,
+    ) -> Result<bool, DecodeError> {
+        if !const_try!(self.matches(opcode, oso, rex_w, allowed_rex_bits)) {
+            return Ok(false);
+        }
+
+        let mod_rm = const_try!(self.peek_mod_rm(opcode.len()));
+        Ok(mod_rm.reg == extended_opcode)
+    }
+
+    /// Returns the mod/rm byte at `offset` bytes from the current position,
+    /// without consuming it.
+    pub const fn peek_mod_rm(&self, offset: usize) -> Result<ModRM, DecodeError> {
+        let value = const_try!(self.peek(offset));
+        Ok(ModRM::from_u8(value))
+    }
+
+    /// Like [`Decoder::matches`], but the lower 3 bits of the opcode's last byte
+    /// encode a register.
+    pub const fn matches_register_in_opcode(
+        &self,
+        opcode: &[u8],
+        oso: bool,
+        rex_w: bool,
+        allowed_rex_bits: u8,
+    ) -> Result<bool, DecodeError> {
+        let (head, last) = opcode.split_at(opcode.len() - 1);
+        if !const_try!(self.matches(head, oso, rex_w, allowed_rex_bits)) {
+            return Ok(false);
+        }
+
+        let byte = const_try!(self.peek(head.len()));
+        Ok(byte & !0b111 == last[0])
+    }
+
+    /// Skips `count` bytes, which have already been matched.
+    #[inline(always)]
+    pub const fn consume(&mut self, count: usize) {
+        debug_assert!(self.position + count <= self.bytes.len());
+        self.position += count;
+    }
+
+    /// Reads the register encoded in the lower 3 bits of the last consumed
+    /// byte (the opcode), extended by REX.B.
+    pub const fn opcode_gpr(&self, size: Size) -> GPR {
+        debug_assert!(self.position > 0);
+        let index = (self.bytes[self.position - 1] & 0b111) | self.rex_bit(REX_B_BIT);
+        GPR::from_index(index, size, self.has_rex())
+    }
+
+    pub const fn read_mod_rm(&mut self) -> Result<ModRM, DecodeError> {
+        let value = const_try!(self.read_u8());
+        Ok(ModRM::from_u8(value))
+    }
+
+    /// Returns the register encoded in the reg field of `mod_rm`, extended by REX.R.
+    pub const fn reg_gpr(&self, mod_rm: ModRM, size: Size) -> GPR {
+        let index = mod_rm.reg | self.rex_bit(REX_R_BIT);
+        GPR::from_index(index, size, self.has_rex())
+    }
+
+    /// Reads register or memory operand described by `mod_rm`.
+    /// This is the inverse of `encode_memory` for memory operands.
+    pub const fn read_gpr_or_memory(&mut self, mod_rm: ModRM, size: Size) -> Result<GPROrMemory, DecodeError> {
+        if mod_rm.is_register() {
+            let index = mod_rm.rm | self.rex_bit(REX_B_BIT);
+            return Ok(GPROrMemory::GPR {
+                gpr: GPR::from_index(index, size, self.has_rex()),
+            });
+        }
+
+        let memory = const_try!(self.read_memory(mod_rm));
+        Ok(GPROrMemory::Memory { memory })
+    }
+
+    /// Returns the XMM register encoded in the reg field of `mod_rm`, extended by REX.R
+    /// (and EVEX.R').
+    pub const fn reg_xmm(&self, mod_rm: ModRM) -> XMM {
+        XMM::from_index(self.reg_index(mod_rm))
+    }
+
+    /// Returns the XMM register encoded in the r/m field of `mod_rm`, extended by REX.B
+    /// (and EVEX.X). The `mod_rm` must describe a register.
+    pub const fn rm_xmm(&self, mod_rm: ModRM) -> XMM {
+        XMM::from_index(self.rm_index(mod_rm))
+    }
+
+    /// Reads XMM or memory operand described by `mod_rm`.
+    pub const fn read_xmm_or_memory(&mut self, mod_rm: ModRM) -> Result<XMMOrMemory, DecodeError> {
+        if mod_rm.is_register() {
+            return Ok(XMMOrMemory::XMM {
+                xmm: self.rm_xmm(mod_rm),
+            });
+        }
+
+        let memory = const_try!(self.read_memory(mod_rm));
+        Ok(XMMOrMemory::Memory { memory })
+    }
+
+    /// Returns the ZMM register encoded in the reg field of `mod_rm`, extended by EVEX.R and EVEX.R'.
+    pub const fn reg_zmm(&self, mod_rm: ModRM) -> ZMM {
+        ZMM::from_index(self.reg_index(mod_rm))
+    }
+
+    /// Returns the ZMM register encoded in the r/m field of `mod_rm`, extended by EVEX.B and EVEX.X.
+    /// The `mod_rm` must describe a register.
+    pub const fn rm_zmm(&self, mod_rm: ModRM) -> ZMM {
+        ZMM::from_index(self.rm_index(mod_rm))
+    }
+
+    /// Reads ZMM or memory operand described by `mod_rm`. With EVEX.b set the memory
+    /// operand is a broadcast of a single element of `element_size` bytes,
+    /// otherwise it is 64 bytes wide. The displacement is scaled accordingly.
+    pub const fn read_zmm_or_memory(&mut self, mod_rm: ModRM, element_size: u8) -> Result<ZMMOrMemory, DecodeError> {
+        if mod_rm.is_register() {
+            return Ok(ZMMOrMemory::ZMM {
+                zmm: self.rm_zmm(mod_rm),
+            });
+        }
+
+        let broadcast = self.evex_b();
+        self.disp8_scale = if broadcast { element_size } else { 64 };
+        let memory = const_try!(self.read_memory(mod_rm));
+        if broadcast {
+            Ok(ZMMOrMemory::Broadcast { memory })
+        } else {
+            Ok(ZMMOrMemory::Memory { memory })
+        }
+    }
+
+    /// Returns the opmask register encoded in the reg field of `mod_rm`, or `None` if
+    /// the register is extended by REX.R (or EVEX.R'), since there are only 8 opmask registers.
+    pub const fn reg_opmask(&self, mod_rm: ModRM) -> Option<Opmask> {
+        if self.reg_index(mod_rm) >= 8 {
+            return None;
+        }
+        Some(Opmask::from_index(mod_rm.reg))
+    }
+
+    /// Returns the opmask register encoded in the r/m field of `mod_rm`, or `None` if
+    /// it is extended by REX.B (or EVEX.X). The `mod_rm` must describe a register.
+    pub const fn rm_opmask(&self, mod_rm: ModRM) -> Option<Opmask> {
+        if self.rm_index(mod_rm) >= 8 {
+            return None;
+        }
+        Some(Opmask::from_index(mod_rm.rm))
+    }
+
+    /// Reads opmask register or memory operand described by `mod_rm`,
+    /// with the same restrictions as [`Decoder::rm_opmask`].
+    pub const fn read_opmask_or_memory(&mut self, mod_rm: ModRM) -> Result<Option<OpmaskOrMemory>, DecodeError> {
+        if mod_rm.is_register() {
+            return match self.rm_opmask(mod_rm) {
+                Some(opmask) => Ok(Some(OpmaskOrMemory::Opmask { opmask })),
+                None => Ok(None),
+            };
+        }
+
+        let memory = const_try!(self.read_memory(mod_rm));
+        Ok(Some(OpmaskOrMemory::Memory { memory }))
+    }
+
+    /// Returns the YMM register encoded in the reg field of `mod_rm`, extended by VEX.R
+    /// (and EVEX.R').
+    pub const fn reg_ymm(&self, mod_rm: ModRM) -> YMM {
+        YMM::from_index(self.reg_index(mod_rm))
+    }
+
+    /// Returns the YMM register encoded in the r/m field of `mod_rm`, extended by VEX.B.
+    /// The `mod_rm` must describe a register.
+    pub const fn rm_ymm(&self, mod_rm: ModRM) -> YMM {
+        YMM::from_index(mod_rm.rm | self.rex_bit(REX_B_BIT))
+    }
+
+    /// Reads YMM or memory operand described by `mod_rm`.
+    pub const fn read_ymm_or_memory(&mut self, mod_rm: ModRM) -> Result<YMMOrMemory, DecodeError> {
+        if mod_rm.is_register() {
+            return Ok(YMMOrMemory::YMM {
+                ymm: self.rm_ymm(mod_rm),
+            });
+        }
+
+        let memory = const_try!(self.read_memory(mod_rm));
+        Ok(YMMOrMemory::Memory { memory })
+    }
+
+    /// Reads memory operand described by `mod_rm`. The `mod_rm` must not
+    /// describe a register.
+    ///
+    /// The decoded memory is in the form produced by the encoders, e.g.
+    /// `[rbp + 0]` encoded with 8-bit displacement decodes as `[rbp]`.
+    pub const fn read_memory(&mut self, mod_rm: ModRM) -> Result<Memory, DecodeError> {
+        debug_assert!(!mod_rm.is_register());
+
+        if mod_rm.rm == 0b101 && mod_rm.mode == 0b00 {
+            // RIP-relative addressing, always with 32-bit displacement.
+            let imm32 = const_try!(self.read_imm32());
+            return Ok(Memory::RelativeToRIP {
+                offset: Offset::Bit32(imm32),
+            });
+        }
+
+        if mod_rm.rm != 0b100 {
+            let base = Self::memory_gpr(mod_rm.rm | self.rex_bit(REX_B_BIT));
+            let offset = const_try!(self.read_offset(mod_rm, base));
+            return Ok(Memory::Based { base, offset });
+        }
+
+        let sib = const_try!(self.read_u8());
+        let scale = Scale::from_u8((sib >> 6) + 1);
+        let index_field = ((sib >> 3) & 0b111) | self.rex_bit(REX_X_BIT);
+        let base_field = (sib & 0b111) | self.rex_bit(REX_B_BIT);
+
+        // Index 0b100 without REX.X means "no index", since RSP cannot be an index.
+        let index = if index_field == 0b100 {
+            None
+        } else {
+            Some(Self::memory_gpr(index_field))
+        };
+
+        if base_field & 0b111 == 0b101 && mod_rm.mode == 0b00 {
+            // No base, mandatory 32-bit displacement.
+            let Some(index) = index else {
+                return Err(DecodeError::UnsupportedAddressing);
+            };
+            let imm32 = const_try!(self.read_imm32());
+            return Ok(Memory::Scaled {
+                index,
+                scale,
+                offset: Offset::Bit32(imm32),
+            });
+        }
+
+        let base = Self::memory_gpr(base_field);
+        let offset = const_try!(self.read_offset(mod_rm, base));
+
+        match index {
+            Some(index) => Ok(Memory::BasedAndScaled {
+                base,
+                index,
+                scale,
+                offset,
+            }),
+            None => Ok(Memory::Based { base, offset }),
+        }
+    }
+
+    /// Returns true if `mod_rm` describes VSIB memory, i.e. memory with SIB byte.
+    #[inline(always)]
+    pub const fn is_vsib(mod_rm: ModRM) -> bool {
+        !mod_rm.is_register() && mod_rm.rm == 0b100
+    }
+
+    /// Returns the full index of the vector index register of the SIB byte at
+    /// `offset`, relative to the current position. Nothing is consumed.
+    pub const fn peek_vsib_index(&self, offset: usize) -> Result<u8, DecodeError> {
+        let sib = const_try!(self.peek(offset));
+        Ok(self.vsib_index(sib))
+    }
+
+    /// Reads VSIB memory operand described by `mod_rm`, with vector index
+    /// of `index_size` size. The `mod_rm` has to satisfy [`Decoder::is_vsib`].
+    ///
+    /// Unlike in [`Decoder::read_memory`], the `0b100` index is a valid
+    /// register. With EVEX prefix the index is extended to 5 bits by EVEX.V'.
+    pub const fn read_vsib_memory(&mut self, mod_rm: ModRM, index_size: Size) -> Result<VSIBMemory, DecodeError> {
+        debug_assert!(Self::is_vsib(mod_rm));
+
+        let sib = const_try!(self.read_u8());
+        let scale = Scale::from_u8((sib >> 6) + 1);
+        let index = VSIBIndex::from_index(self.vsib_index(sib), index_size);
+        let base_field = (sib & 0b111) | self.rex_bit(REX_B_BIT);
+
+        if base_field & 0b111 == 0b101 && mod_rm.mode == 0b00 {
+            // No base, mandatory 32-bit displacement.
+            let imm32 = const_try!(self.read_imm32());
+            return Ok(VSIBMemory::Scaled {
+                index,
+                scale,
+                offset: Offset::Bit32(imm32),
+            });
+        }
+
+        let base = Self::memory_gpr(base_field);
+        let offset = const_try!(self.read_offset(mod_rm, base));
+        Ok(VSIBMemory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        })
+    }
+
+    pub const fn read_imm8(&mut self) -> Result<Immediate8, DecodeError> {
+        let bytes = const_try!(self.read_array::<1>());
+        Ok(Immediate8::from_u8(bytes[0]))
+    }
+
+    pub const fn read_imm16(&mut self) -> Result<Immediate16, DecodeError> {
+        let bytes = const_try!(self.read_array());
+        Ok(Immediate16::from_u16(u16::from_le_bytes(bytes)))
+    }
+
+    pub const fn read_imm32(&mut self) -> Result<Immediate32, DecodeError> {
+        let bytes = const_try!(self.read_array());
+        Ok(Immediate32::from_u32(u32::from_le_bytes(bytes)))
+    }
+
+    pub const fn read_imm64(&mut self) -> Result<Immediate64, DecodeError> {
+        let bytes = const_try!(self.read_array());
+        Ok(Immediate64::from_u64(u64::from_le_bytes(bytes)))
+    }
+
+    /// Returns the total number of bytes read so far, including prefixes.
+    #[inline(always)]
+    pub const fn length(&self) -> usize {
+        self.position
+    }
+
+    /// Reads the displacement of memory operand with `base` register.
+    /// The 8-bit displacement is multiplied by the disp8*N scale.
+    const fn read_offset(&mut self, mod_rm: ModRM, base: GPR) -> Result<Offset, DecodeError> {
+        match mod_rm.mode {
+            0b00 => Ok(Offset::None),
+            0b01 => {
+                let imm8 = const_try!(self.read_imm8());
+                // RBP and R13 cannot be encoded without displacement,
+                // so the encoders use zero 8-bit displacement instead.
+                if imm8.as_u8() == 0 && (base.equals(GPR::RBP) || base.equals(GPR::R13)) {
+                    Ok(Offset::None)
+                } else if self.disp8_scale == 1 {
+                    Ok(Offset::Bit8(imm8))
+                } else {
+                    let value = imm8.as_i8() as i32 * self.disp8_scale as i32;
+                    if value >= i8::MIN as i32 && value <= i8::MAX as i32 {
+                        #[allow(clippy::cast_possible_truncation)]
+                        Ok(Offset::from_i8(value as i8))
+                    } else {
+                        Ok(Offset::from_i32(value))
+                    }
+                }
+            }
+            _ => Ok(Offset::Bit32(const_try!(self.read_imm32()))),
+        }
+    }
+
+    /// Returns the full index of the register in the reg field of `mod_rm`.
+    #[inline(always)]
+    const fn reg_index(&self, mod_rm: ModRM) -> u8 {
+        mod_rm.reg | self.rex_bit(REX_R_BIT) | self.evex_r_prime
+    }
+
+    /// Returns the full index of the register in the r/m field of `mod_rm`.
+    /// EVEX.X extends it to 5 bits, since there is no SIB index.
+    #[inline(always)]
+    const fn rm_index(&self, mod_rm: ModRM) -> u8 {
+        let x = if self.is_evex && self.rex & REX_X_BIT != 0 {
+            0b1_0000
+        } else {
+            0
+        };
+        mod_rm.rm | self.rex_bit(REX_B_BIT) | x
+    }
+
+    /// Returns the full index of the vector index register of `sib`. With EVEX
+    /// prefix, EVEX.V' extends it to 5 bits, since there is no EVEX.vvvv operand.
+    #[inline(always)]
+    const fn vsib_index(&self, sib: u8) -> u8 {
+        let v_prime = if self.is_evex { self.vex_vvvv & 0b1_0000 } else { 0 };
+        ((sib >> 3) & 0b111) | self.rex_bit(REX_X_BIT) | v_prime
+    }
+
+    #[inline(always)]
+    const fn memory_gpr(index: u8) -> GPR {
+        GPR::from_index(index, Size::Bit64, true)
+    }
+
+    /// Returns `0b1000` if the REX `bit` is set, `0` otherwise.
+    #[inline(always)]
+    const fn rex_bit(&self, bit: u8) -> u8 {
+        if self.rex & bit == 0 { 0 } else { 0b1000 }
+    }
+
+    #[inline(always)]
+    const fn peek(&self, offset: usize) -> Result<u8, DecodeError> {
+        let position = self.position + offset;
+        if position < self.bytes.len() {
+            Ok(self.bytes[position])
+        } else {
+            Err(DecodeError::UnexpectedEnd)
+        }
+    }
+
+    #[inline(always)]
+    const fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        let value = const_try!(self.peek(0));
+        self.position += 1;
+        Ok(value)
+    }
+
+    const fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        if self.position + N > self.bytes.len() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+
+        let mut result = [0u8; N];
+        let mut index = 0;
+        while index < N {
+            result[index] = self.bytes[self.position + index];
+            index += 1;
+        }
+        self.position += N;
+        Ok(result)
+    }
+}
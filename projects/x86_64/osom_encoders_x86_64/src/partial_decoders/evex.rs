@@ -0,0 +1,460 @@
+use crate::models::{
+    DecodeError, Immediate8, Masking, Opmask, Rounding, Size, VSIBMemory, XMM, XMMOrMemory, YMM, ZMM, ZMMOrMemory,
+};
+
+use super::Decoder;
+use super::core::ModRM;
+
+/// The result of partial decoders: the operands, or `None` if the instruction doesn't match.
+type Decoded<T> = Result<Option<T>, DecodeError>;
+
+/// The EVEX.L'L field of 512-bit instructions.
+const VECTOR_LENGTH_512: u8 = 0b10;
+
+/// The form of EVEX.512 r/m operand expected by the partial decoders.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Form {
+    /// ZMM or memory, with broadcast allowed if `broadcast` is set.
+    Vector { broadcast: bool },
+
+    /// ZMM register with EVEX.b set, i.e. `{er}` or `{sae}`.
+    RegisterWithB,
+}
+
+/// Checks EVEX prefix, opcode, masking and the mod/rm byte of EVEX.512 instruction
+/// of given `form`. Nothing is consumed. Returns the masking and the mod/rm byte on success.
+const fn matches_zmm<const N: usize>(
+    decoder: &Decoder,
+    opcode: &[u8; N],
+    prefix: u8,
+    w: bool,
+    form: Form,
+) -> Decoded<(Masking, ModRM)> {
+    if !const_try!(decoder.matches_evex(opcode, prefix, w)) {
+        return Ok(None);
+    }
+
+    let Some(masking) = decoder.evex_masking() else {
+        return Ok(None);
+    };
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let matches = match form {
+        Form::Vector { broadcast } => {
+            if mod_rm.is_register() || !decoder.evex_b() {
+                !decoder.evex_b() && decoder.evex_vector_length() == VECTOR_LENGTH_512
+            } else {
+                broadcast && decoder.evex_vector_length() == VECTOR_LENGTH_512
+            }
+        }
+        Form::RegisterWithB => mod_rm.is_register() && decoder.evex_b(),
+    };
+
+    if matches { Ok(Some((masking, mod_rm))) } else { Ok(None) }
+}
+
+/// Returns the size in bytes of a single (broadcast or scalar) element,
+/// selected by EVEX.W.
+#[inline(always)]
+const fn element_size(w: bool) -> u8 {
+    if w { 8 } else { 4 }
+}
+
+/// Decodes EVEX.512 instruction with reg, EVEX.vvvv and r/m ZMM operands. The `broadcast`
+/// tells whether the instruction supports embedded broadcast.
+/// This is the inverse of [`encode_zmm_zmm_zmm_or_memory`][crate::partial_encoders::evex::encode_zmm_zmm_zmm_or_memory].
+pub const fn decode_zmm_zmm_zmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    broadcast: bool,
+) -> Decoded<(ZMM, Masking, ZMM, ZMMOrMemory)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::Vector { broadcast })) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let zmm_or_memory = const_try!(decoder.read_zmm_or_memory(mod_rm, element_size(w)));
+    Ok(Some((
+        decoder.reg_zmm(mod_rm),
+        masking,
+        decoder.evex_zmm(),
+        zmm_or_memory,
+    )))
+}
+
+/// Decodes EVEX.512 instruction with reg, EVEX.vvvv and r/m ZMM register operands
+/// and static rounding.
+/// This is the inverse of [`encode_zmm_zmm_zmm_er`][crate::partial_encoders::evex::encode_zmm_zmm_zmm_er].
+pub const fn decode_zmm_zmm_zmm_er<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(ZMM, Masking, ZMM, ZMM, Rounding)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::RegisterWithB)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some((
+        decoder.reg_zmm(mod_rm),
+        masking,
+        decoder.evex_zmm(),
+        decoder.rm_zmm(mod_rm),
+        decoder.evex_rounding(),
+    )))
+}
+
+/// Decodes EVEX.512 instruction with reg, EVEX.vvvv and r/m ZMM register operands
+/// and suppressed floating-point exceptions.
+/// This is the inverse of [`encode_zmm_zmm_zmm_sae`][crate::partial_encoders::evex::encode_zmm_zmm_zmm_sae].
+pub const fn decode_zmm_zmm_zmm_sae<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(ZMM, Masking, ZMM, ZMM)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::RegisterWithB)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some((
+        decoder.reg_zmm(mod_rm),
+        masking,
+        decoder.evex_zmm(),
+        decoder.rm_zmm(mod_rm),
+    )))
+}
+
+/// Decodes EVEX.512 instruction with reg and r/m ZMM operands and unused EVEX.vvvv.
+/// The `broadcast` is exactly as in [`decode_zmm_zmm_zmm_or_memory`].
+/// This is the inverse of [`encode_zmm_zmm_or_memory`][crate::partial_encoders::evex::encode_zmm_zmm_or_memory].
+pub const fn decode_zmm_zmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    broadcast: bool,
+) -> Decoded<(ZMM, Masking, ZMMOrMemory)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::Vector { broadcast })) else {
+        return Ok(None);
+    };
+    if decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let zmm_or_memory = const_try!(decoder.read_zmm_or_memory(mod_rm, element_size(w)));
+    Ok(Some((decoder.reg_zmm(mod_rm), masking, zmm_or_memory)))
+}
+
+/// Decodes EVEX.512 store instruction with ZMM or memory r/m destination, reg source
+/// and unused EVEX.vvvv. Only merging-masking is allowed.
+/// This is the inverse of [`encode_zmm_zmm_or_memory`][crate::partial_encoders::evex::encode_zmm_zmm_or_memory]
+/// with the operands of the store form.
+pub const fn decode_zmm_or_memory_zmm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(ZMM, Masking, ZMMOrMemory)> {
+    let Some((masking, _)) = const_try!(matches_zmm(
+        decoder,
+        &opcode,
+        prefix,
+        w,
+        Form::Vector { broadcast: false }
+    )) else {
+        return Ok(None);
+    };
+    if masking.is_zeroing() {
+        return Ok(None);
+    }
+    decode_zmm_zmm_or_memory(decoder, opcode, prefix, w, false)
+}
+
+/// Decodes EVEX.512 instruction with reg and r/m ZMM register operands, unused
+/// EVEX.vvvv and static rounding.
+/// This is the inverse of [`encode_zmm_zmm_er`][crate::partial_encoders::evex::encode_zmm_zmm_er].
+pub const fn decode_zmm_zmm_er<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(ZMM, Masking, ZMM, Rounding)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::RegisterWithB)) else {
+        return Ok(None);
+    };
+    if decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some((
+        decoder.reg_zmm(mod_rm),
+        masking,
+        decoder.rm_zmm(mod_rm),
+        decoder.evex_rounding(),
+    )))
+}
+
+/// Decodes EVEX scalar instruction with reg, EVEX.vvvv and r/m XMM operands.
+/// The vector length is ignored.
+/// This is the inverse of [`encode_xmm_xmm_xmm_or_memory`][crate::partial_encoders::evex::encode_xmm_xmm_xmm_or_memory].
+pub const fn decode_xmm_xmm_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(XMM, Masking, XMM, XMMOrMemory)> {
+    if !const_try!(decoder.matches_evex(&opcode, prefix, w)) || decoder.evex_b() {
+        return Ok(None);
+    }
+    let Some(masking) = decoder.evex_masking() else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    decoder.set_disp8_scale(element_size(w));
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((
+        decoder.reg_xmm(mod_rm),
+        masking,
+        decoder.vex_xmm(),
+        xmm_or_memory,
+    )))
+}
+
+/// Decodes EVEX scalar instruction with reg, EVEX.vvvv and r/m XMM register operands
+/// and static rounding.
+/// This is the inverse of [`encode_xmm_xmm_xmm_er`][crate::partial_encoders::evex::encode_xmm_xmm_xmm_er].
+pub const fn decode_xmm_xmm_xmm_er<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(XMM, Masking, XMM, XMM, Rounding)> {
+    let Some((masking, _)) = const_try!(matches_zmm(decoder, &opcode, prefix, w, Form::RegisterWithB)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some((
+        decoder.reg_xmm(mod_rm),
+        masking,
+        decoder.vex_xmm(),
+        decoder.rm_xmm(mod_rm),
+        decoder.evex_rounding(),
+    )))
+}
+
+/// Decodes EVEX.512 gather with ZMM destination and VSIB memory with index of
+/// `index_size` size. The destination and the index have to be distinct,
+/// as required by the processor.
+/// This is the inverse of [`encode_zmm_vsib`][crate::partial_encoders::evex::encode_zmm_vsib].
+pub const fn decode_zmm_vsib<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Decoded<(ZMM, Masking, VSIBMemory)> {
+    let Some((masking, mod_rm, index)) = const_try!(matches_vsib(decoder, &opcode, prefix, w)) else {
+        return Ok(None);
+    };
+    let zmm = decoder.reg_zmm(mod_rm);
+    if zmm.index() == index {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    decoder.set_disp8_scale(element_size(w));
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((zmm, masking, vsib_memory)))
+}
+
+/// Decodes EVEX.512 scatter with VSIB memory destination, with index of
+/// `index_size` size, and ZMM source.
+/// This is the inverse of [`encode_zmm_vsib`][crate::partial_encoders::evex::encode_zmm_vsib]
+/// with the operands of the scatter form.
+pub const fn decode_vsib_zmm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Decoded<(ZMM, Masking, VSIBMemory)> {
+    let Some((masking, _, _)) = const_try!(matches_vsib(decoder, &opcode, prefix, w)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    decoder.set_disp8_scale(element_size(w));
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((decoder.reg_zmm(mod_rm), masking, vsib_memory)))
+}
+
+/// Decodes EVEX.512 gather with YMM destination and VSIB memory with ZMM index.
+/// The remaining arguments are exactly as in [`decode_zmm_vsib`].
+/// This is the inverse of [`encode_ymm_vsib`][crate::partial_encoders::evex::encode_ymm_vsib].
+pub const fn decode_ymm_vsib<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Decoded<(YMM, Masking, VSIBMemory)> {
+    let Some((masking, mod_rm, index)) = const_try!(matches_vsib(decoder, &opcode, prefix, w)) else {
+        return Ok(None);
+    };
+    let ymm = decoder.reg_ymm(mod_rm);
+    if ymm.index() == index {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    decoder.set_disp8_scale(element_size(w));
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((ymm, masking, vsib_memory)))
+}
+
+/// Decodes EVEX.512 scatter with VSIB memory destination, with ZMM index, and YMM source.
+/// This is the inverse of [`encode_ymm_vsib`][crate::partial_encoders::evex::encode_ymm_vsib]
+/// with the operands of the scatter form.
+pub const fn decode_vsib_ymm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Decoded<(YMM, Masking, VSIBMemory)> {
+    let Some((masking, _, _)) = const_try!(matches_vsib(decoder, &opcode, prefix, w)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    decoder.set_disp8_scale(element_size(w));
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((decoder.reg_ymm(mod_rm), masking, vsib_memory)))
+}
+
+/// Checks EVEX prefix, opcode, masking and the mod/rm byte of EVEX.512 gather or
+/// scatter. The masking has to be merge-masking with a mask other than K0, and
+/// EVEX.vvvv has to be unused. Nothing is consumed. Returns the masking, the mod/rm
+/// byte and the full index of the vector index register on success.
+const fn matches_vsib<const N: usize>(
+    decoder: &Decoder,
+    opcode: &[u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(Masking, ModRM, u8)> {
+    let Some((masking, mod_rm)) = const_try!(matches_zmm(
+        decoder,
+        opcode,
+        prefix,
+        w,
+        Form::Vector { broadcast: false }
+    )) else {
+        return Ok(None);
+    };
+    if !matches!(masking, Masking::Merge { .. }) || !Decoder::is_vsib(mod_rm) || decoder.vex_vvvv() & 0b1111 != 0 {
+        return Ok(None);
+    }
+
+    let index = const_try!(decoder.peek_vsib_index(2));
+    Ok(Some((masking, mod_rm, index)))
+}
+
+/// Checks the opmask destination and masking of EVEX.512 compare instruction.
+/// Returns the destination and the masking on success.
+const fn matches_k_zmm<const N: usize>(
+    decoder: &Decoder,
+    opcode: &[u8; N],
+    prefix: u8,
+    w: bool,
+    form: Form,
+) -> Decoded<(Opmask, Masking)> {
+    let Some((masking, mod_rm)) = const_try!(matches_zmm(decoder, opcode, prefix, w, form)) else {
+        return Ok(None);
+    };
+    if masking.is_zeroing() {
+        return Ok(None);
+    }
+
+    match decoder.reg_opmask(mod_rm) {
+        Some(k) => Ok(Some((k, masking))),
+        None => Ok(None),
+    }
+}
+
+/// Decodes EVEX.512 compare instruction with opmask reg operand, ZMM EVEX.vvvv operand
+/// and ZMM or memory r/m operand. The `broadcast` is exactly as in [`decode_zmm_zmm_zmm_or_memory`].
+/// This is the inverse of [`encode_k_zmm_zmm_or_memory`][crate::partial_encoders::evex::encode_k_zmm_zmm_or_memory].
+pub const fn decode_k_zmm_zmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    broadcast: bool,
+) -> Decoded<(Opmask, Masking, ZMM, ZMMOrMemory)> {
+    let Some((k, masking)) = const_try!(matches_k_zmm(decoder, &opcode, prefix, w, Form::Vector { broadcast })) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let zmm_or_memory = const_try!(decoder.read_zmm_or_memory(mod_rm, element_size(w)));
+    Ok(Some((k, masking, decoder.evex_zmm(), zmm_or_memory)))
+}
+
+/// Decodes EVEX.512 compare instruction with an 8-bit immediate predicate.
+/// This is the inverse of [`encode_k_zmm_zmm_or_memory_imm8`][crate::partial_encoders::evex::encode_k_zmm_zmm_or_memory_imm8].
+pub const fn decode_k_zmm_zmm_or_memory_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    broadcast: bool,
+) -> Decoded<(Opmask, Masking, ZMM, ZMMOrMemory, Immediate8)> {
+    let Some((k, masking, vvvv, zmm_or_memory)) =
+        const_try!(decode_k_zmm_zmm_or_memory(decoder, opcode, prefix, w, broadcast))
+    else {
+        return Ok(None);
+    };
+
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((k, masking, vvvv, zmm_or_memory, imm8)))
+}
+
+/// Decodes EVEX.512 compare instruction with register operands only, suppressed
+/// floating-point exceptions and an 8-bit immediate predicate.
+/// This is the inverse of [`encode_k_zmm_zmm_sae_imm8`][crate::partial_encoders::evex::encode_k_zmm_zmm_sae_imm8].
+pub const fn decode_k_zmm_zmm_sae_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Decoded<(Opmask, Masking, ZMM, ZMM, Immediate8)> {
+    let Some((k, masking)) = const_try!(matches_k_zmm(decoder, &opcode, prefix, w, Form::RegisterWithB)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((k, masking, decoder.evex_zmm(), decoder.rm_zmm(mod_rm), imm8)))
+}
@@ -0,0 +1,73 @@
+use crate::models::{DecodeError, Immediate8, Immediate16, Immediate32};
+
+use super::Decoder;
+
+/// Decodes I encoding with an 8-bit immediate value.
+#[inline]
+pub const fn decode_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+) -> Result<Option<Immediate8>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, false, 0)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(const_try!(decoder.read_imm8())))
+}
+
+/// Decodes I encoding with an 16-bit immediate value and operand size override.
+#[inline]
+pub const fn decode_imm16_oso<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+) -> Result<Option<Immediate16>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, true, false, 0)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(const_try!(decoder.read_imm16())))
+}
+
+/// Decodes I encoding with an 16-bit immediate value.
+#[inline]
+pub const fn decode_imm16<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+) -> Result<Option<Immediate16>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, false, 0)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(const_try!(decoder.read_imm16())))
+}
+
+/// Decodes I encoding with an 32-bit immediate value.
+#[inline]
+pub const fn decode_imm32<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+) -> Result<Option<Immediate32>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, false, 0)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(const_try!(decoder.read_imm32())))
+}
+
+/// Decodes I encoding with an 32-bit immediate value and REX.W prefix.
+#[inline]
+pub const fn decode_imm32_rexw<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+) -> Result<Option<Immediate32>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, true, 0)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(const_try!(decoder.read_imm32())))
+}
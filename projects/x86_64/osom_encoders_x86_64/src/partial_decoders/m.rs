@@ -0,0 +1,48 @@
+use crate::models::{DecodeError, GPROrMemory, Memory, Size};
+
+use super::Decoder;
+use super::core::{REX_B_BIT, REX_X_BIT};
+
+/// Decodes M encoding, i.e. a single register or memory operand
+/// with an extended opcode. This is the inverse of
+/// [`encode_gpr_or_memory`][crate::partial_encoders::m::encode_gpr_or_memory]
+/// and takes the same flags.
+pub const fn decode_gpr_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+    size: Size,
+    bit64_requires_rex_w: bool,
+    bit16_requires_oso_prefix: bool,
+) -> Result<Option<GPROrMemory>, DecodeError> {
+    let oso = bit16_requires_oso_prefix && size.equals(Size::Bit16);
+    let rex_w = bit64_requires_rex_w && size.equals(Size::Bit64);
+    if !const_try!(decoder.matches_extended(&opcode, extended_opcode, oso, rex_w, REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some(const_try!(decoder.read_gpr_or_memory(mod_rm, size))))
+}
+
+/// Decodes M encoding with memory-only operand. This is the inverse of
+/// [`encode_memory`][crate::partial_encoders::m::encode_memory].
+pub const fn decode_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+    requires_rex_w: bool,
+) -> Result<Option<Memory>, DecodeError> {
+    if !const_try!(decoder.matches_extended(&opcode, extended_opcode, false, requires_rex_w, REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    if const_try!(decoder.peek_mod_rm(N)).is_register() {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some(const_try!(decoder.read_memory(mod_rm))))
+}
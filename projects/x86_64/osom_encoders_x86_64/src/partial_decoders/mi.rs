@@ -0,0 +1,144 @@
+use crate::models::{DecodeError, GPROrMemory, Immediate8, Immediate16, Immediate32, Size};
+
+use super::Decoder;
+use super::m::decode_gpr_or_memory;
+
+/// Decodes MI encoding with 8-bit register or memory and 8-bit immediate.
+pub const fn decode_rm8_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate8)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit8,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr_or_memory, imm8)))
+}
+
+/// Decodes MI encoding with 16-bit register or memory and 16-bit immediate.
+pub const fn decode_rm16_imm16<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate16)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit16,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm16 = const_try!(decoder.read_imm16());
+    Ok(Some((gpr_or_memory, imm16)))
+}
+
+/// Decodes MI encoding with 16-bit register or memory and 8-bit immediate.
+pub const fn decode_rm16_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate8)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit16,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr_or_memory, imm8)))
+}
+
+/// Decodes MI encoding with 32-bit register or memory and 32-bit immediate.
+pub const fn decode_rm32_imm32<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate32)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit32,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm32 = const_try!(decoder.read_imm32());
+    Ok(Some((gpr_or_memory, imm32)))
+}
+
+/// Decodes MI encoding with 32-bit register or memory and 8-bit immediate.
+pub const fn decode_rm32_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate8)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit32,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr_or_memory, imm8)))
+}
+
+/// Decodes MI encoding with 64-bit register or memory and 32-bit immediate.
+pub const fn decode_rm64_imm32<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate32)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit64,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm32 = const_try!(decoder.read_imm32());
+    Ok(Some((gpr_or_memory, imm32)))
+}
+
+/// Decodes MI encoding with 64-bit register or memory and 8-bit immediate.
+pub const fn decode_rm64_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+) -> Result<Option<(GPROrMemory, Immediate8)>, DecodeError> {
+    let Some(gpr_or_memory) = const_try!(decode_gpr_or_memory(
+        decoder,
+        opcode,
+        extended_opcode,
+        Size::Bit64,
+        true,
+        true
+    )) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr_or_memory, imm8)))
+}
@@ -0,0 +1,27 @@
+/// The `?` operator for `const fn`s, which cannot use it yet.
+macro_rules! const_try {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+pub(crate) use const_try;
+
+mod core;
+
+pub use core::Decoder;
+
+pub mod evex;
+pub mod i;
+pub mod m;
+pub mod mi;
+pub mod mr;
+pub mod o;
+pub mod oi;
+pub mod rmi;
+pub mod vex;
+pub mod xmm;
+pub mod zo;
@@ -0,0 +1,67 @@
+use crate::models::{DecodeError, GPR, GPROrMemory, Memory, Size};
+
+use super::Decoder;
+use super::core::{REX_B_BIT, REX_R_BIT, REX_X_BIT};
+
+/// Decodes MR encoding, where both operands have `size` size.
+/// This is the inverse of [`encode`][crate::partial_encoders::mr::encode].
+#[inline]
+pub const fn decode<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<(GPROrMemory, GPR)>, DecodeError> {
+    decode_mixed(decoder, opcode, size, size)
+}
+
+/// Decodes MR encoding, where the register operand has `size` size and
+/// the register or memory operand has `rm_size` size, e.g. `movzx eax, bl`.
+/// This is the inverse of [`encode`][crate::partial_encoders::mr::encode].
+pub const fn decode_mixed<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+    rm_size: Size,
+) -> Result<Option<(GPROrMemory, GPR)>, DecodeError> {
+    if !const_try!(decoder.matches(
+        &opcode,
+        size.equals(Size::Bit16),
+        size.equals(Size::Bit64),
+        REX_R_BIT | REX_X_BIT | REX_B_BIT
+    )) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let gpr_or_memory = const_try!(decoder.read_gpr_or_memory(mod_rm, rm_size));
+    let gpr = decoder.reg_gpr(mod_rm, size);
+    Ok(Some((gpr_or_memory, gpr)))
+}
+
+/// Decodes MR encoding with memory only operand, where the register operand
+/// has `size` size. This is the inverse of [`encode_mem`][crate::partial_encoders::mr::encode_mem].
+pub const fn decode_mem<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<(Memory, GPR)>, DecodeError> {
+    if !const_try!(decoder.matches(
+        &opcode,
+        size.equals(Size::Bit16),
+        size.equals(Size::Bit64),
+        REX_R_BIT | REX_X_BIT | REX_B_BIT
+    )) {
+        return Ok(None);
+    }
+
+    if const_try!(decoder.peek_mod_rm(N)).is_register() {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let memory = const_try!(decoder.read_memory(mod_rm));
+    let gpr = decoder.reg_gpr(mod_rm, size);
+    Ok(Some((memory, gpr)))
+}
@@ -0,0 +1,40 @@
+use crate::models::{DecodeError, GPR, Size};
+
+use super::Decoder;
+use super::core::REX_B_BIT;
+
+/// Decodes O encoding, i.e. a register encoded in the last byte of the opcode.
+/// This is the inverse of [`encode`][crate::partial_encoders::o::encode].
+#[inline]
+pub const fn decode<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<GPR>, DecodeError> {
+    decode_with_rex_w(decoder, opcode, size, false)
+}
+
+/// Like [`decode`], but with REX.W prefix. This is the inverse
+/// of [`encode_rexw`][crate::partial_encoders::o::encode_rexw].
+#[inline]
+pub const fn decode_rexw<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<GPR>, DecodeError> {
+    decode_with_rex_w(decoder, opcode, size, true)
+}
+
+const fn decode_with_rex_w<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+    rex_w: bool,
+) -> Result<Option<GPR>, DecodeError> {
+    if !const_try!(decoder.matches_register_in_opcode(&opcode, size.equals(Size::Bit16), rex_w, REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    Ok(Some(decoder.opcode_gpr(size)))
+}
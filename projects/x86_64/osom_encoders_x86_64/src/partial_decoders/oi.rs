@@ -0,0 +1,52 @@
+use crate::models::{DecodeError, GPR, Immediate8, Immediate16, Immediate32, Immediate64, Size};
+
+use super::Decoder;
+use super::core::REX_B_BIT;
+
+/// Decodes OI encoding with 8-bit register and immediate.
+pub const fn decode_reg8_imm8(decoder: &mut Decoder, opcode: u8) -> Result<Option<(GPR, Immediate8)>, DecodeError> {
+    if !const_try!(decoder.matches_register_in_opcode(&[opcode], false, false, REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let gpr = decoder.opcode_gpr(Size::Bit8);
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr, imm8)))
+}
+
+/// Decodes OI encoding with 16-bit register and immediate.
+pub const fn decode_reg16_imm16(decoder: &mut Decoder, opcode: u8) -> Result<Option<(GPR, Immediate16)>, DecodeError> {
+    if !const_try!(decoder.matches_register_in_opcode(&[opcode], true, false, REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let gpr = decoder.opcode_gpr(Size::Bit16);
+    let imm16 = const_try!(decoder.read_imm16());
+    Ok(Some((gpr, imm16)))
+}
+
+/// Decodes OI encoding with 32-bit register and immediate.
+pub const fn decode_reg32_imm32(decoder: &mut Decoder, opcode: u8) -> Result<Option<(GPR, Immediate32)>, DecodeError> {
+    if !const_try!(decoder.matches_register_in_opcode(&[opcode], false, false, REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let gpr = decoder.opcode_gpr(Size::Bit32);
+    let imm32 = const_try!(decoder.read_imm32());
+    Ok(Some((gpr, imm32)))
+}
+
+/// Decodes OI encoding with 64-bit register and immediate.
+pub const fn decode_reg64_imm64(decoder: &mut Decoder, opcode: u8) -> Result<Option<(GPR, Immediate64)>, DecodeError> {
+    if !const_try!(decoder.matches_register_in_opcode(&[opcode], false, true, REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let gpr = decoder.opcode_gpr(Size::Bit64);
+    let imm64 = const_try!(decoder.read_imm64());
+    Ok(Some((gpr, imm64)))
+}
@@ -0,0 +1,45 @@
+use crate::models::{DecodeError, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32, Size};
+
+use super::Decoder;
+
+/// Decodes RMI encoding with an 8-bit immediate, where the register operand
+/// has `size` size. This is the inverse of [`encode_imm8`][crate::partial_encoders::rmi::encode_imm8].
+pub const fn decode_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<(GPR, GPROrMemory, Immediate8)>, DecodeError> {
+    let Some((gpr_or_memory, gpr)) = const_try!(super::mr::decode(decoder, opcode, size)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr, gpr_or_memory, imm8)))
+}
+
+/// Decodes RMI encoding with a 16-bit immediate, where the register operand
+/// has `size` size. This is the inverse of [`encode_imm16`][crate::partial_encoders::rmi::encode_imm16].
+pub const fn decode_imm16<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<(GPR, GPROrMemory, Immediate16)>, DecodeError> {
+    let Some((gpr_or_memory, gpr)) = const_try!(super::mr::decode(decoder, opcode, size)) else {
+        return Ok(None);
+    };
+    let imm16 = const_try!(decoder.read_imm16());
+    Ok(Some((gpr, gpr_or_memory, imm16)))
+}
+
+/// Decodes RMI encoding with a 32-bit immediate, where the register operand
+/// has `size` size. This is the inverse of [`encode_imm32`][crate::partial_encoders::rmi::encode_imm32].
+pub const fn decode_imm32<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    size: Size,
+) -> Result<Option<(GPR, GPROrMemory, Immediate32)>, DecodeError> {
+    let Some((gpr_or_memory, gpr)) = const_try!(super::mr::decode(decoder, opcode, size)) else {
+        return Ok(None);
+    };
+    let imm32 = const_try!(decoder.read_imm32());
+    Ok(Some((gpr, gpr_or_memory, imm32)))
+}
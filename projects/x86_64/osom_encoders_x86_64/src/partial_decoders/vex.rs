@@ -0,0 +1,440 @@
+use crate::models::{
+    DecodeError, GPR, GPROrMemory, Immediate8, Memory, Opmask, OpmaskOrMemory, Size, VSIBMemory, XMM, XMMOrMemory, YMM,
+    YMMOrMemory,
+};
+
+use super::Decoder;
+
+/// Decodes RVM (and RMV) encoding, where all operands have `size` size.
+/// Returns the reg, VEX.vvvv and r/m operands, in this order.
+/// This is the inverse of [`encode_rvm`][crate::partial_encoders::vex::encode_rvm].
+pub const fn decode_rvm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    size: Size,
+) -> Result<Option<(GPR, GPR, GPROrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, false)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let gpr_or_memory = const_try!(decoder.read_gpr_or_memory(mod_rm, size));
+    let gpr = decoder.reg_gpr(mod_rm, size);
+    let vvvv = decoder.vex_gpr(size);
+    Ok(Some((gpr, vvvv, gpr_or_memory)))
+}
+
+/// Decodes VM encoding, where all operands have `size` size.
+/// This is the inverse of [`encode_vm`][crate::partial_encoders::vex::encode_vm].
+pub const fn decode_vm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    extended_opcode: u8,
+    prefix: u8,
+    w: bool,
+    size: Size,
+) -> Result<Option<(GPR, GPROrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, false)) || decoder.has_rex_r() {
+        return Ok(None);
+    }
+
+    if const_try!(decoder.peek_mod_rm(1)).reg != extended_opcode {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let gpr_or_memory = const_try!(decoder.read_gpr_or_memory(mod_rm, size));
+    let vvvv = decoder.vex_gpr(size);
+    Ok(Some((vvvv, gpr_or_memory)))
+}
+
+/// Decodes RMI encoding with an 8-bit immediate and unused VEX.vvvv,
+/// where the register operands have `size` size.
+/// This is the inverse of [`encode_rmi`][crate::partial_encoders::vex::encode_rmi].
+pub const fn decode_rmi<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    size: Size,
+) -> Result<Option<(GPR, GPROrMemory, Immediate8)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, false)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let gpr_or_memory = const_try!(decoder.read_gpr_or_memory(mod_rm, size));
+    let gpr = decoder.reg_gpr(mod_rm, size);
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr, gpr_or_memory, imm8)))
+}
+
+/// Decodes VEX.128 instruction with reg, VEX.vvvv and r/m XMM operands.
+/// This is the inverse of [`encode_xmm_xmm_xmm_or_memory`][crate::partial_encoders::vex::encode_xmm_xmm_xmm_or_memory].
+pub const fn decode_xmm_xmm_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(XMM, XMM, XMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, false)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_xmm(mod_rm), decoder.vex_xmm(), xmm_or_memory)))
+}
+
+/// Decodes VEX.256 instruction with reg, VEX.vvvv and r/m YMM operands.
+/// This is the inverse of [`encode_ymm_ymm_ymm_or_memory`][crate::partial_encoders::vex::encode_ymm_ymm_ymm_or_memory].
+pub const fn decode_ymm_ymm_ymm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(YMM, YMM, YMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, true)) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let ymm_or_memory = const_try!(decoder.read_ymm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_ymm(mod_rm), decoder.vex_ymm(), ymm_or_memory)))
+}
+
+/// Decodes VEX.128 instruction with reg and r/m XMM operands and unused VEX.vvvv.
+/// This is the inverse of [`encode_xmm_xmm_or_memory`][crate::partial_encoders::vex::encode_xmm_xmm_or_memory].
+pub const fn decode_xmm_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(XMM, XMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, false)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_xmm(mod_rm), xmm_or_memory)))
+}
+
+/// Decodes VEX.256 instruction with reg and r/m YMM operands and unused VEX.vvvv.
+/// This is the inverse of [`encode_ymm_ymm_or_memory`][crate::partial_encoders::vex::encode_ymm_ymm_or_memory].
+pub const fn decode_ymm_ymm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(YMM, YMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, true)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let ymm_or_memory = const_try!(decoder.read_ymm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_ymm(mod_rm), ymm_or_memory)))
+}
+
+/// Decodes VEX.256 instruction with YMM reg operand, XMM or memory r/m operand
+/// and unused VEX.vvvv.
+/// This is the inverse of [`encode_ymm_xmm_or_memory`][crate::partial_encoders::vex::encode_ymm_xmm_or_memory].
+pub const fn decode_ymm_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(YMM, XMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, true)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_ymm(mod_rm), xmm_or_memory)))
+}
+
+/// Decodes VEX.128 instruction with reg and r/m XMM operands, unused VEX.vvvv
+/// and an 8-bit immediate.
+/// This is the inverse of [`encode_xmm_xmm_or_memory_imm8`][crate::partial_encoders::vex::encode_xmm_xmm_or_memory_imm8].
+pub const fn decode_xmm_xmm_or_memory_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(XMM, XMMOrMemory, Immediate8)>, DecodeError> {
+    let Some((xmm, xmm_or_memory)) = const_try!(decode_xmm_xmm_or_memory(decoder, opcode, prefix, w)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((xmm, xmm_or_memory, imm8)))
+}
+
+/// Decodes VEX.256 instruction with YMM reg operand, XMM or memory r/m operand,
+/// unused VEX.vvvv and an 8-bit immediate.
+/// This is the inverse of [`encode_ymm_xmm_or_memory_imm8`][crate::partial_encoders::vex::encode_ymm_xmm_or_memory_imm8].
+pub const fn decode_ymm_xmm_or_memory_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+) -> Result<Option<(YMM, XMMOrMemory, Immediate8)>, DecodeError> {
+    let Some((ymm, xmm_or_memory)) = const_try!(decode_ymm_xmm_or_memory(decoder, opcode, prefix, w)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((ymm, xmm_or_memory, imm8)))
+}
+
+/// Decodes VEX gather with XMM destination, XMM mask in VEX.vvvv and VSIB memory
+/// with index of `index_size` size, which selects VEX.L. The registers have
+/// to be distinct, as required by the processor.
+/// This is the inverse of [`encode_xmm_xmm_vsib`][crate::partial_encoders::vex::encode_xmm_xmm_vsib].
+pub const fn decode_xmm_xmm_vsib<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Result<Option<(XMM, XMM, VSIBMemory)>, DecodeError> {
+    let l = index_size.equals(Size::Bit256);
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    if !Decoder::is_vsib(mod_rm) {
+        return Ok(None);
+    }
+    let xmm = decoder.reg_xmm(mod_rm);
+    let vvvv = decoder.vex_xmm();
+    if !are_gather_registers_distinct(xmm.index(), vvvv.index(), const_try!(decoder.peek_vsib_index(2))) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((xmm, vvvv, vsib_memory)))
+}
+
+/// Decodes VEX.256 gather with YMM destination, YMM mask in VEX.vvvv and VSIB memory
+/// with index of `index_size` size. The registers have to be distinct, as required
+/// by the processor.
+/// This is the inverse of [`encode_ymm_ymm_vsib`][crate::partial_encoders::vex::encode_ymm_ymm_vsib].
+pub const fn decode_ymm_ymm_vsib<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    index_size: Size,
+) -> Result<Option<(YMM, YMM, VSIBMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, true)) {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    if !Decoder::is_vsib(mod_rm) {
+        return Ok(None);
+    }
+    let ymm = decoder.reg_ymm(mod_rm);
+    let vvvv = decoder.vex_ymm();
+    if !are_gather_registers_distinct(ymm.index(), vvvv.index(), const_try!(decoder.peek_vsib_index(2))) {
+        return Ok(None);
+    }
+
+    decoder.consume(1);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let vsib_memory = const_try!(decoder.read_vsib_memory(mod_rm, index_size));
+    Ok(Some((ymm, vvvv, vsib_memory)))
+}
+
+#[inline(always)]
+const fn are_gather_registers_distinct(destination: u8, mask: u8, index: u8) -> bool {
+    destination != mask && destination != index && mask != index
+}
+
+/// Decodes VEX instruction with reg, VEX.vvvv and r/m opmask operands.
+/// This is the inverse of [`encode_k_k_k`][crate::partial_encoders::vex::encode_k_k_k].
+pub const fn decode_k_k_k<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(Opmask, Opmask, Opmask)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() >= 8 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let (Some(k), true, Some(k_rm)) = (
+        decoder.reg_opmask(mod_rm),
+        mod_rm.is_register(),
+        decoder.rm_opmask(mod_rm),
+    ) else {
+        return Ok(None);
+    };
+
+    decoder.consume(2);
+    Ok(Some((k, decoder.vex_opmask(), k_rm)))
+}
+
+/// Decodes VEX instruction with reg and r/m opmask operands and unused VEX.vvvv.
+/// This is the inverse of [`encode_k_k`][crate::partial_encoders::vex::encode_k_k].
+pub const fn decode_k_k<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(Opmask, Opmask)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let (Some(k), true, Some(k_rm)) = (
+        decoder.reg_opmask(mod_rm),
+        mod_rm.is_register(),
+        decoder.rm_opmask(mod_rm),
+    ) else {
+        return Ok(None);
+    };
+
+    decoder.consume(2);
+    Ok(Some((k, k_rm)))
+}
+
+/// Decodes VEX instruction with opmask reg operand, opmask or memory r/m operand
+/// and unused VEX.vvvv.
+/// This is the inverse of [`encode_k_k_or_memory`][crate::partial_encoders::vex::encode_k_k_or_memory].
+pub const fn decode_k_k_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(Opmask, OpmaskOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let Some(k) = decoder.reg_opmask(mod_rm) else {
+        return Ok(None);
+    };
+    if mod_rm.is_register() && decoder.rm_opmask(mod_rm).is_none() {
+        return Ok(None);
+    }
+
+    decoder.consume(2);
+    let Some(k_or_memory) = const_try!(decoder.read_opmask_or_memory(mod_rm)) else {
+        return Ok(None);
+    };
+    Ok(Some((k, k_or_memory)))
+}
+
+/// Decodes VEX instruction with opmask reg operand, memory r/m operand and unused VEX.vvvv.
+/// This is the inverse of [`encode_k_memory`][crate::partial_encoders::vex::encode_k_memory].
+pub const fn decode_k_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(Opmask, Memory)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let Some(k) = decoder.reg_opmask(mod_rm) else {
+        return Ok(None);
+    };
+    if mod_rm.is_register() {
+        return Ok(None);
+    }
+
+    decoder.consume(2);
+    let memory = const_try!(decoder.read_memory(mod_rm));
+    Ok(Some((k, memory)))
+}
+
+/// Decodes VEX instruction with opmask reg operand, 32-bit GPR r/m operand and unused VEX.vvvv.
+/// This is the inverse of [`encode_k_gpr`][crate::partial_encoders::vex::encode_k_gpr].
+pub const fn decode_k_gpr<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(Opmask, GPR)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let Some(k) = decoder.reg_opmask(mod_rm) else {
+        return Ok(None);
+    };
+    if !mod_rm.is_register() {
+        return Ok(None);
+    }
+
+    decoder.consume(2);
+    let GPROrMemory::GPR { gpr } = const_try!(decoder.read_gpr_or_memory(mod_rm, Size::Bit32)) else {
+        return Ok(None);
+    };
+    Ok(Some((k, gpr)))
+}
+
+/// Decodes VEX instruction with 32-bit GPR reg operand, opmask r/m operand and unused VEX.vvvv.
+/// This is the inverse of [`encode_gpr_k`][crate::partial_encoders::vex::encode_gpr_k].
+pub const fn decode_gpr_k<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<Option<(GPR, Opmask)>, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 {
+        return Ok(None);
+    }
+
+    let mod_rm = const_try!(decoder.peek_mod_rm(1));
+    let (true, Some(k)) = (mod_rm.is_register(), decoder.rm_opmask(mod_rm)) else {
+        return Ok(None);
+    };
+
+    decoder.consume(2);
+    Ok(Some((decoder.reg_gpr(mod_rm, Size::Bit32), k)))
+}
+
+/// Decodes VEX instruction without operands. Returns `true` if `opcode` matches.
+/// This is the inverse of [`encode_zo`][crate::partial_encoders::vex::encode_zo].
+pub const fn decode_zo<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> Result<bool, DecodeError> {
+    if !const_try!(decoder.matches_vex(&opcode, prefix, w, l)) || decoder.vex_vvvv() != 0 || decoder.has_rex_r() {
+        return Ok(false);
+    }
+
+    decoder.consume(1);
+    Ok(true)
+}
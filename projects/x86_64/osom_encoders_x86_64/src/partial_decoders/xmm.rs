@@ -0,0 +1,128 @@
+use crate::models::{DecodeError, GPR, GPROrMemory, Immediate8, Size, XMM, XMMOrMemory};
+
+use super::Decoder;
+use super::core::{REX_B_BIT, REX_R_BIT, REX_X_BIT};
+
+/// Decodes SSE instruction with XMM in the reg field of mod/rm and
+/// XMM or memory in the r/m field. Returns them in this order. This is the inverse of
+/// [`encode_xmm_xmm_or_memory`][crate::partial_encoders::xmm::encode_xmm_xmm_or_memory].
+pub const fn decode_xmm_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+) -> Result<Option<(XMM, XMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, rex_w, REX_R_BIT | REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_xmm(mod_rm), xmm_or_memory)))
+}
+
+/// Decodes SSE instruction with XMM in the reg field of mod/rm and
+/// `size` GPR or memory in the r/m field. Returns them in this order. This is the inverse of
+/// [`encode_xmm_gpr_or_memory`][crate::partial_encoders::xmm::encode_xmm_gpr_or_memory].
+pub const fn decode_xmm_gpr_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+    size: Size,
+) -> Result<Option<(XMM, GPROrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, rex_w, REX_R_BIT | REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let gpr_or_memory = const_try!(decoder.read_gpr_or_memory(mod_rm, size));
+    Ok(Some((decoder.reg_xmm(mod_rm), gpr_or_memory)))
+}
+
+/// Decodes SSE instruction with `size` GPR in the reg field of mod/rm and
+/// XMM or memory in the r/m field. Returns them in this order. This is the inverse of
+/// [`encode_gpr_xmm_or_memory`][crate::partial_encoders::xmm::encode_gpr_xmm_or_memory].
+pub const fn decode_gpr_xmm_or_memory<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+    size: Size,
+) -> Result<Option<(GPR, XMMOrMemory)>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, rex_w, REX_R_BIT | REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    let xmm_or_memory = const_try!(decoder.read_xmm_or_memory(mod_rm));
+    Ok(Some((decoder.reg_gpr(mod_rm, size), xmm_or_memory)))
+}
+
+/// Decodes SSE instruction with `size` GPR in the reg field of mod/rm and
+/// XMM in the r/m field. Returns them in this order. This is the inverse of
+/// [`encode_gpr_xmm`][crate::partial_encoders::xmm::encode_gpr_xmm].
+pub const fn decode_gpr_xmm<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+    size: Size,
+) -> Result<Option<(GPR, XMM)>, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, rex_w, REX_R_BIT | REX_X_BIT | REX_B_BIT)) {
+        return Ok(None);
+    }
+    if !const_try!(decoder.peek_mod_rm(N)).is_register() {
+        return Ok(None);
+    }
+
+    decoder.consume(N);
+    let mod_rm = const_try!(decoder.read_mod_rm());
+    Ok(Some((decoder.reg_gpr(mod_rm, size), decoder.rm_xmm(mod_rm))))
+}
+
+/// Decodes SSE instruction exactly as [`decode_xmm_xmm_or_memory`] followed by
+/// an 8-bit immediate. This is the inverse of
+/// [`encode_xmm_xmm_or_memory_imm8`][crate::partial_encoders::xmm::encode_xmm_xmm_or_memory_imm8].
+pub const fn decode_xmm_xmm_or_memory_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+) -> Result<Option<(XMM, XMMOrMemory, Immediate8)>, DecodeError> {
+    let Some((xmm, xmm_or_memory)) = const_try!(decode_xmm_xmm_or_memory(decoder, opcode, rex_w)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((xmm, xmm_or_memory, imm8)))
+}
+
+/// Decodes SSE instruction exactly as [`decode_xmm_gpr_or_memory`] followed by
+/// an 8-bit immediate. This is the inverse of
+/// [`encode_xmm_gpr_or_memory_imm8`][crate::partial_encoders::xmm::encode_xmm_gpr_or_memory_imm8].
+pub const fn decode_xmm_gpr_or_memory_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+    size: Size,
+) -> Result<Option<(XMM, GPROrMemory, Immediate8)>, DecodeError> {
+    let Some((xmm, gpr_or_memory)) = const_try!(decode_xmm_gpr_or_memory(decoder, opcode, rex_w, size)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((xmm, gpr_or_memory, imm8)))
+}
+
+/// Decodes SSE instruction exactly as [`decode_gpr_xmm`] followed by
+/// an 8-bit immediate. This is the inverse of
+/// [`encode_gpr_xmm_imm8`][crate::partial_encoders::xmm::encode_gpr_xmm_imm8].
+pub const fn decode_gpr_xmm_imm8<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    rex_w: bool,
+    size: Size,
+) -> Result<Option<(GPR, XMM, Immediate8)>, DecodeError> {
+    let Some((gpr, xmm)) = const_try!(decode_gpr_xmm(decoder, opcode, rex_w, size)) else {
+        return Ok(None);
+    };
+    let imm8 = const_try!(decoder.read_imm8());
+    Ok(Some((gpr, xmm, imm8)))
+}
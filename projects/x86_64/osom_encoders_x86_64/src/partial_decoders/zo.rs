@@ -0,0 +1,32 @@
+use crate::models::DecodeError;
+
+use super::Decoder;
+
+/// Decodes a zero operand instruction. Returns `true` if `opcode` matches.
+#[inline]
+pub const fn decode<const N: usize>(decoder: &mut Decoder, opcode: [u8; N]) -> Result<bool, DecodeError> {
+    if !const_try!(decoder.matches(&opcode, false, false, 0)) {
+        return Ok(false);
+    }
+
+    decoder.consume(N);
+    Ok(true)
+}
+
+/// Decodes a zero operand instruction with the legacy `prefix` (or 0 if there
+/// is none) and the operand size prefixes. Returns `true` if all of them match.
+#[inline]
+pub const fn decode_with_prefixes<const N: usize>(
+    decoder: &mut Decoder,
+    opcode: [u8; N],
+    prefix: u8,
+    oso: bool,
+    rex_w: bool,
+) -> Result<bool, DecodeError> {
+    if !const_try!(decoder.matches_with_prefix(&opcode, prefix, oso, rex_w, 0)) {
+        return Ok(false);
+    }
+
+    decoder.consume(N);
+    Ok(true)
+}
@@ -5,7 +5,7 @@ use _osom_encoders_common::fixed_bytes::FixedBytes;
 #[allow(unused_imports)]
 use crate::models::{Memory, Size};
 
-use crate::models::{GPR, Offset, Scale};
+use crate::models::{EncodedX86_64Instruction, GPR, Offset, Scale, VSIBMemory};
 
 pub const OPERAND_SIZE_OVERRIDE_PREFIX: NonZero<u8> = const {
     match NonZero::new(0x66) {
@@ -14,6 +14,27 @@ pub const OPERAND_SIZE_OVERRIDE_PREFIX: NonZero<u8> = const {
     }
 };
 
+pub const LOCK_PREFIX: NonZero<u8> = const {
+    match NonZero::new(0xF0) {
+        Some(value) => value,
+        None => panic!("LOCK_PREFIX is not a valid NonZero<u8>"),
+    }
+};
+
+pub const REP_PREFIX: NonZero<u8> = const {
+    match NonZero::new(0xF3) {
+        Some(value) => value,
+        None => panic!("REP_PREFIX is not a valid NonZero<u8>"),
+    }
+};
+
+pub const REPNE_PREFIX: NonZero<u8> = const {
+    match NonZero::new(0xF2) {
+        Some(value) => value,
+        None => panic!("REPNE_PREFIX is not a valid NonZero<u8>"),
+    }
+};
+
 pub const REX: NonZero<u8> = rex(0, 0, 0, 0);
 pub const REX_W: NonZero<u8> = rex(1, 0, 0, 0);
 pub const REX_R: NonZero<u8> = rex(0, 1, 0, 0);
@@ -30,6 +51,16 @@ pub const fn rex(w: u8, r: u8, x: u8, b: u8) -> NonZero<u8> {
     unsafe { NonZero::new_unchecked(0b0100_0000 | (w << 3) | (r << 2) | (x << 1) | b) }
 }
 
+/// Prepends LOCK prefix to `instruction`. It does not check whether
+/// the instruction actually accepts the prefix.
+#[inline]
+pub const fn with_lock_prefix(instruction: &EncodedX86_64Instruction) -> EncodedX86_64Instruction {
+    let mut result_instr = EncodedX86_64Instruction::new();
+    result_instr.push_array([LOCK_PREFIX.get()]);
+    result_instr.push_slice(instruction.as_slice());
+    result_instr
+}
+
 #[inline(always)]
 #[must_use]
 pub const fn mod_rm(mod_field: u8, reg_field: u8, rm_field: u8) -> u8 {
@@ -52,19 +83,39 @@ pub const fn sib(scale: u8, index: u8, base: u8) -> u8 {
 
 pub type MemoryBuffer = FixedBytes<7>;
 
-pub const fn encode_memory(reg_field: u8, memory: Memory) -> MemoryBuffer {
+/// Encodes the `ModRM` byte together with optional SIB byte and displacement.
+///
+/// The `disp8_scale` is the `N` of EVEX compressed displacement (disp8*N), i.e. the
+/// disp8 byte is multiplied by it. Legacy and VEX encodings pass `1`, which keeps
+/// the displacement exactly as requested. Any other value picks the shortest
+/// displacement: disp8 when the offset is a multiple of `N` that fits, disp32 otherwise.
+pub const fn encode_memory(reg_field: u8, memory: Memory, disp8_scale: u8) -> MemoryBuffer {
     debug_assert!(reg_field < 8);
+    debug_assert!(disp8_scale.is_power_of_two());
     memory_contains_bit64_registers(memory);
 
     match memory {
-        Memory::Based { base, offset } => encode_based_memory(reg_field, base, offset),
-        Memory::Scaled { index, scale, offset } => encode_scaled_memory(reg_field, index, scale, offset),
+        Memory::Based { base, offset } => encode_based_memory(reg_field, base, compress_offset(offset, disp8_scale)),
+        Memory::Scaled { index, scale, offset } => {
+            // Note that `index == RSP` is not allowed in SIB.
+            debug_assert!(!index.equals(GPR::RSP));
+            encode_scaled_memory(reg_field, index.lower_3_bits_index(), scale, offset)
+        }
         Memory::BasedAndScaled {
             base,
             index,
             scale,
             offset,
-        } => encode_based_scaled_memory(reg_field, base, index, scale, offset),
+        } => {
+            debug_assert!(!index.equals(GPR::RSP));
+            encode_based_scaled_memory(
+                reg_field,
+                base,
+                index.lower_3_bits_index(),
+                scale,
+                compress_offset(offset, disp8_scale),
+            )
+        }
         Memory::RelativeToRIP { offset } => {
             let mut buffer = MemoryBuffer::new();
             buffer.push_array([mod_rm(0b00, reg_field, 0b101)]);
@@ -78,6 +129,58 @@ pub const fn encode_memory(reg_field: u8, memory: Memory) -> MemoryBuffer {
     }
 }
 
+/// Encodes the `ModRM` byte together with SIB byte and displacement of a VSIB
+/// memory operand, i.e. of gathers and scatters. The mod/disp selection is
+/// exactly as in [`encode_memory`], except that the SIB index is a vector
+/// register, and so the `0b100` index is not special.
+///
+/// Only the lower 3 bits of the index are encoded here, the higher
+/// ones go into the VEX/EVEX prefix.
+#[inline]
+pub const fn encode_vsib_memory(reg_field: u8, memory: VSIBMemory, disp8_scale: u8) -> MemoryBuffer {
+    debug_assert!(reg_field < 8);
+    debug_assert!(disp8_scale.is_power_of_two());
+
+    let index_field = memory.index().index() & 0b111;
+    match memory {
+        VSIBMemory::Scaled { scale, offset, .. } => encode_scaled_memory(reg_field, index_field, scale, offset),
+        VSIBMemory::BasedAndScaled {
+            base, scale, offset, ..
+        } => {
+            debug_assert!(base.size().equals(Size::Bit64), "Memory contains non-64-bit registers");
+            encode_based_scaled_memory(
+                reg_field,
+                base,
+                index_field,
+                scale,
+                compress_offset(offset, disp8_scale),
+            )
+        }
+    }
+}
+
+/// Converts the `offset` to the EVEX compressed displacement form. The resulting
+/// [`Offset::Bit8`] holds the already scaled down value.
+const fn compress_offset(offset: Offset, disp8_scale: u8) -> Offset {
+    if disp8_scale == 1 {
+        return offset;
+    }
+
+    let value = offset.to_sign_extended_imm32().as_i32();
+    if value == 0 {
+        return Offset::None;
+    }
+
+    let scale = disp8_scale as i32;
+    let compressed = value / scale;
+    if value % scale == 0 && compressed >= i8::MIN as i32 && compressed <= i8::MAX as i32 {
+        #[allow(clippy::cast_possible_truncation)]
+        Offset::from_i8(compressed as i8)
+    } else {
+        Offset::from_i32(value)
+    }
+}
+
 const fn encode_based_memory(reg_field: u8, base: GPR, offset: Offset) -> MemoryBuffer {
     let mut buffer = MemoryBuffer::new();
 
@@ -129,21 +232,15 @@ const fn encode_based_memory(reg_field: u8, base: GPR, offset: Offset) -> Memory
     buffer
 }
 
-const fn encode_scaled_memory(reg_field: u8, index: GPR, scale: Scale, offset: Offset) -> MemoryBuffer {
+const fn encode_scaled_memory(reg_field: u8, index_field: u8, scale: Scale, offset: Offset) -> MemoryBuffer {
     let mut buffer = MemoryBuffer::new();
 
     // All of these require SIB byte.
-    // Note that `index == RSP` is not allowed in SIB.
-    debug_assert!(!index.equals(GPR::RSP));
-
     // The only encoding that allows index without base is `mod_field == 0b00`
     // and SIB base `0b100` (the reason why RSP is not allowed btw).
     // This encoding requires mandatory disp32 at the end, so we need
     // to convert the offset.
-    buffer.push_array([
-        mod_rm(0b00, reg_field, 0b100),
-        sib(scale.index(), index.lower_3_bits_index(), 0b101),
-    ]);
+    buffer.push_array([mod_rm(0b00, reg_field, 0b100), sib(scale.index(), index_field, 0b101)]);
 
     let offset = offset.to_sign_extended_imm32();
     buffer.push_array(offset.encode());
@@ -151,16 +248,20 @@ const fn encode_scaled_memory(reg_field: u8, index: GPR, scale: Scale, offset: O
     buffer
 }
 
-const fn encode_based_scaled_memory(reg_field: u8, base: GPR, index: GPR, scale: Scale, offset: Offset) -> MemoryBuffer {
+const fn encode_based_scaled_memory(
+    reg_field: u8,
+    base: GPR,
+    index_field: u8,
+    scale: Scale,
+    offset: Offset,
+) -> MemoryBuffer {
     let mut buffer = MemoryBuffer::new();
     // All of these require SIB byte, i.e. `rm_field == 0b100` in mod_rm byte.
-    // Note that `index == RSP` is not allowed in SIB.
-    debug_assert!(!index.equals(GPR::RSP));
 
     // These can be encoded in multiple ways. The most efficient encoding
     // depends on the offset.
 
-    let sib_byte = sib(scale.index(), index.lower_3_bits_index(), base.lower_3_bits_index());
+    let sib_byte = sib(scale.index(), index_field, base.lower_3_bits_index());
 
     if matches!(offset, Offset::None) && (base.equals(GPR::RBP) || base.equals(GPR::R13)) {
         // Special case: these registers require `mod_field == 0b01`,
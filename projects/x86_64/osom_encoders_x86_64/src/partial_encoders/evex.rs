@@ -0,0 +1,499 @@
+use crate::models::{
+    EncodedX86_64Instruction, Immediate8, Masking, Memory, Opmask, Rounding, VSIBMemory, XMM, XMMOrMemory, YMM, ZMM,
+    ZMMOrMemory,
+};
+
+use super::core::{encode_memory, encode_vsib_memory, mod_rm};
+use super::vex::{implied_prefix_field, opcode_map};
+
+pub const EVEX_PREFIX: u8 = 0x62;
+
+/// The EVEX.L'L field of 512-bit instructions.
+const VECTOR_LENGTH_512: u8 = 0b10;
+
+/// The EVEX.L'L field of scalar instructions, which ignore the vector length.
+const VECTOR_LENGTH_SCALAR: u8 = 0b00;
+
+/// Encodes EVEX.512 instruction with `zmm` in the reg field of mod/rm, `vvvv`
+/// in EVEX.vvvv and `zmm_or_memory` in the r/m field of mod/rm,
+/// e.g. `vaddps zmm0 {k1} {z}, zmm1, zmmword ptr [rax]`. The `masking` is
+/// encoded as EVEX.aaa and EVEX.z, broadcast memory sets EVEX.b.
+///
+/// The leading `0F`, `0F 38` or `0F 3A` bytes of `opcode` select the opcode map,
+/// `prefix` (0, `66`, `F3` or `F2`) is encoded as EVEX.pp and `w` as EVEX.W.
+/// The `w` also selects the element size of broadcast memory, i.e. 32 bits
+/// for `false` and 64 bits for `true`. Memory displacements are compressed
+/// (disp8*N) whenever possible.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_zmm_zmm_zmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_or_memory: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        zmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::from_zmm_or_memory(zmm_or_memory),
+    )
+}
+
+/// Encodes EVEX.512 instruction with register operands only and static
+/// `rounding` (`{er}`), e.g. `vaddps zmm0, zmm1, zmm2, {rn-sae}`.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+#[allow(clippy::too_many_arguments)]
+pub const unsafe fn encode_zmm_zmm_zmm_er<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_rm: ZMM,
+    rounding: Rounding,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::rounding(&opcode, prefix, w, masking, rounding),
+        zmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::Register { index: zmm_rm.index() },
+    )
+}
+
+/// Encodes EVEX.512 instruction with register operands only and suppressed
+/// floating-point exceptions (`{sae}`), e.g. `vmaxps zmm0, zmm1, zmm2, {sae}`.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_zmm_zmm_zmm_sae<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_rm: ZMM,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::sae(&opcode, prefix, w, masking),
+        zmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::Register { index: zmm_rm.index() },
+    )
+}
+
+/// Encodes EVEX.512 instruction with `zmm` in the reg field of mod/rm,
+/// `zmm_or_memory` in the r/m field of mod/rm and unused EVEX.vvvv,
+/// e.g. `vsqrtps zmm0 {k1}, zmmword ptr [rax]`. The store forms, e.g.
+/// `vmovups zmmword ptr [rax] {k1}, zmm0`, are encoded with this function as well.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_zmm_zmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    zmm_or_memory: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        zmm.index(),
+        0,
+        RegisterOrMemory::from_zmm_or_memory(zmm_or_memory),
+    )
+}
+
+/// Encodes EVEX.512 instruction with `zmm` in the reg field of mod/rm,
+/// `zmm_rm` in the r/m field of mod/rm, unused EVEX.vvvv and static `rounding`,
+/// e.g. `vsqrtps zmm0, zmm1, {ru-sae}`.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_zmm_zmm_er<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    zmm_rm: ZMM,
+    rounding: Rounding,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::rounding(&opcode, prefix, w, masking, rounding),
+        zmm.index(),
+        0,
+        RegisterOrMemory::Register { index: zmm_rm.index() },
+    )
+}
+
+/// Encodes EVEX scalar instruction with `xmm` in the reg field of mod/rm, `vvvv`
+/// in EVEX.vvvv and `xmm_or_memory` in the r/m field of mod/rm,
+/// e.g. `vaddss xmm16 {k1}, xmm17, dword ptr [rax]`. The `w` selects the
+/// size of the memory operand, i.e. 32 bits for `false` and 64 bits for `true`.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    masking: Masking,
+    vvvv: XMM,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    let register_or_memory = match xmm_or_memory {
+        XMMOrMemory::XMM { xmm } => RegisterOrMemory::Register { index: xmm.index() },
+        XMMOrMemory::Memory { memory } => RegisterOrMemory::Memory { memory },
+    };
+    encode_evex(
+        EvexFields::scalar(&opcode, prefix, w, masking),
+        xmm.index(),
+        vvvv.index(),
+        register_or_memory,
+    )
+}
+
+/// Encodes EVEX scalar instruction with register operands only and static
+/// `rounding`, e.g. `vaddss xmm1, xmm2, xmm3, {rd-sae}`.
+/// The remaining arguments are exactly as in [`encode_xmm_xmm_xmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+#[allow(clippy::too_many_arguments)]
+pub const unsafe fn encode_xmm_xmm_xmm_er<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    masking: Masking,
+    vvvv: XMM,
+    xmm_rm: XMM,
+    rounding: Rounding,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::rounding(&opcode, prefix, w, masking, rounding),
+        xmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::Register { index: xmm_rm.index() },
+    )
+}
+
+/// Encodes EVEX.512 compare instruction with opmask `k` in the reg field of mod/rm,
+/// `vvvv` in EVEX.vvvv and `zmm_or_memory` in the r/m field of mod/rm,
+/// e.g. `vpcmpeqd k1 {k2}, zmm1, zmmword ptr [rax]`. Only merge `masking` is
+/// meaningful here, the result is and-ed with the mask.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_zmm_zmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    k: Opmask,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_or_memory: ZMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        k.index(),
+        vvvv.index(),
+        RegisterOrMemory::from_zmm_or_memory(zmm_or_memory),
+    )
+}
+
+/// Encodes EVEX.512 compare instruction with an 8-bit immediate predicate,
+/// e.g. `vcmpps k1 {k2}, zmm1, zmm2, 1`.
+/// The remaining arguments are exactly as in [`encode_k_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+#[allow(clippy::too_many_arguments)]
+pub const unsafe fn encode_k_zmm_zmm_or_memory_imm8<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    k: Opmask,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_or_memory: ZMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    let mut instr = encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        k.index(),
+        vvvv.index(),
+        RegisterOrMemory::from_zmm_or_memory(zmm_or_memory),
+    );
+    instr.push_array(imm8.encode());
+    instr
+}
+
+/// Encodes EVEX.512 compare instruction with register operands only, suppressed
+/// floating-point exceptions and an 8-bit immediate predicate,
+/// e.g. `vcmpps k1, zmm1, zmm2, {sae}, 1`.
+/// The remaining arguments are exactly as in [`encode_k_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+#[allow(clippy::too_many_arguments)]
+pub const unsafe fn encode_k_zmm_zmm_sae_imm8<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    k: Opmask,
+    masking: Masking,
+    vvvv: ZMM,
+    zmm_rm: ZMM,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    let mut instr = encode_evex(
+        EvexFields::sae(&opcode, prefix, w, masking),
+        k.index(),
+        vvvv.index(),
+        RegisterOrMemory::Register { index: zmm_rm.index() },
+    );
+    instr.push_array(imm8.encode());
+    instr
+}
+
+/// Encodes EVEX.512 gather with `zmm` destination in the reg field of mod/rm and
+/// `vsib_memory` in the r/m field of mod/rm, e.g. `vpgatherdd zmm0 {k1}, dword ptr [rax + zmm1*4]`.
+/// The scatters, e.g. `vpscatterdd dword ptr [rax + zmm1*4] {k1}, zmm0`, are encoded
+/// with this function as well. The highest bit of the index is encoded in EVEX.V',
+/// and the displacement is compressed by the element size selected by `w`.
+/// The remaining arguments are exactly as in [`encode_zmm_zmm_zmm_or_memory`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_zmm_vsib<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    zmm: ZMM,
+    masking: Masking,
+    vsib_memory: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        zmm.index(),
+        0,
+        RegisterOrMemory::VSIBMemory { memory: vsib_memory },
+    )
+}
+
+/// Encodes EVEX.512 gather with `ymm` destination in the reg field of mod/rm and
+/// `vsib_memory` with ZMM index in the r/m field of mod/rm, e.g.
+/// `vpgatherqd ymm0 {k1}, dword ptr [rax + zmm1*4]`. The scatters, e.g.
+/// `vpscatterqd dword ptr [rax + zmm1*4] {k1}, ymm0`, are encoded with this function as well.
+/// The remaining arguments are exactly as in [`encode_zmm_vsib`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_vsib<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    masking: Masking,
+    vsib_memory: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    encode_evex(
+        EvexFields::vector(&opcode, prefix, w, masking),
+        ymm.index(),
+        0,
+        RegisterOrMemory::VSIBMemory { memory: vsib_memory },
+    )
+}
+
+/// The r/m operand of EVEX instruction, with registers represented by their full 5-bit indexes.
+#[derive(Clone, Copy)]
+enum RegisterOrMemory {
+    Register { index: u8 },
+    Memory { memory: Memory },
+    Broadcast { memory: Memory },
+    VSIBMemory { memory: VSIBMemory },
+}
+
+impl RegisterOrMemory {
+    const fn from_zmm_or_memory(zmm_or_memory: ZMMOrMemory) -> Self {
+        match zmm_or_memory {
+            ZMMOrMemory::ZMM { zmm } => Self::Register { index: zmm.index() },
+            ZMMOrMemory::Memory { memory } => Self::Memory { memory },
+            ZMMOrMemory::Broadcast { memory } => Self::Broadcast { memory },
+        }
+    }
+}
+
+/// The fields of EVEX prefix that don't depend on the register operands.
+#[derive(Clone, Copy)]
+struct EvexFields<'a> {
+    opcode: &'a [u8],
+    prefix: u8,
+    w: bool,
+    masking: Masking,
+
+    /// The EVEX.L'L field. With register operands and `b` set, this is
+    /// the static rounding control instead.
+    vector_length: u8,
+
+    /// The EVEX.b bit for register operands, i.e. `{er}` or `{sae}`.
+    b: bool,
+
+    /// The N of compressed displacement (disp8*N) of full memory operand.
+    memory_size: u8,
+}
+
+impl<'a> EvexFields<'a> {
+    const fn vector(opcode: &'a [u8], prefix: u8, w: bool, masking: Masking) -> Self {
+        Self {
+            opcode,
+            prefix,
+            w,
+            masking,
+            vector_length: VECTOR_LENGTH_512,
+            b: false,
+            memory_size: 64,
+        }
+    }
+
+    const fn scalar(opcode: &'a [u8], prefix: u8, w: bool, masking: Masking) -> Self {
+        Self {
+            vector_length: VECTOR_LENGTH_SCALAR,
+            memory_size: element_size(w),
+            ..Self::vector(opcode, prefix, w, masking)
+        }
+    }
+
+    const fn rounding(opcode: &'a [u8], prefix: u8, w: bool, masking: Masking, rounding: Rounding) -> Self {
+        Self {
+            vector_length: rounding.as_u8(),
+            b: true,
+            ..Self::vector(opcode, prefix, w, masking)
+        }
+    }
+
+    const fn sae(opcode: &'a [u8], prefix: u8, w: bool, masking: Masking) -> Self {
+        Self {
+            vector_length: 0b00,
+            b: true,
+            ..Self::vector(opcode, prefix, w, masking)
+        }
+    }
+}
+
+/// Returns the size in bytes of a single (broadcast or scalar) element,
+/// selected by EVEX.W.
+#[inline(always)]
+const fn element_size(w: bool) -> u8 {
+    if w { 8 } else { 4 }
+}
+
+/// Encodes the 4-byte EVEX prefix, the last byte of `opcode` and mod/rm (with SIB
+/// and compressed displacement, if any). The `reg_field` and `vvvv` are full 5-bit indexes.
+const fn encode_evex(
+    fields: EvexFields,
+    reg_field: u8,
+    vvvv: u8,
+    register_or_memory: RegisterOrMemory,
+) -> EncodedX86_64Instruction {
+    debug_assert!(reg_field < 32);
+    debug_assert!(vvvv < 32);
+    let map = opcode_map(fields.opcode);
+    assert!(map != 0, "EVEX opcode has to start with 0F, 0F 38 or 0F 3A.");
+
+    // With register r/m, EVEX.X holds the highest bit of its 5-bit index.
+    let (x, b, broadcast) = match register_or_memory {
+        RegisterOrMemory::Register { index } => (index & 0b1_0000 != 0, index & 0b1000 != 0, fields.b),
+        RegisterOrMemory::Memory { memory } => {
+            let ext = memory.base_index_is_extended();
+            (ext.index_is_extended, ext.base_is_extended, false)
+        }
+        RegisterOrMemory::Broadcast { memory } => {
+            let ext = memory.base_index_is_extended();
+            (ext.index_is_extended, ext.base_is_extended, true)
+        }
+        RegisterOrMemory::VSIBMemory { memory } => {
+            let ext = memory.base_index_is_extended();
+            (ext.index_is_extended, ext.base_is_extended, false)
+        }
+    };
+
+    // With VSIB memory, EVEX.V' holds the highest bit of the 5-bit index,
+    // since EVEX.vvvv is unused.
+    let v_prime = match register_or_memory {
+        RegisterOrMemory::VSIBMemory { memory } => memory.index().index() & 0b1_0000 != 0,
+        _ => vvvv & 0b1_0000 != 0,
+    };
+
+    // EVEX stores R, X, B, R', vvvv and V' inverted.
+    let p0 = (if reg_field & 0b1000 != 0 { 0 } else { 0b1000_0000 })
+        | (if x { 0 } else { 0b0100_0000 })
+        | (if b { 0 } else { 0b0010_0000 })
+        | (if reg_field & 0b1_0000 != 0 { 0 } else { 0b0001_0000 })
+        | map;
+    let p1 =
+        (if fields.w { 0b1000_0000 } else { 0 }) | ((!vvvv & 0b1111) << 3) | 0b100 | implied_prefix_field(fields.prefix);
+    let (z, aaa) = match fields.masking {
+        Masking::None => (0, 0),
+        Masking::Merge { opmask } => (0, opmask.index()),
+        Masking::Zero { opmask } => (0b1000_0000, opmask.index()),
+    };
+    let p2 = z
+        | (fields.vector_length << 5)
+        | (if broadcast { 0b1_0000 } else { 0 })
+        | (if v_prime { 0 } else { 0b1000 })
+        | aaa;
+
+    let mut result_instr = EncodedX86_64Instruction::new();
+    result_instr.push_array([EVEX_PREFIX, p0, p1, p2, fields.opcode[fields.opcode.len() - 1]]);
+
+    match register_or_memory {
+        RegisterOrMemory::Register { index } => {
+            result_instr.push_array([mod_rm(0b11, reg_field & 0b111, index & 0b111)]);
+        }
+        RegisterOrMemory::Memory { memory } => {
+            let result = encode_memory(reg_field & 0b111, memory, fields.memory_size);
+            result_instr.push_slice(result.as_slice());
+        }
+        RegisterOrMemory::Broadcast { memory } => {
+            let result = encode_memory(reg_field & 0b111, memory, element_size(fields.w));
+            result_instr.push_slice(result.as_slice());
+        }
+        RegisterOrMemory::VSIBMemory { memory } => {
+            let result = encode_vsib_memory(reg_field & 0b111, memory, element_size(fields.w));
+            result_instr.push_slice(result.as_slice());
+        }
+    }
+
+    result_instr
+}
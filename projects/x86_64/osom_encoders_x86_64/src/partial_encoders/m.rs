@@ -1,28 +1,37 @@
 use core::num::NonZero;
 
-use crate::models::{EncodedX86_64Instruction, GPRKind, GPROrMemory, Size};
+use crate::models::{EncodedX86_64Instruction, GPRKind, GPROrMemory, Memory, Size};
 
-use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REX, REX_B, REX_W, encode_memory, mod_rm, rex};
+use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REX, REX_B, REX_W, mod_rm, rex};
 
+/// Encodes M encoding. The `size` is the operand size of the instruction,
+/// which for memory operands cannot be inferred from the operand itself.
+///
 /// # Safety
 ///
 /// The caller has to ensure that the operands are valid,
-/// in particular the function does not check register sizes.
+/// in particular the function does not check register sizes
+/// (other than in debug builds).
 pub const unsafe fn encode_gpr_or_memory<const N: usize>(
     opcode: [u8; N],
     extended_opcode: u8,
     gpr_or_memory: GPROrMemory,
+    size: Size,
     bit64_requires_rex_w: bool,
     bit16_requires_oso_prefix: bool,
 ) -> EncodedX86_64Instruction {
     unsafe {
         let mut result_instr = EncodedX86_64Instruction::new();
 
+        if bit16_requires_oso_prefix && size.equals(Size::Bit16) {
+            result_instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
+        }
+
+        let requires_rex_w = bit64_requires_rex_w && size.equals(Size::Bit64);
+
         match gpr_or_memory {
             GPROrMemory::GPR { gpr } => {
-                if bit16_requires_oso_prefix && gpr.size().equals(Size::Bit16) {
-                    result_instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
-                }
+                debug_assert!(gpr.size().equals(size), "GPR size mismatch");
 
                 let rex = {
                     const fn unwrap_rex(val: Option<NonZero<u8>>) -> u8 {
@@ -38,7 +47,7 @@ pub const unsafe fn encode_gpr_or_memory<const N: usize>(
                         rex_result = Some(NonZero::new_unchecked(val | REX_B.get()));
                     }
 
-                    if bit64_requires_rex_w && gpr.size().equals(Size::Bit64) {
+                    if requires_rex_w {
                         let val = unwrap_rex(rex_result);
                         rex_result = Some(NonZero::new_unchecked(val | REX_W.get()));
                     }
@@ -57,9 +66,9 @@ pub const unsafe fn encode_gpr_or_memory<const N: usize>(
                 let base_is_extended = ext.base_is_extended;
                 let index_is_extended = ext.index_is_extended;
 
-                if base_is_extended || index_is_extended {
+                if base_is_extended || index_is_extended || requires_rex_w {
                     let rex = rex(
-                        0,
+                        if requires_rex_w { 1 } else { 0 },
                         0,
                         if index_is_extended { 1 } else { 0 },
                         if base_is_extended { 1 } else { 0 },
@@ -70,7 +79,7 @@ pub const unsafe fn encode_gpr_or_memory<const N: usize>(
 
                 result_instr.push_array(opcode);
 
-                let result = encode_memory(extended_opcode, memory);
+                let result = super::core::encode_memory(extended_opcode, memory, 1);
                 result_instr.push_slice(result.as_slice());
             }
         }
@@ -78,3 +87,28 @@ pub const unsafe fn encode_gpr_or_memory<const N: usize>(
         result_instr
     }
 }
+
+/// Encodes M encoding with memory-only operand, e.g. `cmpxchg8b m64`.
+///
+/// # Safety
+///
+/// The caller has to ensure that the memory operand is valid.
+pub const unsafe fn encode_memory<const N: usize>(
+    opcode: [u8; N],
+    extended_opcode: u8,
+    memory: Memory,
+    requires_rex_w: bool,
+) -> EncodedX86_64Instruction {
+    // The size only decides about REX.W here, since memory operands are not sized.
+    let size = if requires_rex_w { Size::Bit64 } else { Size::Bit32 };
+    unsafe {
+        encode_gpr_or_memory(
+            opcode,
+            extended_opcode,
+            GPROrMemory::Memory { memory },
+            size,
+            true,
+            false,
+        )
+    }
+}
@@ -43,7 +43,9 @@ pub const unsafe fn encode_rm8_imm8<const N: usize>(
     unsafe {
         gpr_size_is_valid(gpr_or_memory, Size::Bit8);
         let mut instr = EncodedX86_64Instruction::new();
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit8, true, false).as_slice(),
+        );
         instr.push_array(imm8.encode());
         instr
     }
@@ -68,7 +70,9 @@ pub const unsafe fn encode_rm16_imm16<const N: usize>(
         gpr_size_is_valid(gpr_or_memory, Size::Bit16);
         let mut instr = EncodedX86_64Instruction::new();
         instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit16, true, false).as_slice(),
+        );
         instr.push_array(imm16.encode());
         instr
     }
@@ -93,7 +97,9 @@ pub const unsafe fn encode_rm16_imm8<const N: usize>(
         gpr_size_is_valid(gpr_or_memory, Size::Bit16);
         let mut instr = EncodedX86_64Instruction::new();
         instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit16, true, false).as_slice(),
+        );
         instr.push_array(imm8.encode());
         instr
     }
@@ -117,7 +123,9 @@ pub const unsafe fn encode_rm32_imm32<const N: usize>(
     unsafe {
         gpr_size_is_valid(gpr_or_memory, Size::Bit32);
         let mut instr = EncodedX86_64Instruction::new();
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit32, true, false).as_slice(),
+        );
         instr.push_array(imm32.encode());
         instr
     }
@@ -141,7 +149,9 @@ pub const unsafe fn encode_rm32_imm8<const N: usize>(
     unsafe {
         gpr_size_is_valid(gpr_or_memory, Size::Bit32);
         let mut instr = EncodedX86_64Instruction::new();
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit32, true, false).as_slice(),
+        );
         instr.push_array(imm8.encode());
         instr
     }
@@ -165,7 +175,9 @@ pub const unsafe fn encode_rm64_imm32<const N: usize>(
     unsafe {
         gpr_size_is_valid(gpr_or_memory, Size::Bit64);
         let mut instr = EncodedX86_64Instruction::new();
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit64, true, false).as_slice(),
+        );
         instr.push_array(imm32.encode());
         instr
     }
@@ -189,7 +201,9 @@ pub const unsafe fn encode_rm64_imm8<const N: usize>(
     unsafe {
         gpr_size_is_valid(gpr_or_memory, Size::Bit64);
         let mut instr = EncodedX86_64Instruction::new();
-        instr.push_slice(encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, true, false).as_slice());
+        instr.push_slice(
+            encode_gpr_or_memory(opcode, extended_opcode, gpr_or_memory, Size::Bit64, true, false).as_slice(),
+        );
         instr.push_array(imm8.encode());
         instr
     }
@@ -1,9 +1,13 @@
-mod core;
+pub mod core;
 
+pub mod evex;
 pub mod i;
 pub mod m;
 pub mod mi;
 pub mod mr;
 pub mod o;
 pub mod oi;
+pub mod rmi;
+pub mod vex;
+pub mod xmm;
 pub mod zo;
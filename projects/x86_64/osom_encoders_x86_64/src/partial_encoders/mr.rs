@@ -7,6 +7,10 @@ use crate::{
 
 use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REX, REX_B, REX_R, REX_W, mod_rm, rex};
 
+/// Encodes MR encoding. The size of the operation (and thus the prefixes) is
+/// inferred from `gpr`, which allows `gpr_or_memory` to be of a different size,
+/// e.g. for `movzx eax, bl`.
+///
 /// # Safety
 ///
 /// This function is unsafe because it doesn't validate the operands.
@@ -14,6 +18,22 @@ pub const unsafe fn encode<const N: usize>(
     opcode: [u8; N],
     gpr_or_memory: GPROrMemory,
     gpr: GPR,
+) -> EncodedX86_64Instruction {
+    unsafe { encode_with_mandatory_prefix(opcode, 0, gpr_or_memory, gpr) }
+}
+
+/// Encodes MR encoding exactly as [`encode`], with `mandatory_prefix`
+/// (e.g. `F3` of `popcnt`, or 0 if there is none) placed after
+/// the operand size override prefix and before REX.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_with_mandatory_prefix<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    gpr_or_memory: GPROrMemory,
+    gpr: GPR,
 ) -> EncodedX86_64Instruction {
     unsafe {
         let mut result_instr = EncodedX86_64Instruction::new();
@@ -21,6 +41,10 @@ pub const unsafe fn encode<const N: usize>(
             result_instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
         }
 
+        if mandatory_prefix != 0 {
+            result_instr.push_array([mandatory_prefix]);
+        }
+
         match gpr_or_memory {
             GPROrMemory::GPR { gpr: mem_gpr } => {
                 let rex = {
@@ -28,7 +52,9 @@ pub const unsafe fn encode<const N: usize>(
                         (if let Some(val) = val { val } else { REX }).get()
                     }
                     let mut rex_result = None;
-                    if mem_gpr.index_matches_bit8_high() && mem_gpr.kind().equals(GPRKind::Bit8) {
+                    if (mem_gpr.index_matches_bit8_high() && mem_gpr.kind().equals(GPRKind::Bit8))
+                        || (gpr.index_matches_bit8_high() && gpr.kind().equals(GPRKind::Bit8))
+                    {
                         rex_result = Some(REX);
                     }
 
@@ -37,7 +63,7 @@ pub const unsafe fn encode<const N: usize>(
                         rex_result = Some(NonZero::new_unchecked(val | REX_B.get()));
                     }
 
-                    if mem_gpr.size().equals(Size::Bit64) {
+                    if gpr.size().equals(Size::Bit64) {
                         let val = unwrap_rex(rex_result);
                         rex_result = Some(NonZero::new_unchecked(val | REX_W.get()));
                     }
@@ -63,8 +89,9 @@ pub const unsafe fn encode<const N: usize>(
                 let index_is_extended = ext.index_is_extended;
                 let gpr_is_extended = gpr.is_extended();
                 let gpr_is_bit64 = gpr.size().equals(Size::Bit64);
+                let gpr_requires_rex = gpr.index_matches_bit8_high() && gpr.kind().equals(GPRKind::Bit8);
 
-                if base_is_extended || index_is_extended || gpr_is_extended || gpr_is_bit64 {
+                if base_is_extended || index_is_extended || gpr_is_extended || gpr_is_bit64 || gpr_requires_rex {
                     let rex = rex(
                         if gpr_is_bit64 { 1 } else { 0 },
                         if gpr_is_extended { 1 } else { 0 },
@@ -77,7 +104,7 @@ pub const unsafe fn encode<const N: usize>(
 
                 result_instr.push_array(opcode);
 
-                let result = encode_memory(gpr.lower_3_bits_index(), memory);
+                let result = encode_memory(gpr.lower_3_bits_index(), memory, 1);
                 result_instr.push_slice(result.as_slice());
             }
         }
@@ -100,8 +127,9 @@ pub const unsafe fn encode_mem<const N: usize>(opcode: [u8; N], memory: Memory,
     let index_is_extended = ext.index_is_extended;
     let gpr_is_extended = gpr.is_extended();
     let gpr_is_bit64 = gpr.size().equals(Size::Bit64);
+    let gpr_requires_rex = gpr.index_matches_bit8_high() && gpr.kind().equals(GPRKind::Bit8);
 
-    if base_is_extended || index_is_extended || gpr_is_extended || gpr_is_bit64 {
+    if base_is_extended || index_is_extended || gpr_is_extended || gpr_is_bit64 || gpr_requires_rex {
         let rex = rex(
             if gpr_is_bit64 { 1 } else { 0 },
             if gpr_is_extended { 1 } else { 0 },
@@ -114,7 +142,7 @@ pub const unsafe fn encode_mem<const N: usize>(opcode: [u8; N], memory: Memory,
 
     result_instr.push_array(opcode);
 
-    let result = encode_memory(gpr.lower_3_bits_index(), memory);
+    let result = encode_memory(gpr.lower_3_bits_index(), memory, 1);
     result_instr.push_slice(result.as_slice());
 
     result_instr
@@ -1,21 +1,43 @@
 use crate::models::{EncodedX86_64Instruction, GPR, Size};
 
-use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REX_B};
+use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, rex};
 
+/// Encodes O encoding, i.e. a register added to the last byte of `opcode`.
+///
+/// # Safety
+///
+/// The caller has to ensure that the operands are valid,
+/// in particular the function does not check register sizes.
+#[inline]
+pub const unsafe fn encode<const N: usize>(opcode: [u8; N], gpr: GPR) -> EncodedX86_64Instruction {
+    unsafe { encode_with_rex_w(opcode, gpr, false) }
+}
+
+/// Like [`encode`], but with REX.W prefix.
+///
 /// # Safety
 ///
 /// The caller has to ensure that the operands are valid,
 /// in particular the function does not check register sizes.
-pub const unsafe fn encode(opcode: [u8; 1], gpr: GPR) -> EncodedX86_64Instruction {
+#[inline]
+pub const unsafe fn encode_rexw<const N: usize>(opcode: [u8; N], gpr: GPR) -> EncodedX86_64Instruction {
+    unsafe { encode_with_rex_w(opcode, gpr, true) }
+}
+
+const unsafe fn encode_with_rex_w<const N: usize>(
+    mut opcode: [u8; N],
+    gpr: GPR,
+    rex_w: bool,
+) -> EncodedX86_64Instruction {
     debug_assert!(!gpr.size().equals(Size::Bit8));
-    debug_assert!(!gpr.size().equals(Size::Bit32));
     let mut encoded_instruction = EncodedX86_64Instruction::new();
     if gpr.size().equals(Size::Bit16) {
         encoded_instruction.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
     }
-    if gpr.is_extended() {
-        encoded_instruction.push_array([REX_B.get()]);
+    if rex_w || gpr.is_extended() {
+        encoded_instruction.push_array([rex(rex_w as u8, 0, 0, gpr.is_extended() as u8).get()]);
     }
-    encoded_instruction.push_array([opcode[0] + gpr.lower_3_bits_index()]);
+    opcode[N - 1] += gpr.lower_3_bits_index();
+    encoded_instruction.push_array(opcode);
     encoded_instruction
 }
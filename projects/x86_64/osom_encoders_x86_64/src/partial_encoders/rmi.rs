@@ -0,0 +1,58 @@
+use crate::models::{EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Immediate16, Immediate32};
+
+/// Encodes RMI encoding with an 8-bit immediate. The size of the operation
+/// is inferred from `gpr`, exactly as in [`mr::encode`][super::mr::encode].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_imm8<const N: usize>(
+    opcode: [u8; N],
+    gpr: GPR,
+    gpr_or_memory: GPROrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = super::mr::encode(opcode, gpr_or_memory, gpr);
+        instr.push_array(imm8.encode());
+        instr
+    }
+}
+
+/// Encodes RMI encoding with a 16-bit immediate. The size of the operation
+/// is inferred from `gpr`, exactly as in [`mr::encode`][super::mr::encode].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_imm16<const N: usize>(
+    opcode: [u8; N],
+    gpr: GPR,
+    gpr_or_memory: GPROrMemory,
+    imm16: Immediate16,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = super::mr::encode(opcode, gpr_or_memory, gpr);
+        instr.push_array(imm16.encode());
+        instr
+    }
+}
+
+/// Encodes RMI encoding with a 32-bit immediate. The size of the operation
+/// is inferred from `gpr`, exactly as in [`mr::encode`][super::mr::encode].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_imm32<const N: usize>(
+    opcode: [u8; N],
+    gpr: GPR,
+    gpr_or_memory: GPROrMemory,
+    imm32: Immediate32,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = super::mr::encode(opcode, gpr_or_memory, gpr);
+        instr.push_array(imm32.encode());
+        instr
+    }
+}
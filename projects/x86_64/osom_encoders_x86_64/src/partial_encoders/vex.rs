@@ -0,0 +1,636 @@
+use crate::models::{
+    EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Memory, Opmask, OpmaskOrMemory, Size, VSIBIndex, VSIBMemory,
+    XMM, XMMOrMemory, YMM, YMMOrMemory,
+};
+
+use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REP_PREFIX, REPNE_PREFIX, encode_memory, encode_vsib_memory, mod_rm};
+
+pub const VEX2_PREFIX: u8 = 0xC5;
+pub const VEX3_PREFIX: u8 = 0xC4;
+
+/// Returns the VEX opcode map (`mmmmm` field) of `opcode`, i.e. 1 for `0F xx`,
+/// 2 for `0F 38 xx` and 3 for `0F 3A xx`, or 0 if `opcode` doesn't belong
+/// to any of them.
+#[must_use]
+pub const fn opcode_map(opcode: &[u8]) -> u8 {
+    match opcode {
+        [0x0F, 0x38, _] => 2,
+        [0x0F, 0x3A, _] => 3,
+        [0x0F, _] => 1,
+        _ => 0,
+    }
+}
+
+/// Returns the VEX `pp` field corresponding to mandatory `prefix`
+/// (0 for no prefix, `66`, `F3` or `F2`).
+#[must_use]
+pub const fn implied_prefix_field(prefix: u8) -> u8 {
+    if prefix == OPERAND_SIZE_OVERRIDE_PREFIX.get() {
+        0b01
+    } else if prefix == REP_PREFIX.get() {
+        0b10
+    } else if prefix == REPNE_PREFIX.get() {
+        0b11
+    } else {
+        debug_assert!(prefix == 0);
+        0b00
+    }
+}
+
+/// Encodes RVM encoding, i.e. `gpr` in the reg field of mod/rm, `vvvv`
+/// in VEX.vvvv and `gpr_or_memory` in the r/m field of mod/rm. RMV encoding
+/// differs only in the order of operands, and so it is encoded with this function as well.
+///
+/// The leading `0F`, `0F 38` or `0F 3A` bytes of `opcode` select the opcode map,
+/// `prefix` (0, `66`, `F3` or `F2`) is encoded as VEX.pp and `w` as VEX.W.
+/// The shorter 2-byte VEX prefix is used whenever possible.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_rvm<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    gpr: GPR,
+    vvvv: GPR,
+    gpr_or_memory: GPROrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        false,
+        register_index(gpr),
+        register_index(vvvv),
+        RegisterOrMemory::from_gpr_or_memory(gpr_or_memory),
+    )
+}
+
+/// Encodes VM encoding, i.e. `vvvv` in VEX.vvvv, `extended_opcode` in
+/// the reg field of mod/rm and `gpr_or_memory` in the r/m field of mod/rm.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_vm<const N: usize>(
+    opcode: [u8; N],
+    extended_opcode: u8,
+    prefix: u8,
+    w: bool,
+    vvvv: GPR,
+    gpr_or_memory: GPROrMemory,
+) -> EncodedX86_64Instruction {
+    debug_assert!(extended_opcode < 8);
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        false,
+        extended_opcode,
+        register_index(vvvv),
+        RegisterOrMemory::from_gpr_or_memory(gpr_or_memory),
+    )
+}
+
+/// Encodes RMI encoding with an 8-bit immediate and unused VEX.vvvv.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_rmi<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    gpr: GPR,
+    gpr_or_memory: GPROrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    let mut instr = encode_vex(
+        &opcode,
+        prefix,
+        w,
+        false,
+        register_index(gpr),
+        0,
+        RegisterOrMemory::from_gpr_or_memory(gpr_or_memory),
+    );
+    instr.push_array(imm8.encode());
+    instr
+}
+
+/// Encodes VEX.128 instruction with `xmm` in the reg field of mod/rm, `vvvv`
+/// in VEX.vvvv and `xmm_or_memory` in the r/m field of mod/rm,
+/// e.g. `vaddps xmm0, xmm1, xmmword ptr [rax]`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    vvvv: XMM,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        false,
+        xmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::from_xmm_or_memory(xmm_or_memory),
+    )
+}
+
+/// Encodes VEX.256 instruction with `ymm` in the reg field of mod/rm, `vvvv`
+/// in VEX.vvvv and `ymm_or_memory` in the r/m field of mod/rm,
+/// e.g. `vaddps ymm0, ymm1, ymmword ptr [rax]`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_ymm_ymm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    vvvv: YMM,
+    ymm_or_memory: YMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        true,
+        ymm.index(),
+        vvvv.index(),
+        RegisterOrMemory::from_ymm_or_memory(ymm_or_memory),
+    )
+}
+
+/// Encodes VEX.128 instruction with `xmm` in the reg field of mod/rm,
+/// `xmm_or_memory` in the r/m field of mod/rm and unused VEX.vvvv,
+/// e.g. `vmovdqu xmm0, xmmword ptr [rax]`. The store forms, e.g.
+/// `vmovdqu xmmword ptr [rax], xmm0`, are encoded with this function as well.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        false,
+        xmm.index(),
+        0,
+        RegisterOrMemory::from_xmm_or_memory(xmm_or_memory),
+    )
+}
+
+/// Encodes VEX.256 instruction with `ymm` in the reg field of mod/rm,
+/// `ymm_or_memory` in the r/m field of mod/rm and unused VEX.vvvv,
+/// e.g. `vmovdqu ymm0, ymmword ptr [rax]`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_ymm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    ymm_or_memory: YMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        true,
+        ymm.index(),
+        0,
+        RegisterOrMemory::from_ymm_or_memory(ymm_or_memory),
+    )
+}
+
+/// Encodes VEX.256 instruction with `ymm` in the reg field of mod/rm,
+/// `xmm_or_memory` in the r/m field of mod/rm and unused VEX.vvvv,
+/// e.g. `vpbroadcastd ymm0, xmm1`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        true,
+        ymm.index(),
+        0,
+        RegisterOrMemory::from_xmm_or_memory(xmm_or_memory),
+    )
+}
+
+/// Encodes VEX.128 instruction with `xmm` in the reg field of mod/rm,
+/// `xmm_or_memory` in the r/m field of mod/rm, unused VEX.vvvv and an 8-bit
+/// immediate, e.g. `vcvtps2ph qword ptr [rax], xmm0, 4`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_or_memory_imm8<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    xmm_or_memory: XMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    let mut instr = unsafe { encode_xmm_xmm_or_memory(opcode, prefix, w, xmm, xmm_or_memory) };
+    instr.push_array(imm8.encode());
+    instr
+}
+
+/// Encodes VEX.256 instruction with `ymm` in the reg field of mod/rm,
+/// `xmm_or_memory` in the r/m field of mod/rm, unused VEX.vvvv and an 8-bit
+/// immediate, e.g. `vcvtps2ph xmmword ptr [rax], ymm0, 4`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_xmm_or_memory_imm8<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    xmm_or_memory: XMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    let mut instr = unsafe { encode_ymm_xmm_or_memory(opcode, prefix, w, ymm, xmm_or_memory) };
+    instr.push_array(imm8.encode());
+    instr
+}
+
+/// Encodes VEX gather with `xmm` destination in the reg field of mod/rm, `vvvv`
+/// mask in VEX.vvvv and `vsib_memory` in the r/m field of mod/rm, e.g.
+/// `vpgatherdd xmm0, dword ptr [rax + xmm1*4], xmm2`. The VEX.L is set whenever
+/// the index is YMM, e.g. `vpgatherqd xmm0, dword ptr [rax + ymm1*4], xmm2`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_vsib<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    xmm: XMM,
+    vvvv: XMM,
+    vsib_memory: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        matches!(vsib_memory.index(), VSIBIndex::YMM { .. }),
+        xmm.index(),
+        vvvv.index(),
+        RegisterOrMemory::VSIBMemory { memory: vsib_memory },
+    )
+}
+
+/// Encodes VEX.256 gather with `ymm` destination in the reg field of mod/rm, `vvvv`
+/// mask in VEX.vvvv and `vsib_memory` in the r/m field of mod/rm, e.g.
+/// `vpgatherdd ymm0, dword ptr [rax + ymm1*4], ymm2`.
+/// The remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_ymm_ymm_vsib<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    ymm: YMM,
+    vvvv: YMM,
+    vsib_memory: VSIBMemory,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        true,
+        ymm.index(),
+        vvvv.index(),
+        RegisterOrMemory::VSIBMemory { memory: vsib_memory },
+    )
+}
+
+/// Encodes VEX instruction with opmask `k` in the reg field of mod/rm, `vvvv`
+/// in VEX.vvvv and opmask `k_rm` in the r/m field of mod/rm, e.g. `kandw k1, k2, k3`.
+/// The `l` is encoded as VEX.L, the remaining arguments are exactly as in [`encode_rvm`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_k_k<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    k: Opmask,
+    vvvv: Opmask,
+    k_rm: Opmask,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        l,
+        k.index(),
+        vvvv.index(),
+        RegisterOrMemory::Register { index: k_rm.index() },
+    )
+}
+
+/// Encodes VEX instruction with opmask `k` in the reg field of mod/rm,
+/// opmask `k_rm` in the r/m field of mod/rm and unused VEX.vvvv, e.g. `knotw k1, k2`.
+/// The remaining arguments are exactly as in [`encode_k_k_k`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_k<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    k: Opmask,
+    k_rm: Opmask,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        l,
+        k.index(),
+        0,
+        RegisterOrMemory::Register { index: k_rm.index() },
+    )
+}
+
+/// Encodes VEX instruction with opmask `k` in the reg field of mod/rm,
+/// `k_or_memory` in the r/m field of mod/rm and unused VEX.vvvv, e.g. `kmovw k1, word ptr [rax]`.
+/// The remaining arguments are exactly as in [`encode_k_k_k`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_k_or_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    k: Opmask,
+    k_or_memory: OpmaskOrMemory,
+) -> EncodedX86_64Instruction {
+    let register_or_memory = match k_or_memory {
+        OpmaskOrMemory::Opmask { opmask } => RegisterOrMemory::Register { index: opmask.index() },
+        OpmaskOrMemory::Memory { memory } => RegisterOrMemory::Memory { memory },
+    };
+    encode_vex(&opcode, prefix, w, l, k.index(), 0, register_or_memory)
+}
+
+/// Encodes VEX instruction with opmask `k` in the reg field of mod/rm, `memory`
+/// in the r/m field of mod/rm and unused VEX.vvvv, e.g. `kmovw word ptr [rax], k1`.
+/// The remaining arguments are exactly as in [`encode_k_k_k`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_memory<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    k: Opmask,
+    memory: Memory,
+) -> EncodedX86_64Instruction {
+    encode_vex(&opcode, prefix, w, l, k.index(), 0, RegisterOrMemory::Memory { memory })
+}
+
+/// Encodes VEX instruction with opmask `k` in the reg field of mod/rm,
+/// `gpr` in the r/m field of mod/rm and unused VEX.vvvv, e.g. `kmovw k1, eax`.
+/// The remaining arguments are exactly as in [`encode_k_k_k`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_k_gpr<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    k: Opmask,
+    gpr: GPR,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        l,
+        k.index(),
+        0,
+        RegisterOrMemory::Register {
+            index: register_index(gpr),
+        },
+    )
+}
+
+/// Encodes VEX instruction with `gpr` in the reg field of mod/rm,
+/// opmask `k` in the r/m field of mod/rm and unused VEX.vvvv, e.g. `kmovw eax, k1`.
+/// The remaining arguments are exactly as in [`encode_k_k_k`].
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_gpr_k<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    gpr: GPR,
+    k: Opmask,
+) -> EncodedX86_64Instruction {
+    encode_vex(
+        &opcode,
+        prefix,
+        w,
+        l,
+        register_index(gpr),
+        0,
+        RegisterOrMemory::Register { index: k.index() },
+    )
+}
+
+/// Encodes VEX instruction without operands, e.g. `vzeroupper`.
+/// The `l` is encoded as VEX.L, the remaining arguments are exactly as in [`encode_rvm`].
+pub const unsafe fn encode_zo<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    w: bool,
+    l: bool,
+) -> EncodedX86_64Instruction {
+    let mut result_instr = encode_vex_prefix(&opcode, prefix, w, l, 0, INVERTED_RXB_UNUSED);
+    result_instr.push_array([opcode[N - 1]]);
+    result_instr
+}
+
+/// The r/m operand of VEX instruction, with registers represented by their full 4-bit indexes.
+#[derive(Clone, Copy)]
+enum RegisterOrMemory {
+    Register { index: u8 },
+    Memory { memory: Memory },
+    VSIBMemory { memory: VSIBMemory },
+}
+
+impl RegisterOrMemory {
+    const fn from_gpr_or_memory(gpr_or_memory: GPROrMemory) -> Self {
+        match gpr_or_memory {
+            GPROrMemory::GPR { gpr } => Self::Register {
+                index: register_index(gpr),
+            },
+            GPROrMemory::Memory { memory } => Self::Memory { memory },
+        }
+    }
+
+    const fn from_xmm_or_memory(xmm_or_memory: XMMOrMemory) -> Self {
+        match xmm_or_memory {
+            XMMOrMemory::XMM { xmm } => Self::Register { index: xmm.index() },
+            XMMOrMemory::Memory { memory } => Self::Memory { memory },
+        }
+    }
+
+    const fn from_ymm_or_memory(ymm_or_memory: YMMOrMemory) -> Self {
+        match ymm_or_memory {
+            YMMOrMemory::YMM { ymm } => Self::Register { index: ymm.index() },
+            YMMOrMemory::Memory { memory } => Self::Memory { memory },
+        }
+    }
+}
+
+#[inline(always)]
+const fn register_index(gpr: GPR) -> u8 {
+    debug_assert!(gpr.size().equals(Size::Bit32) || gpr.size().equals(Size::Bit64));
+    gpr.lower_3_bits_index() | if gpr.is_extended() { 0b1000 } else { 0 }
+}
+
+/// Encodes VEX prefix, the last byte of `opcode` and mod/rm (with SIB and
+/// displacement, if any). The `reg_field` and `vvvv` are full 4-bit indexes.
+const fn encode_vex(
+    opcode: &[u8],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    reg_field: u8,
+    vvvv: u8,
+    register_or_memory: RegisterOrMemory,
+) -> EncodedX86_64Instruction {
+    debug_assert!(reg_field < 16);
+
+    let (x, b) = match register_or_memory {
+        RegisterOrMemory::Register { index } => (false, index >= 8),
+        RegisterOrMemory::Memory { memory } => {
+            let ext = memory.base_index_is_extended();
+            (ext.index_is_extended, ext.base_is_extended)
+        }
+        RegisterOrMemory::VSIBMemory { memory } => {
+            let ext = memory.base_index_is_extended();
+            (ext.index_is_extended, ext.base_is_extended)
+        }
+    };
+
+    // VEX stores R, X and B inverted.
+    let inverted_r = if reg_field >= 8 { 0 } else { 0b1000_0000 };
+    let inverted_x = if x { 0 } else { 0b0100_0000 };
+    let inverted_b = if b { 0 } else { 0b0010_0000 };
+
+    let mut result_instr = encode_vex_prefix(opcode, prefix, w, l, vvvv, inverted_r | inverted_x | inverted_b);
+    result_instr.push_array([opcode[opcode.len() - 1]]);
+
+    match register_or_memory {
+        RegisterOrMemory::Register { index } => {
+            result_instr.push_array([mod_rm(0b11, reg_field & 0b111, index & 0b111)]);
+        }
+        RegisterOrMemory::Memory { memory } => {
+            let result = encode_memory(reg_field & 0b111, memory, 1);
+            result_instr.push_slice(result.as_slice());
+        }
+        RegisterOrMemory::VSIBMemory { memory } => {
+            let result = encode_vsib_memory(reg_field & 0b111, memory, 1);
+            result_instr.push_slice(result.as_slice());
+        }
+    }
+
+    result_instr
+}
+
+/// The inverted R, X and B bits of VEX prefix without any extended register.
+const INVERTED_RXB_UNUSED: u8 = 0b1110_0000;
+
+/// Encodes the 2-byte `C5` VEX prefix whenever possible, and the 3-byte
+/// `C4` VEX prefix otherwise. The `vvvv` is a full 4-bit index, while
+/// `inverted_rxb` holds the already inverted R, X and B bits at their
+/// positions in the second byte of the 3-byte form.
+const fn encode_vex_prefix(
+    opcode: &[u8],
+    prefix: u8,
+    w: bool,
+    l: bool,
+    vvvv: u8,
+    inverted_rxb: u8,
+) -> EncodedX86_64Instruction {
+    let map = opcode_map(opcode);
+    assert!(map != 0, "VEX opcode has to start with 0F, 0F 38 or 0F 3A.");
+    debug_assert!(vvvv < 16);
+
+    // VEX stores vvvv inverted as well.
+    let w_vvvv_l_pp = (if w { 0b1000_0000 } else { 0 })
+        | ((!vvvv & 0b1111) << 3)
+        | (if l { 0b100 } else { 0 })
+        | implied_prefix_field(prefix);
+
+    let mut result_instr = EncodedX86_64Instruction::new();
+    let inverted_x_b = INVERTED_RXB_UNUSED & !0b1000_0000;
+    if map == 1 && (inverted_rxb & inverted_x_b) == inverted_x_b && !w {
+        result_instr.push_array([VEX2_PREFIX, (inverted_rxb & 0b1000_0000) | w_vvvv_l_pp]);
+    } else {
+        result_instr.push_array([VEX3_PREFIX, inverted_rxb | map, w_vvvv_l_pp]);
+    }
+    result_instr
+}
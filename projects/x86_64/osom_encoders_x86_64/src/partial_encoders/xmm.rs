@@ -0,0 +1,219 @@
+use core::num::NonZero;
+
+use crate::models::{EncodedX86_64Instruction, GPR, GPROrMemory, Immediate8, Memory, Size, XMM, XMMOrMemory};
+
+use super::core::{REX, encode_memory, mod_rm, rex};
+
+/// Encodes SSE instruction with `xmm` in the reg field of mod/rm and
+/// `xmm_or_memory` in the r/m field, e.g. `addss xmm0, dword ptr [rax]`.
+/// The `mandatory_prefix` (e.g. `F3` of `addss`, or 0 if there is none)
+/// is placed right before REX.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    xmm: XMM,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    match xmm_or_memory {
+        XMMOrMemory::XMM { xmm: rm_xmm } => {
+            encode_register(&opcode, mandatory_prefix, rex_w, xmm.index(), rm_xmm.index())
+        }
+        XMMOrMemory::Memory { memory } => encode_memory_operand(&opcode, mandatory_prefix, rex_w, xmm.index(), memory),
+    }
+}
+
+/// Encodes SSE instruction with `xmm` in the reg field of mod/rm and
+/// `gpr_or_memory` in the r/m field, e.g. `cvtsi2sd xmm0, eax` or `movd eax, xmm0`.
+/// The `gpr_or_memory` has to be 32-bit, unless `rex_w` is set.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_gpr_or_memory<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    xmm: XMM,
+    gpr_or_memory: GPROrMemory,
+) -> EncodedX86_64Instruction {
+    match gpr_or_memory {
+        GPROrMemory::GPR { gpr } => encode_register(&opcode, mandatory_prefix, rex_w, xmm.index(), gpr_index(gpr)),
+        GPROrMemory::Memory { memory } => encode_memory_operand(&opcode, mandatory_prefix, rex_w, xmm.index(), memory),
+    }
+}
+
+/// Encodes SSE instruction with `gpr` in the reg field of mod/rm and
+/// `xmm_or_memory` in the r/m field, e.g. `cvttsd2si eax, xmm0`.
+/// The `gpr` has to be 32-bit, unless `rex_w` is set.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_gpr_xmm_or_memory<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    gpr: GPR,
+    xmm_or_memory: XMMOrMemory,
+) -> EncodedX86_64Instruction {
+    match xmm_or_memory {
+        XMMOrMemory::XMM { xmm } => encode_register(&opcode, mandatory_prefix, rex_w, gpr_index(gpr), xmm.index()),
+        XMMOrMemory::Memory { memory } => {
+            encode_memory_operand(&opcode, mandatory_prefix, rex_w, gpr_index(gpr), memory)
+        }
+    }
+}
+
+/// Encodes SSE instruction with `gpr` in the reg field of mod/rm and
+/// `xmm` in the r/m field, e.g. `pmovmskb eax, xmm0`. There is no memory form.
+/// The `gpr` has to be 32-bit, unless `rex_w` is set.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_gpr_xmm<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    gpr: GPR,
+    xmm: XMM,
+) -> EncodedX86_64Instruction {
+    encode_register(&opcode, mandatory_prefix, rex_w, gpr_index(gpr), xmm.index())
+}
+
+/// Encodes SSE instruction exactly as [`encode_xmm_xmm_or_memory`]
+/// followed by `imm8`, e.g. `pshufd xmm0, xmm1, 0x1B`.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_xmm_or_memory_imm8<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    xmm: XMM,
+    xmm_or_memory: XMMOrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = encode_xmm_xmm_or_memory(opcode, mandatory_prefix, rex_w, xmm, xmm_or_memory);
+        instr.push_array(imm8.encode());
+        instr
+    }
+}
+
+/// Encodes SSE instruction exactly as [`encode_xmm_gpr_or_memory`]
+/// followed by `imm8`, e.g. `pinsrd xmm0, eax, 1` or `pextrb byte ptr [rax], xmm0, 1`.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_xmm_gpr_or_memory_imm8<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    xmm: XMM,
+    gpr_or_memory: GPROrMemory,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = encode_xmm_gpr_or_memory(opcode, mandatory_prefix, rex_w, xmm, gpr_or_memory);
+        instr.push_array(imm8.encode());
+        instr
+    }
+}
+
+/// Encodes SSE instruction exactly as [`encode_gpr_xmm`]
+/// followed by `imm8`, e.g. `pextrw eax, xmm0, 1`.
+///
+/// # Safety
+///
+/// This function is unsafe because it doesn't validate the operands.
+pub const unsafe fn encode_gpr_xmm_imm8<const N: usize>(
+    opcode: [u8; N],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    gpr: GPR,
+    xmm: XMM,
+    imm8: Immediate8,
+) -> EncodedX86_64Instruction {
+    unsafe {
+        let mut instr = encode_gpr_xmm(opcode, mandatory_prefix, rex_w, gpr, xmm);
+        instr.push_array(imm8.encode());
+        instr
+    }
+}
+
+#[inline(always)]
+const fn gpr_index(gpr: GPR) -> u8 {
+    debug_assert!(gpr.size().equals(Size::Bit32) || gpr.size().equals(Size::Bit64));
+    gpr.lower_3_bits_index() | if gpr.is_extended() { 0b1000 } else { 0 }
+}
+
+/// Encodes mandatory prefix (if any), REX (if needed), `opcode` and mod/rm with
+/// both fields being registers. The `reg_field` and `rm_field` are full 4-bit indexes.
+const fn encode_register(
+    opcode: &[u8],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    reg_field: u8,
+    rm_field: u8,
+) -> EncodedX86_64Instruction {
+    let mut result_instr = EncodedX86_64Instruction::new();
+    push_mandatory_prefix(&mut result_instr, mandatory_prefix);
+    push_rex(
+        &mut result_instr,
+        rex(rex_w as u8, (reg_field >= 8) as u8, 0, (rm_field >= 8) as u8),
+    );
+    result_instr.push_slice(opcode);
+    result_instr.push_array([mod_rm(0b11, reg_field & 0b111, rm_field & 0b111)]);
+    result_instr
+}
+
+/// Encodes mandatory prefix (if any), REX (if needed), `opcode` and mod/rm with
+/// memory operand. The `reg_field` is full 4-bit index.
+const fn encode_memory_operand(
+    opcode: &[u8],
+    mandatory_prefix: u8,
+    rex_w: bool,
+    reg_field: u8,
+    memory: Memory,
+) -> EncodedX86_64Instruction {
+    let ext = memory.base_index_is_extended();
+    let mut result_instr = EncodedX86_64Instruction::new();
+    push_mandatory_prefix(&mut result_instr, mandatory_prefix);
+    push_rex(
+        &mut result_instr,
+        rex(
+            rex_w as u8,
+            (reg_field >= 8) as u8,
+            ext.index_is_extended as u8,
+            ext.base_is_extended as u8,
+        ),
+    );
+    result_instr.push_slice(opcode);
+    let result = encode_memory(reg_field & 0b111, memory, 1);
+    result_instr.push_slice(result.as_slice());
+    result_instr
+}
+
+/// Pushes `mandatory_prefix` to `instr`, unless it is 0.
+#[inline(always)]
+const fn push_mandatory_prefix(instr: &mut EncodedX86_64Instruction, mandatory_prefix: u8) {
+    if mandatory_prefix != 0 {
+        instr.push_array([mandatory_prefix]);
+    }
+}
+
+/// Pushes `rex` to `instr`, unless it has no bits set.
+#[inline(always)]
+const fn push_rex(instr: &mut EncodedX86_64Instruction, rex: NonZero<u8>) {
+    if rex.get() != REX.get() {
+        instr.push_array([rex.get()]);
+    }
+}
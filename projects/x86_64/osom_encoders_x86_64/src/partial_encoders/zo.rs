@@ -1,8 +1,34 @@
 use crate::models::EncodedX86_64Instruction;
 
+use super::core::{OPERAND_SIZE_OVERRIDE_PREFIX, REX_W};
+
 /// Encodes a zero operand instruction. This function can be used
 /// to encode full instruction with raw bytes directly.
 #[inline(always)]
 pub const unsafe fn encode<const N: usize>(opcode: [u8; N]) -> EncodedX86_64Instruction {
     EncodedX86_64Instruction::from_array(opcode)
 }
+
+/// Encodes a zero operand instruction preceded by the legacy `prefix`
+/// (e.g. REP, or 0 if there is none) and the prefixes implying the operand
+/// size, e.g. `rep movsw`.
+#[inline]
+pub const unsafe fn encode_with_prefixes<const N: usize>(
+    opcode: [u8; N],
+    prefix: u8,
+    oso: bool,
+    rex_w: bool,
+) -> EncodedX86_64Instruction {
+    let mut instr = EncodedX86_64Instruction::new();
+    if prefix != 0 {
+        instr.push_array([prefix]);
+    }
+    if oso {
+        instr.push_array([OPERAND_SIZE_OVERRIDE_PREFIX.get()]);
+    }
+    if rex_w {
+        instr.push_array([REX_W.get()]);
+    }
+    instr.push_array(opcode);
+    instr
+}
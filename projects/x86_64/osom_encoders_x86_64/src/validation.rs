@@ -0,0 +1,281 @@
+//! Operand validation used by the safe encoders. Unlike the debug
+//! checks in `partial_encoders`, these are always on.
+
+use crate::models::{
+    EncodeError, GPR, GPRKind, GPROrMemory, Masking, Memory, OpmaskOrMemory, Size, VSIBIndex, VSIBMemory, XMM,
+    XMMOrMemory, YMM, YMMOrMemory, ZMMOrMemory,
+};
+
+/// Validates that `gpr` has exactly `size` size.
+#[inline]
+pub(crate) const fn validate_gpr(gpr: GPR, size: Size) -> Result<(), EncodeError> {
+    if gpr.size().equals(size) {
+        Ok(())
+    } else {
+        Err(EncodeError::InvalidGPRSize { gpr, expected: size })
+    }
+}
+
+/// Validates that `memory` is well-formed, i.e. all its registers
+/// are 64-bit wide and `RSP` is not used as index.
+pub(crate) const fn validate_memory(memory: Memory) -> Result<(), EncodeError> {
+    match memory {
+        Memory::Based { base, .. } => validate_memory_base(base),
+        Memory::Scaled { index, .. } => validate_memory_index(index),
+        Memory::BasedAndScaled { base, index, .. } => {
+            if let Err(err) = validate_memory_base(base) {
+                return Err(err);
+            }
+            validate_memory_index(index)
+        }
+        Memory::RelativeToRIP { .. } => Ok(()),
+    }
+}
+
+/// Validates `gpr_or_memory`. If it is a [`GPR`] then it has to have `size` size,
+/// otherwise the memory has to be well-formed.
+#[inline]
+pub(crate) const fn validate_gpr_or_memory(gpr_or_memory: GPROrMemory, size: Size) -> Result<(), EncodeError> {
+    match gpr_or_memory {
+        GPROrMemory::GPR { gpr } => validate_gpr(gpr, size),
+        GPROrMemory::Memory { memory } => validate_memory(memory),
+    }
+}
+
+/// Validates that `xmm` is encodable without EVEX, i.e. it is
+/// one of XMM0 to XMM15.
+#[inline]
+pub(crate) const fn validate_xmm(xmm: XMM) -> Result<(), EncodeError> {
+    if xmm.index() < 16 {
+        Ok(())
+    } else {
+        Err(EncodeError::XMMRequiresEvex { xmm })
+    }
+}
+
+/// Validates that `ymm` is encodable without EVEX, i.e. it is
+/// one of YMM0 to YMM15.
+#[inline]
+pub(crate) const fn validate_ymm(ymm: YMM) -> Result<(), EncodeError> {
+    if ymm.index() < 16 {
+        Ok(())
+    } else {
+        Err(EncodeError::YMMRequiresEvex { ymm })
+    }
+}
+
+/// Validates `xmm_or_memory` of a non-EVEX instruction. The [`XMM`]
+/// has to be encodable without EVEX, memory has to be well-formed.
+#[inline]
+pub(crate) const fn validate_xmm_or_memory(xmm_or_memory: XMMOrMemory) -> Result<(), EncodeError> {
+    match xmm_or_memory {
+        XMMOrMemory::XMM { xmm } => validate_xmm(xmm),
+        XMMOrMemory::Memory { memory } => validate_memory(memory),
+    }
+}
+
+/// Validates `ymm_or_memory` of a non-EVEX instruction. The [`YMM`]
+/// has to be encodable without EVEX, memory has to be well-formed.
+#[inline]
+pub(crate) const fn validate_ymm_or_memory(ymm_or_memory: YMMOrMemory) -> Result<(), EncodeError> {
+    match ymm_or_memory {
+        YMMOrMemory::YMM { ymm } => validate_ymm(ymm),
+        YMMOrMemory::Memory { memory } => validate_memory(memory),
+    }
+}
+
+/// Validates `xmm_or_memory` of an EVEX instruction. Every [`XMM`] is valid,
+/// memory has to be well-formed.
+#[inline]
+pub(crate) const fn validate_evex_xmm_or_memory(xmm_or_memory: XMMOrMemory) -> Result<(), EncodeError> {
+    match xmm_or_memory {
+        XMMOrMemory::XMM { .. } => Ok(()),
+        XMMOrMemory::Memory { memory } => validate_memory(memory),
+    }
+}
+
+/// Validates `zmm_or_memory`. Every [`ZMM`][crate::models::ZMM] is valid,
+/// memory has to be well-formed and broadcast is only valid if the
+/// instruction supports it, as told by `broadcast`.
+#[inline]
+pub(crate) const fn validate_zmm_or_memory(zmm_or_memory: ZMMOrMemory, broadcast: bool) -> Result<(), EncodeError> {
+    match zmm_or_memory {
+        ZMMOrMemory::ZMM { .. } => Ok(()),
+        ZMMOrMemory::Memory { memory } => validate_memory(memory),
+        ZMMOrMemory::Broadcast { memory } => {
+            if broadcast {
+                validate_memory(memory)
+            } else {
+                Err(EncodeError::BroadcastNotAllowed)
+            }
+        }
+    }
+}
+
+/// Validates `opmask_or_memory`. Every [`Opmask`][crate::models::Opmask] is valid,
+/// memory has to be well-formed.
+#[inline]
+pub(crate) const fn validate_opmask_or_memory(opmask_or_memory: OpmaskOrMemory) -> Result<(), EncodeError> {
+    match opmask_or_memory {
+        OpmaskOrMemory::Opmask { .. } => Ok(()),
+        OpmaskOrMemory::Memory { memory } => validate_memory(memory),
+    }
+}
+
+/// Validates the write `masking` of an EVEX instruction. K0 cannot be
+/// used as write mask and zeroing is only valid if the instruction
+/// supports it, as told by `zeroing`.
+#[inline]
+pub(crate) const fn validate_masking(masking: Masking, zeroing: bool) -> Result<(), EncodeError> {
+    match masking {
+        Masking::Merge { opmask } | Masking::Zero { opmask } if opmask.index() == 0 => {
+            Err(EncodeError::InvalidWriteMask)
+        }
+        Masking::None | Masking::Merge { .. } => Ok(()),
+        Masking::Zero { .. } => {
+            if zeroing {
+                Ok(())
+            } else {
+                Err(EncodeError::ZeroingMaskingNotAllowed)
+            }
+        }
+    }
+}
+
+/// Validates `vsib_memory` of a gather or scatter. The base has to be 64-bit wide
+/// and the vector index has to have `index_size` size. Unless `evex` is set,
+/// the index has to be encodable without EVEX as well.
+pub(crate) const fn validate_vsib_memory(
+    vsib_memory: VSIBMemory,
+    index_size: Size,
+    evex: bool,
+) -> Result<(), EncodeError> {
+    if let Some(base) = vsib_memory.base()
+        && let Err(err) = validate_memory_base(base)
+    {
+        return Err(err);
+    }
+
+    let index = vsib_memory.index();
+    if !index.size().equals(index_size) {
+        return Err(EncodeError::InvalidVSIBIndex {
+            index,
+            expected: index_size,
+        });
+    }
+
+    match index {
+        _ if evex => Ok(()),
+        VSIBIndex::XMM { xmm } => validate_xmm(xmm),
+        VSIBIndex::YMM { ymm } => validate_ymm(ymm),
+        VSIBIndex::ZMM { .. } => Err(EncodeError::InvalidVSIBIndex {
+            index,
+            expected: index_size,
+        }),
+    }
+}
+
+/// Validates the write `masking` of an EVEX gather or scatter. The mask
+/// is mandatory, since the processor clears its bits as the elements
+/// are processed, and zeroing is not allowed.
+#[inline]
+pub(crate) const fn validate_vsib_masking(masking: Masking) -> Result<(), EncodeError> {
+    if matches!(masking, Masking::None) {
+        Err(EncodeError::WriteMaskRequired)
+    } else {
+        validate_masking(masking, false)
+    }
+}
+
+/// Validates that the `destination` register, the vector index of `vsib_memory`
+/// and the vector `mask` register (VEX gathers only) of a gather are distinct.
+/// The registers are passed as indexes, since they may differ in size,
+/// e.g. `vpgatherdq ymm1, [rax + xmm1*4], ymm2` uses the same register twice.
+#[inline]
+pub(crate) const fn validate_gather_registers(
+    destination: u8,
+    vsib_memory: VSIBMemory,
+    mask: Option<u8>,
+) -> Result<(), EncodeError> {
+    let index = vsib_memory.index().index();
+    let mask_is_distinct = match mask {
+        Some(mask) => mask != destination && mask != index,
+        None => true,
+    };
+    if destination != index && mask_is_distinct {
+        Ok(())
+    } else {
+        Err(EncodeError::GatherRegistersNotDistinct)
+    }
+}
+
+/// Validates that the destination of LOCK-prefixed instruction is memory.
+/// LOCK with register destination raises #UD.
+#[inline]
+pub(crate) const fn validate_lock_destination(gpr_or_memory: GPROrMemory) -> Result<(), EncodeError> {
+    match gpr_or_memory {
+        GPROrMemory::GPR { gpr } => Err(EncodeError::LockWithRegisterDestination { gpr }),
+        GPROrMemory::Memory { .. } => Ok(()),
+    }
+}
+
+/// Validates that AH, BH, CH and DH registers are not used together
+/// with anything that forces REX prefix on the instruction.
+///
+/// The `rex_w` argument tells whether the instruction itself requires REX.W.
+pub(crate) const fn validate_bit8_high_with_rex(
+    gpr_or_memory: GPROrMemory,
+    gpr: GPR,
+    rex_w: bool,
+) -> Result<(), EncodeError> {
+    let rm_requires_rex = match gpr_or_memory {
+        GPROrMemory::GPR { gpr: rm_gpr } => rm_gpr.requires_rex(),
+        GPROrMemory::Memory { memory } => {
+            let ext = memory.base_index_is_extended();
+            ext.base_is_extended || ext.index_is_extended
+        }
+    };
+
+    if !(rex_w || rm_requires_rex || gpr.requires_rex()) {
+        return Ok(());
+    }
+
+    if is_bit8_high(gpr) {
+        return Err(EncodeError::Bit8HighRegisterWithRex { gpr });
+    }
+
+    if let GPROrMemory::GPR { gpr: rm_gpr } = gpr_or_memory
+        && is_bit8_high(rm_gpr)
+    {
+        return Err(EncodeError::Bit8HighRegisterWithRex { gpr: rm_gpr });
+    }
+
+    Ok(())
+}
+
+#[inline(always)]
+const fn is_bit8_high(gpr: GPR) -> bool {
+    gpr.kind().equals(GPRKind::Bit8High)
+}
+
+#[inline]
+const fn validate_memory_base(base: GPR) -> Result<(), EncodeError> {
+    if base.size().equals(Size::Bit64) {
+        Ok(())
+    } else {
+        Err(EncodeError::InvalidMemoryRegisterSize { gpr: base })
+    }
+}
+
+#[inline]
+const fn validate_memory_index(index: GPR) -> Result<(), EncodeError> {
+    if !index.size().equals(Size::Bit64) {
+        return Err(EncodeError::InvalidMemoryRegisterSize { gpr: index });
+    }
+
+    if index.equals(GPR::RSP) {
+        return Err(EncodeError::InvalidMemoryIndex { index });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::ECX }, &[0x66, 0x0F, 0x38, 0xF6, 0xC1])]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::R10D }, &[0x66, 0x45, 0x0F, 0x38, 0xF6, 0xC2])]
+#[case::memory(GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0x0F, 0x38, 0xF6, 0x10])]
+#[case::memory(GPR::R11D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0x66, 0x45, 0x0F, 0x38, 0xF6, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::ECX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0x66, 0x41, 0x0F, 0x38, 0xF6, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_adcx_reg32_rm32(#[case] reg32: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { adcx::encode_reg32_rm32(reg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::RCX }, &[0x66, 0x48, 0x0F, 0x38, 0xF6, 0xC1])]
+#[case::gpr(GPR::R8, GPROrMemory::GPR { gpr: GPR::R10 }, &[0x66, 0x4D, 0x0F, 0x38, 0xF6, 0xC2])]
+#[case::memory(GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0x48, 0x0F, 0x38, 0xF6, 0x10])]
+#[case::memory(GPR::R11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0x66, 0x4D, 0x0F, 0x38, 0xF6, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::RCX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0x66, 0x49, 0x0F, 0x38, 0xF6, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_adcx_reg64_rm64(#[case] reg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { adcx::encode_reg64_rm64(reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
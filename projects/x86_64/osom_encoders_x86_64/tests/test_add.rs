@@ -81,3 +81,37 @@ fn test_add_rm8_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate
     let instr = unsafe { add::encode_rm8_imm8(gpr_or_memory, imm8) };
     assert_eq!(expected, instr.as_slice());
 }
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate32::from_i32(1), &[0x48, 0x81, 0xC0, 0x01, 0x00, 0x00, 0x00])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9 }, Immediate32::from_i32(1), &[0x49, 0x81, 0xC1, 0x01, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate32::from_i32(5), &[0x48, 0x81, 0x45, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(-1), &[0x49, 0x81, 0x04, 0x24, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, Immediate32::from_i32(1), &[0x48, 0x81, 0x05, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])]
+fn test_add_rm64_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
+    let instr = unsafe { add::encode_rm64_imm32(gpr_or_memory, imm32) };
+    assert_eq!(expected, instr.as_slice());
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate8::from_i8(1), &[0x48, 0x83, 0xC0, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate8::from_i8(5), &[0x48, 0x83, 0x45, 0xF8, 0x05])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::R9, scale: Scale::Scale8, offset: Offset::None } }, Immediate8::from_i8(1), &[0x4A, 0x83, 0x04, 0xC8, 0x01])]
+fn test_add_rm64_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { add::encode_rm64_imm8(gpr_or_memory, imm8) };
+    assert_eq!(expected, instr.as_slice());
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_i8(1), &[0xF0, 0x83, 0x00, 0x01])]
+fn test_add_lock_rm32_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { add::encode_lock_rm32_imm8(gpr_or_memory, imm8) };
+    assert_eq!(expected, instr.as_slice());
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::None } }, GPR::RSI, &[0xF0, 0x48, 0x01, 0x37])]
+fn test_add_lock_rm64_reg64(#[case] gpr_or_memory: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { add::encode_lock_rm64_reg64(gpr_or_memory, reg64) };
+    assert_eq!(expected, instr.as_slice());
+}
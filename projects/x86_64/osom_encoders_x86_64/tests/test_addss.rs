@@ -0,0 +1,15 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xF3, 0x0F, 0x58, 0xC1])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xF3, 0x45, 0x0F, 0x58, 0xC7])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF3, 0x0F, 0x58, 0x10])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xF3, 0x45, 0x0F, 0x58, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xF3, 0x41, 0x0F, 0x58, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_addss_xmm_xmmm32(#[case] xmm: XMM, #[case] xmmm32: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { addss::encode_xmm_xmmm32(xmm, xmmm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
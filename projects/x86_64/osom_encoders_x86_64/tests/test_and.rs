@@ -0,0 +1,150 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case(0, &[0x24, 0x00])]
+#[case(1, &[0x24, 0x01])]
+fn test_and_al_imm8(#[case] imm8: i8, #[case] expected: &[u8]) {
+    let imm8 = Immediate8::from_i8(imm8);
+    let instr = unsafe { and::encode_al_imm8(imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(0, &[0x66, 0x25, 0x00, 0x00])]
+#[case(1, &[0x66, 0x25, 0x01, 0x00])]
+fn test_and_ax_imm16(#[case] imm16: i16, #[case] expected: &[u8]) {
+    let imm16 = Immediate16::from_i16(imm16);
+    let instr = unsafe { and::encode_ax_imm16(imm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(0, &[0x25, 0x00, 0x00, 0x00, 0x00])]
+#[case(1, &[0x25, 0x01, 0x00, 0x00, 0x00])]
+fn test_and_eax_imm32(#[case] imm32: i32, #[case] expected: &[u8]) {
+    let imm32 = Immediate32::from_i32(imm32);
+    let instr = unsafe { and::encode_eax_imm32(imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(0, &[0x48, 0x25, 0x00, 0x00, 0x00, 0x00])]
+#[case(1, &[0x48, 0x25, 0x01, 0x00, 0x00, 0x00])]
+fn test_and_rax_imm32(#[case] imm32: i32, #[case] expected: &[u8]) {
+    let imm32 = Immediate32::from_i32(imm32);
+    let instr = unsafe { and::encode_rax_imm32(imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AL }, Immediate8::from_i8(1), &[0x80, 0xE0, 0x01])]
+fn test_and_rm8_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm8_imm8(gpr_or_memory, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, Immediate16::from_i16(1), &[0x66, 0x81, 0xE0, 0x01, 0x00])]
+fn test_and_rm16_imm16(#[case] gpr_or_memory: GPROrMemory, #[case] imm16: Immediate16, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm16_imm16(gpr_or_memory, imm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, Immediate32::from_i32(1), &[0x81, 0xE0, 0x01, 0x00, 0x00, 0x00])]
+fn test_and_rm32_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm32_imm32(gpr_or_memory, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate32::from_i32(1), &[0x48, 0x81, 0xE0, 0x01, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate32::from_i32(5), &[0x48, 0x81, 0x65, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(-1), &[0x49, 0x81, 0x24, 0x24, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, Immediate32::from_i32(1), &[0x48, 0x81, 0x25, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])]
+fn test_and_rm64_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm64_imm32(gpr_or_memory, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, Immediate8::from_i8(1), &[0x66, 0x83, 0xE0, 0x01])]
+fn test_and_rm16_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm16_imm8(gpr_or_memory, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, Immediate8::from_i8(1), &[0x83, 0xE0, 0x01])]
+fn test_and_rm32_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm32_imm8(gpr_or_memory, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate8::from_i8(1), &[0x48, 0x83, 0xE0, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate8::from_i8(5), &[0x48, 0x83, 0x65, 0xF8, 0x05])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::R9, scale: Scale::Scale8, offset: Offset::None } }, Immediate8::from_i8(1), &[0x4A, 0x83, 0x24, 0xC8, 0x01])]
+fn test_and_rm64_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm64_imm8(gpr_or_memory, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AL }, GPR::BL, &[0x20, 0xD8])]
+fn test_and_rm8_reg8(#[case] rm8: GPROrMemory, #[case] reg8: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm8_reg8(rm8, reg8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, GPR::BX, &[0x66, 0x21, 0xD8])]
+fn test_and_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, GPR::EBX, &[0x21, 0xD8])]
+fn test_and_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, GPR::RBX, &[0x48, 0x21, 0xD8])]
+fn test_and_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::AL, GPROrMemory::GPR { gpr: GPR::BL }, &[0x22, 0xC3])]
+fn test_and_reg8_rm8(#[case] reg8: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_reg8_rm8(reg8, rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::AX, GPROrMemory::GPR { gpr: GPR::BX }, &[0x66, 0x23, 0xC3])]
+fn test_and_reg16_rm16(#[case] reg16: GPR, #[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_reg16_rm16(reg16, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::EBX }, &[0x23, 0xC3])]
+fn test_and_reg32_rm32(#[case] reg32: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_reg32_rm32(reg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::RBX }, &[0x48, 0x23, 0xC3])]
+fn test_and_reg64_rm64(#[case] reg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { and::encode_reg64_rm64(reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
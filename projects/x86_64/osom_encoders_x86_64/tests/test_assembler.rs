@@ -0,0 +1,257 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::assembler::*;
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[test]
+fn test_assembler_jmp_forward() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.jmp(label);
+    assembler.emit(unsafe { nop::encode() });
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code, [0xEB, 0x01, 0x90]);
+}
+
+#[test]
+fn test_assembler_jmp_backward() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.bind_label(label).unwrap();
+    assembler.emit(unsafe { nop::encode() });
+    assembler.jmp(label);
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code, [0x90, 0xEB, 0xFD]);
+}
+
+#[test]
+fn test_assembler_call() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.call(label);
+    assembler.emit(unsafe { ret::encode() });
+    assembler.bind_label(label).unwrap();
+    assembler.emit(unsafe { ret::encode() });
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code, [0xE8, 0x01, 0x00, 0x00, 0x00, 0xC3, 0xC3]);
+}
+
+#[test]
+fn test_assembler_jcc() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.jcc(Condition::E, label);
+    assembler.jcc(Condition::NE, label);
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code, [0x74, 0x02, 0x75, 0x00]);
+}
+
+#[test]
+fn test_assembler_position() {
+    let mut assembler = Assembler::new();
+    assert_eq!(assembler.position(), 0);
+    let label = assembler.create_label();
+    assembler.emit(unsafe { nop::encode() });
+    assert_eq!(assembler.position(), 1);
+    assembler.jmp(label);
+    assert_eq!(assembler.position(), 3);
+    assembler.jcc(Condition::E, label);
+    assert_eq!(assembler.position(), 5);
+    assembler.emit(vec![0x90; 200]);
+    assert_eq!(assembler.position(), 205);
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code.len(), 212);
+}
+
+#[rstest]
+#[case::short(127, &[0xEB, 0x7F])]
+#[case::long(128, &[0xE9, 0x80, 0x00, 0x00, 0x00])]
+#[case::long(1000, &[0xE9, 0xE8, 0x03, 0x00, 0x00])]
+fn test_assembler_relax_jmp_forward(#[case] distance: usize, #[case] expected: &[u8]) {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.jmp(label);
+    assembler.emit(vec![0x90; distance]);
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(&code[..expected.len()], expected);
+    assert_eq!(code.len(), expected.len() + distance);
+}
+
+#[rstest]
+#[case::short(126, &[0xEB, 0x80])]
+#[case::long(127, &[0xE9, 0x7C, 0xFF, 0xFF, 0xFF])]
+fn test_assembler_relax_jmp_backward(#[case] distance: usize, #[case] expected: &[u8]) {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.bind_label(label).unwrap();
+    assembler.emit(vec![0x90; distance]);
+    assembler.jmp(label);
+    let code = assembler.finalize().unwrap();
+    assert_eq!(&code[distance..], expected);
+}
+
+#[rstest]
+#[case::short(127, &[0x7C, 0x7F])]
+#[case::long(128, &[0x0F, 0x8C, 0x80, 0x00, 0x00, 0x00])]
+fn test_assembler_relax_jcc(#[case] distance: usize, #[case] expected: &[u8]) {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.jcc(Condition::L, label);
+    assembler.emit(vec![0x90; distance]);
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(&code[..expected.len()], expected);
+}
+
+#[test]
+fn test_assembler_relax_cascade() {
+    // The second jump does not fit into rel8, and after its promotion
+    // the first one does not fit either.
+    let mut assembler = Assembler::new();
+    let first = assembler.create_label();
+    let second = assembler.create_label();
+    assembler.jmp(first);
+    assembler.jmp(second);
+    assembler.emit([0x90; 123]);
+    assembler.bind_label(first).unwrap();
+    assembler.emit([0x90; 5]);
+    assembler.bind_label(second).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(
+        &code[..10],
+        &[0xE9, 0x80, 0x00, 0x00, 0x00, 0xE9, 0x80, 0x00, 0x00, 0x00]
+    );
+    assert_eq!(code.len(), 10 + 128);
+}
+
+#[test]
+fn test_assembler_relax_with_rip_relative() {
+    let mut assembler = Assembler::new();
+    let short_target = assembler.create_label();
+    let long_target = assembler.create_label();
+    assembler.lea(GPR::RAX, long_target).unwrap();
+    assembler.jmp(short_target);
+    assembler.call(long_target);
+    assembler.bind_label(short_target).unwrap();
+    assembler.jmp(long_target);
+    assembler.emit([0x90; 200]);
+    assembler.bind_label(long_target).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(
+        &code[..19],
+        &[
+            0x48, 0x8D, 0x05, 0xD4, 0x00, 0x00, 0x00, 0xEB, 0x05, 0xE8, 0xCD, 0x00, 0x00, 0x00, 0xE9, 0xC8, 0x00, 0x00,
+            0x00
+        ]
+    );
+    assert_eq!(code.len(), 19 + 200);
+}
+
+#[test]
+fn test_assembler_lea() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.lea(GPR::RCX, label).unwrap();
+    assembler.lea(GPR::R9D, label).unwrap();
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(
+        code,
+        [
+            0x48, 0x8D, 0x0D, 0x07, 0x00, 0x00, 0x00, 0x44, 0x8D, 0x0D, 0x00, 0x00, 0x00, 0x00
+        ]
+    );
+}
+
+#[test]
+fn test_assembler_lea_invalid_register() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    let result = assembler.lea(GPR::AL, label);
+    assert_eq!(result, Err(AssemblerError::UnsupportedRegisterSize { gpr: GPR::AL }));
+}
+
+#[test]
+fn test_assembler_mov() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.bind_label(label).unwrap();
+    assembler.emit([0x01, 0x02]);
+    assembler.mov(GPR::RAX, label).unwrap();
+    assembler.mov(GPR::BL, label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(
+        code,
+        [
+            0x01, 0x02, 0x48, 0x8B, 0x05, 0xF7, 0xFF, 0xFF, 0xFF, 0x8A, 0x1D, 0xF1, 0xFF, 0xFF, 0xFF
+        ]
+    );
+}
+
+#[test]
+fn test_assembler_rip_relative_with_immediate() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler
+        .emit_rip_relative(label, |memory| {
+            safe::mov::encode_rm32_imm32(GPROrMemory::Memory { memory }, Immediate32::from_i32(5))
+        })
+        .unwrap();
+    assembler.emit(unsafe { ret::encode() });
+    assembler.bind_label(label).unwrap();
+    let code = assembler.finalize().unwrap();
+    assert_eq!(code, [0xC7, 0x05, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0xC3]);
+}
+
+#[test]
+fn test_assembler_rip_relative_without_memory() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    let result = assembler.emit_rip_relative(label, |_| safe::ret::encode());
+    assert_eq!(result, Err(AssemblerError::MissingDisplacement));
+}
+
+#[test]
+fn test_assembler_unbound_label() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.jmp(label);
+    assert_eq!(assembler.finalize(), Err(AssemblerError::UnboundLabel { label }));
+}
+
+#[test]
+fn test_assembler_unreferenced_unbound_label() {
+    let mut assembler = Assembler::new();
+    let _ = assembler.create_label();
+    assembler.emit(unsafe { ret::encode() });
+    assert_eq!(assembler.finalize().unwrap(), [0xC3]);
+}
+
+#[test]
+fn test_assembler_label_already_bound() {
+    let mut assembler = Assembler::new();
+    let label = assembler.create_label();
+    assembler.bind_label(label).unwrap();
+    assert_eq!(
+        assembler.bind_label(label),
+        Err(AssemblerError::LabelAlreadyBound { label })
+    );
+}
+
+#[test]
+fn test_assembler_foreign_label() {
+    let mut other = Assembler::new();
+    let _ = other.create_label();
+    let label = other.create_label();
+
+    let mut assembler = Assembler::new();
+    let _ = assembler.create_label();
+    assert_eq!(assembler.bind_label(label), Err(AssemblerError::InvalidLabel { label }));
+    assembler.jmp(label);
+    assert_eq!(assembler.finalize(), Err(AssemblerError::InvalidLabel { label }));
+}
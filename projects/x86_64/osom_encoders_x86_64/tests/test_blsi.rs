@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EBX, GPROrMemory::GPR { gpr: GPR::ECX }, &[0xC4, 0xE2, 0x60, 0xF3, 0xD9])]
+#[case::gpr(GPR::R9D, GPROrMemory::GPR { gpr: GPR::R10D }, &[0xC4, 0xC2, 0x30, 0xF3, 0xDA])]
+#[case::memory(GPR::R15D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x00, 0xF3, 0x18])]
+#[case::memory(GPR::ESI, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0xC2, 0x48, 0xF3, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x68, 0xF3, 0x9D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_blsi_vreg32_rm32(#[case] vreg32: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { blsi::encode_vreg32_rm32(vreg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RBX, GPROrMemory::GPR { gpr: GPR::RCX }, &[0xC4, 0xE2, 0xE0, 0xF3, 0xD9])]
+#[case::gpr(GPR::R9, GPROrMemory::GPR { gpr: GPR::R10 }, &[0xC4, 0xC2, 0xB0, 0xF3, 0xDA])]
+#[case::memory(GPR::R15, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x80, 0xF3, 0x18])]
+#[case::memory(GPR::RSI, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0xC2, 0xC8, 0xF3, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0xE8, 0xF3, 0x9D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_blsi_vreg64_rm64(#[case] vreg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { blsi::encode_vreg64_rm64(vreg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,29 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::AX, GPROrMemory::GPR { gpr: GPR::CX }, &[0x66, 0x0F, 0xBC, 0xC1])]
+#[case::memory(GPR::R8W, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0x44, 0x0F, 0xBC, 0x00])]
+fn test_bsf_reg16_rm16(#[case] reg16: GPR, #[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { bsf::encode_reg16_rm16(reg16, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::ECX }, &[0x0F, 0xBC, 0xC1])]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::R9D }, &[0x45, 0x0F, 0xBC, 0xC1])]
+#[case::memory(GPR::EBX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-4) } }, &[0x0F, 0xBC, 0x5D, 0xFC])]
+fn test_bsf_reg32_rm32(#[case] reg32: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { bsf::encode_reg32_rm32(reg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::RBX }, &[0x48, 0x0F, 0xBC, 0xC3])]
+#[case::memory(GPR::R15, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, &[0x4D, 0x0F, 0xBC, 0x3C, 0x24])]
+fn test_bsf_reg64_rm64(#[case] reg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { bsf::encode_reg64_rm64(reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
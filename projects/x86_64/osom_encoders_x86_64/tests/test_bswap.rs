@@ -0,0 +1,21 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, &[0x0F, 0xC8])]
+#[case::gpr(GPR::EDI, &[0x0F, 0xCF])]
+#[case::gpr(GPR::R9D, &[0x41, 0x0F, 0xC9])]
+fn test_bswap_reg32(#[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { bswap::encode_reg32(reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, &[0x48, 0x0F, 0xC8])]
+#[case::gpr(GPR::R15, &[0x49, 0x0F, 0xCF])]
+fn test_bswap_reg64(#[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { bswap::encode_reg64(reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,51 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, GPR::CX, &[0x66, 0x0F, 0xA3, 0xC8])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::R9W, &[0x66, 0x44, 0x0F, 0xA3, 0x08])]
+fn test_bt_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, GPR::ECX, &[0x0F, 0xA3, 0xC8])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R8D }, GPR::EDX, &[0x41, 0x0F, 0xA3, 0xD0])]
+fn test_bt_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, GPR::RCX, &[0x48, 0x0F, 0xA3, 0xC8])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(8) } }, GPR::R11, &[0x4C, 0x0F, 0xA3, 0x5F, 0x08])]
+fn test_bt_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BX }, Immediate8::from_u8(15), &[0x66, 0x0F, 0xBA, 0xE3, 0x0F])]
+fn test_bt_rm16_imm8(#[case] rm16: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm16_imm8(rm16, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10D }, Immediate8::from_u8(1), &[0x41, 0x0F, 0xBA, 0xE2, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(4) } }, Immediate8::from_u8(31), &[0x0F, 0xBA, 0x64, 0x24, 0x04, 0x1F])]
+fn test_bt_rm32_imm8(#[case] rm32: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm32_imm8(rm32, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate8::from_u8(63), &[0x48, 0x0F, 0xBA, 0xE0, 0x3F])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_u8(5), &[0x48, 0x0F, 0xBA, 0x20, 0x05])]
+fn test_bt_rm64_imm8(#[case] rm64: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { bt::encode_rm64_imm8(rm64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
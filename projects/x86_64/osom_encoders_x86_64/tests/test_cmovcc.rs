@@ -0,0 +1,44 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(Condition::E, GPR::AX, GPROrMemory::GPR { gpr: GPR::BX }, &[0x66, 0x0F, 0x44, 0xC3])]
+#[case::memory(Condition::NO, GPR::SI, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::None } }, &[0x66, 0x0F, 0x41, 0x37])]
+fn test_cmovcc_reg16_rm16(
+    #[case] condition: Condition,
+    #[case] reg16: GPR,
+    #[case] rm16: GPROrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { cmovcc::encode_reg16_rm16(condition, reg16, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(Condition::NE, GPR::ECX, GPROrMemory::GPR { gpr: GPR::EDX }, &[0x0F, 0x45, 0xCA])]
+#[case::memory(Condition::A, GPR::R12D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, &[0x45, 0x0F, 0x47, 0x65, 0x00])]
+fn test_cmovcc_reg32_rm32(
+    #[case] condition: Condition,
+    #[case] reg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { cmovcc::encode_reg32_rm32(condition, reg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(Condition::L, GPR::R8, GPROrMemory::GPR { gpr: GPR::R9 }, &[0x4D, 0x0F, 0x4C, 0xC1])]
+#[case::gpr(Condition::S, GPR::RDX, GPROrMemory::GPR { gpr: GPR::RCX }, &[0x48, 0x0F, 0x48, 0xD1])]
+#[case::memory(Condition::GE, GPR::RAX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, &[0x48, 0x0F, 0x4D, 0x45, 0xF8])]
+fn test_cmovcc_reg64_rm64(
+    #[case] condition: Condition,
+    #[case] reg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { cmovcc::encode_reg64_rm64(condition, reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
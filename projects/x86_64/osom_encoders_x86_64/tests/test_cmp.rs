@@ -89,6 +89,9 @@ fn test_cmp_rm32_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immedi
 
 #[rstest]
 #[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate32::from_i32(1), &[0x48, 0x81, 0xF8, 0x01, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate32::from_i32(5), &[0x48, 0x81, 0x7D, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(-1), &[0x49, 0x81, 0x3C, 0x24, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, Immediate32::from_i32(1), &[0x48, 0x81, 0x3D, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])]
 fn test_cmp_rm64_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
     let instr = unsafe { cmp::encode_rm64_imm32(gpr_or_memory, imm32) };
     assert_eq!(instr.as_slice(), expected);
@@ -166,6 +169,8 @@ fn test_cmp_rm32_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediat
 
 #[rstest]
 #[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate8::from_i8(1), &[0x48, 0x83, 0xF8, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate8::from_i8(5), &[0x48, 0x83, 0x7D, 0xF8, 0x05])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::R9, scale: Scale::Scale8, offset: Offset::None } }, Immediate8::from_i8(1), &[0x4A, 0x83, 0x3C, 0xC8, 0x01])]
 fn test_cmp_rm64_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
     let instr = unsafe { cmp::encode_rm64_imm8(gpr_or_memory, imm8) };
     assert_eq!(instr.as_slice(), expected);
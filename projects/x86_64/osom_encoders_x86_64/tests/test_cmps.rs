@@ -0,0 +1,31 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::byte(unsafe { cmps::encode_b() }, &[0xA6])]
+#[case::word(unsafe { cmps::encode_w() }, &[0x66, 0xA7])]
+#[case::dword(unsafe { cmps::encode_d() }, &[0xA7])]
+#[case::qword(unsafe { cmps::encode_q() }, &[0x48, 0xA7])]
+fn test_cmps(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { cmps::encode_repe_b() }, &[0xF3, 0xA6])]
+#[case::word(unsafe { cmps::encode_repe_w() }, &[0xF3, 0x66, 0xA7])]
+#[case::dword(unsafe { cmps::encode_repe_d() }, &[0xF3, 0xA7])]
+#[case::qword(unsafe { cmps::encode_repe_q() }, &[0xF3, 0x48, 0xA7])]
+fn test_cmps_repe(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { cmps::encode_repne_b() }, &[0xF2, 0xA6])]
+#[case::word(unsafe { cmps::encode_repne_w() }, &[0xF2, 0x66, 0xA7])]
+#[case::dword(unsafe { cmps::encode_repne_d() }, &[0xF2, 0xA7])]
+#[case::qword(unsafe { cmps::encode_repne_q() }, &[0xF2, 0x48, 0xA7])]
+fn test_cmps_repne(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,47 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::None } }, GPR::SIL, &[0x40, 0x0F, 0xB0, 0x37])]
+fn test_cmpxchg_rm8_reg8(#[case] rm8: GPROrMemory, #[case] reg8: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_rm8_reg8(rm8, reg8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::CX, &[0x66, 0x0F, 0xB1, 0x08])]
+fn test_cmpxchg_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, GPR::EDX, &[0x0F, 0xB1, 0xD1])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::None } }, GPR::ESI, &[0x0F, 0xB1, 0x37])]
+fn test_cmpxchg_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, GPR::RBX, &[0x49, 0x0F, 0xB1, 0x1C, 0x24])]
+fn test_cmpxchg_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::CX, &[0xF0, 0x66, 0x0F, 0xB1, 0x08])]
+fn test_cmpxchg_lock_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_lock_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, GPR::RBX, &[0xF0, 0x49, 0x0F, 0xB1, 0x1C, 0x24])]
+fn test_cmpxchg_lock_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg::encode_lock_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
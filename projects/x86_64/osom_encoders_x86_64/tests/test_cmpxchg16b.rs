@@ -0,0 +1,19 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::memory(Memory::Based { base: GPR::RDI, offset: Offset::None }, &[0x48, 0x0F, 0xC7, 0x0F])]
+#[case::memory(Memory::RelativeToRIP { offset: Offset::from_i32(32) }, &[0x48, 0x0F, 0xC7, 0x0D, 0x20, 0x00, 0x00, 0x00])]
+fn test_cmpxchg16b_mem128(#[case] mem128: Memory, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg16b::encode_mem128(mem128) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(Memory::Based { base: GPR::RDI, offset: Offset::None }, &[0xF0, 0x48, 0x0F, 0xC7, 0x0F])]
+fn test_cmpxchg16b_lock_mem128(#[case] mem128: Memory, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg16b::encode_lock_mem128(mem128) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,19 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::memory(Memory::Based { base: GPR::RDI, offset: Offset::None }, &[0x0F, 0xC7, 0x0F])]
+#[case::memory(Memory::Based { base: GPR::R13, offset: Offset::from_i8(16) }, &[0x41, 0x0F, 0xC7, 0x4D, 0x10])]
+fn test_cmpxchg8b_mem64(#[case] mem64: Memory, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg8b::encode_mem64(mem64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(Memory::Based { base: GPR::RDI, offset: Offset::None }, &[0xF0, 0x0F, 0xC7, 0x0F])]
+fn test_cmpxchg8b_lock_mem64(#[case] mem64: Memory, #[case] expected: &[u8]) {
+    let instr = unsafe { cmpxchg8b::encode_lock_mem64(mem64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,49 @@
+use osom_encoders_x86_64::models::*;
+
+#[test]
+fn test_condition_codes() {
+    for (code, condition) in Condition::ALL.into_iter().enumerate() {
+        let code = u8::try_from(code).unwrap();
+        assert_eq!(condition.code(), code);
+        assert_eq!(Condition::from_code(code), Some(condition));
+        assert_eq!(Condition::from_name(condition.name()), Some(condition));
+    }
+    assert_eq!(Condition::from_code(16), None);
+}
+
+#[test]
+fn test_condition_negate() {
+    for condition in Condition::ALL {
+        assert_ne!(condition.negate(), condition);
+        assert_eq!(condition.negate().negate(), condition);
+        assert_eq!(condition.negate().is_signed(), condition.is_signed());
+        assert_eq!(condition.negate().is_unsigned(), condition.is_unsigned());
+    }
+    assert_eq!(Condition::E.negate(), Condition::NE);
+    assert_eq!(Condition::A.negate(), Condition::BE);
+    assert_eq!(Condition::L.negate(), Condition::GE);
+    assert_eq!(Condition::P.negate(), Condition::PO);
+}
+
+#[test]
+fn test_condition_classification() {
+    let unsigned: Vec<_> = Condition::ALL.into_iter().filter(|c| c.is_unsigned()).collect();
+    let signed: Vec<_> = Condition::ALL.into_iter().filter(|c| c.is_signed()).collect();
+    assert_eq!(unsigned, [Condition::B, Condition::AE, Condition::BE, Condition::A]);
+    assert_eq!(signed, [Condition::L, Condition::GE, Condition::LE, Condition::G]);
+    assert!(!Condition::E.is_signed() && !Condition::E.is_unsigned());
+}
+
+#[test]
+fn test_condition_aliases() {
+    assert_eq!(Condition::Z, Condition::E);
+    assert_eq!(Condition::C, Condition::B);
+    assert_eq!(Condition::NC, Condition::AE);
+    assert_eq!(Condition::PE, Condition::P);
+    assert_eq!(Condition::NLE, Condition::G);
+    assert_eq!(Condition::from_name("nz"), Some(Condition::NE));
+    assert_eq!(Condition::from_name("nae"), Some(Condition::B));
+    assert_eq!(Condition::from_name("po"), Some(Condition::NP));
+    assert_eq!(Condition::from_name("x"), None);
+    assert_eq!(Condition::from_name("E"), None);
+}
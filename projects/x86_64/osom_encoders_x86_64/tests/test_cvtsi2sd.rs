@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(XMM::XMM0, GPROrMemory::GPR { gpr: GPR::EAX }, &[0xF2, 0x0F, 0x2A, 0xC0])]
+#[case::gpr(XMM::XMM9, GPROrMemory::GPR { gpr: GPR::R10D }, &[0xF2, 0x45, 0x0F, 0x2A, 0xCA])]
+#[case::memory(XMM::XMM2, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF2, 0x0F, 0x2A, 0x10])]
+#[case::memory(XMM::XMM11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xF2, 0x45, 0x0F, 0x2A, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xF2, 0x41, 0x0F, 0x2A, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_cvtsi2sd_xmm_rm32(#[case] xmm: XMM, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { cvtsi2sd::encode_xmm_rm32(xmm, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(XMM::XMM0, GPROrMemory::GPR { gpr: GPR::RAX }, &[0xF2, 0x48, 0x0F, 0x2A, 0xC0])]
+#[case::gpr(XMM::XMM9, GPROrMemory::GPR { gpr: GPR::R10 }, &[0xF2, 0x4D, 0x0F, 0x2A, 0xCA])]
+#[case::memory(XMM::XMM2, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF2, 0x48, 0x0F, 0x2A, 0x10])]
+#[case::memory(XMM::XMM11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xF2, 0x4D, 0x0F, 0x2A, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xF2, 0x49, 0x0F, 0x2A, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_cvtsi2sd_xmm_rm64(#[case] xmm: XMM, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { cvtsi2sd::encode_xmm_rm64(xmm, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(GPR::EAX, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xF2, 0x0F, 0x2C, 0xC1])]
+#[case::xmm(GPR::R8D, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xF2, 0x45, 0x0F, 0x2C, 0xC7])]
+#[case::memory(GPR::EDX, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF2, 0x0F, 0x2C, 0x10])]
+#[case::memory(GPR::R11D, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xF2, 0x45, 0x0F, 0x2C, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::ECX, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xF2, 0x41, 0x0F, 0x2C, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_cvttsd2si_reg32_xmmm64(#[case] reg32: GPR, #[case] xmmm64: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { cvttsd2si::encode_reg32_xmmm64(reg32, xmmm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(GPR::RAX, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xF2, 0x48, 0x0F, 0x2C, 0xC1])]
+#[case::xmm(GPR::R8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xF2, 0x4D, 0x0F, 0x2C, 0xC7])]
+#[case::memory(GPR::RDX, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF2, 0x48, 0x0F, 0x2C, 0x10])]
+#[case::memory(GPR::R11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xF2, 0x4D, 0x0F, 0x2C, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::RCX, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xF2, 0x49, 0x0F, 0x2C, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_cvttsd2si_reg64_xmmm64(#[case] reg64: GPR, #[case] xmmm64: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { cvttsd2si::encode_reg64_xmmm64(reg64, xmmm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
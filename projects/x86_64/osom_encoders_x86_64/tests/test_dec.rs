@@ -0,0 +1,33 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::DH }, &[0xFE, 0xCE])]
+fn test_dec_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { dec::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, &[0x66, 0xFF, 0xC8])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(2) } }, &[0x66, 0x41, 0xFF, 0x4C, 0x24, 0x02])]
+fn test_dec_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { dec::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9D }, &[0x41, 0xFF, 0xC9])]
+fn test_dec_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { dec::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RBX }, &[0x48, 0xFF, 0xCB])]
+fn test_dec_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { dec::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
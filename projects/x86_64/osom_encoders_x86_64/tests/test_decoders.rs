@@ -0,0 +1,779 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::decoders::*;
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+const GPRS_64: [GPR; 16] = [
+    GPR::RAX,
+    GPR::RCX,
+    GPR::RDX,
+    GPR::RBX,
+    GPR::RSP,
+    GPR::RBP,
+    GPR::RSI,
+    GPR::RDI,
+    GPR::R8,
+    GPR::R9,
+    GPR::R10,
+    GPR::R11,
+    GPR::R12,
+    GPR::R13,
+    GPR::R14,
+    GPR::R15,
+];
+
+const GPRS_8: [GPR; 20] = [
+    GPR::AL,
+    GPR::CL,
+    GPR::DL,
+    GPR::BL,
+    GPR::SPL,
+    GPR::BPL,
+    GPR::SIL,
+    GPR::DIL,
+    GPR::R8B,
+    GPR::R9B,
+    GPR::R10B,
+    GPR::R11B,
+    GPR::R12B,
+    GPR::R13B,
+    GPR::R14B,
+    GPR::R15B,
+    GPR::AH,
+    GPR::CH,
+    GPR::DH,
+    GPR::BH,
+];
+
+fn assert_round_trip(instruction: Instruction) {
+    let encoded = instruction.encode().unwrap();
+    let expected = Ok((instruction, encoded.as_slice().len()));
+    assert_eq!(
+        decode(encoded.as_slice()),
+        expected,
+        "bytes: {:02X?}",
+        encoded.as_slice()
+    );
+}
+
+/// All memory operands in the form returned by the decoders.
+fn all_memories() -> Vec<Memory> {
+    let offsets = [
+        Offset::None,
+        Offset::from_i8(-8),
+        Offset::from_i8(127),
+        Offset::from_i32(0x1234_5678),
+    ];
+    let scales = [Scale::Scale1, Scale::Scale2, Scale::Scale4, Scale::Scale8];
+    let mut result = Vec::new();
+    for base in GPRS_64 {
+        for offset in offsets {
+            result.push(Memory::Based { base, offset });
+        }
+    }
+
+    for index in GPRS_64.into_iter().filter(|gpr| *gpr != GPR::RSP) {
+        for scale in scales {
+            result.push(Memory::Scaled {
+                index,
+                scale,
+                offset: Offset::from_i32(-4),
+            });
+            for base in [GPR::RAX, GPR::RSP, GPR::RBP, GPR::R12, GPR::R13, GPR::R15] {
+                for offset in offsets {
+                    result.push(Memory::BasedAndScaled {
+                        base,
+                        index,
+                        scale,
+                        offset,
+                    });
+                }
+            }
+        }
+    }
+
+    result.push(Memory::RelativeToRIP {
+        offset: Offset::from_i32(0),
+    });
+    result.push(Memory::RelativeToRIP {
+        offset: Offset::from_i32(-1000),
+    });
+    result
+}
+
+#[rstest]
+#[case::zo(Instruction::Nop)]
+#[case::zo(Instruction::Cpuid)]
+#[case::zo(Instruction::Syscall)]
+#[case::zo(Instruction::Int3)]
+#[case::i(Instruction::RetImm16 { imm16: Immediate16::from_u16(16) })]
+#[case::i(Instruction::AddAlImm8 { imm8: Immediate8::from_i8(-1) })]
+#[case::i(Instruction::AddAxImm16 { imm16: Immediate16::from_i16(1234) })]
+#[case::i(Instruction::AddEaxImm32 { imm32: Immediate32::from_i32(-5) })]
+#[case::i(Instruction::AddRaxImm32 { imm32: Immediate32::from_i32(12345678) })]
+#[case::i(Instruction::PushImm16 { imm16: Immediate16::from_i16(-2) })]
+#[case::i(Instruction::PushImm32 { imm32: Immediate32::from_i32(-2) })]
+#[case::i(Instruction::JccEImm32 { imm32: Immediate32::from_i32(-100) })]
+#[case::i(Instruction::JmpImm8 { imm8: Immediate8::from_i8(3) })]
+#[case::mi(Instruction::MovRm8Imm8 { rm8: GPR::SPL.into(), imm8: Immediate8::from_i8(1) })]
+#[case::mi(Instruction::MovRm16Imm16 { rm16: GPR::R9W.into(), imm16: Immediate16::from_i16(1) })]
+#[case::mi(Instruction::MovRm64Imm32 { rm64: GPR::R15.into(), imm32: Immediate32::from_i32(1) })]
+#[case::mi(Instruction::CmpRm16Imm8 { rm16: GPR::AX.into(), imm8: Immediate8::from_i8(1) })]
+#[case::mi(Instruction::SubRm32Imm8 { rm32: GPR::R12D.into(), imm8: Immediate8::from_i8(1) })]
+#[case::mi(Instruction::XorRm64Imm8 { rm64: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) }.into(), imm8: Immediate8::from_i8(1) })]
+#[case::oi(Instruction::MovReg8Imm8 { reg8: GPR::AH, imm8: Immediate8::from_i8(1) })]
+#[case::oi(Instruction::MovReg8Imm8 { reg8: GPR::SIL, imm8: Immediate8::from_i8(1) })]
+#[case::oi(Instruction::MovReg16Imm16 { reg16: GPR::R8W, imm16: Immediate16::from_i16(1) })]
+#[case::oi(Instruction::MovReg32Imm32 { reg32: GPR::EDI, imm32: Immediate32::from_i32(1) })]
+#[case::oi(Instruction::MovReg64Imm64 { reg64: GPR::R13, imm64: Immediate64::from_i64(-1) })]
+#[case::mr(Instruction::MovRm8Reg8 { rm8: GPR::AH.into(), reg8: GPR::BH })]
+#[case::mr(Instruction::MovRm8Reg8 { rm8: GPR::DIL.into(), reg8: GPR::R8B })]
+#[case::mr(Instruction::AddReg16Rm16 { reg16: GPR::R11W, rm16: GPR::SP.into() })]
+#[case::mr(Instruction::CmpReg32Rm32 { reg32: GPR::EAX, rm32: Memory::RelativeToRIP { offset: Offset::from_i32(8) }.into() })]
+#[case::mr(Instruction::SubRm64Reg64 { rm64: Memory::Scaled { index: GPR::R9, scale: Scale::Scale8, offset: Offset::from_i32(16) }.into(), reg64: GPR::RSP })]
+#[case::mr(Instruction::LeaReg16Mem64 { reg16: GPR::BX, mem64: Memory::Based { base: GPR::R13, offset: Offset::None } })]
+#[case::mr(Instruction::LeaReg64Mem64 { reg64: GPR::R10, mem64: Memory::BasedAndScaled { base: GPR::R12, index: GPR::R13, scale: Scale::Scale2, offset: Offset::from_i32(-1) } })]
+#[case::m(Instruction::JmpRm64 { rm64: GPR::R11.into() })]
+#[case::m(Instruction::CallRm64 { rm64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) }.into() })]
+#[case::m(Instruction::PushRm64 { rm64: Memory::RelativeToRIP { offset: Offset::from_i32(0) }.into() })]
+#[case::m(Instruction::PopRm64 { rm64: GPR::RAX.into() })]
+#[case::m(Instruction::MulRm8 { rm8: GPR::AH.into() })]
+#[case::m(Instruction::IdivRm64 { rm64: Memory::RelativeToRIP { offset: Offset::from_i32(4) }.into() })]
+#[case::m(Instruction::NotRm8 { rm8: GPR::DIL.into() })]
+#[case::m(Instruction::DecRm16 { rm16: GPR::R14W.into() })]
+#[case::i(Instruction::TestAlImm8 { imm8: Immediate8::from_i8(-128) })]
+#[case::mi(Instruction::TestRm64Imm32 { rm64: GPR::R10.into(), imm32: Immediate32::from_i32(-1) })]
+#[case::mi(Instruction::AndRm32Imm8 { rm32: GPR::ESP.into(), imm8: Immediate8::from_i8(-16) })]
+#[case::mr(Instruction::TestRm8Reg8 { rm8: GPR::AH.into(), reg8: GPR::CH })]
+#[case::mr(Instruction::MovzxReg32Rm8 { reg32: GPR::EAX, rm8: GPR::AH.into() })]
+#[case::mr(Instruction::MovzxReg32Rm8 { reg32: GPR::EAX, rm8: GPR::SPL.into() })]
+#[case::mr(Instruction::MovzxReg16Rm8 { reg16: GPR::R10W, rm8: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() })]
+#[case::mr(Instruction::MovsxReg64Rm16 { reg64: GPR::RDX, rm16: GPR::R13W.into() })]
+#[case::mr(Instruction::MovsxdReg64Rm32 { reg64: GPR::R9, rm32: GPR::EBP.into() })]
+#[case::m(Instruction::SetccERm8 { rm8: GPR::AL.into() })]
+#[case::m(Instruction::SetccNeRm8 { rm8: GPR::SPL.into() })]
+#[case::m(Instruction::SetccGRm8 { rm8: Memory::Based { base: GPR::R12, offset: Offset::from_i8(1) }.into() })]
+#[case::mr(Instruction::CmovccEReg16Rm16 { reg16: GPR::AX, rm16: GPR::BX.into() })]
+#[case::mr(Instruction::CmovccAReg32Rm32 { reg32: GPR::R12D, rm32: Memory::Based { base: GPR::R13, offset: Offset::None }.into() })]
+#[case::mr(Instruction::CmovccLReg64Rm64 { reg64: GPR::R8, rm64: GPR::R9.into() })]
+#[case::mr(Instruction::XchgRm8Reg8 { rm8: GPR::AH.into(), reg8: GPR::BL })]
+#[case::mr(Instruction::XchgRm64Reg64 { rm64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) }.into(), reg64: GPR::R15 })]
+#[case::mr(Instruction::CmpxchgRm16Reg16 { rm16: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), reg16: GPR::CX })]
+#[case::mr(Instruction::XaddRm32Reg32 { rm32: GPR::EAX.into(), reg32: GPR::EBX })]
+#[case::m(Instruction::Cmpxchg8bMem64 { mem64: Memory::Based { base: GPR::R13, offset: Offset::from_i8(16) } })]
+#[case::m(Instruction::Cmpxchg16bMem128 { mem128: Memory::RelativeToRIP { offset: Offset::from_i32(32) } })]
+#[case::mr(Instruction::AdcReg64Rm64 { reg64: GPR::RDX, rm64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) }.into() })]
+#[case::mr(Instruction::ImulReg16Rm16 { reg16: GPR::R8W, rm16: GPR::DI.into() })]
+#[case::rmi(Instruction::ImulReg32Rm32Imm8 { reg32: GPR::EAX, rm32: GPR::R9D.into(), imm8: Immediate8::from_i8(-5) })]
+#[case::rmi(Instruction::ImulReg16Rm16Imm16 { reg16: GPR::BP, rm16: Memory::Based { base: GPR::RSP, offset: Offset::None }.into(), imm16: Immediate16::from_i16(300) })]
+#[case::rmi(Instruction::ImulReg64Rm64Imm32 { reg64: GPR::R15, rm64: GPR::RSI.into(), imm32: Immediate32::from_i32(-70000) })]
+#[case::m(Instruction::ShlRm8One { rm8: GPR::SIL.into() })]
+#[case::m(Instruction::SarRm16Cl { rm16: GPR::R10W.into() })]
+#[case::m(Instruction::RclRm64One { rm64: Memory::Based { base: GPR::R13, offset: Offset::None }.into() })]
+#[case::mi(Instruction::RorRm32Imm8 { rm32: GPR::EBX.into(), imm8: Immediate8::from_u8(200) })]
+#[case::o(Instruction::PushReg64 { reg64: GPR::R12 })]
+#[case::o(Instruction::PopReg64 { reg64: GPR::RBP })]
+#[case::zo(Instruction::MovsB)]
+#[case::zo(Instruction::MovsRepW)]
+#[case::zo(Instruction::StosRepQ)]
+#[case::zo(Instruction::LodsD)]
+#[case::zo(Instruction::CmpsRepeB)]
+#[case::zo(Instruction::ScasRepneQ)]
+#[case::zo(Instruction::ScasW)]
+#[case::mr(Instruction::BtRm32Reg32 { rm32: GPR::EAX.into(), reg32: GPR::ECX })]
+#[case::mi(Instruction::BtsRm64Imm8 { rm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), imm8: Immediate8::from_u8(200) })]
+#[case::mi(Instruction::BtcRm16Imm8 { rm16: GPR::R12W.into(), imm8: Immediate8::from_u8(3) })]
+#[case::mr(Instruction::BsfReg64Rm64 { reg64: GPR::RAX, rm64: GPR::R8.into() })]
+#[case::mr(Instruction::BsrReg16Rm16 { reg16: GPR::DX, rm16: GPR::BX.into() })]
+#[case::o(Instruction::BswapReg32 { reg32: GPR::R11D })]
+#[case::o(Instruction::BswapReg64 { reg64: GPR::RSI })]
+#[case::mr(Instruction::PopcntReg16Rm16 { reg16: GPR::AX, rm16: GPR::BX.into() })]
+#[case::mr(Instruction::LzcntReg32Rm32 { reg32: GPR::R8D, rm32: GPR::ECX.into() })]
+#[case::mr(Instruction::TzcntReg64Rm64 { reg64: GPR::RAX, rm64: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() })]
+#[case::vex(Instruction::AndnReg32Vreg32Rm32 { reg32: GPR::EAX, vreg32: GPR::R11D, rm32: GPR::ECX.into() })]
+#[case::vex(Instruction::BextrReg64Rm64Vreg64 { reg64: GPR::R8, rm64: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into(), vreg64: GPR::RDX })]
+#[case::vex(Instruction::BlsiVreg32Rm32 { vreg32: GPR::R15D, rm32: GPR::EDI.into() })]
+#[case::vex(Instruction::BlsmskVreg64Rm64 { vreg64: GPR::RAX, rm64: GPR::R9.into() })]
+#[case::vex(Instruction::BlsrVreg64Rm64 { vreg64: GPR::RCX, rm64: Memory::Based { base: GPR::RSP, offset: Offset::None }.into() })]
+#[case::vex(Instruction::BzhiReg32Rm32Vreg32 { reg32: GPR::EBX, rm32: GPR::EDX.into(), vreg32: GPR::ESI })]
+#[case::vex(Instruction::PdepReg64Vreg64Rm64 { reg64: GPR::RAX, vreg64: GPR::RBX, rm64: GPR::RCX.into() })]
+#[case::vex(Instruction::PextReg32Vreg32Rm32 { reg32: GPR::R10D, vreg32: GPR::EAX, rm32: GPR::R13D.into() })]
+#[case::vex(Instruction::MulxReg64Vreg64Rm64 { reg64: GPR::R8, vreg64: GPR::R9, rm64: Memory::RelativeToRIP { offset: Offset::from_i32(64) }.into() })]
+#[case::vex(Instruction::RorxReg64Rm64Imm8 { reg64: GPR::RDX, rm64: GPR::R14.into(), imm8: Immediate8::from_u8(13) })]
+#[case::vex(Instruction::SarxReg32Rm32Vreg32 { reg32: GPR::EAX, rm32: GPR::ECX.into(), vreg32: GPR::EDX })]
+#[case::vex(Instruction::ShlxReg64Rm64Vreg64 { reg64: GPR::RAX, rm64: GPR::RCX.into(), vreg64: GPR::R12 })]
+#[case::vex(Instruction::ShrxReg32Rm32Vreg32 { reg32: GPR::EAX, rm32: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(8) }.into(), vreg32: GPR::EBX })]
+#[case::mr(Instruction::AdcxReg32Rm32 { reg32: GPR::EAX, rm32: GPR::EBX.into() })]
+#[case::mr(Instruction::AdoxReg64Rm64 { reg64: GPR::R9, rm64: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() })]
+#[case::sse(Instruction::MovssXmmXmmm32 { xmm: XMM::XMM0, xmmm32: XMM::XMM15.into() })]
+#[case::sse(Instruction::MovssXmmm32Xmm { xmmm32: XMM::XMM9.into(), xmm: XMM::XMM1 })]
+#[case::sse(Instruction::MovsdXmmm64Xmm { xmmm64: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into(), xmm: XMM::XMM12 })]
+#[case::sse(Instruction::AddsdXmmXmmm64 { xmm: XMM::XMM3, xmmm64: Memory::RelativeToRIP { offset: Offset::from_i32(64) }.into() })]
+#[case::sse(Instruction::MulssXmmXmmm32 { xmm: XMM::XMM8, xmmm32: XMM::XMM2.into() })]
+#[case::sse(Instruction::SqrtsdXmmXmmm64 { xmm: XMM::XMM4, xmmm64: XMM::XMM4.into() })]
+#[case::sse(Instruction::UcomissXmmXmmm32 { xmm: XMM::XMM0, xmmm32: XMM::XMM1.into() })]
+#[case::sse(Instruction::UcomisdXmmXmmm64 { xmm: XMM::XMM10, xmmm64: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into() })]
+#[case::sse(Instruction::Cvtsi2sdXmmRm32 { xmm: XMM::XMM1, rm32: GPR::R9D.into() })]
+#[case::sse(Instruction::Cvtsi2sdXmmRm64 { xmm: XMM::XMM13, rm64: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() })]
+#[case::sse(Instruction::Cvttsd2siReg64Xmmm64 { reg64: GPR::R15, xmmm64: XMM::XMM7.into() })]
+#[case::sse(Instruction::MovdXmmRm32 { xmm: XMM::XMM5, rm32: GPR::EAX.into() })]
+#[case::sse(Instruction::MovdRm32Xmm { rm32: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into(), xmm: XMM::XMM11 })]
+#[case::sse(Instruction::MovqXmmRm64 { xmm: XMM::XMM14, rm64: GPR::RSI.into() })]
+#[case::sse(Instruction::MovqRm64Xmm { rm64: GPR::R8.into(), xmm: XMM::XMM0 })]
+#[case::sse(Instruction::MovapsXmmXmmm128 { xmm: XMM::XMM1, xmmm128: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() })]
+#[case::sse(Instruction::MovupsXmmm128Xmm { xmmm128: XMM::XMM9.into(), xmm: XMM::XMM2 })]
+#[case::sse(Instruction::MovdqaXmmm128Xmm { xmmm128: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into(), xmm: XMM::XMM12 })]
+#[case::sse(Instruction::MovdquXmmXmmm128 { xmm: XMM::XMM15, xmmm128: XMM::XMM0.into() })]
+#[case::sse(Instruction::AddpsXmmXmmm128 { xmm: XMM::XMM3, xmmm128: XMM::XMM4.into() })]
+#[case::sse(Instruction::MulpdXmmXmmm128 { xmm: XMM::XMM8, xmmm128: Memory::RelativeToRIP { offset: Offset::from_i32(64) }.into() })]
+#[case::sse(Instruction::PadddXmmXmmm128 { xmm: XMM::XMM0, xmmm128: XMM::XMM1.into() })]
+#[case::sse(Instruction::PsubqXmmXmmm128 { xmm: XMM::XMM10, xmmm128: XMM::XMM11.into() })]
+#[case::sse(Instruction::PxorXmmXmmm128 { xmm: XMM::XMM5, xmmm128: XMM::XMM5.into() })]
+#[case::sse(Instruction::PcmpeqqXmmXmmm128 { xmm: XMM::XMM1, xmmm128: XMM::XMM14.into() })]
+#[case::sse(Instruction::PcmpgtbXmmXmmm128 { xmm: XMM::XMM2, xmmm128: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() })]
+#[case::sse(Instruction::PshufdXmmXmmm128Imm8 { xmm: XMM::XMM0, xmmm128: XMM::XMM1.into(), imm8: Immediate8::from_u8(0x1B) })]
+#[case::sse(Instruction::PshufbXmmXmmm128 { xmm: XMM::XMM9, xmmm128: XMM::XMM10.into() })]
+#[case::sse(Instruction::PunpcklqdqXmmXmmm128 { xmm: XMM::XMM6, xmmm128: XMM::XMM7.into() })]
+#[case::sse(Instruction::PmovmskbReg32Xmm { reg32: GPR::R8D, xmm: XMM::XMM15 })]
+#[case::sse(Instruction::PinsrbXmmR32m8Imm8 { xmm: XMM::XMM1, r32m8: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), imm8: Immediate8::from_u8(15) })]
+#[case::sse(Instruction::PinsrwXmmR32m16Imm8 { xmm: XMM::XMM2, r32m16: GPR::ECX.into(), imm8: Immediate8::from_u8(7) })]
+#[case::sse(Instruction::PinsrqXmmRm64Imm8 { xmm: XMM::XMM11, rm64: GPR::R9.into(), imm8: Immediate8::from_u8(1) })]
+#[case::sse(Instruction::PextrbR32m8XmmImm8 { r32m8: GPR::EAX.into(), xmm: XMM::XMM3, imm8: Immediate8::from_u8(2) })]
+#[case::sse(Instruction::PextrwReg32XmmImm8 { reg32: GPR::EDX, xmm: XMM::XMM8, imm8: Immediate8::from_u8(3) })]
+#[case::sse(Instruction::PextrwR32m16XmmImm8 { r32m16: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) }.into(), xmm: XMM::XMM4, imm8: Immediate8::from_u8(5) })]
+#[case::sse(Instruction::PextrdRm32XmmImm8 { rm32: GPR::R10D.into(), xmm: XMM::XMM0, imm8: Immediate8::from_u8(3) })]
+#[case::sse(Instruction::BlendvpsXmmXmmm128Xmm0 { xmm: XMM::XMM1, xmmm128: XMM::XMM2.into() })]
+#[case::sse(Instruction::PblendvbXmmXmmm128Xmm0 { xmm: XMM::XMM12, xmmm128: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(16) }.into() })]
+#[case::sse(Instruction::PtestXmmXmmm128 { xmm: XMM::XMM0, xmmm128: XMM::XMM15.into() })]
+#[case::avx(Instruction::VaddpsXmmVxmmXmmm128 { xmm: XMM::XMM0, vxmm: XMM::XMM1, xmmm128: XMM::XMM2.into() })]
+#[case::avx(Instruction::VaddpdYmmVymmYmmm256 { ymm: YMM::YMM8, vymm: YMM::YMM15, ymmm256: YMM::YMM9.into() })]
+#[case::avx(Instruction::VmulpsYmmVymmYmmm256 { ymm: YMM::YMM3, vymm: YMM::YMM4, ymmm256: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into() })]
+#[case::avx(Instruction::VpadddXmmVxmmXmmm128 { xmm: XMM::XMM12, vxmm: XMM::XMM0, xmmm128: Memory::RelativeToRIP { offset: Offset::from_i32(64) }.into() })]
+#[case::avx(Instruction::VpxorYmmVymmYmmm256 { ymm: YMM::YMM0, vymm: YMM::YMM0, ymmm256: YMM::YMM0.into() })]
+#[case::avx(Instruction::VpshufbYmmVymmYmmm256 { ymm: YMM::YMM1, vymm: YMM::YMM2, ymmm256: YMM::YMM3.into() })]
+#[case::avx(Instruction::VpermdYmmVymmYmmm256 { ymm: YMM::YMM10, vymm: YMM::YMM11, ymmm256: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() })]
+#[case::avx(Instruction::VpbroadcastbXmmXmmm8 { xmm: XMM::XMM1, xmmm8: XMM::XMM2.into() })]
+#[case::avx(Instruction::VpbroadcastdYmmXmmm32 { ymm: YMM::YMM7, xmmm32: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() })]
+#[case::avx(Instruction::VpbroadcastqYmmXmmm64 { ymm: YMM::YMM14, xmmm64: XMM::XMM13.into() })]
+#[case::avx(Instruction::VmovapsXmmXmmm128 { xmm: XMM::XMM1, xmmm128: XMM::XMM9.into() })]
+#[case::avx(Instruction::VmovupsYmmm256Ymm { ymmm256: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) }.into(), ymm: YMM::YMM2 })]
+#[case::avx(Instruction::VmovdqaYmmYmmm256 { ymm: YMM::YMM15, ymmm256: YMM::YMM0.into() })]
+#[case::avx(Instruction::VmovdquXmmm128Xmm { xmmm128: XMM::XMM4.into(), xmm: XMM::XMM5 })]
+#[case::avx(Instruction::Vzeroupper)]
+#[case::fma(Instruction::Vfmadd132psXmmVxmmXmmm128 { xmm: XMM::XMM0, vxmm: XMM::XMM1, xmmm128: XMM::XMM2.into() })]
+#[case::fma(Instruction::Vfmadd213pdYmmVymmYmmm256 { ymm: YMM::YMM8, vymm: YMM::YMM15, ymmm256: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }.into() })]
+#[case::fma(Instruction::Vfnmadd231ssXmmVxmmXmmm32 { xmm: XMM::XMM3, vxmm: XMM::XMM4, xmmm32: XMM::XMM5.into() })]
+#[case::fma(Instruction::Vfnmsub132sdXmmVxmmXmmm64 { xmm: XMM::XMM9, vxmm: XMM::XMM10, xmmm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() })]
+#[case::f16c(Instruction::Vcvtph2psYmmXmmm128 { ymm: YMM::YMM1, xmmm128: XMM::XMM2.into() })]
+#[case::f16c(Instruction::Vcvtps2phXmmm64XmmImm8 { xmmm64: Memory::Based { base: GPR::RDI, offset: Offset::None }.into(), xmm: XMM::XMM1, imm8: Immediate8::from_i8(4) })]
+#[case::f16c(Instruction::Vcvtps2phXmmm128YmmImm8 { xmmm128: XMM::XMM14.into(), ymm: YMM::YMM3, imm8: Immediate8::from_i8(0) })]
+#[case::avx512(Instruction::VaddpsZmmMaskVzmmZmmm512b32 { zmm: ZMM::ZMM0, mask: Masking::Zero { opmask: Opmask::K1 }, vzmm: ZMM::ZMM1, zmmm512b32: ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } } })]
+#[case::avx512(Instruction::VaddpdZmmMaskVzmmZmmrEr { zmm: ZMM::ZMM16, mask: Masking::Merge { opmask: Opmask::K7 }, vzmm: ZMM::ZMM31, zmmr: ZMM::ZMM8, er: Rounding::Zero })]
+#[case::avx512(Instruction::VmulpsZmmMaskVzmmZmmm512b32 { zmm: ZMM::ZMM24, mask: Masking::None, vzmm: ZMM::ZMM9, zmmm512b32: ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(128) } } })]
+#[case::avx512(Instruction::VmaxpsZmmMaskVzmmZmmrSae { zmm: ZMM::ZMM1, mask: Masking::None, vzmm: ZMM::ZMM2, zmmr: ZMM::ZMM3 })]
+#[case::avx512(Instruction::VsqrtpdZmmMaskZmmm512b64 { zmm: ZMM::ZMM5, mask: Masking::Zero { opmask: Opmask::K2 }, zmmm512b64: ZMM::ZMM6.into() })]
+#[case::avx512(Instruction::VaddsdXmmMaskVxmmXmmm64 { xmm: XMM::XMM17, mask: Masking::Merge { opmask: Opmask::K3 }, vxmm: XMM::XMM2, xmmm64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) }.into() })]
+#[case::avx512(Instruction::VsubssXmmMaskVxmmXmmrEr { xmm: XMM::XMM1, mask: Masking::None, vxmm: XMM::XMM30, xmmr: XMM::XMM3, er: Rounding::Down })]
+#[case::avx512(Instruction::VcmppdKMergemaskVzmmZmmm512b64Imm8 { k: Opmask::K3, mergemask: Masking::Merge { opmask: Opmask::K4 }, vzmm: ZMM::ZMM20, zmmm512b64: ZMM::ZMM21.into(), imm8: Immediate8::from_u8(5) })]
+#[case::avx512(Instruction::VpcmpeqdKMergemaskVzmmZmmm512b32 { k: Opmask::K1, mergemask: Masking::None, vzmm: ZMM::ZMM1, zmmm512b32: ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } } })]
+#[case::avx512(Instruction::Vmovdqu64Zmmm512MergemaskZmm { zmmm512: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(-64) }.into(), mergemask: Masking::Merge { opmask: Opmask::K1 }, zmm: ZMM::ZMM31 })]
+#[case::avx512(Instruction::KmovwKKm16 { k: Opmask::K7, km16: Opmask::K0.into() })]
+#[case::avx512(Instruction::KmovwMem16K { mem16: Memory::Based { base: GPR::RAX, offset: Offset::None }, k: Opmask::K1 })]
+#[case::avx512(Instruction::KmovwReg32Kr { reg32: GPR::R15D, kr: Opmask::K2 })]
+#[case::avx512(Instruction::KxnorwKVkKr { k: Opmask::K1, vk: Opmask::K2, kr: Opmask::K3 })]
+#[case::avx512(Instruction::KortestwKKr { k: Opmask::K0, kr: Opmask::K0 })]
+#[case::gather(Instruction::VpgatherddXmmVm32xVxmm { xmm: XMM::XMM1, vm32x: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: XMM::XMM2.into(), scale: Scale::Scale4, offset: Offset::None }, vxmm: XMM::XMM3 })]
+#[case::gather(Instruction::VpgatherqqYmmVm64yVymm { ymm: YMM::YMM9, vm64y: VSIBMemory::BasedAndScaled { base: GPR::R12, index: YMM::YMM15.into(), scale: Scale::Scale2, offset: Offset::from_i8(-128) }, vymm: YMM::YMM0 })]
+#[case::gather(Instruction::VgatherdpdYmmVm32xVymm { ymm: YMM::YMM1, vm32x: VSIBMemory::Scaled { index: XMM::XMM2.into(), scale: Scale::Scale8, offset: Offset::from_i32(8) }, vymm: YMM::YMM3 })]
+#[case::gather(Instruction::VpgatherqdXmmVm64yVxmm { xmm: XMM::XMM4, vm64y: VSIBMemory::BasedAndScaled { base: GPR::RBP, index: YMM::YMM5.into(), scale: Scale::Scale1, offset: Offset::None }, vxmm: XMM::XMM6 })]
+#[case::gather(Instruction::VpgatherddZmmMergemaskVm32z { zmm: ZMM::ZMM1, mergemask: Masking::Merge { opmask: Opmask::K1 }, vm32z: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: ZMM::ZMM2.into(), scale: Scale::Scale4, offset: Offset::from_i32(256) } })]
+#[case::gather(Instruction::VgatherqpdZmmMergemaskVm64z { zmm: ZMM::ZMM30, mergemask: Masking::Merge { opmask: Opmask::K7 }, vm64z: VSIBMemory::BasedAndScaled { base: GPR::RSP, index: ZMM::ZMM1.into(), scale: Scale::Scale8, offset: Offset::from_i32(1024) } })]
+#[case::gather(Instruction::VpgatherqdYmmMergemaskVm64z { ymm: YMM::YMM17, mergemask: Masking::Merge { opmask: Opmask::K2 }, vm64z: VSIBMemory::Scaled { index: ZMM::ZMM31.into(), scale: Scale::Scale4, offset: Offset::from_i32(-16) } })]
+#[case::scatter(Instruction::VpscatterdqVm32yMergemaskZmm { vm32y: VSIBMemory::BasedAndScaled { base: GPR::RDI, index: YMM::YMM3.into(), scale: Scale::Scale4, offset: Offset::from_i8(-8) }, mergemask: Masking::Merge { opmask: Opmask::K3 }, zmm: ZMM::ZMM2 })]
+#[case::scatter(Instruction::VscatterqpsVm64zMergemaskYmm { vm64z: VSIBMemory::BasedAndScaled { base: GPR::R8, index: ZMM::ZMM20.into(), scale: Scale::Scale1, offset: Offset::None }, mergemask: Masking::Merge { opmask: Opmask::K2 }, ymm: YMM::YMM5 })]
+#[case::lock(Instruction::AddLockRm64Reg64 { rm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), reg64: GPR::RCX })]
+#[case::lock(Instruction::IncLockRm32 { rm32: Memory::Based { base: GPR::RBX, offset: Offset::from_i8(8) }.into() })]
+#[case::lock(Instruction::XaddLockRm16Reg16 { rm16: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), reg16: GPR::CX })]
+#[case::lock(Instruction::BtsLockRm32Imm8 { rm32: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), imm8: Immediate8::from_u8(3) })]
+#[case::lock(Instruction::Cmpxchg8bLockMem64 { mem64: Memory::Based { base: GPR::RSI, offset: Offset::None } })]
+fn test_decode_round_trip(#[case] instruction: Instruction) {
+    assert_round_trip(instruction);
+}
+
+#[test]
+fn test_decode_round_trip_memories() {
+    for memory in all_memories() {
+        assert_round_trip(Instruction::AddRm64Reg64 {
+            rm64: memory.into(),
+            reg64: GPR::R9,
+        });
+        assert_round_trip(Instruction::MovReg8Rm8 {
+            reg8: GPR::SIL,
+            rm8: memory.into(),
+        });
+        assert_round_trip(Instruction::MovRm16Imm16 {
+            rm16: memory.into(),
+            imm16: Immediate16::from_i16(-3),
+        });
+        assert_round_trip(Instruction::SubRm64Imm8 {
+            rm64: memory.into(),
+            imm8: Immediate8::from_i8(5),
+        });
+        assert_round_trip(Instruction::LeaReg32Mem64 {
+            reg32: GPR::R15D,
+            mem64: memory,
+        });
+        assert_round_trip(Instruction::PushRm64 { rm64: memory.into() });
+    }
+}
+
+#[test]
+fn test_decode_round_trip_registers() {
+    for reg64 in GPRS_64 {
+        assert_round_trip(Instruction::PushReg64 { reg64 });
+        assert_round_trip(Instruction::PopReg64 { reg64 });
+        assert_round_trip(Instruction::MovReg64Imm64 {
+            reg64,
+            imm64: Immediate64::from_u64(0x0123_4567_89AB_CDEF),
+        });
+        for other in GPRS_64 {
+            assert_round_trip(Instruction::XorRm64Reg64 {
+                rm64: other.into(),
+                reg64,
+            });
+        }
+    }
+
+    for reg8 in GPRS_8 {
+        assert_round_trip(Instruction::MovReg8Imm8 {
+            reg8,
+            imm8: Immediate8::from_u8(0xAB),
+        });
+        for other in GPRS_8 {
+            let instruction = Instruction::MovRm8Reg8 {
+                rm8: other.into(),
+                reg8,
+            };
+            // Combinations of AH, BH, CH, DH with registers requiring REX are not encodable.
+            if instruction.encode().is_ok() {
+                assert_round_trip(instruction);
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case::p_pe(Instruction::JccPeImm8 { imm8: Immediate8::from_i8(1) }, Instruction::JccPImm8 { imm8: Immediate8::from_i8(1) })]
+#[case::c_b(Instruction::JccCImm32 { imm32: Immediate32::from_i32(1) }, Instruction::JccBImm32 { imm32: Immediate32::from_i32(1) })]
+#[case::rbp_zero_offset(
+    Instruction::MovReg64Rm64 { reg64: GPR::RAX, rm64: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(0) }.into() },
+    Instruction::MovReg64Rm64 { reg64: GPR::RAX, rm64: Memory::Based { base: GPR::RBP, offset: Offset::None }.into() },
+)]
+#[case::rip_offset(
+    Instruction::LeaReg64Mem64 { reg64: GPR::RAX, mem64: Memory::RelativeToRIP { offset: Offset::None } },
+    Instruction::LeaReg64Mem64 { reg64: GPR::RAX, mem64: Memory::RelativeToRIP { offset: Offset::from_i32(0) } },
+)]
+fn test_decode_ambiguous(#[case] instruction: Instruction, #[case] expected: Instruction) {
+    let encoded = instruction.encode().unwrap();
+    let (decoded, length) = decode(encoded.as_slice()).unwrap();
+    assert_eq!(decoded, expected);
+    assert_eq!(length, encoded.as_slice().len());
+}
+
+#[test]
+fn test_decode_legacy_prefixes_in_any_order() {
+    assert_eq!(decode(&[0xF3, 0x66, 0xA5]), Ok((Instruction::MovsRepW, 3)));
+    assert_eq!(decode(&[0x66, 0xF3, 0xA5]), Ok((Instruction::MovsRepW, 3)));
+    assert_eq!(decode(&[0xF2, 0x48, 0xAF]), Ok((Instruction::ScasRepneQ, 3)));
+    assert_eq!(
+        decode(&[0xF3, 0x66, 0x0F, 0xB8, 0xC3]),
+        Ok((
+            Instruction::PopcntReg16Rm16 {
+                reg16: GPR::AX,
+                rm16: GPR::BX.into()
+            },
+            5
+        ))
+    );
+}
+
+#[test]
+fn test_decode_lock_prefix() {
+    let memory = Memory::Based {
+        base: GPR::RAX,
+        offset: Offset::None,
+    };
+    assert_eq!(
+        decode(&[0xF0, 0x48, 0x01, 0x08]),
+        Ok((
+            Instruction::AddLockRm64Reg64 {
+                rm64: memory.into(),
+                reg64: GPR::RCX
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0x66, 0xF0, 0x0F, 0xC1, 0x08]),
+        Ok((
+            Instruction::XaddLockRm16Reg16 {
+                rm16: memory.into(),
+                reg16: GPR::CX
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(&[0x48, 0x01, 0x08]),
+        Ok((
+            Instruction::AddRm64Reg64 {
+                rm64: memory.into(),
+                reg64: GPR::RCX
+            },
+            3
+        ))
+    );
+}
+
+#[test]
+fn test_decode_mandatory_prefix() {
+    let bsf = unsafe { bsf::encode_reg32_rm32(GPR::EAX, GPR::ECX.into()) };
+    let tzcnt = unsafe { tzcnt::encode_reg32_rm32(GPR::EAX, GPR::ECX.into()) };
+    assert_eq!(
+        decode(bsf.as_slice()),
+        Ok((
+            Instruction::BsfReg32Rm32 {
+                reg32: GPR::EAX,
+                rm32: GPR::ECX.into()
+            },
+            3
+        ))
+    );
+    assert_eq!(
+        decode(tzcnt.as_slice()),
+        Ok((
+            Instruction::TzcntReg32Rm32 {
+                reg32: GPR::EAX,
+                rm32: GPR::ECX.into()
+            },
+            4
+        ))
+    );
+}
+
+#[test]
+fn test_decode_vex() {
+    // `sarx`, `shlx`, `shrx` and `bextr` share the opcode byte with the legacy
+    // F7 group, and differ in VEX.pp only.
+    let sarx = unsafe { sarx::encode_reg32_rm32_vreg32(GPR::EAX, GPR::ECX.into(), GPR::EDX) };
+    let shrx = unsafe { shrx::encode_reg32_rm32_vreg32(GPR::EAX, GPR::ECX.into(), GPR::EDX) };
+    assert_eq!(
+        decode(sarx.as_slice()),
+        Ok((
+            Instruction::SarxReg32Rm32Vreg32 {
+                reg32: GPR::EAX,
+                rm32: GPR::ECX.into(),
+                vreg32: GPR::EDX
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(shrx.as_slice()),
+        Ok((
+            Instruction::ShrxReg32Rm32Vreg32 {
+                reg32: GPR::EAX,
+                rm32: GPR::ECX.into(),
+                vreg32: GPR::EDX
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(&[0xF7, 0xD1]),
+        Ok((Instruction::NotRm32 { rm32: GPR::ECX.into() }, 2))
+    );
+}
+
+#[test]
+fn test_decode_sse() {
+    // `movd` and `movq` differ in REX.W only, while `ucomiss` and `ucomisd`
+    // differ in the mandatory 66 prefix.
+    assert_eq!(
+        decode(&[0x66, 0x0F, 0x7E, 0xC8]),
+        Ok((
+            Instruction::MovdRm32Xmm {
+                rm32: GPR::EAX.into(),
+                xmm: XMM::XMM1
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0x66, 0x48, 0x0F, 0x7E, 0xC8]),
+        Ok((
+            Instruction::MovqRm64Xmm {
+                rm64: GPR::RAX.into(),
+                xmm: XMM::XMM1
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(&[0x0F, 0x2E, 0xC1]),
+        Ok((
+            Instruction::UcomissXmmXmmm32 {
+                xmm: XMM::XMM0,
+                xmmm32: XMM::XMM1.into()
+            },
+            3
+        ))
+    );
+    assert_eq!(
+        decode(&[0x66, 0x0F, 0x2E, 0xC1]),
+        Ok((
+            Instruction::UcomisdXmmXmmm64 {
+                xmm: XMM::XMM0,
+                xmmm64: XMM::XMM1.into()
+            },
+            4
+        ))
+    );
+}
+
+#[test]
+fn test_decode_packed_sse() {
+    // `movdqa` and `movdqu` differ in the mandatory prefix only, while `pextrw`
+    // with register destination has a shorter form than with memory.
+    assert_eq!(
+        decode(&[0x66, 0x0F, 0x6F, 0xC1]),
+        Ok((
+            Instruction::MovdqaXmmXmmm128 {
+                xmm: XMM::XMM0,
+                xmmm128: XMM::XMM1.into()
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0xF3, 0x0F, 0x6F, 0xC1]),
+        Ok((
+            Instruction::MovdquXmmXmmm128 {
+                xmm: XMM::XMM0,
+                xmmm128: XMM::XMM1.into()
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0x66, 0x0F, 0xC5, 0xC1, 0x01]),
+        Ok((
+            Instruction::PextrwReg32XmmImm8 {
+                reg32: GPR::EAX,
+                xmm: XMM::XMM1,
+                imm8: Immediate8::from_u8(1)
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(&[0x66, 0x0F, 0x3A, 0x15, 0x08, 0x01]),
+        Ok((
+            Instruction::PextrwR32m16XmmImm8 {
+                r32m16: Memory::Based {
+                    base: GPR::RAX,
+                    offset: Offset::None
+                }
+                .into(),
+                xmm: XMM::XMM1,
+                imm8: Immediate8::from_u8(1)
+            },
+            6
+        ))
+    );
+}
+
+#[test]
+fn test_decode_avx() {
+    // `vaddps` with XMM and YMM operands differ in VEX.L only. The 3-byte
+    // VEX prefix decodes to the same instruction as the shorter 2-byte one.
+    assert_eq!(
+        decode(&[0xC5, 0xF0, 0x58, 0xC2]),
+        Ok((
+            Instruction::VaddpsXmmVxmmXmmm128 {
+                xmm: XMM::XMM0,
+                vxmm: XMM::XMM1,
+                xmmm128: XMM::XMM2.into()
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0xC5, 0xF4, 0x58, 0xC2]),
+        Ok((
+            Instruction::VaddpsYmmVymmYmmm256 {
+                ymm: YMM::YMM0,
+                vymm: YMM::YMM1,
+                ymmm256: YMM::YMM2.into()
+            },
+            4
+        ))
+    );
+    assert_eq!(
+        decode(&[0xC4, 0xE1, 0x70, 0x58, 0xC2]),
+        Ok((
+            Instruction::VaddpsXmmVxmmXmmm128 {
+                xmm: XMM::XMM0,
+                vxmm: XMM::XMM1,
+                xmmm128: XMM::XMM2.into()
+            },
+            5
+        ))
+    );
+    assert_eq!(
+        decode(&[0x0F, 0x58, 0xC2]),
+        Ok((
+            Instruction::AddpsXmmXmmm128 {
+                xmm: XMM::XMM0,
+                xmmm128: XMM::XMM2.into()
+            },
+            3
+        ))
+    );
+}
+
+#[test]
+fn test_decode_vsib() {
+    // Gathers and scatters always have SIB byte, whose index is a vector register.
+    assert_eq!(
+        decode(&[0xC4, 0x02, 0xFD, 0x91, 0x4C, 0x7C, 0x80]),
+        Ok((
+            Instruction::VpgatherqqYmmVm64yVymm {
+                ymm: YMM::YMM9,
+                vm64y: VSIBMemory::BasedAndScaled {
+                    base: GPR::R12,
+                    index: YMM::YMM15.into(),
+                    scale: Scale::Scale2,
+                    offset: Offset::from_i8(-128)
+                },
+                vymm: YMM::YMM0
+            },
+            7
+        ))
+    );
+    assert_eq!(
+        decode(&[0x62, 0xD2, 0x7D, 0x42, 0xA3, 0x2C, 0x20]),
+        Ok((
+            Instruction::VscatterqpsVm64zMergemaskYmm {
+                vm64z: VSIBMemory::BasedAndScaled {
+                    base: GPR::R8,
+                    index: ZMM::ZMM20.into(),
+                    scale: Scale::Scale1,
+                    offset: Offset::None
+                },
+                mergemask: Masking::Merge { opmask: Opmask::K2 },
+                ymm: YMM::YMM5
+            },
+            7
+        ))
+    );
+}
+
+#[test]
+fn test_decode_first_instruction() {
+    let push = unsafe { push::encode_reg64(GPR::RBP) };
+    let mov = unsafe { mov::encode_rm64_reg64(GPR::RBP.into(), GPR::RSP) };
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(push.as_slice());
+    bytes.extend_from_slice(mov.as_slice());
+
+    let (instruction, length) = decode(&bytes).unwrap();
+    assert_eq!(instruction, Instruction::PushReg64 { reg64: GPR::RBP });
+    let (instruction, _) = decode(&bytes[length..]).unwrap();
+    assert_eq!(
+        instruction,
+        Instruction::MovRm64Reg64 {
+            rm64: GPR::RBP.into(),
+            reg64: GPR::RSP
+        }
+    );
+}
+
+#[rstest]
+#[case::empty(&[], DecodeError::UnexpectedEnd)]
+#[case::prefixes_only(&[0x66, 0x48], DecodeError::UnexpectedEnd)]
+#[case::missing_mod_rm(&[0x48, 0x81], DecodeError::UnexpectedEnd)]
+#[case::missing_immediate(&[0x48, 0x81, 0xC0, 0x01, 0x00], DecodeError::UnexpectedEnd)]
+#[case::missing_displacement(&[0x8B, 0x45], DecodeError::UnexpectedEnd)]
+#[case::missing_sib(&[0x8B, 0x04], DecodeError::UnexpectedEnd)]
+#[case::missing_second_opcode_byte(&[0x0F], DecodeError::UnexpectedEnd)]
+#[case::unknown_opcode(&[0x0F, 0xFF], DecodeError::UnknownInstruction)]
+#[case::unknown_opcode(&[0xF4], DecodeError::UnknownInstruction)]
+#[case::unknown_extended_opcode(&[0xFF, 0xF8], DecodeError::UnknownInstruction)]
+#[case::lea_with_register(&[0x48, 0x8D, 0xC0], DecodeError::UnknownInstruction)]
+#[case::nop_with_rex_b(&[0x41, 0x90], DecodeError::UnknownInstruction)]
+#[case::nop_with_oso(&[0x66, 0x90], DecodeError::UnknownInstruction)]
+#[case::byte_with_rex_w(&[0x48, 0x88, 0xC0], DecodeError::UnknownInstruction)]
+#[case::absolute_address(&[0x8B, 0x04, 0x25, 0x00, 0x00, 0x00, 0x00], DecodeError::UnsupportedAddressing)]
+#[case::prefixes_only(&[0xF3, 0x66], DecodeError::UnexpectedEnd)]
+#[case::rep_with_non_string(&[0xF3, 0x90], DecodeError::UnknownInstruction)]
+#[case::rep_with_non_string(&[0xF2, 0x48, 0x01, 0xC0], DecodeError::UnknownInstruction)]
+#[case::lock_only(&[0xF0], DecodeError::UnexpectedEnd)]
+#[case::lock_with_register_destination(&[0xF0, 0x48, 0x01, 0xC8], DecodeError::UnknownInstruction)]
+#[case::lock_with_non_atomic(&[0xF0, 0x48, 0x89, 0x08], DecodeError::UnknownInstruction)]
+#[case::lock_with_nop(&[0xF0, 0x90], DecodeError::UnknownInstruction)]
+#[case::lock_missing_mod_rm(&[0xF0, 0x48, 0x01], DecodeError::UnexpectedEnd)]
+#[case::lock_with_vex(&[0xF0, 0xC5, 0xF0, 0x58, 0xC2], DecodeError::UnknownInstruction)]
+#[case::mandatory_prefix_with_bswap(&[0xF3, 0x0F, 0xC8], DecodeError::UnknownInstruction)]
+#[case::popcnt_with_repne(&[0xF2, 0x0F, 0xB8, 0xC3], DecodeError::UnknownInstruction)]
+#[case::truncated_vex(&[0xC4, 0xE2], DecodeError::UnexpectedEnd)]
+#[case::vex_with_vex_l(&[0xC4, 0xE2, 0x64, 0xF2, 0xC1], DecodeError::UnknownInstruction)]
+#[case::vex_with_oso(&[0x66, 0xC4, 0xE2, 0x60, 0xF2, 0xC1], DecodeError::UnknownInstruction)]
+#[case::vex_wrong_map(&[0xC4, 0xE1, 0x60, 0xF2, 0xC1], DecodeError::UnknownInstruction)]
+#[case::vex_map_zero(&[0xC4, 0x40, 0xC6, 0x3B, 0x19], DecodeError::UnknownInstruction)]
+#[case::vex_map_zero_legacy_mov(&[0xC4, 0xE0, 0x78, 0x89, 0xC1], DecodeError::UnknownInstruction)]
+#[case::vex_reserved_map(&[0xC4, 0xE4, 0x78, 0x89, 0xC1], DecodeError::UnknownInstruction)]
+#[case::rorx_with_vvvv(&[0xC4, 0xE3, 0x73, 0xF0, 0xC1, 0x05], DecodeError::UnknownInstruction)]
+#[case::adcx_without_prefix(&[0x0F, 0x38, 0xF6, 0xC3], DecodeError::UnknownInstruction)]
+#[case::subsd_with_oso(&[0x66, 0x0F, 0x5C, 0xC1], DecodeError::UnknownInstruction)]
+#[case::cvttsd2si_missing_mod_rm(&[0xF2, 0x48, 0x0F, 0x2C], DecodeError::UnexpectedEnd)]
+#[case::pmovmskb_with_memory(&[0x66, 0x0F, 0xD7, 0x00], DecodeError::UnknownInstruction)]
+#[case::pshufd_missing_imm8(&[0x66, 0x0F, 0x70, 0xC1], DecodeError::UnexpectedEnd)]
+#[case::vzeroupper_with_vvvv(&[0xC5, 0xF0, 0x77], DecodeError::UnknownInstruction)]
+#[case::vpermd_without_vex_l(&[0xC4, 0xE2, 0x71, 0x36, 0xC2], DecodeError::UnknownInstruction)]
+#[case::vmovdqu_with_vvvv(&[0xC5, 0xF2, 0x6F, 0xC1], DecodeError::UnknownInstruction)]
+#[case::vaddps_missing_mod_rm(&[0xC5, 0xF0, 0x58], DecodeError::UnexpectedEnd)]
+#[case::vcvtps2ph_missing_imm8(&[0xC4, 0xE3, 0x79, 0x1D, 0x08], DecodeError::UnexpectedEnd)]
+#[case::truncated_evex(&[0x62, 0xF1, 0x74], DecodeError::UnexpectedEnd)]
+#[case::evex_reserved_bit(&[0x62, 0xF1, 0x70, 0x48, 0x58, 0xC2], DecodeError::UnknownInstruction)]
+#[case::evex_wrong_vector_length(&[0x62, 0xF1, 0x74, 0x28, 0x58, 0xC2], DecodeError::UnknownInstruction)]
+#[case::evex_zeroing_on_store(&[0x62, 0xF1, 0x7C, 0xC9, 0x11, 0x00], DecodeError::UnknownInstruction)]
+#[case::kmovw_with_vex_l(&[0xC5, 0xFC, 0x90, 0xCA], DecodeError::UnknownInstruction)]
+#[case::gather_without_sib(&[0xC4, 0xE2, 0x61, 0x90, 0x08], DecodeError::UnknownInstruction)]
+#[case::gather_with_register(&[0xC4, 0xE2, 0x61, 0x90, 0xCA], DecodeError::UnknownInstruction)]
+#[case::gather_destination_is_index(&[0xC4, 0xE2, 0x61, 0x90, 0x0C, 0x88], DecodeError::UnknownInstruction)]
+#[case::gather_destination_is_mask(&[0xC4, 0xE2, 0x71, 0x90, 0x0C, 0x90], DecodeError::UnknownInstruction)]
+#[case::gather_index_is_mask(&[0xC4, 0xE2, 0x51, 0x90, 0x0C, 0xA8], DecodeError::UnknownInstruction)]
+#[case::evex_gather_without_mask(&[0x62, 0xF2, 0x7D, 0x48, 0x90, 0x0C, 0x90], DecodeError::UnknownInstruction)]
+#[case::evex_gather_with_zeroing(&[0x62, 0xF2, 0x7D, 0xC9, 0x90, 0x0C, 0x90], DecodeError::UnknownInstruction)]
+#[case::evex_gather_destination_is_index(&[0x62, 0xF2, 0x7D, 0x49, 0x90, 0x0C, 0x88], DecodeError::UnknownInstruction)]
+#[case::gather_missing_sib(&[0xC4, 0xE2, 0x61, 0x90, 0x0C], DecodeError::UnexpectedEnd)]
+fn test_decode_errors(#[case] bytes: &[u8], #[case] expected: DecodeError) {
+    assert_eq!(decode(bytes), Err(expected));
+}
+
+#[test]
+fn test_decode_const() {
+    const DECODED: Result<(Instruction, usize), DecodeError> = decode(&[0x48, 0x83, 0xC4, 0x08]);
+    assert_eq!(
+        DECODED,
+        Ok((
+            Instruction::AddRm64Imm8 {
+                rm64: GPR::RSP.into(),
+                imm8: Immediate8::from_i8(8)
+            },
+            4
+        ))
+    );
+}
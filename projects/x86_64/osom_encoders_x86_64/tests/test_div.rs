@@ -0,0 +1,34 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BH }, &[0xF6, 0xF7])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, &[0xF6, 0x35, 0x10, 0x00, 0x00, 0x00])]
+fn test_div_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { div::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9W }, &[0x66, 0x41, 0xF7, 0xF1])]
+fn test_div_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { div::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, &[0xF7, 0xF1])]
+fn test_div_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { div::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R11 }, &[0x49, 0xF7, 0xF3])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RDI, index: GPR::RSI, scale: Scale::Scale8, offset: Offset::None } }, &[0x48, 0xF7, 0x34, 0xF7])]
+fn test_div_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { div::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,251 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use osom_encoders_x86_64::assembler::*;
+use osom_encoders_x86_64::elf::*;
+use osom_encoders_x86_64::models::*;
+use osom_encoders_x86_64::parsing::parse_intel;
+
+fn emit_intel(assembler: &mut Assembler, source: &str) {
+    for instruction in parse_intel(source).unwrap() {
+        assembler.emit(instruction);
+    }
+}
+
+/// Links `object` with C `main_source` by the system `cc` and runs the result.
+/// Returns the exit code of the program.
+fn link_and_run(name: &str, object: &[u8], main_source: &str, extra_args: &[&str]) -> i32 {
+    let directory = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("test_elf").join(name);
+    std::fs::create_dir_all(&directory).unwrap();
+    let object_path = directory.join("code.o");
+    let main_path = directory.join("main.c");
+    let program_path = directory.join("program");
+    std::fs::write(&object_path, object).unwrap();
+    std::fs::write(&main_path, main_source).unwrap();
+
+    let output = Command::new("cc")
+        .args(extra_args)
+        .arg("-o")
+        .arg(&program_path)
+        .arg(&main_path)
+        .arg(&object_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Linking failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Command::new(&program_path).status().unwrap().code().unwrap()
+}
+
+#[test]
+fn test_elf_header() {
+    let mut writer = ElfWriter::new(vec![0xC3]);
+    let _ = writer
+        .define_symbol("function", 0, 1, SymbolKind::Function, SymbolBinding::Global)
+        .unwrap();
+    let object = writer.write();
+    assert_eq!(&object[..8], &[0x7F, b'E', b'L', b'F', 2, 1, 1, 0]);
+    assert_eq!(u16::from_le_bytes([object[16], object[17]]), 1); // ET_REL
+    assert_eq!(u16::from_le_bytes([object[18], object[19]]), 62); // EM_X86_64
+    assert_eq!(object[64], 0xC3); // .text right after the header
+}
+
+#[test]
+fn test_elf_errors() {
+    let mut writer = ElfWriter::new(vec![0x90; 8]);
+    let symbol = writer
+        .define_symbol("local", 2, 2, SymbolKind::NoType, SymbolBinding::Local)
+        .unwrap();
+    assert_eq!(writer.symbol("local"), Some(symbol));
+    assert_eq!(writer.symbol("other"), None);
+    assert_eq!(
+        writer.define_symbol("local", 0, 1, SymbolKind::NoType, SymbolBinding::Global),
+        Err(ElfError::DuplicateSymbol)
+    );
+    assert_eq!(writer.declare_external("local"), Err(ElfError::DuplicateSymbol));
+    assert_eq!(writer.declare_external(""), Err(ElfError::InvalidSymbolName));
+    assert_eq!(writer.declare_external("a\0b"), Err(ElfError::InvalidSymbolName));
+    assert_eq!(
+        writer.define_symbol("outside", 4, 5, SymbolKind::Object, SymbolBinding::Local),
+        Err(ElfError::SymbolOutOfRange { offset: 4, size: 5 })
+    );
+    assert_eq!(
+        writer.add_relocation(5, symbol, RelocationKind::PC32, -4),
+        Err(ElfError::RelocationOutOfRange { offset: 5 })
+    );
+    assert_eq!(
+        writer.add_relocation(1, symbol, RelocationKind::Absolute64, 0),
+        Err(ElfError::RelocationOutOfRange { offset: 1 })
+    );
+    assert!(writer.add_relocation(4, symbol, RelocationKind::PC32, -4).is_ok());
+    assert!(writer.add_relocation(0, symbol, RelocationKind::Absolute64, 0).is_ok());
+
+    let mut other = ElfWriter::new(vec![0x90; 8]);
+    assert_eq!(
+        other.add_relocation(0, symbol, RelocationKind::PC32, -4),
+        Err(ElfError::InvalidSymbol)
+    );
+}
+
+#[test]
+fn test_assembler_finalize_relocatable() {
+    let mut assembler = Assembler::new();
+    let local = assembler.create_label();
+    let external = assembler.create_label();
+    assembler.jmp(local);
+    assembler.jmp(external);
+    assembler.call(external);
+    assembler.bind_label(local).unwrap();
+    assembler.mov(GPR::EAX, external).unwrap();
+
+    let code = assembler.clone().finalize_relocatable().unwrap();
+    assert_eq!(
+        code.code(),
+        &[
+            0xEB, 0x0A, // jmp local
+            0xE9, 0x00, 0x00, 0x00, 0x00, // jmp external
+            0xE8, 0x00, 0x00, 0x00, 0x00, // call external
+            0x8B, 0x05, 0x00, 0x00, 0x00, 0x00, // mov eax, [rip + external]
+        ]
+    );
+    assert_eq!(code.label_position(local), Some(12));
+    assert_eq!(code.label_position(external), None);
+    assert_eq!(
+        code.references(),
+        &[
+            LabelReference {
+                offset: 3,
+                relative_to: 7,
+                label: external
+            },
+            LabelReference {
+                offset: 8,
+                relative_to: 12,
+                label: external
+            },
+            LabelReference {
+                offset: 14,
+                relative_to: 18,
+                label: external
+            },
+        ]
+    );
+    assert!(code.references().iter().all(|reference| reference.addend() == -4));
+    assert_eq!(
+        assembler.finalize(),
+        Err(AssemblerError::UnboundLabel { label: external })
+    );
+}
+
+#[test]
+fn test_elf_link_function() {
+    let mut assembler = Assembler::new();
+    emit_intel(&mut assembler, "mov eax, 42; ret");
+    let code = assembler.finalize().unwrap();
+
+    let mut writer = ElfWriter::new(code.clone());
+    let _ = writer
+        .define_symbol("answer", 0, code.len(), SymbolKind::Function, SymbolBinding::Global)
+        .unwrap();
+
+    let main = "int answer(void);\nint main(void) { return answer() == 42 ? 0 : 1; }\n";
+    assert_eq!(link_and_run("function", &writer.write(), main, &[]), 0);
+}
+
+#[test]
+fn test_elf_link_relocations() {
+    // int compute(int x) { return negate(twice(x) + counter + x + helper()); }
+    // with `negate` being a tail call.
+    let mut assembler = Assembler::new();
+    let compute = assembler.create_label();
+    let helper = assembler.create_label();
+    let twice = assembler.create_label();
+    let negate = assembler.create_label();
+    let counter = assembler.create_label();
+
+    assembler.bind_label(compute).unwrap();
+    emit_intel(&mut assembler, "push rbx; mov ebx, edi");
+    assembler.call(twice);
+    assembler.mov(GPR::ECX, counter).unwrap();
+    emit_intel(&mut assembler, "add eax, ecx; add eax, ebx; mov ebx, eax");
+    assembler.call(helper);
+    emit_intel(&mut assembler, "add eax, ebx; pop rbx; mov edi, eax");
+    assembler.jmp(negate);
+    assembler.bind_label(helper).unwrap();
+    emit_intel(&mut assembler, "mov eax, 1; ret");
+
+    let code = assembler.finalize_relocatable().unwrap();
+    let mut writer = ElfWriter::new(code.code().to_vec());
+    let compute_position = code.label_position(compute).unwrap();
+    let helper_position = code.label_position(helper).unwrap();
+    let _ = writer
+        .define_symbol(
+            "compute",
+            compute_position,
+            helper_position - compute_position,
+            SymbolKind::Function,
+            SymbolBinding::Global,
+        )
+        .unwrap();
+    let _ = writer
+        .define_symbol(
+            "helper",
+            helper_position,
+            code.code().len() - helper_position,
+            SymbolKind::Function,
+            SymbolBinding::Local,
+        )
+        .unwrap();
+    let twice_symbol = writer.declare_external("twice").unwrap();
+    let negate_symbol = writer.declare_external("negate").unwrap();
+    let counter_symbol = writer.declare_external("counter").unwrap();
+    for reference in code.references() {
+        let (symbol, kind) = match reference.label {
+            label if label == twice => (twice_symbol, RelocationKind::PLT32),
+            label if label == negate => (negate_symbol, RelocationKind::PLT32),
+            label if label == counter => (counter_symbol, RelocationKind::PC32),
+            label => panic!("Unexpected reference to {label:?}."),
+        };
+        writer.add_label_reference(reference, symbol, kind).unwrap();
+    }
+
+    let main = "int counter = 100;\n\
+        int twice(int x) { return 2 * x; }\n\
+        int negate(int x) { return -x; }\n\
+        int compute(int x);\n\
+        int main(void) { return compute(5) == -(10 + 100 + 5 + 1) ? 0 : 1; }\n";
+    assert_eq!(link_and_run("relocations", &writer.write(), main, &[]), 0);
+}
+
+#[test]
+fn test_elf_link_absolute() {
+    let mut assembler = Assembler::new();
+    emit_intel(&mut assembler, "mov rax, 0x123456789ABCDEF0");
+    let immediate_end = assembler.create_label();
+    assembler.bind_label(immediate_end).unwrap();
+    emit_intel(&mut assembler, "ret");
+    let code = assembler.finalize_relocatable().unwrap();
+
+    let mut writer = ElfWriter::new(code.code().to_vec());
+    let _ = writer
+        .define_symbol(
+            "counter_address",
+            0,
+            code.code().len(),
+            SymbolKind::Function,
+            SymbolBinding::Global,
+        )
+        .unwrap();
+    let counter = writer.declare_external("counter").unwrap();
+    let offset = code.label_position(immediate_end).unwrap() - 8;
+    writer
+        .add_relocation(offset, counter, RelocationKind::Absolute64, 4)
+        .unwrap();
+
+    let main = "int counter[2];\n\
+        int *counter_address(void);\n\
+        int main(void) { return counter_address() == &counter[1] ? 0 : 1; }\n";
+    assert_eq!(link_and_run("absolute", &writer.write(), main, &["-no-pie"]), 0);
+}
@@ -0,0 +1,390 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::decoders::*;
+use osom_encoders_x86_64::formatting::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::zo(Instruction::Nop, "nop")]
+#[case::zo(Instruction::Syscall, "syscall")]
+#[case::int3(Instruction::Int3, "int3")]
+#[case::ret(Instruction::RetImm16 { imm16: Immediate16::from_u16(16) }, "ret 16")]
+#[case::accumulator(Instruction::AddAlImm8 { imm8: Immediate8::from_i8(-1) }, "add al, -1")]
+#[case::accumulator(Instruction::CmpRaxImm32 { imm32: Immediate32::from_i32(1000) }, "cmp rax, 1000")]
+#[case::jcc(Instruction::JccNeImm8 { imm8: Immediate8::from_i8(-2) }, "jne -2")]
+#[case::shift(Instruction::ShlRm32One { rm32: GPR::EAX.into() }, "shl eax, 1")]
+#[case::extension(Instruction::MovzxReg64Rm8 { reg64: GPR::RAX, rm8: GPR::SIL.into() }, "movzx rax, sil")]
+#[case::condition(Instruction::SetccERm8 { rm8: GPR::AL.into() }, "sete al")]
+#[case::condition(Instruction::CmovccGeReg64Rm64 { reg64: GPR::RAX, rm64: GPR::RCX.into() }, "cmovge rax, rcx")]
+#[case::atomic(
+    Instruction::Cmpxchg8bMem64 { mem64: Memory::Based { base: GPR::RDI, offset: Offset::None } },
+    "cmpxchg8b qword ptr [rdi]",
+)]
+#[case::atomic(
+    Instruction::Cmpxchg16bMem128 { mem128: Memory::Based { base: GPR::RDI, offset: Offset::None } },
+    "cmpxchg16b xmmword ptr [rdi]",
+)]
+#[case::rmi(
+    Instruction::ImulReg64Rm64Imm32 { reg64: GPR::RAX, rm64: Memory::Based { base: GPR::RBX, offset: Offset::None }.into(), imm32: Immediate32::from_i32(-200) },
+    "imul rax, qword ptr [rbx], -200",
+)]
+#[case::shift(Instruction::SarRm64Cl { rm64: GPR::R9.into() }, "sar r9, cl")]
+#[case::shift(Instruction::RolRm16Imm8 { rm16: GPR::DX.into(), imm8: Immediate8::from_u8(200) }, "rol dx, 200")]
+#[case::jcc(Instruction::JccPeImm32 { imm32: Immediate32::from_i32(256) }, "jpe 256")]
+#[case::jmp(Instruction::JmpRm64 { rm64: GPR::R11.into() }, "jmp r11")]
+#[case::push(Instruction::PushReg64 { reg64: GPR::RBP }, "push rbp")]
+#[case::push(Instruction::PushImm16 { imm16: Immediate16::from_i16(1446) }, "push word 1446")]
+#[case::reg_reg(Instruction::MovRm64Reg64 { rm64: GPR::RBP.into(), reg64: GPR::RSP }, "mov rbp, rsp")]
+#[case::reg_reg(Instruction::XorReg32Rm32 { reg32: GPR::R8D, rm32: GPR::R8D.into() }, "xor r8d, r8d")]
+#[case::reg8(Instruction::MovRm8Reg8 { rm8: GPR::AH.into(), reg8: GPR::BH }, "mov ah, bh")]
+#[case::reg8(Instruction::MovReg8Imm8 { reg8: GPR::SIL, imm8: Immediate8::from_u8(0xFF) }, "mov sil, -1")]
+#[case::imm64(Instruction::MovReg64Imm64 { reg64: GPR::R15, imm64: Immediate64::from_i64(-5_000_000_000) }, "mov r15, -5000000000")]
+#[case::based(
+    Instruction::MovRm64Imm32 {
+        rm64: Memory::BasedAndScaled { base: GPR::RBP, index: GPR::RAX, scale: Scale::Scale4, offset: Offset::from_i8(-8) }.into(),
+        imm32: Immediate32::from_i32(5),
+    },
+    "mov qword ptr [rbp + rax*4 - 8], 5",
+)]
+#[case::based(
+    Instruction::AddReg64Rm64 { reg64: GPR::RAX, rm64: Memory::BasedAndScaled { base: GPR::RDI, index: GPR::RSI, scale: Scale::Scale8, offset: Offset::from_i32(16) }.into() },
+    "add rax, qword ptr [rdi + rsi*8 + 16]",
+)]
+#[case::based(
+    Instruction::MovRm8Imm8 { rm8: Memory::Based { base: GPR::RSP, offset: Offset::None }.into(), imm8: Immediate8::from_i8(1) },
+    "mov byte ptr [rsp], 1",
+)]
+#[case::based(
+    Instruction::SubRm16Imm8 { rm16: Memory::Based { base: GPR::R13, offset: Offset::from_i8(0) }.into(), imm8: Immediate8::from_i8(2) },
+    "sub word ptr [r13], 2",
+)]
+#[case::scale_1(
+    Instruction::MovReg32Rm32 { reg32: GPR::EAX, rm32: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::R9, scale: Scale::Scale1, offset: Offset::None }.into() },
+    "mov eax, dword ptr [rax + r9]",
+)]
+#[case::scaled(
+    Instruction::PushRm64 { rm64: Memory::Scaled { index: GPR::RCX, scale: Scale::Scale2, offset: Offset::from_i32(-2147483648) }.into() },
+    "push qword ptr [rcx*2 - 2147483648]",
+)]
+#[case::rip(
+    Instruction::CallRm64 { rm64: Memory::RelativeToRIP { offset: Offset::from_i32(100) }.into() },
+    "call qword ptr [rip + 100]",
+)]
+#[case::lea(
+    Instruction::LeaReg64Mem64 { reg64: GPR::RAX, mem64: Memory::Based { base: GPR::RBX, offset: Offset::from_i8(-1) } },
+    "lea rax, [rbx - 1]",
+)]
+#[case::string(Instruction::StosB, "stosb")]
+#[case::string(Instruction::MovsRepQ, "rep movsq")]
+#[case::string(Instruction::CmpsRepneD, "repne cmpsd")]
+#[case::lock(
+    Instruction::AddLockRm64Reg64 { rm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), reg64: GPR::RCX },
+    "lock add qword ptr [rax], rcx",
+)]
+#[case::bits(Instruction::BtRm64Imm8 { rm64: GPR::RAX.into(), imm8: Immediate8::from_u8(200) }, "bt rax, 200")]
+#[case::bits(Instruction::BswapReg32 { reg32: GPR::EAX }, "bswap eax")]
+#[case::bits(Instruction::PopcntReg64Rm64 { reg64: GPR::RAX, rm64: GPR::RBX.into() }, "popcnt rax, rbx")]
+#[case::bmi(Instruction::AndnReg64Vreg64Rm64 { reg64: GPR::RAX, vreg64: GPR::RBX, rm64: GPR::RCX.into() }, "andn rax, rbx, rcx")]
+#[case::bmi(Instruction::BlsiVreg32Rm32 { vreg32: GPR::EAX, rm32: GPR::ECX.into() }, "blsi eax, ecx")]
+#[case::sse(Instruction::AddssXmmXmmm32 { xmm: XMM::XMM0, xmmm32: XMM::XMM15.into() }, "addss xmm0, xmm15")]
+#[case::sse(
+    Instruction::MovsdXmmm64Xmm { xmmm64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) }.into(), xmm: XMM::XMM1 },
+    "movsd qword ptr [rsp + 8], xmm1",
+)]
+#[case::sse(Instruction::Cvttsd2siReg32Xmmm64 { reg32: GPR::EAX, xmmm64: XMM::XMM2.into() }, "cvttsd2si eax, xmm2")]
+#[case::packed(
+    Instruction::MovdqaXmmXmmm128 { xmm: XMM::XMM1, xmmm128: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "movdqa xmm1, xmmword ptr [rax]",
+)]
+#[case::packed(
+    Instruction::PextrbR32m8XmmImm8 { r32m8: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), xmm: XMM::XMM1, imm8: Immediate8::from_u8(1) },
+    "pextrb byte ptr [rax], xmm1, 1",
+)]
+#[case::packed(Instruction::BlendvpdXmmXmmm128Xmm0 { xmm: XMM::XMM1, xmmm128: XMM::XMM2.into() }, "blendvpd xmm1, xmm2, xmm0")]
+#[case::avx(
+    Instruction::VaddpsYmmVymmYmmm256 { ymm: YMM::YMM0, vymm: YMM::YMM1, ymmm256: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "vaddps ymm0, ymm1, ymmword ptr [rax]",
+)]
+#[case::avx(Instruction::VpbroadcastbYmmXmmm8 { ymm: YMM::YMM15, xmmm8: XMM::XMM2.into() }, "vpbroadcastb ymm15, xmm2")]
+#[case::avx(Instruction::Vzeroupper, "vzeroupper")]
+#[case::avx512(
+    Instruction::VaddpsZmmMaskVzmmZmmm512b32 { zmm: ZMM::ZMM0, mask: Masking::Zero { opmask: Opmask::K1 }, vzmm: ZMM::ZMM1, zmmm512b32: ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } } },
+    "vaddps zmm0 {k1} {z}, zmm1, dword ptr [rax + 4]{1to16}",
+)]
+#[case::avx512(
+    Instruction::VaddpsZmmMaskVzmmZmmrEr { zmm: ZMM::ZMM0, mask: Masking::Merge { opmask: Opmask::K1 }, vzmm: ZMM::ZMM1, zmmr: ZMM::ZMM31, er: Rounding::Up },
+    "vaddps zmm0 {k1}, zmm1, zmm31, {ru-sae}",
+)]
+#[case::avx512(
+    Instruction::VcmppsKMergemaskVzmmZmmrSaeImm8 { k: Opmask::K1, mergemask: Masking::Merge { opmask: Opmask::K2 }, vzmm: ZMM::ZMM1, zmmr: ZMM::ZMM2, imm8: Immediate8::from_u8(1) },
+    "vcmpps k1 {k2}, zmm1, zmm2, {sae}, 1",
+)]
+#[case::avx512(
+    Instruction::VmovupsZmmm512MergemaskZmm { zmmm512: ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, mergemask: Masking::None, zmm: ZMM::ZMM0 },
+    "vmovups zmmword ptr [rax], zmm0",
+)]
+#[case::avx512(Instruction::VaddssXmmMaskVxmmXmmm32 { xmm: XMM::XMM16, mask: Masking::None, vxmm: XMM::XMM1, xmmm32: XMM::XMM2.into() }, "vaddss xmm16, xmm1, xmm2")]
+#[case::avx512(Instruction::KandwKVkKr { k: Opmask::K1, vk: Opmask::K2, kr: Opmask::K3 }, "kandw k1, k2, k3")]
+#[case::gather(
+    Instruction::VpgatherqdXmmVm64xVxmm { xmm: XMM::XMM1, vm64x: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: XMM::XMM2.into(), scale: Scale::Scale4, offset: Offset::None }, vxmm: XMM::XMM3 },
+    "vpgatherqd xmm1, dword ptr [rax + xmm2*4], xmm3",
+)]
+#[case::gather(
+    Instruction::VgatherqpsXmmVm64xVxmm { xmm: XMM::XMM1, vm64x: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: XMM::XMM2.into(), scale: Scale::Scale4, offset: Offset::None }, vxmm: XMM::XMM3 },
+    "vgatherqps xmm1, dword ptr [rax + xmm2*4], xmm3",
+)]
+#[case::gather(
+    Instruction::VgatherdpdYmmVm32xVymm { ymm: YMM::YMM1, vm32x: VSIBMemory::Scaled { index: XMM::XMM2.into(), scale: Scale::Scale8, offset: Offset::from_i32(8) }, vymm: YMM::YMM3 },
+    "vgatherdpd ymm1, qword ptr [xmm2*8 + 8], ymm3",
+)]
+#[case::gather(
+    Instruction::VpgatherddZmmMergemaskVm32z { zmm: ZMM::ZMM1, mergemask: Masking::Merge { opmask: Opmask::K1 }, vm32z: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: ZMM::ZMM2.into(), scale: Scale::Scale4, offset: Offset::from_i32(256) } },
+    "vpgatherdd zmm1 {k1}, dword ptr [rax + zmm2*4 + 256]",
+)]
+#[case::scatter(
+    Instruction::VscatterqpsVm64zMergemaskYmm { vm64z: VSIBMemory::BasedAndScaled { base: GPR::R8, index: ZMM::ZMM20.into(), scale: Scale::Scale1, offset: Offset::None }, mergemask: Masking::Merge { opmask: Opmask::K2 }, ymm: YMM::YMM5 },
+    "vscatterqps dword ptr [r8 + zmm20] {k2}, ymm5",
+)]
+fn test_format_intel(#[case] instruction: Instruction, #[case] expected: &str) {
+    assert_eq!(instruction.to_string(), expected);
+}
+
+#[rstest]
+#[case::prologue(&[0x55], "push rbp")]
+#[case::prologue(&[0x48, 0x89, 0xE5], "mov rbp, rsp")]
+#[case::stack(&[0x48, 0x83, 0xEC, 0x10], "sub rsp, 16")]
+#[case::store(&[0xC7, 0x44, 0x24, 0x08, 0x2A, 0x00, 0x00, 0x00], "mov dword ptr [rsp + 8], 42")]
+#[case::epilogue(&[0xC3], "ret")]
+#[case::lock(&[0xF0, 0x48, 0x01, 0x08], "lock add qword ptr [rax], rcx")]
+fn test_format_intel_decoded(#[case] bytes: &[u8], #[case] expected: &str) {
+    let (instruction, _) = decode(bytes).unwrap();
+    assert_eq!(instruction.to_string(), expected);
+}
+
+#[test]
+fn test_format_gpr() {
+    assert_eq!(GPR::RAX.to_string(), "rax");
+    assert_eq!(GPR::R10D.to_string(), "r10d");
+    assert_eq!(GPR::R15W.to_string(), "r15w");
+    assert_eq!(GPR::SPL.to_string(), "spl");
+    assert_eq!(GPR::DH.to_string(), "dh");
+}
+
+#[test]
+fn test_format_xmm() {
+    assert_eq!(XMM::XMM0.to_string(), "xmm0");
+    assert_eq!(XMM::XMM15.to_string(), "xmm15");
+    assert_eq!(XMM::from_name("XMM7"), Some(XMM::XMM7));
+    assert_eq!(XMM::XMM31.to_string(), "xmm31");
+    assert_eq!(XMM::from_name("xmm16"), Some(XMM::XMM16));
+    assert_eq!(XMM::from_name("xmm32"), None);
+}
+
+#[test]
+fn test_format_ymm() {
+    assert_eq!(YMM::YMM0.to_string(), "ymm0");
+    assert_eq!(YMM::YMM15.to_string(), "ymm15");
+    assert_eq!(YMM::from_name("YMM7"), Some(YMM::YMM7));
+    assert_eq!(YMM::YMM31.to_string(), "ymm31");
+    assert_eq!(YMM::from_name("ymm16"), Some(YMM::YMM16));
+    assert_eq!(YMM::from_name("ymm32"), None);
+}
+
+#[test]
+fn test_format_zmm() {
+    assert_eq!(ZMM::ZMM0.to_string(), "zmm0");
+    assert_eq!(ZMM::ZMM31.to_string(), "zmm31");
+    assert_eq!(ZMM::from_name("ZMM7"), Some(ZMM::ZMM7));
+    assert_eq!(ZMM::from_name("zmm32"), None);
+}
+
+#[test]
+fn test_format_opmask() {
+    assert_eq!(Opmask::K0.to_string(), "k0");
+    assert_eq!(Opmask::K7.to_string(), "k7");
+    assert_eq!(Opmask::from_name("K3"), Some(Opmask::K3));
+    assert_eq!(Opmask::from_name("k8"), None);
+}
+
+#[test]
+fn test_instruction_operands() {
+    let instruction = Instruction::CmpAxImm16 {
+        imm16: Immediate16::from_i16(-3),
+    };
+    assert_eq!(instruction.mnemonic(), "cmp");
+    assert_eq!(
+        instruction.operands().as_slice(),
+        &[
+            Operand::GPR { gpr: GPR::AX },
+            Operand::Immediate {
+                value: -3,
+                size: Size::Bit16
+            }
+        ]
+    );
+    assert!(Instruction::Cpuid.operands().is_empty());
+}
+
+const ATT: FormatterOptions = FormatterOptions::new(Syntax::Att);
+
+#[rstest]
+#[case::zo(Instruction::Nop, "nop")]
+#[case::ret(Instruction::RetImm16 { imm16: Immediate16::from_u16(16) }, "ret $16")]
+#[case::accumulator(Instruction::AddAlImm8 { imm8: Immediate8::from_i8(-1) }, "addb $-1, %al")]
+#[case::accumulator(Instruction::CmpRaxImm32 { imm32: Immediate32::from_i32(1000) }, "cmpq $1000, %rax")]
+#[case::jcc(Instruction::JccNeImm8 { imm8: Immediate8::from_i8(-2) }, "jne $-2")]
+#[case::shift(Instruction::ShrRm8One { rm8: GPR::BL.into() }, "shrb $1, %bl")]
+#[case::shift(Instruction::RcrRm64Cl { rm64: GPR::RAX.into() }, "rcrq %cl, %rax")]
+#[case::extension(Instruction::MovzxReg32Rm8 { reg32: GPR::EAX, rm8: GPR::BL.into() }, "movzbl %bl, %eax")]
+#[case::extension(
+    Instruction::MovsxReg64Rm16 { reg64: GPR::RCX, rm16: Memory::Based { base: GPR::RDI, offset: Offset::None }.into() },
+    "movswq (%rdi), %rcx",
+)]
+#[case::extension(Instruction::MovsxdReg64Rm32 { reg64: GPR::RAX, rm32: GPR::ECX.into() }, "movslq %ecx, %rax")]
+#[case::condition(Instruction::SetccERm8 { rm8: GPR::AL.into() }, "sete %al")]
+#[case::condition(Instruction::CmovccGeReg64Rm64 { reg64: GPR::RAX, rm64: GPR::RCX.into() }, "cmovgeq %rcx, %rax")]
+#[case::atomic(
+    Instruction::XaddRm64Reg64 { rm64: Memory::Based { base: GPR::RDX, offset: Offset::None }.into(), reg64: GPR::RAX },
+    "xaddq %rax, (%rdx)",
+)]
+#[case::atomic(
+    Instruction::Cmpxchg8bMem64 { mem64: Memory::Based { base: GPR::RDI, offset: Offset::None } },
+    "cmpxchg8b (%rdi)",
+)]
+#[case::atomic(
+    Instruction::Cmpxchg16bLockMem128 { mem128: Memory::Based { base: GPR::RDI, offset: Offset::None } },
+    "lock cmpxchg16b (%rdi)",
+)]
+#[case::rmi(
+    Instruction::ImulReg32Rm32Imm8 { reg32: GPR::EAX, rm32: GPR::ECX.into(), imm8: Immediate8::from_i8(10) },
+    "imull $10, %ecx, %eax",
+)]
+#[case::jmp(Instruction::JmpRm64 { rm64: GPR::R11.into() }, "jmpq *%r11")]
+#[case::call(Instruction::CallRm64 { rm64: Memory::RelativeToRIP { offset: Offset::from_i32(100) }.into() }, "callq *100(%rip)")]
+#[case::push(Instruction::PushReg64 { reg64: GPR::RBP }, "pushq %rbp")]
+#[case::push(Instruction::PushImm8 { imm8: Immediate8::from_i8(5) }, "push $5")]
+#[case::push(Instruction::PushImm16 { imm16: Immediate16::from_i16(-14880) }, "pushw $-14880")]
+#[case::reg_reg(Instruction::MovRm64Reg64 { rm64: GPR::RBP.into(), reg64: GPR::RSP }, "movq %rsp, %rbp")]
+#[case::reg_reg(Instruction::XorReg32Rm32 { reg32: GPR::R8D, rm32: GPR::EAX.into() }, "xorl %eax, %r8d")]
+#[case::reg8(Instruction::MovRm8Reg8 { rm8: GPR::AH.into(), reg8: GPR::BH }, "movb %bh, %ah")]
+#[case::reg16(Instruction::MovReg16Imm16 { reg16: GPR::R9W, imm16: Immediate16::from_i16(300) }, "movw $300, %r9w")]
+#[case::based(
+    Instruction::MovRm64Imm32 {
+        rm64: Memory::BasedAndScaled { base: GPR::RBP, index: GPR::RAX, scale: Scale::Scale4, offset: Offset::from_i8(-8) }.into(),
+        imm32: Immediate32::from_i32(5),
+    },
+    "movq $5, -8(%rbp,%rax,4)",
+)]
+#[case::based(
+    Instruction::AddReg64Rm64 { reg64: GPR::RAX, rm64: Memory::BasedAndScaled { base: GPR::RDI, index: GPR::RSI, scale: Scale::Scale1, offset: Offset::from_i32(16) }.into() },
+    "addq 16(%rdi,%rsi,1), %rax",
+)]
+#[case::based(
+    Instruction::SubRm16Imm8 { rm16: Memory::Based { base: GPR::R13, offset: Offset::from_i8(0) }.into(), imm8: Immediate8::from_i8(2) },
+    "subw $2, (%r13)",
+)]
+#[case::scaled(
+    Instruction::PopRm64 { rm64: Memory::Scaled { index: GPR::RCX, scale: Scale::Scale8, offset: Offset::from_i32(-64) }.into() },
+    "popq -64(,%rcx,8)",
+)]
+#[case::lea(
+    Instruction::LeaReg32Mem64 { reg32: GPR::ECX, mem64: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) } },
+    "leal 8(%rsp), %ecx",
+)]
+#[case::string(Instruction::LodsW, "lodsw")]
+#[case::string(Instruction::MovsRepD, "rep movsl")]
+#[case::string(Instruction::ScasRepeD, "repe scasl")]
+#[case::lock(
+    Instruction::BtsLockRm32Imm8 { rm32: Memory::Based { base: GPR::RAX, offset: Offset::None }.into(), imm8: Immediate8::from_u8(3) },
+    "lock btsl $3, (%rax)",
+)]
+#[case::bits(Instruction::BtsRm32Reg32 { rm32: GPR::EAX.into(), reg32: GPR::ECX }, "btsl %ecx, %eax")]
+#[case::bits(Instruction::LzcntReg16Rm16 { reg16: GPR::AX, rm16: GPR::BX.into() }, "lzcntw %bx, %ax")]
+#[case::bmi(Instruction::SarxReg32Rm32Vreg32 { reg32: GPR::EAX, rm32: GPR::ECX.into(), vreg32: GPR::EDX }, "sarxl %edx, %ecx, %eax")]
+#[case::bmi(Instruction::RorxReg64Rm64Imm8 { reg64: GPR::RAX, rm64: GPR::RCX.into(), imm8: Immediate8::from_u8(5) }, "rorxq $5, %rcx, %rax")]
+#[case::sse(
+    Instruction::AddsdXmmXmmm64 { xmm: XMM::XMM0, xmmm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "addsd (%rax), %xmm0",
+)]
+#[case::sse(Instruction::MovssXmmm32Xmm { xmmm32: XMM::XMM1.into(), xmm: XMM::XMM2 }, "movss %xmm2, %xmm1")]
+#[case::sse(Instruction::Cvtsi2sdXmmRm32 { xmm: XMM::XMM0, rm32: GPR::EAX.into() }, "cvtsi2sd %eax, %xmm0")]
+#[case::sse(
+    Instruction::Cvtsi2sdXmmRm64 { xmm: XMM::XMM0, rm64: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "cvtsi2sdq (%rax), %xmm0",
+)]
+#[case::sse(Instruction::Cvttsd2siReg64Xmmm64 { reg64: GPR::RAX, xmmm64: XMM::XMM1.into() }, "cvttsd2si %xmm1, %rax")]
+#[case::sse(Instruction::MovqRm64Xmm { rm64: GPR::RAX.into(), xmm: XMM::XMM1 }, "movq %xmm1, %rax")]
+#[case::packed(
+    Instruction::MovdqaXmmXmmm128 { xmm: XMM::XMM1, xmmm128: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "movdqa (%rax), %xmm1",
+)]
+#[case::packed(
+    Instruction::PextrwReg32XmmImm8 { reg32: GPR::EAX, xmm: XMM::XMM1, imm8: Immediate8::from_u8(1) },
+    "pextrw $1, %xmm1, %eax",
+)]
+#[case::packed(
+    Instruction::PshufdXmmXmmm128Imm8 { xmm: XMM::XMM1, xmmm128: XMM::XMM2.into(), imm8: Immediate8::from_u8(0x1B) },
+    "pshufd $27, %xmm2, %xmm1",
+)]
+#[case::packed(Instruction::BlendvpsXmmXmmm128Xmm0 { xmm: XMM::XMM1, xmmm128: XMM::XMM2.into() }, "blendvps %xmm0, %xmm2, %xmm1")]
+#[case::avx(
+    Instruction::VpadddYmmVymmYmmm256 { ymm: YMM::YMM0, vymm: YMM::YMM1, ymmm256: YMM::YMM2.into() },
+    "vpaddd %ymm2, %ymm1, %ymm0",
+)]
+#[case::avx(
+    Instruction::VmovdquYmmm256Ymm { ymmm256: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(32) }.into(), ymm: YMM::YMM3 },
+    "vmovdqu %ymm3, 32(%rsp)",
+)]
+#[case::avx(
+    Instruction::VpbroadcastdXmmXmmm32 { xmm: XMM::XMM1, xmmm32: Memory::Based { base: GPR::RAX, offset: Offset::None }.into() },
+    "vpbroadcastd (%rax), %xmm1",
+)]
+#[case::avx512(
+    Instruction::VaddpsZmmMaskVzmmZmmm512b32 { zmm: ZMM::ZMM0, mask: Masking::Zero { opmask: Opmask::K1 }, vzmm: ZMM::ZMM1, zmmm512b32: ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } } },
+    "vaddps 4(%rax){1to16}, %zmm1, %zmm0 {%k1} {z}",
+)]
+#[case::avx512(
+    Instruction::VaddpsZmmMaskVzmmZmmrEr { zmm: ZMM::ZMM0, mask: Masking::Merge { opmask: Opmask::K1 }, vzmm: ZMM::ZMM1, zmmr: ZMM::ZMM31, er: Rounding::Up },
+    "vaddps {ru-sae}, %zmm31, %zmm1, %zmm0 {%k1}",
+)]
+#[case::avx512(
+    Instruction::VcmppsKMergemaskVzmmZmmrSaeImm8 { k: Opmask::K1, mergemask: Masking::Merge { opmask: Opmask::K2 }, vzmm: ZMM::ZMM1, zmmr: ZMM::ZMM2, imm8: Immediate8::from_u8(1) },
+    "vcmpps $1, {sae}, %zmm2, %zmm1, %k1 {%k2}",
+)]
+#[case::avx512(
+    Instruction::KmovwKKm16 { k: Opmask::K1, km16: OpmaskOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } } },
+    "kmovw 4(%rax), %k1",
+)]
+#[case::gather(
+    Instruction::VgatherdpdYmmVm32xVymm { ymm: YMM::YMM1, vm32x: VSIBMemory::BasedAndScaled { base: GPR::RAX, index: XMM::XMM2.into(), scale: Scale::Scale8, offset: Offset::from_i8(8) }, vymm: YMM::YMM3 },
+    "vgatherdpd %ymm3, 8(%rax,%xmm2,8), %ymm1",
+)]
+#[case::gather(
+    Instruction::VgatherqpdZmmMergemaskVm64z { zmm: ZMM::ZMM30, mergemask: Masking::Merge { opmask: Opmask::K7 }, vm64z: VSIBMemory::Scaled { index: ZMM::ZMM1.into(), scale: Scale::Scale8, offset: Offset::from_i32(1024) } },
+    "vgatherqpd 1024(,%zmm1,8), %zmm30 {%k7}",
+)]
+#[case::scatter(
+    Instruction::VpscatterdqVm32yMergemaskZmm { vm32y: VSIBMemory::BasedAndScaled { base: GPR::RDI, index: YMM::YMM3.into(), scale: Scale::Scale4, offset: Offset::from_i8(-8) }, mergemask: Masking::Merge { opmask: Opmask::K3 }, zmm: ZMM::ZMM2 },
+    "vpscatterdq %zmm2, -8(%rdi,%ymm3,4) {%k3}",
+)]
+fn test_format_att(#[case] instruction: Instruction, #[case] expected: &str) {
+    assert_eq!(instruction.display(ATT).to_string(), expected);
+}
+
+#[test]
+fn test_format_options() {
+    let instruction = Instruction::MovRm32Reg32 {
+        rm32: GPR::EDI.into(),
+        reg32: GPR::ESI,
+    };
+    assert_eq!(FormatterOptions::default().syntax, Syntax::Intel);
+    assert_eq!(
+        instruction.display(FormatterOptions::default()).to_string(),
+        instruction.to_string()
+    );
+    assert_eq!(
+        instruction.display(FormatterOptions::new(Syntax::Intel)).to_string(),
+        "mov edi, esi"
+    );
+    assert_eq!(instruction.display(ATT).to_string(), "movl %esi, %edi");
+}
@@ -0,0 +1,33 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BH }, &[0xF6, 0xFF])]
+fn test_idiv_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { idiv::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9W }, &[0x66, 0x41, 0xF7, 0xF9])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0xF7, 0x38])]
+fn test_idiv_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { idiv::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, &[0xF7, 0xF9])]
+fn test_idiv_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { idiv::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R11 }, &[0x49, 0xF7, 0xFB])]
+fn test_idiv_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { idiv::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
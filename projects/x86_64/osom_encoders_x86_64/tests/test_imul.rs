@@ -0,0 +1,129 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BH }, &[0xF6, 0xEF])]
+fn test_imul_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9W }, &[0x66, 0x41, 0xF7, 0xE9])]
+fn test_imul_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, &[0xF7, 0xE9])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, &[0x41, 0xF7, 0x2C, 0x24])]
+fn test_imul_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R11 }, &[0x49, 0xF7, 0xEB])]
+fn test_imul_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::AX, GPROrMemory::GPR { gpr: GPR::BX }, &[0x66, 0x0F, 0xAF, 0xC3])]
+fn test_imul_reg16_rm16(#[case] reg16: GPR, #[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_reg16_rm16(reg16, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::ESP }, &[0x44, 0x0F, 0xAF, 0xC4])]
+fn test_imul_reg32_rm32(#[case] reg32: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_reg32_rm32(reg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RDX, GPROrMemory::GPR { gpr: GPR::R13 }, &[0x49, 0x0F, 0xAF, 0xD5])]
+#[case::memory(GPR::R14, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(16) } }, &[0x4C, 0x0F, 0xAF, 0x74, 0x24, 0x10])]
+fn test_imul_reg64_rm64(#[case] reg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { imul::encode_reg64_rm64(reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::AX, GPROrMemory::GPR { gpr: GPR::CX }, Immediate8::from_i8(-3), &[0x66, 0x6B, 0xC1, 0xFD])]
+fn test_imul_reg16_rm16_imm8(
+    #[case] reg16: GPR,
+    #[case] rm16: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg16_rm16_imm8(reg16, rm16, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EDI, GPROrMemory::GPR { gpr: GPR::R15D }, Immediate8::from_i8(100), &[0x41, 0x6B, 0xFF, 0x64])]
+fn test_imul_reg32_rm32_imm8(
+    #[case] reg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg32_rm32_imm8(reg32, rm32, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::R10, GPROrMemory::GPR { gpr: GPR::RAX }, Immediate8::from_i8(-1), &[0x4C, 0x6B, 0xD0, 0xFF])]
+#[case::memory(GPR::RCX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, Immediate8::from_i8(-1), &[0x49, 0x6B, 0x4D, 0x00, 0xFF])]
+fn test_imul_reg64_rm64_imm8(
+    #[case] reg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg64_rm64_imm8(reg64, rm64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::SI, GPROrMemory::GPR { gpr: GPR::R8W }, Immediate16::from_i16(1000), &[0x66, 0x41, 0x69, 0xF0, 0xE8, 0x03])]
+fn test_imul_reg16_rm16_imm16(
+    #[case] reg16: GPR,
+    #[case] rm16: GPROrMemory,
+    #[case] imm16: Immediate16,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg16_rm16_imm16(reg16, rm16, imm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::EBX }, Immediate32::from_i32(100000), &[0x69, 0xC3, 0xA0, 0x86, 0x01, 0x00])]
+#[case::memory(GPR::R9D, GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(-8) } }, Immediate32::from_i32(100000), &[0x44, 0x69, 0x0D, 0xF8, 0xFF, 0xFF, 0xFF, 0xA0, 0x86, 0x01, 0x00])]
+fn test_imul_reg32_rm32_imm32(
+    #[case] reg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] imm32: Immediate32,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg32_rm32_imm32(reg32, rm32, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RBP, GPROrMemory::GPR { gpr: GPR::R12 }, Immediate32::from_i32(-1000), &[0x49, 0x69, 0xEC, 0x18, 0xFC, 0xFF, 0xFF])]
+fn test_imul_reg64_rm64_imm32(
+    #[case] reg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] imm32: Immediate32,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { imul::encode_reg64_rm64_imm32(reg64, rm64, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
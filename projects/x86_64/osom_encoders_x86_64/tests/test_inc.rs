@@ -0,0 +1,41 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AL }, &[0xFE, 0xC0])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(0) } }, &[0xFE, 0x05, 0x00, 0x00, 0x00, 0x00])]
+fn test_inc_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { inc::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BP }, &[0x66, 0xFF, 0xC5])]
+fn test_inc_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { inc::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, &[0xFF, 0xC1])]
+fn test_inc_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { inc::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R15 }, &[0x49, 0xFF, 0xC7])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, &[0x49, 0xFF, 0x45, 0x00])]
+fn test_inc_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { inc::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xF0, 0x48, 0xFF, 0x00])]
+fn test_inc_lock_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { inc::encode_lock_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -380,3 +380,44 @@ fn test_jcc_long_s_patch(#[case] imm32: i32, #[case] expected: &[u8]) {
     assert_eq!(instr.as_slice(), expected);
     assert_eq!(instr.as_slice().len(), JCC_LONG_LENGTH);
 }
+
+#[test]
+fn test_jcc_condition() {
+    let imm8 = Immediate8::from_i8(0x12);
+    let imm32 = Immediate32::from_i32(0x100);
+    let named = [
+        (Condition::A, unsafe { jcc::encode_a_imm8(imm8) }, unsafe {
+            jcc::encode_a_imm32(imm32)
+        }),
+        (Condition::C, unsafe { jcc::encode_c_imm8(imm8) }, unsafe {
+            jcc::encode_c_imm32(imm32)
+        }),
+        (Condition::E, unsafe { jcc::encode_e_imm8(imm8) }, unsafe {
+            jcc::encode_e_imm32(imm32)
+        }),
+        (Condition::GE, unsafe { jcc::encode_ge_imm8(imm8) }, unsafe {
+            jcc::encode_ge_imm32(imm32)
+        }),
+        (Condition::NO, unsafe { jcc::encode_no_imm8(imm8) }, unsafe {
+            jcc::encode_no_imm32(imm32)
+        }),
+        (Condition::PE, unsafe { jcc::encode_pe_imm8(imm8) }, unsafe {
+            jcc::encode_pe_imm32(imm32)
+        }),
+        (Condition::PO, unsafe { jcc::encode_po_imm8(imm8) }, unsafe {
+            jcc::encode_po_imm32(imm32)
+        }),
+        (Condition::S, unsafe { jcc::encode_s_imm8(imm8) }, unsafe {
+            jcc::encode_s_imm32(imm32)
+        }),
+    ];
+    for (condition, short, long) in named {
+        assert_eq!(unsafe { jcc::encode_imm8(condition, imm8) }, short, "{condition:?}");
+        assert_eq!(unsafe { jcc::encode_imm32(condition, imm32) }, long, "{condition:?}");
+    }
+
+    let instr = unsafe { jcc::encode_imm8(Condition::GE, imm8) };
+    assert_eq!(instr.as_slice(), &[0x7D, 0x12]);
+    let instr = unsafe { jcc::encode_imm32(Condition::P, imm32) };
+    assert_eq!(instr.as_slice(), &[0x0F, 0x8A, 0x00, 0x01, 0x00, 0x00]);
+}
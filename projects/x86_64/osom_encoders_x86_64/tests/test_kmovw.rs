@@ -0,0 +1,42 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K1, OpmaskOrMemory::Opmask { opmask: Opmask::K2 }, &[0xC5, 0xF8, 0x90, 0xCA])]
+#[case::k(Opmask::K7, OpmaskOrMemory::Opmask { opmask: Opmask::K0 }, &[0xC5, 0xF8, 0x90, 0xF8])]
+#[case::memory(Opmask::K1, OpmaskOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xF8, 0x90, 0x08])]
+#[case::memory(Opmask::K2, OpmaskOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0xC1, 0x78, 0x90, 0x54, 0x24, 0x08])]
+#[case::memory(Opmask::K3, OpmaskOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x78, 0x90, 0x9D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_kmovw_k_km16(#[case] k: Opmask, #[case] km16: OpmaskOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { kmovw::encode_k_km16(k, km16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(Memory::Based { base: GPR::RAX, offset: Offset::None }, Opmask::K1, &[0xC5, 0xF8, 0x91, 0x08])]
+#[case::memory(Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) }, Opmask::K7, &[0xC4, 0xC1, 0x78, 0x91, 0x7C, 0x24, 0x08])]
+#[case::memory(Memory::Based { base: GPR::RBP, offset: Offset::from_i32(-300) }, Opmask::K0, &[0xC5, 0xF8, 0x91, 0x85, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_kmovw_mem16_k(#[case] mem16: Memory, #[case] k: Opmask, #[case] expected: &[u8]) {
+    let instr = unsafe { kmovw::encode_mem16_k(mem16, k) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::k(Opmask::K1, GPR::EAX, &[0xC5, 0xF8, 0x92, 0xC8])]
+#[case::k(Opmask::K7, GPR::R15D, &[0xC4, 0xC1, 0x78, 0x92, 0xFF])]
+#[case::k(Opmask::K0, GPR::EBP, &[0xC5, 0xF8, 0x92, 0xC5])]
+fn test_kmovw_k_reg32(#[case] k: Opmask, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { kmovw::encode_k_reg32(k, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EAX, Opmask::K1, &[0xC5, 0xF8, 0x93, 0xC1])]
+#[case::gpr(GPR::R15D, Opmask::K7, &[0xC5, 0x78, 0x93, 0xFF])]
+#[case::gpr(GPR::ESP, Opmask::K0, &[0xC5, 0xF8, 0x93, 0xE0])]
+fn test_kmovw_reg32_kr(#[case] reg32: GPR, #[case] kr: Opmask, #[case] expected: &[u8]) {
+    let instr = unsafe { kmovw::encode_reg32_kr(reg32, kr) };
+    assert_eq!(instr.as_slice(), expected);
+}
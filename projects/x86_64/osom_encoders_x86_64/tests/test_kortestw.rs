@@ -0,0 +1,13 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K1, Opmask::K2, &[0xC5, 0xF8, 0x98, 0xCA])]
+#[case::k(Opmask::K7, Opmask::K0, &[0xC5, 0xF8, 0x98, 0xF8])]
+#[case::k(Opmask::K0, Opmask::K6, &[0xC5, 0xF8, 0x98, 0xC6])]
+fn test_kortestw_k_kr(#[case] k: Opmask, #[case] kr: Opmask, #[case] expected: &[u8]) {
+    let instr = unsafe { kortestw::encode_k_kr(k, kr) };
+    assert_eq!(instr.as_slice(), expected);
+}
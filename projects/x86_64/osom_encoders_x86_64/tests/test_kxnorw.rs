@@ -0,0 +1,13 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K1, Opmask::K2, Opmask::K3, &[0xC5, 0xEC, 0x46, 0xCB])]
+#[case::k(Opmask::K0, Opmask::K7, Opmask::K5, &[0xC5, 0xC4, 0x46, 0xC5])]
+#[case::k(Opmask::K7, Opmask::K0, Opmask::K1, &[0xC5, 0xFC, 0x46, 0xF9])]
+fn test_kxnorw_k_vk_kr(#[case] k: Opmask, #[case] vk: Opmask, #[case] kr: Opmask, #[case] expected: &[u8]) {
+    let instr = unsafe { kxnorw::encode_k_vk_kr(k, vk, kr) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,22 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::byte(unsafe { lods::encode_b() }, &[0xAC])]
+#[case::word(unsafe { lods::encode_w() }, &[0x66, 0xAD])]
+#[case::dword(unsafe { lods::encode_d() }, &[0xAD])]
+#[case::qword(unsafe { lods::encode_q() }, &[0x48, 0xAD])]
+fn test_lods(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { lods::encode_rep_b() }, &[0xF3, 0xAC])]
+#[case::word(unsafe { lods::encode_rep_w() }, &[0xF3, 0x66, 0xAD])]
+#[case::dword(unsafe { lods::encode_rep_d() }, &[0xF3, 0xAD])]
+#[case::qword(unsafe { lods::encode_rep_q() }, &[0xF3, 0x48, 0xAD])]
+fn test_lods_rep(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -71,10 +71,14 @@ fn test_mov_rm32_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immedi
 #[case::gpr(GPROrMemory::GPR { gpr: GPR::RBX }, Immediate32::from_i32(5), &[0x48, 0xC7, 0xC3, 0x05, 0x00, 0x00, 0x00])]
 #[case::gpr(GPROrMemory::GPR { gpr: GPR::R10 }, Immediate32::from_i32(-1), &[0x49, 0xC7, 0xC2, 0xFF, 0xFF, 0xFF, 0xFF])]
 #[case::gpr(GPROrMemory::GPR { gpr: GPR::R15 }, Immediate32::from_i32(-15), &[0x49, 0xC7, 0xC7, 0xF1, 0xFF, 0xFF, 0xFF])]
-#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::None } }, Immediate32::from_i32(3), &[0xC7, 0x04, 0x24, 0x03, 0x00, 0x00, 0x00])]
-#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(3), &[0x41, 0xC7, 0x04, 0x24, 0x03, 0x00, 0x00, 0x00])]
-#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, Immediate32::from_i32(4), &[0xC7, 0x45, 0x00, 0x04, 0x00, 0x00, 0x00])]
-#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, Immediate32::from_i32(4), &[0x41, 0xC7, 0x45, 0x00, 0x04, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::None } }, Immediate32::from_i32(3), &[0x48, 0xC7, 0x04, 0x24, 0x03, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(3), &[0x49, 0xC7, 0x04, 0x24, 0x03, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, Immediate32::from_i32(4), &[0x48, 0xC7, 0x45, 0x00, 0x04, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, Immediate32::from_i32(4), &[0x49, 0xC7, 0x45, 0x00, 0x04, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-8) } }, Immediate32::from_i32(5), &[0x48, 0xC7, 0x45, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RBP, index: GPR::RAX, scale: Scale::Scale4, offset: Offset::from_i8(-8) } }, Immediate32::from_i32(5), &[0x48, 0xC7, 0x44, 0x85, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Scaled { index: GPR::R9, scale: Scale::Scale8, offset: Offset::None } }, Immediate32::from_i32(-1), &[0x4A, 0xC7, 0x04, 0xCD, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, Immediate32::from_i32(1), &[0x48, 0xC7, 0x05, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])]
 fn test_mov_rm64_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
     let instr = unsafe { mov::encode_rm64_imm32(gpr_or_memory, imm32) };
     assert_eq!(instr.as_slice(), expected);
@@ -129,6 +133,8 @@ fn test_mov_reg64_imm64(#[case] reg64: GPR, #[case] imm64: Immediate64, #[case]
 #[case::gpr(GPR::BL, GPROrMemory::GPR { gpr: GPR::R10B }, &[0x41, 0x8A, 0xDA])]
 #[case::gpr(GPR::R11B, GPROrMemory::GPR { gpr: GPR::DL }, &[0x44, 0x8A, 0xDA,])]
 #[case::gpr(GPR::R11B, GPROrMemory::GPR { gpr: GPR::R12B }, &[0x45, 0x8A, 0xDC])]
+#[case::gpr(GPR::SIL, GPROrMemory::GPR { gpr: GPR::AL }, &[0x40, 0x8A, 0xF0])]
+#[case::gpr(GPR::DIL, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x40, 0x8A, 0x38])]
 fn test_mov_reg8_rm8(#[case] reg8: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
     let instr = unsafe { mov::encode_reg8_rm8(reg8, rm8) };
     assert_eq!(instr.as_slice(), expected);
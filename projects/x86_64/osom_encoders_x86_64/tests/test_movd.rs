@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(XMM::XMM0, GPROrMemory::GPR { gpr: GPR::EAX }, &[0x66, 0x0F, 0x6E, 0xC0])]
+#[case::gpr(XMM::XMM9, GPROrMemory::GPR { gpr: GPR::R10D }, &[0x66, 0x45, 0x0F, 0x6E, 0xCA])]
+#[case::memory(XMM::XMM2, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0x0F, 0x6E, 0x10])]
+#[case::memory(XMM::XMM11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0x66, 0x45, 0x0F, 0x6E, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0x66, 0x41, 0x0F, 0x6E, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_movd_xmm_rm32(#[case] xmm: XMM, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movd::encode_xmm_rm32(xmm, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, XMM::XMM0, &[0x66, 0x0F, 0x7E, 0xC0])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10D }, XMM::XMM9, &[0x66, 0x45, 0x0F, 0x7E, 0xCA])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, &[0x66, 0x0F, 0x7E, 0x10])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, &[0x66, 0x45, 0x0F, 0x7E, 0x5C, 0x24, 0x08])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, &[0x66, 0x41, 0x0F, 0x7E, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_movd_rm32_xmm(#[case] rm32: GPROrMemory, #[case] xmm: XMM, #[case] expected: &[u8]) {
+    let instr = unsafe { movd::encode_rm32_xmm(rm32, xmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
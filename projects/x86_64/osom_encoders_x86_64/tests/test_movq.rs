@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(XMM::XMM0, GPROrMemory::GPR { gpr: GPR::RAX }, &[0x66, 0x48, 0x0F, 0x6E, 0xC0])]
+#[case::gpr(XMM::XMM9, GPROrMemory::GPR { gpr: GPR::R10 }, &[0x66, 0x4D, 0x0F, 0x6E, 0xCA])]
+#[case::memory(XMM::XMM2, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x66, 0x48, 0x0F, 0x6E, 0x10])]
+#[case::memory(XMM::XMM11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0x66, 0x4D, 0x0F, 0x6E, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0x66, 0x49, 0x0F, 0x6E, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_movq_xmm_rm64(#[case] xmm: XMM, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movq::encode_xmm_rm64(xmm, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, XMM::XMM0, &[0x66, 0x48, 0x0F, 0x7E, 0xC0])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10 }, XMM::XMM9, &[0x66, 0x4D, 0x0F, 0x7E, 0xCA])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, &[0x66, 0x48, 0x0F, 0x7E, 0x10])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, &[0x66, 0x4D, 0x0F, 0x7E, 0x5C, 0x24, 0x08])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, &[0x66, 0x49, 0x0F, 0x7E, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_movq_rm64_xmm(#[case] rm64: GPROrMemory, #[case] xmm: XMM, #[case] expected: &[u8]) {
+    let instr = unsafe { movq::encode_rm64_xmm(rm64, xmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,22 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::byte(unsafe { movs::encode_b() }, &[0xA4])]
+#[case::word(unsafe { movs::encode_w() }, &[0x66, 0xA5])]
+#[case::dword(unsafe { movs::encode_d() }, &[0xA5])]
+#[case::qword(unsafe { movs::encode_q() }, &[0x48, 0xA5])]
+fn test_movs(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { movs::encode_rep_b() }, &[0xF3, 0xA4])]
+#[case::word(unsafe { movs::encode_rep_w() }, &[0xF3, 0x66, 0xA5])]
+#[case::dword(unsafe { movs::encode_rep_d() }, &[0xF3, 0xA5])]
+#[case::qword(unsafe { movs::encode_rep_q() }, &[0xF3, 0x48, 0xA5])]
+fn test_movs_rep(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,47 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::AX, GPROrMemory::GPR { gpr: GPR::BL }, &[0x66, 0x0F, 0xBE, 0xC3])]
+#[case::gpr(GPR::R12W, GPROrMemory::GPR { gpr: GPR::SPL }, &[0x66, 0x44, 0x0F, 0xBE, 0xE4])]
+fn test_movsx_reg16_rm8(#[case] reg16: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsx::encode_reg16_rm8(reg16, rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::AH }, &[0x0F, 0xBE, 0xC4])]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::SIL }, &[0x40, 0x0F, 0xBE, 0xC6])]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::R15B }, &[0x45, 0x0F, 0xBE, 0xC7])]
+#[case::memory(GPR::R8D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R9, offset: Offset::None } }, &[0x45, 0x0F, 0xBE, 0x01])]
+fn test_movsx_reg32_rm8(#[case] reg32: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsx::encode_reg32_rm8(reg32, rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::BL }, &[0x48, 0x0F, 0xBE, 0xC3])]
+#[case::gpr(GPR::RDI, GPROrMemory::GPR { gpr: GPR::DIL }, &[0x48, 0x0F, 0xBE, 0xFF])]
+#[case::memory(GPR::RCX, GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(64) } }, &[0x48, 0x0F, 0xBE, 0x0D, 0x40, 0x00, 0x00, 0x00])]
+fn test_movsx_reg64_rm8(#[case] reg64: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsx::encode_reg64_rm8(reg64, rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::ECX, GPROrMemory::GPR { gpr: GPR::DX }, &[0x0F, 0xBF, 0xCA])]
+#[case::memory(GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-2) } }, &[0x0F, 0xBF, 0x55, 0xFE])]
+fn test_movsx_reg32_rm16(#[case] reg32: GPR, #[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsx::encode_reg32_rm16(reg32, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::R11, GPROrMemory::GPR { gpr: GPR::AX }, &[0x4C, 0x0F, 0xBF, 0xD8])]
+#[case::memory(GPR::RAX, GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RSP, index: GPR::R10, scale: Scale::Scale2, offset: Offset::None } }, &[0x4A, 0x0F, 0xBF, 0x04, 0x54])]
+fn test_movsx_reg64_rm16(#[case] reg64: GPR, #[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsx::encode_reg64_rm16(reg64, rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
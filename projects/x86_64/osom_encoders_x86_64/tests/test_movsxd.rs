@@ -0,0 +1,13 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::ECX }, &[0x48, 0x63, 0xC1])]
+#[case::gpr(GPR::R9, GPROrMemory::GPR { gpr: GPR::R10D }, &[0x4D, 0x63, 0xCA])]
+#[case::memory(GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i8(-2) } }, &[0x48, 0x63, 0x55, 0xFE])]
+fn test_movsxd_reg64_rm32(#[case] reg64: GPR, #[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { movsxd::encode_reg64_rm32(reg64, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,33 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::SIL }, &[0x40, 0xF6, 0xDE])]
+fn test_neg_rm8(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { neg::encode_rm8(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::DX }, &[0x66, 0xF7, 0xDA])]
+fn test_neg_rm16(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { neg::encode_rm16(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R13D }, &[0x41, 0xF7, 0xDD])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::RCX, scale: Scale::Scale4, offset: Offset::from_i32(256) } }, &[0xF7, 0x9C, 0x88, 0x00, 0x01, 0x00, 0x00])]
+fn test_neg_rm32(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { neg::encode_rm32(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, &[0x48, 0xF7, 0xD8])]
+fn test_neg_rm64(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { neg::encode_rm64(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
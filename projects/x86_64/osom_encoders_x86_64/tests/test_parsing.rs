@@ -0,0 +1,415 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::decoders::*;
+use osom_encoders_x86_64::models::*;
+use osom_encoders_x86_64::parsing::*;
+
+fn parse_single(source: &str) -> Vec<u8> {
+    let encoded = parse_intel(source).unwrap();
+    assert_eq!(encoded.len(), 1);
+    encoded[0].as_slice().to_vec()
+}
+
+#[rstest]
+#[case::zo("ret", &[0xC3])]
+#[case::zo("int3", &[0xCC])]
+#[case::prologue("push rbp", &[0x55])]
+#[case::prologue("mov rbp, rsp", &[0x48, 0x89, 0xE5])]
+#[case::upper_case("MOV RAX, RBX", &[0x48, 0x89, 0xD8])]
+#[case::reg_reg("xor r8d, r8d", &[0x45, 0x31, 0xC0])]
+#[case::reg8("mov ah, bh", &[0x88, 0xFC])]
+#[case::imm8_form("add rax, 1", &[0x48, 0x83, 0xC0, 0x01])]
+#[case::accumulator("add rax, 1000", &[0x48, 0x05, 0xE8, 0x03, 0x00, 0x00])]
+#[case::accumulator("add eax, 1000", &[0x05, 0xE8, 0x03, 0x00, 0x00])]
+#[case::accumulator("add ax, 1000", &[0x66, 0x05, 0xE8, 0x03])]
+#[case::accumulator("add al, 5", &[0x04, 0x05])]
+#[case::accumulator("add rax, 255", &[0x48, 0x05, 0xFF, 0x00, 0x00, 0x00])]
+#[case::imm32_form("sub rsp, 0x80", &[0x48, 0x81, 0xEC, 0x80, 0x00, 0x00, 0x00])]
+#[case::unsigned("mov al, 255", &[0xB0, 0xFF])]
+#[case::unsigned("mov eax, 0xFFFFFFFF", &[0xB8, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::unsigned("int 0x80", &[0xCD, 0x80])]
+#[case::unsigned("ret 8", &[0xC2, 0x08, 0x00])]
+#[case::imm64("mov rax, 0xFFFFFFFF", &[0x48, 0xB8, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00])]
+#[case::imm64("mov r15, 0x123456789ABCDEF0", &[0x49, 0xBF, 0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12])]
+#[case::sign_extended("mov rax, -1", &[0x48, 0xC7, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF])]
+#[case::push("push 5", &[0x6A, 0x05])]
+#[case::push("push 1000", &[0x68, 0xE8, 0x03, 0x00, 0x00])]
+#[case::push("push word 1000", &[0x66, 0x68, 0xE8, 0x03])]
+#[case::jmp("jmp -2", &[0xEB, 0xFE])]
+#[case::jmp("jmp rax", &[0xFF, 0xE0])]
+#[case::jcc("jne 1000", &[0x0F, 0x85, 0xE8, 0x03, 0x00, 0x00])]
+#[case::memory("add rax, [rdi + rsi*8 + 16]", &[0x48, 0x03, 0x44, 0xF7, 0x10])]
+#[case::memory("mov qword ptr [rbp + rax*4 - 8], 5", &[0x48, 0xC7, 0x44, 0x85, 0xF8, 0x05, 0x00, 0x00, 0x00])]
+#[case::memory("mov byte ptr [rax], 1", &[0xC6, 0x00, 0x01])]
+#[case::memory("cmp byte ptr [rdi + 1], -1", &[0x80, 0x7F, 0x01, 0xFF])]
+#[case::memory("mov rax, [r13]", &[0x49, 0x8B, 0x45, 0x00])]
+#[case::memory("mov rax, [rbx + rsp]", &[0x48, 0x8B, 0x04, 0x1C])]
+#[case::memory("mov eax, [rcx*4]", &[0x8B, 0x04, 0x8D, 0x00, 0x00, 0x00, 0x00])]
+#[case::lea("lea rcx, [rsp + 8]", &[0x48, 0x8D, 0x4C, 0x24, 0x08])]
+#[case::rip("lea rax, [rip + 16]", &[0x48, 0x8D, 0x05, 0x10, 0x00, 0x00, 0x00])]
+#[case::rip("call qword ptr [rip + 0x10]", &[0xFF, 0x15, 0x10, 0x00, 0x00, 0x00])]
+#[case::shift_one("shl eax, 1", &[0xD1, 0xE0])]
+#[case::shift_imm8("shl eax, 2", &[0xC1, 0xE0, 0x02])]
+#[case::shift_imm8("sar rdx, 255", &[0x48, 0xC1, 0xFA, 0xFF])]
+#[case::shift_cl("rol byte ptr [rax], cl", &[0xD2, 0x00])]
+#[case::shift_cl("rcr r11w, cl", &[0x66, 0x41, 0xD3, 0xDB])]
+#[case::mul("mul byte ptr [rax]", &[0xF6, 0x20])]
+#[case::mul("idiv rcx", &[0x48, 0xF7, 0xF9])]
+#[case::mul("imul rdx, r8", &[0x49, 0x0F, 0xAF, 0xD0])]
+#[case::rmi("imul eax, ecx, 10", &[0x6B, 0xC1, 0x0A])]
+#[case::rmi("imul ax, bx, 1000", &[0x66, 0x69, 0xC3, 0xE8, 0x03])]
+#[case::rmi("imul rax, [rbx], -200", &[0x48, 0x69, 0x03, 0x38, 0xFF, 0xFF, 0xFF])]
+#[case::alu("test eax, eax", &[0x85, 0xC0])]
+#[case::alu("and rsp, -16", &[0x48, 0x83, 0xE4, 0xF0])]
+#[case::alu("or al, 0x80", &[0x0C, 0x80])]
+#[case::alu("sbb ecx, [rbp - 4]", &[0x1B, 0x4D, 0xFC])]
+#[case::alu("inc dword ptr [rax]", &[0xFF, 0x00])]
+#[case::alu("neg rax", &[0x48, 0xF7, 0xD8])]
+#[case::extension("movzx eax, byte ptr [rax]", &[0x0F, 0xB6, 0x00])]
+#[case::extension("movzx rax, bl", &[0x48, 0x0F, 0xB6, 0xC3])]
+#[case::extension("movsx ecx, ah", &[0x0F, 0xBE, 0xCC])]
+#[case::extension("movsx r8, word ptr [rsp]", &[0x4C, 0x0F, 0xBF, 0x04, 0x24])]
+#[case::extension("movsxd rax, ecx", &[0x48, 0x63, 0xC1])]
+#[case::condition("sete al", &[0x0F, 0x94, 0xC0])]
+#[case::condition("setne spl", &[0x40, 0x0F, 0x95, 0xC4])]
+#[case::condition("setg byte ptr [rax]", &[0x0F, 0x9F, 0x00])]
+#[case::condition("setpe [rax]", &[0x0F, 0x9A, 0x00])]
+#[case::condition("cmove ax, bx", &[0x66, 0x0F, 0x44, 0xC3])]
+#[case::condition("cmovl r8, r9", &[0x4D, 0x0F, 0x4C, 0xC1])]
+#[case::condition("cmovge rax, [rbp - 8]", &[0x48, 0x0F, 0x4D, 0x45, 0xF8])]
+#[case::atomic("xchg qword ptr [rsp + 8], r15", &[0x4C, 0x87, 0x7C, 0x24, 0x08])]
+#[case::atomic("xchg r9, [rdi]", &[0x4C, 0x87, 0x0F])]
+#[case::atomic("cmpxchg [r12], rbx", &[0x49, 0x0F, 0xB1, 0x1C, 0x24])]
+#[case::atomic("cmpxchg8b [rdi]", &[0x0F, 0xC7, 0x0F])]
+#[case::atomic("cmpxchg16b [rdi]", &[0x48, 0x0F, 0xC7, 0x0F])]
+#[case::atomic("cmpxchg16b xmmword ptr [rdi]", &[0x48, 0x0F, 0xC7, 0x0F])]
+#[case::atomic("xadd word ptr [rax], r10w", &[0x66, 0x44, 0x0F, 0xC1, 0x10])]
+#[case::string("movsb", &[0xA4])]
+#[case::string("stosq", &[0x48, 0xAB])]
+#[case::string("rep movsb", &[0xF3, 0xA4])]
+#[case::string("rep stosw", &[0xF3, 0x66, 0xAB])]
+#[case::string("REP LODSD", &[0xF3, 0xAD])]
+#[case::string("repe cmpsq", &[0xF3, 0x48, 0xA7])]
+#[case::string("repz cmpsb", &[0xF3, 0xA6])]
+#[case::string("repnz scasb", &[0xF2, 0xAE])]
+#[case::lock("lock add qword ptr [rax], rcx", &[0xF0, 0x48, 0x01, 0x08])]
+#[case::lock("LOCK inc dword ptr [rbx]", &[0xF0, 0xFF, 0x03])]
+#[case::lock("lock add byte ptr [rax], 1", &[0xF0, 0x80, 0x00, 0x01])]
+#[case::lock("lock cmpxchg qword ptr [rdi], rsi", &[0xF0, 0x48, 0x0F, 0xB1, 0x37])]
+#[case::lock("lock xchg qword ptr [rax], rbx", &[0xF0, 0x48, 0x87, 0x18])]
+#[case::lock("lock bts dword ptr [rax], 3", &[0xF0, 0x0F, 0xBA, 0x28, 0x03])]
+#[case::lock("lock cmpxchg8b qword ptr [rsi]", &[0xF0, 0x0F, 0xC7, 0x0E])]
+#[case::lock("lock cmpxchg16b xmmword ptr [rax]", &[0xF0, 0x48, 0x0F, 0xC7, 0x08])]
+#[case::bits("bt eax, ecx", &[0x0F, 0xA3, 0xC8])]
+#[case::bits("bts qword ptr [rax], 5", &[0x48, 0x0F, 0xBA, 0x28, 0x05])]
+#[case::bits("btc rax, 63", &[0x48, 0x0F, 0xBA, 0xF8, 0x3F])]
+#[case::bits("bsf ax, cx", &[0x66, 0x0F, 0xBC, 0xC1])]
+#[case::bits("bswap r9", &[0x49, 0x0F, 0xC9])]
+#[case::bits("popcnt ax, bx", &[0x66, 0xF3, 0x0F, 0xB8, 0xC3])]
+#[case::bits("lzcnt r8d, ecx", &[0xF3, 0x44, 0x0F, 0xBD, 0xC1])]
+#[case::bits("tzcnt rax, [rdi]", &[0xF3, 0x48, 0x0F, 0xBC, 0x07])]
+#[case::bmi("andn eax, ebx, ecx", &[0xC4, 0xE2, 0x60, 0xF2, 0xC1])]
+#[case::bmi("andn r8, r9, qword ptr [r10]", &[0xC4, 0x42, 0xB0, 0xF2, 0x02])]
+#[case::bmi("blsr r9, [rax]", &[0xC4, 0xE2, 0xB0, 0xF3, 0x08])]
+#[case::bmi("mulx r8, r9, r10", &[0xC4, 0x42, 0xB3, 0xF6, 0xC2])]
+#[case::bmi("rorx eax, ecx, 5", &[0xC4, 0xE3, 0x7B, 0xF0, 0xC1, 0x05])]
+#[case::bmi("shrx eax, dword ptr [rdi + 8], ebx", &[0xC4, 0xE2, 0x63, 0xF7, 0x47, 0x08])]
+#[case::bmi("adcx eax, ebx", &[0x66, 0x0F, 0x38, 0xF6, 0xC3])]
+#[case::bmi("adox rax, [rdi]", &[0xF3, 0x48, 0x0F, 0x38, 0xF6, 0x07])]
+#[case::sse("addsd xmm0, qword ptr [rax]", &[0xF2, 0x0F, 0x58, 0x00])]
+#[case::sse("subss xmm9, xmm1", &[0xF3, 0x44, 0x0F, 0x5C, 0xC9])]
+#[case::sse("movss dword ptr [rsp + 4], xmm2", &[0xF3, 0x0F, 0x11, 0x54, 0x24, 0x04])]
+#[case::sse("movsd xmm0, [rdi]", &[0xF2, 0x0F, 0x10, 0x07])]
+#[case::sse("cvtsi2sd xmm0, eax", &[0xF2, 0x0F, 0x2A, 0xC0])]
+#[case::sse("cvtsi2sd xmm0, qword ptr [rax]", &[0xF2, 0x48, 0x0F, 0x2A, 0x00])]
+#[case::sse("cvttsd2si r8, xmm3", &[0xF2, 0x4C, 0x0F, 0x2C, 0xC3])]
+#[case::sse("movq rax, xmm1", &[0x66, 0x48, 0x0F, 0x7E, 0xC8])]
+#[case::sse("movd xmm1, dword ptr [rax]", &[0x66, 0x0F, 0x6E, 0x08])]
+#[case::sse("UCOMISD XMM0, XMM1", &[0x66, 0x0F, 0x2E, 0xC1])]
+#[case::packed("paddd xmm0, xmmword ptr [rax]", &[0x66, 0x0F, 0xFE, 0x00])]
+#[case::packed("movdqa xmm8, [rsi]", &[0x66, 0x44, 0x0F, 0x6F, 0x06])]
+#[case::packed("movups xmmword ptr [rsp + 16], xmm3", &[0x0F, 0x11, 0x5C, 0x24, 0x10])]
+#[case::packed("pshufd xmm1, xmm2, 0x1b", &[0x66, 0x0F, 0x70, 0xCA, 0x1B])]
+#[case::packed("pextrb byte ptr [rax], xmm1, 1", &[0x66, 0x0F, 0x3A, 0x14, 0x08, 0x01])]
+#[case::packed("pextrw eax, xmm1, 1", &[0x66, 0x0F, 0xC5, 0xC1, 0x01])]
+#[case::packed("pextrw word ptr [rax], xmm1, 1", &[0x66, 0x0F, 0x3A, 0x15, 0x08, 0x01])]
+#[case::packed("pinsrq xmm1, rax, 1", &[0x66, 0x48, 0x0F, 0x3A, 0x22, 0xC8, 0x01])]
+#[case::packed("pinsrb xmm1, byte ptr [rdi], 3", &[0x66, 0x0F, 0x3A, 0x20, 0x0F, 0x03])]
+#[case::packed("blendvps xmm1, xmm2, xmm0", &[0x66, 0x0F, 0x38, 0x14, 0xCA])]
+#[case::packed("pmovmskb eax, xmm1", &[0x66, 0x0F, 0xD7, 0xC1])]
+#[case::packed("pcmpeqq xmm1, xmm9", &[0x66, 0x41, 0x0F, 0x38, 0x29, 0xC9])]
+#[case::avx("vaddps ymm0, ymm1, ymm2", &[0xC5, 0xF4, 0x58, 0xC2])]
+#[case::avx("vaddps xmm0, xmm1, xmm2", &[0xC5, 0xF0, 0x58, 0xC2])]
+#[case::avx("vpxor xmm8, xmm9, xmm15", &[0xC4, 0x41, 0x31, 0xEF, 0xC7])]
+#[case::avx("vpermd ymm1, ymm2, [rax]", &[0xC4, 0xE2, 0x6D, 0x36, 0x08])]
+#[case::avx("vpbroadcastd ymm1, dword ptr [rax]", &[0xC4, 0xE2, 0x7D, 0x58, 0x08])]
+#[case::avx("vpbroadcastb xmm1, xmm2", &[0xC4, 0xE2, 0x79, 0x78, 0xCA])]
+#[case::avx("vmovdqu ymmword ptr [rdi], ymm0", &[0xC5, 0xFE, 0x7F, 0x07])]
+#[case::avx("VZEROUPPER", &[0xC5, 0xF8, 0x77])]
+#[case::fma("vfmadd132ps xmm0, xmm1, xmm2", &[0xC4, 0xE2, 0x71, 0x98, 0xC2])]
+#[case::fma("vfmadd213ps xmm0, xmm1, xmm2", &[0xC4, 0xE2, 0x71, 0xA8, 0xC2])]
+#[case::fma("vfmadd231ps xmm0, xmm1, xmm2", &[0xC4, 0xE2, 0x71, 0xB8, 0xC2])]
+#[case::fma("vfnmsub231pd ymm8, ymm9, ymmword ptr [rax]", &[0xC4, 0x62, 0xB5, 0xBE, 0x00])]
+#[case::fma("vfmsub213sd xmm1, xmm2, qword ptr [rdi + 8]", &[0xC4, 0xE2, 0xE9, 0xAB, 0x4F, 0x08])]
+#[case::f16c("vcvtph2ps ymm1, xmmword ptr [rax]", &[0xC4, 0xE2, 0x7D, 0x13, 0x08])]
+#[case::f16c("vcvtps2ph qword ptr [rax], xmm1, 4", &[0xC4, 0xE3, 0x79, 0x1D, 0x08, 0x04])]
+#[case::avx512("vaddps zmm0 {k1} {z}, zmm1, zmm2", &[0x62, 0xF1, 0x74, 0xC9, 0x58, 0xC2])]
+#[case::avx512("vaddps zmm0 {k1}, zmm1, zmmword ptr [rax + 64]", &[0x62, 0xF1, 0x74, 0x49, 0x58, 0x40, 0x01])]
+#[case::avx512("vaddps zmm0 {k1}, zmm1, dword ptr [rax + 4]{1to16}", &[0x62, 0xF1, 0x74, 0x59, 0x58, 0x40, 0x01])]
+#[case::avx512("vaddps zmm16, zmm17, zmm31", &[0x62, 0x81, 0x74, 0x40, 0x58, 0xC7])]
+#[case::avx512("vmaxps zmm0, zmm1, zmm2, {sae}", &[0x62, 0xF1, 0x74, 0x18, 0x5F, 0xC2])]
+#[case::avx512("vsqrtps zmm0, zmm1, {ru-sae}", &[0x62, 0xF1, 0x7C, 0x58, 0x51, 0xC1])]
+#[case::avx512("vaddss xmm1, xmm2, xmm3, {rd-sae}", &[0x62, 0xF1, 0x6E, 0x38, 0x58, 0xCB])]
+#[case::avx512("vcmpps k1 {k2}, zmm1, zmm2, 1", &[0x62, 0xF1, 0x74, 0x4A, 0xC2, 0xCA, 0x01])]
+#[case::avx512("vcmpps k1, zmm1, zmm2, {sae}, 1", &[0x62, 0xF1, 0x74, 0x18, 0xC2, 0xCA, 0x01])]
+#[case::avx512("vpcmpeqd k1 {k2}, zmm1, dword ptr [rax]{1to16}", &[0x62, 0xF1, 0x75, 0x5A, 0x76, 0x08])]
+#[case::avx512("vmovups zmmword ptr [rax] {k1}, zmm0", &[0x62, 0xF1, 0x7C, 0x49, 0x11, 0x00])]
+#[case::avx512("kmovw k1, word ptr [rax]", &[0xC5, 0xF8, 0x90, 0x08])]
+#[case::avx512("kmovw eax, k1", &[0xC5, 0xF8, 0x93, 0xC1])]
+#[case::avx512("kandw k1, k2, k3", &[0xC5, 0xEC, 0x41, 0xCB])]
+#[case::gather("vpgatherdd xmm1, dword ptr [rax + 4*xmm2], xmm3", &[0xC4, 0xE2, 0x61, 0x90, 0x0C, 0x90])]
+#[case::gather("vpgatherqd xmm1, dword ptr [rax + xmm2*4], xmm3", &[0xC4, 0xE2, 0x61, 0x91, 0x0C, 0x90])]
+#[case::gather("vpgatherqd xmm1, [rax + ymm2*4], xmm3", &[0xC4, 0xE2, 0x65, 0x91, 0x0C, 0x90])]
+#[case::gather("vgatherdpd ymm1, [rax + xmm2*8 + 8], ymm3", &[0xC4, 0xE2, 0xE5, 0x92, 0x4C, 0xD0, 0x08])]
+#[case::gather("vpgatherqq ymm9, [ymm15*2 + r12 - 128], ymm0", &[0xC4, 0x02, 0xFD, 0x91, 0x4C, 0x7C, 0x80])]
+#[case::gather("vpgatherdd xmm1, [xmm2*4], xmm3", &[0xC4, 0xE2, 0x61, 0x90, 0x0C, 0x95, 0x00, 0x00, 0x00, 0x00])]
+#[case::gather("vpgatherdd zmm1 {k1}, dword ptr [rax + zmm2*4 + 256]", &[0x62, 0xF2, 0x7D, 0x49, 0x90, 0x4C, 0x90, 0x40])]
+#[case::gather("vgatherqpd zmm30 {k7}, [rsp + zmm1*8 + 1024]", &[0x62, 0x62, 0xFD, 0x4F, 0x93, 0xB4, 0xCC, 0x00, 0x04, 0x00, 0x00])]
+#[case::scatter("vpscatterdd [rax + zmm2*4] {k1}, zmm3", &[0x62, 0xF2, 0x7D, 0x49, 0xA0, 0x1C, 0x90])]
+#[case::scatter("vscatterqps dword ptr [r8 + zmm20] {k2}, ymm5", &[0x62, 0xD2, 0x7D, 0x42, 0xA3, 0x2C, 0x20])]
+#[case::avx512("vcmppd k1, zmm2, zmm3, 5", &[0x62, 0xF1, 0xED, 0x48, 0xC2, 0xCB, 0x05])]
+#[case::avx512("vcmppd k1 {k2}, zmm2, zmm3, {sae}, 5", &[0x62, 0xF1, 0xED, 0x1A, 0xC2, 0xCB, 0x05])]
+#[case::avx512("vcmppd k1, zmm2, qword ptr [rax]{1to8}, 5", &[0x62, 0xF1, 0xED, 0x58, 0xC2, 0x08, 0x05])]
+#[case::avx512("vpcmpq k1, zmm2, zmm3, 4", &[0x62, 0xF3, 0xED, 0x48, 0x1F, 0xCB, 0x04])]
+#[case::avx512("vpcmpuq k1 {k3}, zmm2, qword ptr [rax + 8]{1to8}, 1", &[0x62, 0xF3, 0xED, 0x5B, 0x1E, 0x48, 0x01, 0x01])]
+fn test_parse_intel(#[case] source: &str, #[case] expected: &[u8]) {
+    assert_eq!(parse_single(source), expected);
+}
+
+#[test]
+fn test_parse_intel_multiple_statements() {
+    let source = "push rbp; mov rbp, rsp # prologue\n\n  # only a comment\npop rbp\r\nret";
+    let encoded = parse_intel(source).unwrap();
+    let bytes: Vec<u8> = encoded.iter().flat_map(|x| x.as_slice().iter().copied()).collect();
+    assert_eq!(bytes, [0x55, 0x48, 0x89, 0xE5, 0x5D, 0xC3]);
+    assert!(parse_intel("").unwrap().is_empty());
+    assert!(parse_intel(" ; \n# nothing\n").unwrap().is_empty());
+}
+
+#[rstest]
+#[case::zo("syscall")]
+#[case::accumulator("cmp rax, 1000")]
+#[case::jcc("jne -2")]
+#[case::reg8("mov sil, -1")]
+#[case::imm64("mov r15, -5000000000")]
+#[case::based("mov qword ptr [rbp + rax*4 - 8], 5")]
+#[case::based("mov byte ptr [rsp], 1")]
+#[case::scale_1("mov eax, dword ptr [rax + r9]")]
+#[case::scaled("push qword ptr [rcx*2 - 2147483648]")]
+#[case::push("push word 1446")]
+#[case::rip("call qword ptr [rip + 100]")]
+#[case::lea("lea rax, [rbx - 1]")]
+#[case::string("rep movsq")]
+#[case::string("repne scasd")]
+#[case::lock("lock add qword ptr [rax], rcx")]
+#[case::lock("lock xadd word ptr [rax + 8], cx")]
+#[case::lock("lock cmpxchg8b qword ptr [rsi]")]
+#[case::lock("lock cmpxchg16b xmmword ptr [rax]")]
+#[case::bits("btr r10d, 1")]
+#[case::bits("popcnt rax, qword ptr [rdi]")]
+#[case::bmi("pext rax, rbx, qword ptr [rcx]")]
+#[case::bmi("bzhi r15d, esi, r8d")]
+#[case::sse("divsd xmm15, qword ptr [rip + 16]")]
+#[case::sse("movss xmm1, xmm2")]
+#[case::sse("movd dword ptr [rax], xmm7")]
+#[case::packed("movaps xmm0, xmmword ptr [rax + 16]")]
+#[case::packed("pinsrw xmm1, eax, 2")]
+#[case::packed("pextrd dword ptr [rax], xmm1, 3")]
+#[case::packed("pblendvb xmm3, xmm4, xmm0")]
+#[case::avx("vmulpd ymm3, ymm4, ymmword ptr [rsp + 32]")]
+#[case::avx("vpshufb xmm1, xmm2, xmm3")]
+#[case::avx("vpbroadcastw ymm0, word ptr [rax]")]
+#[case::avx("vmovaps xmmword ptr [rax], xmm1")]
+#[case::gather("vgatherqps xmm1, dword ptr [rax + xmm2*4], xmm3")]
+#[case::avx512("vcmppd k1, zmm2, zmm3, 5")]
+#[case::avx512("vcmppd k1 {k2}, zmm2, zmm3, {sae}, 5")]
+#[case::avx512("vcmppd k1, zmm2, qword ptr [rax]{1to8}, 5")]
+#[case::avx512("vpcmpq k1, zmm2, zmm3, 4")]
+#[case::avx512("vpcmpuq k1 {k3}, zmm2, qword ptr [rax + 8]{1to8}, 1")]
+fn test_parse_intel_formatter_round_trip(#[case] source: &str) {
+    let instructions = parse_intel_instructions(source).unwrap();
+    assert_eq!(instructions.len(), 1);
+    assert_eq!(instructions[0].to_string(), source);
+}
+
+#[test]
+fn test_parse_intel_instructions() {
+    let instructions = parse_intel_instructions("add al, 5\nadd rax, 1").unwrap();
+    assert_eq!(
+        instructions,
+        [
+            Instruction::AddAlImm8 {
+                imm8: Immediate8::from_i8(5)
+            },
+            Instruction::AddRm64Imm8 {
+                rm64: GPR::RAX.into(),
+                imm8: Immediate8::from_i8(1)
+            },
+        ]
+    );
+}
+
+#[rstest]
+#[case::unknown_mnemonic("foo rax", ParseErrorKind::UnknownMnemonic, 0, 3)]
+#[case::unknown_register("mov rax, foo", ParseErrorKind::UnknownRegister, 9, 12)]
+#[case::unexpected_character("mov rax, $5", ParseErrorKind::UnexpectedCharacter, 9, 10)]
+#[case::unexpected_token("2 rax", ParseErrorKind::UnexpectedToken, 0, 1)]
+#[case::unexpected_token("mov rax rbx", ParseErrorKind::UnexpectedToken, 8, 11)]
+#[case::unexpected_end("mov rax, rbx,", ParseErrorKind::UnexpectedEnd, 13, 13)]
+#[case::invalid_number("mov rax, 0xZZ", ParseErrorKind::InvalidNumber, 9, 13)]
+#[case::out_of_range("mov rax, 99999999999999999999", ParseErrorKind::ValueOutOfRange, 9, 29)]
+#[case::invalid_memory("mov rax, [eax]", ParseErrorKind::InvalidMemoryOperand, 10, 13)]
+#[case::ambiguous("mov [rax], 5", ParseErrorKind::AmbiguousOperandSize, 4, 9)]
+#[case::ambiguous("shl [rax], cl", ParseErrorKind::AmbiguousOperandSize, 4, 9)]
+#[case::ambiguous("movzx eax, [rax]", ParseErrorKind::AmbiguousOperandSize, 11, 16)]
+#[case::shift_count("shl eax, dl", ParseErrorKind::InvalidOperands, 4, 11)]
+#[case::invalid_operands("mov rax, ebx", ParseErrorKind::InvalidOperands, 4, 12)]
+#[case::invalid_operands("lea rax, rbx", ParseErrorKind::InvalidOperands, 4, 12)]
+#[case::invalid_operands("cmove al, bl", ParseErrorKind::InvalidOperands, 6, 12)]
+#[case::invalid_operands("sete eax", ParseErrorKind::InvalidOperands, 5, 8)]
+#[case::invalid_operands("cmpxchg8b rax", ParseErrorKind::InvalidOperands, 10, 13)]
+#[case::invalid_operands("cmpxchg16b qword ptr [rdi]", ParseErrorKind::InvalidOperands, 11, 26)]
+#[case::invalid_operands("add rax, 0x100000000", ParseErrorKind::InvalidOperands, 4, 20)]
+#[case::invalid_prefix("rep add rax, rbx", ParseErrorKind::InvalidPrefix, 0, 3)]
+#[case::invalid_prefix("repne movsb", ParseErrorKind::InvalidPrefix, 0, 5)]
+#[case::invalid_prefix("rep cmpsb", ParseErrorKind::InvalidPrefix, 0, 3)]
+#[case::unknown_mnemonic("rep foo", ParseErrorKind::UnknownMnemonic, 4, 7)]
+#[case::invalid_prefix("lock mov qword ptr [rax], rbx", ParseErrorKind::InvalidPrefix, 0, 4)]
+#[case::invalid_prefix("lock movsb", ParseErrorKind::InvalidPrefix, 0, 4)]
+#[case::invalid_operands("movsb rax", ParseErrorKind::InvalidOperands, 6, 9)]
+#[case::invalid_operands("bswap ax", ParseErrorKind::InvalidOperands, 6, 8)]
+#[case::ambiguous("bt [rax], 1", ParseErrorKind::AmbiguousOperandSize, 3, 8)]
+#[case::ambiguous("cvtsi2sd xmm0, [rax]", ParseErrorKind::AmbiguousOperandSize, 15, 20)]
+#[case::invalid_operands("addss xmm0, qword ptr [rax]", ParseErrorKind::InvalidOperands, 6, 27)]
+#[case::invalid_operands("addss xmm0, eax", ParseErrorKind::InvalidOperands, 6, 15)]
+#[case::invalid_operands("movd xmm0, rax", ParseErrorKind::InvalidOperands, 5, 14)]
+#[case::unknown_register("addss xmm32, xmm0", ParseErrorKind::UnknownRegister, 6, 11)]
+#[case::invalid_operands("blendvps xmm1, xmm2, xmm3", ParseErrorKind::InvalidOperands, 9, 25)]
+#[case::invalid_operands("pextrb word ptr [rax], xmm1, 1", ParseErrorKind::InvalidOperands, 7, 30)]
+#[case::invalid_operands("pinsrb xmm1, al, 1", ParseErrorKind::InvalidOperands, 7, 18)]
+#[case::invalid_operands("vaddps ymm0, xmm1, ymm2", ParseErrorKind::InvalidOperands, 7, 23)]
+#[case::invalid_operands("vpermd xmm0, xmm1, xmm2", ParseErrorKind::InvalidOperands, 7, 23)]
+#[case::invalid_operands("vpbroadcastd ymm0, qword ptr [rax]", ParseErrorKind::InvalidOperands, 13, 34)]
+#[case::unknown_register("vaddps ymm32, ymm0, ymm1", ParseErrorKind::UnknownRegister, 7, 12)]
+#[case::unexpected_token("vaddps zmm0 {z}, zmm1, zmm2", ParseErrorKind::UnexpectedToken, 12, 15)]
+#[case::invalid_operands(
+    "vaddps zmm0, zmm1, zmmword ptr [rax], {rn-sae}",
+    ParseErrorKind::InvalidOperands,
+    7,
+    46
+)]
+#[case::invalid_operands("vfmadd231ss xmm0, xmm1, qword ptr [rax]", ParseErrorKind::InvalidOperands, 12, 39)]
+#[case::invalid_operands("vcvtps2ph xmm0, ymm1", ParseErrorKind::InvalidOperands, 10, 20)]
+#[case::invalid_operands("vaddps zmm0, zmm1, qword ptr [rax]{1to8}", ParseErrorKind::InvalidOperands, 7, 40)]
+#[case::invalid_operands(
+    "vpgatherqd xmm1, xmmword ptr [rax + xmm2*4], xmm3",
+    ParseErrorKind::InvalidOperands,
+    11,
+    49
+)]
+#[case::invalid_operands("vpgatherdd xmm1, [rax + ymm2*4], xmm3", ParseErrorKind::InvalidOperands, 11, 37)]
+#[case::invalid_operands("vaddps xmm1, xmm2, [rax + xmm3*4]", ParseErrorKind::InvalidOperands, 7, 33)]
+#[case::invalid_memory_operand(
+    "vpgatherdd xmm1, [rax + rcx + xmm2*4], xmm3",
+    ParseErrorKind::InvalidMemoryOperand,
+    30,
+    36
+)]
+#[case::invalid_memory_operand(
+    "vpgatherdd xmm1, [rip + xmm2*4], xmm3",
+    ParseErrorKind::InvalidMemoryOperand,
+    17,
+    31
+)]
+#[case::unexpected_token(
+    "vpgatherdd zmm1 {k1}, [rax + zmm2*4]{1to16}",
+    ParseErrorKind::UnexpectedToken,
+    36,
+    43
+)]
+fn test_parse_intel_errors(
+    #[case] source: &str,
+    #[case] kind: ParseErrorKind,
+    #[case] start: usize,
+    #[case] end: usize,
+) {
+    let error = parse_intel(source).unwrap_err();
+    assert_eq!(error, ParseError::new(kind, Span::new(start, end)));
+}
+
+#[rstest]
+#[case::two_indexes("mov rax, [rax + rbx + rcx]")]
+#[case::invalid_scale("mov rax, [rax*3]")]
+#[case::rip_with_index("mov rax, [rip + rax]")]
+#[case::offset_too_large("mov rax, [rax + 0x100000000]")]
+fn test_parse_intel_invalid_memory(#[case] source: &str) {
+    let error = parse_intel(source).unwrap_err();
+    assert!(
+        matches!(
+            error.kind,
+            ParseErrorKind::InvalidMemoryOperand | ParseErrorKind::ValueOutOfRange
+        ),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn test_parse_intel_encode_error() {
+    let source = "nop\nmov ah, sil";
+    let error = parse_intel(source).unwrap_err();
+    assert!(matches!(error.kind, ParseErrorKind::Encode(_)), "{error:?}");
+    assert_eq!(error.span, Span::new(4, 15));
+}
+
+#[test]
+fn test_parse_intel_lock_encode_error() {
+    let error = parse_intel("lock add rax, rbx").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Encode(EncodeError::LockWithRegisterDestination { gpr: GPR::RAX })
+    );
+    assert_eq!(error.span, Span::new(0, 17));
+}
+
+#[test]
+fn test_parse_intel_evex_encode_error() {
+    let error = parse_intel("addss xmm16, xmm0").unwrap_err();
+    assert!(matches!(error.kind, ParseErrorKind::Encode(_)), "{error:?}");
+    let error = parse_intel("vaddps zmm0 {k0}, zmm1, zmm2").unwrap_err();
+    assert!(matches!(error.kind, ParseErrorKind::Encode(_)), "{error:?}");
+    let error = parse_intel("vmovups zmmword ptr [rax] {k1} {z}, zmm0").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Encode(EncodeError::ZeroingMaskingNotAllowed)
+    );
+}
+
+#[test]
+fn test_parse_intel_vsib_encode_error() {
+    let error = parse_intel("vpgatherdd xmm1, [rax + xmm1*4], xmm3").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Encode(EncodeError::GatherRegistersNotDistinct)
+    );
+    let error = parse_intel("vpgatherdd zmm1, [rax + zmm2*4]").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::Encode(EncodeError::WriteMaskRequired));
+    let error = parse_intel("vpscatterdd [rax + zmm2*4] {k0}, zmm3").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::Encode(EncodeError::InvalidWriteMask));
+}
+
+#[test]
+fn test_parse_error_display() {
+    let error = parse_intel("nop\nfoo").unwrap_err();
+    assert_eq!(error.span, Span::new(4, 7));
+    assert!(error.to_string().ends_with("at 4..7"), "{error}");
+}
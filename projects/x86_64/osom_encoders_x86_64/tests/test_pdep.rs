@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPR::EBX, GPROrMemory::GPR { gpr: GPR::ECX }, &[0xC4, 0xE2, 0x63, 0xF5, 0xC1])]
+#[case::gpr(GPR::R8D, GPR::R9D, GPROrMemory::GPR { gpr: GPR::R10D }, &[0xC4, 0x42, 0x33, 0xF5, 0xC2])]
+#[case::memory(GPR::EDX, GPR::R15D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x03, 0xF5, 0x10])]
+#[case::memory(GPR::R11D, GPR::ESI, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x4B, 0xF5, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::ECX, GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x6B, 0xF5, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_pdep_reg32_vreg32_rm32(
+    #[case] reg32: GPR,
+    #[case] vreg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pdep::encode_reg32_vreg32_rm32(reg32, vreg32, rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPR::RBX, GPROrMemory::GPR { gpr: GPR::RCX }, &[0xC4, 0xE2, 0xE3, 0xF5, 0xC1])]
+#[case::gpr(GPR::R8, GPR::R9, GPROrMemory::GPR { gpr: GPR::R10 }, &[0xC4, 0x42, 0xB3, 0xF5, 0xC2])]
+#[case::memory(GPR::RDX, GPR::R15, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x83, 0xF5, 0x10])]
+#[case::memory(GPR::R11, GPR::RSI, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0xCB, 0xF5, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::RCX, GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0xEB, 0xF5, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_pdep_reg64_vreg64_rm64(
+    #[case] reg64: GPR,
+    #[case] vreg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pdep::encode_reg64_vreg64_rm64(reg64, vreg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,20 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, XMM::XMM0, Immediate8::from_u8(1), &[0x66, 0x0F, 0x3A, 0x14, 0xC0, 0x01])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10D }, XMM::XMM9, Immediate8::from_u8(15), &[0x66, 0x45, 0x0F, 0x3A, 0x14, 0xCA, 0x0F])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, Immediate8::from_u8(0), &[0x66, 0x0F, 0x3A, 0x14, 0x10, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, Immediate8::from_u8(7), &[0x66, 0x45, 0x0F, 0x3A, 0x14, 0x5C, 0x24, 0x08, 0x07])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, Immediate8::from_u8(3), &[0x66, 0x41, 0x0F, 0x3A, 0x14, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0x03])]
+fn test_pextrb_r32m8_xmm_imm8(
+    #[case] r32m8: GPROrMemory,
+    #[case] xmm: XMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pextrb::encode_r32m8_xmm_imm8(r32m8, xmm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,20 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, XMM::XMM0, Immediate8::from_u8(1), &[0x66, 0x48, 0x0F, 0x3A, 0x16, 0xC0, 0x01])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10 }, XMM::XMM9, Immediate8::from_u8(0), &[0x66, 0x4D, 0x0F, 0x3A, 0x16, 0xCA, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, Immediate8::from_u8(1), &[0x66, 0x48, 0x0F, 0x3A, 0x16, 0x10, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, Immediate8::from_u8(0), &[0x66, 0x4D, 0x0F, 0x3A, 0x16, 0x5C, 0x24, 0x08, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, Immediate8::from_u8(1), &[0x66, 0x49, 0x0F, 0x3A, 0x16, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0x01])]
+fn test_pextrq_rm64_xmm_imm8(
+    #[case] rm64: GPROrMemory,
+    #[case] xmm: XMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pextrq::encode_rm64_xmm_imm8(rm64, xmm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
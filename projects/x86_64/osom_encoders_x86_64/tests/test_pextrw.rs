@@ -0,0 +1,29 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, XMM::XMM1, Immediate8::from_u8(1), &[0x66, 0x0F, 0xC5, 0xC1, 0x01])]
+#[case::gpr(GPR::R8D, XMM::XMM15, Immediate8::from_u8(7), &[0x66, 0x45, 0x0F, 0xC5, 0xC7, 0x07])]
+#[case::gpr(GPR::ECX, XMM::XMM8, Immediate8::from_u8(0), &[0x66, 0x41, 0x0F, 0xC5, 0xC8, 0x00])]
+fn test_pextrw_reg32_xmm_imm8(#[case] reg32: GPR, #[case] xmm: XMM, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { pextrw::encode_reg32_xmm_imm8(reg32, xmm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, XMM::XMM0, Immediate8::from_u8(1), &[0x66, 0x0F, 0x3A, 0x15, 0xC0, 0x01])]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10D }, XMM::XMM9, Immediate8::from_u8(15), &[0x66, 0x45, 0x0F, 0x3A, 0x15, 0xCA, 0x0F])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, Immediate8::from_u8(0), &[0x66, 0x0F, 0x3A, 0x15, 0x10, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, Immediate8::from_u8(7), &[0x66, 0x45, 0x0F, 0x3A, 0x15, 0x5C, 0x24, 0x08, 0x07])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, Immediate8::from_u8(3), &[0x66, 0x41, 0x0F, 0x3A, 0x15, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0x03])]
+fn test_pextrw_r32m16_xmm_imm8(
+    #[case] r32m16: GPROrMemory,
+    #[case] xmm: XMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pextrw::encode_r32m16_xmm_imm8(r32m16, xmm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
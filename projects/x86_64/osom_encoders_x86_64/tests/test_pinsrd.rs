@@ -0,0 +1,20 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(XMM::XMM0, GPROrMemory::GPR { gpr: GPR::EAX }, Immediate8::from_u8(1), &[0x66, 0x0F, 0x3A, 0x22, 0xC0, 0x01])]
+#[case::gpr(XMM::XMM9, GPROrMemory::GPR { gpr: GPR::R10D }, Immediate8::from_u8(3), &[0x66, 0x45, 0x0F, 0x3A, 0x22, 0xCA, 0x03])]
+#[case::memory(XMM::XMM2, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_u8(0), &[0x66, 0x0F, 0x3A, 0x22, 0x10, 0x00])]
+#[case::memory(XMM::XMM11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, Immediate8::from_u8(2), &[0x66, 0x45, 0x0F, 0x3A, 0x22, 0x5C, 0x24, 0x08, 0x02])]
+#[case::memory(XMM::XMM7, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, Immediate8::from_u8(3), &[0x66, 0x41, 0x0F, 0x3A, 0x22, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0x03])]
+fn test_pinsrd_xmm_rm32_imm8(
+    #[case] xmm: XMM,
+    #[case] rm32: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pinsrd::encode_xmm_rm32_imm8(xmm, rm32, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
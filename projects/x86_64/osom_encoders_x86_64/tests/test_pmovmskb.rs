@@ -0,0 +1,14 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, XMM::XMM1, &[0x66, 0x0F, 0xD7, 0xC1])]
+#[case::gpr(GPR::R8D, XMM::XMM15, &[0x66, 0x45, 0x0F, 0xD7, 0xC7])]
+#[case::gpr(GPR::ECX, XMM::XMM8, &[0x66, 0x41, 0x0F, 0xD7, 0xC8])]
+#[case::gpr(GPR::R15D, XMM::XMM0, &[0x66, 0x44, 0x0F, 0xD7, 0xF8])]
+fn test_pmovmskb_reg32_xmm(#[case] reg32: GPR, #[case] xmm: XMM, #[case] expected: &[u8]) {
+    let instr = unsafe { pmovmskb::encode_reg32_xmm(reg32, xmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
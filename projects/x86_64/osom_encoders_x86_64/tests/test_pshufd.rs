@@ -0,0 +1,20 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, Immediate8::from_u8(27), &[0x66, 0x0F, 0x70, 0xC1, 0x1B])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, Immediate8::from_u8(0), &[0x66, 0x45, 0x0F, 0x70, 0xC7, 0x00])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_u8(255), &[0x66, 0x0F, 0x70, 0x10, 0xFF])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, Immediate8::from_u8(1), &[0x66, 0x45, 0x0F, 0x70, 0x5C, 0x24, 0x08, 0x01])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, Immediate8::from_u8(3), &[0x66, 0x41, 0x0F, 0x70, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0x03])]
+fn test_pshufd_xmm_xmmm128_imm8(
+    #[case] xmm: XMM,
+    #[case] xmmm128: XMMOrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { pshufd::encode_xmm_xmmm128_imm8(xmm, xmmm128, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
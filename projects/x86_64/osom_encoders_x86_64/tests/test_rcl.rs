@@ -0,0 +1,88 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BL }, &[0xD0, 0xD3])]
+fn test_rcl_rm8_one(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm8_one(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::SI }, &[0x66, 0xD1, 0xD6])]
+fn test_rcl_rm16_one(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm16_one(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10D }, &[0x41, 0xD1, 0xD2])]
+fn test_rcl_rm32_one(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm32_one(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, &[0x48, 0xD1, 0xD0])]
+fn test_rcl_rm64_one(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm64_one(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::SPL }, Immediate8::from_u8(3), &[0x40, 0xC0, 0xD4, 0x03])]
+fn test_rcl_rm8_imm8(#[case] rm8: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm8_imm8(rm8, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::CX }, Immediate8::from_u8(5), &[0x66, 0xC1, 0xD1, 0x05])]
+fn test_rcl_rm16_imm8(#[case] rm16: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm16_imm8(rm16, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EDX }, Immediate8::from_u8(31), &[0xC1, 0xD2, 0x1F])]
+fn test_rcl_rm32_imm8(#[case] rm32: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm32_imm8(rm32, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R9 }, Immediate8::from_u8(63), &[0x49, 0xC1, 0xD1, 0x3F])]
+fn test_rcl_rm64_imm8(#[case] rm64: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm64_imm8(rm64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R8B }, &[0x41, 0xD2, 0xD0])]
+fn test_rcl_rm8_cl(#[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm8_cl(rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, &[0x66, 0xD3, 0xD0])]
+fn test_rcl_rm16_cl(#[case] rm16: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm16_cl(rm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EDI }, &[0xD3, 0xD7])]
+fn test_rcl_rm32_cl(#[case] rm32: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm32_cl(rm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R15 }, &[0x49, 0xD3, 0xD7])]
+fn test_rcl_rm64_cl(#[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { rcl::encode_rm64_cl(rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
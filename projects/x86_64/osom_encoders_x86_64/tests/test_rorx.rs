@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::ECX }, Immediate8::from_u8(0), &[0xC4, 0xE3, 0x7B, 0xF0, 0xC1, 0x00])]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::R10D }, Immediate8::from_u8(1), &[0xC4, 0x43, 0x7B, 0xF0, 0xC2, 0x01])]
+#[case::memory(GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_u8(31), &[0xC4, 0xE3, 0x7B, 0xF0, 0x10, 0x1F])]
+#[case::memory(GPR::R11D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, Immediate8::from_u8(5), &[0xC4, 0x43, 0x7B, 0xF0, 0x5C, 0x24, 0x08, 0x05])]
+#[case::memory(GPR::ECX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, Immediate8::from_u8(255), &[0xC4, 0xC3, 0x7B, 0xF0, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF, 0xFF])]
+fn test_rorx_reg32_rm32_imm8(
+    #[case] reg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { rorx::encode_reg32_rm32_imm8(reg32, rm32, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::RCX }, Immediate8::from_u8(0), &[0xC4, 0xE3, 0xFB, 0xF0, 0xC1, 0x00])]
+#[case::gpr(GPR::R8, GPROrMemory::GPR { gpr: GPR::R10 }, Immediate8::from_u8(1), &[0xC4, 0x43, 0xFB, 0xF0, 0xC2, 0x01])]
+#[case::memory(GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Immediate8::from_u8(63), &[0xC4, 0xE3, 0xFB, 0xF0, 0x10, 0x3F])]
+#[case::memory(GPR::R11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, Immediate8::from_u8(5), &[0xC4, 0x43, 0xFB, 0xF0, 0x5C, 0x24, 0x08, 0x05])]
+#[case::memory(GPR::RCX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, Immediate8::from_u8(255), &[0xC4, 0xC3, 0xFB, 0xF0, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF, 0xFF])]
+fn test_rorx_reg64_rm64_imm8(
+    #[case] reg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { rorx::encode_reg64_rm64_imm8(reg64, rm64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
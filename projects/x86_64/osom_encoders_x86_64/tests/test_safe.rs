@@ -0,0 +1,368 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::RAX }, Immediate32::from_i32(1))]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R15 }, Immediate32::from_i32(-15))]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::None } }, Immediate32::from_i32(3))]
+#[case::memory(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::R14, scale: Scale::Scale2, offset: Offset::None } }, Immediate32::from_i32(3))]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(-8) } }, Immediate32::from_i32(3))]
+fn test_safe_matches_unsafe(#[case] rm64: GPROrMemory, #[case] imm32: Immediate32) {
+    let safe_instr = safe::mov::encode_rm64_imm32(rm64, imm32).unwrap();
+    let unsafe_instr = unsafe { mov::encode_rm64_imm32(rm64, imm32) };
+    assert_eq!(safe_instr, unsafe_instr);
+}
+
+#[rstest]
+#[case::gpr_too_small(GPROrMemory::GPR { gpr: GPR::EAX }, EncodeError::InvalidGPRSize { gpr: GPR::EAX, expected: Size::Bit64 })]
+#[case::gpr_too_small(GPROrMemory::GPR { gpr: GPR::AH }, EncodeError::InvalidGPRSize { gpr: GPR::AH, expected: Size::Bit64 })]
+#[case::base_not_64(GPROrMemory::Memory { memory: Memory::Based { base: GPR::EAX, offset: Offset::None } }, EncodeError::InvalidMemoryRegisterSize { gpr: GPR::EAX })]
+#[case::index_not_64(GPROrMemory::Memory { memory: Memory::Scaled { index: GPR::R9D, scale: Scale::Scale1, offset: Offset::None } }, EncodeError::InvalidMemoryRegisterSize { gpr: GPR::R9D })]
+#[case::index_rsp(GPROrMemory::Memory { memory: Memory::Scaled { index: GPR::RSP, scale: Scale::Scale1, offset: Offset::None } }, EncodeError::InvalidMemoryIndex { index: GPR::RSP })]
+#[case::index_rsp(GPROrMemory::Memory { memory: Memory::BasedAndScaled { base: GPR::RAX, index: GPR::RSP, scale: Scale::Scale8, offset: Offset::None } }, EncodeError::InvalidMemoryIndex { index: GPR::RSP })]
+fn test_safe_mov_rm64_imm32_errors(#[case] rm64: GPROrMemory, #[case] expected: EncodeError) {
+    let result = safe::mov::encode_rm64_imm32(rm64, Immediate32::from_i32(1));
+    assert_eq!(result, Err(expected));
+}
+
+#[rstest]
+#[case(GPR::EAX, EncodeError::InvalidGPRSize { gpr: GPR::EAX, expected: Size::Bit64 })]
+#[case(GPR::SPL, EncodeError::InvalidGPRSize { gpr: GPR::SPL, expected: Size::Bit64 })]
+fn test_safe_push_reg64_errors(#[case] reg64: GPR, #[case] expected: EncodeError) {
+    assert_eq!(safe::push::encode_reg64(reg64), Err(expected));
+}
+
+#[rstest]
+#[case(GPR::AH, GPROrMemory::GPR { gpr: GPR::R8B }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::AH })]
+#[case(GPR::AH, GPROrMemory::GPR { gpr: GPR::SIL }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::AH })]
+#[case(GPR::R9B, GPROrMemory::GPR { gpr: GPR::BH }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::BH })]
+#[case(GPR::DIL, GPROrMemory::GPR { gpr: GPR::CH }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::CH })]
+#[case(GPR::DH, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::None } }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::DH })]
+#[case(GPR::DH, GPROrMemory::Memory { memory: Memory::Scaled { index: GPR::R8, scale: Scale::Scale2, offset: Offset::None } }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::DH })]
+fn test_safe_mov_reg8_rm8_rex_conflicts(#[case] reg8: GPR, #[case] rm8: GPROrMemory, #[case] expected: EncodeError) {
+    assert_eq!(safe::mov::encode_reg8_rm8(reg8, rm8), Err(expected));
+}
+
+#[rstest]
+#[case::extended_reg(GPR::R8D, GPROrMemory::GPR { gpr: GPR::BH }, EncodeError::Bit8HighRegisterWithRex { gpr: GPR::BH })]
+#[case::rm_size(GPR::EAX, GPROrMemory::GPR { gpr: GPR::AX }, EncodeError::InvalidGPRSize { gpr: GPR::AX, expected: Size::Bit8 })]
+#[case::reg_size(GPR::AX, GPROrMemory::GPR { gpr: GPR::AL }, EncodeError::InvalidGPRSize { gpr: GPR::AX, expected: Size::Bit32 })]
+fn test_safe_movzx_reg32_rm8_errors(#[case] reg32: GPR, #[case] rm8: GPROrMemory, #[case] expected: EncodeError) {
+    assert_eq!(safe::movzx::encode_reg32_rm8(reg32, rm8), Err(expected));
+}
+
+#[test]
+fn test_safe_movzx_reg64_rm8_rex_w() {
+    assert_eq!(
+        safe::movzx::encode_reg64_rm8(GPR::RAX, GPR::AH.into()),
+        Err(EncodeError::Bit8HighRegisterWithRex { gpr: GPR::AH })
+    );
+}
+
+#[test]
+fn test_safe_condition_encoders() {
+    assert_eq!(
+        safe::setcc::encode_rm8(Condition::E, GPR::EAX.into()),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::EAX,
+            expected: Size::Bit8
+        })
+    );
+    assert_eq!(
+        safe::cmovcc::encode_reg32_rm32(Condition::NE, GPR::RAX, GPR::ECX.into()),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::RAX,
+            expected: Size::Bit32
+        })
+    );
+    assert_eq!(
+        safe::cmovcc::encode_reg64_rm64(Condition::L, GPR::R8, GPR::R9.into())
+            .unwrap()
+            .as_slice(),
+        &[0x4D, 0x0F, 0x4C, 0xC1]
+    );
+    assert_eq!(
+        safe::jcc::encode_imm8(Condition::GE, Immediate8::from_i8(0x12))
+            .unwrap()
+            .as_slice(),
+        &[0x7D, 0x12]
+    );
+}
+
+#[rstest]
+#[case(GPR::AH, GPROrMemory::GPR { gpr: GPR::BL }, &[0x8A, 0xE3])]
+#[case(GPR::AH, GPROrMemory::GPR { gpr: GPR::BH }, &[0x8A, 0xE7])]
+#[case(GPR::AH, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x8A, 0x20])]
+#[case(GPR::AL, GPROrMemory::GPR { gpr: GPR::SIL }, &[0x40, 0x8A, 0xC6])]
+#[case(GPR::SIL, GPROrMemory::GPR { gpr: GPR::AL }, &[0x40, 0x8A, 0xF0])]
+#[case(GPR::SIL, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x40, 0x8A, 0x30])]
+fn test_safe_mov_reg8_rm8(#[case] reg8: GPR, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = safe::mov::encode_reg8_rm8(reg8, rm8).unwrap();
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[test]
+fn test_safe_lea_errors() {
+    let memory = Memory::Based {
+        base: GPR::EDX,
+        offset: Offset::from_i8(4),
+    };
+    assert_eq!(
+        safe::lea::encode_reg64_mem64(GPR::RAX, memory),
+        Err(EncodeError::InvalidMemoryRegisterSize { gpr: GPR::EDX })
+    );
+    assert_eq!(
+        safe::lea::encode_reg64_mem64(GPR::AX, memory),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::AX,
+            expected: Size::Bit64
+        })
+    );
+}
+
+#[rstest]
+#[case::add(safe::add::encode_lock_rm32_imm8(GPR::EAX.into(), Immediate8::from_i8(1)), GPR::EAX)]
+#[case::inc(safe::inc::encode_lock_rm64(GPR::RCX.into()), GPR::RCX)]
+#[case::xchg(safe::xchg::encode_lock_rm32_reg32(GPR::EDX.into(), GPR::EAX), GPR::EDX)]
+#[case::cmpxchg(safe::cmpxchg::encode_lock_rm64_reg64(GPR::RBX.into(), GPR::RCX), GPR::RBX)]
+#[case::xadd(safe::xadd::encode_lock_rm8_reg8(GPR::AL.into(), GPR::BL), GPR::AL)]
+#[case::bts(safe::bts::encode_lock_rm32_imm8(GPR::ECX.into(), Immediate8::from_u8(1)), GPR::ECX)]
+fn test_safe_lock_register_destination(#[case] result: Result<EncodedX86_64Instruction, EncodeError>, #[case] gpr: GPR) {
+    assert_eq!(result, Err(EncodeError::LockWithRegisterDestination { gpr }));
+}
+
+#[test]
+fn test_safe_lock_memory_destination() {
+    let memory = Memory::Based {
+        base: GPR::RDI,
+        offset: Offset::None,
+    };
+    assert_eq!(
+        safe::cmpxchg16b::encode_lock_mem128(memory).unwrap().as_slice(),
+        &[0xF0, 0x48, 0x0F, 0xC7, 0x0F]
+    );
+    assert_eq!(
+        safe::xadd::encode_lock_rm64_reg64(memory.into(), GPR::RAX)
+            .unwrap()
+            .as_slice(),
+        &[0xF0, 0x48, 0x0F, 0xC1, 0x07]
+    );
+    assert_eq!(
+        safe::xadd::encode_lock_rm64_reg64(memory.into(), GPR::EAX),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::EAX,
+            expected: Size::Bit64
+        })
+    );
+}
+
+#[test]
+fn test_safe_bit_manipulation() {
+    assert_eq!(
+        safe::bswap::encode_reg32(GPR::AX),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::AX,
+            expected: Size::Bit32
+        })
+    );
+    assert_eq!(
+        safe::popcnt::encode_reg64_rm64(GPR::RAX, GPR::ECX.into()),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::ECX,
+            expected: Size::Bit64
+        })
+    );
+    assert_eq!(
+        safe::lzcnt::encode_reg16_rm16(GPR::AX, GPR::BX.into())
+            .unwrap()
+            .as_slice(),
+        &[0x66, 0xF3, 0x0F, 0xBD, 0xC3]
+    );
+}
+
+#[test]
+fn test_safe_vex_operands() {
+    assert_eq!(
+        safe::andn::encode_reg64_vreg64_rm64(GPR::RAX, GPR::EBX, GPR::RCX.into()),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::EBX,
+            expected: Size::Bit64
+        })
+    );
+    assert_eq!(
+        safe::shlx::encode_reg64_rm64_vreg64(GPR::RAX, GPR::RCX.into(), GPR::R12)
+            .unwrap()
+            .as_slice(),
+        &[0xC4, 0xE2, 0x99, 0xF7, 0xC1]
+    );
+}
+
+#[test]
+fn test_safe_sse_operands() {
+    assert_eq!(
+        safe::cvtsi2sd::encode_xmm_rm64(XMM::XMM0, GPR::EAX.into()),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::EAX,
+            expected: Size::Bit64
+        })
+    );
+    assert_eq!(
+        safe::cvttsd2si::encode_reg32_xmmm64(GPR::R8D, XMM::XMM9.into())
+            .unwrap()
+            .as_slice(),
+        &[0xF2, 0x45, 0x0F, 0x2C, 0xC1]
+    );
+    assert_eq!(
+        safe::addsd::encode_xmm_xmmm64(
+            XMM::XMM1,
+            Memory::Based {
+                base: GPR::EAX,
+                offset: Offset::None
+            }
+            .into()
+        ),
+        Err(EncodeError::InvalidMemoryRegisterSize { gpr: GPR::EAX })
+    );
+}
+
+#[test]
+fn test_safe_packed_sse_operands() {
+    assert_eq!(
+        safe::pinsrb::encode_xmm_r32m8_imm8(XMM::XMM1, GPR::AL.into(), Immediate8::from_u8(1)),
+        Err(EncodeError::InvalidGPRSize {
+            gpr: GPR::AL,
+            expected: Size::Bit32
+        })
+    );
+    assert_eq!(
+        safe::pextrw::encode_reg32_xmm_imm8(GPR::R9D, XMM::XMM2, Immediate8::from_u8(7))
+            .unwrap()
+            .as_slice(),
+        &[0x66, 0x44, 0x0F, 0xC5, 0xCA, 0x07]
+    );
+    assert_eq!(
+        safe::movdqa::encode_xmm_xmmm128(
+            XMM::XMM1,
+            Memory::Scaled {
+                index: GPR::RSP,
+                scale: Scale::Scale1,
+                offset: Offset::None
+            }
+            .into()
+        ),
+        Err(EncodeError::InvalidMemoryIndex { index: GPR::RSP })
+    );
+}
+
+#[test]
+fn test_safe_avx_operands() {
+    assert_eq!(
+        safe::vaddps::encode_ymm_vymm_ymmm256(YMM::YMM0, YMM::YMM1, YMM::YMM2.into())
+            .unwrap()
+            .as_slice(),
+        &[0xC5, 0xF4, 0x58, 0xC2]
+    );
+    assert_eq!(
+        safe::vmovdqu::encode_ymmm256_ymm(
+            Memory::Scaled {
+                index: GPR::RSP,
+                scale: Scale::Scale2,
+                offset: Offset::None
+            }
+            .into(),
+            YMM::YMM1
+        ),
+        Err(EncodeError::InvalidMemoryIndex { index: GPR::RSP })
+    );
+    assert_eq!(safe::vzeroupper::encode().unwrap().as_slice(), &[0xC5, 0xF8, 0x77]);
+}
+
+#[test]
+fn test_safe_zero_operands() {
+    assert_eq!(safe::ret::encode().unwrap().as_slice(), &[0xC3]);
+    assert_eq!(safe::syscall::encode().unwrap().as_slice(), &[0x0F, 0x05]);
+    assert_eq!(safe::movs::encode_rep_b().unwrap().as_slice(), &[0xF3, 0xA4]);
+    assert_eq!(safe::stos::encode_rep_q().unwrap().as_slice(), &[0xF3, 0x48, 0xAB]);
+}
+
+#[test]
+fn test_safe_vsib_operands() {
+    let vsib_memory = VSIBMemory::BasedAndScaled {
+        base: GPR::RAX,
+        index: XMM::XMM2.into(),
+        scale: Scale::Scale4,
+        offset: Offset::None,
+    };
+    assert_eq!(
+        safe::vpgatherdd::encode_xmm_vm32x_vxmm(XMM::XMM1, vsib_memory, XMM::XMM3)
+            .unwrap()
+            .as_slice(),
+        &[0xC4, 0xE2, 0x61, 0x90, 0x0C, 0x90]
+    );
+    assert_eq!(
+        safe::vpgatherdd::encode_xmm_vm32x_vxmm(XMM::XMM2, vsib_memory, XMM::XMM3),
+        Err(EncodeError::GatherRegistersNotDistinct)
+    );
+    assert_eq!(
+        safe::vpgatherdq::encode_ymm_vm32x_vymm(YMM::YMM1, vsib_memory, YMM::YMM1),
+        Err(EncodeError::GatherRegistersNotDistinct)
+    );
+    assert_eq!(
+        safe::vpgatherdd::encode_ymm_vm32y_vymm(YMM::YMM1, vsib_memory, YMM::YMM3),
+        Err(EncodeError::InvalidVSIBIndex {
+            index: XMM::XMM2.into(),
+            expected: Size::Bit256
+        })
+    );
+    assert_eq!(
+        safe::vpgatherdd::encode_xmm_vm32x_vxmm(
+            XMM::XMM1,
+            VSIBMemory::Scaled {
+                index: XMM::XMM16.into(),
+                scale: Scale::Scale4,
+                offset: Offset::None
+            },
+            XMM::XMM3
+        ),
+        Err(EncodeError::XMMRequiresEvex { xmm: XMM::XMM16 })
+    );
+
+    let vsib_memory = VSIBMemory::BasedAndScaled {
+        base: GPR::EAX,
+        index: ZMM::ZMM2.into(),
+        scale: Scale::Scale4,
+        offset: Offset::None,
+    };
+    let opmask = Opmask::K1;
+    assert_eq!(
+        safe::vpscatterdd::encode_vm32z_mergemask_zmm(vsib_memory, Masking::Merge { opmask }, ZMM::ZMM3),
+        Err(EncodeError::InvalidMemoryRegisterSize { gpr: GPR::EAX })
+    );
+
+    let vsib_memory = VSIBMemory::BasedAndScaled {
+        base: GPR::RAX,
+        index: ZMM::ZMM2.into(),
+        scale: Scale::Scale4,
+        offset: Offset::None,
+    };
+    assert_eq!(
+        safe::vpgatherdd::encode_zmm_mergemask_vm32z(ZMM::ZMM1, Masking::None, vsib_memory),
+        Err(EncodeError::WriteMaskRequired)
+    );
+    assert_eq!(
+        safe::vpgatherdd::encode_zmm_mergemask_vm32z(ZMM::ZMM1, Masking::Zero { opmask }, vsib_memory),
+        Err(EncodeError::ZeroingMaskingNotAllowed)
+    );
+    assert_eq!(
+        safe::vpgatherdd::encode_zmm_mergemask_vm32z(ZMM::ZMM2, Masking::Merge { opmask }, vsib_memory),
+        Err(EncodeError::GatherRegistersNotDistinct)
+    );
+    assert_eq!(
+        safe::vpscatterdd::encode_vm32z_mergemask_zmm(vsib_memory, Masking::Merge { opmask }, ZMM::ZMM2)
+            .unwrap()
+            .as_slice(),
+        &[0x62, 0xF2, 0x7D, 0x49, 0xA0, 0x14, 0x90]
+    );
+}
@@ -0,0 +1,31 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::byte(unsafe { scas::encode_b() }, &[0xAE])]
+#[case::word(unsafe { scas::encode_w() }, &[0x66, 0xAF])]
+#[case::dword(unsafe { scas::encode_d() }, &[0xAF])]
+#[case::qword(unsafe { scas::encode_q() }, &[0x48, 0xAF])]
+fn test_scas(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { scas::encode_repe_b() }, &[0xF3, 0xAE])]
+#[case::word(unsafe { scas::encode_repe_w() }, &[0xF3, 0x66, 0xAF])]
+#[case::dword(unsafe { scas::encode_repe_d() }, &[0xF3, 0xAF])]
+#[case::qword(unsafe { scas::encode_repe_q() }, &[0xF3, 0x48, 0xAF])]
+fn test_scas_repe(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { scas::encode_repne_b() }, &[0xF2, 0xAE])]
+#[case::word(unsafe { scas::encode_repne_w() }, &[0xF2, 0x66, 0xAF])]
+#[case::dword(unsafe { scas::encode_repne_d() }, &[0xF2, 0xAF])]
+#[case::qword(unsafe { scas::encode_repne_q() }, &[0xF2, 0x48, 0xAF])]
+fn test_scas_repne(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,19 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(Condition::E, GPROrMemory::GPR { gpr: GPR::AL }, &[0x0F, 0x94, 0xC0])]
+#[case::gpr(Condition::E, GPROrMemory::GPR { gpr: GPR::AH }, &[0x0F, 0x94, 0xC4])]
+#[case::gpr(Condition::E, GPROrMemory::GPR { gpr: GPR::SPL }, &[0x40, 0x0F, 0x94, 0xC4])]
+#[case::gpr(Condition::NE, GPROrMemory::GPR { gpr: GPR::SPL }, &[0x40, 0x0F, 0x95, 0xC4])]
+#[case::gpr(Condition::B, GPROrMemory::GPR { gpr: GPR::R15B }, &[0x41, 0x0F, 0x92, 0xC7])]
+#[case::gpr(Condition::O, GPROrMemory::GPR { gpr: GPR::AH }, &[0x0F, 0x90, 0xC4])]
+#[case::memory(Condition::E, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x0F, 0x94, 0x00])]
+#[case::memory(Condition::G, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0x0F, 0x9F, 0x00])]
+#[case::memory(Condition::PE, GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(16) } }, &[0x0F, 0x9A, 0x05, 0x10, 0x00, 0x00, 0x00])]
+fn test_setcc_rm8(#[case] condition: Condition, #[case] rm8: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { setcc::encode_rm8(condition, rm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
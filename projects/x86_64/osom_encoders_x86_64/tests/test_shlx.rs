@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPR::EAX, GPROrMemory::GPR { gpr: GPR::ECX }, GPR::EBX, &[0xC4, 0xE2, 0x61, 0xF7, 0xC1])]
+#[case::gpr(GPR::R8D, GPROrMemory::GPR { gpr: GPR::R10D }, GPR::R9D, &[0xC4, 0x42, 0x31, 0xF7, 0xC2])]
+#[case::memory(GPR::EDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::R15D, &[0xC4, 0xE2, 0x01, 0xF7, 0x10])]
+#[case::memory(GPR::R11D, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, GPR::ESI, &[0xC4, 0x42, 0x49, 0xF7, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::ECX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, GPR::EDX, &[0xC4, 0xC2, 0x69, 0xF7, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_shlx_reg32_rm32_vreg32(
+    #[case] reg32: GPR,
+    #[case] rm32: GPROrMemory,
+    #[case] vreg32: GPR,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { shlx::encode_reg32_rm32_vreg32(reg32, rm32, vreg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPR::RAX, GPROrMemory::GPR { gpr: GPR::RCX }, GPR::RBX, &[0xC4, 0xE2, 0xE1, 0xF7, 0xC1])]
+#[case::gpr(GPR::R8, GPROrMemory::GPR { gpr: GPR::R10 }, GPR::R9, &[0xC4, 0x42, 0xB1, 0xF7, 0xC2])]
+#[case::memory(GPR::RDX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::R15, &[0xC4, 0xE2, 0x81, 0xF7, 0x10])]
+#[case::memory(GPR::R11, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, GPR::RSI, &[0xC4, 0x42, 0xC9, 0xF7, 0x5C, 0x24, 0x08])]
+#[case::memory(GPR::RCX, GPROrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, GPR::RDX, &[0xC4, 0xC2, 0xE9, 0xF7, 0x8D, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_shlx_reg64_rm64_vreg64(
+    #[case] reg64: GPR,
+    #[case] rm64: GPROrMemory,
+    #[case] vreg64: GPR,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { shlx::encode_reg64_rm64_vreg64(reg64, rm64, vreg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,22 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::byte(unsafe { stos::encode_b() }, &[0xAA])]
+#[case::word(unsafe { stos::encode_w() }, &[0x66, 0xAB])]
+#[case::dword(unsafe { stos::encode_d() }, &[0xAB])]
+#[case::qword(unsafe { stos::encode_q() }, &[0x48, 0xAB])]
+fn test_stos(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::byte(unsafe { stos::encode_rep_b() }, &[0xF3, 0xAA])]
+#[case::word(unsafe { stos::encode_rep_w() }, &[0xF3, 0x66, 0xAB])]
+#[case::dword(unsafe { stos::encode_rep_d() }, &[0xF3, 0xAB])]
+#[case::qword(unsafe { stos::encode_rep_q() }, &[0xF3, 0x48, 0xAB])]
+fn test_stos_rep(#[case] instr: EncodedX86_64Instruction, #[case] expected: &[u8]) {
+    assert_eq!(instr.as_slice(), expected);
+}
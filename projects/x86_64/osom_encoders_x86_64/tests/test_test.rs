@@ -0,0 +1,99 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case(0, &[0xA8, 0x00])]
+#[case(-1, &[0xA8, 0xFF])]
+fn test_test_al_imm8(#[case] imm8: i8, #[case] expected: &[u8]) {
+    let imm8 = Immediate8::from_i8(imm8);
+    let instr = unsafe { test::encode_al_imm8(imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(0, &[0x66, 0xA9, 0x00, 0x00])]
+#[case(1000, &[0x66, 0xA9, 0xE8, 0x03])]
+fn test_test_ax_imm16(#[case] imm16: i16, #[case] expected: &[u8]) {
+    let imm16 = Immediate16::from_i16(imm16);
+    let instr = unsafe { test::encode_ax_imm16(imm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(1, &[0xA9, 0x01, 0x00, 0x00, 0x00])]
+#[case(-100000, &[0xA9, 0x60, 0x79, 0xFE, 0xFF])]
+fn test_test_eax_imm32(#[case] imm32: i32, #[case] expected: &[u8]) {
+    let imm32 = Immediate32::from_i32(imm32);
+    let instr = unsafe { test::encode_eax_imm32(imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case(1, &[0x48, 0xA9, 0x01, 0x00, 0x00, 0x00])]
+#[case(2147483647, &[0x48, 0xA9, 0xFF, 0xFF, 0xFF, 0x7F])]
+fn test_test_rax_imm32(#[case] imm32: i32, #[case] expected: &[u8]) {
+    let imm32 = Immediate32::from_i32(imm32);
+    let instr = unsafe { test::encode_rax_imm32(imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::BL }, Immediate8::from_i8(1), &[0xF6, 0xC3, 0x01])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(1) } }, Immediate8::from_i8(1), &[0xF6, 0x47, 0x01, 0x01])]
+fn test_test_rm8_imm8(#[case] gpr_or_memory: GPROrMemory, #[case] imm8: Immediate8, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm8_imm8(gpr_or_memory, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R10W }, Immediate16::from_i16(0x100), &[0x66, 0x41, 0xF7, 0xC2, 0x00, 0x01])]
+fn test_test_rm16_imm16(#[case] gpr_or_memory: GPROrMemory, #[case] imm16: Immediate16, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm16_imm16(gpr_or_memory, imm16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ESI }, Immediate32::from_i32(-1), &[0xF7, 0xC6, 0xFF, 0xFF, 0xFF, 0xFF])]
+fn test_test_rm32_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm32_imm32(gpr_or_memory, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R8 }, Immediate32::from_i32(8), &[0x49, 0xF7, 0xC0, 0x08, 0x00, 0x00, 0x00])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::RelativeToRIP { offset: Offset::from_i32(32) } }, Immediate32::from_i32(8), &[0x48, 0xF7, 0x05, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00])]
+fn test_test_rm64_imm32(#[case] gpr_or_memory: GPROrMemory, #[case] imm32: Immediate32, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm64_imm32(gpr_or_memory, imm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AH }, GPR::BL, &[0x84, 0xDC])]
+fn test_test_rm8_reg8(#[case] rm8: GPROrMemory, #[case] reg8: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm8_reg8(rm8, reg8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AX }, GPR::R9W, &[0x66, 0x44, 0x85, 0xC8])]
+fn test_test_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EDI }, GPR::EDI, &[0x85, 0xFF])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::None } }, GPR::EDI, &[0x85, 0x3C, 0x24])]
+fn test_test_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::R12 }, GPR::RAX, &[0x49, 0x85, 0xC4])]
+fn test_test_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { test::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,74 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMM::XMM1, XMMOrMemory::XMM { xmm: XMM::XMM2 }, &[0xC5, 0xF1, 0x58, 0xC2])]
+#[case::xmm(XMM::XMM8, XMM::XMM9, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x41, 0x31, 0x58, 0xC7])]
+#[case::memory(XMM::XMM2, XMM::XMM3, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xE1, 0x58, 0x10])]
+#[case::memory(XMM::XMM11, XMM::XMM12, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x19, 0x58, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMM::XMM0, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x79, 0x58, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vaddpd_xmm_vxmm_xmmm128(
+    #[case] xmm: XMM,
+    #[case] vxmm: XMM,
+    #[case] xmmm128: XMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vaddpd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMM::YMM0, YMM::YMM1, YMMOrMemory::YMM { ymm: YMM::YMM2 }, &[0xC5, 0xF5, 0x58, 0xC2])]
+#[case::ymm(YMM::YMM8, YMM::YMM9, YMMOrMemory::YMM { ymm: YMM::YMM15 }, &[0xC4, 0x41, 0x35, 0x58, 0xC7])]
+#[case::memory(YMM::YMM2, YMM::YMM3, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xE5, 0x58, 0x10])]
+#[case::memory(YMM::YMM11, YMM::YMM12, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x1D, 0x58, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, YMM::YMM0, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x7D, 0x58, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vaddpd_ymm_vymm_ymmm256(
+    #[case] ymm: YMM,
+    #[case] vymm: YMM,
+    #[case] ymmm256: YMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vaddpd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF1, 0xF5, 0x48, 0x58, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0xF5, 0x41, 0x58, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0x51, 0xB5, 0xCF, 0x58, 0xC7])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF1, 0xDD, 0x48, 0x58, 0x58, 0x01])]
+#[case::memory(ZMM::ZMM5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD1, 0xDD, 0x42, 0x58, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(ZMM::ZMM24, Masking::Zero { opmask: Opmask::K3 }, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0x61, 0xCD, 0xCB, 0x58, 0x45, 0x00])]
+#[case::memory(ZMM::ZMM7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF1, 0xFD, 0x48, 0x58, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(ZMM::ZMM1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, &[0x62, 0xF1, 0xED, 0x59, 0x58, 0x48, 0x01])]
+#[case::broadcast(ZMM::ZMM30, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-1600) } }, &[0x62, 0x41, 0x95, 0x50, 0x58, 0xB4, 0x24, 0xC0, 0xF9, 0xFF, 0xFF])]
+fn test_vaddpd_zmm_mask_vzmm_zmmm512b64(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b64: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vaddpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMM::ZMM2, Rounding::Nearest, &[0x62, 0xF1, 0xF5, 0x18, 0x58, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMM::ZMM31, Rounding::Down, &[0x62, 0x81, 0xF5, 0x31, 0x58, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMM::ZMM15, Rounding::Up, &[0x62, 0x51, 0xB5, 0xDF, 0x58, 0xC7])]
+#[case::zmm(ZMM::ZMM3, Masking::None, ZMM::ZMM28, ZMM::ZMM4, Rounding::Zero, &[0x62, 0xF1, 0x9D, 0x70, 0x58, 0xDC])]
+fn test_vaddpd_zmm_mask_vzmm_zmmr_er(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmr: ZMM,
+    #[case] er: Rounding,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vaddpd::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,43 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, Immediate8::from_u8(1), &[0x62, 0xF1, 0xF5, 0x48, 0xC2, 0xC2, 0x01])]
+#[case::masked(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, Immediate8::from_u8(1), &[0x62, 0x91, 0xF5, 0x41, 0xC2, 0xCF, 0x01])]
+#[case::masked(Opmask::K0, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, Immediate8::from_u8(1), &[0x62, 0xD1, 0xB5, 0x4F, 0xC2, 0xC7, 0x01])]
+#[case::memory(Opmask::K3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, Immediate8::from_u8(1), &[0x62, 0xF1, 0xDD, 0x48, 0xC2, 0x58, 0x01, 0x01])]
+#[case::memory(Opmask::K5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, Immediate8::from_u8(1), &[0x62, 0xD1, 0xDD, 0x42, 0xC2, 0xAD, 0x41, 0x00, 0x00, 0x00, 0x01])]
+#[case::memory(Opmask::K6, Masking::None, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, Immediate8::from_u8(1), &[0x62, 0xF1, 0xCD, 0x48, 0xC2, 0x75, 0x00, 0x01])]
+#[case::memory(Opmask::K7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, Immediate8::from_u8(1), &[0x62, 0xF1, 0xFD, 0x48, 0xC2, 0x7C, 0x24, 0xFE, 0x01])]
+#[case::broadcast(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, Immediate8::from_u8(1), &[0x62, 0xF1, 0xED, 0x59, 0xC2, 0x48, 0x01, 0x01])]
+#[case::broadcast(Opmask::K2, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-1600) } }, Immediate8::from_u8(1), &[0x62, 0xD1, 0x95, 0x50, 0xC2, 0x94, 0x24, 0xC0, 0xF9, 0xFF, 0xFF, 0x01])]
+fn test_vcmppd_k_mergemask_vzmm_zmmm512b64_imm8(
+    #[case] k: Opmask,
+    #[case] mergemask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b64: ZMMOrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vcmppd::encode_k_mergemask_vzmm_zmmm512b64_imm8(k, mergemask, vzmm, zmmm512b64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::k(Opmask::K1, Masking::None, ZMM::ZMM1, ZMM::ZMM2, Immediate8::from_u8(1), &[0x62, 0xF1, 0xF5, 0x18, 0xC2, 0xCA, 0x01])]
+#[case::masked(Opmask::K3, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMM::ZMM31, Immediate8::from_u8(2), &[0x62, 0x91, 0xF5, 0x11, 0xC2, 0xDF, 0x02])]
+#[case::masked(Opmask::K2, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM9, ZMM::ZMM15, Immediate8::from_u8(3), &[0x62, 0xD1, 0xB5, 0x1F, 0xC2, 0xD7, 0x03])]
+#[case::k(Opmask::K4, Masking::None, ZMM::ZMM28, ZMM::ZMM4, Immediate8::from_u8(4), &[0x62, 0xF1, 0x9D, 0x10, 0xC2, 0xE4, 0x04])]
+fn test_vcmppd_k_mergemask_vzmm_zmmr_sae_imm8(
+    #[case] k: Opmask,
+    #[case] mergemask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmr: ZMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vcmppd::encode_k_mergemask_vzmm_zmmr_sae_imm8(k, mergemask, vzmm, zmmr, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
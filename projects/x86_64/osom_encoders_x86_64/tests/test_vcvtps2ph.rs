@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM1 }, XMM::XMM0, Immediate8::from_u8(0), &[0xC4, 0xE3, 0x79, 0x1D, 0xC1, 0x00])]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM15 }, XMM::XMM8, Immediate8::from_u8(4), &[0xC4, 0x43, 0x79, 0x1D, 0xC7, 0x04])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, Immediate8::from_u8(8), &[0xC4, 0xE3, 0x79, 0x1D, 0x10, 0x08])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, Immediate8::from_u8(3), &[0xC4, 0x43, 0x79, 0x1D, 0x5C, 0x24, 0x08, 0x03])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, Immediate8::from_u8(255), &[0xC4, 0xC3, 0x79, 0x1D, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0xFF])]
+fn test_vcvtps2ph_xmmm64_xmm_imm8(
+    #[case] xmmm64: XMMOrMemory,
+    #[case] xmm: XMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vcvtps2ph::encode_xmmm64_xmm_imm8(xmmm64, xmm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM1 }, YMM::YMM0, Immediate8::from_u8(0), &[0xC4, 0xE3, 0x7D, 0x1D, 0xC1, 0x00])]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM15 }, YMM::YMM8, Immediate8::from_u8(4), &[0xC4, 0x43, 0x7D, 0x1D, 0xC7, 0x04])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, YMM::YMM2, Immediate8::from_u8(8), &[0xC4, 0xE3, 0x7D, 0x1D, 0x10, 0x08])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, YMM::YMM11, Immediate8::from_u8(3), &[0xC4, 0x43, 0x7D, 0x1D, 0x5C, 0x24, 0x08, 0x03])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, YMM::YMM7, Immediate8::from_u8(255), &[0xC4, 0xC3, 0x7D, 0x1D, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF, 0xFF])]
+fn test_vcvtps2ph_xmmm128_ymm_imm8(
+    #[case] xmmm128: XMMOrMemory,
+    #[case] ymm: YMM,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vcvtps2ph::encode_xmmm128_ymm_imm8(xmmm128, ymm, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
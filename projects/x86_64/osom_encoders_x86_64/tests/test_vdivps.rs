@@ -0,0 +1,42 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF1, 0x74, 0x48, 0x5E, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0x74, 0x41, 0x5E, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0x51, 0x34, 0xCF, 0x5E, 0xC7])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF1, 0x5C, 0x48, 0x5E, 0x58, 0x01])]
+#[case::memory(ZMM::ZMM5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD1, 0x5C, 0x42, 0x5E, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(ZMM::ZMM24, Masking::Zero { opmask: Opmask::K3 }, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0x61, 0x4C, 0xCB, 0x5E, 0x45, 0x00])]
+#[case::memory(ZMM::ZMM7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF1, 0x7C, 0x48, 0x5E, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(ZMM::ZMM1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } }, &[0x62, 0xF1, 0x6C, 0x59, 0x5E, 0x48, 0x01])]
+#[case::broadcast(ZMM::ZMM30, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-800) } }, &[0x62, 0x41, 0x14, 0x50, 0x5E, 0xB4, 0x24, 0xE0, 0xFC, 0xFF, 0xFF])]
+fn test_vdivps_zmm_mask_vzmm_zmmm512b32(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b32: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vdivps::encode_zmm_mask_vzmm_zmmm512b32(zmm, mask, vzmm, zmmm512b32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMM::ZMM2, Rounding::Nearest, &[0x62, 0xF1, 0x74, 0x18, 0x5E, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMM::ZMM31, Rounding::Down, &[0x62, 0x81, 0x74, 0x31, 0x5E, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMM::ZMM15, Rounding::Up, &[0x62, 0x51, 0x34, 0xDF, 0x5E, 0xC7])]
+#[case::zmm(ZMM::ZMM3, Masking::None, ZMM::ZMM28, ZMM::ZMM4, Rounding::Zero, &[0x62, 0xF1, 0x1C, 0x70, 0x5E, 0xDC])]
+fn test_vdivps_zmm_mask_vzmm_zmmr_er(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmr: ZMM,
+    #[case] er: Rounding,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vdivps::encode_zmm_mask_vzmm_zmmr_er(zmm, mask, vzmm, zmmr, er) };
+    assert_eq!(instr.as_slice(), expected);
+}
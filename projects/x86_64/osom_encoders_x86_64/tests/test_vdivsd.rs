@@ -0,0 +1,40 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, Masking::None, XMM::XMM1, XMMOrMemory::XMM { xmm: XMM::XMM2 }, &[0x62, 0xF1, 0xF7, 0x08, 0x5E, 0xC2])]
+#[case::masked(XMM::XMM16, Masking::Merge { opmask: Opmask::K1 }, XMM::XMM17, XMMOrMemory::XMM { xmm: XMM::XMM31 }, &[0x62, 0x81, 0xF7, 0x01, 0x5E, 0xC7])]
+#[case::zeroing(XMM::XMM8, Masking::Zero { opmask: Opmask::K7 }, XMM::XMM9, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0x62, 0x51, 0xB7, 0x8F, 0x5E, 0xC7])]
+#[case::memory(XMM::XMM3, Masking::None, XMM::XMM4, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, &[0x62, 0xF1, 0xDF, 0x08, 0x5E, 0x58, 0x01])]
+#[case::memory(XMM::XMM5, Masking::Merge { opmask: Opmask::K2 }, XMM::XMM20, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(9) } }, &[0x62, 0xD1, 0xDF, 0x02, 0x5E, 0xAD, 0x09, 0x00, 0x00, 0x00])]
+#[case::memory(XMM::XMM24, Masking::Zero { opmask: Opmask::K3 }, XMM::XMM6, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0x61, 0xCF, 0x8B, 0x5E, 0x45, 0x00])]
+#[case::memory(XMM::XMM7, Masking::None, XMM::XMM0, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-16) } }, &[0x62, 0xF1, 0xFF, 0x08, 0x5E, 0x7C, 0x24, 0xFE])]
+fn test_vdivsd_xmm_mask_vxmm_xmmm64(
+    #[case] xmm: XMM,
+    #[case] mask: Masking,
+    #[case] vxmm: XMM,
+    #[case] xmmm64: XMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vdivsd::encode_xmm_mask_vxmm_xmmm64(xmm, mask, vxmm, xmmm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(XMM::XMM0, Masking::None, XMM::XMM1, XMM::XMM2, Rounding::Nearest, &[0x62, 0xF1, 0xF7, 0x18, 0x5E, 0xC2])]
+#[case::masked(XMM::XMM16, Masking::Merge { opmask: Opmask::K1 }, XMM::XMM17, XMM::XMM31, Rounding::Down, &[0x62, 0x81, 0xF7, 0x31, 0x5E, 0xC7])]
+#[case::zeroing(XMM::XMM8, Masking::Zero { opmask: Opmask::K7 }, XMM::XMM9, XMM::XMM15, Rounding::Up, &[0x62, 0x51, 0xB7, 0xDF, 0x5E, 0xC7])]
+#[case::xmm(XMM::XMM3, Masking::None, XMM::XMM28, XMM::XMM4, Rounding::Zero, &[0x62, 0xF1, 0x9F, 0x70, 0x5E, 0xDC])]
+fn test_vdivsd_xmm_mask_vxmm_xmmr_er(
+    #[case] xmm: XMM,
+    #[case] mask: Masking,
+    #[case] vxmm: XMM,
+    #[case] xmmr: XMM,
+    #[case] er: Rounding,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vdivsd::encode_xmm_mask_vxmm_xmmr_er(xmm, mask, vxmm, xmmr, er) };
+    assert_eq!(instr.as_slice(), expected);
+}
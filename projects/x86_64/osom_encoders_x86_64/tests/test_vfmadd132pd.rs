@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMM::XMM1, XMMOrMemory::XMM { xmm: XMM::XMM2 }, &[0xC4, 0xE2, 0xF1, 0x98, 0xC2])]
+#[case::xmm(XMM::XMM8, XMM::XMM9, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0xB1, 0x98, 0xC7])]
+#[case::memory(XMM::XMM2, XMM::XMM3, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0xE1, 0x98, 0x10])]
+#[case::memory(XMM::XMM11, XMM::XMM12, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x99, 0x98, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMM::XMM0, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0xF9, 0x98, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vfmadd132pd_xmm_vxmm_xmmm128(
+    #[case] xmm: XMM,
+    #[case] vxmm: XMM,
+    #[case] xmmm128: XMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vfmadd132pd::encode_xmm_vxmm_xmmm128(xmm, vxmm, xmmm128) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMM::YMM0, YMM::YMM1, YMMOrMemory::YMM { ymm: YMM::YMM2 }, &[0xC4, 0xE2, 0xF5, 0x98, 0xC2])]
+#[case::ymm(YMM::YMM8, YMM::YMM9, YMMOrMemory::YMM { ymm: YMM::YMM15 }, &[0xC4, 0x42, 0xB5, 0x98, 0xC7])]
+#[case::memory(YMM::YMM2, YMM::YMM3, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0xE5, 0x98, 0x10])]
+#[case::memory(YMM::YMM11, YMM::YMM12, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x9D, 0x98, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, YMM::YMM0, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0xFD, 0x98, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vfmadd132pd_ymm_vymm_ymmm256(
+    #[case] ymm: YMM,
+    #[case] vymm: YMM,
+    #[case] ymmm256: YMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vfmadd132pd::encode_ymm_vymm_ymmm256(ymm, vymm, ymmm256) };
+    assert_eq!(instr.as_slice(), expected);
+}
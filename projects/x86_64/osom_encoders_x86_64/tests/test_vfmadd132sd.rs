@@ -0,0 +1,20 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMM::XMM1, XMMOrMemory::XMM { xmm: XMM::XMM2 }, &[0xC4, 0xE2, 0xF1, 0x99, 0xC2])]
+#[case::xmm(XMM::XMM8, XMM::XMM9, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0xB1, 0x99, 0xC7])]
+#[case::memory(XMM::XMM2, XMM::XMM3, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0xE1, 0x99, 0x10])]
+#[case::memory(XMM::XMM11, XMM::XMM12, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x99, 0x99, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMM::XMM0, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0xF9, 0x99, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vfmadd132sd_xmm_vxmm_xmmm64(
+    #[case] xmm: XMM,
+    #[case] vxmm: XMM,
+    #[case] xmmm64: XMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vfmadd132sd::encode_xmm_vxmm_xmmm64(xmm, vxmm, xmmm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,41 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF1, 0xF5, 0x48, 0x5F, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0xF5, 0x41, 0x5F, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0x51, 0xB5, 0xCF, 0x5F, 0xC7])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF1, 0xDD, 0x48, 0x5F, 0x58, 0x01])]
+#[case::memory(ZMM::ZMM5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD1, 0xDD, 0x42, 0x5F, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(ZMM::ZMM24, Masking::Zero { opmask: Opmask::K3 }, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0x61, 0xCD, 0xCB, 0x5F, 0x45, 0x00])]
+#[case::memory(ZMM::ZMM7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF1, 0xFD, 0x48, 0x5F, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(ZMM::ZMM1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, &[0x62, 0xF1, 0xED, 0x59, 0x5F, 0x48, 0x01])]
+#[case::broadcast(ZMM::ZMM30, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-1600) } }, &[0x62, 0x41, 0x95, 0x50, 0x5F, 0xB4, 0x24, 0xC0, 0xF9, 0xFF, 0xFF])]
+fn test_vmaxpd_zmm_mask_vzmm_zmmm512b64(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b64: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmaxpd::encode_zmm_mask_vzmm_zmmm512b64(zmm, mask, vzmm, zmmm512b64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM1, ZMM::ZMM2, &[0x62, 0xF1, 0xF5, 0x18, 0x5F, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMM::ZMM31, &[0x62, 0x81, 0xF5, 0x11, 0x5F, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM9, ZMM::ZMM15, &[0x62, 0x51, 0xB5, 0x9F, 0x5F, 0xC7])]
+#[case::zmm(ZMM::ZMM3, Masking::None, ZMM::ZMM28, ZMM::ZMM4, &[0x62, 0xF1, 0x9D, 0x10, 0x5F, 0xDC])]
+fn test_vmaxpd_zmm_mask_vzmm_zmmr_sae(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmr: ZMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmaxpd::encode_zmm_mask_vzmm_zmmr_sae(zmm, mask, vzmm, zmmr) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,48 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC5, 0xF9, 0x6F, 0xC1])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x41, 0x79, 0x6F, 0xC7])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xF9, 0x6F, 0x10])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x79, 0x6F, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x79, 0x6F, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovdqa_xmm_xmmm128(#[case] xmm: XMM, #[case] xmmm128: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovdqa::encode_xmm_xmmm128(xmm, xmmm128) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM1 }, XMM::XMM0, &[0xC5, 0xF9, 0x7F, 0xC1])]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM15 }, XMM::XMM8, &[0xC4, 0x41, 0x79, 0x7F, 0xC7])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, &[0xC5, 0xF9, 0x7F, 0x10])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, &[0xC4, 0x41, 0x79, 0x7F, 0x5C, 0x24, 0x08])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, &[0xC4, 0xC1, 0x79, 0x7F, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovdqa_xmmm128_xmm(#[case] xmmm128: XMMOrMemory, #[case] xmm: XMM, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovdqa::encode_xmmm128_xmm(xmmm128, xmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMM::YMM0, YMMOrMemory::YMM { ymm: YMM::YMM1 }, &[0xC5, 0xFD, 0x6F, 0xC1])]
+#[case::ymm(YMM::YMM8, YMMOrMemory::YMM { ymm: YMM::YMM15 }, &[0xC4, 0x41, 0x7D, 0x6F, 0xC7])]
+#[case::memory(YMM::YMM2, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xFD, 0x6F, 0x10])]
+#[case::memory(YMM::YMM11, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x7D, 0x6F, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x7D, 0x6F, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovdqa_ymm_ymmm256(#[case] ymm: YMM, #[case] ymmm256: YMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovdqa::encode_ymm_ymmm256(ymm, ymmm256) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMMOrMemory::YMM { ymm: YMM::YMM1 }, YMM::YMM0, &[0xC5, 0xFD, 0x7F, 0xC1])]
+#[case::ymm(YMMOrMemory::YMM { ymm: YMM::YMM15 }, YMM::YMM8, &[0xC4, 0x41, 0x7D, 0x7F, 0xC7])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, YMM::YMM2, &[0xC5, 0xFD, 0x7F, 0x10])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, YMM::YMM11, &[0xC4, 0x41, 0x7D, 0x7F, 0x5C, 0x24, 0x08])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, YMM::YMM7, &[0xC4, 0xC1, 0x7D, 0x7F, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovdqa_ymmm256_ymm(#[case] ymmm256: YMMOrMemory, #[case] ymm: YMM, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovdqa::encode_ymmm256_ymm(ymmm256, ymm) };
+    assert_eq!(instr.as_slice(), expected);
+}
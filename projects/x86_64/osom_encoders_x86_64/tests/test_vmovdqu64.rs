@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMMOrMemory::ZMM { zmm: ZMM::ZMM1 }, &[0x62, 0xF1, 0xFE, 0x48, 0x6F, 0xC1])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0xFE, 0x49, 0x6F, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM9 }, &[0x62, 0x51, 0xFE, 0xCF, 0x6F, 0xC1])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i32(128) } }, &[0x62, 0xF1, 0xFE, 0x48, 0x6F, 0x58, 0x02])]
+#[case::memory(ZMM::ZMM5, Masking::Zero { opmask: Opmask::K1 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(-64) } }, &[0x62, 0xD1, 0xFE, 0xC9, 0x6F, 0x6D, 0xFF])]
+#[case::memory(ZMM::ZMM24, Masking::Merge { opmask: Opmask::K3 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(100) } }, &[0x62, 0x61, 0xFE, 0x4B, 0x6F, 0x84, 0x24, 0x64, 0x00, 0x00, 0x00])]
+fn test_vmovdqu64_zmm_mask_zmmm512(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] zmmm512: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmovdqu64::encode_zmm_mask_zmmm512(zmm, mask, zmmm512) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Masking::None, ZMM::ZMM0, &[0x62, 0xF1, 0xFE, 0x48, 0x7F, 0x00])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(64) } }, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, &[0x62, 0xC1, 0xFE, 0x49, 0x7F, 0x4C, 0x24, 0x01])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i32(-4096) } }, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM31, &[0x62, 0x61, 0xFE, 0x4F, 0x7F, 0x7D, 0xC0])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(63) } }, Masking::None, ZMM::ZMM8, &[0x62, 0x71, 0xFE, 0x48, 0x7F, 0x87, 0x3F, 0x00, 0x00, 0x00])]
+fn test_vmovdqu64_zmmm512_mergemask_zmm(
+    #[case] zmmm512: ZMMOrMemory,
+    #[case] mergemask: Masking,
+    #[case] zmm: ZMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmovdqu64::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
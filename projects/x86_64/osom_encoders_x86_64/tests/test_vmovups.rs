@@ -0,0 +1,80 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC5, 0xF8, 0x10, 0xC1])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x41, 0x78, 0x10, 0xC7])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xF8, 0x10, 0x10])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x78, 0x10, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x78, 0x10, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovups_xmm_xmmm128(#[case] xmm: XMM, #[case] xmmm128: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovups::encode_xmm_xmmm128(xmm, xmmm128) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM1 }, XMM::XMM0, &[0xC5, 0xF8, 0x11, 0xC1])]
+#[case::xmm(XMMOrMemory::XMM { xmm: XMM::XMM15 }, XMM::XMM8, &[0xC4, 0x41, 0x78, 0x11, 0xC7])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, XMM::XMM2, &[0xC5, 0xF8, 0x11, 0x10])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, XMM::XMM11, &[0xC4, 0x41, 0x78, 0x11, 0x5C, 0x24, 0x08])]
+#[case::memory(XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, XMM::XMM7, &[0xC4, 0xC1, 0x78, 0x11, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovups_xmmm128_xmm(#[case] xmmm128: XMMOrMemory, #[case] xmm: XMM, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovups::encode_xmmm128_xmm(xmmm128, xmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMM::YMM0, YMMOrMemory::YMM { ymm: YMM::YMM1 }, &[0xC5, 0xFC, 0x10, 0xC1])]
+#[case::ymm(YMM::YMM8, YMMOrMemory::YMM { ymm: YMM::YMM15 }, &[0xC4, 0x41, 0x7C, 0x10, 0xC7])]
+#[case::memory(YMM::YMM2, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC5, 0xFC, 0x10, 0x10])]
+#[case::memory(YMM::YMM11, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x41, 0x7C, 0x10, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC1, 0x7C, 0x10, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovups_ymm_ymmm256(#[case] ymm: YMM, #[case] ymmm256: YMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovups::encode_ymm_ymmm256(ymm, ymmm256) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::ymm(YMMOrMemory::YMM { ymm: YMM::YMM1 }, YMM::YMM0, &[0xC5, 0xFC, 0x11, 0xC1])]
+#[case::ymm(YMMOrMemory::YMM { ymm: YMM::YMM15 }, YMM::YMM8, &[0xC4, 0x41, 0x7C, 0x11, 0xC7])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, YMM::YMM2, &[0xC5, 0xFC, 0x11, 0x10])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, YMM::YMM11, &[0xC4, 0x41, 0x7C, 0x11, 0x5C, 0x24, 0x08])]
+#[case::memory(YMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, YMM::YMM7, &[0xC4, 0xC1, 0x7C, 0x11, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vmovups_ymmm256_ymm(#[case] ymmm256: YMMOrMemory, #[case] ymm: YMM, #[case] expected: &[u8]) {
+    let instr = unsafe { vmovups::encode_ymmm256_ymm(ymmm256, ymm) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMMOrMemory::ZMM { zmm: ZMM::ZMM1 }, &[0x62, 0xF1, 0x7C, 0x48, 0x10, 0xC1])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0x7C, 0x49, 0x10, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM9 }, &[0x62, 0x51, 0x7C, 0xCF, 0x10, 0xC1])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i32(128) } }, &[0x62, 0xF1, 0x7C, 0x48, 0x10, 0x58, 0x02])]
+#[case::memory(ZMM::ZMM5, Masking::Zero { opmask: Opmask::K1 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(-64) } }, &[0x62, 0xD1, 0x7C, 0xC9, 0x10, 0x6D, 0xFF])]
+#[case::memory(ZMM::ZMM24, Masking::Merge { opmask: Opmask::K3 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(100) } }, &[0x62, 0x61, 0x7C, 0x4B, 0x10, 0x84, 0x24, 0x64, 0x00, 0x00, 0x00])]
+fn test_vmovups_zmm_mask_zmmm512(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] zmmm512: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmovups::encode_zmm_mask_zmmm512(zmm, mask, zmmm512) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, Masking::None, ZMM::ZMM0, &[0x62, 0xF1, 0x7C, 0x48, 0x11, 0x00])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(64) } }, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, &[0x62, 0xC1, 0x7C, 0x49, 0x11, 0x4C, 0x24, 0x01])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::from_i32(-4096) } }, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM31, &[0x62, 0x61, 0x7C, 0x4F, 0x11, 0x7D, 0xC0])]
+#[case::memory(ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::from_i8(63) } }, Masking::None, ZMM::ZMM8, &[0x62, 0x71, 0x7C, 0x48, 0x11, 0x87, 0x3F, 0x00, 0x00, 0x00])]
+fn test_vmovups_zmmm512_mergemask_zmm(
+    #[case] zmmm512: ZMMOrMemory,
+    #[case] mergemask: Masking,
+    #[case] zmm: ZMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vmovups::encode_zmmm512_mergemask_zmm(zmmm512, mergemask, zmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
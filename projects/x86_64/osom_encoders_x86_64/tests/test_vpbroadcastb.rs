@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC4, 0xE2, 0x79, 0x78, 0xC1])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0x79, 0x78, 0xC7])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x79, 0x78, 0x10])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x79, 0x78, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x79, 0x78, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vpbroadcastb_xmm_xmmm8(#[case] xmm: XMM, #[case] xmmm8: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vpbroadcastb::encode_xmm_xmmm8(xmm, xmmm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(YMM::YMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC4, 0xE2, 0x7D, 0x78, 0xC1])]
+#[case::xmm(YMM::YMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0x7D, 0x78, 0xC7])]
+#[case::memory(YMM::YMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x7D, 0x78, 0x10])]
+#[case::memory(YMM::YMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x7D, 0x78, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x7D, 0x78, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vpbroadcastb_ymm_xmmm8(#[case] ymm: YMM, #[case] xmmm8: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vpbroadcastb::encode_ymm_xmmm8(ymm, xmmm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
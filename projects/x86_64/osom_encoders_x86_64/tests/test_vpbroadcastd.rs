@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::xmm(XMM::XMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC4, 0xE2, 0x79, 0x58, 0xC1])]
+#[case::xmm(XMM::XMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0x79, 0x58, 0xC7])]
+#[case::memory(XMM::XMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x79, 0x58, 0x10])]
+#[case::memory(XMM::XMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x79, 0x58, 0x5C, 0x24, 0x08])]
+#[case::memory(XMM::XMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x79, 0x58, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vpbroadcastd_xmm_xmmm32(#[case] xmm: XMM, #[case] xmmm32: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vpbroadcastd::encode_xmm_xmmm32(xmm, xmmm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::xmm(YMM::YMM0, XMMOrMemory::XMM { xmm: XMM::XMM1 }, &[0xC4, 0xE2, 0x7D, 0x58, 0xC1])]
+#[case::xmm(YMM::YMM8, XMMOrMemory::XMM { xmm: XMM::XMM15 }, &[0xC4, 0x42, 0x7D, 0x58, 0xC7])]
+#[case::memory(YMM::YMM2, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, &[0xC4, 0xE2, 0x7D, 0x58, 0x10])]
+#[case::memory(YMM::YMM11, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i8(8) } }, &[0xC4, 0x42, 0x7D, 0x58, 0x5C, 0x24, 0x08])]
+#[case::memory(YMM::YMM7, XMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i32(-300) } }, &[0xC4, 0xC2, 0x7D, 0x58, 0xBD, 0xD4, 0xFE, 0xFF, 0xFF])]
+fn test_vpbroadcastd_ymm_xmmm32(#[case] ymm: YMM, #[case] xmmm32: XMMOrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { vpbroadcastd::encode_ymm_xmmm32(ymm, xmmm32) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,25 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF1, 0x75, 0x48, 0x76, 0xC2])]
+#[case::masked(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x91, 0x75, 0x41, 0x76, 0xCF])]
+#[case::masked(Opmask::K0, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0xD1, 0x35, 0x4F, 0x76, 0xC7])]
+#[case::memory(Opmask::K3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF1, 0x5D, 0x48, 0x76, 0x58, 0x01])]
+#[case::memory(Opmask::K5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD1, 0x5D, 0x42, 0x76, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(Opmask::K6, Masking::None, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0xF1, 0x4D, 0x48, 0x76, 0x75, 0x00])]
+#[case::memory(Opmask::K7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF1, 0x7D, 0x48, 0x76, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } }, &[0x62, 0xF1, 0x6D, 0x59, 0x76, 0x48, 0x01])]
+#[case::broadcast(Opmask::K2, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-800) } }, &[0x62, 0xD1, 0x15, 0x50, 0x76, 0x94, 0x24, 0xE0, 0xFC, 0xFF, 0xFF])]
+fn test_vpcmpeqd_k_mergemask_vzmm_zmmm512b32(
+    #[case] k: Opmask,
+    #[case] mergemask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b32: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpcmpeqd::encode_k_mergemask_vzmm_zmmm512b32(k, mergemask, vzmm, zmmm512b32) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,25 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF2, 0xF5, 0x48, 0x29, 0xC2])]
+#[case::masked(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x92, 0xF5, 0x41, 0x29, 0xCF])]
+#[case::masked(Opmask::K0, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0xD2, 0xB5, 0x4F, 0x29, 0xC7])]
+#[case::memory(Opmask::K3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF2, 0xDD, 0x48, 0x29, 0x58, 0x01])]
+#[case::memory(Opmask::K5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD2, 0xDD, 0x42, 0x29, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(Opmask::K6, Masking::None, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0xF2, 0xCD, 0x48, 0x29, 0x75, 0x00])]
+#[case::memory(Opmask::K7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF2, 0xFD, 0x48, 0x29, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, &[0x62, 0xF2, 0xED, 0x59, 0x29, 0x48, 0x01])]
+#[case::broadcast(Opmask::K2, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-1600) } }, &[0x62, 0xD2, 0x95, 0x50, 0x29, 0x94, 0x24, 0xC0, 0xF9, 0xFF, 0xFF])]
+fn test_vpcmpeqq_k_mergemask_vzmm_zmmm512b64(
+    #[case] k: Opmask,
+    #[case] mergemask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b64: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpcmpeqq::encode_k_mergemask_vzmm_zmmm512b64(k, mergemask, vzmm, zmmm512b64) };
+    assert_eq!(instr.as_slice(), expected);
+}
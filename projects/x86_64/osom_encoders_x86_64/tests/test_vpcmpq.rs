@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::k(Opmask::K0, Masking::None, ZMM::ZMM1, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, Immediate8::from_u8(1), &[0x62, 0xF3, 0xF5, 0x48, 0x1F, 0xC2, 0x01])]
+#[case::masked(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM17, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, Immediate8::from_u8(1), &[0x62, 0x93, 0xF5, 0x41, 0x1F, 0xCF, 0x01])]
+#[case::masked(Opmask::K0, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM9, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, Immediate8::from_u8(1), &[0x62, 0xD3, 0xB5, 0x4F, 0x1F, 0xC7, 0x01])]
+#[case::memory(Opmask::K3, Masking::None, ZMM::ZMM4, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, Immediate8::from_u8(1), &[0x62, 0xF3, 0xDD, 0x48, 0x1F, 0x58, 0x01, 0x01])]
+#[case::memory(Opmask::K5, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM20, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, Immediate8::from_u8(1), &[0x62, 0xD3, 0xDD, 0x42, 0x1F, 0xAD, 0x41, 0x00, 0x00, 0x00, 0x01])]
+#[case::memory(Opmask::K6, Masking::None, ZMM::ZMM6, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, Immediate8::from_u8(1), &[0x62, 0xF3, 0xCD, 0x48, 0x1F, 0x75, 0x00, 0x01])]
+#[case::memory(Opmask::K7, Masking::None, ZMM::ZMM0, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, Immediate8::from_u8(1), &[0x62, 0xF3, 0xFD, 0x48, 0x1F, 0x7C, 0x24, 0xFE, 0x01])]
+#[case::broadcast(Opmask::K1, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM2, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(8) } }, Immediate8::from_u8(1), &[0x62, 0xF3, 0xED, 0x59, 0x1F, 0x48, 0x01, 0x01])]
+#[case::broadcast(Opmask::K2, Masking::None, ZMM::ZMM29, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-1600) } }, Immediate8::from_u8(1), &[0x62, 0xD3, 0x95, 0x50, 0x1F, 0x94, 0x24, 0xC0, 0xF9, 0xFF, 0xFF, 0x01])]
+fn test_vpcmpq_k_mergemask_vzmm_zmmm512b64_imm8(
+    #[case] k: Opmask,
+    #[case] mergemask: Masking,
+    #[case] vzmm: ZMM,
+    #[case] zmmm512b64: ZMMOrMemory,
+    #[case] imm8: Immediate8,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpcmpq::encode_k_mergemask_vzmm_zmmm512b64_imm8(k, mergemask, vzmm, zmmm512b64, imm8) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,49 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::based(XMM::XMM1, VSIBMemory::BasedAndScaled { base: GPR::RAX, index: XMM::XMM2.into(), scale: Scale::Scale4, offset: Offset::None }, XMM::XMM3, &[0xC4, 0xE2, 0x61, 0x90, 0x0C, 0x90])]
+#[case::extended(XMM::XMM9, VSIBMemory::BasedAndScaled { base: GPR::R13, index: XMM::XMM10.into(), scale: Scale::Scale8, offset: Offset::from_i8(8) }, XMM::XMM11, &[0xC4, 0x02, 0x21, 0x90, 0x4C, 0xD5, 0x08])]
+#[case::based(XMM::XMM0, VSIBMemory::BasedAndScaled { base: GPR::RSP, index: XMM::XMM5.into(), scale: Scale::Scale1, offset: Offset::from_i32(-300) }, XMM::XMM7, &[0xC4, 0xE2, 0x41, 0x90, 0x84, 0x2C, 0xD4, 0xFE, 0xFF, 0xFF])]
+#[case::scaled(XMM::XMM4, VSIBMemory::Scaled { index: XMM::XMM6.into(), scale: Scale::Scale2, offset: Offset::from_i32(64) }, XMM::XMM5, &[0xC4, 0xE2, 0x51, 0x90, 0x24, 0x75, 0x40, 0x00, 0x00, 0x00])]
+fn test_vpgatherdd_xmm_vm32x_vxmm(
+    #[case] xmm: XMM,
+    #[case] vm32x: VSIBMemory,
+    #[case] vxmm: XMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpgatherdd::encode_xmm_vm32x_vxmm(xmm, vm32x, vxmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::based(YMM::YMM1, VSIBMemory::BasedAndScaled { base: GPR::RAX, index: YMM::YMM2.into(), scale: Scale::Scale4, offset: Offset::None }, YMM::YMM3, &[0xC4, 0xE2, 0x65, 0x90, 0x0C, 0x90])]
+#[case::extended(YMM::YMM9, VSIBMemory::BasedAndScaled { base: GPR::R13, index: YMM::YMM10.into(), scale: Scale::Scale8, offset: Offset::from_i8(8) }, YMM::YMM11, &[0xC4, 0x02, 0x25, 0x90, 0x4C, 0xD5, 0x08])]
+#[case::based(YMM::YMM0, VSIBMemory::BasedAndScaled { base: GPR::RSP, index: YMM::YMM5.into(), scale: Scale::Scale1, offset: Offset::from_i32(-300) }, YMM::YMM7, &[0xC4, 0xE2, 0x45, 0x90, 0x84, 0x2C, 0xD4, 0xFE, 0xFF, 0xFF])]
+#[case::scaled(YMM::YMM4, VSIBMemory::Scaled { index: YMM::YMM6.into(), scale: Scale::Scale2, offset: Offset::from_i32(64) }, YMM::YMM5, &[0xC4, 0xE2, 0x55, 0x90, 0x24, 0x75, 0x40, 0x00, 0x00, 0x00])]
+fn test_vpgatherdd_ymm_vm32y_vymm(
+    #[case] ymm: YMM,
+    #[case] vm32y: VSIBMemory,
+    #[case] vymm: YMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpgatherdd::encode_ymm_vm32y_vymm(ymm, vm32y, vymm) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::masked(ZMM::ZMM1, Masking::Merge { opmask: Opmask::K1 }, VSIBMemory::BasedAndScaled { base: GPR::RAX, index: ZMM::ZMM2.into(), scale: Scale::Scale4, offset: Offset::from_i32(256) }, &[0x62, 0xF2, 0x7D, 0x49, 0x90, 0x4C, 0x90, 0x40])]
+#[case::extended(ZMM::ZMM17, Masking::Merge { opmask: Opmask::K7 }, VSIBMemory::BasedAndScaled { base: GPR::R13, index: ZMM::ZMM18.into(), scale: Scale::Scale8, offset: Offset::None }, &[0x62, 0xC2, 0x7D, 0x47, 0x90, 0x4C, 0xD5, 0x00])]
+#[case::masked(ZMM::ZMM0, Masking::Merge { opmask: Opmask::K2 }, VSIBMemory::BasedAndScaled { base: GPR::RBP, index: ZMM::ZMM31.into(), scale: Scale::Scale1, offset: Offset::from_i8(-8) }, &[0x62, 0xB2, 0x7D, 0x42, 0x90, 0x44, 0x3D, 0xFE])]
+#[case::scaled(ZMM::ZMM3, Masking::Merge { opmask: Opmask::K1 }, VSIBMemory::Scaled { index: ZMM::ZMM4.into(), scale: Scale::Scale4, offset: Offset::from_i32(16) }, &[0x62, 0xF2, 0x7D, 0x49, 0x90, 0x1C, 0xA5, 0x10, 0x00, 0x00, 0x00])]
+fn test_vpgatherdd_zmm_mergemask_vm32z(
+    #[case] zmm: ZMM,
+    #[case] mergemask: Masking,
+    #[case] vm32z: VSIBMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vpgatherdd::encode_zmm_mergemask_vm32z(zmm, mergemask, vm32z) };
+    assert_eq!(instr.as_slice(), expected);
+}
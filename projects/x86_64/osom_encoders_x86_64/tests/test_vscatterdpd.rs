@@ -0,0 +1,19 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::masked(VSIBMemory::BasedAndScaled { base: GPR::RAX, index: YMM::YMM2.into(), scale: Scale::Scale4, offset: Offset::from_i32(512) }, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM1, &[0x62, 0xF2, 0xFD, 0x49, 0xA2, 0x4C, 0x90, 0x40])]
+#[case::extended(VSIBMemory::BasedAndScaled { base: GPR::R13, index: YMM::YMM18.into(), scale: Scale::Scale8, offset: Offset::None }, Masking::Merge { opmask: Opmask::K7 }, ZMM::ZMM17, &[0x62, 0xC2, 0xFD, 0x47, 0xA2, 0x4C, 0xD5, 0x00])]
+#[case::masked(VSIBMemory::BasedAndScaled { base: GPR::RBP, index: YMM::YMM31.into(), scale: Scale::Scale1, offset: Offset::from_i8(-8) }, Masking::Merge { opmask: Opmask::K2 }, ZMM::ZMM0, &[0x62, 0xB2, 0xFD, 0x42, 0xA2, 0x44, 0x3D, 0xFF])]
+#[case::scaled(VSIBMemory::Scaled { index: YMM::YMM4.into(), scale: Scale::Scale4, offset: Offset::from_i32(16) }, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM3, &[0x62, 0xF2, 0xFD, 0x49, 0xA2, 0x1C, 0xA5, 0x10, 0x00, 0x00, 0x00])]
+fn test_vscatterdpd_vm32y_mergemask_zmm(
+    #[case] vm32y: VSIBMemory,
+    #[case] mergemask: Masking,
+    #[case] zmm: ZMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vscatterdpd::encode_vm32y_mergemask_zmm(vm32y, mergemask, zmm) };
+    assert_eq!(instr.as_slice(), expected);
+}
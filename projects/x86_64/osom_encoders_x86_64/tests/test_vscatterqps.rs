@@ -0,0 +1,19 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::masked(VSIBMemory::BasedAndScaled { base: GPR::RAX, index: ZMM::ZMM2.into(), scale: Scale::Scale4, offset: Offset::from_i32(256) }, Masking::Merge { opmask: Opmask::K1 }, YMM::YMM1, &[0x62, 0xF2, 0x7D, 0x49, 0xA3, 0x4C, 0x90, 0x40])]
+#[case::extended(VSIBMemory::BasedAndScaled { base: GPR::R13, index: ZMM::ZMM18.into(), scale: Scale::Scale8, offset: Offset::None }, Masking::Merge { opmask: Opmask::K7 }, YMM::YMM17, &[0x62, 0xC2, 0x7D, 0x47, 0xA3, 0x4C, 0xD5, 0x00])]
+#[case::masked(VSIBMemory::BasedAndScaled { base: GPR::RBP, index: ZMM::ZMM31.into(), scale: Scale::Scale1, offset: Offset::from_i8(-8) }, Masking::Merge { opmask: Opmask::K2 }, YMM::YMM0, &[0x62, 0xB2, 0x7D, 0x42, 0xA3, 0x44, 0x3D, 0xFE])]
+#[case::scaled(VSIBMemory::Scaled { index: ZMM::ZMM4.into(), scale: Scale::Scale4, offset: Offset::from_i32(16) }, Masking::Merge { opmask: Opmask::K1 }, YMM::YMM3, &[0x62, 0xF2, 0x7D, 0x49, 0xA3, 0x1C, 0xA5, 0x10, 0x00, 0x00, 0x00])]
+fn test_vscatterqps_vm64z_mergemask_ymm(
+    #[case] vm64z: VSIBMemory,
+    #[case] mergemask: Masking,
+    #[case] ymm: YMM,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vscatterqps::encode_vm64z_mergemask_ymm(vm64z, mergemask, ymm) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,40 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMMOrMemory::ZMM { zmm: ZMM::ZMM2 }, &[0x62, 0xF1, 0x7C, 0x48, 0x51, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM31 }, &[0x62, 0x81, 0x7C, 0x49, 0x51, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMMOrMemory::ZMM { zmm: ZMM::ZMM15 }, &[0x62, 0x51, 0x7C, 0xCF, 0x51, 0xC7])]
+#[case::memory(ZMM::ZMM3, Masking::None, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(64) } }, &[0x62, 0xF1, 0x7C, 0x48, 0x51, 0x58, 0x01])]
+#[case::memory(ZMM::ZMM5, Masking::Merge { opmask: Opmask::K2 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::R13, offset: Offset::from_i8(65) } }, &[0x62, 0xD1, 0x7C, 0x4A, 0x51, 0xAD, 0x41, 0x00, 0x00, 0x00])]
+#[case::memory(ZMM::ZMM24, Masking::Zero { opmask: Opmask::K3 }, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RBP, offset: Offset::None } }, &[0x62, 0x61, 0x7C, 0xCB, 0x51, 0x45, 0x00])]
+#[case::memory(ZMM::ZMM7, Masking::None, ZMMOrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(-128) } }, &[0x62, 0xF1, 0x7C, 0x48, 0x51, 0x7C, 0x24, 0xFE])]
+#[case::broadcast(ZMM::ZMM1, Masking::Merge { opmask: Opmask::K1 }, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::RAX, offset: Offset::from_i8(4) } }, &[0x62, 0xF1, 0x7C, 0x59, 0x51, 0x48, 0x01])]
+#[case::broadcast(ZMM::ZMM30, Masking::None, ZMMOrMemory::Broadcast { memory: Memory::Based { base: GPR::R12, offset: Offset::from_i32(-800) } }, &[0x62, 0x41, 0x7C, 0x58, 0x51, 0xB4, 0x24, 0xE0, 0xFC, 0xFF, 0xFF])]
+fn test_vsqrtps_zmm_mask_zmmm512b32(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] zmmm512b32: ZMMOrMemory,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vsqrtps::encode_zmm_mask_zmmm512b32(zmm, mask, zmmm512b32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::zmm(ZMM::ZMM0, Masking::None, ZMM::ZMM2, Rounding::Nearest, &[0x62, 0xF1, 0x7C, 0x18, 0x51, 0xC2])]
+#[case::masked(ZMM::ZMM16, Masking::Merge { opmask: Opmask::K1 }, ZMM::ZMM31, Rounding::Down, &[0x62, 0x81, 0x7C, 0x39, 0x51, 0xC7])]
+#[case::zeroing(ZMM::ZMM8, Masking::Zero { opmask: Opmask::K7 }, ZMM::ZMM15, Rounding::Up, &[0x62, 0x51, 0x7C, 0xDF, 0x51, 0xC7])]
+#[case::zmm(ZMM::ZMM3, Masking::None, ZMM::ZMM4, Rounding::Zero, &[0x62, 0xF1, 0x7C, 0x78, 0x51, 0xDC])]
+fn test_vsqrtps_zmm_mask_zmmr_er(
+    #[case] zmm: ZMM,
+    #[case] mask: Masking,
+    #[case] zmmr: ZMM,
+    #[case] er: Rounding,
+    #[case] expected: &[u8],
+) {
+    let instr = unsafe { vsqrtps::encode_zmm_mask_zmmr_er(zmm, mask, zmmr, er) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,7 @@
+use osom_encoders_x86_64::encoders::*;
+
+#[test]
+fn test_encode_vzeroupper() {
+    let instr = unsafe { vzeroupper::encode() };
+    assert_eq!(instr.as_slice(), &[0xC5, 0xF8, 0x77]);
+}
@@ -0,0 +1,40 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::AL, &[0x0F, 0xC0, 0x00])]
+fn test_xadd_rm8_reg8(#[case] rm8: GPROrMemory, #[case] reg8: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xadd::encode_rm8_reg8(rm8, reg8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::R10W, &[0x66, 0x44, 0x0F, 0xC1, 0x10])]
+fn test_xadd_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xadd::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::EAX }, GPR::EBX, &[0x0F, 0xC1, 0xD8])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBX, offset: Offset::from_i8(4) } }, GPR::ECX, &[0x0F, 0xC1, 0x4B, 0x04])]
+fn test_xadd_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xadd::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDX, offset: Offset::None } }, GPR::RAX, &[0x48, 0x0F, 0xC1, 0x02])]
+fn test_xadd_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xadd::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDX, offset: Offset::None } }, GPR::RAX, &[0xF0, 0x48, 0x0F, 0xC1, 0x02])]
+fn test_xadd_lock_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xadd::encode_lock_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
@@ -0,0 +1,48 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::*;
+use osom_encoders_x86_64::models::*;
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::AH }, GPR::BL, &[0x86, 0xDC])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::CL, &[0x86, 0x08])]
+fn test_xchg_rm8_reg8(#[case] rm8: GPROrMemory, #[case] reg8: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_rm8_reg8(rm8, reg8) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RBX, offset: Offset::None } }, GPR::DX, &[0x66, 0x87, 0x13])]
+fn test_xchg_rm16_reg16(#[case] rm16: GPROrMemory, #[case] reg16: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_rm16_reg16(rm16, reg16) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::gpr(GPROrMemory::GPR { gpr: GPR::ECX }, GPR::EAX, &[0x87, 0xC1])]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::R8, offset: Offset::None } }, GPR::EAX, &[0x41, 0x87, 0x00])]
+fn test_xchg_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RSP, offset: Offset::from_i8(8) } }, GPR::R15, &[0x4C, 0x87, 0x7C, 0x24, 0x08])]
+fn test_xchg_rm64_reg64(#[case] rm64: GPROrMemory, #[case] reg64: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_rm64_reg64(rm64, reg64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPR::R9, GPROrMemory::Memory { memory: Memory::Based { base: GPR::RDI, offset: Offset::None } }, &[0x4C, 0x87, 0x0F])]
+fn test_xchg_reg64_rm64(#[case] reg64: GPR, #[case] rm64: GPROrMemory, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_reg64_rm64(reg64, rm64) };
+    assert_eq!(instr.as_slice(), expected);
+}
+
+#[rstest]
+#[case::memory(GPROrMemory::Memory { memory: Memory::Based { base: GPR::RAX, offset: Offset::None } }, GPR::ECX, &[0xF0, 0x87, 0x08])]
+fn test_xchg_lock_rm32_reg32(#[case] rm32: GPROrMemory, #[case] reg32: GPR, #[case] expected: &[u8]) {
+    let instr = unsafe { xchg::encode_lock_rm32_reg32(rm32, reg32) };
+    assert_eq!(instr.as_slice(), expected);
+}
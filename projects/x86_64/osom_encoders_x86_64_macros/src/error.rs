@@ -0,0 +1,23 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+
+/// Represents a compile error reported by the macro.
+#[derive(Debug)]
+pub(crate) struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    pub fn to_compile_error(&self) -> TokenStream {
+        let message = &self.message;
+        quote_spanned! { self.span => ::core::compile_error!(#message) }
+    }
+}
@@ -0,0 +1,353 @@
+use osom_encoders_x86_64::decoders::{Instruction, OperandKind};
+use osom_encoders_x86_64::models::{GPR, Masking, Memory, Offset, Operand, Scale, VSIBIndex, VSIBMemory};
+use osom_encoders_x86_64::parsing::{parse_intel, parse_intel_instructions};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{quote, quote_spanned};
+
+use crate::error::Error;
+use crate::interpolation::{Interpolation, InterpolationKind};
+use crate::statement::{Statement, split_statements};
+
+/// Represents a part of the output, see `osom_encoders_x86_64::macro_support::Chunk`.
+enum Chunk {
+    Bytes(Vec<u8>),
+    Instruction(TokenStream),
+}
+
+pub(crate) fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let mut chunks: Vec<Chunk> = Vec::new();
+    for statement in split_statements(input)? {
+        if statement.interpolations.is_empty() {
+            let encoded = parse_intel(&statement.source).map_err(|err| statement.error(&err))?;
+            let bytes = encoded[0].as_slice();
+            if let Some(Chunk::Bytes(last)) = chunks.last_mut() {
+                last.extend_from_slice(bytes);
+            } else {
+                chunks.push(Chunk::Bytes(bytes.to_vec()));
+            }
+        } else {
+            let instructions = parse_intel_instructions(&statement.source).map_err(|err| statement.error(&err))?;
+            chunks.push(Chunk::Instruction(instruction_tokens(&statement, instructions[0])?));
+        }
+    }
+
+    match chunks.as_slice() {
+        [] => Ok(quote! { [0u8; 0] }),
+        [Chunk::Bytes(bytes)] => {
+            let bytes = bytes.iter().map(|byte| Literal::u8_suffixed(*byte));
+            Ok(quote! { [#(#bytes),*] })
+        }
+        _ => {
+            let chunks = chunks.iter().map(|chunk| match chunk {
+                Chunk::Bytes(bytes) => {
+                    let bytes = bytes.iter().map(|byte| Literal::u8_suffixed(*byte));
+                    quote! { ::osom_encoders_x86_64::macro_support::Chunk::Bytes(&[#(#bytes),*]) }
+                }
+                Chunk::Instruction(instruction) => {
+                    quote! { ::osom_encoders_x86_64::macro_support::Chunk::Instruction(#instruction) }
+                }
+            });
+            Ok(quote! {
+                {
+                    const CHUNKS: &[::osom_encoders_x86_64::macro_support::Chunk] = &[#(#chunks),*];
+                    const LEN: usize = ::osom_encoders_x86_64::macro_support::encoded_len(CHUNKS);
+                    const BYTES: [u8; LEN] = ::osom_encoders_x86_64::macro_support::encode::<LEN>(CHUNKS);
+                    BYTES
+                }
+            })
+        }
+    }
+}
+
+/// Generates the expression constructing `instruction`, with the interpolated
+/// operands replaced by the corresponding expressions.
+fn instruction_tokens(statement: &Statement, instruction: Instruction) -> Result<TokenStream, Error> {
+    let form = instruction.form();
+    // The masking is not separated by `,`, and so it is not counted by the statement.
+    let operand_indexes = form
+        .operands
+        .iter()
+        .enumerate()
+        .filter(|(_, kind)| !matches!(kind, OperandKind::Mask | OperandKind::MergeMask))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    for interpolation in &statement.interpolations {
+        let kind = form.operands[operand_indexes[interpolation.operand]];
+        if !interpolation.kind.matches(kind) {
+            return Err(Error::new(
+                interpolation.span,
+                format!(
+                    "interpolated {} does not match {kind:?} operand of the picked `{}` encoding",
+                    interpolation.kind.name(),
+                    form.mnemonic
+                ),
+            ));
+        }
+    }
+
+    let operands = instruction.operands();
+    let fields = form
+        .operands
+        .iter()
+        .enumerate()
+        .filter(|(_, kind)| !kind.is_implicit())
+        .map(|(idx, kind)| {
+            let field = Ident::new(&format!("{kind:?}").to_lowercase(), Span::call_site());
+            let value = match statement
+                .interpolations
+                .iter()
+                .find(|x| operand_indexes[x.operand] == idx)
+            {
+                Some(interpolation) => interpolation_tokens(*kind, interpolation),
+                None => operand_tokens(*kind, operands.as_slice()[idx]),
+            };
+            quote! { #field: #value }
+        })
+        .collect::<Vec<_>>();
+
+    let name = Ident::new(form.name, Span::call_site());
+    if fields.is_empty() {
+        Ok(quote! { ::osom_encoders_x86_64::decoders::Instruction::#name })
+    } else {
+        Ok(quote! { ::osom_encoders_x86_64::decoders::Instruction::#name { #(#fields),* } })
+    }
+}
+
+fn interpolation_tokens(kind: OperandKind, interpolation: &Interpolation) -> TokenStream {
+    let expression = &interpolation.expression;
+    let models = quote! { ::osom_encoders_x86_64::models };
+    let span = interpolation.span;
+    match interpolation.kind {
+        InterpolationKind::Reg8 | InterpolationKind::Reg16 | InterpolationKind::Reg32 | InterpolationKind::Reg64 => {
+            if is_gpr_or_memory(kind) {
+                quote_spanned! { span => #models::GPROrMemory::GPR { gpr: #expression } }
+            } else {
+                quote_spanned! { span => (#expression) }
+            }
+        }
+        InterpolationKind::Imm8 => quote_spanned! { span => #models::Immediate8::from_i8(#expression) },
+        InterpolationKind::Imm16 => quote_spanned! { span => #models::Immediate16::from_i16(#expression) },
+        InterpolationKind::Imm32 => quote_spanned! { span => #models::Immediate32::from_i32(#expression) },
+        InterpolationKind::Imm64 => quote_spanned! { span => #models::Immediate64::from_i64(#expression) },
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn operand_tokens(kind: OperandKind, operand: Operand) -> TokenStream {
+    let models = quote! { ::osom_encoders_x86_64::models };
+    match operand {
+        Operand::GPR { gpr } => {
+            let gpr = Ident::new(&format!("{gpr:?}"), Span::call_site());
+            if is_gpr_or_memory(kind) {
+                quote! { #models::GPROrMemory::GPR { gpr: #models::GPR::#gpr } }
+            } else {
+                quote! { #models::GPR::#gpr }
+            }
+        }
+        Operand::Memory { memory, .. } => {
+            let memory = memory_tokens(memory);
+            if is_gpr_or_memory(kind) {
+                quote! { #models::GPROrMemory::Memory { memory: #memory } }
+            } else if is_xmm_or_memory(kind) {
+                quote! { #models::XMMOrMemory::Memory { memory: #memory } }
+            } else if kind == OperandKind::YmmM256 {
+                quote! { #models::YMMOrMemory::Memory { memory: #memory } }
+            } else if is_zmm_or_memory(kind) {
+                quote! { #models::ZMMOrMemory::Memory { memory: #memory } }
+            } else if kind == OperandKind::KM16 {
+                quote! { #models::OpmaskOrMemory::Memory { memory: #memory } }
+            } else {
+                memory
+            }
+        }
+        Operand::Broadcast { memory, .. } => {
+            let memory = memory_tokens(memory);
+            quote! { #models::ZMMOrMemory::Broadcast { memory: #memory } }
+        }
+        Operand::VSIBMemory { memory, .. } => vsib_memory_tokens(memory),
+        Operand::XMM { xmm } => {
+            let xmm = Ident::new(&format!("{xmm:?}"), Span::call_site());
+            if is_xmm_or_memory(kind) {
+                quote! { #models::XMMOrMemory::XMM { xmm: #models::XMM::#xmm } }
+            } else {
+                quote! { #models::XMM::#xmm }
+            }
+        }
+        Operand::YMM { ymm } => {
+            let ymm = Ident::new(&format!("{ymm:?}"), Span::call_site());
+            if kind == OperandKind::YmmM256 {
+                quote! { #models::YMMOrMemory::YMM { ymm: #models::YMM::#ymm } }
+            } else {
+                quote! { #models::YMM::#ymm }
+            }
+        }
+        Operand::ZMM { zmm } => {
+            let zmm = Ident::new(&format!("{zmm:?}"), Span::call_site());
+            if is_zmm_or_memory(kind) {
+                quote! { #models::ZMMOrMemory::ZMM { zmm: #models::ZMM::#zmm } }
+            } else {
+                quote! { #models::ZMM::#zmm }
+            }
+        }
+        Operand::Opmask { opmask } => {
+            let opmask = Ident::new(&format!("{opmask:?}"), Span::call_site());
+            if kind == OperandKind::KM16 {
+                quote! { #models::OpmaskOrMemory::Opmask { opmask: #models::Opmask::#opmask } }
+            } else {
+                quote! { #models::Opmask::#opmask }
+            }
+        }
+        Operand::Masking { masking } => match masking {
+            Masking::None => quote! { #models::Masking::None },
+            Masking::Merge { opmask } => {
+                let opmask = Ident::new(&format!("{opmask:?}"), Span::call_site());
+                quote! { #models::Masking::Merge { opmask: #models::Opmask::#opmask } }
+            }
+            Masking::Zero { opmask } => {
+                let opmask = Ident::new(&format!("{opmask:?}"), Span::call_site());
+                quote! { #models::Masking::Zero { opmask: #models::Opmask::#opmask } }
+            }
+        },
+        Operand::Rounding { rounding } => {
+            let rounding = Ident::new(&format!("{rounding:?}"), Span::call_site());
+            quote! { #models::Rounding::#rounding }
+        }
+        Operand::Sae => unreachable!("Implicit operands are not passed to encoders."),
+        Operand::Immediate { value, .. } => match kind {
+            OperandKind::Imm8 => {
+                let value = Literal::u8_suffixed(value as u8);
+                quote! { #models::Immediate8::from_u8(#value) }
+            }
+            OperandKind::Imm16 => {
+                let value = Literal::u16_suffixed(value as u16);
+                quote! { #models::Immediate16::from_u16(#value) }
+            }
+            OperandKind::Imm32 => {
+                let value = Literal::u32_suffixed(value as u32);
+                quote! { #models::Immediate32::from_u32(#value) }
+            }
+            _ => {
+                let value = Literal::u64_suffixed(value as u64);
+                quote! { #models::Immediate64::from_u64(#value) }
+            }
+        },
+    }
+}
+
+fn memory_tokens(memory: Memory) -> TokenStream {
+    let models = quote! { ::osom_encoders_x86_64::models };
+    match memory {
+        Memory::Based { base, offset } => {
+            let (base, offset) = (gpr_tokens(base), offset_tokens(offset));
+            quote! { #models::Memory::Based { base: #base, offset: #offset } }
+        }
+        Memory::Scaled { index, scale, offset } => {
+            let (index, scale, offset) = (gpr_tokens(index), scale_tokens(scale), offset_tokens(offset));
+            quote! { #models::Memory::Scaled { index: #index, scale: #scale, offset: #offset } }
+        }
+        Memory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            let (base, index) = (gpr_tokens(base), gpr_tokens(index));
+            let (scale, offset) = (scale_tokens(scale), offset_tokens(offset));
+            quote! { #models::Memory::BasedAndScaled { base: #base, index: #index, scale: #scale, offset: #offset } }
+        }
+        Memory::RelativeToRIP { offset } => {
+            let offset = offset_tokens(offset);
+            quote! { #models::Memory::RelativeToRIP { offset: #offset } }
+        }
+    }
+}
+
+fn vsib_memory_tokens(memory: VSIBMemory) -> TokenStream {
+    let models = quote! { ::osom_encoders_x86_64::models };
+    match memory {
+        VSIBMemory::Scaled { index, scale, offset } => {
+            let (index, scale, offset) = (vsib_index_tokens(index), scale_tokens(scale), offset_tokens(offset));
+            quote! { #models::VSIBMemory::Scaled { index: #index, scale: #scale, offset: #offset } }
+        }
+        VSIBMemory::BasedAndScaled {
+            base,
+            index,
+            scale,
+            offset,
+        } => {
+            let (base, index) = (gpr_tokens(base), vsib_index_tokens(index));
+            let (scale, offset) = (scale_tokens(scale), offset_tokens(offset));
+            quote! { #models::VSIBMemory::BasedAndScaled { base: #base, index: #index, scale: #scale, offset: #offset } }
+        }
+    }
+}
+
+fn vsib_index_tokens(index: VSIBIndex) -> TokenStream {
+    let models = quote! { ::osom_encoders_x86_64::models };
+    match index {
+        VSIBIndex::XMM { xmm } => {
+            let xmm = Ident::new(&format!("{xmm:?}"), Span::call_site());
+            quote! { #models::VSIBIndex::XMM { xmm: #models::XMM::#xmm } }
+        }
+        VSIBIndex::YMM { ymm } => {
+            let ymm = Ident::new(&format!("{ymm:?}"), Span::call_site());
+            quote! { #models::VSIBIndex::YMM { ymm: #models::YMM::#ymm } }
+        }
+        VSIBIndex::ZMM { zmm } => {
+            let zmm = Ident::new(&format!("{zmm:?}"), Span::call_site());
+            quote! { #models::VSIBIndex::ZMM { zmm: #models::ZMM::#zmm } }
+        }
+    }
+}
+
+fn gpr_tokens(gpr: GPR) -> TokenStream {
+    let gpr = Ident::new(&format!("{gpr:?}"), Span::call_site());
+    quote! { ::osom_encoders_x86_64::models::GPR::#gpr }
+}
+
+fn scale_tokens(scale: Scale) -> TokenStream {
+    let scale = Ident::new(&format!("{scale:?}"), Span::call_site());
+    quote! { ::osom_encoders_x86_64::models::Scale::#scale }
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn offset_tokens(offset: Offset) -> TokenStream {
+    let models = quote! { ::osom_encoders_x86_64::models };
+    match offset {
+        Offset::None => quote! { #models::Offset::None },
+        Offset::Bit8(value) => {
+            let value = Literal::u8_suffixed(value.as_u8());
+            quote! { #models::Offset::from_u8(#value) }
+        }
+        Offset::Bit32(value) => {
+            let value = Literal::u32_suffixed(value.as_u32());
+            quote! { #models::Offset::from_u32(#value) }
+        }
+    }
+}
+
+const fn is_gpr_or_memory(kind: OperandKind) -> bool {
+    matches!(
+        kind,
+        OperandKind::RM8
+            | OperandKind::RM16
+            | OperandKind::RM32
+            | OperandKind::RM64
+            | OperandKind::R32M8
+            | OperandKind::R32M16
+    )
+}
+
+const fn is_xmm_or_memory(kind: OperandKind) -> bool {
+    matches!(
+        kind,
+        OperandKind::XmmM8 | OperandKind::XmmM16 | OperandKind::XmmM32 | OperandKind::XmmM64 | OperandKind::XmmM128
+    )
+}
+
+const fn is_zmm_or_memory(kind: OperandKind) -> bool {
+    matches!(
+        kind,
+        OperandKind::ZmmM512 | OperandKind::ZmmM512B32 | OperandKind::ZmmM512B64
+    )
+}
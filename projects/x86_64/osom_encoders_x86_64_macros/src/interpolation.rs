@@ -0,0 +1,129 @@
+use osom_encoders_x86_64::decoders::OperandKind;
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+
+use crate::error::Error;
+
+/// Represents the declared kind of an interpolated operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InterpolationKind {
+    Reg8,
+    Reg16,
+    Reg32,
+    Reg64,
+    Imm8,
+    Imm16,
+    Imm32,
+    Imm64,
+}
+
+const KINDS: [(&str, InterpolationKind); 8] = [
+    ("reg8", InterpolationKind::Reg8),
+    ("reg16", InterpolationKind::Reg16),
+    ("reg32", InterpolationKind::Reg32),
+    ("reg64", InterpolationKind::Reg64),
+    ("imm8", InterpolationKind::Imm8),
+    ("imm16", InterpolationKind::Imm16),
+    ("imm32", InterpolationKind::Imm32),
+    ("imm64", InterpolationKind::Imm64),
+];
+
+impl InterpolationKind {
+    fn from_name(name: &str) -> Option<Self> {
+        KINDS
+            .iter()
+            .find_map(|(kind_name, kind)| (*kind_name == name).then_some(*kind))
+    }
+
+    pub fn name(self) -> &'static str {
+        KINDS
+            .iter()
+            .find_map(|(name, kind)| (*kind == self).then_some(*name))
+            .unwrap()
+    }
+
+    /// Returns the text put in place of the interpolated operand for the parser.
+    /// It has to pick the same encoding as any other value of given kind, thus
+    /// accumulator registers and immediates fitting in smaller sizes are avoided.
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            Self::Reg8 => "cl",
+            Self::Reg16 => "cx",
+            Self::Reg32 => "ecx",
+            Self::Reg64 => "rcx",
+            Self::Imm8 => "0x12",
+            Self::Imm16 => "0x1234",
+            Self::Imm32 => "0x12345678",
+            Self::Imm64 => "0x123456789ABCDEF0",
+        }
+    }
+
+    /// Returns true if the operand of the picked encoding accepts this kind.
+    pub fn matches(self, kind: OperandKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::Reg8, OperandKind::Reg8 | OperandKind::RM8)
+                | (Self::Reg16, OperandKind::Reg16 | OperandKind::RM16)
+                | (
+                    Self::Reg32,
+                    OperandKind::Reg32 | OperandKind::RM32 | OperandKind::R32M8 | OperandKind::R32M16
+                )
+                | (Self::Reg64, OperandKind::Reg64 | OperandKind::RM64)
+                | (Self::Imm8, OperandKind::Imm8)
+                | (Self::Imm16, OperandKind::Imm16)
+                | (Self::Imm32, OperandKind::Imm32)
+                | (Self::Imm64, OperandKind::Imm64)
+        )
+    }
+}
+
+/// Represents `{expression: kind}` operand.
+#[derive(Debug, Clone)]
+pub(crate) struct Interpolation {
+    /// The index of the operand in the statement.
+    pub operand: usize,
+    pub kind: InterpolationKind,
+    pub expression: TokenStream,
+    pub span: Span,
+}
+
+impl Interpolation {
+    /// Parses the content of `{expression: kind}` group.
+    pub fn parse(content: TokenStream, operand: usize, span: Span) -> Result<Self, Error> {
+        let tokens: Vec<TokenTree> = content.into_iter().collect();
+        let missing_kind = || {
+            Error::new(
+                span,
+                "expected `{expression: kind}`, where kind is one of reg8, reg16, reg32, reg64, imm8, imm16, imm32 and imm64",
+            )
+        };
+
+        // The last single `:`, i.e. not a part of `::` path separator.
+        let colon = (0..tokens.len())
+            .rev()
+            .find(|idx| {
+                is_colon(&tokens[*idx], Spacing::Alone) && (*idx == 0 || !is_colon(&tokens[idx - 1], Spacing::Joint))
+            })
+            .ok_or_else(missing_kind)?;
+
+        let [TokenTree::Ident(name)] = &tokens[colon + 1..] else {
+            return Err(missing_kind());
+        };
+        let kind = InterpolationKind::from_name(&name.to_string())
+            .ok_or_else(|| Error::new(name.span(), format!("unknown operand kind `{name}`")))?;
+
+        if colon == 0 {
+            return Err(missing_kind());
+        }
+
+        Ok(Self {
+            operand,
+            kind,
+            expression: tokens[..colon].iter().cloned().collect(),
+            span,
+        })
+    }
+}
+
+fn is_colon(token: &TokenTree, spacing: Spacing) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':' && punct.spacing() == spacing)
+}
@@ -0,0 +1,64 @@
+//! This crate provides the [`x86_64_asm!`] macro, which assembles Intel-syntax
+//! instructions at compile time, by using `osom_encoders_x86_64` encoders.
+#![deny(warnings)]
+#![warn(clippy::all, clippy::pedantic)]
+
+mod error;
+mod expand;
+mod interpolation;
+mod statement;
+
+use proc_macro::TokenStream;
+
+/// Assembles Intel-syntax instructions at compile time into a `[u8; N]` array.
+///
+/// The syntax is the same as of `osom_encoders_x86_64::parsing::parse_intel`,
+/// except that instructions have to be separated by `;`. AVX-512 decorations, e.g.
+/// `{k1}`, `{z}`, `{1to16}` and `{rn-sae}`, are passed through. Whenever multiple
+/// encodings match the instruction, the shortest one is picked. Invalid
+/// instructions are reported as compile errors.
+///
+/// # Interpolation
+///
+/// Register and immediate operands can be interpolated with
+/// `{expression: kind}`, where `kind` is one of:
+///
+/// * `reg8`, `reg16`, `reg32` and `reg64` - the expression has to evaluate to
+///   `GPR` of given size.
+/// * `imm8`, `imm16`, `imm32` and `imm64` - the expression has to evaluate to
+///   `i8`, `i16`, `i32` and `i64` respectively.
+///
+/// The `kind` has to match the operand of the picked encoding, e.g. `add rax, {x: imm8}`
+/// picks `add::encode_rm64_imm8`, while `mov rax, {x: imm8}` is an error, since there is
+/// no `mov` variant taking an 8-bit immediate with a 64-bit register. The encoding
+/// depends on the `kind` only, e.g. `add {reg: reg64}, 1000` never picks the accumulator
+/// form, even if `reg` is `GPR::RAX`.
+///
+/// The expressions are evaluated in a `const` context. Thus they can only refer to
+/// constants and `const fn`s.
+///
+/// # Examples
+///
+/// ```rust
+/// use osom_encoders_x86_64::models::GPR;
+/// use osom_encoders_x86_64_macros::x86_64_asm;
+///
+/// const FRAME_SIZE: i8 = 16;
+/// const SCRATCH: GPR = GPR::R11;
+///
+/// const PROLOGUE: &[u8] = &x86_64_asm! {
+///     push rbp;
+///     mov rbp, rsp;
+///     sub rsp, {FRAME_SIZE: imm8};
+///     mov {SCRATCH: reg64}, [rbp + 16];
+/// };
+///
+/// assert_eq!(PROLOGUE, &[0x55, 0x48, 0x89, 0xE5, 0x48, 0x83, 0xEC, 0x10, 0x4C, 0x8B, 0x5D, 0x10]);
+/// ```
+#[proc_macro]
+pub fn x86_64_asm(input: TokenStream) -> TokenStream {
+    match expand::expand(input.into()) {
+        Ok(output) => output.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
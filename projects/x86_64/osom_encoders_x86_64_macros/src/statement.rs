@@ -0,0 +1,159 @@
+use osom_encoders_x86_64::parsing::ParseError;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+
+use crate::error::Error;
+use crate::interpolation::Interpolation;
+
+/// Represents the part of the source corresponding to a single token.
+#[derive(Debug, Clone, Copy)]
+struct SourceToken {
+    start: usize,
+    end: usize,
+    span: Span,
+}
+
+/// Represents a single instruction of the macro input, converted to the source
+/// understood by `osom_encoders_x86_64::parsing`.
+#[derive(Debug, Clone)]
+pub(crate) struct Statement {
+    pub source: String,
+    pub interpolations: Vec<Interpolation>,
+    tokens: Vec<SourceToken>,
+    operand: usize,
+}
+
+impl Statement {
+    fn new() -> Self {
+        Self {
+            source: String::new(),
+            interpolations: Vec::new(),
+            tokens: Vec::new(),
+            operand: 0,
+        }
+    }
+
+    fn push(&mut self, text: &str, span: Span) {
+        if !self.source.is_empty() {
+            self.source.push(' ');
+        }
+        let start = self.source.len();
+        self.source.push_str(text);
+        self.tokens.push(SourceToken {
+            start,
+            end: self.source.len(),
+            span,
+        });
+    }
+
+    fn push_token(&mut self, token: TokenTree, in_memory: bool) -> Result<(), Error> {
+        match token {
+            TokenTree::Ident(ident) => self.push(&ident.to_string(), ident.span()),
+            TokenTree::Literal(literal) => self.push(&literal.to_string(), literal.span()),
+            TokenTree::Punct(punct) => match punct.as_char() {
+                ch @ ('+' | '-' | '*') => self.push(&ch.to_string(), punct.span()),
+                ',' if !in_memory => {
+                    self.operand += 1;
+                    self.push(",", punct.span());
+                }
+                _ => return Err(Error::new(punct.span(), "unexpected character")),
+            },
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Bracket if !in_memory => {
+                    self.push("[", group.span_open());
+                    for inner in group.stream() {
+                        self.push_token(inner, true)?;
+                    }
+                    self.push("]", group.span_close());
+                }
+                Delimiter::Brace if !in_memory => {
+                    if let Some(decoration) = decoration_text(group.stream()) {
+                        self.push(&format!("{{{decoration}}}"), group.span());
+                        return Ok(());
+                    }
+                    let interpolation = Interpolation::parse(group.stream(), self.operand, group.span())?;
+                    self.push(interpolation.kind.placeholder(), group.span());
+                    self.interpolations.push(interpolation);
+                }
+                Delimiter::Brace => {
+                    return Err(Error::new(
+                        group.span(),
+                        "only register and immediate operands can be interpolated",
+                    ));
+                }
+                Delimiter::None => {
+                    for inner in group.stream() {
+                        self.push_token(inner, in_memory)?;
+                    }
+                }
+                _ => return Err(Error::new(group.span(), "unexpected token")),
+            },
+        }
+        Ok(())
+    }
+
+    /// Converts `err` returned by the parser to a compile error pointing
+    /// at the corresponding tokens.
+    pub fn error(&self, err: &ParseError) -> Error {
+        let mut overlapping = self
+            .tokens
+            .iter()
+            .filter(|token| token.start < err.span.end && token.end > err.span.start);
+        let first = overlapping.next().or_else(|| self.tokens.last()).unwrap().span;
+        let span = overlapping
+            .next_back()
+            .and_then(|last| first.join(last.span))
+            .unwrap_or(first);
+        Error::new(span, err.kind.to_string())
+    }
+}
+
+/// Returns the text of AVX-512 decoration, e.g. `k1` of `{k1}` or `rn-sae` of `{rn-sae}`,
+/// or `None` if `content` is not a decoration (e.g. it is an interpolation).
+fn decoration_text(content: TokenStream) -> Option<String> {
+    let mut text = String::new();
+    for token in content {
+        match token {
+            TokenTree::Ident(ident) => text.push_str(&ident.to_string()),
+            TokenTree::Literal(literal) => text.push_str(&literal.to_string()),
+            TokenTree::Punct(punct) if punct.as_char() == '-' => text.push('-'),
+            _ => return None,
+        }
+    }
+    is_decoration(&text.to_ascii_lowercase()).then_some(text)
+}
+
+fn is_decoration(text: &str) -> bool {
+    match text {
+        "z" | "sae" | "rn-sae" | "rd-sae" | "ru-sae" | "rz-sae" => true,
+        _ => {
+            let opmask = text
+                .strip_prefix('k')
+                .is_some_and(|index| matches!(index, "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7"));
+            let broadcast = text
+                .strip_prefix("1to")
+                .is_some_and(|count| !count.is_empty() && count.bytes().all(|byte| byte.is_ascii_digit()));
+            opmask || broadcast
+        }
+    }
+}
+
+/// Splits the macro input into statements separated by `;`.
+pub(crate) fn split_statements(input: TokenStream) -> Result<Vec<Statement>, Error> {
+    let mut statements = Vec::new();
+    let mut current = Statement::new();
+    for token in input {
+        if let TokenTree::Punct(punct) = &token
+            && punct.as_char() == ';'
+        {
+            if !current.tokens.is_empty() {
+                statements.push(core::mem::replace(&mut current, Statement::new()));
+            }
+            continue;
+        }
+        current.push_token(token, false)?;
+    }
+    if !current.tokens.is_empty() {
+        statements.push(current);
+    }
+    Ok(statements)
+}
@@ -0,0 +1,131 @@
+use rstest::rstest;
+
+use osom_encoders_x86_64::encoders::{add, jmp};
+use osom_encoders_x86_64::models::*;
+use osom_encoders_x86_64::parsing::parse_intel;
+use osom_encoders_x86_64_macros::x86_64_asm;
+
+const FRAME_SIZE: i8 = 16;
+const SCRATCH: GPR = GPR::R11;
+const VALUE: i32 = 0x1234_5678;
+
+const fn double(value: i32) -> i32 {
+    value * 2
+}
+
+fn parse(source: &str) -> Vec<u8> {
+    parse_intel(source)
+        .unwrap()
+        .iter()
+        .flat_map(|encoded| encoded.as_slice().iter().copied())
+        .collect()
+}
+
+#[rstest]
+#[case::zo(&x86_64_asm! { ret }, "ret")]
+#[case::prologue(&x86_64_asm! { push rbp; mov rbp, rsp; }, "push rbp; mov rbp, rsp")]
+#[case::accumulator(&x86_64_asm! { add rax, 1000 }, "add rax, 1000")]
+#[case::imm8_form(&x86_64_asm! { add rax, 1 }, "add rax, 1")]
+#[case::negative(&x86_64_asm! { mov qword ptr [rbp + rax*4 - 8], -5 }, "mov qword ptr [rbp + rax*4 - 8], -5")]
+#[case::rip(&x86_64_asm! { lea rax, [rip + 0x10] }, "lea rax, [rip + 16]")]
+#[case::unsigned(&x86_64_asm! { int 0x80; ret 65535 }, "int 0x80; ret 65535")]
+#[case::upper_case(&x86_64_asm! { MOV R15, QWORD PTR [RSP] }, "mov r15, [rsp]")]
+#[case::sse(&x86_64_asm! { addsd xmm0, qword ptr [rax]; movq rax, xmm9 }, "addsd xmm0, [rax]; movq rax, xmm9")]
+#[case::interpolation_with_sse(&x86_64_asm! { cvtsi2sd xmm1, {SCRATCH: reg64}; movss xmm2, xmm1 }, "cvtsi2sd xmm1, r11; movss xmm2, xmm1")]
+#[case::packed(&x86_64_asm! { movdqu xmm0, xmmword ptr [rsi]; pblendvb xmm1, xmm0, xmm0 }, "movdqu xmm0, [rsi]; pblendvb xmm1, xmm0, xmm0")]
+#[case::avx(&x86_64_asm! { vpaddd ymm0, ymm1, ymmword ptr [rsi]; vpbroadcastq ymm2, xmm3; vzeroupper }, "vpaddd ymm0, ymm1, [rsi]; vpbroadcastq ymm2, xmm3; vzeroupper")]
+#[case::interpolation_with_packed(&x86_64_asm! { pinsrb xmm1, {GPR::R11D: reg32}, 3; pextrw {GPR::EAX: reg32}, xmm1, 1 }, "pinsrb xmm1, r11d, 3; pextrw eax, xmm1, 1")]
+#[case::avx512(
+    &x86_64_asm! { vaddps zmm0 {k1} {z}, zmm1, dword ptr [rax + 4]{1to16}; vaddps zmm2, zmm3, zmm4, {rn-sae} },
+    "vaddps zmm0 {k1} {z}, zmm1, dword ptr [rax + 4]{1to16}; vaddps zmm2, zmm3, zmm4, {rn-sae}",
+)]
+#[case::avx512(
+    &x86_64_asm! { vcmpps k1 {k2}, zmm1, zmm2, {sae}, 1; kmovw eax, k1 },
+    "vcmpps k1 {k2}, zmm1, zmm2, {sae}, 1; kmovw eax, k1",
+)]
+#[case::avx512(
+    &x86_64_asm! { vcmppd k1, zmm2, zmm3, 5; vpcmpuq k1 {k3}, zmm2, qword ptr [rax]{1to8}, 1 },
+    "vcmppd k1, zmm2, zmm3, 5; vpcmpuq k1 {k3}, zmm2, qword ptr [rax]{1to8}, 1",
+)]
+#[case::interpolation_with_avx512(
+    &x86_64_asm! { vmovups zmmword ptr [rdi] {k1}, zmm31; vpcmpd k1, zmm1, zmm2, {FRAME_SIZE: imm8} },
+    "vmovups zmmword ptr [rdi] {k1}, zmm31; vpcmpd k1, zmm1, zmm2, 16",
+)]
+#[case::vsib(
+    &x86_64_asm! { vpgatherdd xmm1, [rax + xmm2*4 + 8], xmm3; vgatherqpd zmm1 {k1}, [zmm2*8 + 1024] },
+    "vpgatherdd xmm1, [rax + xmm2*4 + 8], xmm3; vgatherqpd zmm1 {k1}, [zmm2*8 + 1024]",
+)]
+#[case::vsib(
+    &x86_64_asm! { vpscatterqd dword ptr [r12 + zmm31] {k7}, ymm16 },
+    "vpscatterqd dword ptr [r12 + zmm31] {k7}, ymm16",
+)]
+fn test_asm(#[case] bytes: &[u8], #[case] source: &str) {
+    assert_eq!(bytes, parse(source));
+}
+
+#[rstest]
+#[case::reg(&x86_64_asm! { mov {SCRATCH: reg64}, rax }, "mov r11, rax")]
+#[case::reg(&x86_64_asm! { mov rax, {SCRATCH: reg64} }, "mov rax, r11")]
+#[case::reg(&x86_64_asm! { push {GPR::RBX: reg64} }, "push rbx")]
+#[case::reg(&x86_64_asm! { xor {GPR::R8D: reg32}, {GPR::R8D: reg32} }, "xor r8d, r8d")]
+#[case::reg8(&x86_64_asm! { mov {GPR::SIL: reg8}, {FRAME_SIZE: imm8} }, "mov sil, 16")]
+#[case::reg8(&x86_64_asm! { mov {GPR::AH: reg8}, bh }, "mov ah, bh")]
+#[case::imm8(&x86_64_asm! { sub rsp, {FRAME_SIZE: imm8} }, "sub rsp, 16")]
+#[case::imm8(&x86_64_asm! { int {0x80u8 as i8: imm8} }, "int 0x80")]
+#[case::imm16(&x86_64_asm! { push word {-2: imm16} }, "push word -2")]
+#[case::imm32(&x86_64_asm! { add eax, {VALUE: imm32} }, "add eax, 0x12345678")]
+#[case::imm64(&x86_64_asm! { mov rax, {i64::MIN: imm64} }, "mov rax, 0x8000000000000000")]
+#[case::memory(
+    &x86_64_asm! { mov qword ptr [rbp + rax*4 - 8], {VALUE: imm32} },
+    "mov qword ptr [rbp + rax*4 - 8], 0x12345678",
+)]
+#[case::memory(&x86_64_asm! { mov {SCRATCH: reg64}, [rcx*8 - 300] }, "mov r11, [rcx*8 - 300]")]
+#[case::memory(&x86_64_asm! { lea {SCRATCH: reg64}, [rip + 5] }, "lea r11, [rip + 5]")]
+#[case::memory(&x86_64_asm! { add [r13 + rsp + 1], {SCRATCH: reg64} }, "add [r13 + rsp + 1], r11")]
+#[case::memory(&x86_64_asm! { mov byte ptr [rsp], {1 + 1: imm8} }, "mov byte ptr [rsp], 2")]
+fn test_asm_interpolation(#[case] bytes: &[u8], #[case] source: &str) {
+    assert_eq!(bytes, parse(source));
+}
+
+#[test]
+fn test_asm_interpolation_picks_encoding_by_kind() {
+    const ADD: &[u8] = &x86_64_asm! { add {GPR::RAX: reg64}, 1000 };
+    const JMP: &[u8] = &x86_64_asm! { jmp {double(-4): imm32} };
+    let add = unsafe { add::encode_rm64_imm32(GPR::RAX.into(), Immediate32::from_i32(1000)) };
+    let jmp = unsafe { jmp::encode_imm32(Immediate32::from_i32(-8)) };
+    assert_eq!(ADD, add.as_slice());
+    assert_eq!(JMP, jmp.as_slice());
+}
+
+#[test]
+fn test_asm_mixed() {
+    const CODE: &[u8] = &x86_64_asm! {
+        push rbp;
+        mov rbp, rsp;
+        sub rsp, {FRAME_SIZE: imm8};
+        mov {SCRATCH: reg64}, [rbp + 16];
+        mov rsp, rbp;
+        pop rbp;
+        ret;
+    };
+    assert_eq!(
+        CODE,
+        parse("push rbp; mov rbp, rsp; sub rsp, 16; mov r11, [rbp + 16]; mov rsp, rbp; pop rbp; ret")
+    );
+}
+
+#[test]
+fn test_asm_const_array() {
+    const EMPTY: [u8; 0] = x86_64_asm! {};
+    const NOP: [u8; 1] = x86_64_asm! { nop };
+    const PUSH: [u8; 2] = x86_64_asm! { push {GPR::R12: reg64} };
+    assert!(EMPTY.is_empty());
+    assert_eq!(NOP, [0x90]);
+    assert_eq!(PUSH, [0x41, 0x54]);
+}
+
+#[test]
+fn test_compile_errors() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,5 @@
+use osom_encoders_x86_64_macros::x86_64_asm;
+
+fn main() {
+    let _ = x86_64_asm! { mov [rax + 8], 5 };
+}
@@ -0,0 +1,6 @@
+use osom_encoders_x86_64::models::GPR;
+use osom_encoders_x86_64_macros::x86_64_asm;
+
+fn main() {
+    let _ = x86_64_asm! { mov {GPR::EAX: reg64}, 1 };
+}
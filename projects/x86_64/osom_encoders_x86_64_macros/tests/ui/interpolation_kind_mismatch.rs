@@ -0,0 +1,7 @@
+use osom_encoders_x86_64_macros::x86_64_asm;
+
+const VALUE: i8 = 1;
+
+fn main() {
+    let _ = x86_64_asm! { mov rax, {VALUE: imm8} };
+}